@@ -0,0 +1,3 @@
+-- Add down migration script here
+DROP TABLE "shopping_list_items";
+DROP TABLE "shopping_lists";
@@ -0,0 +1,21 @@
+-- Add up migration script here
+CREATE TABLE "shopping_lists" (
+  "id" uuid PRIMARY KEY,
+  "name" varchar(255) NOT NULL,
+  "recipe_ids" uuid array NOT NULL DEFAULT '{}',
+  "created_at" TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT timezone('utc', now()),
+  "updated_at" TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT timezone('utc', now())
+);
+
+CREATE TABLE "shopping_list_items" (
+  "id" uuid PRIMARY KEY,
+  "shopping_list_id" uuid NOT NULL,
+  "ingredient_id" uuid NOT NULL,
+  "amount" json NOT NULL,
+  "checked" bool NOT NULL DEFAULT false,
+  "position" integer NOT NULL
+);
+
+ALTER TABLE "shopping_list_items" ADD FOREIGN KEY ("shopping_list_id") REFERENCES "shopping_lists" ("id") ON DELETE CASCADE;
+
+ALTER TABLE "shopping_list_items" ADD FOREIGN KEY ("ingredient_id") REFERENCES "ingredients" ("id") ON DELETE CASCADE;
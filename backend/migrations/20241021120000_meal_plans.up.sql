@@ -0,0 +1,14 @@
+-- Add up migration script here
+CREATE TABLE "meal_plan_entries" (
+  "id" uuid PRIMARY KEY,
+  "date" date NOT NULL,
+  "slot" varchar(20) NOT NULL,
+  "recipe_id" uuid NOT NULL,
+  "servings" smallint NOT NULL,
+  "created_at" TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT timezone('utc', now()),
+  "updated_at" TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT timezone('utc', now())
+);
+
+ALTER TABLE "meal_plan_entries" ADD FOREIGN KEY ("recipe_id") REFERENCES "recipes" ("id") ON DELETE CASCADE;
+
+CREATE INDEX "meal_plan_entries_date_idx" ON "meal_plan_entries" ("date");
@@ -0,0 +1,7 @@
+-- Add up migration script here
+CREATE TABLE "calendar_feeds" (
+  "id" uuid PRIMARY KEY,
+  "name" varchar(255) NOT NULL,
+  "token" varchar(64) UNIQUE NOT NULL,
+  "created_at" TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT timezone('utc', now())
+);
@@ -0,0 +1,2 @@
+-- Add down migration script here
+DROP TABLE "pantry_items";
@@ -0,0 +1,11 @@
+-- Add up migration script here
+CREATE TABLE "pantry_items" (
+  "id" uuid PRIMARY KEY,
+  "ingredient_id" uuid UNIQUE NOT NULL,
+  "amount" json,
+  "expires_at" date,
+  "created_at" TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT timezone('utc', now()),
+  "updated_at" TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT timezone('utc', now())
+);
+
+ALTER TABLE "pantry_items" ADD FOREIGN KEY ("ingredient_id") REFERENCES "ingredients" ("id") ON DELETE CASCADE;
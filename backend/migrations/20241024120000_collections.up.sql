@@ -0,0 +1,25 @@
+-- Add up migration script here
+CREATE TABLE "collections" (
+  "id" uuid PRIMARY KEY,
+  "name" varchar(255) NOT NULL,
+  "description" text NOT NULL,
+  "visibility" varchar(20) NOT NULL,
+  "cover_recipe_id" uuid,
+  "created_at" TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT timezone('utc', now()),
+  "updated_at" TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT timezone('utc', now())
+);
+
+CREATE TABLE "collections_recipes" (
+  "collection_id" uuid NOT NULL,
+  "recipe_id" uuid NOT NULL,
+  "position" integer NOT NULL,
+  PRIMARY KEY ("collection_id", "recipe_id")
+);
+
+ALTER TABLE "collections" ADD FOREIGN KEY ("cover_recipe_id") REFERENCES "recipes" ("id") ON DELETE SET NULL;
+
+ALTER TABLE "collections_recipes" ADD FOREIGN KEY ("collection_id") REFERENCES "collections" ("id") ON DELETE CASCADE;
+
+ALTER TABLE "collections_recipes" ADD FOREIGN KEY ("recipe_id") REFERENCES "recipes" ("id") ON DELETE CASCADE;
+
+CREATE INDEX "collections_recipes_recipe_id_idx" ON "collections_recipes" ("recipe_id");
@@ -0,0 +1,15 @@
+-- Add up migration script here
+CREATE TABLE "recipe_reviews" (
+  "id" uuid PRIMARY KEY,
+  "recipe_id" uuid NOT NULL,
+  "author" varchar(255) NOT NULL,
+  "rating" smallint NOT NULL CHECK ("rating" BETWEEN 1 AND 5),
+  "body" text,
+  "created_at" TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT timezone('utc', now()),
+  "updated_at" TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT timezone('utc', now()),
+  UNIQUE ("recipe_id", "author")
+);
+
+ALTER TABLE "recipe_reviews" ADD FOREIGN KEY ("recipe_id") REFERENCES "recipes" ("id") ON DELETE CASCADE;
+
+CREATE INDEX "recipe_reviews_updated_at_idx" ON "recipe_reviews" ("updated_at");
@@ -0,0 +1,16 @@
+-- Add up migration script here
+CREATE TABLE "favorites" (
+  "recipe_id" uuid PRIMARY KEY,
+  "created_at" TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT timezone('utc', now())
+);
+
+CREATE TABLE "recipe_notes" (
+  "recipe_id" uuid PRIMARY KEY,
+  "content" text NOT NULL,
+  "created_at" TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT timezone('utc', now()),
+  "updated_at" TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT timezone('utc', now())
+);
+
+ALTER TABLE "favorites" ADD FOREIGN KEY ("recipe_id") REFERENCES "recipes" ("id") ON DELETE CASCADE;
+
+ALTER TABLE "recipe_notes" ADD FOREIGN KEY ("recipe_id") REFERENCES "recipes" ("id") ON DELETE CASCADE;
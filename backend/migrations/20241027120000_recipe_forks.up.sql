@@ -0,0 +1,6 @@
+-- Add up migration script here
+ALTER TABLE "recipes" ADD COLUMN "forked_from" uuid;
+
+ALTER TABLE "recipes" ADD FOREIGN KEY ("forked_from") REFERENCES "recipes" ("id") ON DELETE SET NULL;
+
+CREATE INDEX ON "recipes" ("forked_from");
@@ -0,0 +1,3 @@
+-- Add down migration script here
+DROP TABLE "recipe_share_tokens";
+ALTER TABLE "recipes" DROP COLUMN "visibility";
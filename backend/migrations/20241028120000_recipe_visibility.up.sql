@@ -0,0 +1,13 @@
+-- Add up migration script here
+-- Every recipe used to be readable by anyone, so the existing ones stay public
+ALTER TABLE "recipes" ADD COLUMN "visibility" varchar(20) NOT NULL DEFAULT 'public';
+
+CREATE TABLE "recipe_share_tokens" (
+  "token" text PRIMARY KEY,
+  "recipe_id" uuid NOT NULL,
+  "created_at" TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT timezone('utc', now())
+);
+
+ALTER TABLE "recipe_share_tokens" ADD FOREIGN KEY ("recipe_id") REFERENCES "recipes" ("id") ON DELETE CASCADE;
+
+CREATE INDEX ON "recipe_share_tokens" ("recipe_id");
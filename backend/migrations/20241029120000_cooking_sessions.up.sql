@@ -0,0 +1,12 @@
+-- Add up migration script here
+CREATE TABLE "cooking_sessions" (
+  "id" uuid PRIMARY KEY,
+  "recipe_id" uuid NOT NULL,
+  "current_step" integer NOT NULL DEFAULT 0,
+  "checked_ingredients" uuid[] NOT NULL DEFAULT '{}',
+  "timers" jsonb NOT NULL DEFAULT '[]',
+  "created_at" TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT timezone('utc', now()),
+  "updated_at" TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT timezone('utc', now())
+);
+
+ALTER TABLE "cooking_sessions" ADD FOREIGN KEY ("recipe_id") REFERENCES "recipes" ("id") ON DELETE CASCADE;
@@ -0,0 +1,25 @@
+-- Add up migration script here
+CREATE TABLE "webhooks" (
+  "id" uuid PRIMARY KEY,
+  "url" text NOT NULL,
+  "secret" text NOT NULL,
+  "event_types" text[] NOT NULL,
+  "created_at" TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT timezone('utc', now())
+);
+
+CREATE TABLE "webhook_deliveries" (
+  "id" uuid NOT NULL,
+  "webhook_id" uuid NOT NULL,
+  "event_type" varchar(100) NOT NULL,
+  "payload" jsonb NOT NULL,
+  "attempt" integer NOT NULL,
+  "status_code" integer,
+  "error" text,
+  "succeeded" boolean NOT NULL,
+  "created_at" TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT timezone('utc', now()),
+  PRIMARY KEY ("id", "attempt")
+);
+
+ALTER TABLE "webhook_deliveries" ADD FOREIGN KEY ("webhook_id") REFERENCES "webhooks" ("id") ON DELETE CASCADE;
+
+CREATE INDEX ON "webhook_deliveries" ("webhook_id", "created_at");
@@ -0,0 +1,10 @@
+-- Add up migration script here
+CREATE TABLE "outbox" (
+  "id" uuid PRIMARY KEY,
+  "event_type" varchar(100) NOT NULL,
+  "payload" jsonb NOT NULL,
+  "created_at" TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT timezone('utc', now()),
+  "published_at" TIMESTAMP WITH TIME ZONE
+);
+
+CREATE INDEX ON "outbox" ("created_at") WHERE "published_at" IS NULL;
@@ -0,0 +1,2 @@
+-- Add down migration script here
+ALTER TABLE "ingredients" DROP COLUMN updated_at CASCADE;
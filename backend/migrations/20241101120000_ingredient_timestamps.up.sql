@@ -0,0 +1,2 @@
+-- Add up migration script here
+ALTER TABLE "ingredients" ADD COLUMN updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT timezone('utc', now());
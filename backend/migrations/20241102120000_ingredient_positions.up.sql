@@ -0,0 +1,13 @@
+-- Add up migration script here
+ALTER TABLE "ingredients_recipes" ADD COLUMN position INTEGER NOT NULL DEFAULT 0;
+
+-- Keep the order the ingredients were added in so far
+UPDATE "ingredients_recipes" AS ir
+SET position = numbered.position
+FROM (
+    SELECT
+    ctid,
+    ROW_NUMBER() OVER (PARTITION BY recipe_id ORDER BY ctid) - 1 AS position
+    FROM "ingredients_recipes"
+) AS numbered
+WHERE ir.ctid = numbered.ctid;
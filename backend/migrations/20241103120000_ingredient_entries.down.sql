@@ -0,0 +1,15 @@
+-- Add down migration script here
+DROP INDEX "ingredients_recipes_ingredient_id_idx";
+
+-- Only the first entry of a repeated ingredient fits the old primary key
+DELETE FROM "ingredients_recipes" AS ir
+USING "ingredients_recipes" AS earlier
+WHERE ir.recipe_id = earlier.recipe_id
+AND ir.ingredient_id = earlier.ingredient_id
+AND ir.position > earlier.position;
+
+ALTER TABLE "ingredients_recipes" DROP CONSTRAINT "ingredients_recipes_pkey";
+ALTER TABLE "ingredients_recipes" ADD PRIMARY KEY ("recipe_id", "ingredient_id");
+
+ALTER TABLE "ingredients_recipes" DROP COLUMN group_label;
+ALTER TABLE "ingredients_recipes" DROP COLUMN id;
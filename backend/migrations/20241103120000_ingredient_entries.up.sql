@@ -0,0 +1,10 @@
+-- Add up migration script here
+ALTER TABLE "ingredients_recipes" ADD COLUMN id uuid NOT NULL DEFAULT gen_random_uuid();
+ALTER TABLE "ingredients_recipes" ALTER COLUMN id DROP DEFAULT;
+ALTER TABLE "ingredients_recipes" ADD COLUMN group_label text;
+
+-- Entries are addressed by their own ID, so an ingredient can be used more than once
+ALTER TABLE "ingredients_recipes" DROP CONSTRAINT "ingredients_recipes_pkey";
+ALTER TABLE "ingredients_recipes" ADD PRIMARY KEY ("recipe_id", "id");
+
+CREATE INDEX "ingredients_recipes_ingredient_id_idx" ON "ingredients_recipes" ("ingredient_id");
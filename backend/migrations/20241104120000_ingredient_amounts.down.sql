@@ -0,0 +1,39 @@
+-- Add down migration script here
+
+-- Ranges become their upper bound, so there is still enough of the ingredient
+CREATE FUNCTION upper_bound_of_amount(amount json) RETURNS json AS $$
+    SELECT CASE
+        WHEN amount->'range' IS NULL THEN amount
+        ELSE (
+            SELECT json_object_agg(
+                unit.key,
+                CASE
+                    WHEN json_typeof(unit.value) = 'object'
+                        THEN json_build_object('amount', amount->'range'->'to', 'unit', unit.value->'unit')
+                    ELSE amount->'range'->'to'
+                END
+            )
+            FROM json_each(amount->'range'->'from') AS unit
+        )
+    END
+$$ LANGUAGE SQL IMMUTABLE;
+
+CREATE FUNCTION revert_ingredient_amount(amount json) RETURNS json AS $$
+    SELECT CASE
+        WHEN upper->'pieces' IS NOT NULL
+            THEN json_build_object('other', json_build_object('amount', upper->'pieces', 'unit', ''))
+        WHEN upper->'tablespoons' IS NOT NULL
+            THEN json_build_object('teaspoons', (upper->>'tablespoons')::float8 * 3)
+        WHEN upper->'unquantified' IS NOT NULL
+            THEN json_build_object('other', json_build_object('amount', 1, 'unit', upper->'unquantified'))
+        ELSE upper
+    END
+    FROM (SELECT upper_bound_of_amount(amount) AS upper) AS bound
+$$ LANGUAGE SQL IMMUTABLE;
+
+UPDATE "ingredients_recipes" SET amount = revert_ingredient_amount(amount);
+UPDATE "shopping_list_items" SET amount = revert_ingredient_amount(amount);
+UPDATE "pantry_items" SET amount = revert_ingredient_amount(amount) WHERE amount IS NOT NULL;
+
+DROP FUNCTION revert_ingredient_amount(json);
+DROP FUNCTION upper_bound_of_amount(json);
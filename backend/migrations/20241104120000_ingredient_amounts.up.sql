@@ -0,0 +1,19 @@
+-- Add up migration script here
+
+-- Amounts without a unit were counts all along, and `to taste` was never a real unit.
+-- Teaspoons that used to be tablespoons can no longer be told apart, so they stay as they are.
+CREATE FUNCTION migrate_ingredient_amount(amount json) RETURNS json AS $$
+    SELECT CASE
+        WHEN lower(trim(amount->'other'->>'unit')) IN ('', 'piece', 'pieces', 'pc', 'pcs', 'whole')
+            THEN json_build_object('pieces', amount->'other'->'amount')
+        WHEN lower(trim(amount->'other'->>'unit')) IN ('to taste', 'as needed')
+            THEN json_build_object('unquantified', lower(trim(amount->'other'->>'unit')))
+        ELSE amount
+    END
+$$ LANGUAGE SQL IMMUTABLE;
+
+UPDATE "ingredients_recipes" SET amount = migrate_ingredient_amount(amount);
+UPDATE "shopping_list_items" SET amount = migrate_ingredient_amount(amount);
+UPDATE "pantry_items" SET amount = migrate_ingredient_amount(amount) WHERE amount IS NOT NULL;
+
+DROP FUNCTION migrate_ingredient_amount(json);
@@ -0,0 +1,8 @@
+-- Add up migration script here
+CREATE TABLE "users" (
+  "id" uuid PRIMARY KEY,
+  "username" varchar(255) UNIQUE NOT NULL,
+  "email" varchar(255) UNIQUE NOT NULL,
+  "password_hash" text NOT NULL,
+  "created_at" TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT timezone('utc', now())
+);
@@ -0,0 +1,6 @@
+-- Add down migration script here
+DROP INDEX "meal_plan_entries_user_id_date_idx";
+CREATE INDEX "meal_plan_entries_date_idx" ON "meal_plan_entries" ("date");
+
+ALTER TABLE "calendar_feeds" DROP COLUMN "user_id";
+ALTER TABLE "meal_plan_entries" DROP COLUMN "user_id";
@@ -0,0 +1,11 @@
+-- Add up migration script here
+-- Meal plans made before there were accounts are kept without an owner, so that no data is lost.
+-- Nobody sees them until they are given one.
+ALTER TABLE "meal_plan_entries" ADD COLUMN "user_id" uuid;
+ALTER TABLE "calendar_feeds" ADD COLUMN "user_id" uuid;
+
+ALTER TABLE "meal_plan_entries" ADD FOREIGN KEY ("user_id") REFERENCES "users" ("id") ON DELETE CASCADE;
+ALTER TABLE "calendar_feeds" ADD FOREIGN KEY ("user_id") REFERENCES "users" ("id") ON DELETE CASCADE;
+
+DROP INDEX "meal_plan_entries_date_idx";
+CREATE INDEX "meal_plan_entries_user_id_date_idx" ON "meal_plan_entries" ("user_id", "date");
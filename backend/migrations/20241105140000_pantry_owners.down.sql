@@ -0,0 +1,4 @@
+-- Add down migration script here
+ALTER TABLE "pantry_items" DROP CONSTRAINT "pantry_items_user_id_ingredient_id_key";
+ALTER TABLE "pantry_items" DROP COLUMN "user_id";
+ALTER TABLE "pantry_items" ADD CONSTRAINT "pantry_items_ingredient_id_key" UNIQUE ("ingredient_id");
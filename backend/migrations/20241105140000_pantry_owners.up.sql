@@ -0,0 +1,9 @@
+-- Add up migration script here
+-- The pantry was shared before there were accounts, so nobody in particular owns what is in it.
+-- Those items are kept without an owner, and stay out of every pantry until they are given one.
+ALTER TABLE "pantry_items" ADD COLUMN "user_id" uuid;
+
+ALTER TABLE "pantry_items" ADD FOREIGN KEY ("user_id") REFERENCES "users" ("id") ON DELETE CASCADE;
+
+ALTER TABLE "pantry_items" DROP CONSTRAINT "pantry_items_ingredient_id_key";
+ALTER TABLE "pantry_items" ADD CONSTRAINT "pantry_items_user_id_ingredient_id_key" UNIQUE ("user_id", "ingredient_id");
@@ -0,0 +1,4 @@
+-- Add down migration script here
+ALTER TABLE "recipe_reviews" DROP CONSTRAINT "recipe_reviews_recipe_id_user_id_key";
+ALTER TABLE "recipe_reviews" DROP COLUMN "user_id";
+ALTER TABLE "recipe_reviews" ADD CONSTRAINT "recipe_reviews_recipe_id_author_key" UNIQUE ("recipe_id", "author");
@@ -0,0 +1,9 @@
+-- Add up migration script here
+-- Reviews were signed with a name anybody could type in, so there is no telling who wrote them.
+-- They are kept with the name they were signed with, but without an author who could edit them.
+ALTER TABLE "recipe_reviews" ADD COLUMN "user_id" uuid;
+
+ALTER TABLE "recipe_reviews" ADD FOREIGN KEY ("user_id") REFERENCES "users" ("id") ON DELETE CASCADE;
+
+ALTER TABLE "recipe_reviews" DROP CONSTRAINT "recipe_reviews_recipe_id_author_key";
+ALTER TABLE "recipe_reviews" ADD CONSTRAINT "recipe_reviews_recipe_id_user_id_key" UNIQUE ("recipe_id", "user_id");
@@ -0,0 +1,11 @@
+-- Add down migration script here
+DELETE FROM "favorites" WHERE "user_id" IS NOT NULL;
+DELETE FROM "recipe_notes" WHERE "user_id" IS NOT NULL;
+
+ALTER TABLE "favorites" DROP CONSTRAINT "favorites_user_id_recipe_id_key";
+ALTER TABLE "favorites" DROP COLUMN "user_id";
+ALTER TABLE "favorites" ADD PRIMARY KEY ("recipe_id");
+
+ALTER TABLE "recipe_notes" DROP CONSTRAINT "recipe_notes_user_id_recipe_id_key";
+ALTER TABLE "recipe_notes" DROP COLUMN "user_id";
+ALTER TABLE "recipe_notes" ADD PRIMARY KEY ("recipe_id");
@@ -0,0 +1,14 @@
+-- Add up migration script here
+-- Favorites and notes were shared before there were accounts, so nobody in particular owns them.
+-- They are kept without an owner, and stay hidden from everybody until they are given one.
+ALTER TABLE "favorites" ADD COLUMN "user_id" uuid;
+ALTER TABLE "recipe_notes" ADD COLUMN "user_id" uuid;
+
+ALTER TABLE "favorites" ADD FOREIGN KEY ("user_id") REFERENCES "users" ("id") ON DELETE CASCADE;
+ALTER TABLE "recipe_notes" ADD FOREIGN KEY ("user_id") REFERENCES "users" ("id") ON DELETE CASCADE;
+
+ALTER TABLE "favorites" DROP CONSTRAINT "favorites_pkey";
+ALTER TABLE "favorites" ADD CONSTRAINT "favorites_user_id_recipe_id_key" UNIQUE ("user_id", "recipe_id");
+
+ALTER TABLE "recipe_notes" DROP CONSTRAINT "recipe_notes_pkey";
+ALTER TABLE "recipe_notes" ADD CONSTRAINT "recipe_notes_user_id_recipe_id_key" UNIQUE ("user_id", "recipe_id");
@@ -0,0 +1,7 @@
+-- Add up migration script here
+-- Recipes from before there were accounts have no owner, so only their visibility decides who sees them
+ALTER TABLE "recipes" ADD COLUMN "owner_id" uuid;
+
+ALTER TABLE "recipes" ADD FOREIGN KEY ("owner_id") REFERENCES "users" ("id") ON DELETE SET NULL;
+
+CREATE INDEX ON "recipes" ("owner_id");
@@ -0,0 +1,2 @@
+-- Add down migration script here
+DROP INDEX "outbox_published_at_idx";
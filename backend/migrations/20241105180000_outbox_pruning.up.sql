@@ -0,0 +1,3 @@
+-- Add up migration script here
+-- Published events are only kept around for a while, this finds the ones old enough to go
+CREATE INDEX "outbox_published_at_idx" ON "outbox" ("published_at") WHERE "published_at" IS NOT NULL;
@@ -0,0 +1,7 @@
+-- Add up migration script here
+-- Collections from before there were accounts have no owner, so only their visibility decides who sees them
+ALTER TABLE "collections" ADD COLUMN "owner_id" uuid;
+
+ALTER TABLE "collections" ADD FOREIGN KEY ("owner_id") REFERENCES "users" ("id") ON DELETE SET NULL;
+
+CREATE INDEX ON "collections" ("owner_id");
@@ -0,0 +1,6 @@
+-- Add down migration script here
+ALTER TABLE "webhook_deliveries" DROP CONSTRAINT "webhook_deliveries_pkey";
+
+ALTER TABLE "webhook_deliveries" ADD PRIMARY KEY ("id", "attempt");
+
+ALTER TABLE "webhooks" DROP COLUMN "owner_id";
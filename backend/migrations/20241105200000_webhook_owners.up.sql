@@ -0,0 +1,13 @@
+-- Add up migration script here
+-- Webhooks from before there were accounts have no owner, so every signed in user can manage them.
+-- The webhooks of a user go away with the user, rather than becoming everybody's.
+ALTER TABLE "webhooks" ADD COLUMN "owner_id" uuid;
+
+ALTER TABLE "webhooks" ADD FOREIGN KEY ("owner_id") REFERENCES "users" ("id") ON DELETE CASCADE;
+
+CREATE INDEX ON "webhooks" ("owner_id");
+
+-- Deliveries are identified by the event they deliver, which goes to every webhook that wants it
+ALTER TABLE "webhook_deliveries" DROP CONSTRAINT "webhook_deliveries_pkey";
+
+ALTER TABLE "webhook_deliveries" ADD PRIMARY KEY ("webhook_id", "id", "attempt");
@@ -0,0 +1,10 @@
+-- Add down migration script here
+DROP INDEX "outbox_created_at_idx";
+
+CREATE INDEX "outbox_created_at_idx" ON "outbox" ("created_at") WHERE "published_at" IS NULL;
+
+ALTER TABLE "outbox" DROP COLUMN "failure";
+
+ALTER TABLE "outbox" DROP COLUMN "failed_at";
+
+ALTER TABLE "outbox" DROP COLUMN "version";
@@ -0,0 +1,14 @@
+-- Add up migration script here
+-- The events recorded so far were all written the first way there was
+ALTER TABLE "outbox" ADD COLUMN "version" smallint NOT NULL DEFAULT 1;
+
+ALTER TABLE "outbox" ALTER COLUMN "version" DROP DEFAULT;
+
+-- Events that can't be read are set aside along with the reason, instead of holding up the rest
+ALTER TABLE "outbox" ADD COLUMN "failed_at" TIMESTAMP WITH TIME ZONE;
+
+ALTER TABLE "outbox" ADD COLUMN "failure" text;
+
+DROP INDEX "outbox_created_at_idx";
+
+CREATE INDEX "outbox_created_at_idx" ON "outbox" ("created_at") WHERE "published_at" IS NULL AND "failed_at" IS NULL;
@@ -0,0 +1,4 @@
+INSERT INTO collections_recipes
+(collection_id, recipe_id, position)
+VALUES
+($1, $2, $3);
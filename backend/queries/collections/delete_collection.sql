@@ -0,0 +1 @@
+DELETE FROM collections WHERE id = $1;
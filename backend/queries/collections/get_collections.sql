@@ -0,0 +1,17 @@
+SELECT
+c.id,
+c.name,
+c.description,
+c.visibility,
+c.owner_id,
+c.cover_recipe_id,
+c.created_at,
+c.updated_at,
+ARRAY(
+    SELECT cr.recipe_id
+    FROM collections_recipes AS cr
+    WHERE cr.collection_id = c.id
+    ORDER BY cr.position ASC
+) AS "recipes!"
+FROM collections AS c
+ORDER BY c.name ASC, c.id ASC
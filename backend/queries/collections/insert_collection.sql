@@ -0,0 +1,4 @@
+INSERT INTO collections
+(id, name, description, visibility, owner_id, cover_recipe_id, created_at, updated_at)
+VALUES
+($1, $2, $3, $4, $5, $6, $7, $8);
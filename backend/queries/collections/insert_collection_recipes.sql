@@ -0,0 +1,4 @@
+INSERT INTO collections_recipes
+(collection_id, recipe_id, position)
+SELECT $1, r.recipe_id, (r.position - 1)::integer
+FROM UNNEST($2::uuid[]) WITH ORDINALITY AS r(recipe_id, position);
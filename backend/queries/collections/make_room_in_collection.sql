@@ -0,0 +1,3 @@
+UPDATE collections_recipes
+SET position = position + 1
+WHERE collection_id = $1 AND position >= $2;
@@ -0,0 +1,9 @@
+WITH removed AS (
+    DELETE FROM collections_recipes
+    WHERE collection_id = $1 AND recipe_id = $2
+    RETURNING collection_id, position
+)
+UPDATE collections_recipes AS cr
+SET position = cr.position - 1
+FROM removed
+WHERE cr.collection_id = removed.collection_id AND cr.position > removed.position;
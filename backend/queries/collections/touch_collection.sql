@@ -0,0 +1,3 @@
+UPDATE collections
+SET updated_at = timezone('utc', now())
+WHERE id = $1;
@@ -0,0 +1,5 @@
+UPDATE collections
+SET
+cover_recipe_id = NULLIF(cover_recipe_id, $2),
+updated_at = timezone('utc', now())
+WHERE id = $1;
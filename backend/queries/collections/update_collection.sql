@@ -0,0 +1,8 @@
+UPDATE collections
+SET
+name = COALESCE($2, name),
+description = COALESCE($3, description),
+visibility = COALESCE($4, visibility),
+cover_recipe_id = COALESCE($5, cover_recipe_id),
+updated_at = timezone('utc', now())
+WHERE id = $1;
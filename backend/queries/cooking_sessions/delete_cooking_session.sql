@@ -0,0 +1,2 @@
+DELETE FROM cooking_sessions
+WHERE id = $1;
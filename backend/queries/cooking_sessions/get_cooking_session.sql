@@ -0,0 +1,10 @@
+SELECT
+id,
+recipe_id,
+current_step,
+checked_ingredients,
+timers,
+created_at,
+updated_at
+FROM cooking_sessions
+WHERE id = $1;
@@ -0,0 +1,14 @@
+SELECT
+id,
+recipe_id,
+current_step,
+checked_ingredients,
+timers,
+created_at,
+updated_at
+FROM cooking_sessions
+WHERE EXISTS (
+    SELECT 1
+    FROM jsonb_array_elements(timers) AS timer
+    WHERE NOT (timer->>'finished')::boolean
+);
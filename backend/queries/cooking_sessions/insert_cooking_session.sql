@@ -0,0 +1,4 @@
+INSERT INTO cooking_sessions
+(id, recipe_id, current_step, checked_ingredients, timers, created_at, updated_at)
+VALUES
+($1, $2, $3, $4, $5, $6, $7);
@@ -0,0 +1,7 @@
+UPDATE cooking_sessions
+SET
+current_step = $2,
+checked_ingredients = $3,
+timers = $4,
+updated_at = $5
+WHERE id = $1;
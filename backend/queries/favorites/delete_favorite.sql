@@ -0,0 +1,2 @@
+DELETE FROM favorites
+WHERE user_id = $1 AND recipe_id = $2;
@@ -0,0 +1,6 @@
+SELECT
+user_id AS "user_id!",
+recipe_id,
+created_at
+FROM favorites
+WHERE user_id = $1 AND recipe_id = $2;
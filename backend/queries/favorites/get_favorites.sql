@@ -0,0 +1,7 @@
+SELECT
+user_id AS "user_id!",
+recipe_id,
+created_at
+FROM favorites
+WHERE user_id = $1
+ORDER BY created_at DESC, recipe_id ASC;
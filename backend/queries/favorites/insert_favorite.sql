@@ -0,0 +1,4 @@
+INSERT INTO favorites
+(user_id, recipe_id, created_at)
+VALUES
+($1, $2, $3);
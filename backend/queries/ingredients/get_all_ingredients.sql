@@ -1,2 +1,2 @@
-SELECT id, name, description, diet_violations
+SELECT id, name, description, diet_violations, updated_at
 FROM ingredients;
@@ -1,3 +1,3 @@
-SELECT id, name, description, diet_violations
+SELECT id, name, description, diet_violations, updated_at
 FROM ingredients
 WHERE id = $1;
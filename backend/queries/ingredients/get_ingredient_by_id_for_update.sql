@@ -0,0 +1,4 @@
+SELECT id, name, description, diet_violations, updated_at
+FROM ingredients
+WHERE id = $1
+FOR UPDATE;
@@ -0,0 +1,3 @@
+UPDATE ingredients
+SET updated_at = timezone('utc', now())
+WHERE ingredients.id = $1;
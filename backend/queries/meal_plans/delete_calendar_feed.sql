@@ -0,0 +1,2 @@
+DELETE FROM calendar_feeds
+WHERE id = $1;
@@ -0,0 +1,2 @@
+DELETE FROM meal_plan_entries
+WHERE id = $1;
@@ -0,0 +1,8 @@
+SELECT
+id,
+user_id AS "user_id!",
+name,
+token,
+created_at
+FROM calendar_feeds
+WHERE token = $1 AND user_id IS NOT NULL
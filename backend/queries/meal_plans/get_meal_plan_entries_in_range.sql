@@ -0,0 +1,12 @@
+SELECT
+id,
+user_id AS "user_id!",
+date,
+slot,
+recipe_id,
+servings,
+created_at,
+updated_at
+FROM meal_plan_entries
+WHERE user_id = $1 AND date BETWEEN $2 AND $3
+ORDER BY date, array_position(ARRAY['breakfast', 'lunch', 'dinner', 'snack']::varchar[], slot), created_at
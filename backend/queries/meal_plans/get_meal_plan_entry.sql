@@ -0,0 +1,11 @@
+SELECT
+id,
+user_id AS "user_id!",
+date,
+slot,
+recipe_id,
+servings,
+created_at,
+updated_at
+FROM meal_plan_entries
+WHERE id = $1 AND user_id = $2
@@ -0,0 +1,4 @@
+INSERT INTO calendar_feeds
+(id, user_id, name, token, created_at)
+VALUES
+($1, $2, $3, $4, $5);
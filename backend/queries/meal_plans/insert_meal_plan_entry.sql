@@ -0,0 +1,4 @@
+INSERT INTO meal_plan_entries
+(id, user_id, date, slot, recipe_id, servings, created_at, updated_at)
+VALUES
+($1, $2, $3, $4, $5, $6, $7, $8);
@@ -0,0 +1,8 @@
+UPDATE meal_plan_entries
+SET
+date = COALESCE($2, date),
+slot = COALESCE($3, slot),
+recipe_id = COALESCE($4, recipe_id),
+servings = COALESCE($5, servings),
+updated_at = timezone('utc', now())
+WHERE id = $1;
@@ -0,0 +1,2 @@
+DELETE FROM outbox
+WHERE published_at < $1;
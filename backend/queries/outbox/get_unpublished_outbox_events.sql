@@ -0,0 +1,11 @@
+SELECT
+id,
+event_type,
+version,
+payload,
+created_at,
+published_at
+FROM outbox
+WHERE published_at IS NULL AND failed_at IS NULL
+ORDER BY created_at, id
+LIMIT $1;
@@ -0,0 +1,2 @@
+INSERT INTO outbox (id, event_type, version, payload, created_at)
+VALUES ($1, $2, $3, $4, $5);
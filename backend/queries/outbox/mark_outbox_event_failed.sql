@@ -0,0 +1,3 @@
+UPDATE outbox
+SET failed_at = timezone('utc', now()), failure = $2
+WHERE id = $1;
@@ -0,0 +1,3 @@
+UPDATE outbox
+SET published_at = timezone('utc', now())
+WHERE id = ANY($1);
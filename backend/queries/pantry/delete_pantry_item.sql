@@ -0,0 +1,2 @@
+DELETE FROM pantry_items
+WHERE id = $1;
@@ -0,0 +1,19 @@
+SELECT
+pi.id,
+pi.user_id AS "user_id!",
+pi.amount,
+pi.expires_at,
+pi.created_at,
+pi.updated_at,
+(
+    i.id,
+    i.name,
+    i.description,
+    i.diet_violations,
+    i.updated_at
+) as "ingredient!: IngredientModel"
+FROM pantry_items AS pi
+JOIN ingredients AS i
+    ON i.id = pi.ingredient_id
+WHERE pi.user_id = $1
+ORDER BY pi.expires_at ASC NULLS LAST, i.name ASC
@@ -0,0 +1,4 @@
+INSERT INTO pantry_items
+(id, user_id, ingredient_id, amount, expires_at, created_at, updated_at)
+VALUES
+($1, $2, $3, $4, $5, $6, $7);
@@ -0,0 +1,6 @@
+UPDATE pantry_items
+SET
+    amount = $2,
+    expires_at = $3,
+    updated_at = timezone('utc', now())
+WHERE id = $1;
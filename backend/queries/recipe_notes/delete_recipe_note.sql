@@ -0,0 +1,2 @@
+DELETE FROM recipe_notes
+WHERE user_id = $1 AND recipe_id = $2;
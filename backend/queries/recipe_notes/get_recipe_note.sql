@@ -0,0 +1,8 @@
+SELECT
+user_id AS "user_id!",
+recipe_id,
+content,
+created_at,
+updated_at
+FROM recipe_notes
+WHERE user_id = $1 AND recipe_id = $2;
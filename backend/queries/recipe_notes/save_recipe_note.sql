@@ -0,0 +1,8 @@
+INSERT INTO recipe_notes
+(user_id, recipe_id, content, created_at, updated_at)
+VALUES
+($1, $2, $3, $4, $5)
+ON CONFLICT (user_id, recipe_id) DO UPDATE
+SET
+content = EXCLUDED.content,
+updated_at = EXCLUDED.updated_at;
@@ -1,3 +1,3 @@
 DELETE FROM ingredients_recipes
 WHERE ingredients_recipes.recipe_id = $1
-AND ingredients_recipes.ingredient_id = $2;
+AND ingredients_recipes.id = $2;
@@ -0,0 +1,2 @@
+DELETE FROM recipe_reviews
+WHERE recipe_id = $1;
@@ -0,0 +1,2 @@
+DELETE FROM recipe_share_tokens
+WHERE token = $1;
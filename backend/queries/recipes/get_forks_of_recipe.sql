@@ -0,0 +1,4 @@
+SELECT id
+FROM recipes
+WHERE forked_from = $1
+ORDER BY created_at, id;
@@ -0,0 +1,4 @@
+SELECT id
+FROM ingredients_recipes
+WHERE recipe_id = $1
+ORDER BY position
@@ -1,14 +1,18 @@
 SELECT
+ir.id,
 ir.amount,
 ir.notes,
 ir.optional,
+ir.group_label,
 (
     i.id,
     i.name,
     i.description,
-    i.diet_violations
+    i.diet_violations,
+    i.updated_at
 ) as "ingredient!: IngredientModel"
 FROM ingredients_recipes AS ir
 JOIN ingredients AS i
     ON i.id = ir.ingredient_id
 WHERE ir.recipe_id = $1
+ORDER BY ir.position
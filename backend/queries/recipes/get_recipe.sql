@@ -5,10 +5,22 @@ r.description,
 r.steps,
 r.time,
 r.servings,
+r.forked_from,
+r.visibility,
+r.owner_id,
 r.created_at,
-r.updated_at
+r.updated_at,
+(
+    SELECT AVG(rr.rating)::float8
+    FROM recipe_reviews AS rr
+    WHERE rr.recipe_id = r.id
+) AS rating_average,
+(
+    SELECT COUNT(*)::integer
+    FROM recipe_reviews AS rr
+    WHERE rr.recipe_id = r.id
+) AS "rating_count!"
 FROM recipes AS r
 JOIN ingredients_recipes AS ir ON r.id = ir.recipe_id
 JOIN ingredients AS i ON ir.ingredient_id = i.id
 WHERE r.id = $1
-
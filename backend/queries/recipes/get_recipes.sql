@@ -0,0 +1,2 @@
+SELECT id
+FROM recipes;
@@ -0,0 +1,3 @@
+SELECT DISTINCT recipe_id
+FROM ingredients_recipes
+WHERE ingredient_id = ANY($1);
@@ -0,0 +1,11 @@
+SELECT
+id,
+recipe_id,
+user_id,
+author,
+rating,
+body,
+created_at,
+updated_at
+FROM recipe_reviews
+WHERE id = $1 AND recipe_id = $2;
@@ -0,0 +1,12 @@
+SELECT
+id,
+recipe_id,
+user_id,
+author,
+rating,
+body,
+created_at,
+updated_at
+FROM recipe_reviews
+WHERE recipe_id = $1
+ORDER BY created_at ASC, id ASC;
@@ -0,0 +1,3 @@
+SELECT token, recipe_id, created_at
+FROM recipe_share_tokens
+WHERE token = $1;
@@ -0,0 +1,4 @@
+SELECT token, recipe_id, created_at
+FROM recipe_share_tokens
+WHERE recipe_id = $1
+ORDER BY created_at, token;
@@ -1,4 +1,17 @@
 INSERT INTO ingredients_recipes
-(recipe_id, ingredient_id, amount, notes, optional)
+(recipe_id, id, ingredient_id, amount, notes, optional, group_label, position)
 VALUES
-($1, $2, $3, $4, $5);
+(
+    $1,
+    $2,
+    $3,
+    $4,
+    $5,
+    $6,
+    $7,
+    (
+        SELECT COALESCE(MAX(position) + 1, 0)
+        FROM ingredients_recipes
+        WHERE recipe_id = $1
+    )
+);
@@ -1,5 +1,5 @@
 INSERT INTO recipes
-(id, name, description, steps, time, servings, metadata)
+(id, name, description, steps, time, servings, metadata, forked_from, visibility, owner_id)
 VALUES
-($1, $2, $3, $4, $5, $6, $7)
+($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
 RETURNING id;
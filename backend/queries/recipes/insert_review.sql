@@ -0,0 +1,4 @@
+INSERT INTO recipe_reviews
+(id, recipe_id, user_id, author, rating, body, created_at, updated_at)
+VALUES
+($1, $2, $3, $4, $5, $6, $7, $8);
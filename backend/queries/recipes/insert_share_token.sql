@@ -0,0 +1,4 @@
+INSERT INTO recipe_share_tokens
+(token, recipe_id, created_at)
+VALUES
+($1, $2, $3);
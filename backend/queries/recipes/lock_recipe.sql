@@ -0,0 +1,4 @@
+SELECT id
+FROM recipes
+WHERE id = $1
+FOR UPDATE;
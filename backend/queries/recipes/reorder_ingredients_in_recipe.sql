@@ -0,0 +1,5 @@
+UPDATE ingredients_recipes AS ir
+SET position = (new_order.position - 1)::integer
+FROM UNNEST($2::uuid[]) WITH ORDINALITY AS new_order(id, position)
+WHERE ir.recipe_id = $1
+AND ir.id = new_order.id
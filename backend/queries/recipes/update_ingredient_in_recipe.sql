@@ -1,4 +1,8 @@
 UPDATE ingredients_recipes
-SET amount = $3
-WHERE ingredient_id = $2
+SET
+amount = COALESCE($3::json, amount),
+optional = COALESCE($4, optional),
+notes = CASE WHEN $5::boolean THEN $6::text ELSE notes END,
+group_label = CASE WHEN $7::boolean THEN $8::text ELSE group_label END
+WHERE id = $2
 AND recipe_id = $1
@@ -0,0 +1,16 @@
+WITH changes AS (
+    SELECT
+    id,
+    COALESCE($2, rating) AS rating,
+    CASE WHEN $3::boolean THEN $4::text ELSE body END AS body
+    FROM recipe_reviews
+    WHERE id = $1
+)
+UPDATE recipe_reviews AS rr
+SET
+rating = changes.rating,
+body = changes.body,
+updated_at = timezone('utc', now())
+FROM changes
+WHERE rr.id = changes.id
+AND (rr.rating IS DISTINCT FROM changes.rating OR rr.body IS DISTINCT FROM changes.body);
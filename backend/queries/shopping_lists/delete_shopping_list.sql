@@ -0,0 +1,2 @@
+DELETE FROM shopping_lists
+WHERE id = $1;
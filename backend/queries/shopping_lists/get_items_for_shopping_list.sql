@@ -0,0 +1,16 @@
+SELECT
+sli.id,
+sli.amount,
+sli.checked,
+(
+    i.id,
+    i.name,
+    i.description,
+    i.diet_violations,
+    i.updated_at
+) as "ingredient!: IngredientModel"
+FROM shopping_list_items AS sli
+JOIN ingredients AS i
+    ON i.id = sli.ingredient_id
+WHERE sli.shopping_list_id = $1
+ORDER BY sli.position
@@ -0,0 +1,8 @@
+SELECT
+sl.id,
+sl.name,
+sl.recipe_ids,
+sl.created_at,
+sl.updated_at
+FROM shopping_lists AS sl
+WHERE sl.id = $1
@@ -0,0 +1,4 @@
+INSERT INTO shopping_lists
+(id, name, recipe_ids, created_at, updated_at)
+VALUES
+($1, $2, $3, $4, $5);
@@ -0,0 +1,4 @@
+INSERT INTO shopping_list_items
+(id, shopping_list_id, ingredient_id, amount, checked, position)
+VALUES
+($1, $2, $3, $4, $5, $6);
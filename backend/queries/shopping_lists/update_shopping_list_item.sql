@@ -0,0 +1,3 @@
+UPDATE shopping_list_items
+SET checked = $3
+WHERE shopping_list_id = $1 AND id = $2;
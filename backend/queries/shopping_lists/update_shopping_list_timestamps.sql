@@ -0,0 +1,3 @@
+UPDATE shopping_lists
+SET updated_at = timezone('utc', now())
+WHERE shopping_lists.id = $1;
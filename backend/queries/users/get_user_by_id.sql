@@ -0,0 +1,8 @@
+SELECT
+id,
+username,
+email,
+password_hash,
+created_at
+FROM users
+WHERE id = $1;
@@ -0,0 +1,4 @@
+INSERT INTO users
+(id, username, email, password_hash, created_at)
+VALUES
+($1, $2, $3, $4, $5);
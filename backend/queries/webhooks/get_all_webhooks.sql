@@ -0,0 +1,9 @@
+SELECT
+id,
+url,
+secret,
+event_types,
+owner_id,
+created_at
+FROM webhooks
+ORDER BY created_at;
@@ -0,0 +1,13 @@
+SELECT
+id,
+webhook_id,
+event_type,
+payload,
+attempt,
+status_code,
+error,
+succeeded,
+created_at
+FROM webhook_deliveries
+WHERE webhook_id = $1
+ORDER BY created_at, attempt;
@@ -0,0 +1,4 @@
+INSERT INTO webhooks
+(id, url, secret, event_types, owner_id, created_at)
+VALUES
+($1, $2, $3, $4, $5, $6);
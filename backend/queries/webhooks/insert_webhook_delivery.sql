@@ -0,0 +1,4 @@
+INSERT INTO webhook_deliveries
+(id, webhook_id, event_type, payload, attempt, status_code, error, succeeded, created_at)
+VALUES
+($1, $2, $3, $4, $5, $6, $7, $8, $9);
@@ -0,0 +1,127 @@
+use std::convert::Infallible;
+
+use crate::domain::entities::precondition::Precondition;
+use axum::{
+    async_trait,
+    extract::FromRequestParts,
+    http::{header, request::Parts, HeaderName},
+};
+
+/// `ETag` header of a resource with the given tag, as in
+/// [`entity_tag`](crate::domain::entities::precondition::entity_tag)
+pub fn etag(tag: &str) -> String {
+    format!("\"{tag}\"")
+}
+
+/// A single entry of an `If-Match` or `If-None-Match` header
+#[derive(Debug)]
+enum EntityTag {
+    Wildcard,
+    Strong(String),
+    Weak(String),
+    /// Not a tag at all, so it can never match
+    Unknown,
+}
+
+impl EntityTag {
+    fn parse(value: &str) -> Self {
+        let value = value.trim();
+        if value == "*" {
+            return Self::Wildcard;
+        }
+
+        let (weak, quoted) = match value.strip_prefix("W/") {
+            Some(rest) => (true, rest),
+            None => (false, value),
+        };
+
+        let Some(tag) = quoted
+            .strip_prefix('"')
+            .and_then(|rest| rest.strip_suffix('"'))
+            .map(str::to_string)
+        else {
+            return Self::Unknown;
+        };
+
+        match weak {
+            true => Self::Weak(tag),
+            false => Self::Strong(tag),
+        }
+    }
+}
+
+fn entity_tags(parts: &Parts, name: HeaderName) -> Option<Vec<EntityTag>> {
+    if !parts.headers.contains_key(&name) {
+        return None;
+    }
+
+    let tags = parts
+        .headers
+        .get_all(name)
+        .iter()
+        .flat_map(|value| value.to_str().unwrap_or_default().split(','))
+        .filter(|value| !value.trim().is_empty())
+        .map(EntityTag::parse)
+        .collect();
+
+    Some(tags)
+}
+
+/// Extracts the `If-Match` header as a precondition for a write.
+/// A missing header or `*` lets the write through, otherwise the resource
+/// must still be at one of the listed versions
+#[derive(Debug)]
+pub struct IfMatch(pub Precondition);
+
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for IfMatch {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+        let Some(tags) = entity_tags(parts, header::IF_MATCH) else {
+            return Ok(Self(Precondition::Any));
+        };
+
+        if tags.iter().any(|tag| matches!(tag, EntityTag::Wildcard)) {
+            return Ok(Self(Precondition::Any));
+        }
+
+        // If-Match uses the strong comparison, so weak tags never match
+        let tags = tags
+            .into_iter()
+            .filter_map(|tag| match tag {
+                EntityTag::Strong(tag) => Some(tag),
+                _ => None,
+            })
+            .collect();
+
+        Ok(Self(Precondition::Tags(tags)))
+    }
+}
+
+/// Extracts the `If-None-Match` header of a read
+#[derive(Debug)]
+pub struct IfNoneMatch(Vec<EntityTag>);
+
+impl IfNoneMatch {
+    /// Whether the client already has the version of the resource with the given tag
+    pub fn matches(&self, tag: &str) -> bool {
+        // If-None-Match uses the weak comparison
+        self.0.iter().any(|expected| match expected {
+            EntityTag::Wildcard => true,
+            EntityTag::Strong(expected) | EntityTag::Weak(expected) => expected == tag,
+            EntityTag::Unknown => false,
+        })
+    }
+}
+
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for IfNoneMatch {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+        Ok(Self(
+            entity_tags(parts, header::IF_NONE_MATCH).unwrap_or_default(),
+        ))
+    }
+}
@@ -1,3 +1,9 @@
+mod conditional;
 mod json;
+mod upload;
+mod user;
 
+pub use conditional::*;
 pub use json::*;
+pub use upload::*;
+pub use user::*;
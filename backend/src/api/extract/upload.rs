@@ -0,0 +1,82 @@
+use axum::{
+    async_trait,
+    extract::{
+        multipart::{MultipartError, MultipartRejection},
+        FromRequest, Multipart, Request,
+    },
+};
+use reqwest::StatusCode;
+
+use crate::api::errors::api::ApiError;
+
+/// Extracts the first field of a `multipart/form-data` request as a text file
+#[derive(Debug)]
+pub struct UploadedFile {
+    pub file_name: Option<String>,
+    pub content: String,
+}
+
+/// Extracts every field of a `multipart/form-data` request as a text file
+pub struct UploadedFiles(pub Vec<UploadedFile>);
+
+#[async_trait]
+impl<S: Send + Sync> FromRequest<S> for UploadedFiles {
+    type Rejection = ApiError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let mut multipart = Multipart::from_request(req, state).await?;
+        let mut files = vec![];
+
+        while let Some(field) = multipart.next_field().await? {
+            let file_name = field.file_name().map(ToString::to_string);
+            let content = field.text().await?;
+
+            files.push(UploadedFile { file_name, content });
+        }
+
+        if files.is_empty() {
+            return Err(no_files_uploaded());
+        }
+
+        Ok(Self(files))
+    }
+}
+
+#[async_trait]
+impl<S: Send + Sync> FromRequest<S> for UploadedFile {
+    type Rejection = ApiError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let UploadedFiles(files) = UploadedFiles::from_request(req, state).await?;
+
+        files.into_iter().next().ok_or_else(no_files_uploaded)
+    }
+}
+
+fn no_files_uploaded() -> ApiError {
+    ApiError {
+        kind: "UPLOAD_EMPTY",
+        status: StatusCode::BAD_REQUEST,
+        message: "No files were uploaded".to_string(),
+    }
+}
+
+impl From<MultipartRejection> for ApiError {
+    fn from(rejection: MultipartRejection) -> Self {
+        Self {
+            kind: "MULTIPART_PARSE",
+            status: rejection.status(),
+            message: rejection.body_text(),
+        }
+    }
+}
+
+impl From<MultipartError> for ApiError {
+    fn from(error: MultipartError) -> Self {
+        Self {
+            kind: "MULTIPART_PARSE",
+            status: error.status(),
+            message: error.body_text(),
+        }
+    }
+}
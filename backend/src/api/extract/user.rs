@@ -0,0 +1,112 @@
+use axum::{
+    async_trait,
+    extract::FromRequestParts,
+    http::{header, request::Parts},
+};
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    api::{errors::api::ApiError, AppState},
+    domain::{
+        entities::user::{Session, User},
+        repositories::{session::errors::GetSessionError, user::errors::GetUserError},
+    },
+};
+
+/// The session the request was made in, from the token in the `Authorization: Bearer` header
+#[derive(Debug)]
+pub struct CurrentSession(pub Session);
+
+/// The user who made the request, from the session they are logged in with
+#[derive(Debug)]
+pub struct CurrentUser(pub User);
+
+fn unauthorized(message: &str) -> ApiError {
+    ApiError {
+        kind: "UNAUTHORIZED",
+        status: StatusCode::UNAUTHORIZED,
+        message: message.to_string(),
+    }
+}
+
+fn unknown(error: impl ToString) -> ApiError {
+    ApiError {
+        kind: "UNKNOWN",
+        status: StatusCode::INTERNAL_SERVER_ERROR,
+        message: error.to_string(),
+    }
+}
+
+#[async_trait]
+impl FromRequestParts<AppState> for CurrentSession {
+    type Rejection = ApiError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let token = parts
+            .headers
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .map(str::trim)
+            .ok_or_else(|| unauthorized("Log in to do this"))?;
+
+        match state.session_repository.get(&Session::hash(token)).await {
+            Ok(session) => Ok(Self(session)),
+            Err(GetSessionError::NotFound) => {
+                Err(unauthorized("The session has expired, log in again"))
+            }
+            Err(e) => Err(unknown(e)),
+        }
+    }
+}
+
+#[async_trait]
+impl FromRequestParts<AppState> for CurrentUser {
+    type Rejection = ApiError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let CurrentSession(session) = CurrentSession::from_request_parts(parts, state).await?;
+
+        match state.user_repository.get_by_id(&session.user_id).await {
+            Ok(user) => Ok(Self(user)),
+            Err(GetUserError::NotFound) => Err(unauthorized("The account no longer exists")),
+            Err(e) => Err(unknown(e)),
+        }
+    }
+}
+
+/// The user who made the request if they sent a session token, for routes that visitors can use too.
+/// A token that is not valid is still rejected, rather than treating the request as anonymous.
+#[derive(Debug)]
+pub struct MaybeUser(pub Option<User>);
+
+impl MaybeUser {
+    pub fn id(&self) -> Option<&Uuid> {
+        self.0.as_ref().map(|user| &user.id)
+    }
+}
+
+#[async_trait]
+impl FromRequestParts<AppState> for MaybeUser {
+    type Rejection = ApiError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        if !parts.headers.contains_key(header::AUTHORIZATION) {
+            return Ok(Self(None));
+        }
+
+        let CurrentUser(user) = CurrentUser::from_request_parts(parts, state).await?;
+
+        Ok(Self(Some(user)))
+    }
+}
@@ -4,15 +4,69 @@ mod routes;
 
 use std::sync::Arc;
 
+use crate::domain::commands::cooking_sessions::reschedule_timers::reschedule_cooking_timers;
 use crate::domain::repositories::{
+    collection::{
+        in_memory::InMemoryCollectionRepository, postgres::PostgresCollectionRepository,
+        CollectionRepository, CollectionRepositoryService,
+    },
+    cooking_session::{
+        in_memory::InMemoryCookingSessionRepository, postgres::PostgresCookingSessionRepository,
+        CookingSessionRepository, CookingSessionRepositoryService,
+    },
+    favorite::{
+        in_memory::InMemoryFavoriteRepository, postgres::PostgresFavoriteRepository,
+        FavoriteRepository, FavoriteRepositoryService,
+    },
     ingredients::{
         in_memory::InMemoryIngredientRepository, postgres::PostgresIngredientRepository,
         IngredientRepository, IngredientRepositoryService,
     },
+    meal_plan::{
+        in_memory::InMemoryMealPlanRepository, postgres::PostgresMealPlanRepository,
+        MealPlanRepository, MealPlanRepositoryService,
+    },
+    outbox::{
+        in_memory::InMemoryOutboxRepository, postgres::PostgresOutboxRepository, OutboxRepository,
+        OutboxRepositoryService,
+    },
+    pantry::{
+        in_memory::InMemoryPantryRepository, postgres::PostgresPantryRepository, PantryRepository,
+        PantryRepositoryService,
+    },
     recipe::{
         in_memory::InMemoryRecipeRepository, postgres::PostgresRecipeRepository, RecipeRepository,
         RecipeRepositoryService,
     },
+    recipe_note::{
+        in_memory::InMemoryRecipeNoteRepository, postgres::PostgresRecipeNoteRepository,
+        RecipeNoteRepository, RecipeNoteRepositoryService,
+    },
+    session::{
+        in_memory::InMemorySessionRepository, redis::RedisSessionRepository,
+        SessionRepositoryService,
+    },
+    shopping_list::{
+        in_memory::InMemoryShoppingListRepository, postgres::PostgresShoppingListRepository,
+        ShoppingListRepository, ShoppingListRepositoryService,
+    },
+    unit_of_work::{
+        in_memory::InMemoryUnitOfWork, postgres::PostgresUnitOfWork, UnitOfWork, UnitOfWorkService,
+    },
+    user::{
+        in_memory::InMemoryUserRepository, postgres::PostgresUserRepository, UserRepository,
+        UserRepositoryService,
+    },
+    webhook::{
+        in_memory::InMemoryWebhookRepository, postgres::PostgresWebhookRepository,
+        WebhookRepository, WebhookRepositoryService,
+    },
+};
+use crate::domain::services::{
+    cooking_sessions::CookingSessionHub,
+    events::EventBus,
+    outbox::OutboxRelay,
+    webhooks::{WebhookDispatcher, WebhookTargets},
 };
 use axum::{
     routing::{delete, get, post, put},
@@ -22,7 +76,10 @@ use axum_tracing_opentelemetry::middleware::{OtelAxumLayer, OtelInResponseLayer}
 use color_eyre::Result;
 use sqlx::PgPool;
 
-use self::routes::{ingredients::*, recipes::*};
+use self::routes::{
+    collections::*, cooking_sessions::*, events::*, favorites::*, ingredients::*, meal_plans::*,
+    pantry::*, recipe_notes::*, recipes::*, shopping_lists::*, users::*, webhooks::*,
+};
 
 pub struct App {
     router: Router,
@@ -32,45 +89,228 @@ pub struct App {
 pub struct AppState {
     pub ingredient_repository: IngredientRepositoryService,
     pub recipe_repository: RecipeRepositoryService,
+    pub shopping_list_repository: ShoppingListRepositoryService,
+    pub meal_plan_repository: MealPlanRepositoryService,
+    pub pantry_repository: PantryRepositoryService,
+    pub collection_repository: CollectionRepositoryService,
+    pub favorite_repository: FavoriteRepositoryService,
+    pub recipe_note_repository: RecipeNoteRepositoryService,
+    pub cooking_session_repository: CookingSessionRepositoryService,
+    pub webhook_repository: WebhookRepositoryService,
+    pub webhook_targets: WebhookTargets,
+    pub user_repository: UserRepositoryService,
+    pub session_repository: SessionRepositoryService,
+    pub unit_of_work: UnitOfWorkService,
+    pub cooking_session_hub: CookingSessionHub,
+    pub event_bus: EventBus,
 }
 
 impl App {
     fn get_router() -> Router<AppState> {
         Router::new()
+            .route("/user/signup", post(signup_route))
+            .route("/user/login", post(login_route))
+            .route("/user/logout", post(logout_route))
             .route("/ingredient/create", post(create_ingredient_route))
+            .route("/ingredient/parse", post(parse_ingredients_route))
             .route("/ingredient/:id", put(update_ingredient_route))
             .route("/ingredient/:id", get(get_ingredient_by_id_route))
             .route("/ingredient/:id", delete(delete_ingredient_route))
             .route("/ingredient", get(get_all_ingredients_route))
+            .route("/recipe", get(get_all_recipes_route))
             .route("/recipe/create", post(create_recipe_route))
+            .route(
+                "/recipe/import/jsonld",
+                post(import_recipe_from_jsonld_route),
+            )
+            .route(
+                "/recipe/import/cooklang",
+                post(import_recipe_from_cooklang_route),
+            )
+            .route(
+                "/recipe/import/cooklang/bulk",
+                post(import_recipes_from_cooklang_route),
+            )
+            .route("/recipe/import/text", post(preview_recipes_from_text_route))
+            .route("/recipe/export/epub", post(export_cookbook_route))
             .route("/recipe/:id", get(get_recipe_by_id_route))
             .route("/recipe/:id", delete(delete_recipe_route))
             .route("/recipe/:id", put(update_recipe_route))
+            .route("/recipe/:id/batch", post(batch_edit_recipe_route))
+            .route("/recipe/:id/export", get(export_recipe_route))
+            .route(
+                "/recipe/:id/collections",
+                get(get_collections_of_recipe_route),
+            )
+            .route("/recipe/:id/reviews", get(get_reviews_of_recipe_route))
+            .route("/recipe/:id/fork", post(fork_recipe_route))
+            .route("/recipe/:id/forks", get(get_forks_of_recipe_route))
+            .route("/recipe/:id/ancestry", get(get_ancestry_of_recipe_route))
+            .route("/recipe/:id/share", post(create_share_token_route))
+            .route("/recipe/:id/shares", get(get_share_tokens_of_recipe_route))
+            .route(
+                "/recipe/:recipe_id/share/:token",
+                delete(revoke_share_token_route),
+            )
+            .route("/recipe/:id/favorite", put(add_favorite_route))
+            .route("/recipe/:id/favorite", delete(remove_favorite_route))
+            .route("/recipe/:id/notes", get(get_recipe_note_route))
+            .route("/recipe/:id/notes", put(save_recipe_note_route))
+            .route("/recipe/:id/notes", delete(delete_recipe_note_route))
+            .route("/recipe/:id/review", post(create_review_route))
+            .route(
+                "/recipe/:recipe_id/review/:review_id",
+                put(update_review_route),
+            )
             .route(
                 "/recipe/:id/ingredient",
                 post(add_ingredient_to_recipe_route),
             )
             .route(
-                "/recipe/:recipe_id/ingredient/:ingredient_id",
+                "/recipe/:id/ingredient/line",
+                post(add_ingredient_line_to_recipe_route),
+            )
+            .route(
+                "/recipe/:recipe_id/ingredient/:entry_id",
                 delete(delete_ingredient_from_recipe_route),
             )
             .route(
-                "/recipe/:recipe_id/ingredient/:ingredient_id",
+                "/recipe/:recipe_id/ingredient/:entry_id",
                 put(update_ingredient_in_recipe_route),
             )
+            .route("/shopping-list/create", post(create_shopping_list_route))
+            .route("/shopping-list/:id", get(get_shopping_list_by_id_route))
+            .route("/shopping-list/:id", delete(delete_shopping_list_route))
+            .route("/shopping-list/:id/export", get(export_shopping_list_route))
+            .route(
+                "/shopping-list/:shopping_list_id/item/:item_id",
+                put(check_shopping_list_item_route),
+            )
+            .route("/meal-plan", get(get_meal_plan_route))
+            .route("/meal-plan/create", post(create_meal_plan_entry_route))
+            .route("/meal-plan/summary", get(get_meal_plan_summary_route))
+            .route("/meal-plan/feed/create", post(create_calendar_feed_route))
+            .route("/meal-plan/feed/:token", delete(delete_calendar_feed_route))
+            .route(
+                "/meal-plan/feed/:token/calendar.ics",
+                get(get_meal_plan_calendar_route),
+            )
+            .route("/meal-plan/:id", get(get_meal_plan_entry_by_id_route))
+            .route("/meal-plan/:id", put(update_meal_plan_entry_route))
+            .route("/meal-plan/:id", delete(delete_meal_plan_entry_route))
+            .route("/pantry", get(get_pantry_route))
+            .route("/pantry/create", post(add_pantry_item_route))
+            .route("/pantry/cookable", get(get_cookable_recipes_route))
+            .route("/pantry/:id", put(update_pantry_item_route))
+            .route("/pantry/:id", delete(remove_pantry_item_route))
+            .route("/favorite", get(get_favorite_recipes_route))
+            .route("/collection", get(get_all_collections_route))
+            .route("/collection/create", post(create_collection_route))
+            .route("/collection/:id", get(get_collection_by_id_route))
+            .route("/collection/:id", put(update_collection_route))
+            .route("/collection/:id", delete(delete_collection_route))
+            .route(
+                "/collection/:id/recipe",
+                post(add_recipe_to_collection_route),
+            )
+            .route(
+                "/collection/:collection_id/recipe/:recipe_id",
+                delete(remove_recipe_from_collection_route),
+            )
+            .route("/cooking-session", post(create_cooking_session_route))
+            .route("/cooking-session/:id", get(get_cooking_session_by_id_route))
+            .route("/cooking-session/:id", delete(delete_cooking_session_route))
+            .route(
+                "/cooking-session/:id/action",
+                post(update_cooking_session_route),
+            )
+            .route("/cooking-session/:id/ws", get(cooking_session_socket_route))
+            .route("/events", get(get_events_route))
+            .route("/webhook", get(get_all_webhooks_route))
+            .route("/webhook/create", post(create_webhook_route))
+            .route("/webhook/:id", delete(delete_webhook_route))
+            .route("/webhook/:id/deliveries", get(get_webhook_deliveries_route))
             .layer(OtelInResponseLayer)
             .layer(OtelAxumLayer::default())
     }
 
-    pub fn new<I: IngredientRepository + 'static, R: RecipeRepository + 'static>(
+    #[allow(clippy::too_many_arguments)]
+    pub fn new<
+        I: IngredientRepository + 'static,
+        R: RecipeRepository + 'static,
+        S: ShoppingListRepository + 'static,
+        M: MealPlanRepository + 'static,
+        P: PantryRepository + 'static,
+        C: CollectionRepository + 'static,
+        F: FavoriteRepository + 'static,
+        N: RecipeNoteRepository + 'static,
+        K: CookingSessionRepository + 'static,
+        W: WebhookRepository + 'static,
+        O: OutboxRepository + 'static,
+        A: UserRepository + 'static,
+        U: UnitOfWork + 'static,
+    >(
         irs: I,
         rrs: R,
+        srs: S,
+        mrs: M,
+        prs: P,
+        crs: C,
+        frs: F,
+        nrs: N,
+        krs: K,
+        wrs: W,
+        ors: O,
+        ars: A,
+        session_repository: SessionRepositoryService,
+        uow: U,
+        webhook_targets: WebhookTargets,
     ) -> Result<Self> {
         let ingredient_repository: IngredientRepositoryService = Arc::new(Box::new(irs));
         let recipe_repository: RecipeRepositoryService = Arc::new(Box::new(rrs));
+        let shopping_list_repository: ShoppingListRepositoryService = Arc::new(Box::new(srs));
+        let meal_plan_repository: MealPlanRepositoryService = Arc::new(Box::new(mrs));
+        let pantry_repository: PantryRepositoryService = Arc::new(Box::new(prs));
+        let collection_repository: CollectionRepositoryService = Arc::new(Box::new(crs));
+        let favorite_repository: FavoriteRepositoryService = Arc::new(Box::new(frs));
+        let recipe_note_repository: RecipeNoteRepositoryService = Arc::new(Box::new(nrs));
+        let cooking_session_repository: CookingSessionRepositoryService = Arc::new(Box::new(krs));
+        let webhook_repository: WebhookRepositoryService = Arc::new(Box::new(wrs));
+        let outbox_repository: OutboxRepositoryService = Arc::new(Box::new(ors));
+        let user_repository: UserRepositoryService = Arc::new(Box::new(ars));
+        let unit_of_work: UnitOfWorkService = Arc::new(Box::new(uow));
+        let event_bus = EventBus::new();
+        OutboxRelay::new(outbox_repository).start(&event_bus);
+        WebhookDispatcher::new(webhook_repository.clone(), recipe_repository.clone())
+            .with_targets(webhook_targets)
+            .start(&event_bus);
+        let cooking_session_hub = CookingSessionHub::new();
+        {
+            let session_repo = cooking_session_repository.clone();
+            let hub = cooking_session_hub.clone();
+            tokio::spawn(async move {
+                if let Err(e) = reschedule_cooking_timers(session_repo, hub).await {
+                    tracing::error!("Could not reschedule the cooking timers: {e}");
+                }
+            });
+        }
         let state = AppState {
             ingredient_repository,
             recipe_repository,
+            shopping_list_repository,
+            meal_plan_repository,
+            pantry_repository,
+            collection_repository,
+            favorite_repository,
+            recipe_note_repository,
+            cooking_session_repository,
+            webhook_repository,
+            webhook_targets,
+            user_repository,
+            session_repository,
+            unit_of_work,
+            cooking_session_hub,
+            event_bus,
         };
         let router = Self::get_router().with_state(state);
 
@@ -88,6 +328,8 @@ impl App {
 #[derive(Default)]
 pub struct AppBuilder {
     postgres_db: Option<PgPool>,
+    redis: Option<redis::Client>,
+    webhook_targets: WebhookTargets,
 }
 
 impl AppBuilder {
@@ -97,16 +339,65 @@ impl AppBuilder {
         self
     }
 
+    /// Keeps sessions in Redis instead of in memory, where they are lost when the app restarts
+    pub fn with_redis(mut self, client: redis::Client) -> Self {
+        self.redis = Some(client);
+
+        self
+    }
+
+    /// Lets webhooks point to loopback and private addresses, which they can't by default
+    pub fn allow_private_webhook_targets(mut self) -> Self {
+        self.webhook_targets = WebhookTargets::Any;
+
+        self
+    }
+
     pub fn build(self) -> Result<App> {
+        let sessions = match self.redis {
+            Some(client) => RedisSessionRepository::new(client).service(),
+            None => InMemorySessionRepository::new().service(),
+        };
+
         if let Some(postgres_db) = self.postgres_db {
             App::new(
                 PostgresIngredientRepository::new(postgres_db.clone()),
-                PostgresRecipeRepository::new(postgres_db),
+                PostgresRecipeRepository::new(postgres_db.clone()),
+                PostgresShoppingListRepository::new(postgres_db.clone()),
+                PostgresMealPlanRepository::new(postgres_db.clone()),
+                PostgresPantryRepository::new(postgres_db.clone()),
+                PostgresCollectionRepository::new(postgres_db.clone()),
+                PostgresFavoriteRepository::new(postgres_db.clone()),
+                PostgresRecipeNoteRepository::new(postgres_db.clone()),
+                PostgresCookingSessionRepository::new(postgres_db.clone()),
+                PostgresWebhookRepository::new(postgres_db.clone()),
+                PostgresOutboxRepository::new(postgres_db.clone()),
+                PostgresUserRepository::new(postgres_db.clone()),
+                sessions,
+                PostgresUnitOfWork::new(postgres_db),
+                self.webhook_targets,
             )
         } else {
+            let outbox = InMemoryOutboxRepository::new();
+            let ingredients = InMemoryIngredientRepository::new().with_outbox(outbox.clone());
+            let recipes = InMemoryRecipeRepository::new().with_outbox(outbox.clone());
+
             App::new(
-                InMemoryIngredientRepository::new(),
-                InMemoryRecipeRepository::new(),
+                ingredients.clone(),
+                recipes.clone(),
+                InMemoryShoppingListRepository::new(),
+                InMemoryMealPlanRepository::new(),
+                InMemoryPantryRepository::new(),
+                InMemoryCollectionRepository::new(),
+                InMemoryFavoriteRepository::new(),
+                InMemoryRecipeNoteRepository::new(),
+                InMemoryCookingSessionRepository::new(),
+                InMemoryWebhookRepository::new(),
+                outbox,
+                InMemoryUserRepository::new(),
+                sessions,
+                InMemoryUnitOfWork::new(ingredients, recipes),
+                self.webhook_targets,
             )
         }
     }
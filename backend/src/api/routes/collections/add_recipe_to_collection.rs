@@ -0,0 +1,68 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use common::{AddRecipeToCollectionDTO, CollectionDTO};
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    api::{
+        errors::MakeError,
+        extract::{Json, MaybeUser},
+        AppState,
+    },
+    domain::commands::collections::add_recipe::{
+        add_recipe_to_collection, AddRecipeToCollectionError,
+    },
+};
+
+impl MakeError<String> for AddRecipeToCollectionError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::NotOwner(_) => StatusCode::FORBIDDEN,
+            Self::RecipeNotFound(_) => StatusCode::BAD_REQUEST,
+            Self::AlreadyInCollection(_) => StatusCode::CONFLICT,
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for AddRecipeToCollectionError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument(
+    "[ROUTE] Adding a recipe to a collection",
+    skip(collection_repository, recipe_repository, user)
+)]
+pub async fn add_recipe_to_collection_route(
+    State(AppState {
+        collection_repository,
+        recipe_repository,
+        ..
+    }): State<AppState>,
+    user: MaybeUser,
+    Path(collection_id): Path<Uuid>,
+    Json(body): Json<AddRecipeToCollectionDTO>,
+) -> Result<Json<CollectionDTO>, AddRecipeToCollectionError> {
+    let result = add_recipe_to_collection(
+        collection_repository,
+        recipe_repository,
+        user.id(),
+        &collection_id,
+        &body.into(),
+    )
+    .await?;
+
+    Ok(Json(result.into()))
+}
@@ -0,0 +1,61 @@
+use axum::{extract::State, response::IntoResponse};
+use common::{CollectionDTO, CreateCollectionDTO};
+use reqwest::StatusCode;
+
+use crate::{
+    api::{
+        errors::MakeError,
+        extract::{Json, MaybeUser},
+        AppState,
+    },
+    domain::commands::collections::create::{create_collection, CreateCollectionError},
+};
+
+impl MakeError<String> for CreateCollectionError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::RecipeNotFound(_)
+            | Self::DuplicateRecipe(_)
+            | Self::CoverNotInCollection(_)
+            | Self::ValidationError(_) => StatusCode::BAD_REQUEST,
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for CreateCollectionError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument(
+    "[ROUTE] Creating a collection",
+    skip(collection_repository, recipe_repository, user)
+)]
+pub async fn create_collection_route(
+    State(AppState {
+        collection_repository,
+        recipe_repository,
+        ..
+    }): State<AppState>,
+    user: MaybeUser,
+    Json(body): Json<CreateCollectionDTO>,
+) -> Result<impl IntoResponse, CreateCollectionError> {
+    let result: CollectionDTO = create_collection(
+        collection_repository,
+        recipe_repository,
+        user.id(),
+        &body.into(),
+    )
+    .await?
+    .into();
+
+    Ok((StatusCode::CREATED, Json(result)))
+}
@@ -0,0 +1,47 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    api::{errors::MakeError, extract::MaybeUser, AppState},
+    domain::commands::collections::delete::{delete_collection, DeleteCollectionError},
+};
+
+impl MakeError<String> for DeleteCollectionError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::NotOwner(_) => StatusCode::FORBIDDEN,
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for DeleteCollectionError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument("[ROUTE] Deleting a collection", skip(collection_repository, user))]
+pub async fn delete_collection_route(
+    State(AppState {
+        collection_repository,
+        ..
+    }): State<AppState>,
+    user: MaybeUser,
+    Path(collection_id): Path<Uuid>,
+) -> Result<(), DeleteCollectionError> {
+    delete_collection(collection_repository, user.id(), &collection_id).await?;
+
+    Ok(())
+}
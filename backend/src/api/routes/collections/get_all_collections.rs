@@ -0,0 +1,43 @@
+use axum::{extract::State, response::IntoResponse};
+use common::CollectionDTO;
+use reqwest::StatusCode;
+
+use crate::{
+    api::{
+        errors::MakeError,
+        extract::{Json, MaybeUser},
+        AppState,
+    },
+    domain::queries::collections::get_all::{get_all_collections, GetAllCollectionsError},
+};
+
+impl MakeError<String> for GetAllCollectionsError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        StatusCode::INTERNAL_SERVER_ERROR
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for GetAllCollectionsError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument("[ROUTE] Getting all collections", skip(collection_repository, user))]
+pub async fn get_all_collections_route(
+    State(AppState {
+        collection_repository,
+        ..
+    }): State<AppState>,
+    user: MaybeUser,
+) -> Result<Json<Vec<CollectionDTO>>, GetAllCollectionsError> {
+    let result = get_all_collections(collection_repository, user.id()).await?;
+
+    Ok(Json(result.into_iter().map(|c| c.into()).collect()))
+}
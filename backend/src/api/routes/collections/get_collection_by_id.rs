@@ -0,0 +1,51 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use common::CollectionDTO;
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    api::{
+        errors::MakeError,
+        extract::{Json, MaybeUser},
+        AppState,
+    },
+    domain::queries::collections::get_by_id::{get_collection_by_id, GetCollectionByIdError},
+};
+
+impl MakeError<String> for GetCollectionByIdError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for GetCollectionByIdError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument("[ROUTE] Getting a collection", skip(collection_repository, user))]
+pub async fn get_collection_by_id_route(
+    State(AppState {
+        collection_repository,
+        ..
+    }): State<AppState>,
+    user: MaybeUser,
+    Path(collection_id): Path<Uuid>,
+) -> Result<Json<CollectionDTO>, GetCollectionByIdError> {
+    let result = get_collection_by_id(collection_repository, user.id(), &collection_id).await?;
+
+    Ok(Json(result.into()))
+}
@@ -0,0 +1,63 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use common::CollectionDTO;
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    api::{
+        errors::MakeError,
+        extract::{Json, MaybeUser},
+        AppState,
+    },
+    domain::queries::collections::get_by_recipe::{
+        get_collections_of_recipe, GetCollectionsOfRecipeError,
+    },
+};
+
+impl MakeError<String> for GetCollectionsOfRecipeError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::RecipeNotFound(_) => StatusCode::NOT_FOUND,
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for GetCollectionsOfRecipeError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument(
+    "[ROUTE] Getting the collections a recipe belongs to",
+    skip(collection_repository, recipe_repository, user)
+)]
+pub async fn get_collections_of_recipe_route(
+    State(AppState {
+        collection_repository,
+        recipe_repository,
+        ..
+    }): State<AppState>,
+    user: MaybeUser,
+    Path(recipe_id): Path<Uuid>,
+) -> Result<Json<Vec<CollectionDTO>>, GetCollectionsOfRecipeError> {
+    let result = get_collections_of_recipe(
+        collection_repository,
+        recipe_repository,
+        user.id(),
+        &recipe_id,
+    )
+    .await?;
+
+    Ok(Json(result.into_iter().map(|c| c.into()).collect()))
+}
@@ -0,0 +1,17 @@
+mod add_recipe_to_collection;
+mod create_collection;
+mod delete_collection;
+mod get_all_collections;
+mod get_collection_by_id;
+mod get_collections_of_recipe;
+mod remove_recipe_from_collection;
+mod update_collection;
+
+pub use add_recipe_to_collection::*;
+pub use create_collection::*;
+pub use delete_collection::*;
+pub use get_all_collections::*;
+pub use get_collection_by_id::*;
+pub use get_collections_of_recipe::*;
+pub use remove_recipe_from_collection::*;
+pub use update_collection::*;
@@ -0,0 +1,59 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use common::CollectionDTO;
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    api::{
+        errors::MakeError,
+        extract::{Json, MaybeUser},
+        AppState,
+    },
+    domain::commands::collections::remove_recipe::{
+        remove_recipe_from_collection, RemoveRecipeFromCollectionError,
+    },
+};
+
+impl MakeError<String> for RemoveRecipeFromCollectionError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) | Self::RecipeNotInCollection(_) => StatusCode::NOT_FOUND,
+            Self::NotOwner(_) => StatusCode::FORBIDDEN,
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for RemoveRecipeFromCollectionError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument(
+    "[ROUTE] Removing a recipe from a collection",
+    skip(collection_repository, user)
+)]
+pub async fn remove_recipe_from_collection_route(
+    State(AppState {
+        collection_repository,
+        ..
+    }): State<AppState>,
+    user: MaybeUser,
+    Path((collection_id, recipe_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<CollectionDTO>, RemoveRecipeFromCollectionError> {
+    let result =
+        remove_recipe_from_collection(collection_repository, user.id(), &collection_id, &recipe_id)
+            .await?;
+
+    Ok(Json(result.into()))
+}
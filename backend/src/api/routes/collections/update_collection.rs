@@ -0,0 +1,60 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use common::{CollectionDTO, UpdateCollectionDTO};
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    api::{
+        errors::MakeError,
+        extract::{Json, MaybeUser},
+        AppState,
+    },
+    domain::commands::collections::update::{update_collection, UpdateCollectionError},
+};
+
+impl MakeError<String> for UpdateCollectionError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::NotOwner(_) => StatusCode::FORBIDDEN,
+            Self::CoverNotInCollection(_) | Self::ValidationError(_) => StatusCode::BAD_REQUEST,
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for UpdateCollectionError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument("[ROUTE] Updating a collection", skip(collection_repository, user))]
+pub async fn update_collection_route(
+    State(AppState {
+        collection_repository,
+        ..
+    }): State<AppState>,
+    user: MaybeUser,
+    Path(collection_id): Path<Uuid>,
+    Json(body): Json<UpdateCollectionDTO>,
+) -> Result<Json<CollectionDTO>, UpdateCollectionError> {
+    let result = update_collection(
+        collection_repository,
+        user.id(),
+        &collection_id,
+        body.into(),
+    )
+    .await?;
+
+    Ok(Json(result.into()))
+}
@@ -0,0 +1,141 @@
+use axum::{
+    extract::{
+        ws::{Message, WebSocket},
+        Path, State, WebSocketUpgrade,
+    },
+    response::IntoResponse,
+};
+use common::{CookingActionDTO, CookingSessionDTO};
+use reqwest::StatusCode;
+use serde::Serialize;
+use tokio::sync::broadcast::error::RecvError;
+use uuid::Uuid;
+
+use crate::{
+    api::{
+        errors::{api::ApiError, MakeError},
+        AppState,
+    },
+    domain::{
+        commands::cooking_sessions::update::update_cooking_session,
+        entities::cooking_session::CookingSession,
+        queries::cooking_sessions::get_by_id::{
+            get_cooking_session_by_id, GetCookingSessionByIdError,
+        },
+    },
+};
+
+/// Lets a device take part in the cooking session.
+/// The device gets the current state right away and then every change made by any device,
+/// or by the server when a timer runs out. Messages sent by the device are actions to apply.
+#[tracing::instrument("[ROUTE] Connecting to a cooking session", skip_all)]
+pub async fn cooking_session_socket_route(
+    State(state): State<AppState>,
+    Path(session_id): Path<Uuid>,
+    ws: WebSocketUpgrade,
+) -> Result<impl IntoResponse, GetCookingSessionByIdError> {
+    get_cooking_session_by_id(state.cooking_session_repository.clone(), &session_id).await?;
+
+    Ok(ws.on_upgrade(move |socket| handle_socket(socket, state, session_id)))
+}
+
+async fn handle_socket(mut socket: WebSocket, state: AppState, session_id: Uuid) {
+    let AppState {
+        cooking_session_repository,
+        recipe_repository,
+        cooking_session_hub,
+        ..
+    } = state;
+
+    // Subscribing before reading the state, so that no change gets lost in between
+    let mut updates = cooking_session_hub.subscribe(&session_id);
+
+    let Ok(session) =
+        get_cooking_session_by_id(cooking_session_repository.clone(), &session_id).await
+    else {
+        return;
+    };
+
+    if send_session(&mut socket, session).await.is_err() {
+        return;
+    }
+
+    loop {
+        tokio::select! {
+            update = updates.recv() => {
+                let session = match update {
+                    Ok(session) => session,
+                    Err(RecvError::Lagged(_)) => {
+                        match get_cooking_session_by_id(cooking_session_repository.clone(), &session_id).await {
+                            Ok(session) => session,
+                            Err(_) => break,
+                        }
+                    }
+                    // The session was deleted
+                    Err(RecvError::Closed) => break,
+                };
+
+                if send_session(&mut socket, session).await.is_err() {
+                    return;
+                }
+            }
+            message = socket.recv() => {
+                let text = match message {
+                    Some(Ok(Message::Text(text))) => text,
+                    Some(Ok(Message::Close(_))) | Some(Err(_)) | None => return,
+                    Some(Ok(_)) => continue,
+                };
+
+                let action = match serde_json::from_str::<CookingActionDTO>(&text) {
+                    Ok(action) => action,
+                    Err(e) => {
+                        let error = ApiError {
+                            kind: "JSON_PARSE",
+                            status: StatusCode::BAD_REQUEST,
+                            message: e.to_string(),
+                        };
+                        if send_error(&mut socket, error).await.is_err() {
+                            return;
+                        }
+                        continue;
+                    }
+                };
+
+                // The new state reaches this device through the updates, like every other device
+                if let Err(e) = update_cooking_session(
+                    cooking_session_repository.clone(),
+                    recipe_repository.clone(),
+                    cooking_session_hub.clone(),
+                    &session_id,
+                    action.into(),
+                )
+                .await
+                {
+                    if send_error(&mut socket, e).await.is_err() {
+                        return;
+                    }
+                }
+            }
+        }
+    }
+
+    let _ = socket.send(Message::Close(None)).await;
+}
+
+async fn send_session(socket: &mut WebSocket, session: CookingSession) -> Result<(), axum::Error> {
+    let dto: CookingSessionDTO = session.into();
+    send_json(socket, &dto).await
+}
+
+/// Errors only go to the device that caused them
+async fn send_error(
+    socket: &mut WebSocket,
+    error: impl MakeError<String>,
+) -> Result<(), axum::Error> {
+    send_json(socket, &error.get_error_message()).await
+}
+
+async fn send_json(socket: &mut WebSocket, value: &impl Serialize) -> Result<(), axum::Error> {
+    let text = serde_json::to_string(value).map_err(axum::Error::new)?;
+    socket.send(Message::Text(text)).await
+}
@@ -0,0 +1,57 @@
+use axum::{extract::State, response::IntoResponse};
+use common::{CookingSessionDTO, CreateCookingSessionDTO};
+use reqwest::StatusCode;
+
+use crate::{
+    api::{
+        errors::MakeError,
+        extract::{Json, MaybeUser},
+        AppState,
+    },
+    domain::commands::cooking_sessions::create::{
+        create_cooking_session, CreateCookingSessionError,
+    },
+};
+
+impl MakeError<String> for CreateCookingSessionError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::RecipeNotFound(_) => StatusCode::NOT_FOUND,
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for CreateCookingSessionError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument("[ROUTE] Starting a cooking session", skip_all)]
+pub async fn create_cooking_session_route(
+    State(AppState {
+        cooking_session_repository,
+        recipe_repository,
+        ..
+    }): State<AppState>,
+    user: MaybeUser,
+    Json(body): Json<CreateCookingSessionDTO>,
+) -> Result<impl IntoResponse, CreateCookingSessionError> {
+    let result: CookingSessionDTO = create_cooking_session(
+        cooking_session_repository,
+        recipe_repository,
+        user.id(),
+        &body.recipe_id,
+    )
+    .await?
+    .into();
+
+    Ok((StatusCode::CREATED, Json(result)))
+}
@@ -0,0 +1,48 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    api::{errors::MakeError, AppState},
+    domain::commands::cooking_sessions::delete::{
+        delete_cooking_session, DeleteCookingSessionError,
+    },
+};
+
+impl MakeError<String> for DeleteCookingSessionError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for DeleteCookingSessionError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument("[ROUTE] Deleting a cooking session", skip_all)]
+pub async fn delete_cooking_session_route(
+    State(AppState {
+        cooking_session_repository,
+        cooking_session_hub,
+        ..
+    }): State<AppState>,
+    Path(session_id): Path<Uuid>,
+) -> Result<(), DeleteCookingSessionError> {
+    delete_cooking_session(cooking_session_repository, cooking_session_hub, &session_id).await?;
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use common::CookingSessionDTO;
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    api::{errors::MakeError, extract::Json, AppState},
+    domain::queries::cooking_sessions::get_by_id::{
+        get_cooking_session_by_id, GetCookingSessionByIdError,
+    },
+};
+
+impl MakeError<String> for GetCookingSessionByIdError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for GetCookingSessionByIdError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument("[ROUTE] Getting a cooking session by ID", skip_all)]
+pub async fn get_cooking_session_by_id_route(
+    State(AppState {
+        cooking_session_repository,
+        ..
+    }): State<AppState>,
+    Path(session_id): Path<Uuid>,
+) -> Result<Json<CookingSessionDTO>, GetCookingSessionByIdError> {
+    let result = get_cooking_session_by_id(cooking_session_repository, &session_id).await?;
+
+    Ok(Json(result.into()))
+}
@@ -0,0 +1,11 @@
+mod cooking_session_socket;
+mod create_cooking_session;
+mod delete_cooking_session;
+mod get_cooking_session_by_id;
+mod update_cooking_session;
+
+pub use cooking_session_socket::*;
+pub use create_cooking_session::*;
+pub use delete_cooking_session::*;
+pub use get_cooking_session_by_id::*;
+pub use update_cooking_session::*;
@@ -0,0 +1,59 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use common::{CookingActionDTO, CookingSessionDTO};
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    api::{errors::MakeError, extract::Json, AppState},
+    domain::commands::cooking_sessions::update::{
+        update_cooking_session, UpdateCookingSessionError,
+    },
+};
+
+impl MakeError<String> for UpdateCookingSessionError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::InvalidAction(_) => StatusCode::BAD_REQUEST,
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for UpdateCookingSessionError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument("[ROUTE] Updating a cooking session", skip_all)]
+pub async fn update_cooking_session_route(
+    State(AppState {
+        cooking_session_repository,
+        recipe_repository,
+        cooking_session_hub,
+        ..
+    }): State<AppState>,
+    Path(session_id): Path<Uuid>,
+    Json(body): Json<CookingActionDTO>,
+) -> Result<Json<CookingSessionDTO>, UpdateCookingSessionError> {
+    let result = update_cooking_session(
+        cooking_session_repository,
+        recipe_repository,
+        cooking_session_hub,
+        &session_id,
+        body.into(),
+    )
+    .await?;
+
+    Ok(Json(result.into()))
+}
@@ -0,0 +1,92 @@
+use std::convert::Infallible;
+
+use axum::{
+    extract::{Query, State},
+    response::{
+        sse::{Event, KeepAlive},
+        Sse,
+    },
+};
+use common::DomainEventDTO;
+use futures::{stream, Stream};
+use serde::Deserialize;
+use tokio::sync::broadcast::error::RecvError;
+use uuid::Uuid;
+
+use crate::{
+    api::{extract::MaybeUser, AppState},
+    domain::{entities::event::OutboxEvent, queries::events::is_visible::is_event_visible},
+};
+
+#[derive(Deserialize, Debug)]
+pub struct EventsQuery {
+    /// Only send the events concerning this recipe
+    recipe_id: Option<Uuid>,
+}
+
+/// Streams what happens to recipes and ingredients as server-sent events,
+/// named after the `type` of the event they carry. Every event has an ID of its own, which stays
+/// the same when the event happens to be sent again.
+/// When filtering by a recipe, events about ingredients themselves are left out.
+/// Events about recipes the user can't see are left out too.
+#[tracing::instrument(
+    "[ROUTE] Subscribing to events",
+    skip(event_bus, recipe_repository, user)
+)]
+pub async fn get_events_route(
+    State(AppState {
+        event_bus,
+        recipe_repository,
+        ..
+    }): State<AppState>,
+    user: MaybeUser,
+    Query(EventsQuery { recipe_id }): Query<EventsQuery>,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    let receiver = event_bus.subscribe();
+    let user_id = user.id().copied();
+
+    let events = stream::unfold(receiver, move |mut receiver| {
+        let recipe_repository = recipe_repository.clone();
+        async move {
+            loop {
+                match receiver.recv().await {
+                    Ok(event) if recipe_id.is_none() || event.event.recipe_id() == recipe_id => {
+                        match is_event_visible(
+                            recipe_repository.clone(),
+                            user_id.as_ref(),
+                            &event.event,
+                        )
+                        .await
+                        {
+                            Ok(true) => return Some((Ok(to_sse(event)), receiver)),
+                            Ok(false) => continue,
+                            Err(e) => {
+                                tracing::error!("Could not tell whether to send an event: {e}");
+                                continue;
+                            }
+                        }
+                    }
+                    Ok(_) => continue,
+                    Err(RecvError::Lagged(skipped)) => {
+                        tracing::warn!("Subscriber fell behind, skipped {skipped} events");
+                        continue;
+                    }
+                    Err(RecvError::Closed) => return None,
+                }
+            }
+        }
+    });
+
+    Sse::new(events).keep_alive(KeepAlive::default())
+}
+
+fn to_sse(OutboxEvent { id, event, .. }: OutboxEvent) -> Event {
+    let name = event.as_ref().to_string();
+    let dto: DomainEventDTO = event.into();
+
+    Event::default()
+        .id(id.to_string())
+        .event(name)
+        .json_data(dto)
+        .unwrap_or_else(|e| Event::default().event("error").data(e.to_string()))
+}
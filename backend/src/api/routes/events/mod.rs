@@ -0,0 +1,3 @@
+mod get_events;
+
+pub use get_events::*;
@@ -0,0 +1,55 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use common::FavoriteDTO;
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    api::{
+        errors::MakeError,
+        extract::{CurrentUser, Json},
+        AppState,
+    },
+    domain::commands::favorites::add::{add_favorite, AddFavoriteError},
+};
+
+impl MakeError<String> for AddFavoriteError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::RecipeNotFound(_) => StatusCode::NOT_FOUND,
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for AddFavoriteError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument(
+    "[ROUTE] Adding a recipe to favorites",
+    skip(favorite_repository, recipe_repository, user)
+)]
+pub async fn add_favorite_route(
+    State(AppState {
+        favorite_repository,
+        recipe_repository,
+        ..
+    }): State<AppState>,
+    CurrentUser(user): CurrentUser,
+    Path(recipe_id): Path<Uuid>,
+) -> Result<Json<FavoriteDTO>, AddFavoriteError> {
+    let result = add_favorite(favorite_repository, recipe_repository, &user.id, &recipe_id).await?;
+
+    Ok(Json(result.into()))
+}
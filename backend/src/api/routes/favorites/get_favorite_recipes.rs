@@ -0,0 +1,54 @@
+use axum::{extract::State, response::IntoResponse};
+use common::FavoriteRecipeDTO;
+use reqwest::StatusCode;
+
+use crate::{
+    api::{
+        errors::MakeError,
+        extract::{CurrentUser, Json},
+        AppState,
+    },
+    domain::queries::favorites::get_all::{get_favorite_recipes, GetFavoriteRecipesError},
+};
+
+impl MakeError<String> for GetFavoriteRecipesError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        StatusCode::INTERNAL_SERVER_ERROR
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for GetFavoriteRecipesError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument(
+    "[ROUTE] Getting favorite recipes",
+    skip(favorite_repository, recipe_repository, recipe_note_repository, user)
+)]
+pub async fn get_favorite_recipes_route(
+    State(AppState {
+        favorite_repository,
+        recipe_repository,
+        recipe_note_repository,
+        ..
+    }): State<AppState>,
+    CurrentUser(user): CurrentUser,
+) -> Result<Json<Vec<FavoriteRecipeDTO>>, GetFavoriteRecipesError> {
+    let result = get_favorite_recipes(
+        favorite_repository,
+        recipe_repository,
+        recipe_note_repository,
+        &user.id,
+    )
+    .await?;
+
+    Ok(Json(result.into_iter().map(|f| f.into()).collect()))
+}
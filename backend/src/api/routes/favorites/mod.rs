@@ -0,0 +1,7 @@
+mod add_favorite;
+mod get_favorite_recipes;
+mod remove_favorite;
+
+pub use add_favorite::*;
+pub use get_favorite_recipes::*;
+pub use remove_favorite::*;
@@ -0,0 +1,49 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    api::{errors::MakeError, extract::CurrentUser, AppState},
+    domain::commands::favorites::remove::{remove_favorite, RemoveFavoriteError},
+};
+
+impl MakeError<String> for RemoveFavoriteError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFavorite(_) => StatusCode::NOT_FOUND,
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for RemoveFavoriteError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument(
+    "[ROUTE] Removing a recipe from favorites",
+    skip(favorite_repository, user)
+)]
+pub async fn remove_favorite_route(
+    State(AppState {
+        favorite_repository,
+        ..
+    }): State<AppState>,
+    CurrentUser(user): CurrentUser,
+    Path(recipe_id): Path<Uuid>,
+) -> Result<(), RemoveFavoriteError> {
+    remove_favorite(favorite_repository, &user.id, &recipe_id).await?;
+
+    Ok(())
+}
@@ -32,19 +32,12 @@ impl IntoResponse for DeleteIngredientError {
     }
 }
 
-#[tracing::instrument(
-    "[ROUTE] Deleting an ingredient",
-    skip(ingredient_repository, recipe_repository)
-)]
+#[tracing::instrument("[ROUTE] Deleting an ingredient", skip(unit_of_work))]
 pub async fn delete_ingredient_route(
-    State(AppState {
-        ingredient_repository,
-        recipe_repository,
-        ..
-    }): State<AppState>,
+    State(AppState { unit_of_work, .. }): State<AppState>,
     Path(ingredient_id): Path<Uuid>,
 ) -> Result<(), DeleteIngredientError> {
-    delete_ingredient(ingredient_repository, recipe_repository, &ingredient_id).await?;
+    delete_ingredient(unit_of_work, &ingredient_id).await?;
 
     Ok(())
 }
@@ -1,12 +1,17 @@
 use axum::{
     extract::{Path, State},
-    response::IntoResponse,
+    http::header,
+    response::{IntoResponse, Response},
 };
 use common::IngredientDTO;
 use uuid::Uuid;
 
 use crate::{
-    api::{errors::MakeError, extract::Json, AppState},
+    api::{
+        errors::MakeError,
+        extract::{etag, IfNoneMatch, Json},
+        AppState,
+    },
     domain::queries::ingredients::get_by_id::{get_ingredient_by_id, GetIngredientError},
 };
 
@@ -35,9 +40,16 @@ pub async fn get_ingredient_by_id_route(
         ingredient_repository,
         ..
     }): State<AppState>,
-) -> Result<Json<IngredientDTO>, GetIngredientError> {
+    if_none_match: IfNoneMatch,
+) -> Result<Response, GetIngredientError> {
     // TODO: Switch to storing the amount of recipes with certain ingredient in some sort of cache
     let result = get_ingredient_by_id(ingredient_repository, &ingredient_id).await?;
+    let entity_tag = result.entity_tag();
+    let tag = [(header::ETAG, etag(&entity_tag))];
 
-    Ok(Json(result.into()))
+    if if_none_match.matches(&entity_tag) {
+        return Ok((reqwest::StatusCode::NOT_MODIFIED, tag).into_response());
+    }
+
+    Ok((tag, Json(IngredientDTO::from(result))).into_response())
 }
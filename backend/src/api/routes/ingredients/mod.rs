@@ -2,10 +2,12 @@ mod all_ingredients;
 mod create_ingredient;
 mod delete_ingredient;
 mod get_ingredient_by_id;
+mod parse_ingredients;
 mod update_ingredient;
 
 pub use all_ingredients::*;
 pub use create_ingredient::*;
 pub use delete_ingredient::*;
 pub use get_ingredient_by_id::*;
+pub use parse_ingredients::*;
 pub use update_ingredient::*;
@@ -0,0 +1,42 @@
+use axum::{extract::State, response::IntoResponse};
+use common::{ParseIngredientLinesDTO, ParsedIngredientLineDTO};
+use reqwest::StatusCode;
+
+use crate::{
+    api::{errors::MakeError, extract::Json, AppState},
+    domain::queries::ingredients::parse::{parse_ingredient_lines, ParseIngredientLinesError},
+};
+
+impl MakeError<String> for ParseIngredientLinesError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::InvalidLine(_, _) => StatusCode::UNPROCESSABLE_ENTITY,
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for ParseIngredientLinesError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument("[ROUTE] Parsing ingredient lines", skip(ingredient_repository))]
+pub async fn parse_ingredients_route(
+    State(AppState {
+        ingredient_repository,
+        ..
+    }): State<AppState>,
+    Json(body): Json<ParseIngredientLinesDTO>,
+) -> Result<Json<Vec<ParsedIngredientLineDTO>>, ParseIngredientLinesError> {
+    let result = parse_ingredient_lines(ingredient_repository, &body.lines).await?;
+
+    Ok(Json(result.into_iter().map(|p| p.into()).collect()))
+}
@@ -1,12 +1,17 @@
 use axum::{
     extract::{Path, State},
+    http::header,
     response::IntoResponse,
 };
 use common::{IngredientDTO, UpdateIngredientDTO};
 use uuid::Uuid;
 
 use crate::{
-    api::{errors::MakeError, extract::Json, AppState},
+    api::{
+        errors::MakeError,
+        extract::{etag, IfMatch, Json},
+        AppState,
+    },
     domain::commands::ingredients::update::{update_ingredient, UpdateIngredientError},
 };
 
@@ -20,6 +25,7 @@ impl MakeError<String> for UpdateIngredientError {
     fn get_status_code(&self) -> reqwest::StatusCode {
         match self {
             Self::NotFound(_) => reqwest::StatusCode::NOT_FOUND,
+            Self::PreconditionFailed(_) => reqwest::StatusCode::PRECONDITION_FAILED,
             _ => reqwest::StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
@@ -27,20 +33,22 @@ impl MakeError<String> for UpdateIngredientError {
 
 impl IntoResponse for UpdateIngredientError {
     fn into_response(self) -> axum::response::Response {
-        (self.get_status_code(), self.get_message()).into_response()
+        (self.get_status_code(), self.get_json()).into_response()
     }
 }
 
-#[tracing::instrument("[ROUTE] Updating an existing ingredient", skip(ingredient_repository))]
+#[tracing::instrument("[ROUTE] Updating an existing ingredient", skip(unit_of_work))]
 pub async fn update_ingredient_route(
     Path(ingredient_id): Path<Uuid>,
-    State(AppState {
-        ingredient_repository,
-        ..
-    }): State<AppState>,
+    State(AppState { unit_of_work, .. }): State<AppState>,
+    IfMatch(precondition): IfMatch,
     Json(body): Json<UpdateIngredientDTO>,
-) -> Result<Json<IngredientDTO>, UpdateIngredientError> {
-    let result = update_ingredient(ingredient_repository, ingredient_id, &body.into()).await?;
+) -> Result<impl IntoResponse, UpdateIngredientError> {
+    let result =
+        update_ingredient(unit_of_work, ingredient_id, &body.into(), &precondition).await?;
 
-    Ok(Json(result.into()))
+    Ok((
+        [(header::ETAG, etag(&result.entity_tag()))],
+        Json(IngredientDTO::from(result)),
+    ))
 }
@@ -0,0 +1,49 @@
+use axum::{extract::State, response::IntoResponse};
+use common::{CalendarFeedDTO, CreateCalendarFeedDTO};
+use reqwest::StatusCode;
+
+use crate::{
+    api::{
+        errors::MakeError,
+        extract::{CurrentUser, Json},
+        AppState,
+    },
+    domain::commands::meal_plans::create_feed::{create_calendar_feed, CreateCalendarFeedError},
+};
+
+impl MakeError<String> for CreateCalendarFeedError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::ValidationError(_) => StatusCode::BAD_REQUEST,
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for CreateCalendarFeedError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument("[ROUTE] Creating a calendar feed", skip(meal_plan_repository, user))]
+pub async fn create_calendar_feed_route(
+    State(AppState {
+        meal_plan_repository,
+        ..
+    }): State<AppState>,
+    CurrentUser(user): CurrentUser,
+    Json(body): Json<CreateCalendarFeedDTO>,
+) -> Result<impl IntoResponse, CreateCalendarFeedError> {
+    let result: CalendarFeedDTO = create_calendar_feed(meal_plan_repository, &user.id, &body.name)
+        .await?
+        .into();
+
+    Ok((StatusCode::CREATED, Json(result)))
+}
@@ -0,0 +1,58 @@
+use axum::{extract::State, response::IntoResponse};
+use common::{CreateMealPlanEntryDTO, MealPlanEntryDTO};
+use reqwest::StatusCode;
+
+use crate::{
+    api::{
+        errors::MakeError,
+        extract::{CurrentUser, Json},
+        AppState,
+    },
+    domain::commands::meal_plans::create::{create_meal_plan_entry, CreateMealPlanEntryError},
+};
+
+impl MakeError<String> for CreateMealPlanEntryError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::RecipeNotFound(_) | Self::ValidationError(_) => StatusCode::BAD_REQUEST,
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for CreateMealPlanEntryError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument(
+    "[ROUTE] Planning a meal",
+    skip(meal_plan_repository, recipe_repository, user)
+)]
+pub async fn create_meal_plan_entry_route(
+    State(AppState {
+        meal_plan_repository,
+        recipe_repository,
+        ..
+    }): State<AppState>,
+    CurrentUser(user): CurrentUser,
+    Json(body): Json<CreateMealPlanEntryDTO>,
+) -> Result<impl IntoResponse, CreateMealPlanEntryError> {
+    let result: MealPlanEntryDTO = create_meal_plan_entry(
+        meal_plan_repository,
+        recipe_repository,
+        &user.id,
+        &body.into(),
+    )
+    .await?
+    .into();
+
+    Ok((StatusCode::CREATED, Json(result)))
+}
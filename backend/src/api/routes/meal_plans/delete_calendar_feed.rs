@@ -0,0 +1,45 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use reqwest::StatusCode;
+
+use crate::{
+    api::{errors::MakeError, extract::CurrentUser, AppState},
+    domain::commands::meal_plans::delete_feed::{delete_calendar_feed, DeleteCalendarFeedError},
+};
+
+impl MakeError<String> for DeleteCalendarFeedError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound => StatusCode::NOT_FOUND,
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for DeleteCalendarFeedError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument("[ROUTE] Revoking a calendar feed", skip_all)]
+pub async fn delete_calendar_feed_route(
+    State(AppState {
+        meal_plan_repository,
+        ..
+    }): State<AppState>,
+    CurrentUser(user): CurrentUser,
+    Path(token): Path<String>,
+) -> Result<(), DeleteCalendarFeedError> {
+    delete_calendar_feed(meal_plan_repository, &user.id, &token).await?;
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    api::{errors::MakeError, extract::CurrentUser, AppState},
+    domain::commands::meal_plans::delete::{delete_meal_plan_entry, DeleteMealPlanEntryError},
+};
+
+impl MakeError<String> for DeleteMealPlanEntryError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for DeleteMealPlanEntryError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument("[ROUTE] Removing a planned meal", skip(meal_plan_repository, user))]
+pub async fn delete_meal_plan_entry_route(
+    State(AppState {
+        meal_plan_repository,
+        ..
+    }): State<AppState>,
+    CurrentUser(user): CurrentUser,
+    Path(entry_id): Path<Uuid>,
+) -> Result<(), DeleteMealPlanEntryError> {
+    delete_meal_plan_entry(meal_plan_repository, &user.id, &entry_id).await?;
+
+    Ok(())
+}
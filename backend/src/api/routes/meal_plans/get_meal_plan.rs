@@ -0,0 +1,58 @@
+use axum::{
+    extract::{Query, State},
+    response::IntoResponse,
+};
+use chrono::NaiveDate;
+use common::MealPlanEntryDTO;
+use reqwest::StatusCode;
+use serde::Deserialize;
+
+use crate::{
+    api::{
+        errors::MakeError,
+        extract::{CurrentUser, Json},
+        AppState,
+    },
+    domain::queries::meal_plans::get_in_range::{get_meal_plan_in_range, GetMealPlanError},
+};
+
+impl MakeError<String> for GetMealPlanError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::InvalidRange(_, _) => StatusCode::BAD_REQUEST,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for GetMealPlanError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct MealPlanRangeQuery {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+}
+
+#[tracing::instrument("[ROUTE] Getting the meal plan", skip(meal_plan_repository, user))]
+pub async fn get_meal_plan_route(
+    State(AppState {
+        meal_plan_repository,
+        ..
+    }): State<AppState>,
+    CurrentUser(user): CurrentUser,
+    Query(MealPlanRangeQuery { from, to }): Query<MealPlanRangeQuery>,
+) -> Result<Json<Vec<MealPlanEntryDTO>>, GetMealPlanError> {
+    let result = get_meal_plan_in_range(meal_plan_repository, &user.id, &from, &to).await?;
+
+    Ok(Json(result.into_iter().map(|e| e.into()).collect()))
+}
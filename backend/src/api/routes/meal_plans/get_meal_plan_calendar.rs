@@ -0,0 +1,72 @@
+use axum::{
+    extract::{Path, State},
+    http::{header, HeaderMap},
+    response::IntoResponse,
+};
+use chrono::Utc;
+use reqwest::StatusCode;
+
+use crate::{
+    api::{errors::MakeError, AppState},
+    domain::queries::meal_plans::calendar::{get_meal_plan_calendar, GetMealPlanCalendarError},
+};
+
+impl MakeError<String> for GetMealPlanCalendarError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound => StatusCode::NOT_FOUND,
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for GetMealPlanCalendarError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+/// The address the calendar client used to reach us, which the recipes are linked relative to
+fn base_url(headers: &HeaderMap) -> String {
+    let host = headers
+        .get(header::HOST)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or("localhost");
+    let scheme = headers
+        .get("x-forwarded-proto")
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or("http");
+
+    format!("{}://{}", scheme, host)
+}
+
+#[tracing::instrument("[ROUTE] Getting the meal plan calendar", skip_all)]
+pub async fn get_meal_plan_calendar_route(
+    State(AppState {
+        meal_plan_repository,
+        recipe_repository,
+        ..
+    }): State<AppState>,
+    Path(token): Path<String>,
+    headers: HeaderMap,
+) -> Result<impl IntoResponse, GetMealPlanCalendarError> {
+    let result = get_meal_plan_calendar(
+        meal_plan_repository,
+        recipe_repository,
+        &token,
+        &Utc::now().date_naive(),
+        &base_url(&headers),
+    )
+    .await?;
+
+    Ok((
+        [(header::CONTENT_TYPE, "text/calendar; charset=utf-8")],
+        result,
+    ))
+}
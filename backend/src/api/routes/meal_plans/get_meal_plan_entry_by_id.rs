@@ -0,0 +1,53 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use common::MealPlanEntryDTO;
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    api::{
+        errors::MakeError,
+        extract::{CurrentUser, Json},
+        AppState,
+    },
+    domain::queries::meal_plans::get_by_id::{
+        get_meal_plan_entry_by_id, GetMealPlanEntryByIdError,
+    },
+};
+
+impl MakeError<String> for GetMealPlanEntryByIdError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for GetMealPlanEntryByIdError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument("[ROUTE] Getting a planned meal", skip(meal_plan_repository, user))]
+pub async fn get_meal_plan_entry_by_id_route(
+    State(AppState {
+        meal_plan_repository,
+        ..
+    }): State<AppState>,
+    CurrentUser(user): CurrentUser,
+    Path(entry_id): Path<Uuid>,
+) -> Result<Json<MealPlanEntryDTO>, GetMealPlanEntryByIdError> {
+    let result = get_meal_plan_entry_by_id(meal_plan_repository, &user.id, &entry_id).await?;
+
+    Ok(Json(result.into()))
+}
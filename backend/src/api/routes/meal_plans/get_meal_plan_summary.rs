@@ -0,0 +1,37 @@
+use axum::extract::{Query, State};
+use common::MealPlanSummaryDTO;
+
+use crate::{
+    api::{
+        extract::{CurrentUser, Json},
+        AppState,
+    },
+    domain::queries::meal_plans::{get_in_range::GetMealPlanError, summary::get_meal_plan_summary},
+};
+
+use super::MealPlanRangeQuery;
+
+#[tracing::instrument(
+    "[ROUTE] Summarizing the meal plan",
+    skip(meal_plan_repository, recipe_repository, user)
+)]
+pub async fn get_meal_plan_summary_route(
+    State(AppState {
+        meal_plan_repository,
+        recipe_repository,
+        ..
+    }): State<AppState>,
+    CurrentUser(user): CurrentUser,
+    Query(MealPlanRangeQuery { from, to }): Query<MealPlanRangeQuery>,
+) -> Result<Json<MealPlanSummaryDTO>, GetMealPlanError> {
+    let result = get_meal_plan_summary(
+        meal_plan_repository,
+        recipe_repository,
+        &user.id,
+        &from,
+        &to,
+    )
+    .await?;
+
+    Ok(Json(result.into()))
+}
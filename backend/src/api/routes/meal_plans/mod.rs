@@ -0,0 +1,19 @@
+mod create_calendar_feed;
+mod create_meal_plan_entry;
+mod delete_calendar_feed;
+mod delete_meal_plan_entry;
+mod get_meal_plan;
+mod get_meal_plan_calendar;
+mod get_meal_plan_entry_by_id;
+mod get_meal_plan_summary;
+mod update_meal_plan_entry;
+
+pub use create_calendar_feed::*;
+pub use create_meal_plan_entry::*;
+pub use delete_calendar_feed::*;
+pub use delete_meal_plan_entry::*;
+pub use get_meal_plan::*;
+pub use get_meal_plan_calendar::*;
+pub use get_meal_plan_entry_by_id::*;
+pub use get_meal_plan_summary::*;
+pub use update_meal_plan_entry::*;
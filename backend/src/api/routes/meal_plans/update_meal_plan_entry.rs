@@ -0,0 +1,64 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use common::{MealPlanEntryDTO, UpdateMealPlanEntryDTO};
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    api::{
+        errors::MakeError,
+        extract::{CurrentUser, Json},
+        AppState,
+    },
+    domain::commands::meal_plans::update::{update_meal_plan_entry, UpdateMealPlanEntryError},
+};
+
+impl MakeError<String> for UpdateMealPlanEntryError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::RecipeNotFound(_) | Self::ValidationError(_) => StatusCode::BAD_REQUEST,
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for UpdateMealPlanEntryError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument(
+    "[ROUTE] Updating a planned meal",
+    skip(meal_plan_repository, recipe_repository, user)
+)]
+pub async fn update_meal_plan_entry_route(
+    State(AppState {
+        meal_plan_repository,
+        recipe_repository,
+        ..
+    }): State<AppState>,
+    CurrentUser(user): CurrentUser,
+    Path(entry_id): Path<Uuid>,
+    Json(body): Json<UpdateMealPlanEntryDTO>,
+) -> Result<Json<MealPlanEntryDTO>, UpdateMealPlanEntryError> {
+    let result = update_meal_plan_entry(
+        meal_plan_repository,
+        recipe_repository,
+        &user.id,
+        &entry_id,
+        body.into(),
+    )
+    .await?;
+
+    Ok(Json(result.into()))
+}
@@ -1,2 +1,12 @@
+pub mod collections;
+pub mod cooking_sessions;
+pub mod events;
+pub mod favorites;
 pub mod ingredients;
+pub mod meal_plans;
+pub mod pantry;
+pub mod recipe_notes;
 pub mod recipes;
+pub mod shopping_lists;
+pub mod users;
+pub mod webhooks;
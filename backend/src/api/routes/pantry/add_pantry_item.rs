@@ -0,0 +1,59 @@
+use axum::{extract::State, response::IntoResponse};
+use common::{CreatePantryItemDTO, PantryItemDTO};
+use reqwest::StatusCode;
+
+use crate::{
+    api::{
+        errors::MakeError,
+        extract::{CurrentUser, Json},
+        AppState,
+    },
+    domain::commands::pantry::add::{add_pantry_item, AddPantryItemError},
+};
+
+impl MakeError<String> for AddPantryItemError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::IngredientNotFound(_) => StatusCode::BAD_REQUEST,
+            Self::AlreadyInPantry(_) => StatusCode::CONFLICT,
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for AddPantryItemError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument(
+    "[ROUTE] Adding an item to the pantry",
+    skip(pantry_repository, ingredient_repository, user)
+)]
+pub async fn add_pantry_item_route(
+    State(AppState {
+        pantry_repository,
+        ingredient_repository,
+        ..
+    }): State<AppState>,
+    CurrentUser(user): CurrentUser,
+    Json(body): Json<CreatePantryItemDTO>,
+) -> Result<impl IntoResponse, AddPantryItemError> {
+    let result: PantryItemDTO = add_pantry_item(
+        pantry_repository,
+        ingredient_repository,
+        &user.id,
+        &body.into(),
+    )
+    .await?
+    .into();
+
+    Ok((StatusCode::CREATED, Json(result)))
+}
@@ -0,0 +1,54 @@
+use axum::{extract::State, response::IntoResponse};
+use chrono::Utc;
+use common::CookableRecipeDTO;
+use reqwest::StatusCode;
+
+use crate::{
+    api::{
+        errors::MakeError,
+        extract::{CurrentUser, Json},
+        AppState,
+    },
+    domain::queries::pantry::cookable::{get_cookable_recipes, GetCookableRecipesError},
+};
+
+impl MakeError<String> for GetCookableRecipesError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        StatusCode::INTERNAL_SERVER_ERROR
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for GetCookableRecipesError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument(
+    "[ROUTE] Getting recipes that can be cooked now",
+    skip(pantry_repository, recipe_repository, user)
+)]
+pub async fn get_cookable_recipes_route(
+    State(AppState {
+        pantry_repository,
+        recipe_repository,
+        ..
+    }): State<AppState>,
+    CurrentUser(user): CurrentUser,
+) -> Result<Json<Vec<CookableRecipeDTO>>, GetCookableRecipesError> {
+    let result = get_cookable_recipes(
+        pantry_repository,
+        recipe_repository,
+        &user.id,
+        &Utc::now().date_naive(),
+    )
+    .await?;
+
+    Ok(Json(result.into_iter().map(|r| r.into()).collect()))
+}
@@ -0,0 +1,42 @@
+use axum::{extract::State, response::IntoResponse};
+use common::PantryItemDTO;
+use reqwest::StatusCode;
+
+use crate::{
+    api::{
+        errors::MakeError,
+        extract::{CurrentUser, Json},
+        AppState,
+    },
+    domain::queries::pantry::get_all::{get_pantry, GetPantryError},
+};
+
+impl MakeError<String> for GetPantryError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        StatusCode::INTERNAL_SERVER_ERROR
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for GetPantryError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument("[ROUTE] Getting the pantry", skip(pantry_repository, user))]
+pub async fn get_pantry_route(
+    State(AppState {
+        pantry_repository, ..
+    }): State<AppState>,
+    CurrentUser(user): CurrentUser,
+) -> Result<Json<Vec<PantryItemDTO>>, GetPantryError> {
+    let result = get_pantry(pantry_repository, &user.id).await?;
+
+    Ok(Json(result.into_iter().map(|item| item.into()).collect()))
+}
@@ -0,0 +1,11 @@
+mod add_pantry_item;
+mod get_cookable_recipes;
+mod get_pantry;
+mod remove_pantry_item;
+mod update_pantry_item;
+
+pub use add_pantry_item::*;
+pub use get_cookable_recipes::*;
+pub use get_pantry::*;
+pub use remove_pantry_item::*;
+pub use update_pantry_item::*;
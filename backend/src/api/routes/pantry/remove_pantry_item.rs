@@ -0,0 +1,48 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    api::{errors::MakeError, extract::CurrentUser, AppState},
+    domain::commands::pantry::remove::{remove_pantry_item, RemovePantryItemError},
+};
+
+impl MakeError<String> for RemovePantryItemError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for RemovePantryItemError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument(
+    "[ROUTE] Removing an item from the pantry",
+    skip(pantry_repository, user)
+)]
+pub async fn remove_pantry_item_route(
+    State(AppState {
+        pantry_repository, ..
+    }): State<AppState>,
+    CurrentUser(user): CurrentUser,
+    Path(item_id): Path<Uuid>,
+) -> Result<(), RemovePantryItemError> {
+    remove_pantry_item(pantry_repository, &user.id, &item_id).await?;
+
+    Ok(())
+}
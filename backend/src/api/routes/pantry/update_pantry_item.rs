@@ -0,0 +1,51 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use common::{PantryItemDTO, UpdatePantryItemDTO};
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    api::{
+        errors::MakeError,
+        extract::{CurrentUser, Json},
+        AppState,
+    },
+    domain::commands::pantry::update::{update_pantry_item, UpdatePantryItemError},
+};
+
+impl MakeError<String> for UpdatePantryItemError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for UpdatePantryItemError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument("[ROUTE] Updating a pantry item", skip(pantry_repository, user))]
+pub async fn update_pantry_item_route(
+    State(AppState {
+        pantry_repository, ..
+    }): State<AppState>,
+    CurrentUser(user): CurrentUser,
+    Path(item_id): Path<Uuid>,
+    Json(body): Json<UpdatePantryItemDTO>,
+) -> Result<Json<PantryItemDTO>, UpdatePantryItemError> {
+    let result = update_pantry_item(pantry_repository, &user.id, &item_id, body.into()).await?;
+
+    Ok(Json(result.into()))
+}
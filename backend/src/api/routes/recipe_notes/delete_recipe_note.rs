@@ -0,0 +1,49 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    api::{errors::MakeError, extract::CurrentUser, AppState},
+    domain::commands::recipe_notes::delete::{delete_recipe_note, DeleteRecipeNoteError},
+};
+
+impl MakeError<String> for DeleteRecipeNoteError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for DeleteRecipeNoteError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument(
+    "[ROUTE] Deleting notes on a recipe",
+    skip(recipe_note_repository, user)
+)]
+pub async fn delete_recipe_note_route(
+    State(AppState {
+        recipe_note_repository,
+        ..
+    }): State<AppState>,
+    CurrentUser(user): CurrentUser,
+    Path(recipe_id): Path<Uuid>,
+) -> Result<(), DeleteRecipeNoteError> {
+    delete_recipe_note(recipe_note_repository, &user.id, &recipe_id).await?;
+
+    Ok(())
+}
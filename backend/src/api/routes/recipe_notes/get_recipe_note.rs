@@ -0,0 +1,54 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use common::RecipeNoteDTO;
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    api::{
+        errors::MakeError,
+        extract::{CurrentUser, Json},
+        AppState,
+    },
+    domain::queries::recipe_notes::get_by_recipe::{get_recipe_note, GetRecipeNoteByRecipeError},
+};
+
+impl MakeError<String> for GetRecipeNoteByRecipeError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for GetRecipeNoteByRecipeError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument(
+    "[ROUTE] Getting notes on a recipe",
+    skip(recipe_note_repository, user)
+)]
+pub async fn get_recipe_note_route(
+    State(AppState {
+        recipe_note_repository,
+        ..
+    }): State<AppState>,
+    CurrentUser(user): CurrentUser,
+    Path(recipe_id): Path<Uuid>,
+) -> Result<Json<RecipeNoteDTO>, GetRecipeNoteByRecipeError> {
+    let result = get_recipe_note(recipe_note_repository, &user.id, &recipe_id).await?;
+
+    Ok(Json(result.into()))
+}
@@ -0,0 +1,7 @@
+mod delete_recipe_note;
+mod get_recipe_note;
+mod save_recipe_note;
+
+pub use delete_recipe_note::*;
+pub use get_recipe_note::*;
+pub use save_recipe_note::*;
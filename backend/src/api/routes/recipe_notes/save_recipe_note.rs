@@ -0,0 +1,64 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use common::{RecipeNoteDTO, SaveRecipeNoteDTO};
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    api::{
+        errors::MakeError,
+        extract::{CurrentUser, Json},
+        AppState,
+    },
+    domain::commands::recipe_notes::save::{save_recipe_note, SaveRecipeNoteError},
+};
+
+impl MakeError<String> for SaveRecipeNoteError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::RecipeNotFound(_) => StatusCode::NOT_FOUND,
+            Self::ValidationError(_) => StatusCode::BAD_REQUEST,
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for SaveRecipeNoteError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument(
+    "[ROUTE] Saving notes on a recipe",
+    skip(recipe_note_repository, recipe_repository, user)
+)]
+pub async fn save_recipe_note_route(
+    State(AppState {
+        recipe_note_repository,
+        recipe_repository,
+        ..
+    }): State<AppState>,
+    CurrentUser(user): CurrentUser,
+    Path(recipe_id): Path<Uuid>,
+    Json(body): Json<SaveRecipeNoteDTO>,
+) -> Result<Json<RecipeNoteDTO>, SaveRecipeNoteError> {
+    let result = save_recipe_note(
+        recipe_note_repository,
+        recipe_repository,
+        &user.id,
+        &recipe_id,
+        &body.content,
+    )
+    .await?;
+
+    Ok(Json(result.into()))
+}
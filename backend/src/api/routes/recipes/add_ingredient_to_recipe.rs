@@ -1,16 +1,21 @@
 use axum::{
     extract::{Path, State},
+    http::header,
     response::IntoResponse,
 };
-use common::{IngredientAmountDTO, RecipeDTO};
+use common::{AddIngredientLineToRecipeDTO, IngredientAmountDTO, RecipeDTO};
 use reqwest::StatusCode;
 use uuid::Uuid;
 
 use crate::{
-    api::{errors::MakeError, extract::Json, AppState},
+    api::{
+        errors::MakeError,
+        extract::{etag, IfMatch, Json, MaybeUser},
+        AppState,
+    },
     domain::{
         commands::recipes::ingredients::add::{
-            add_ingredient_to_recipe, AddIngredientToRecipeError,
+            add_ingredient_line_to_recipe, add_ingredient_to_recipe, AddIngredientToRecipeError,
         },
         repositories::{
             ingredients::errors::GetIngredientByIdError, recipe::errors::GetRecipeByIdError,
@@ -25,7 +30,10 @@ impl MakeError<String> for AddIngredientToRecipeError {
     fn get_status_code(&self) -> StatusCode {
         match self {
             Self::GetIngredient(GetIngredientByIdError::NotFound(_)) => StatusCode::BAD_REQUEST,
+            Self::InvalidLine(_) | Self::IngredientNotMatched(_) => StatusCode::BAD_REQUEST,
             Self::GetRecipe(GetRecipeByIdError::NotFound(_)) => StatusCode::NOT_FOUND,
+            Self::PreconditionFailed(_) => StatusCode::PRECONDITION_FAILED,
+            Self::NotOwner(_) => StatusCode::FORBIDDEN,
             _ => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
@@ -41,22 +49,51 @@ impl IntoResponse for AddIngredientToRecipeError {
 }
 
 pub async fn add_ingredient_to_recipe_route(
+    State(AppState { unit_of_work, .. }): State<AppState>,
+    user: MaybeUser,
+    Path(recipe_id): Path<Uuid>,
+    IfMatch(precondition): IfMatch,
+    Json(body): Json<IngredientAmountDTO>,
+) -> Result<impl IntoResponse, AddIngredientToRecipeError> {
+    let result = add_ingredient_to_recipe(
+        unit_of_work,
+        user.id(),
+        &recipe_id,
+        body.into(),
+        &precondition,
+    )
+    .await?;
+
+    Ok((
+        [(header::ETAG, etag(&result.entity_tag()))],
+        Json(RecipeDTO::from(result)),
+    ))
+}
+
+/// Adds an ingredient written as a single line, matching it to one of the existing ingredients
+pub async fn add_ingredient_line_to_recipe_route(
     State(AppState {
-        recipe_repository,
+        unit_of_work,
         ingredient_repository,
         ..
     }): State<AppState>,
+    user: MaybeUser,
     Path(recipe_id): Path<Uuid>,
-    Json(body): Json<IngredientAmountDTO>,
-) -> Result<Json<RecipeDTO>, AddIngredientToRecipeError> {
-    let ingredient_to_add = body.into();
-    let result = add_ingredient_to_recipe(
-        recipe_repository,
+    IfMatch(precondition): IfMatch,
+    Json(body): Json<AddIngredientLineToRecipeDTO>,
+) -> Result<impl IntoResponse, AddIngredientToRecipeError> {
+    let result = add_ingredient_line_to_recipe(
+        unit_of_work,
         ingredient_repository,
+        user.id(),
         &recipe_id,
-        ingredient_to_add,
+        &body.line,
+        &precondition,
     )
     .await?;
 
-    Ok(Json(result.into()))
+    Ok((
+        [(header::ETAG, etag(&result.entity_tag()))],
+        Json(RecipeDTO::from(result)),
+    ))
 }
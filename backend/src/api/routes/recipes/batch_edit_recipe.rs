@@ -0,0 +1,85 @@
+use axum::{
+    extract::{Path, State},
+    http::header,
+    response::IntoResponse,
+};
+use common::{error::ErrorMessage, BatchEditRecipeDTO, FailedIngredientOperationDTO, RecipeDTO};
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    api::{
+        errors::MakeError,
+        extract::{etag, IfMatch, Json, MaybeUser},
+        AppState,
+    },
+    domain::commands::recipes::batch::{batch_edit_recipe, BatchEditRecipeError},
+};
+
+impl MakeError<String> for BatchEditRecipeError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::PreconditionFailed(_) => StatusCode::PRECONDITION_FAILED,
+            Self::NotOwner(_) => StatusCode::FORBIDDEN,
+            Self::ChangesetEmpty | Self::ValidationError(_) => StatusCode::BAD_REQUEST,
+            Self::InvalidOperations(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for BatchEditRecipeError {
+    fn into_response(self) -> axum::response::Response {
+        let status = self.get_status_code();
+        let kind = self.get_kind();
+
+        match self {
+            Self::InvalidOperations(errors) => {
+                let failed: Vec<_> = errors
+                    .into_iter()
+                    .map(|(index, error)| FailedIngredientOperationDTO {
+                        index,
+                        kind: error.as_ref().to_string(),
+                        message: error.to_string(),
+                    })
+                    .collect();
+
+                (status, axum::Json(ErrorMessage::new(&kind, failed))).into_response()
+            }
+            e => (status, e.get_json()).into_response(),
+        }
+    }
+}
+
+#[tracing::instrument(
+    "[ROUTE] Editing a recipe in one batch",
+    skip(unit_of_work, user, body)
+)]
+pub async fn batch_edit_recipe_route(
+    State(AppState { unit_of_work, .. }): State<AppState>,
+    user: MaybeUser,
+    Path(recipe_id): Path<Uuid>,
+    IfMatch(precondition): IfMatch,
+    Json(body): Json<BatchEditRecipeDTO>,
+) -> Result<impl IntoResponse, BatchEditRecipeError> {
+    let recipe = batch_edit_recipe(
+        unit_of_work,
+        user.id(),
+        &recipe_id,
+        body.into(),
+        &precondition,
+    )
+    .await?;
+
+    Ok((
+        [(header::ETAG, etag(&recipe.entity_tag()))],
+        Json(RecipeDTO::from(recipe)),
+    ))
+}
@@ -3,7 +3,7 @@ use common::{CreateRecipeDTO, RecipeDTO};
 use reqwest::StatusCode;
 
 use crate::api::errors::MakeError;
-use crate::api::extract::Json;
+use crate::api::extract::{Json, MaybeUser};
 use crate::api::AppState;
 use crate::domain::commands::recipes::create::{create_recipe, CreateRecipe, CreateRecipeError};
 use crate::domain::entities::recipe::IngredientAmountData;
@@ -29,27 +29,16 @@ impl IntoResponse for CreateRecipeError {
     }
 }
 
-#[tracing::instrument(
-    "[ROUTE] Creating a new recipe",
-    skip(ingredient_repository, recipe_repository)
-)]
+#[tracing::instrument("[ROUTE] Creating a new recipe", skip(unit_of_work, user))]
 pub async fn create_recipe_route(
-    State(AppState {
-        ingredient_repository,
-        recipe_repository,
-        ..
-    }): State<AppState>,
+    State(AppState { unit_of_work, .. }): State<AppState>,
+    user: MaybeUser,
     Json(body): Json<CreateRecipeDTO>,
 ) -> Result<impl IntoResponse, CreateRecipeError> {
     let ingredients: Vec<IngredientAmountData> = body
         .ingredients
         .into_iter()
-        .map(|i| IngredientAmountData {
-            notes: i.notes,
-            amount: i.amount.into(),
-            optional: i.optional,
-            ingredient_id: i.ingredient_id,
-        })
+        .map(IngredientAmountData::from)
         .collect();
 
     let input = CreateRecipe {
@@ -65,9 +54,7 @@ pub async fn create_recipe_route(
         ingredients,
     };
 
-    let result: RecipeDTO = create_recipe(recipe_repository, ingredient_repository, &input)
-        .await?
-        .into();
+    let result: RecipeDTO = create_recipe(unit_of_work, user.id(), &input).await?.into();
 
     Ok((StatusCode::CREATED, Json(result)))
 }
@@ -0,0 +1,55 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use common::{CreateReviewDTO, ReviewDTO};
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    api::{
+        errors::MakeError,
+        extract::{CurrentUser, Json},
+        AppState,
+    },
+    domain::commands::recipes::reviews::create::{create_review, CreateReviewError},
+};
+
+impl MakeError<String> for CreateReviewError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::RecipeNotFound(_) => StatusCode::NOT_FOUND,
+            Self::AlreadyReviewed(_) => StatusCode::CONFLICT,
+            Self::ValidationError(_) => StatusCode::BAD_REQUEST,
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for CreateReviewError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument("[ROUTE] Reviewing a recipe", skip(recipe_repository, user))]
+pub async fn create_review_route(
+    State(AppState {
+        recipe_repository, ..
+    }): State<AppState>,
+    CurrentUser(user): CurrentUser,
+    Path(recipe_id): Path<Uuid>,
+    Json(body): Json<CreateReviewDTO>,
+) -> Result<impl IntoResponse, CreateReviewError> {
+    let result: ReviewDTO = create_review(recipe_repository, &user, &recipe_id, &body.try_into()?)
+        .await?
+        .into();
+
+    Ok((StatusCode::CREATED, Json(result)))
+}
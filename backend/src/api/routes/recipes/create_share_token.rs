@@ -0,0 +1,53 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use common::ShareTokenDTO;
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    api::{
+        errors::MakeError,
+        extract::{CurrentUser, Json},
+        AppState,
+    },
+    domain::commands::recipes::share::create::{create_share_token, CreateShareTokenError},
+};
+
+impl MakeError<String> for CreateShareTokenError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::RecipeNotFound(_) => StatusCode::NOT_FOUND,
+            Self::NotOwner(_) => StatusCode::FORBIDDEN,
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for CreateShareTokenError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument("[ROUTE] Sharing a recipe", skip(recipe_repository, user))]
+pub async fn create_share_token_route(
+    State(AppState {
+        recipe_repository, ..
+    }): State<AppState>,
+    CurrentUser(user): CurrentUser,
+    Path(recipe_id): Path<Uuid>,
+) -> Result<impl IntoResponse, CreateShareTokenError> {
+    let result: ShareTokenDTO = create_share_token(recipe_repository, &user.id, &recipe_id)
+        .await?
+        .into();
+
+    Ok((StatusCode::CREATED, Json(result)))
+}
@@ -6,7 +6,11 @@ use reqwest::StatusCode;
 use uuid::Uuid;
 
 use crate::{
-    api::{errors::MakeError, AppState},
+    api::{
+        errors::MakeError,
+        extract::{IfMatch, MaybeUser},
+        AppState,
+    },
     domain::commands::recipes::ingredients::delete::{
         delete_ingredient_from_recipe, DeleteIngredientFromRecipeError,
     },
@@ -25,6 +29,10 @@ impl MakeError<String> for DeleteIngredientFromRecipeError {
             DeleteIngredientFromRecipeError::LastIngredientError => {
                 StatusCode::UNPROCESSABLE_ENTITY
             }
+            DeleteIngredientFromRecipeError::PreconditionFailedError(_) => {
+                StatusCode::PRECONDITION_FAILED
+            }
+            DeleteIngredientFromRecipeError::NotOwnerError(_) => StatusCode::FORBIDDEN,
             _ => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
@@ -40,12 +48,19 @@ impl IntoResponse for DeleteIngredientFromRecipeError {
 }
 
 pub async fn delete_ingredient_from_recipe_route(
-    State(AppState {
-        recipe_repository, ..
-    }): State<AppState>,
-    Path((recipe_id, ingredient_id)): Path<(Uuid, Uuid)>,
+    State(AppState { unit_of_work, .. }): State<AppState>,
+    user: MaybeUser,
+    Path((recipe_id, entry_id)): Path<(Uuid, Uuid)>,
+    IfMatch(precondition): IfMatch,
 ) -> Result<(), DeleteIngredientFromRecipeError> {
-    delete_ingredient_from_recipe(recipe_repository, &recipe_id, &ingredient_id).await?;
+    delete_ingredient_from_recipe(
+        unit_of_work,
+        user.id(),
+        &recipe_id,
+        &entry_id,
+        &precondition,
+    )
+    .await?;
 
     Ok(())
 }
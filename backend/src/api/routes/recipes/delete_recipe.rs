@@ -6,7 +6,7 @@ use reqwest::StatusCode;
 use uuid::Uuid;
 
 use crate::{
-    api::{errors::MakeError, AppState},
+    api::{errors::MakeError, extract::MaybeUser, AppState},
     domain::commands::recipes::delete::{delete_recipe, DeleteRecipeError},
 };
 
@@ -17,6 +17,7 @@ impl MakeError<String> for DeleteRecipeError {
     fn get_status_code(&self) -> StatusCode {
         match self {
             Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::NotOwner(_) => StatusCode::FORBIDDEN,
             _ => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
@@ -31,14 +32,13 @@ impl IntoResponse for DeleteRecipeError {
     }
 }
 
-#[tracing::instrument("[ROUTE] Deleting a recipe", skip(recipe_repository))]
+#[tracing::instrument("[ROUTE] Deleting a recipe", skip(unit_of_work, user))]
 pub async fn delete_recipe_route(
-    State(AppState {
-        recipe_repository, ..
-    }): State<AppState>,
+    State(AppState { unit_of_work, .. }): State<AppState>,
+    user: MaybeUser,
     Path(recipe_id): Path<Uuid>,
 ) -> Result<(), DeleteRecipeError> {
-    delete_recipe(recipe_repository, &recipe_id).await?;
+    delete_recipe(unit_of_work, user.id(), &recipe_id).await?;
 
     Ok(())
 }
@@ -0,0 +1,72 @@
+use axum::{extract::State, http::header, response::IntoResponse};
+use chrono::Utc;
+use common::ExportCookbookDTO;
+use reqwest::StatusCode;
+
+use crate::{
+    api::{
+        errors::MakeError,
+        extract::{Json, MaybeUser},
+        AppState,
+    },
+    domain::queries::recipes::cookbook::{export_cookbook, ExportCookbook, ExportCookbookError},
+};
+
+impl MakeError<String> for ExportCookbookError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::RecipeNotFound(_)
+            | Self::CollectionNotFound(_)
+            | Self::NoRecipes
+            | Self::ValidationError(_) => StatusCode::BAD_REQUEST,
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for ExportCookbookError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument(
+    "[ROUTE] Exporting a cookbook",
+    skip(recipe_repository, collection_repository, user)
+)]
+pub async fn export_cookbook_route(
+    State(AppState {
+        recipe_repository,
+        collection_repository,
+        ..
+    }): State<AppState>,
+    user: MaybeUser,
+    Json(body): Json<ExportCookbookDTO>,
+) -> Result<impl IntoResponse, ExportCookbookError> {
+    let input = ExportCookbook::try_from(body)?;
+    let result = export_cookbook(
+        recipe_repository,
+        collection_repository,
+        user.id(),
+        &input,
+        &Utc::now(),
+    )
+    .await?;
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "application/epub+zip".to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{}\"", result.file_name()),
+            ),
+        ],
+        result.content,
+    ))
+}
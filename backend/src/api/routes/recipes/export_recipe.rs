@@ -0,0 +1,58 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::header,
+    response::IntoResponse,
+};
+use reqwest::StatusCode;
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::api::{errors::MakeError, extract::MaybeUser, AppState};
+use crate::domain::queries::recipes::export::{export_recipe, ExportFormat, ExportRecipeError};
+
+impl MakeError<String> for ExportRecipeError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for ExportRecipeError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ExportRecipeQuery {
+    #[serde(default)]
+    format: ExportFormat,
+}
+
+#[tracing::instrument("[ROUTE] Exporting a recipe", skip(recipe_repository, user))]
+pub async fn export_recipe_route(
+    State(AppState {
+        recipe_repository, ..
+    }): State<AppState>,
+    user: MaybeUser,
+    Path(recipe_id): Path<Uuid>,
+    Query(ExportRecipeQuery { format }): Query<ExportRecipeQuery>,
+) -> Result<impl IntoResponse, ExportRecipeError> {
+    let result = export_recipe(recipe_repository, user.id(), &recipe_id, format).await?;
+
+    let content_type = match format {
+        ExportFormat::JsonLd => "application/ld+json",
+        ExportFormat::Markdown => "text/markdown; charset=utf-8",
+        ExportFormat::Text | ExportFormat::Cooklang => "text/plain; charset=utf-8",
+    };
+
+    Ok(([(header::CONTENT_TYPE, content_type)], result))
+}
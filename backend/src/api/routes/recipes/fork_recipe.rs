@@ -0,0 +1,52 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use common::RecipeDTO;
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    api::{
+        errors::MakeError,
+        extract::{Json, MaybeUser},
+        AppState,
+    },
+    domain::commands::recipes::fork::{fork_recipe, ForkRecipeError},
+};
+
+impl MakeError<String> for ForkRecipeError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for ForkRecipeError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument("[ROUTE] Forking a recipe", skip(recipe_repository, user))]
+pub async fn fork_recipe_route(
+    State(AppState {
+        recipe_repository, ..
+    }): State<AppState>,
+    user: MaybeUser,
+    Path(recipe_id): Path<Uuid>,
+) -> Result<impl IntoResponse, ForkRecipeError> {
+    let result: RecipeDTO = fork_recipe(recipe_repository, user.id(), &recipe_id)
+        .await?
+        .into();
+
+    Ok((StatusCode::CREATED, Json(result)))
+}
@@ -0,0 +1,42 @@
+use axum::{extract::State, response::IntoResponse};
+use common::RecipeDTO;
+use reqwest::StatusCode;
+
+use crate::{
+    api::{
+        errors::MakeError,
+        extract::{Json, MaybeUser},
+        AppState,
+    },
+    domain::queries::recipes::get_all::{get_all_recipes, GetAllRecipesError},
+};
+
+impl MakeError<String> for GetAllRecipesError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        StatusCode::INTERNAL_SERVER_ERROR
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for GetAllRecipesError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument("[ROUTE] Getting all recipes", skip(recipe_repository, user))]
+pub async fn get_all_recipes_route(
+    State(AppState {
+        recipe_repository, ..
+    }): State<AppState>,
+    user: MaybeUser,
+) -> Result<Json<Vec<RecipeDTO>>, GetAllRecipesError> {
+    let result = get_all_recipes(recipe_repository, user.id()).await?;
+
+    Ok(Json(result.into_iter().map(|r| r.into()).collect()))
+}
@@ -1,13 +1,19 @@
 use axum::{
-    extract::{Path, State},
-    response::IntoResponse,
+    extract::{Path, Query, State},
+    http::header,
+    response::{IntoResponse, Response},
     Json,
 };
 use common::RecipeDTO;
 use reqwest::StatusCode;
+use serde::Deserialize;
 use uuid::Uuid;
 
-use crate::api::{errors::MakeError, AppState};
+use crate::api::{
+    errors::MakeError,
+    extract::{etag, IfNoneMatch, MaybeUser},
+    AppState,
+};
 use crate::domain::queries::recipes::get_by_id::{get_recipe_by_id, GetRecipeError};
 
 impl MakeError<String> for GetRecipeError {
@@ -31,14 +37,30 @@ impl IntoResponse for GetRecipeError {
     }
 }
 
-#[tracing::instrument("[ROUTE] Getting a recipe by ID", skip(recipe_repository))]
+#[derive(Deserialize, Debug)]
+pub struct GetRecipeQuery {
+    /// Share token of an unlisted recipe
+    share: Option<String>,
+}
+
+#[tracing::instrument("[ROUTE] Getting a recipe by ID", skip(recipe_repository, user, share))]
 pub async fn get_recipe_by_id_route(
     State(AppState {
         recipe_repository, ..
     }): State<AppState>,
+    user: MaybeUser,
     Path(recipe_id): Path<Uuid>,
-) -> Result<Json<RecipeDTO>, GetRecipeError> {
-    let result = get_recipe_by_id(recipe_repository, &recipe_id).await?;
+    Query(GetRecipeQuery { share }): Query<GetRecipeQuery>,
+    if_none_match: IfNoneMatch,
+) -> Result<Response, GetRecipeError> {
+    let result =
+        get_recipe_by_id(recipe_repository, user.id(), &recipe_id, share.as_deref()).await?;
+    let entity_tag = result.entity_tag();
+    let tag = [(header::ETAG, etag(&entity_tag))];
+
+    if if_none_match.matches(&entity_tag) {
+        return Ok((StatusCode::NOT_MODIFIED, tag).into_response());
+    }
 
-    Ok(axum::Json(result.into()))
+    Ok((tag, Json(RecipeDTO::from(result))).into_response())
 }
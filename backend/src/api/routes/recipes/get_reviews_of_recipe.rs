@@ -0,0 +1,50 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use common::ReviewDTO;
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    api::{
+        errors::MakeError,
+        extract::{Json, MaybeUser},
+        AppState,
+    },
+    domain::queries::recipes::get_reviews::{get_reviews_of_recipe, GetReviewsOfRecipeError},
+};
+
+impl MakeError<String> for GetReviewsOfRecipeError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::RecipeNotFound(_) => StatusCode::NOT_FOUND,
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for GetReviewsOfRecipeError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument("[ROUTE] Getting reviews of a recipe", skip(recipe_repository, user))]
+pub async fn get_reviews_of_recipe_route(
+    State(AppState {
+        recipe_repository, ..
+    }): State<AppState>,
+    user: MaybeUser,
+    Path(recipe_id): Path<Uuid>,
+) -> Result<Json<Vec<ReviewDTO>>, GetReviewsOfRecipeError> {
+    let result = get_reviews_of_recipe(recipe_repository, user.id(), &recipe_id).await?;
+
+    Ok(Json(result.into_iter().map(|r| r.into()).collect()))
+}
@@ -0,0 +1,56 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use common::ShareTokenDTO;
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    api::{
+        errors::MakeError,
+        extract::{CurrentUser, Json},
+        AppState,
+    },
+    domain::queries::recipes::get_share_tokens::{
+        get_share_tokens_of_recipe, GetShareTokensOfRecipeError,
+    },
+};
+
+impl MakeError<String> for GetShareTokensOfRecipeError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::RecipeNotFound(_) => StatusCode::NOT_FOUND,
+            Self::NotOwner(_) => StatusCode::FORBIDDEN,
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for GetShareTokensOfRecipeError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument(
+    "[ROUTE] Getting share tokens of a recipe",
+    skip(recipe_repository, user)
+)]
+pub async fn get_share_tokens_of_recipe_route(
+    State(AppState {
+        recipe_repository, ..
+    }): State<AppState>,
+    CurrentUser(user): CurrentUser,
+    Path(recipe_id): Path<Uuid>,
+) -> Result<Json<Vec<ShareTokenDTO>>, GetShareTokensOfRecipeError> {
+    let result = get_share_tokens_of_recipe(recipe_repository, &user.id, &recipe_id).await?;
+
+    Ok(Json(result.into_iter().map(|r| r.into()).collect()))
+}
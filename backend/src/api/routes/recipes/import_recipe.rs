@@ -0,0 +1,210 @@
+use axum::{extract::State, response::IntoResponse};
+use common::{
+    BulkImportedRecipesDTO, FailedRecipeImportDTO, FailedRecipePreviewDTO, ImportedRecipeDTO,
+    RecipePreviewsDTO,
+};
+use reqwest::StatusCode;
+
+use crate::{
+    api::{
+        errors::MakeError,
+        extract::{Json, MaybeUser, UploadedFile, UploadedFiles},
+        AppState,
+    },
+    domain::commands::recipes::import::{
+        cooklang::{
+            import_recipe_from_cooklang, import_recipes_from_cooklang, CooklangFile,
+            ImportCooklangError,
+        },
+        jsonld::{import_recipe_from_jsonld, ImportJsonLdError},
+        text::{preview_recipes_from_text, ImportTextError},
+    },
+};
+
+impl MakeError<String> for ImportJsonLdError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::InvalidJson(_) => StatusCode::BAD_REQUEST,
+            Self::RecipeNotFound | Self::MissingField(_) | Self::NoIngredientsMatched(_) => {
+                StatusCode::UNPROCESSABLE_ENTITY
+            }
+            Self::CreateRecipe(e) => e.get_status_code(),
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for ImportJsonLdError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument(
+    "[ROUTE] Importing a recipe from JSON-LD",
+    skip(ingredient_repository, unit_of_work, file, user)
+)]
+pub async fn import_recipe_from_jsonld_route(
+    State(AppState {
+        ingredient_repository,
+        unit_of_work,
+        ..
+    }): State<AppState>,
+    user: MaybeUser,
+    file: UploadedFile,
+) -> Result<impl IntoResponse, ImportJsonLdError> {
+    let result: ImportedRecipeDTO = import_recipe_from_jsonld(
+        unit_of_work,
+        ingredient_repository,
+        user.id(),
+        &file.content,
+    )
+    .await?
+    .into();
+
+    Ok((StatusCode::CREATED, Json(result)))
+}
+
+impl MakeError<String> for ImportCooklangError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::MissingField(_) | Self::NoIngredientsMatched(_) => {
+                StatusCode::UNPROCESSABLE_ENTITY
+            }
+            Self::CreateRecipe(e) => e.get_status_code(),
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for ImportCooklangError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+impl From<UploadedFile> for CooklangFile {
+    fn from(value: UploadedFile) -> Self {
+        Self {
+            file_name: value.file_name,
+            content: value.content,
+        }
+    }
+}
+
+#[tracing::instrument(
+    "[ROUTE] Importing a recipe from Cooklang",
+    skip(ingredient_repository, unit_of_work, file, user)
+)]
+pub async fn import_recipe_from_cooklang_route(
+    State(AppState {
+        ingredient_repository,
+        unit_of_work,
+        ..
+    }): State<AppState>,
+    user: MaybeUser,
+    file: UploadedFile,
+) -> Result<impl IntoResponse, ImportCooklangError> {
+    let result: ImportedRecipeDTO =
+        import_recipe_from_cooklang(unit_of_work, ingredient_repository, user.id(), &file.into())
+            .await?
+            .into();
+
+    Ok((StatusCode::CREATED, Json(result)))
+}
+
+/// Imports every `.cook` file uploaded, i.e. through `<input type="file" webkitdirectory>`
+#[tracing::instrument(
+    "[ROUTE] Importing recipes from Cooklang files",
+    skip(ingredient_repository, unit_of_work, files, user)
+)]
+pub async fn import_recipes_from_cooklang_route(
+    State(AppState {
+        ingredient_repository,
+        unit_of_work,
+        ..
+    }): State<AppState>,
+    user: MaybeUser,
+    UploadedFiles(files): UploadedFiles,
+) -> Result<Json<BulkImportedRecipesDTO>, ImportCooklangError> {
+    let files: Vec<CooklangFile> = files.into_iter().map(Into::into).collect();
+    let result =
+        import_recipes_from_cooklang(unit_of_work, ingredient_repository, user.id(), &files)
+            .await?;
+
+    Ok(Json(BulkImportedRecipesDTO {
+        imported: result.imported.into_iter().map(Into::into).collect(),
+        failed: result
+            .failed
+            .into_iter()
+            .map(|(file_name, error)| FailedRecipeImportDTO {
+                file_name,
+                kind: error.get_kind(),
+                message: error.get_message(),
+            })
+            .collect(),
+    }))
+}
+
+impl MakeError<String> for ImportTextError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::RecipeNotFound | Self::MissingSection(_, _) => StatusCode::UNPROCESSABLE_ENTITY,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for ImportTextError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+/// Parses MealMaster files and pasted recipes without creating anything,
+/// so that the user can correct the recipes before creating them.
+/// Recipes in a MealMaster file that can't be parsed are listed next to the ones that could.
+#[tracing::instrument(
+    "[ROUTE] Previewing recipes imported from text",
+    skip(ingredient_repository, file)
+)]
+pub async fn preview_recipes_from_text_route(
+    State(AppState {
+        ingredient_repository,
+        ..
+    }): State<AppState>,
+    file: UploadedFile,
+) -> Result<Json<RecipePreviewsDTO>, ImportTextError> {
+    let result = preview_recipes_from_text(ingredient_repository, &file.content).await?;
+
+    Ok(Json(RecipePreviewsDTO {
+        previews: result.previews.into_iter().map(Into::into).collect(),
+        failed: result
+            .failed
+            .into_iter()
+            .map(|(recipe, error)| FailedRecipePreviewDTO {
+                recipe,
+                kind: error.get_kind(),
+                message: error.get_message(),
+            })
+            .collect(),
+    }))
+}
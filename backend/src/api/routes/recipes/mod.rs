@@ -1,15 +1,43 @@
 mod add_ingredient_to_recipe;
+mod batch_edit_recipe;
 mod create_recipe;
+mod create_review;
+mod create_share_token;
 mod delete_ingredient_from_recipe;
 mod delete_recipe;
+mod export_cookbook;
+mod export_recipe;
+mod fork_recipe;
+mod get_all_recipes;
+mod get_ancestry_of_recipe;
+mod get_forks_of_recipe;
 mod get_recipe_by_id;
+mod get_reviews_of_recipe;
+mod get_share_tokens_of_recipe;
+mod import_recipe;
+mod revoke_share_token;
 mod update_ingredient_in_recipe;
 mod update_recipe;
+mod update_review;
 
 pub use add_ingredient_to_recipe::*;
+pub use batch_edit_recipe::*;
 pub use create_recipe::create_recipe_route;
+pub use create_review::*;
+pub use create_share_token::*;
 pub use delete_ingredient_from_recipe::*;
 pub use delete_recipe::*;
+pub use export_cookbook::*;
+pub use export_recipe::*;
+pub use fork_recipe::*;
+pub use get_all_recipes::*;
+pub use get_ancestry_of_recipe::*;
+pub use get_forks_of_recipe::*;
 pub use get_recipe_by_id::*;
+pub use get_reviews_of_recipe::*;
+pub use get_share_tokens_of_recipe::*;
+pub use import_recipe::*;
+pub use revoke_share_token::*;
 pub use update_ingredient_in_recipe::*;
 pub use update_recipe::*;
+pub use update_review::*;
@@ -0,0 +1,46 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    api::{errors::MakeError, extract::CurrentUser, AppState},
+    domain::commands::recipes::share::revoke::{revoke_share_token, RevokeShareTokenError},
+};
+
+impl MakeError<String> for RevokeShareTokenError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::RecipeNotFound(_) | Self::NotFound => StatusCode::NOT_FOUND,
+            Self::NotOwner(_) => StatusCode::FORBIDDEN,
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for RevokeShareTokenError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument("[ROUTE] Revoking a share token", skip_all)]
+pub async fn revoke_share_token_route(
+    State(AppState {
+        recipe_repository, ..
+    }): State<AppState>,
+    CurrentUser(user): CurrentUser,
+    Path((recipe_id, token)): Path<(Uuid, String)>,
+) -> Result<(), RevokeShareTokenError> {
+    revoke_share_token(recipe_repository, &user.id, &recipe_id, &token).await?;
+
+    Ok(())
+}
@@ -1,18 +1,22 @@
 use axum::{
     extract::{Path, State},
+    http::header,
     response::IntoResponse,
 };
-use common::{IngredientUnitDTO, RecipeDTO};
+use common::{RecipeDTO, UpdateIngredientInRecipeDTO};
 use reqwest::StatusCode;
 use uuid::Uuid;
 
 use crate::{
-    api::{errors::MakeError, extract::Json, AppState},
+    api::{
+        errors::MakeError,
+        extract::{etag, IfMatch, Json, MaybeUser},
+        AppState,
+    },
     domain::{
         commands::recipes::ingredients::update::{
             update_ingredient_in_recipe, UpdateIngredientInRecipeError,
         },
-        entities::recipe::IngredientUnit,
         repositories::recipe::errors::GetRecipeByIdError,
     },
 };
@@ -27,6 +31,9 @@ impl MakeError<String> for UpdateIngredientInRecipeError {
             | UpdateIngredientInRecipeError::GetRecipe(GetRecipeByIdError::NotFound(_)) => {
                 StatusCode::NOT_FOUND
             }
+            UpdateIngredientInRecipeError::ChangesetEmpty => StatusCode::BAD_REQUEST,
+            UpdateIngredientInRecipeError::PreconditionFailed(_) => StatusCode::PRECONDITION_FAILED,
+            UpdateIngredientInRecipeError::NotOwner(_) => StatusCode::FORBIDDEN,
             _ => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
@@ -42,15 +49,24 @@ impl IntoResponse for UpdateIngredientInRecipeError {
 }
 
 pub async fn update_ingredient_in_recipe_route(
-    State(AppState {
-        recipe_repository, ..
-    }): State<AppState>,
-    Path((recipe_id, ingredient_id)): Path<(Uuid, Uuid)>,
-    Json(body): Json<IngredientUnitDTO>,
-) -> Result<Json<RecipeDTO>, UpdateIngredientInRecipeError> {
-    let amount: IngredientUnit = body.into();
-    let recipe =
-        update_ingredient_in_recipe(recipe_repository, &recipe_id, &ingredient_id, amount).await?;
+    State(AppState { unit_of_work, .. }): State<AppState>,
+    user: MaybeUser,
+    Path((recipe_id, entry_id)): Path<(Uuid, Uuid)>,
+    IfMatch(precondition): IfMatch,
+    Json(body): Json<UpdateIngredientInRecipeDTO>,
+) -> Result<impl IntoResponse, UpdateIngredientInRecipeError> {
+    let recipe = update_ingredient_in_recipe(
+        unit_of_work,
+        user.id(),
+        &recipe_id,
+        &entry_id,
+        body.into(),
+        &precondition,
+    )
+    .await?;
 
-    Ok(Json(recipe.into()))
+    Ok((
+        [(header::ETAG, etag(&recipe.entity_tag()))],
+        Json(RecipeDTO::from(recipe)),
+    ))
 }
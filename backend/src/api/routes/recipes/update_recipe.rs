@@ -1,11 +1,12 @@
 use axum::extract::Path;
+use axum::http::header;
 use axum::{extract::State, response::IntoResponse};
 use common::{RecipeDTO, UpdateRecipeDTO};
 use reqwest::StatusCode;
 use uuid::Uuid;
 
 use crate::api::errors::MakeError;
-use crate::api::extract::Json;
+use crate::api::extract::{etag, IfMatch, Json, MaybeUser};
 use crate::api::AppState;
 use crate::domain::commands::recipes::update::{update_recipe, UpdateRecipeError};
 
@@ -18,6 +19,8 @@ impl MakeError<String> for UpdateRecipeError {
             Self::NotFound(_) => StatusCode::NOT_FOUND,
             Self::ValidationError(_) => StatusCode::BAD_REQUEST,
             Self::ChangesetEmpty => StatusCode::BAD_REQUEST,
+            Self::PreconditionFailed(_) => StatusCode::PRECONDITION_FAILED,
+            Self::NotOwner(_) => StatusCode::FORBIDDEN,
             _ => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
@@ -32,15 +35,25 @@ impl IntoResponse for UpdateRecipeError {
     }
 }
 
-#[tracing::instrument("[ROUTE] Creating a new recipe", skip(recipe_repository))]
+#[tracing::instrument("[ROUTE] Creating a new recipe", skip(unit_of_work, user))]
 pub async fn update_recipe_route(
-    State(AppState {
-        recipe_repository, ..
-    }): State<AppState>,
+    State(AppState { unit_of_work, .. }): State<AppState>,
+    user: MaybeUser,
     Path(recipe_id): Path<Uuid>,
+    IfMatch(precondition): IfMatch,
     Json(body): Json<UpdateRecipeDTO>,
-) -> Result<Json<RecipeDTO>, UpdateRecipeError> {
-    let recipe = update_recipe(recipe_repository, &recipe_id, body.into()).await?;
+) -> Result<impl IntoResponse, UpdateRecipeError> {
+    let recipe = update_recipe(
+        unit_of_work,
+        user.id(),
+        &recipe_id,
+        body.into(),
+        &precondition,
+    )
+    .await?;
 
-    Ok(Json(recipe.into()))
+    Ok((
+        [(header::ETAG, etag(&recipe.entity_tag()))],
+        Json(RecipeDTO::from(recipe)),
+    ))
 }
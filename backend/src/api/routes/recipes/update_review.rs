@@ -0,0 +1,60 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use common::{ReviewDTO, UpdateReviewDTO};
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    api::{
+        errors::MakeError,
+        extract::{CurrentUser, Json},
+        AppState,
+    },
+    domain::commands::recipes::reviews::update::{update_review, UpdateReviewError},
+};
+
+impl MakeError<String> for UpdateReviewError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::RecipeNotFound(_) | Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::NotAuthor(_) => StatusCode::FORBIDDEN,
+            Self::ValidationError(_) => StatusCode::BAD_REQUEST,
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for UpdateReviewError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument("[ROUTE] Editing a review", skip(recipe_repository, user))]
+pub async fn update_review_route(
+    State(AppState {
+        recipe_repository, ..
+    }): State<AppState>,
+    CurrentUser(user): CurrentUser,
+    Path((recipe_id, review_id)): Path<(Uuid, Uuid)>,
+    Json(body): Json<UpdateReviewDTO>,
+) -> Result<Json<ReviewDTO>, UpdateReviewError> {
+    let result = update_review(
+        recipe_repository,
+        &user.id,
+        &recipe_id,
+        &review_id,
+        body.try_into()?,
+    )
+    .await?;
+
+    Ok(Json(result.into()))
+}
@@ -0,0 +1,58 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use common::{ShoppingListDTO, UpdateShoppingListItemDTO};
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    api::{errors::MakeError, extract::Json, AppState},
+    domain::commands::shopping_lists::check_item::{
+        check_shopping_list_item, CheckShoppingListItemError,
+    },
+};
+
+impl MakeError<String> for CheckShoppingListItemError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) | Self::ItemNotFound(_) => StatusCode::NOT_FOUND,
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for CheckShoppingListItemError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument(
+    "[ROUTE] Checking off a shopping list item",
+    skip(shopping_list_repository)
+)]
+pub async fn check_shopping_list_item_route(
+    State(AppState {
+        shopping_list_repository,
+        ..
+    }): State<AppState>,
+    Path((shopping_list_id, item_id)): Path<(Uuid, Uuid)>,
+    Json(body): Json<UpdateShoppingListItemDTO>,
+) -> Result<Json<ShoppingListDTO>, CheckShoppingListItemError> {
+    let result = check_shopping_list_item(
+        shopping_list_repository,
+        &shopping_list_id,
+        &item_id,
+        body.checked,
+    )
+    .await?;
+
+    Ok(Json(result.into()))
+}
@@ -0,0 +1,58 @@
+use axum::{extract::State, response::IntoResponse};
+use common::{CreateShoppingListDTO, ShoppingListDTO};
+use reqwest::StatusCode;
+
+use crate::{
+    api::{
+        errors::MakeError,
+        extract::{Json, MaybeUser},
+        AppState,
+    },
+    domain::commands::shopping_lists::create::{create_shopping_list, CreateShoppingListError},
+};
+
+impl MakeError<String> for CreateShoppingListError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::RecipeNotFound(_) | Self::ValidationError(_) => StatusCode::BAD_REQUEST,
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for CreateShoppingListError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument(
+    "[ROUTE] Creating a new shopping list",
+    skip(shopping_list_repository, recipe_repository, user)
+)]
+pub async fn create_shopping_list_route(
+    State(AppState {
+        shopping_list_repository,
+        recipe_repository,
+        ..
+    }): State<AppState>,
+    user: MaybeUser,
+    Json(body): Json<CreateShoppingListDTO>,
+) -> Result<impl IntoResponse, CreateShoppingListError> {
+    let result: ShoppingListDTO = create_shopping_list(
+        shopping_list_repository,
+        recipe_repository,
+        user.id(),
+        &body.into(),
+    )
+    .await?
+    .into();
+
+    Ok((StatusCode::CREATED, Json(result)))
+}
@@ -0,0 +1,45 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    api::{errors::MakeError, AppState},
+    domain::commands::shopping_lists::delete::{delete_shopping_list, DeleteShoppingListError},
+};
+
+impl MakeError<String> for DeleteShoppingListError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for DeleteShoppingListError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument("[ROUTE] Deleting a shopping list", skip(shopping_list_repository))]
+pub async fn delete_shopping_list_route(
+    State(AppState {
+        shopping_list_repository,
+        ..
+    }): State<AppState>,
+    Path(shopping_list_id): Path<Uuid>,
+) -> Result<(), DeleteShoppingListError> {
+    delete_shopping_list(shopping_list_repository, &shopping_list_id).await?;
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use axum::{
+    extract::{Path, State},
+    http::header,
+    response::IntoResponse,
+};
+use uuid::Uuid;
+
+use crate::{
+    api::AppState,
+    domain::queries::shopping_lists::{
+        export::export_shopping_list, get_by_id::GetShoppingListError,
+    },
+};
+
+#[tracing::instrument("[ROUTE] Exporting a shopping list", skip(shopping_list_repository))]
+pub async fn export_shopping_list_route(
+    State(AppState {
+        shopping_list_repository,
+        ..
+    }): State<AppState>,
+    Path(shopping_list_id): Path<Uuid>,
+) -> Result<impl IntoResponse, GetShoppingListError> {
+    let result = export_shopping_list(shopping_list_repository, &shopping_list_id).await?;
+
+    Ok((
+        [(header::CONTENT_TYPE, "text/plain; charset=utf-8")],
+        result,
+    ))
+}
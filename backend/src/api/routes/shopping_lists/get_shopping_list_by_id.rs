@@ -0,0 +1,46 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use common::ShoppingListDTO;
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    api::{errors::MakeError, extract::Json, AppState},
+    domain::queries::shopping_lists::get_by_id::{get_shopping_list_by_id, GetShoppingListError},
+};
+
+impl MakeError<String> for GetShoppingListError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for GetShoppingListError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument("[ROUTE] Getting a shopping list", skip(shopping_list_repository))]
+pub async fn get_shopping_list_by_id_route(
+    State(AppState {
+        shopping_list_repository,
+        ..
+    }): State<AppState>,
+    Path(shopping_list_id): Path<Uuid>,
+) -> Result<Json<ShoppingListDTO>, GetShoppingListError> {
+    let result = get_shopping_list_by_id(shopping_list_repository, &shopping_list_id).await?;
+
+    Ok(Json(result.into()))
+}
@@ -0,0 +1,11 @@
+mod check_shopping_list_item;
+mod create_shopping_list;
+mod delete_shopping_list;
+mod export_shopping_list;
+mod get_shopping_list_by_id;
+
+pub use check_shopping_list_item::*;
+pub use create_shopping_list::*;
+pub use delete_shopping_list::*;
+pub use export_shopping_list::*;
+pub use get_shopping_list_by_id::*;
@@ -0,0 +1,49 @@
+use axum::{extract::State, response::IntoResponse};
+use common::user::{LoginUserDTO, SessionDTO};
+use reqwest::StatusCode;
+
+use crate::{
+    api::{errors::MakeError, extract::Json, AppState},
+    domain::commands::users::login::{login, LoginError},
+};
+
+impl MakeError<String> for LoginError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::InvalidCredentials => StatusCode::UNAUTHORIZED,
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for LoginError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument("[ROUTE] Logging in", skip(user_repository, session_repository, body))]
+pub async fn login_route(
+    State(AppState {
+        user_repository,
+        session_repository,
+        ..
+    }): State<AppState>,
+    Json(body): Json<LoginUserDTO>,
+) -> Result<Json<SessionDTO>, LoginError> {
+    let session = login(
+        user_repository,
+        session_repository,
+        &body.username,
+        &body.password,
+    )
+    .await?;
+
+    Ok(Json(session.into()))
+}
@@ -0,0 +1,39 @@
+use axum::{extract::State, response::IntoResponse};
+use reqwest::StatusCode;
+
+use crate::{
+    api::{errors::MakeError, extract::CurrentSession, AppState},
+    domain::commands::users::logout::{logout, LogoutError},
+};
+
+impl MakeError<String> for LogoutError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for LogoutError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument("[ROUTE] Logging out", skip_all)]
+pub async fn logout_route(
+    State(AppState {
+        session_repository, ..
+    }): State<AppState>,
+    CurrentSession(session): CurrentSession,
+) -> Result<(), LogoutError> {
+    logout(session_repository, &session).await?;
+
+    Ok(())
+}
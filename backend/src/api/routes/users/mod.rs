@@ -0,0 +1,7 @@
+mod login;
+mod logout;
+mod signup;
+
+pub use login::*;
+pub use logout::*;
+pub use signup::*;
@@ -0,0 +1,42 @@
+use axum::{extract::State, response::IntoResponse};
+use common::user::{CreateNewUserDTO, UserDataDTO};
+use reqwest::StatusCode;
+
+use crate::{
+    api::{errors::MakeError, extract::Json, AppState},
+    domain::commands::users::signup::{signup, SignupError},
+};
+
+impl MakeError<String> for SignupError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::Taken(_) => StatusCode::CONFLICT,
+            Self::ValidationError(_) => StatusCode::BAD_REQUEST,
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for SignupError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument("[ROUTE] Signing up", skip(user_repository, body))]
+pub async fn signup_route(
+    State(AppState {
+        user_repository, ..
+    }): State<AppState>,
+    Json(body): Json<CreateNewUserDTO>,
+) -> Result<Json<UserDataDTO>, SignupError> {
+    let user = signup(user_repository, &body.username, &body.email, &body.password).await?;
+
+    Ok(Json(user.into()))
+}
@@ -0,0 +1,56 @@
+use axum::{extract::State, response::IntoResponse};
+use common::{CreateWebhookDTO, WebhookDTO};
+use reqwest::StatusCode;
+
+use crate::{
+    api::{
+        errors::MakeError,
+        extract::{CurrentUser, Json},
+        AppState,
+    },
+    domain::commands::webhooks::create::{create_webhook, CreateWebhookError},
+};
+
+impl MakeError<String> for CreateWebhookError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::InvalidUrl(_) | Self::NotPublic(_) | Self::ValidationError(_) => {
+                StatusCode::BAD_REQUEST
+            }
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for CreateWebhookError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument(
+    "[ROUTE] Registering a webhook",
+    skip(webhook_repository, webhook_targets, user, body)
+)]
+pub async fn create_webhook_route(
+    State(AppState {
+        webhook_repository,
+        webhook_targets,
+        ..
+    }): State<AppState>,
+    CurrentUser(user): CurrentUser,
+    Json(body): Json<CreateWebhookDTO>,
+) -> Result<impl IntoResponse, CreateWebhookError> {
+    let result: WebhookDTO =
+        create_webhook(webhook_repository, webhook_targets, &user.id, &body.into())
+            .await?
+            .into();
+
+    Ok((StatusCode::CREATED, Json(result)))
+}
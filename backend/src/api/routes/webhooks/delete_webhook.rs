@@ -0,0 +1,45 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    api::{errors::MakeError, extract::CurrentUser, AppState},
+    domain::commands::webhooks::delete::{delete_webhook, DeleteWebhookError},
+};
+
+impl MakeError<String> for DeleteWebhookError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for DeleteWebhookError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument("[ROUTE] Deleting a webhook", skip(webhook_repository, user))]
+pub async fn delete_webhook_route(
+    State(AppState {
+        webhook_repository, ..
+    }): State<AppState>,
+    CurrentUser(user): CurrentUser,
+    Path(webhook_id): Path<Uuid>,
+) -> Result<(), DeleteWebhookError> {
+    delete_webhook(webhook_repository, &user.id, &webhook_id).await?;
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use axum::{extract::State, response::IntoResponse};
+use common::WebhookDTO;
+use reqwest::StatusCode;
+
+use crate::{
+    api::{
+        errors::MakeError,
+        extract::{CurrentUser, Json},
+        AppState,
+    },
+    domain::queries::webhooks::get_all::{get_all_webhooks, GetAllWebhooksError},
+};
+
+impl MakeError<String> for GetAllWebhooksError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        StatusCode::INTERNAL_SERVER_ERROR
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for GetAllWebhooksError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument("[ROUTE] Getting all webhooks", skip(webhook_repository, user))]
+pub async fn get_all_webhooks_route(
+    State(AppState {
+        webhook_repository, ..
+    }): State<AppState>,
+    CurrentUser(user): CurrentUser,
+) -> Result<Json<Vec<WebhookDTO>>, GetAllWebhooksError> {
+    let result = get_all_webhooks(webhook_repository, &user.id).await?;
+
+    Ok(Json(result.into_iter().map(|w| w.into()).collect()))
+}
@@ -0,0 +1,55 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use common::WebhookDeliveryDTO;
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    api::{
+        errors::MakeError,
+        extract::{CurrentUser, Json},
+        AppState,
+    },
+    domain::queries::webhooks::get_deliveries::{
+        get_webhook_deliveries, GetWebhookDeliveriesError,
+    },
+};
+
+impl MakeError<String> for GetWebhookDeliveriesError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for GetWebhookDeliveriesError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument(
+    "[ROUTE] Getting the deliveries of a webhook",
+    skip(webhook_repository, user)
+)]
+pub async fn get_webhook_deliveries_route(
+    State(AppState {
+        webhook_repository, ..
+    }): State<AppState>,
+    CurrentUser(user): CurrentUser,
+    Path(webhook_id): Path<Uuid>,
+) -> Result<Json<Vec<WebhookDeliveryDTO>>, GetWebhookDeliveriesError> {
+    let result = get_webhook_deliveries(webhook_repository, &user.id, &webhook_id).await?;
+
+    Ok(Json(result.into_iter().map(|d| d.into()).collect()))
+}
@@ -0,0 +1,9 @@
+mod create_webhook;
+mod delete_webhook;
+mod get_all_webhooks;
+mod get_webhook_deliveries;
+
+pub use create_webhook::*;
+pub use delete_webhook::*;
+pub use get_all_webhooks::*;
+pub use get_webhook_deliveries::*;
@@ -0,0 +1,125 @@
+use common::AddRecipeToCollectionDTO;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::collection::Collection,
+    repositories::{
+        collection::{
+            errors::{
+                AddRecipeToCollectionError as AddRecipeToCollectionErrorInternal,
+                GetCollectionError,
+            },
+            CollectionRepositoryService,
+        },
+        recipe::{errors::GetRecipeByIdError, RecipeRepositoryService},
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum AddRecipeToCollectionError {
+    #[error("Could not find the collection with the ID {0}")]
+    NotFound(Uuid),
+
+    #[error("Could not find the recipe with the ID {0}")]
+    RecipeNotFound(Uuid),
+
+    #[error("Only the owner can make changes to the collection with ID {0}")]
+    NotOwner(Uuid),
+
+    #[error("The recipe with the ID {0} is already in the collection")]
+    AlreadyInCollection(Uuid),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Report),
+}
+
+impl From<GetCollectionError> for AddRecipeToCollectionError {
+    fn from(value: GetCollectionError) -> Self {
+        match value {
+            GetCollectionError::NotFound(id) => Self::NotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<GetRecipeByIdError> for AddRecipeToCollectionError {
+    fn from(value: GetRecipeByIdError) -> Self {
+        match value {
+            GetRecipeByIdError::NotFound(id) => Self::RecipeNotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<AddRecipeToCollectionErrorInternal> for AddRecipeToCollectionError {
+    fn from(value: AddRecipeToCollectionErrorInternal) -> Self {
+        match value {
+            AddRecipeToCollectionErrorInternal::AlreadyInCollection(id) => {
+                Self::AlreadyInCollection(id)
+            }
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AddRecipeToCollection {
+    pub recipe_id: Uuid,
+    pub position: Option<usize>,
+}
+
+impl From<AddRecipeToCollectionDTO> for AddRecipeToCollection {
+    fn from(value: AddRecipeToCollectionDTO) -> Self {
+        Self {
+            recipe_id: value.recipe_id,
+            position: value.position,
+        }
+    }
+}
+
+#[tracing::instrument(
+    "[COMMAND] Adding a recipe to a collection",
+    skip(collection_repo, recipe_repo)
+)]
+pub async fn add_recipe_to_collection(
+    collection_repo: CollectionRepositoryService,
+    recipe_repo: RecipeRepositoryService,
+    user_id: Option<&Uuid>,
+    id: &Uuid,
+    input: &AddRecipeToCollection,
+) -> Result<Collection, AddRecipeToCollectionError> {
+    let collection = collection_repo.get_by_id(id).await?;
+    if !collection.is_visible_to(user_id) {
+        return Err(AddRecipeToCollectionError::NotFound(*id));
+    }
+
+    if !collection.is_editable_by(user_id) {
+        return Err(AddRecipeToCollectionError::NotOwner(*id));
+    }
+
+    if collection.contains(&input.recipe_id) {
+        return Err(AddRecipeToCollectionError::AlreadyInCollection(
+            input.recipe_id,
+        ));
+    }
+
+    recipe_repo
+        .get_visible(user_id, &input.recipe_id, None)
+        .await?;
+
+    let position = input
+        .position
+        .unwrap_or(collection.recipes.len())
+        .min(collection.recipes.len());
+
+    collection_repo
+        .add_recipe(&collection, &input.recipe_id, position)
+        .await?;
+
+    let collection = collection_repo.get_by_id(id).await?;
+
+    Ok(collection)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,267 @@
+use std::sync::Arc;
+
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        commands::collections::add_recipe::{
+            add_recipe_to_collection, AddRecipeToCollection, AddRecipeToCollectionError,
+        },
+        entities::{collection::Collection, recipe::Recipe, visibility::Visibility},
+        repositories::{
+            collection::{CollectionRepository, CollectionRepositoryService},
+            ingredients::IngredientRepository,
+            recipe::{RecipeRepository, RecipeRepositoryService},
+            user::UserRepository,
+        },
+    },
+    test_utils::{
+        another_recipe_fixture, another_user_fixture, collection_fixture, insert_collections,
+        insert_users, private_collection_fixture, user_fixture,
+    },
+};
+
+pub async fn adding_recipes_to_a_collection_works(
+    collection_repo: impl CollectionRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let collection = collection_fixture();
+    insert_collections(
+        &collection_repo,
+        &recipe_repo,
+        &ingredient_repo,
+        std::slice::from_ref(&collection),
+    )
+    .await;
+    let first = another_recipe_fixture(1);
+    let last = another_recipe_fixture(2);
+    recipe_repo.insert(first.clone()).await.unwrap();
+    recipe_repo.insert(last.clone()).await.unwrap();
+    let collection_repo: CollectionRepositoryService = Arc::new(Box::new(collection_repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    add_recipe_to_collection(
+        collection_repo.clone(),
+        recipe_repo.clone(),
+        None,
+        &collection.id,
+        &AddRecipeToCollection {
+            recipe_id: last.id,
+            position: None,
+        },
+    )
+    .await
+    .unwrap();
+
+    let result = add_recipe_to_collection(
+        collection_repo.clone(),
+        recipe_repo,
+        None,
+        &collection.id,
+        &AddRecipeToCollection {
+            recipe_id: first.id,
+            position: Some(0),
+        },
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        result.recipes,
+        vec![first.id, collection.recipes[0], last.id]
+    );
+    assert!(result.updated_at > collection.updated_at);
+    assert_eq!(
+        collection_repo
+            .get_by_id(&collection.id)
+            .await
+            .unwrap()
+            .recipes,
+        result.recipes
+    );
+}
+
+pub async fn adding_an_invalid_recipe_to_a_collection_errors(
+    collection_repo: impl CollectionRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let collection = collection_fixture();
+    insert_collections(
+        &collection_repo,
+        &recipe_repo,
+        &ingredient_repo,
+        std::slice::from_ref(&collection),
+    )
+    .await;
+    let collection_repo: CollectionRepositoryService = Arc::new(Box::new(collection_repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+    let missing = Uuid::now_v7();
+
+    let error = add_recipe_to_collection(
+        collection_repo.clone(),
+        recipe_repo.clone(),
+        None,
+        &collection.id,
+        &AddRecipeToCollection {
+            recipe_id: collection.recipes[0],
+            position: None,
+        },
+    )
+    .await
+    .unwrap_err();
+
+    assert!(
+        matches!(error, AddRecipeToCollectionError::AlreadyInCollection(id) if id == collection.recipes[0])
+    );
+
+    let error = add_recipe_to_collection(
+        collection_repo.clone(),
+        recipe_repo.clone(),
+        None,
+        &collection.id,
+        &AddRecipeToCollection {
+            recipe_id: missing,
+            position: None,
+        },
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(error, AddRecipeToCollectionError::RecipeNotFound(id) if id == missing));
+
+    let error = add_recipe_to_collection(
+        collection_repo,
+        recipe_repo,
+        None,
+        &missing,
+        &AddRecipeToCollection {
+            recipe_id: collection.recipes[0],
+            position: None,
+        },
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(error, AddRecipeToCollectionError::NotFound(id) if id == missing));
+}
+
+pub async fn adding_a_private_recipe_of_someone_else_to_a_collection_errors(
+    collection_repo: impl CollectionRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let collection = collection_fixture();
+    insert_users(&user_repo).await;
+    insert_collections(
+        &collection_repo,
+        &recipe_repo,
+        &ingredient_repo,
+        std::slice::from_ref(&collection),
+    )
+    .await;
+    let private = Recipe {
+        visibility: Visibility::Private,
+        owner_id: Some(user_fixture().id),
+        ..another_recipe_fixture(1)
+    };
+    recipe_repo.insert(private.clone()).await.unwrap();
+    let collection_repo: CollectionRepositoryService = Arc::new(Box::new(collection_repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let error = add_recipe_to_collection(
+        collection_repo.clone(),
+        recipe_repo,
+        Some(&another_user_fixture().id),
+        &collection.id,
+        &AddRecipeToCollection {
+            recipe_id: private.id,
+            position: None,
+        },
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(error, AddRecipeToCollectionError::RecipeNotFound(id) if id == private.id));
+    assert_eq!(
+        collection_repo
+            .get_by_id(&collection.id)
+            .await
+            .unwrap()
+            .recipes,
+        collection.recipes
+    );
+}
+
+pub async fn adding_a_recipe_to_a_collection_of_someone_else_errors(
+    collection_repo: impl CollectionRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let private = private_collection_fixture();
+    let public = Collection {
+        id: Uuid::from_u128(1025),
+        visibility: Visibility::Public,
+        ..private_collection_fixture()
+    };
+    insert_users(&user_repo).await;
+    insert_collections(
+        &collection_repo,
+        &recipe_repo,
+        &ingredient_repo,
+        &[private.clone(), public.clone()],
+    )
+    .await;
+    let recipe = another_recipe_fixture(1);
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+    let collection_repo: CollectionRepositoryService = Arc::new(Box::new(collection_repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+    let input = AddRecipeToCollection {
+        recipe_id: recipe.id,
+        position: None,
+    };
+
+    let error = add_recipe_to_collection(
+        collection_repo.clone(),
+        recipe_repo.clone(),
+        Some(&another_user_fixture().id),
+        &private.id,
+        &input,
+    )
+    .await
+    .unwrap_err();
+    assert!(matches!(error, AddRecipeToCollectionError::NotFound(id) if id == private.id));
+
+    for user_id in [None, Some(&another_user_fixture().id)] {
+        let error = add_recipe_to_collection(
+            collection_repo.clone(),
+            recipe_repo.clone(),
+            user_id,
+            &public.id,
+            &input,
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(error, AddRecipeToCollectionError::NotOwner(id) if id == public.id));
+    }
+
+    assert_eq!(
+        collection_repo.get_by_id(&public.id).await.unwrap().recipes,
+        public.recipes
+    );
+
+    let result = add_recipe_to_collection(
+        collection_repo,
+        recipe_repo,
+        Some(&user_fixture().id),
+        &public.id,
+        &input,
+    )
+    .await
+    .unwrap();
+    assert!(result.contains(&recipe.id));
+}
@@ -0,0 +1,112 @@
+use chrono::Utc;
+use common::CreateCollectionDTO;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{collection::Collection, recipe::errors::ValidationError, visibility::Visibility},
+    repositories::{
+        collection::{errors::InsertCollectionError, CollectionRepositoryService},
+        recipe::{errors::GetRecipeByIdError, RecipeRepositoryService},
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum CreateCollectionError {
+    #[error("Could not find the recipe with the ID {0}")]
+    RecipeNotFound(Uuid),
+
+    #[error("The recipe with the ID {0} was added to the collection more than once")]
+    DuplicateRecipe(Uuid),
+
+    #[error("The cover recipe with the ID {0} is not in the collection")]
+    CoverNotInCollection(Uuid),
+
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Report),
+}
+
+impl From<GetRecipeByIdError> for CreateCollectionError {
+    fn from(value: GetRecipeByIdError) -> Self {
+        match value {
+            GetRecipeByIdError::NotFound(id) => Self::RecipeNotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<InsertCollectionError> for CreateCollectionError {
+    fn from(value: InsertCollectionError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateCollection {
+    pub name: String,
+    pub description: String,
+    pub visibility: Visibility,
+    pub recipes: Vec<Uuid>,
+    pub cover_recipe_id: Option<Uuid>,
+}
+
+impl From<CreateCollectionDTO> for CreateCollection {
+    fn from(value: CreateCollectionDTO) -> Self {
+        Self {
+            name: value.name,
+            description: value.description,
+            visibility: value.visibility.into(),
+            recipes: value.recipes,
+            cover_recipe_id: value.cover_recipe_id,
+        }
+    }
+}
+
+#[tracing::instrument("[COMMAND] Creating a collection", skip(collection_repo, recipe_repo))]
+pub async fn create_collection(
+    collection_repo: CollectionRepositoryService,
+    recipe_repo: RecipeRepositoryService,
+    user_id: Option<&Uuid>,
+    input: &CreateCollection,
+) -> Result<Collection, CreateCollectionError> {
+    let name = input.name.trim();
+    if name.is_empty() {
+        return Err(ValidationError::EmptyField(vec!["name"]).into());
+    }
+
+    for (index, recipe_id) in input.recipes.iter().enumerate() {
+        if input.recipes[..index].contains(recipe_id) {
+            return Err(CreateCollectionError::DuplicateRecipe(*recipe_id));
+        }
+
+        recipe_repo.get_visible(user_id, recipe_id, None).await?;
+    }
+
+    if let Some(cover_recipe_id) = input.cover_recipe_id {
+        if !input.recipes.contains(&cover_recipe_id) {
+            return Err(CreateCollectionError::CoverNotInCollection(cover_recipe_id));
+        }
+    }
+
+    let now = Utc::now();
+    let collection = Collection {
+        id: Uuid::now_v7(),
+        name: name.to_string(),
+        description: input.description.clone(),
+        visibility: input.visibility,
+        owner_id: user_id.copied(),
+        recipes: input.recipes.clone(),
+        cover_recipe_id: input.cover_recipe_id,
+        created_at: now,
+        updated_at: now,
+    };
+
+    collection_repo.insert(collection.clone()).await?;
+
+    Ok(collection)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,192 @@
+use std::sync::Arc;
+
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        commands::collections::create::{
+            create_collection, CreateCollection, CreateCollectionError,
+        },
+        entities::{
+            recipe::{errors::ValidationError, Recipe},
+            visibility::Visibility,
+        },
+        repositories::{
+            collection::{CollectionRepository, CollectionRepositoryService},
+            ingredients::IngredientRepository,
+            recipe::{RecipeRepository, RecipeRepositoryService},
+            user::UserRepository,
+        },
+    },
+    test_utils::{
+        another_recipe_fixture, another_user_fixture, insert_collections, insert_users,
+        recipe_fixture, user_fixture,
+    },
+};
+
+fn input(recipes: Vec<Uuid>, cover_recipe_id: Option<Uuid>) -> CreateCollection {
+    CreateCollection {
+        name: "Christmas 2026".to_string(),
+        description: "Everything for the holidays".to_string(),
+        visibility: Visibility::Unlisted,
+        recipes,
+        cover_recipe_id,
+    }
+}
+
+pub async fn creating_a_collection_works(
+    collection_repo: impl CollectionRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    insert_collections(&collection_repo, &recipe_repo, &ingredient_repo, &[]).await;
+    let collection_repo: CollectionRepositoryService = Arc::new(Box::new(collection_repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+    let recipe = recipe_fixture();
+
+    let result = create_collection(
+        collection_repo.clone(),
+        recipe_repo,
+        None,
+        &input(vec![recipe.id], Some(recipe.id)),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(result.name, "Christmas 2026");
+    assert_eq!(result.recipes, vec![recipe.id]);
+    assert_eq!(result.cover_recipe_id, Some(recipe.id));
+
+    let saved = collection_repo.get_by_id(&result.id).await.unwrap();
+
+    assert_eq!(saved.name, result.name);
+    assert_eq!(saved.description, result.description);
+    assert_eq!(saved.visibility, Visibility::Unlisted);
+    assert_eq!(saved.recipes, result.recipes);
+    assert_eq!(saved.cover_recipe_id, result.cover_recipe_id);
+}
+
+pub async fn creating_an_invalid_collection_errors(
+    collection_repo: impl CollectionRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    insert_collections(&collection_repo, &recipe_repo, &ingredient_repo, &[]).await;
+    let collection_repo: CollectionRepositoryService = Arc::new(Box::new(collection_repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+    let recipe = recipe_fixture();
+    let missing = Uuid::now_v7();
+
+    let error = create_collection(
+        collection_repo.clone(),
+        recipe_repo.clone(),
+        None,
+        &CreateCollection {
+            name: "   ".to_string(),
+            ..input(vec![], None)
+        },
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(
+        error,
+        CreateCollectionError::ValidationError(ValidationError::EmptyField(_))
+    ));
+
+    let error = create_collection(
+        collection_repo.clone(),
+        recipe_repo.clone(),
+        None,
+        &input(vec![recipe.id, missing], None),
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(error, CreateCollectionError::RecipeNotFound(id) if id == missing));
+
+    let error = create_collection(
+        collection_repo.clone(),
+        recipe_repo.clone(),
+        None,
+        &input(vec![recipe.id, recipe.id], None),
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(error, CreateCollectionError::DuplicateRecipe(id) if id == recipe.id));
+
+    let error = create_collection(
+        collection_repo.clone(),
+        recipe_repo,
+        None,
+        &input(vec![], Some(recipe.id)),
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(error, CreateCollectionError::CoverNotInCollection(id) if id == recipe.id));
+
+    assert_eq!(collection_repo.get_all().await.unwrap(), vec![]);
+}
+
+pub async fn creating_a_collection_with_a_private_recipe_of_someone_else_errors(
+    collection_repo: impl CollectionRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    insert_users(&user_repo).await;
+    insert_collections(&collection_repo, &recipe_repo, &ingredient_repo, &[]).await;
+    let private = Recipe {
+        visibility: Visibility::Private,
+        owner_id: Some(user_fixture().id),
+        ..another_recipe_fixture(1)
+    };
+    recipe_repo.insert(private.clone()).await.unwrap();
+    let collection_repo: CollectionRepositoryService = Arc::new(Box::new(collection_repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let error = create_collection(
+        collection_repo,
+        recipe_repo,
+        Some(&another_user_fixture().id),
+        &input(vec![recipe_fixture().id, private.id], None),
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(error, CreateCollectionError::RecipeNotFound(id) if id == private.id));
+}
+
+pub async fn creating_a_collection_makes_the_user_its_owner(
+    collection_repo: impl CollectionRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    insert_users(&user_repo).await;
+    insert_collections(&collection_repo, &recipe_repo, &ingredient_repo, &[]).await;
+    let collection_repo: CollectionRepositoryService = Arc::new(Box::new(collection_repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let result = create_collection(
+        collection_repo.clone(),
+        recipe_repo,
+        Some(&user_fixture().id),
+        &input(vec![recipe_fixture().id], None),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(result.owner_id, Some(user_fixture().id));
+    assert_eq!(
+        collection_repo
+            .get_by_id(&result.id)
+            .await
+            .unwrap()
+            .owner_id,
+        Some(user_fixture().id)
+    );
+}
@@ -0,0 +1,105 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::{
+        collection::in_memory::InMemoryCollectionRepository,
+        ingredients::in_memory::InMemoryIngredientRepository,
+        recipe::in_memory::InMemoryRecipeRepository, user::in_memory::InMemoryUserRepository,
+    };
+
+    #[tokio::test]
+    async fn creating_a_collection_works() {
+        __tests__::creating_a_collection_works(
+            InMemoryCollectionRepository::new(),
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn creating_a_collection_with_a_private_recipe_of_someone_else_errors() {
+        __tests__::creating_a_collection_with_a_private_recipe_of_someone_else_errors(
+            InMemoryCollectionRepository::new(),
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+            InMemoryUserRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn creating_a_collection_makes_the_user_its_owner() {
+        __tests__::creating_a_collection_makes_the_user_its_owner(
+            InMemoryCollectionRepository::new(),
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+            InMemoryUserRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn creating_an_invalid_collection_errors() {
+        __tests__::creating_an_invalid_collection_errors(
+            InMemoryCollectionRepository::new(),
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+        )
+        .await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        collection::postgres::PostgresCollectionRepository,
+        ingredients::postgres::PostgresIngredientRepository,
+        recipe::postgres::PostgresRecipeRepository, user::postgres::PostgresUserRepository,
+    };
+
+    #[sqlx::test]
+    async fn creating_a_collection_works(pool: PgPool) {
+        __tests__::creating_a_collection_works(
+            PostgresCollectionRepository::new(pool.clone()),
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn creating_a_collection_with_a_private_recipe_of_someone_else_errors(pool: PgPool) {
+        __tests__::creating_a_collection_with_a_private_recipe_of_someone_else_errors(
+            PostgresCollectionRepository::new(pool.clone()),
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresUserRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn creating_a_collection_makes_the_user_its_owner(pool: PgPool) {
+        __tests__::creating_a_collection_makes_the_user_its_owner(
+            PostgresCollectionRepository::new(pool.clone()),
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresUserRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn creating_an_invalid_collection_errors(pool: PgPool) {
+        __tests__::creating_an_invalid_collection_errors(
+            PostgresCollectionRepository::new(pool.clone()),
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool),
+        )
+        .await
+    }
+}
@@ -0,0 +1,56 @@
+use uuid::Uuid;
+
+use crate::domain::repositories::collection::{
+    errors::{DeleteCollectionError as DeleteCollectionErrorInternal, GetCollectionError},
+    CollectionRepositoryService,
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum DeleteCollectionError {
+    #[error("Could not find the collection with the ID {0}")]
+    NotFound(Uuid),
+
+    #[error("Only the owner can make changes to the collection with ID {0}")]
+    NotOwner(Uuid),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<DeleteCollectionErrorInternal> for DeleteCollectionError {
+    fn from(value: DeleteCollectionErrorInternal) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+impl From<GetCollectionError> for DeleteCollectionError {
+    fn from(value: GetCollectionError) -> Self {
+        match value {
+            GetCollectionError::NotFound(id) => Self::NotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+#[tracing::instrument("[COMMAND] Deleting a collection", skip(collection_repo))]
+pub async fn delete_collection(
+    collection_repo: CollectionRepositoryService,
+    user_id: Option<&Uuid>,
+    id: &Uuid,
+) -> Result<(), DeleteCollectionError> {
+    let collection = collection_repo.get_by_id(id).await?;
+    if !collection.is_visible_to(user_id) {
+        return Err(DeleteCollectionError::NotFound(*id));
+    }
+
+    if !collection.is_editable_by(user_id) {
+        return Err(DeleteCollectionError::NotOwner(*id));
+    }
+
+    collection_repo.delete(&collection).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,105 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        commands::collections::delete::{delete_collection, DeleteCollectionError},
+        entities::{collection::Collection, visibility::Visibility},
+        repositories::{
+            collection::{
+                errors::GetCollectionError, CollectionRepository, CollectionRepositoryService,
+            },
+            ingredients::IngredientRepository,
+            recipe::RecipeRepository,
+            user::UserRepository,
+        },
+    },
+    test_utils::{
+        another_user_fixture, collection_fixture, insert_collections, insert_users,
+        private_collection_fixture, recipe_fixture, user_fixture,
+    },
+};
+
+pub async fn deleting_a_collection_works(
+    collection_repo: impl CollectionRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let collection = collection_fixture();
+    insert_collections(
+        &collection_repo,
+        &recipe_repo,
+        &ingredient_repo,
+        std::slice::from_ref(&collection),
+    )
+    .await;
+    let collection_repo: CollectionRepositoryService = Arc::new(Box::new(collection_repo));
+
+    delete_collection(collection_repo.clone(), None, &collection.id)
+        .await
+        .unwrap();
+
+    let error = collection_repo.get_by_id(&collection.id).await.unwrap_err();
+
+    assert!(matches!(error, GetCollectionError::NotFound(id) if id == collection.id));
+
+    // The recipes of the collection stay where they were
+    recipe_repo.get_by_id(&recipe_fixture().id).await.unwrap();
+}
+
+pub async fn deleting_a_nonexistent_collection_fails(collection_repo: impl CollectionRepository) {
+    let collection_repo: CollectionRepositoryService = Arc::new(Box::new(collection_repo));
+    let id = Uuid::now_v7();
+
+    let error = delete_collection(collection_repo, None, &id)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, DeleteCollectionError::NotFound(e) if e == id));
+}
+
+pub async fn deleting_a_collection_of_someone_else_errors(
+    collection_repo: impl CollectionRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let private = private_collection_fixture();
+    let public = Collection {
+        id: Uuid::from_u128(1025),
+        visibility: Visibility::Public,
+        ..private_collection_fixture()
+    };
+    insert_users(&user_repo).await;
+    insert_collections(
+        &collection_repo,
+        &recipe_repo,
+        &ingredient_repo,
+        &[private.clone(), public.clone()],
+    )
+    .await;
+    let collection_repo: CollectionRepositoryService = Arc::new(Box::new(collection_repo));
+
+    let error = delete_collection(
+        collection_repo.clone(),
+        Some(&another_user_fixture().id),
+        &private.id,
+    )
+    .await
+    .unwrap_err();
+    assert!(matches!(error, DeleteCollectionError::NotFound(id) if id == private.id));
+
+    for user_id in [None, Some(&another_user_fixture().id)] {
+        let error = delete_collection(collection_repo.clone(), user_id, &public.id)
+            .await
+            .unwrap_err();
+        assert!(matches!(error, DeleteCollectionError::NotOwner(id) if id == public.id));
+    }
+
+    collection_repo.get_by_id(&public.id).await.unwrap();
+
+    delete_collection(collection_repo, Some(&user_fixture().id), &private.id)
+        .await
+        .unwrap();
+}
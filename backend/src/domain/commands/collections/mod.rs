@@ -0,0 +1,5 @@
+pub mod add_recipe;
+pub mod create;
+pub mod delete;
+pub mod remove_recipe;
+pub mod update;
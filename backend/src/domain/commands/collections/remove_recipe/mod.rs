@@ -0,0 +1,76 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::collection::Collection,
+    repositories::collection::{
+        errors::{
+            GetCollectionError,
+            RemoveRecipeFromCollectionError as RemoveRecipeFromCollectionErrorInternal,
+        },
+        CollectionRepositoryService,
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum RemoveRecipeFromCollectionError {
+    #[error("Could not find the collection with the ID {0}")]
+    NotFound(Uuid),
+
+    #[error("Only the owner can make changes to the collection with ID {0}")]
+    NotOwner(Uuid),
+
+    #[error("The recipe with the ID {0} is not in the collection")]
+    RecipeNotInCollection(Uuid),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Report),
+}
+
+impl From<GetCollectionError> for RemoveRecipeFromCollectionError {
+    fn from(value: GetCollectionError) -> Self {
+        match value {
+            GetCollectionError::NotFound(id) => Self::NotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<RemoveRecipeFromCollectionErrorInternal> for RemoveRecipeFromCollectionError {
+    fn from(value: RemoveRecipeFromCollectionErrorInternal) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+#[tracing::instrument("[COMMAND] Removing a recipe from a collection", skip(collection_repo))]
+pub async fn remove_recipe_from_collection(
+    collection_repo: CollectionRepositoryService,
+    user_id: Option<&Uuid>,
+    id: &Uuid,
+    recipe_id: &Uuid,
+) -> Result<Collection, RemoveRecipeFromCollectionError> {
+    let collection = collection_repo.get_by_id(id).await?;
+    if !collection.is_visible_to(user_id) {
+        return Err(RemoveRecipeFromCollectionError::NotFound(*id));
+    }
+
+    if !collection.is_editable_by(user_id) {
+        return Err(RemoveRecipeFromCollectionError::NotOwner(*id));
+    }
+
+    if !collection.contains(recipe_id) {
+        return Err(RemoveRecipeFromCollectionError::RecipeNotInCollection(
+            *recipe_id,
+        ));
+    }
+
+    collection_repo
+        .remove_recipe(&collection, recipe_id)
+        .await?;
+
+    let collection = collection_repo.get_by_id(id).await?;
+
+    Ok(collection)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,162 @@
+use std::sync::Arc;
+
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        commands::collections::remove_recipe::{
+            remove_recipe_from_collection, RemoveRecipeFromCollectionError,
+        },
+        entities::{collection::Collection, visibility::Visibility},
+        repositories::{
+            collection::{CollectionRepository, CollectionRepositoryService},
+            ingredients::IngredientRepository,
+            recipe::RecipeRepository,
+            user::UserRepository,
+        },
+    },
+    test_utils::{
+        another_recipe_fixture, another_user_fixture, collection_fixture,
+        insert_all_ingredients_of_recipe, insert_collections, insert_users,
+        private_collection_fixture, recipe_fixture, user_fixture,
+    },
+};
+
+/// A collection of three recipes, with the recipe fixture in the middle and on the cover
+async fn insert_three_recipe_collection(
+    collection_repo: &impl CollectionRepository,
+    recipe_repo: &impl RecipeRepository,
+    ingredient_repo: &impl IngredientRepository,
+) -> Collection {
+    let recipes = [
+        another_recipe_fixture(1),
+        recipe_fixture(),
+        another_recipe_fixture(2),
+    ];
+    insert_all_ingredients_of_recipe(ingredient_repo, &recipes[0]).await;
+    for recipe in &recipes {
+        recipe_repo.insert(recipe.clone()).await.unwrap();
+    }
+
+    let collection = Collection {
+        recipes: recipes.iter().map(|recipe| recipe.id).collect(),
+        ..collection_fixture()
+    };
+    collection_repo.insert(collection.clone()).await.unwrap();
+
+    collection
+}
+
+pub async fn removing_recipes_from_a_collection_works(
+    collection_repo: impl CollectionRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let collection =
+        insert_three_recipe_collection(&collection_repo, &recipe_repo, &ingredient_repo).await;
+    let collection_repo: CollectionRepositoryService = Arc::new(Box::new(collection_repo));
+    let [first, middle, last] = collection.recipes[..] else {
+        panic!("The collection should have three recipes");
+    };
+
+    let result =
+        remove_recipe_from_collection(collection_repo.clone(), None, &collection.id, &middle)
+            .await
+            .unwrap();
+
+    assert_eq!(result.recipes, vec![first, last]);
+    assert_eq!(result.cover_recipe_id, None);
+    assert!(result.updated_at > collection.updated_at);
+
+    let result = remove_recipe_from_collection(collection_repo, None, &collection.id, &first)
+        .await
+        .unwrap();
+
+    assert_eq!(result.recipes, vec![last]);
+}
+
+pub async fn removing_a_recipe_that_is_not_in_the_collection_errors(
+    collection_repo: impl CollectionRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let collection =
+        insert_three_recipe_collection(&collection_repo, &recipe_repo, &ingredient_repo).await;
+    let collection_repo: CollectionRepositoryService = Arc::new(Box::new(collection_repo));
+    let missing = Uuid::now_v7();
+
+    let error =
+        remove_recipe_from_collection(collection_repo.clone(), None, &collection.id, &missing)
+            .await
+            .unwrap_err();
+
+    assert!(
+        matches!(error, RemoveRecipeFromCollectionError::RecipeNotInCollection(id) if id == missing)
+    );
+
+    let error =
+        remove_recipe_from_collection(collection_repo, None, &missing, &collection.recipes[0])
+            .await
+            .unwrap_err();
+
+    assert!(matches!(error, RemoveRecipeFromCollectionError::NotFound(id) if id == missing));
+}
+
+pub async fn removing_a_recipe_from_a_collection_of_someone_else_errors(
+    collection_repo: impl CollectionRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let private = private_collection_fixture();
+    let public = Collection {
+        id: Uuid::from_u128(1025),
+        visibility: Visibility::Public,
+        ..private_collection_fixture()
+    };
+    let recipe_id = recipe_fixture().id;
+    insert_users(&user_repo).await;
+    insert_collections(
+        &collection_repo,
+        &recipe_repo,
+        &ingredient_repo,
+        &[private.clone(), public.clone()],
+    )
+    .await;
+    let collection_repo: CollectionRepositoryService = Arc::new(Box::new(collection_repo));
+
+    let error = remove_recipe_from_collection(
+        collection_repo.clone(),
+        Some(&another_user_fixture().id),
+        &private.id,
+        &recipe_id,
+    )
+    .await
+    .unwrap_err();
+    assert!(matches!(error, RemoveRecipeFromCollectionError::NotFound(id) if id == private.id));
+
+    for user_id in [None, Some(&another_user_fixture().id)] {
+        let error =
+            remove_recipe_from_collection(collection_repo.clone(), user_id, &public.id, &recipe_id)
+                .await
+                .unwrap_err();
+        assert!(matches!(error, RemoveRecipeFromCollectionError::NotOwner(id) if id == public.id));
+    }
+
+    assert!(collection_repo
+        .get_by_id(&public.id)
+        .await
+        .unwrap()
+        .contains(&recipe_id));
+
+    let result = remove_recipe_from_collection(
+        collection_repo,
+        Some(&user_fixture().id),
+        &public.id,
+        &recipe_id,
+    )
+    .await
+    .unwrap();
+    assert!(!result.contains(&recipe_id));
+}
@@ -0,0 +1,83 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::{
+        collection::in_memory::InMemoryCollectionRepository,
+        ingredients::in_memory::InMemoryIngredientRepository,
+        recipe::in_memory::InMemoryRecipeRepository, user::in_memory::InMemoryUserRepository,
+    };
+
+    #[tokio::test]
+    async fn removing_recipes_from_a_collection_works() {
+        __tests__::removing_recipes_from_a_collection_works(
+            InMemoryCollectionRepository::new(),
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn removing_a_recipe_from_a_collection_of_someone_else_errors() {
+        __tests__::removing_a_recipe_from_a_collection_of_someone_else_errors(
+            InMemoryCollectionRepository::new(),
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+            InMemoryUserRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn removing_a_recipe_that_is_not_in_the_collection_errors() {
+        __tests__::removing_a_recipe_that_is_not_in_the_collection_errors(
+            InMemoryCollectionRepository::new(),
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+        )
+        .await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        collection::postgres::PostgresCollectionRepository,
+        ingredients::postgres::PostgresIngredientRepository,
+        recipe::postgres::PostgresRecipeRepository, user::postgres::PostgresUserRepository,
+    };
+
+    #[sqlx::test]
+    async fn removing_recipes_from_a_collection_works(pool: PgPool) {
+        __tests__::removing_recipes_from_a_collection_works(
+            PostgresCollectionRepository::new(pool.clone()),
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn removing_a_recipe_from_a_collection_of_someone_else_errors(pool: PgPool) {
+        __tests__::removing_a_recipe_from_a_collection_of_someone_else_errors(
+            PostgresCollectionRepository::new(pool.clone()),
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresUserRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn removing_a_recipe_that_is_not_in_the_collection_errors(pool: PgPool) {
+        __tests__::removing_a_recipe_that_is_not_in_the_collection_errors(
+            PostgresCollectionRepository::new(pool.clone()),
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool),
+        )
+        .await
+    }
+}
@@ -0,0 +1,110 @@
+use common::UpdateCollectionDTO;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        collection::{Collection, CollectionChangeset},
+        recipe::errors::ValidationError,
+    },
+    repositories::collection::{
+        errors::{GetCollectionError, UpdateCollectionError as UpdateCollectionErrorInternal},
+        CollectionRepositoryService,
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum UpdateCollectionError {
+    #[error("Could not find the collection with the ID {0}")]
+    NotFound(Uuid),
+
+    #[error("Only the owner can make changes to the collection with ID {0}")]
+    NotOwner(Uuid),
+
+    #[error("The cover recipe with the ID {0} is not in the collection")]
+    CoverNotInCollection(Uuid),
+
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Report),
+}
+
+impl From<GetCollectionError> for UpdateCollectionError {
+    fn from(value: GetCollectionError) -> Self {
+        match value {
+            GetCollectionError::NotFound(id) => Self::NotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<UpdateCollectionErrorInternal> for UpdateCollectionError {
+    fn from(value: UpdateCollectionErrorInternal) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+impl From<UpdateCollectionDTO> for CollectionChangeset {
+    fn from(value: UpdateCollectionDTO) -> Self {
+        Self {
+            name: value.name,
+            description: value.description,
+            visibility: value.visibility.map(|visibility| visibility.into()),
+            cover_recipe_id: value.cover_recipe_id,
+        }
+    }
+}
+
+/// Only the owner can change the visibility, collections without an owner can be edited by everyone
+#[tracing::instrument("[COMMAND] Updating a collection", skip(collection_repo))]
+pub async fn update_collection(
+    collection_repo: CollectionRepositoryService,
+    user_id: Option<&Uuid>,
+    id: &Uuid,
+    mut changeset: CollectionChangeset,
+) -> Result<Collection, UpdateCollectionError> {
+    if changeset.is_empty() {
+        return Err(ValidationError::EmptyField(vec![
+            "name",
+            "description",
+            "visibility",
+            "cover_recipe_id",
+        ])
+        .into());
+    }
+
+    if let Some(name) = changeset.name.as_mut() {
+        *name = name.trim().to_string();
+
+        if name.is_empty() {
+            return Err(ValidationError::EmptyField(vec!["name"]).into());
+        }
+    }
+
+    let collection = collection_repo.get_by_id(id).await?;
+    if !collection.is_visible_to(user_id) {
+        return Err(UpdateCollectionError::NotFound(*id));
+    }
+
+    if !collection.is_editable_by(user_id)
+        || (changeset.visibility.is_some() && !collection.is_owned_by(user_id))
+    {
+        return Err(UpdateCollectionError::NotOwner(*id));
+    }
+
+    if let Some(cover_recipe_id) = changeset.cover_recipe_id {
+        if !collection.contains(&cover_recipe_id) {
+            return Err(UpdateCollectionError::CoverNotInCollection(cover_recipe_id));
+        }
+    }
+
+    collection_repo.update(&collection, changeset).await?;
+
+    let collection = collection_repo.get_by_id(id).await?;
+
+    Ok(collection)
+}
+
+#[cfg(test)]
+mod tests;
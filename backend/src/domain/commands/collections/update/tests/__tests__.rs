@@ -0,0 +1,207 @@
+use std::sync::Arc;
+
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        commands::collections::update::{update_collection, UpdateCollectionError},
+        entities::{
+            collection::{Collection, CollectionChangeset},
+            recipe::errors::ValidationError,
+            visibility::Visibility,
+        },
+        repositories::{
+            collection::{CollectionRepository, CollectionRepositoryService},
+            ingredients::IngredientRepository,
+            recipe::RecipeRepository,
+            user::UserRepository,
+        },
+    },
+    test_utils::{
+        another_user_fixture, collection_fixture, insert_collections, insert_users,
+        private_collection_fixture, user_fixture,
+    },
+};
+
+pub async fn updating_a_collection_works(
+    collection_repo: impl CollectionRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let collection = Collection {
+        cover_recipe_id: None,
+        owner_id: Some(user_fixture().id),
+        ..collection_fixture()
+    };
+    insert_users(&user_repo).await;
+    insert_collections(
+        &collection_repo,
+        &recipe_repo,
+        &ingredient_repo,
+        std::slice::from_ref(&collection),
+    )
+    .await;
+    let collection_repo: CollectionRepositoryService = Arc::new(Box::new(collection_repo));
+
+    let changeset = CollectionChangeset {
+        name: Some(" Weekend dinners ".to_string()),
+        visibility: Some(Visibility::Private),
+        cover_recipe_id: Some(collection.recipes[0]),
+        ..Default::default()
+    };
+
+    let result = update_collection(
+        collection_repo,
+        Some(&user_fixture().id),
+        &collection.id,
+        changeset,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(result.name, "Weekend dinners");
+    assert_eq!(result.visibility, Visibility::Private);
+    assert_eq!(result.cover_recipe_id, Some(collection.recipes[0]));
+    assert_eq!(result.description, collection.description);
+    assert_eq!(result.recipes, collection.recipes);
+    assert!(result.updated_at > collection.updated_at);
+}
+
+pub async fn updating_a_collection_with_invalid_changes_errors(
+    collection_repo: impl CollectionRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let collection = collection_fixture();
+    insert_collections(
+        &collection_repo,
+        &recipe_repo,
+        &ingredient_repo,
+        std::slice::from_ref(&collection),
+    )
+    .await;
+    let collection_repo: CollectionRepositoryService = Arc::new(Box::new(collection_repo));
+
+    let error = update_collection(
+        collection_repo.clone(),
+        None,
+        &collection.id,
+        CollectionChangeset::default(),
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(
+        error,
+        UpdateCollectionError::ValidationError(ValidationError::EmptyField(_))
+    ));
+
+    let missing = Uuid::now_v7();
+    let changeset = CollectionChangeset {
+        cover_recipe_id: Some(missing),
+        ..Default::default()
+    };
+
+    let error = update_collection(collection_repo.clone(), None, &collection.id, changeset)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, UpdateCollectionError::CoverNotInCollection(id) if id == missing));
+
+    let changeset = CollectionChangeset {
+        description: Some("Nothing to see here".to_string()),
+        ..Default::default()
+    };
+
+    let error = update_collection(collection_repo, None, &missing, changeset)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, UpdateCollectionError::NotFound(id) if id == missing));
+}
+
+pub async fn editing_a_collection_of_someone_else_errors(
+    collection_repo: impl CollectionRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let private = private_collection_fixture();
+    let public = Collection {
+        id: Uuid::from_u128(1025),
+        visibility: Visibility::Public,
+        ..private_collection_fixture()
+    };
+    let ownerless = Collection {
+        id: Uuid::from_u128(1026),
+        ..collection_fixture()
+    };
+    insert_users(&user_repo).await;
+    insert_collections(
+        &collection_repo,
+        &recipe_repo,
+        &ingredient_repo,
+        &[private.clone(), public.clone(), ownerless.clone()],
+    )
+    .await;
+    let collection_repo: CollectionRepositoryService = Arc::new(Box::new(collection_repo));
+    let rename = || CollectionChangeset {
+        name: Some("Mine now".to_string()),
+        ..Default::default()
+    };
+
+    let error = update_collection(
+        collection_repo.clone(),
+        Some(&another_user_fixture().id),
+        &private.id,
+        rename(),
+    )
+    .await
+    .unwrap_err();
+    assert!(matches!(error, UpdateCollectionError::NotFound(id) if id == private.id));
+
+    for user_id in [None, Some(&another_user_fixture().id)] {
+        let error = update_collection(collection_repo.clone(), user_id, &public.id, rename())
+            .await
+            .unwrap_err();
+        assert!(matches!(error, UpdateCollectionError::NotOwner(id) if id == public.id));
+    }
+
+    // Collections without an owner can be edited by everyone, but nobody gets to hide them
+    let error = update_collection(
+        collection_repo.clone(),
+        Some(&another_user_fixture().id),
+        &ownerless.id,
+        CollectionChangeset {
+            visibility: Some(Visibility::Private),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap_err();
+    assert!(matches!(error, UpdateCollectionError::NotOwner(id) if id == ownerless.id));
+
+    update_collection(
+        collection_repo.clone(),
+        Some(&another_user_fixture().id),
+        &ownerless.id,
+        rename(),
+    )
+    .await
+    .unwrap();
+
+    let result = update_collection(
+        collection_repo,
+        Some(&user_fixture().id),
+        &private.id,
+        CollectionChangeset {
+            visibility: Some(Visibility::Public),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(result.visibility, Visibility::Public);
+}
@@ -0,0 +1,67 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::cooking_session::CookingSession,
+    repositories::{
+        cooking_session::{
+            errors::{GetCookingSessionError, InsertCookingSessionError},
+            CookingSessionRepositoryService,
+        },
+        recipe::{errors::GetRecipeByIdError, RecipeRepositoryService},
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum CreateCookingSessionError {
+    #[error("Could not find the recipe with the ID {0}")]
+    RecipeNotFound(Uuid),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetRecipeByIdError> for CreateCookingSessionError {
+    fn from(value: GetRecipeByIdError) -> Self {
+        match value {
+            GetRecipeByIdError::NotFound(id) => Self::RecipeNotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<InsertCookingSessionError> for CreateCookingSessionError {
+    fn from(value: InsertCookingSessionError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+impl From<GetCookingSessionError> for CreateCookingSessionError {
+    fn from(value: GetCookingSessionError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+/// Starts cooking the recipe from its first step
+#[tracing::instrument(
+    "[COMMAND] Creating a new cooking session",
+    skip(session_repo, recipe_repo)
+)]
+pub async fn create_cooking_session(
+    session_repo: CookingSessionRepositoryService,
+    recipe_repo: RecipeRepositoryService,
+    user_id: Option<&Uuid>,
+    recipe_id: &Uuid,
+) -> Result<CookingSession, CreateCookingSessionError> {
+    let recipe = recipe_repo.get_visible(user_id, recipe_id, None).await?;
+    let session = CookingSession::new(&recipe);
+    let id = session.id;
+
+    session_repo.insert(session).await?;
+
+    let session = session_repo.get_by_id(&id).await?;
+
+    Ok(session)
+}
+
+#[cfg(test)]
+mod tests;
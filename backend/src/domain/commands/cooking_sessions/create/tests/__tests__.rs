@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        commands::cooking_sessions::create::{create_cooking_session, CreateCookingSessionError},
+        repositories::{
+            cooking_session::{CookingSessionRepository, CookingSessionRepositoryService},
+            ingredients::IngredientRepository,
+            recipe::RecipeRepository,
+        },
+    },
+    test_utils::{insert_all_ingredients_of_recipe, recipe_fixture},
+};
+
+pub async fn creating_a_cooking_session_works(
+    session_repo: impl CookingSessionRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let recipe = recipe_fixture();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+    let session_repo: CookingSessionRepositoryService = Arc::new(Box::new(session_repo));
+
+    let session = create_cooking_session(
+        session_repo.clone(),
+        Arc::new(Box::new(recipe_repo)),
+        None,
+        &recipe.id,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(session.recipe_id, recipe.id);
+    assert_eq!(session.current_step, 0);
+    assert!(session.checked_ingredients.is_empty());
+    assert!(session.timers.is_empty());
+
+    let stored = session_repo.get_by_id(&session.id).await.unwrap();
+    assert_eq!(stored, session);
+}
+
+pub async fn creating_a_cooking_session_of_nonexistent_recipe_errors(
+    session_repo: impl CookingSessionRepository,
+    recipe_repo: impl RecipeRepository,
+) {
+    let recipe_id = Uuid::from_u128(404);
+
+    let error = create_cooking_session(
+        Arc::new(Box::new(session_repo)),
+        Arc::new(Box::new(recipe_repo)),
+        None,
+        &recipe_id,
+    )
+    .await
+    .unwrap_err();
+
+    match error {
+        CreateCookingSessionError::RecipeNotFound(id) => assert_eq!(id, recipe_id),
+        e => panic!("Wrong error returned: {e}"),
+    }
+}
@@ -0,0 +1,59 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::{
+        cooking_session::in_memory::InMemoryCookingSessionRepository,
+        ingredients::in_memory::InMemoryIngredientRepository,
+        recipe::in_memory::InMemoryRecipeRepository,
+    };
+
+    #[tokio::test]
+    async fn creating_a_cooking_session_works() {
+        __tests__::creating_a_cooking_session_works(
+            InMemoryCookingSessionRepository::new(),
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn creating_a_cooking_session_of_nonexistent_recipe_errors() {
+        __tests__::creating_a_cooking_session_of_nonexistent_recipe_errors(
+            InMemoryCookingSessionRepository::new(),
+            InMemoryRecipeRepository::new(),
+        )
+        .await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        cooking_session::postgres::PostgresCookingSessionRepository,
+        ingredients::postgres::PostgresIngredientRepository,
+        recipe::postgres::PostgresRecipeRepository,
+    };
+
+    #[sqlx::test]
+    async fn creating_a_cooking_session_works(pool: PgPool) {
+        __tests__::creating_a_cooking_session_works(
+            PostgresCookingSessionRepository::new(pool.clone()),
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn creating_a_cooking_session_of_nonexistent_recipe_errors(pool: PgPool) {
+        __tests__::creating_a_cooking_session_of_nonexistent_recipe_errors(
+            PostgresCookingSessionRepository::new(pool.clone()),
+            PostgresRecipeRepository::new(pool),
+        )
+        .await
+    }
+}
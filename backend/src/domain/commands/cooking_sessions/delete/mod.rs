@@ -0,0 +1,56 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    repositories::cooking_session::{
+        errors::{
+            DeleteCookingSessionError as DeleteCookingSessionErrorInternal, GetCookingSessionError,
+        },
+        CookingSessionRepositoryService,
+    },
+    services::cooking_sessions::CookingSessionHub,
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum DeleteCookingSessionError {
+    #[error("Could not find the cooking session with the ID {0}")]
+    NotFound(Uuid),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetCookingSessionError> for DeleteCookingSessionError {
+    fn from(value: GetCookingSessionError) -> Self {
+        match value {
+            GetCookingSessionError::NotFound(id) => Self::NotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<DeleteCookingSessionErrorInternal> for DeleteCookingSessionError {
+    fn from(value: DeleteCookingSessionErrorInternal) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+/// Ends the session, disconnecting every device that takes part in it
+#[tracing::instrument("[COMMAND] Deleting a cooking session", skip(session_repo, hub))]
+pub async fn delete_cooking_session(
+    session_repo: CookingSessionRepositoryService,
+    hub: CookingSessionHub,
+    session_id: &Uuid,
+) -> Result<(), DeleteCookingSessionError> {
+    // Sessions that don't exist get no channel in the hub
+    session_repo.get_by_id(session_id).await?;
+    let _guard = hub.lock(session_id).await;
+
+    let session = session_repo.get_by_id(session_id).await?;
+    session_repo.delete(&session).await?;
+    hub.close(session_id);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,60 @@
+use std::sync::Arc;
+
+use pretty_assertions::assert_eq;
+use tokio::sync::broadcast::error::RecvError;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        commands::cooking_sessions::delete::{delete_cooking_session, DeleteCookingSessionError},
+        repositories::{
+            cooking_session::{
+                errors::GetCookingSessionError, CookingSessionRepository,
+                CookingSessionRepositoryService,
+            },
+            ingredients::IngredientRepository,
+            recipe::RecipeRepository,
+        },
+        services::cooking_sessions::CookingSessionHub,
+    },
+    test_utils::insert_cooking_session,
+};
+
+pub async fn deleting_a_cooking_session_disconnects_devices(
+    session_repo: impl CookingSessionRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let session = insert_cooking_session(&session_repo, &recipe_repo, &ingredient_repo).await;
+    let session_repo: CookingSessionRepositoryService = Arc::new(Box::new(session_repo));
+    let hub = CookingSessionHub::new();
+    let mut device = hub.subscribe(&session.id);
+
+    delete_cooking_session(session_repo.clone(), hub, &session.id)
+        .await
+        .unwrap();
+
+    assert!(matches!(device.recv().await, Err(RecvError::Closed)));
+
+    let error = session_repo.get_by_id(&session.id).await.unwrap_err();
+    assert!(matches!(error, GetCookingSessionError::NotFound(_)));
+}
+
+pub async fn deleting_a_nonexistent_cooking_session_errors(
+    session_repo: impl CookingSessionRepository,
+) {
+    let session_id = Uuid::from_u128(404);
+
+    let error = delete_cooking_session(
+        Arc::new(Box::new(session_repo)),
+        CookingSessionHub::new(),
+        &session_id,
+    )
+    .await
+    .unwrap_err();
+
+    match error {
+        DeleteCookingSessionError::NotFound(id) => assert_eq!(id, session_id),
+        e => panic!("Wrong error returned: {e}"),
+    }
+}
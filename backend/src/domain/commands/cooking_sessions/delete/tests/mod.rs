@@ -0,0 +1,57 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::{
+        cooking_session::in_memory::InMemoryCookingSessionRepository,
+        ingredients::in_memory::InMemoryIngredientRepository,
+        recipe::in_memory::InMemoryRecipeRepository,
+    };
+
+    #[tokio::test]
+    async fn deleting_a_cooking_session_disconnects_devices() {
+        __tests__::deleting_a_cooking_session_disconnects_devices(
+            InMemoryCookingSessionRepository::new(),
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn deleting_a_nonexistent_cooking_session_errors() {
+        __tests__::deleting_a_nonexistent_cooking_session_errors(
+            InMemoryCookingSessionRepository::new(),
+        )
+        .await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        cooking_session::postgres::PostgresCookingSessionRepository,
+        ingredients::postgres::PostgresIngredientRepository,
+        recipe::postgres::PostgresRecipeRepository,
+    };
+
+    #[sqlx::test]
+    async fn deleting_a_cooking_session_disconnects_devices(pool: PgPool) {
+        __tests__::deleting_a_cooking_session_disconnects_devices(
+            PostgresCookingSessionRepository::new(pool.clone()),
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn deleting_a_nonexistent_cooking_session_errors(pool: PgPool) {
+        __tests__::deleting_a_nonexistent_cooking_session_errors(
+            PostgresCookingSessionRepository::new(pool),
+        )
+        .await
+    }
+}
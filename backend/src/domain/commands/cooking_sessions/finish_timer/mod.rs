@@ -0,0 +1,83 @@
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::cooking_session::{CookingSession, CookingTimer},
+    repositories::cooking_session::{
+        errors::{GetCookingSessionError, UpdateCookingSessionError},
+        CookingSessionRepositoryService,
+    },
+    services::cooking_sessions::CookingSessionHub,
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum FinishCookingTimerError {
+    #[error("Could not find the cooking session with the ID {0}")]
+    NotFound(Uuid),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetCookingSessionError> for FinishCookingTimerError {
+    fn from(value: GetCookingSessionError) -> Self {
+        match value {
+            GetCookingSessionError::NotFound(id) => Self::NotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<UpdateCookingSessionError> for FinishCookingTimerError {
+    fn from(value: UpdateCookingSessionError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+/// Marks the timer as done once its time is up and lets every device know.
+/// Returns `None` if the timer was cancelled or already finished in the meantime.
+#[tracing::instrument("[COMMAND] Finishing a cooking timer", skip(session_repo, hub))]
+pub async fn finish_cooking_timer(
+    session_repo: CookingSessionRepositoryService,
+    hub: CookingSessionHub,
+    session_id: &Uuid,
+    timer_id: &Uuid,
+) -> Result<Option<CookingSession>, FinishCookingTimerError> {
+    let _guard = hub.lock(session_id).await;
+
+    let mut session = session_repo.get_by_id(session_id).await?;
+
+    if !session.finish_timer(timer_id, Utc::now()) {
+        return Ok(None);
+    }
+
+    session_repo.update(&session).await?;
+
+    let session = session_repo.get_by_id(session_id).await?;
+    hub.publish(&session);
+
+    Ok(Some(session))
+}
+
+/// Finishes the timer in the background once its time is up. Timers whose time ran out
+/// while nobody was around to finish them, like while the server was down, finish right away.
+pub fn schedule_cooking_timer(
+    session_repo: CookingSessionRepositoryService,
+    hub: CookingSessionHub,
+    session_id: Uuid,
+    timer: &CookingTimer,
+) {
+    let remaining = (timer.ends_at() - Utc::now()).to_std().unwrap_or_default();
+    let timer_id = timer.id;
+
+    tokio::spawn(async move {
+        tokio::time::sleep(remaining).await;
+
+        if let Err(e) = finish_cooking_timer(session_repo, hub, &session_id, &timer_id).await {
+            tracing::warn!("Could not finish the timer {timer_id}: {e}");
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,78 @@
+use std::sync::Arc;
+
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        commands::cooking_sessions::{
+            finish_timer::{finish_cooking_timer, FinishCookingTimerError},
+            update::update_cooking_session,
+        },
+        entities::cooking_session::CookingAction,
+        repositories::{
+            cooking_session::{CookingSessionRepository, CookingSessionRepositoryService},
+            ingredients::IngredientRepository,
+            recipe::RecipeRepository,
+        },
+        services::cooking_sessions::CookingSessionHub,
+    },
+    test_utils::insert_cooking_session,
+};
+
+pub async fn finishing_a_timer_notifies_devices_once(
+    session_repo: impl CookingSessionRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let session = insert_cooking_session(&session_repo, &recipe_repo, &ingredient_repo).await;
+    let session_repo: CookingSessionRepositoryService = Arc::new(Box::new(session_repo));
+    let hub = CookingSessionHub::new();
+    let started = update_cooking_session(
+        session_repo.clone(),
+        Arc::new(Box::new(recipe_repo)),
+        hub.clone(),
+        &session.id,
+        CookingAction::StartTimer("Step 6".to_string()),
+    )
+    .await
+    .unwrap();
+    let timer_id = started.timers[0].id;
+    let mut device = hub.subscribe(&session.id);
+
+    let finished = finish_cooking_timer(session_repo.clone(), hub.clone(), &session.id, &timer_id)
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert!(finished.timers[0].finished);
+    assert_eq!(device.recv().await.unwrap(), finished);
+
+    let again = finish_cooking_timer(session_repo.clone(), hub, &session.id, &timer_id)
+        .await
+        .unwrap();
+    assert_eq!(again, None);
+
+    let stored = session_repo.get_by_id(&session.id).await.unwrap();
+    assert_eq!(stored, finished);
+}
+
+pub async fn finishing_a_timer_of_nonexistent_cooking_session_errors(
+    session_repo: impl CookingSessionRepository,
+) {
+    let session_id = Uuid::from_u128(404);
+
+    let error = finish_cooking_timer(
+        Arc::new(Box::new(session_repo)),
+        CookingSessionHub::new(),
+        &session_id,
+        &Uuid::from_u128(1),
+    )
+    .await
+    .unwrap_err();
+
+    match error {
+        FinishCookingTimerError::NotFound(id) => assert_eq!(id, session_id),
+        e => panic!("Wrong error returned: {e}"),
+    }
+}
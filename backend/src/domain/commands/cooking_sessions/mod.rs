@@ -0,0 +1,5 @@
+pub mod create;
+pub mod delete;
+pub mod finish_timer;
+pub mod reschedule_timers;
+pub mod update;
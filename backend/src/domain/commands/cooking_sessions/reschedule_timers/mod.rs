@@ -0,0 +1,42 @@
+use crate::domain::{
+    commands::cooking_sessions::finish_timer::schedule_cooking_timer,
+    repositories::cooking_session::{
+        errors::GetCookingSessionError, CookingSessionRepositoryService,
+    },
+    services::cooking_sessions::CookingSessionHub,
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum RescheduleCookingTimersError {
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetCookingSessionError> for RescheduleCookingTimersError {
+    fn from(value: GetCookingSessionError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+/// Timers are only scheduled in memory, so they have to be scheduled again when the server starts.
+/// Returns how many timers were scheduled.
+#[tracing::instrument("[COMMAND] Rescheduling cooking timers", skip(session_repo, hub))]
+pub async fn reschedule_cooking_timers(
+    session_repo: CookingSessionRepositoryService,
+    hub: CookingSessionHub,
+) -> Result<usize, RescheduleCookingTimersError> {
+    let sessions = session_repo.get_with_running_timers().await?;
+    let mut scheduled = 0;
+
+    for session in sessions {
+        for timer in session.timers.iter().filter(|timer| !timer.finished) {
+            schedule_cooking_timer(session_repo.clone(), hub.clone(), session.id, timer);
+            scheduled += 1;
+        }
+    }
+
+    Ok(scheduled)
+}
+
+#[cfg(test)]
+mod tests;
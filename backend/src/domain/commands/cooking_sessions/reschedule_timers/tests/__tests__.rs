@@ -0,0 +1,69 @@
+use std::{sync::Arc, time::Duration};
+
+use chrono::{TimeDelta, Utc};
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        commands::cooking_sessions::reschedule_timers::reschedule_cooking_timers,
+        entities::cooking_session::CookingTimer,
+        repositories::{
+            cooking_session::{CookingSessionRepository, CookingSessionRepositoryService},
+            ingredients::IngredientRepository,
+            recipe::RecipeRepository,
+        },
+        services::cooking_sessions::CookingSessionHub,
+    },
+    test_utils::insert_cooking_session,
+};
+
+fn timer(id: u128, duration: Duration, finished: bool) -> CookingTimer {
+    CookingTimer {
+        id: Uuid::from_u128(id),
+        label: format!("Timer {id}"),
+        duration,
+        started_at: Utc::now() - TimeDelta::minutes(30),
+        finished,
+    }
+}
+
+pub async fn timers_that_ran_out_in_the_meantime_finish_right_away(
+    session_repo: impl CookingSessionRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let mut session = insert_cooking_session(&session_repo, &recipe_repo, &ingredient_repo).await;
+    let ran_out = timer(1, Duration::from_secs(10 * 60), false);
+    let running = timer(2, Duration::from_secs(60 * 60), false);
+    let finished = timer(3, Duration::from_secs(5 * 60), true);
+    session.timers = vec![ran_out.clone(), running.clone(), finished];
+    session_repo.update(&session).await.unwrap();
+
+    let session_repo: CookingSessionRepositoryService = Arc::new(Box::new(session_repo));
+    let hub = CookingSessionHub::new();
+    let mut device = hub.subscribe(&session.id);
+
+    let scheduled = reschedule_cooking_timers(session_repo.clone(), hub)
+        .await
+        .unwrap();
+    assert_eq!(scheduled, 2);
+
+    let pushed = device.recv().await.unwrap();
+    let finished: Vec<_> = pushed
+        .timers
+        .iter()
+        .map(|timer| (timer.id, timer.finished))
+        .collect();
+    assert_eq!(
+        finished,
+        vec![
+            (ran_out.id, true),
+            (running.id, false),
+            (Uuid::from_u128(3), true)
+        ]
+    );
+
+    let stored = session_repo.get_by_id(&session.id).await.unwrap();
+    assert_eq!(stored, pushed);
+}
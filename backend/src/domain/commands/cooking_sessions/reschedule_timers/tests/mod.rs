@@ -0,0 +1,41 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::{
+        cooking_session::in_memory::InMemoryCookingSessionRepository,
+        ingredients::in_memory::InMemoryIngredientRepository,
+        recipe::in_memory::InMemoryRecipeRepository,
+    };
+
+    #[tokio::test]
+    async fn timers_that_ran_out_in_the_meantime_finish_right_away() {
+        __tests__::timers_that_ran_out_in_the_meantime_finish_right_away(
+            InMemoryCookingSessionRepository::new(),
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+        )
+        .await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        cooking_session::postgres::PostgresCookingSessionRepository,
+        ingredients::postgres::PostgresIngredientRepository,
+        recipe::postgres::PostgresRecipeRepository,
+    };
+
+    #[sqlx::test]
+    async fn timers_that_ran_out_in_the_meantime_finish_right_away(pool: PgPool) {
+        __tests__::timers_that_ran_out_in_the_meantime_finish_right_away(
+            PostgresCookingSessionRepository::new(pool.clone()),
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool),
+        )
+        .await
+    }
+}
@@ -0,0 +1,88 @@
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::domain::{
+    commands::cooking_sessions::finish_timer::schedule_cooking_timer,
+    entities::cooking_session::{CookingAction, CookingActionError, CookingSession},
+    repositories::{
+        cooking_session::{
+            errors::{
+                GetCookingSessionError,
+                UpdateCookingSessionError as UpdateCookingSessionErrorInternal,
+            },
+            CookingSessionRepositoryService,
+        },
+        recipe::{errors::GetRecipeByIdError, RecipeRepositoryService},
+    },
+    services::cooking_sessions::CookingSessionHub,
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum UpdateCookingSessionError {
+    #[error("Could not find the cooking session with the ID {0}")]
+    NotFound(Uuid),
+
+    #[error(transparent)]
+    InvalidAction(#[from] CookingActionError),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetCookingSessionError> for UpdateCookingSessionError {
+    fn from(value: GetCookingSessionError) -> Self {
+        match value {
+            GetCookingSessionError::NotFound(id) => Self::NotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<GetRecipeByIdError> for UpdateCookingSessionError {
+    fn from(value: GetRecipeByIdError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+impl From<UpdateCookingSessionErrorInternal> for UpdateCookingSessionError {
+    fn from(value: UpdateCookingSessionErrorInternal) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+/// Applies what the cook did to the session and pushes the new state to every connected device.
+/// Starting a timer also schedules it to be finished by the server once its time is up.
+#[tracing::instrument(
+    "[COMMAND] Updating a cooking session",
+    skip(session_repo, recipe_repo, hub)
+)]
+pub async fn update_cooking_session(
+    session_repo: CookingSessionRepositoryService,
+    recipe_repo: RecipeRepositoryService,
+    hub: CookingSessionHub,
+    session_id: &Uuid,
+    action: CookingAction,
+) -> Result<CookingSession, UpdateCookingSessionError> {
+    // Sessions that don't exist get no channel in the hub
+    session_repo.get_by_id(session_id).await?;
+    let _guard = hub.lock(session_id).await;
+
+    let mut session = session_repo.get_by_id(session_id).await?;
+    let recipe = recipe_repo.get_by_id(&session.recipe_id).await?;
+
+    let started = session.apply(&recipe, action, Utc::now())?;
+
+    session_repo.update(&session).await?;
+
+    let session = session_repo.get_by_id(session_id).await?;
+    hub.publish(&session);
+
+    if let Some(timer) = started {
+        schedule_cooking_timer(session_repo, hub, *session_id, &timer);
+    }
+
+    Ok(session)
+}
+
+#[cfg(test)]
+mod tests;
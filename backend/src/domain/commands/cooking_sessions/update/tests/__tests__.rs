@@ -0,0 +1,164 @@
+use std::sync::Arc;
+
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        commands::cooking_sessions::update::{update_cooking_session, UpdateCookingSessionError},
+        entities::cooking_session::{CookingAction, CookingActionError},
+        repositories::{
+            cooking_session::{CookingSessionRepository, CookingSessionRepositoryService},
+            ingredients::IngredientRepository,
+            recipe::{RecipeRepository, RecipeRepositoryService},
+        },
+        services::cooking_sessions::CookingSessionHub,
+    },
+    test_utils::{insert_cooking_session, recipe_fixture},
+};
+
+pub async fn updating_a_cooking_session_notifies_devices(
+    session_repo: impl CookingSessionRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let session = insert_cooking_session(&session_repo, &recipe_repo, &ingredient_repo).await;
+    let session_repo: CookingSessionRepositoryService = Arc::new(Box::new(session_repo));
+    let hub = CookingSessionHub::new();
+    let mut device = hub.subscribe(&session.id);
+    let tofu = recipe_fixture().ingredients[0].ingredient.id;
+
+    update_cooking_session(
+        session_repo.clone(),
+        Arc::new(Box::new(recipe_repo)),
+        hub.clone(),
+        &session.id,
+        CookingAction::CheckIngredient(tofu),
+    )
+    .await
+    .unwrap();
+
+    let pushed = device.recv().await.unwrap();
+    assert!(pushed.checked_ingredients.contains(&tofu));
+
+    let stored = session_repo.get_by_id(&session.id).await.unwrap();
+    assert_eq!(stored, pushed);
+}
+
+pub async fn starting_a_timer_saves_it(
+    session_repo: impl CookingSessionRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let session = insert_cooking_session(&session_repo, &recipe_repo, &ingredient_repo).await;
+    let session_repo: CookingSessionRepositoryService = Arc::new(Box::new(session_repo));
+
+    let result = update_cooking_session(
+        session_repo.clone(),
+        Arc::new(Box::new(recipe_repo)),
+        CookingSessionHub::new(),
+        &session.id,
+        CookingAction::StartTimer("Cook time".to_string()),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(result.timers.len(), 1);
+    assert_eq!(result.timers[0].label, "Cook time");
+    assert!(!result.timers[0].finished);
+
+    let stored = session_repo.get_by_id(&session.id).await.unwrap();
+    assert_eq!(stored.timers, result.timers);
+}
+
+pub async fn invalid_action_leaves_the_session_alone(
+    session_repo: impl CookingSessionRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let session = insert_cooking_session(&session_repo, &recipe_repo, &ingredient_repo).await;
+    let session_repo: CookingSessionRepositoryService = Arc::new(Box::new(session_repo));
+    let before = session_repo.get_by_id(&session.id).await.unwrap();
+
+    let error = update_cooking_session(
+        session_repo.clone(),
+        Arc::new(Box::new(recipe_repo)),
+        CookingSessionHub::new(),
+        &session.id,
+        CookingAction::GoToStep(100),
+    )
+    .await
+    .unwrap_err();
+
+    match error {
+        UpdateCookingSessionError::InvalidAction(e) => {
+            assert_eq!(e, CookingActionError::StepOutOfRange(100))
+        }
+        e => panic!("Wrong error returned: {e}"),
+    }
+
+    let after = session_repo.get_by_id(&session.id).await.unwrap();
+    assert_eq!(after, before);
+}
+
+pub async fn updating_a_nonexistent_cooking_session_errors(
+    session_repo: impl CookingSessionRepository,
+    recipe_repo: impl RecipeRepository,
+) {
+    let session_id = Uuid::from_u128(404);
+    let hub = CookingSessionHub::new();
+
+    let error = update_cooking_session(
+        Arc::new(Box::new(session_repo)),
+        Arc::new(Box::new(recipe_repo)),
+        hub.clone(),
+        &session_id,
+        CookingAction::NextStep,
+    )
+    .await
+    .unwrap_err();
+
+    match error {
+        UpdateCookingSessionError::NotFound(id) => assert_eq!(id, session_id),
+        e => panic!("Wrong error returned: {e}"),
+    }
+
+    assert!(!hub.is_open(&session_id));
+}
+
+pub async fn the_hub_forgets_sessions_nobody_uses(
+    session_repo: impl CookingSessionRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let session = insert_cooking_session(&session_repo, &recipe_repo, &ingredient_repo).await;
+    let session_repo: CookingSessionRepositoryService = Arc::new(Box::new(session_repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+    let hub = CookingSessionHub::new();
+    let device = hub.subscribe(&session.id);
+
+    update_cooking_session(
+        session_repo.clone(),
+        recipe_repo.clone(),
+        hub.clone(),
+        &session.id,
+        CookingAction::NextStep,
+    )
+    .await
+    .unwrap();
+    assert!(hub.is_open(&session.id));
+
+    drop(device);
+    assert!(!hub.is_open(&session.id));
+
+    update_cooking_session(
+        session_repo,
+        recipe_repo,
+        hub.clone(),
+        &session.id,
+        CookingAction::NextStep,
+    )
+    .await
+    .unwrap();
+    assert!(!hub.is_open(&session.id));
+}
@@ -0,0 +1,119 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::{
+        cooking_session::in_memory::InMemoryCookingSessionRepository,
+        ingredients::in_memory::InMemoryIngredientRepository,
+        recipe::in_memory::InMemoryRecipeRepository,
+    };
+
+    #[tokio::test]
+    async fn updating_a_cooking_session_notifies_devices() {
+        __tests__::updating_a_cooking_session_notifies_devices(
+            InMemoryCookingSessionRepository::new(),
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn the_hub_forgets_sessions_nobody_uses() {
+        __tests__::the_hub_forgets_sessions_nobody_uses(
+            InMemoryCookingSessionRepository::new(),
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn starting_a_timer_saves_it() {
+        __tests__::starting_a_timer_saves_it(
+            InMemoryCookingSessionRepository::new(),
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn invalid_action_leaves_the_session_alone() {
+        __tests__::invalid_action_leaves_the_session_alone(
+            InMemoryCookingSessionRepository::new(),
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn updating_a_nonexistent_cooking_session_errors() {
+        __tests__::updating_a_nonexistent_cooking_session_errors(
+            InMemoryCookingSessionRepository::new(),
+            InMemoryRecipeRepository::new(),
+        )
+        .await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        cooking_session::postgres::PostgresCookingSessionRepository,
+        ingredients::postgres::PostgresIngredientRepository,
+        recipe::postgres::PostgresRecipeRepository,
+    };
+
+    #[sqlx::test]
+    async fn updating_a_cooking_session_notifies_devices(pool: PgPool) {
+        __tests__::updating_a_cooking_session_notifies_devices(
+            PostgresCookingSessionRepository::new(pool.clone()),
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn the_hub_forgets_sessions_nobody_uses(pool: PgPool) {
+        __tests__::the_hub_forgets_sessions_nobody_uses(
+            PostgresCookingSessionRepository::new(pool.clone()),
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn starting_a_timer_saves_it(pool: PgPool) {
+        __tests__::starting_a_timer_saves_it(
+            PostgresCookingSessionRepository::new(pool.clone()),
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn invalid_action_leaves_the_session_alone(pool: PgPool) {
+        __tests__::invalid_action_leaves_the_session_alone(
+            PostgresCookingSessionRepository::new(pool.clone()),
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn updating_a_nonexistent_cooking_session_errors(pool: PgPool) {
+        __tests__::updating_a_nonexistent_cooking_session_errors(
+            PostgresCookingSessionRepository::new(pool.clone()),
+            PostgresRecipeRepository::new(pool),
+        )
+        .await
+    }
+}
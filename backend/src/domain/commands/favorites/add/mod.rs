@@ -0,0 +1,77 @@
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::favorite::Favorite,
+    repositories::{
+        favorite::{
+            errors::{GetFavoriteError, InsertFavoriteError},
+            FavoriteRepositoryService,
+        },
+        recipe::{errors::GetRecipeByIdError, RecipeRepositoryService},
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum AddFavoriteError {
+    #[error("Could not find the recipe with the ID {0}")]
+    RecipeNotFound(Uuid),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Report),
+}
+
+impl From<GetRecipeByIdError> for AddFavoriteError {
+    fn from(value: GetRecipeByIdError) -> Self {
+        match value {
+            GetRecipeByIdError::NotFound(id) => Self::RecipeNotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<InsertFavoriteError> for AddFavoriteError {
+    fn from(value: InsertFavoriteError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+impl From<GetFavoriteError> for AddFavoriteError {
+    fn from(value: GetFavoriteError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+/// Bookmarks the recipe. Favoriting a recipe twice keeps the original favorite.
+#[tracing::instrument(
+    "[COMMAND] Adding a recipe to favorites",
+    skip(favorite_repo, recipe_repo)
+)]
+pub async fn add_favorite(
+    favorite_repo: FavoriteRepositoryService,
+    recipe_repo: RecipeRepositoryService,
+    user_id: &Uuid,
+    recipe_id: &Uuid,
+) -> Result<Favorite, AddFavoriteError> {
+    recipe_repo
+        .get_visible(Some(user_id), recipe_id, None)
+        .await?;
+
+    let favorite = Favorite {
+        user_id: *user_id,
+        recipe_id: *recipe_id,
+        created_at: Utc::now(),
+    };
+
+    match favorite_repo.insert(favorite).await {
+        Ok(()) | Err(InsertFavoriteError::AlreadyFavorite(_)) => {}
+        Err(e) => return Err(e.into()),
+    };
+
+    let favorite = favorite_repo.get(user_id, recipe_id).await?;
+
+    Ok(favorite)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,122 @@
+use std::sync::Arc;
+
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        commands::favorites::add::{add_favorite, AddFavoriteError},
+        repositories::{
+            favorite::{FavoriteRepository, FavoriteRepositoryService},
+            ingredients::IngredientRepository,
+            recipe::{RecipeRepository, RecipeRepositoryService},
+            user::UserRepository,
+        },
+    },
+    test_utils::{
+        another_user_fixture, insert_all_ingredients_of_recipe, insert_users,
+        private_recipe_fixture, recipe_fixture, user_fixture,
+    },
+};
+
+pub async fn favoriting_a_recipe_works(
+    favorite_repo: impl FavoriteRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let recipe = recipe_fixture();
+    insert_users(&user_repo).await;
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+    let favorite_repo: FavoriteRepositoryService = Arc::new(Box::new(favorite_repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+    let recipe = recipe_repo.get_by_id(&recipe.id).await.unwrap();
+
+    let favorite = add_favorite(
+        favorite_repo.clone(),
+        recipe_repo.clone(),
+        &user_fixture().id,
+        &recipe.id,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(favorite.recipe_id, recipe.id);
+
+    let again = add_favorite(
+        favorite_repo.clone(),
+        recipe_repo.clone(),
+        &user_fixture().id,
+        &recipe.id,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(again, favorite);
+    assert_eq!(
+        favorite_repo.get_all(&user_fixture().id).await.unwrap(),
+        vec![favorite]
+    );
+    assert_eq!(
+        favorite_repo
+            .get_all(&another_user_fixture().id)
+            .await
+            .unwrap(),
+        vec![]
+    );
+    assert_eq!(
+        recipe_repo.get_by_id(&recipe.id).await.unwrap().updated_at,
+        recipe.updated_at
+    );
+}
+
+pub async fn favoriting_a_nonexistent_recipe_errors(
+    favorite_repo: impl FavoriteRepository,
+    recipe_repo: impl RecipeRepository,
+) {
+    let favorite_repo: FavoriteRepositoryService = Arc::new(Box::new(favorite_repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+    let recipe_id = Uuid::from_u128(404);
+
+    let error = add_favorite(favorite_repo, recipe_repo, &user_fixture().id, &recipe_id)
+        .await
+        .unwrap_err();
+
+    match error {
+        AddFavoriteError::RecipeNotFound(id) => assert_eq!(id, recipe_id),
+        e => panic!("Wrong error returned: {e}"),
+    }
+}
+
+pub async fn favoriting_a_private_recipe_of_someone_else_errors(
+    favorite_repo: impl FavoriteRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let recipe = private_recipe_fixture();
+    insert_users(&user_repo).await;
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+    let favorite_repo: FavoriteRepositoryService = Arc::new(Box::new(favorite_repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let error = add_favorite(
+        favorite_repo.clone(),
+        recipe_repo,
+        &another_user_fixture().id,
+        &recipe.id,
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(error, AddFavoriteError::RecipeNotFound(id) if id == recipe.id));
+    assert_eq!(
+        favorite_repo
+            .get_all(&another_user_fixture().id)
+            .await
+            .unwrap(),
+        vec![]
+    );
+}
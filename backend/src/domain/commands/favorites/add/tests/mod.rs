@@ -0,0 +1,83 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::{
+        favorite::in_memory::InMemoryFavoriteRepository,
+        ingredients::in_memory::InMemoryIngredientRepository,
+        recipe::in_memory::InMemoryRecipeRepository, user::in_memory::InMemoryUserRepository,
+    };
+
+    #[tokio::test]
+    async fn favoriting_a_recipe_works() {
+        __tests__::favoriting_a_recipe_works(
+            InMemoryFavoriteRepository::new(),
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+            InMemoryUserRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn favoriting_a_private_recipe_of_someone_else_errors() {
+        __tests__::favoriting_a_private_recipe_of_someone_else_errors(
+            InMemoryFavoriteRepository::new(),
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+            InMemoryUserRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn favoriting_a_nonexistent_recipe_errors() {
+        __tests__::favoriting_a_nonexistent_recipe_errors(
+            InMemoryFavoriteRepository::new(),
+            InMemoryRecipeRepository::new(),
+        )
+        .await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        favorite::postgres::PostgresFavoriteRepository,
+        ingredients::postgres::PostgresIngredientRepository,
+        recipe::postgres::PostgresRecipeRepository, user::postgres::PostgresUserRepository,
+    };
+
+    #[sqlx::test]
+    async fn favoriting_a_recipe_works(pool: PgPool) {
+        __tests__::favoriting_a_recipe_works(
+            PostgresFavoriteRepository::new(pool.clone()),
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresUserRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn favoriting_a_private_recipe_of_someone_else_errors(pool: PgPool) {
+        __tests__::favoriting_a_private_recipe_of_someone_else_errors(
+            PostgresFavoriteRepository::new(pool.clone()),
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresUserRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn favoriting_a_nonexistent_recipe_errors(pool: PgPool) {
+        __tests__::favoriting_a_nonexistent_recipe_errors(
+            PostgresFavoriteRepository::new(pool.clone()),
+            PostgresRecipeRepository::new(pool),
+        )
+        .await
+    }
+}
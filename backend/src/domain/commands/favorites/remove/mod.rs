@@ -0,0 +1,46 @@
+use uuid::Uuid;
+
+use crate::domain::repositories::favorite::{
+    errors::{DeleteFavoriteError, GetFavoriteError},
+    FavoriteRepositoryService,
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum RemoveFavoriteError {
+    #[error("The recipe with the ID {0} is not a favorite")]
+    NotFavorite(Uuid),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Report),
+}
+
+impl From<GetFavoriteError> for RemoveFavoriteError {
+    fn from(value: GetFavoriteError) -> Self {
+        match value {
+            GetFavoriteError::NotFound(id) => Self::NotFavorite(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<DeleteFavoriteError> for RemoveFavoriteError {
+    fn from(value: DeleteFavoriteError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+#[tracing::instrument("[COMMAND] Removing a recipe from favorites", skip(favorite_repo))]
+pub async fn remove_favorite(
+    favorite_repo: FavoriteRepositoryService,
+    user_id: &Uuid,
+    recipe_id: &Uuid,
+) -> Result<(), RemoveFavoriteError> {
+    let favorite = favorite_repo.get(user_id, recipe_id).await?;
+
+    favorite_repo.delete(&favorite).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests;
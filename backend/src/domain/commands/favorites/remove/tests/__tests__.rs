@@ -0,0 +1,59 @@
+use std::sync::Arc;
+
+use pretty_assertions::assert_eq;
+
+use crate::{
+    domain::{
+        commands::favorites::remove::{remove_favorite, RemoveFavoriteError},
+        repositories::{
+            favorite::{FavoriteRepository, FavoriteRepositoryService},
+            ingredients::IngredientRepository,
+            recipe::RecipeRepository,
+            user::UserRepository,
+        },
+    },
+    test_utils::{
+        another_user_fixture, favorite_fixture, insert_all_ingredients_of_recipe, insert_users,
+        recipe_fixture,
+    },
+};
+
+pub async fn unfavoriting_a_recipe_works(
+    favorite_repo: impl FavoriteRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let recipe = recipe_fixture();
+    insert_users(&user_repo).await;
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+    favorite_repo.insert(favorite_fixture()).await.unwrap();
+    let favorite_repo: FavoriteRepositoryService = Arc::new(Box::new(favorite_repo));
+    let user_id = favorite_fixture().user_id;
+
+    let error = remove_favorite(
+        favorite_repo.clone(),
+        &another_user_fixture().id,
+        &recipe.id,
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(error, RemoveFavoriteError::NotFavorite(_)));
+
+    remove_favorite(favorite_repo.clone(), &user_id, &recipe.id)
+        .await
+        .unwrap();
+
+    assert_eq!(favorite_repo.get_all(&user_id).await.unwrap(), vec![]);
+
+    let error = remove_favorite(favorite_repo, &user_id, &recipe.id)
+        .await
+        .unwrap_err();
+
+    match error {
+        RemoveFavoriteError::NotFavorite(id) => assert_eq!(id, recipe.id),
+        e => panic!("Wrong error returned: {e}"),
+    }
+}
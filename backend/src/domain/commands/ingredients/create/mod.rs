@@ -1,3 +1,4 @@
+use chrono::Utc;
 use uuid::Uuid;
 
 use crate::domain::entities::ingredient::*;
@@ -59,6 +60,7 @@ impl<'a> TryFrom<&CreateIngredient<'a>> for Ingredient {
                 .filter_map(|x| DietViolations::try_from(x).ok())
                 .collect::<Vec<_>>()
                 .into(),
+            updated_at: Utc::now(),
         })
     }
 }
@@ -2,13 +2,24 @@ use std::sync::Arc;
 
 use uuid::Uuid;
 
-use crate::domain::{
-    commands::ingredients::create::{create_ingredient, CreateIngredient, CreateIngredientError},
-    entities::ingredient::{
-        types::{DietViolations, WhichDiets},
-        Ingredient,
+use crate::{
+    domain::{
+        commands::ingredients::create::{
+            create_ingredient, CreateIngredient, CreateIngredientError,
+        },
+        entities::{
+            event::DomainEvent,
+            ingredient::{
+                types::{DietViolations, WhichDiets},
+                Ingredient,
+            },
+        },
+        repositories::{
+            ingredients::{IngredientRepository, IngredientRepositoryService},
+            outbox::OutboxRepository,
+        },
     },
-    repositories::ingredients::{IngredientRepository, IngredientRepositoryService},
+    test_utils::unpublished_events,
 };
 
 pub async fn creates_an_ingredient(repo: impl IngredientRepository) {
@@ -111,6 +122,7 @@ pub async fn inserting_an_ingredient_with_a_name_that_already_exists_fails(
         name: "Ingredient name".try_into().unwrap(),
         description: "Ingredient description".try_into().unwrap(),
         diet_violations: WhichDiets::new(),
+        updated_at: Default::default(),
     };
     let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
 
@@ -132,3 +144,24 @@ pub async fn inserting_an_ingredient_with_a_name_that_already_exists_fails(
         CreateIngredientError::Conflict(fieldname) if fieldname == "name"
     ))
 }
+
+pub async fn creating_an_ingredient_records_an_event(
+    repo: impl IngredientRepository,
+    outbox_repo: impl OutboxRepository,
+) {
+    let given = CreateIngredient {
+        name: "Tomato",
+        description: "Description of a tomato",
+        diet_violations: vec!["Vegan".into()],
+    };
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+
+    let ingredient = create_ingredient(repo.clone(), &given).await.unwrap();
+    // A duplicate is rejected and must not record an event
+    create_ingredient(repo, &given).await.unwrap_err();
+
+    assert_eq!(
+        unpublished_events(&outbox_repo).await,
+        vec![DomainEvent::IngredientCreated(ingredient)]
+    );
+}
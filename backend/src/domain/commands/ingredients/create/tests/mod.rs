@@ -1,7 +1,10 @@
 mod __tests__;
 mod in_memory {
     use super::__tests__;
-    use crate::domain::repositories::ingredients::in_memory::InMemoryIngredientRepository;
+    use crate::domain::repositories::{
+        ingredients::in_memory::InMemoryIngredientRepository,
+        outbox::in_memory::InMemoryOutboxRepository,
+    };
 
     #[tokio::test]
     async fn creates_an_ingredient() {
@@ -33,11 +36,24 @@ mod in_memory {
         let repo = InMemoryIngredientRepository::new();
         __tests__::inserting_an_ingredient_with_a_name_that_already_exists_fails(repo).await
     }
+
+    #[tokio::test]
+    async fn creating_an_ingredient_records_an_event() {
+        let outbox = InMemoryOutboxRepository::new();
+        __tests__::creating_an_ingredient_records_an_event(
+            InMemoryIngredientRepository::new().with_outbox(outbox.clone()),
+            outbox,
+        )
+        .await
+    }
 }
 
 mod sql {
     use super::__tests__;
-    use crate::domain::repositories::ingredients::postgres::PostgresIngredientRepository;
+    use crate::domain::repositories::{
+        ingredients::postgres::PostgresIngredientRepository,
+        outbox::postgres::PostgresOutboxRepository,
+    };
 
     use sqlx::PgPool;
 
@@ -73,4 +89,13 @@ mod sql {
         let repo = PostgresIngredientRepository::new(pool);
         __tests__::inserting_an_ingredient_with_a_name_that_already_exists_fails(repo).await
     }
+
+    #[sqlx::test]
+    async fn creating_an_ingredient_records_an_event(pool: PgPool) {
+        __tests__::creating_an_ingredient_records_an_event(
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresOutboxRepository::new(pool),
+        )
+        .await
+    }
 }
@@ -2,11 +2,13 @@ use thiserror::Error;
 use uuid::Uuid;
 
 use crate::domain::repositories::{
-    ingredients::{
-        errors::{DeleteIngredientError as DeleteIngredientErrorInternal, GetIngredientByIdError},
-        IngredientRepositoryService,
+    ingredients::errors::{
+        DeleteIngredientError as DeleteIngredientErrorInternal, GetIngredientByIdError,
+    },
+    unit_of_work::{
+        errors::{BeginTransactionError, CommitTransactionError},
+        UnitOfWorkService,
     },
-    recipe::RecipeRepositoryService,
 };
 
 #[derive(Error, Debug, strum::AsRefStr)]
@@ -36,14 +38,30 @@ impl From<GetIngredientByIdError> for DeleteIngredientError {
     }
 }
 
-#[tracing::instrument("[COMMAND] Deleting a new ingredient", skip(repo, recipe_repo))]
+impl From<BeginTransactionError> for DeleteIngredientError {
+    fn from(value: BeginTransactionError) -> Self {
+        Self::UnknownError(value.into())
+    }
+}
+
+impl From<CommitTransactionError> for DeleteIngredientError {
+    fn from(value: CommitTransactionError) -> Self {
+        Self::UnknownError(value.into())
+    }
+}
+
+/// Checking whether recipes use the ingredient and deleting it happen in the same transaction
+#[tracing::instrument("[COMMAND] Deleting a new ingredient", skip(unit_of_work))]
 pub async fn delete_ingredient(
-    repo: IngredientRepositoryService,
-    recipe_repo: RecipeRepositoryService,
+    unit_of_work: UnitOfWorkService,
     input: &Uuid,
 ) -> Result<(), DeleteIngredientError> {
+    let tx = unit_of_work.begin().await?;
+    let repo = tx.ingredients();
+
     let ingredient = repo.get_by_id(input).await?;
-    let recipes_with_ingredient_exist = recipe_repo
+    let recipes_with_ingredient_exist = tx
+        .recipes()
         .recipes_containing_ingredient_exist(ingredient.clone())
         .await?;
 
@@ -53,6 +71,8 @@ pub async fn delete_ingredient(
 
     repo.delete(ingredient).await?;
 
+    tx.commit().await?;
+
     Ok(())
 }
 
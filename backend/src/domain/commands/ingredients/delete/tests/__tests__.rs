@@ -7,37 +7,36 @@ use crate::{
         commands::ingredients::delete::{delete_ingredient, DeleteIngredientError},
         entities::ingredient::{types::WhichDiets, Ingredient},
         repositories::{
-            ingredients::{IngredientRepository, IngredientRepositoryService},
-            recipe::{RecipeRepository, RecipeRepositoryService},
+            ingredients::IngredientRepository,
+            recipe::RecipeRepository,
+            unit_of_work::{UnitOfWork, UnitOfWorkService},
         },
     },
     test_utils::{ingredient_fixture, insert_all_ingredients_of_recipe, recipe_fixture},
 };
 
-pub async fn deleting_works(repo: impl IngredientRepository, recipe_repo: impl RecipeRepository) {
-    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
-    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+pub async fn deleting_works(repo: impl IngredientRepository, unit_of_work: impl UnitOfWork) {
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
     let input = Ingredient {
         id: Uuid::from_u128(1),
         name: "Ingredient name 1".try_into().unwrap(),
         description: "Ingredient description 1".try_into().unwrap(),
         diet_violations: WhichDiets::new(),
+        updated_at: Default::default(),
     };
 
     let insert_result = repo.insert(input).await.unwrap();
-    delete_ingredient(repo, recipe_repo, &insert_result.id)
+    delete_ingredient(unit_of_work, &insert_result.id)
         .await
         .unwrap();
+
+    repo.get_by_id(&insert_result.id).await.unwrap_err();
 }
 
-pub async fn deleting_nonexistent_ingredient_errors(
-    repo: impl IngredientRepository,
-    recipe_repo: impl RecipeRepository,
-) {
-    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
-    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+pub async fn deleting_nonexistent_ingredient_errors(unit_of_work: impl UnitOfWork) {
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
     let ingredient = ingredient_fixture();
-    let error = delete_ingredient(repo, recipe_repo, &ingredient.id)
+    let error = delete_ingredient(unit_of_work, &ingredient.id)
         .await
         .unwrap_err();
 
@@ -50,18 +49,17 @@ pub async fn deleting_nonexistent_ingredient_errors(
 pub async fn deleting_an_ingredient_still_in_use_by_recipes_errors(
     repo: impl IngredientRepository,
     recipe_repo: impl RecipeRepository,
+    unit_of_work: impl UnitOfWork,
 ) {
     let recipe = recipe_fixture();
     insert_all_ingredients_of_recipe(&repo, &recipe).await;
     recipe_repo.insert(recipe.clone()).await.unwrap();
     let input = &recipe.ingredients.first().unwrap().ingredient.id;
 
-    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
-    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
 
-    let error = delete_ingredient(repo, recipe_repo, input)
-        .await
-        .unwrap_err();
+    let error = delete_ingredient(unit_of_work, input).await.unwrap_err();
 
     assert!(matches!(error, DeleteIngredientError::InUseByRecipe));
+    repo.get_by_id(input).await.unwrap();
 }
@@ -3,28 +3,39 @@ mod in_memory {
     use super::__tests__;
     use crate::domain::repositories::{
         ingredients::in_memory::InMemoryIngredientRepository,
-        recipe::in_memory::InMemoryRecipeRepository,
+        recipe::in_memory::InMemoryRecipeRepository, unit_of_work::in_memory::InMemoryUnitOfWork,
     };
 
     #[tokio::test]
     async fn deleting_works() {
         let repo = InMemoryIngredientRepository::new();
         let recipe_repo = InMemoryRecipeRepository::new();
-        __tests__::deleting_works(repo, recipe_repo).await
+        let unit_of_work = InMemoryUnitOfWork::new(repo.clone(), recipe_repo);
+        __tests__::deleting_works(repo, unit_of_work).await
     }
 
     #[tokio::test]
     async fn deleting_nonexistent_ingredient_errors() {
         let repo = InMemoryIngredientRepository::new();
         let recipe_repo = InMemoryRecipeRepository::new();
-        __tests__::deleting_nonexistent_ingredient_errors(repo, recipe_repo).await
+        __tests__::deleting_nonexistent_ingredient_errors(InMemoryUnitOfWork::new(
+            repo,
+            recipe_repo,
+        ))
+        .await
     }
 
     #[tokio::test]
     async fn deleting_an_ingredient_still_in_use_by_recipes_errors() {
         let repo = InMemoryIngredientRepository::new();
         let recipe_repo = InMemoryRecipeRepository::new();
-        __tests__::deleting_an_ingredient_still_in_use_by_recipes_errors(repo, recipe_repo).await
+        let unit_of_work = InMemoryUnitOfWork::new(repo.clone(), recipe_repo.clone());
+        __tests__::deleting_an_ingredient_still_in_use_by_recipes_errors(
+            repo,
+            recipe_repo,
+            unit_of_work,
+        )
+        .await
     }
 }
 
@@ -32,7 +43,7 @@ mod sql {
     use super::__tests__;
     use crate::domain::repositories::{
         ingredients::postgres::PostgresIngredientRepository,
-        recipe::postgres::PostgresRecipeRepository,
+        recipe::postgres::PostgresRecipeRepository, unit_of_work::postgres::PostgresUnitOfWork,
     };
 
     use sqlx::PgPool;
@@ -40,21 +51,23 @@ mod sql {
     #[sqlx::test]
     async fn deleting_works(pool: PgPool) {
         let repo = PostgresIngredientRepository::new(pool.clone());
-        let recipe_repo = PostgresRecipeRepository::new(pool);
-        __tests__::deleting_works(repo, recipe_repo).await
+        __tests__::deleting_works(repo, PostgresUnitOfWork::new(pool)).await
     }
 
     #[sqlx::test]
     async fn deleting_nonexistent_ingredient_errors(pool: PgPool) {
-        let repo = PostgresIngredientRepository::new(pool.clone());
-        let recipe_repo = PostgresRecipeRepository::new(pool);
-        __tests__::deleting_works(repo, recipe_repo).await
+        __tests__::deleting_nonexistent_ingredient_errors(PostgresUnitOfWork::new(pool)).await
     }
 
     #[sqlx::test]
     async fn deleting_an_ingredient_still_in_use_by_recipes_errors(pool: PgPool) {
         let repo = PostgresIngredientRepository::new(pool.clone());
-        let recipe_repo = PostgresRecipeRepository::new(pool);
-        __tests__::deleting_an_ingredient_still_in_use_by_recipes_errors(repo, recipe_repo).await
+        let recipe_repo = PostgresRecipeRepository::new(pool.clone());
+        __tests__::deleting_an_ingredient_still_in_use_by_recipes_errors(
+            repo,
+            recipe_repo,
+            PostgresUnitOfWork::new(pool),
+        )
+        .await
     }
 }
@@ -2,10 +2,18 @@ use common::UpdateIngredientDTO;
 use uuid::Uuid;
 
 use crate::domain::{
-    entities::ingredient::{errors::ValidationError, Ingredient, IngredientChangeset},
-    repositories::ingredients::{
-        errors::{GetIngredientByIdError, UpdateIngredientError as UpdateIngredientErrorInternal},
-        IngredientRepositoryService,
+    entities::{
+        ingredient::{errors::ValidationError, Ingredient, IngredientChangeset},
+        precondition::Precondition,
+    },
+    repositories::{
+        ingredients::errors::{
+            GetIngredientByIdError, UpdateIngredientError as UpdateIngredientErrorInternal,
+        },
+        unit_of_work::{
+            errors::{BeginTransactionError, CommitTransactionError},
+            UnitOfWorkService,
+        },
     },
 };
 
@@ -54,6 +62,9 @@ pub enum UpdateIngredientError {
     #[error("Could not find the ingredient with ID {0}")]
     NotFound(Uuid),
 
+    #[error("The ingredient with ID {0} has changed since you last fetched it")]
+    PreconditionFailed(Uuid),
+
     #[error(transparent)]
     ValidationError(ValidationError),
 
@@ -76,6 +87,18 @@ impl From<UpdateIngredientErrorInternal> for UpdateIngredientError {
     }
 }
 
+impl From<BeginTransactionError> for UpdateIngredientError {
+    fn from(value: BeginTransactionError) -> Self {
+        Self::Internal(value.into())
+    }
+}
+
+impl From<CommitTransactionError> for UpdateIngredientError {
+    fn from(value: CommitTransactionError) -> Self {
+        Self::Internal(value.into())
+    }
+}
+
 impl From<GetIngredientByIdError> for UpdateIngredientError {
     fn from(value: GetIngredientByIdError) -> Self {
         match value {
@@ -85,13 +108,21 @@ impl From<GetIngredientByIdError> for UpdateIngredientError {
     }
 }
 
-#[tracing::instrument("[COMMAND] Updating an existing ingredient", skip(repo))]
+/// The ingredient stays locked from checking the precondition until the change is committed
+#[tracing::instrument("[COMMAND] Updating an existing ingredient", skip(unit_of_work))]
 pub async fn update_ingredient(
-    repo: IngredientRepositoryService,
+    unit_of_work: UnitOfWorkService,
     id: Uuid,
     input: &UpdateIngredient,
+    precondition: &Precondition,
 ) -> Result<Ingredient, UpdateIngredientError> {
-    let ingredient_to_change = repo.get_by_id(&id).await?;
+    let tx = unit_of_work.begin().await?;
+    let repo = tx.ingredients();
+
+    let ingredient_to_change = tx.lock_ingredient(&id).await?;
+    if !precondition.holds(&ingredient_to_change.entity_tag()) {
+        return Err(UpdateIngredientError::PreconditionFailed(id));
+    }
 
     tracing::info!("Serializing input into a changeset");
     let ingredient: IngredientChangeset = input.try_into()?;
@@ -102,6 +133,8 @@ pub async fn update_ingredient(
 
     let result = repo.get_by_id(&id).await?;
 
+    tx.commit().await?;
+
     Ok(result)
 }
 
@@ -12,12 +12,20 @@ use crate::{
             types::{IngredientName, WhichDiets},
             Ingredient,
         },
-        repositories::ingredients::{IngredientRepository, IngredientRepositoryService},
+        entities::precondition::Precondition,
+        repositories::{
+            ingredients::{IngredientRepository, IngredientRepositoryService},
+            unit_of_work::{UnitOfWork, UnitOfWorkService},
+        },
     },
     test_utils::ingredient_fixture,
 };
 
-pub async fn updating_an_ingredient_success(repo: impl IngredientRepository) {
+pub async fn updating_an_ingredient_success(
+    repo: impl IngredientRepository,
+    unit_of_work: impl UnitOfWork,
+) {
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
     let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
 
     let input = ingredient_fixture();
@@ -28,22 +36,33 @@ pub async fn updating_an_ingredient_success(repo: impl IngredientRepository) {
 
     repo.insert(input.clone()).await.unwrap();
 
-    update_ingredient(repo.clone(), input.id, &changeset)
-        .await
-        .unwrap();
+    update_ingredient(
+        unit_of_work.clone(),
+        input.id,
+        &changeset,
+        &Precondition::Any,
+    )
+    .await
+    .unwrap();
 
     let result = repo.get_by_id(&input.id).await.unwrap();
 
+    assert_ne!(result.updated_at, input.updated_at);
     assert_eq!(
         result,
         Ingredient {
             name: IngredientName("Ingredient name changed".to_string()),
+            updated_at: result.updated_at,
             ..input
         }
     )
 }
 
-pub async fn updating_with_empty_changeset_fails(repo: impl IngredientRepository) {
+pub async fn updating_with_empty_changeset_fails(
+    repo: impl IngredientRepository,
+    unit_of_work: impl UnitOfWork,
+) {
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
     let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
 
     let input = Ingredient {
@@ -51,31 +70,103 @@ pub async fn updating_with_empty_changeset_fails(repo: impl IngredientRepository
         name: "Ingredient name 1".try_into().unwrap(),
         description: "Ingredient description 1".try_into().unwrap(),
         diet_violations: WhichDiets::new(),
+        updated_at: Default::default(),
     };
     let changeset = UpdateIngredient::default();
 
     repo.insert(input.clone()).await.unwrap();
 
-    let error = update_ingredient(repo.clone(), input.id, &changeset)
-        .await
-        .unwrap_err();
+    let error = update_ingredient(
+        unit_of_work.clone(),
+        input.id,
+        &changeset,
+        &Precondition::Any,
+    )
+    .await
+    .unwrap_err();
 
     assert!(
         matches!(error, UpdateIngredientError::ValidationError(ValidationError::EmptyField(fields)) if fields == ["name", "description", "diet_violations"])
     );
 }
 
-pub async fn updating_a_missing_file_fails(repo: impl IngredientRepository) {
-    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+pub async fn updating_a_missing_file_fails(unit_of_work: impl UnitOfWork) {
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
     let input = ingredient_fixture();
     let changeset = UpdateIngredient {
         name: Some("This will fail, so this doesn't matter".to_string()),
         ..Default::default()
     };
 
-    let error = update_ingredient(repo.clone(), input.id, &changeset)
-        .await
-        .unwrap_err();
+    let error = update_ingredient(
+        unit_of_work.clone(),
+        input.id,
+        &changeset,
+        &Precondition::Any,
+    )
+    .await
+    .unwrap_err();
 
     assert!(matches!(error, UpdateIngredientError::NotFound(id) if id == Uuid::from_u128(64)));
 }
+
+pub async fn updating_an_outdated_ingredient_fails(
+    repo: impl IngredientRepository,
+    unit_of_work: impl UnitOfWork,
+) {
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+    let input = ingredient_fixture();
+    let changeset = UpdateIngredient {
+        name: Some("Ingredient name changed".to_string()),
+        ..Default::default()
+    };
+
+    repo.insert(input.clone()).await.unwrap();
+
+    let error = update_ingredient(
+        unit_of_work.clone(),
+        input.id,
+        &changeset,
+        &Precondition::Tags(vec!["outdated".to_string()]),
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(error, UpdateIngredientError::PreconditionFailed(id) if id == input.id));
+
+    let result = repo.get_by_id(&input.id).await.unwrap();
+    assert_eq!(result.name, input.name);
+}
+
+pub async fn concurrent_updates_with_the_same_precondition_let_only_one_through(
+    repo: impl IngredientRepository,
+    unit_of_work: impl UnitOfWork,
+) {
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
+    let input = ingredient_fixture();
+    repo.insert(input.clone()).await.unwrap();
+    let stored = repo.get_by_id(&input.id).await.unwrap();
+    let precondition = Precondition::Tags(vec![stored.entity_tag()]);
+
+    let first = UpdateIngredient {
+        name: Some("First".to_string()),
+        ..Default::default()
+    };
+    let second = UpdateIngredient {
+        name: Some("Second".to_string()),
+        ..Default::default()
+    };
+    let (first, second) = tokio::join!(
+        update_ingredient(unit_of_work.clone(), input.id, &first, &precondition),
+        update_ingredient(unit_of_work.clone(), input.id, &second, &precondition),
+    );
+
+    // Whichever came second has to see the change of the other one
+    let (updated, error) = match (first, second) {
+        (Ok(updated), Err(error)) | (Err(error), Ok(updated)) => (updated, error),
+        (first, second) => panic!("Expected exactly one update to fail: {first:?}, {second:?}"),
+    };
+    assert!(matches!(error, UpdateIngredientError::PreconditionFailed(id) if id == input.id));
+    assert_eq!(repo.get_by_id(&input.id).await.unwrap().name, updated.name);
+}
@@ -1,26 +1,52 @@
 mod __tests__;
 
 mod in_memory {
-    use crate::domain::repositories::ingredients::in_memory::InMemoryIngredientRepository;
+    use crate::domain::repositories::{
+        ingredients::in_memory::InMemoryIngredientRepository,
+        recipe::in_memory::InMemoryRecipeRepository, unit_of_work::in_memory::InMemoryUnitOfWork,
+    };
 
     use super::__tests__;
 
     #[tokio::test]
     async fn updating_an_ingredient_success() {
         let repo = InMemoryIngredientRepository::new();
-        __tests__::updating_an_ingredient_success(repo).await
+        let unit_of_work = InMemoryUnitOfWork::new(repo.clone(), InMemoryRecipeRepository::new());
+        __tests__::updating_an_ingredient_success(repo, unit_of_work).await
     }
 
     #[tokio::test]
     async fn updating_with_empty_changeset_fails() {
         let repo = InMemoryIngredientRepository::new();
-        __tests__::updating_with_empty_changeset_fails(repo).await
+        let unit_of_work = InMemoryUnitOfWork::new(repo.clone(), InMemoryRecipeRepository::new());
+        __tests__::updating_with_empty_changeset_fails(repo, unit_of_work).await
     }
 
     #[tokio::test]
     async fn updating_a_missing_file_fails() {
+        let unit_of_work = InMemoryUnitOfWork::new(
+            InMemoryIngredientRepository::new(),
+            InMemoryRecipeRepository::new(),
+        );
+        __tests__::updating_a_missing_file_fails(unit_of_work).await
+    }
+
+    #[tokio::test]
+    async fn updating_an_outdated_ingredient_fails() {
+        let repo = InMemoryIngredientRepository::new();
+        let unit_of_work = InMemoryUnitOfWork::new(repo.clone(), InMemoryRecipeRepository::new());
+        __tests__::updating_an_outdated_ingredient_fails(repo, unit_of_work).await
+    }
+
+    #[tokio::test]
+    async fn concurrent_updates_with_the_same_precondition_let_only_one_through() {
         let repo = InMemoryIngredientRepository::new();
-        __tests__::updating_a_missing_file_fails(repo).await
+        let unit_of_work = InMemoryUnitOfWork::new(repo.clone(), InMemoryRecipeRepository::new());
+        __tests__::concurrent_updates_with_the_same_precondition_let_only_one_through(
+            repo,
+            unit_of_work,
+        )
+        .await
     }
 }
 
@@ -28,23 +54,41 @@ mod sql {
     use sqlx::PgPool;
 
     use super::__tests__;
-    use crate::domain::repositories::ingredients::postgres::PostgresIngredientRepository;
+    use crate::domain::repositories::{
+        ingredients::postgres::PostgresIngredientRepository,
+        unit_of_work::postgres::PostgresUnitOfWork,
+    };
 
     #[sqlx::test]
     async fn updating_an_ingredient_success(pool: PgPool) {
         let repo = PostgresIngredientRepository::new(pool.clone());
-        __tests__::updating_an_ingredient_success(repo).await
+        __tests__::updating_an_ingredient_success(repo, PostgresUnitOfWork::new(pool)).await
     }
 
     #[sqlx::test]
     async fn updating_with_empty_changeset_fails(pool: PgPool) {
         let repo = PostgresIngredientRepository::new(pool.clone());
-        __tests__::updating_with_empty_changeset_fails(repo).await
+        __tests__::updating_with_empty_changeset_fails(repo, PostgresUnitOfWork::new(pool)).await
     }
 
     #[sqlx::test]
     async fn updating_a_missing_file_fails(pool: PgPool) {
+        __tests__::updating_a_missing_file_fails(PostgresUnitOfWork::new(pool)).await
+    }
+
+    #[sqlx::test]
+    async fn updating_an_outdated_ingredient_fails(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool.clone());
+        __tests__::updating_an_outdated_ingredient_fails(repo, PostgresUnitOfWork::new(pool)).await
+    }
+
+    #[sqlx::test]
+    async fn concurrent_updates_with_the_same_precondition_let_only_one_through(pool: PgPool) {
         let repo = PostgresIngredientRepository::new(pool.clone());
-        __tests__::updating_a_missing_file_fails(repo).await
+        __tests__::concurrent_updates_with_the_same_precondition_let_only_one_through(
+            repo,
+            PostgresUnitOfWork::new(pool),
+        )
+        .await
     }
 }
@@ -0,0 +1,95 @@
+use chrono::{NaiveDate, Utc};
+use common::CreateMealPlanEntryDTO;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        meal_plan::{MealPlanEntry, MealSlot},
+        recipe::errors::ValidationError,
+    },
+    repositories::{
+        meal_plan::{errors::InsertMealPlanEntryError, MealPlanRepositoryService},
+        recipe::{errors::GetRecipeByIdError, RecipeRepositoryService},
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum CreateMealPlanEntryError {
+    #[error("Could not find the recipe with the ID {0}")]
+    RecipeNotFound(Uuid),
+
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Report),
+}
+
+impl From<GetRecipeByIdError> for CreateMealPlanEntryError {
+    fn from(value: GetRecipeByIdError) -> Self {
+        match value {
+            GetRecipeByIdError::NotFound(id) => Self::RecipeNotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<InsertMealPlanEntryError> for CreateMealPlanEntryError {
+    fn from(value: InsertMealPlanEntryError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateMealPlanEntry {
+    pub date: NaiveDate,
+    pub slot: MealSlot,
+    pub recipe_id: Uuid,
+    pub servings: u16,
+}
+
+impl From<CreateMealPlanEntryDTO> for CreateMealPlanEntry {
+    fn from(value: CreateMealPlanEntryDTO) -> Self {
+        Self {
+            date: value.date,
+            slot: value.slot.into(),
+            recipe_id: value.recipe_id,
+            servings: value.servings,
+        }
+    }
+}
+
+#[tracing::instrument("[COMMAND] Planning a meal", skip(meal_plan_repo, recipe_repo))]
+pub async fn create_meal_plan_entry(
+    meal_plan_repo: MealPlanRepositoryService,
+    recipe_repo: RecipeRepositoryService,
+    user_id: &Uuid,
+    input: &CreateMealPlanEntry,
+) -> Result<MealPlanEntry, CreateMealPlanEntryError> {
+    if input.servings == 0 {
+        return Err(ValidationError::EmptyField(vec!["servings"]).into());
+    }
+
+    recipe_repo
+        .get_visible(Some(user_id), &input.recipe_id, None)
+        .await?;
+
+    let now = Utc::now();
+    let entry = MealPlanEntry {
+        id: Uuid::now_v7(),
+        user_id: *user_id,
+        date: input.date,
+        slot: input.slot,
+        recipe_id: input.recipe_id,
+        servings: input.servings,
+        created_at: now,
+        updated_at: now,
+    };
+
+    meal_plan_repo.insert(entry.clone()).await?;
+
+    Ok(entry)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,169 @@
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        commands::meal_plans::create::{
+            create_meal_plan_entry, CreateMealPlanEntry, CreateMealPlanEntryError,
+        },
+        entities::{
+            meal_plan::MealSlot,
+            recipe::{errors::ValidationError, Recipe},
+            visibility::Visibility,
+        },
+        repositories::{
+            ingredients::IngredientRepository,
+            meal_plan::{MealPlanRepository, MealPlanRepositoryService},
+            recipe::{RecipeRepository, RecipeRepositoryService},
+            user::UserRepository,
+        },
+    },
+    test_utils::{
+        another_recipe_fixture, another_user_fixture, insert_meal_plan, recipe_fixture,
+        user_fixture,
+    },
+};
+
+fn input(recipe_id: Uuid, servings: u16) -> CreateMealPlanEntry {
+    CreateMealPlanEntry {
+        date: NaiveDate::from_ymd_opt(2024, 10, 22).unwrap(),
+        slot: MealSlot::Lunch,
+        recipe_id,
+        servings,
+    }
+}
+
+pub async fn planning_a_meal_works(
+    meal_plan_repo: impl MealPlanRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    insert_meal_plan(
+        &meal_plan_repo,
+        &recipe_repo,
+        &ingredient_repo,
+        &user_repo,
+        &[],
+    )
+    .await;
+    let meal_plan_repo: MealPlanRepositoryService = Arc::new(Box::new(meal_plan_repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+    let recipe = recipe_fixture();
+
+    let user = user_fixture();
+
+    let result = create_meal_plan_entry(
+        meal_plan_repo.clone(),
+        recipe_repo,
+        &user.id,
+        &input(recipe.id, 3),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(result.user_id, user.id);
+    assert_eq!(result.recipe_id, recipe.id);
+    assert_eq!(result.slot, MealSlot::Lunch);
+    assert_eq!(result.servings, 3);
+
+    let saved = meal_plan_repo
+        .get_by_id(&user.id, &result.id)
+        .await
+        .unwrap();
+
+    assert_eq!(saved.date, result.date);
+    assert_eq!(saved.slot, result.slot);
+    assert_eq!(saved.recipe_id, result.recipe_id);
+    assert_eq!(saved.servings, result.servings);
+}
+
+pub async fn planning_a_nonexistent_recipe_errors(
+    meal_plan_repo: impl MealPlanRepository,
+    recipe_repo: impl RecipeRepository,
+) {
+    let meal_plan_repo: MealPlanRepositoryService = Arc::new(Box::new(meal_plan_repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+    let id = Uuid::now_v7();
+
+    let error = create_meal_plan_entry(
+        meal_plan_repo,
+        recipe_repo,
+        &user_fixture().id,
+        &input(id, 3),
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(error, CreateMealPlanEntryError::RecipeNotFound(e) if e == id));
+}
+
+pub async fn planning_zero_servings_errors(
+    meal_plan_repo: impl MealPlanRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    insert_meal_plan(
+        &meal_plan_repo,
+        &recipe_repo,
+        &ingredient_repo,
+        &user_repo,
+        &[],
+    )
+    .await;
+    let meal_plan_repo: MealPlanRepositoryService = Arc::new(Box::new(meal_plan_repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let error = create_meal_plan_entry(
+        meal_plan_repo,
+        recipe_repo,
+        &user_fixture().id,
+        &input(recipe_fixture().id, 0),
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(
+        error,
+        CreateMealPlanEntryError::ValidationError(ValidationError::EmptyField(_))
+    ));
+}
+
+pub async fn planning_a_private_recipe_of_someone_else_errors(
+    meal_plan_repo: impl MealPlanRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    insert_meal_plan(
+        &meal_plan_repo,
+        &recipe_repo,
+        &ingredient_repo,
+        &user_repo,
+        &[],
+    )
+    .await;
+    let recipe = Recipe {
+        visibility: Visibility::Private,
+        owner_id: Some(another_user_fixture().id),
+        ..another_recipe_fixture(1)
+    };
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+    let meal_plan_repo: MealPlanRepositoryService = Arc::new(Box::new(meal_plan_repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let error = create_meal_plan_entry(
+        meal_plan_repo,
+        recipe_repo,
+        &user_fixture().id,
+        &input(recipe.id, 2),
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(error, CreateMealPlanEntryError::RecipeNotFound(id) if id == recipe.id));
+}
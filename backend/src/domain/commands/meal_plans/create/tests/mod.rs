@@ -0,0 +1,105 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::{
+        ingredients::in_memory::InMemoryIngredientRepository,
+        meal_plan::in_memory::InMemoryMealPlanRepository,
+        recipe::in_memory::InMemoryRecipeRepository, user::in_memory::InMemoryUserRepository,
+    };
+
+    #[tokio::test]
+    async fn planning_a_meal_works() {
+        __tests__::planning_a_meal_works(
+            InMemoryMealPlanRepository::new(),
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+            InMemoryUserRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn planning_a_private_recipe_of_someone_else_errors() {
+        __tests__::planning_a_private_recipe_of_someone_else_errors(
+            InMemoryMealPlanRepository::new(),
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+            InMemoryUserRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn planning_a_nonexistent_recipe_errors() {
+        __tests__::planning_a_nonexistent_recipe_errors(
+            InMemoryMealPlanRepository::new(),
+            InMemoryRecipeRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn planning_zero_servings_errors() {
+        __tests__::planning_zero_servings_errors(
+            InMemoryMealPlanRepository::new(),
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+            InMemoryUserRepository::new(),
+        )
+        .await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        ingredients::postgres::PostgresIngredientRepository,
+        meal_plan::postgres::PostgresMealPlanRepository,
+        recipe::postgres::PostgresRecipeRepository, user::postgres::PostgresUserRepository,
+    };
+
+    #[sqlx::test]
+    async fn planning_a_meal_works(pool: PgPool) {
+        __tests__::planning_a_meal_works(
+            PostgresMealPlanRepository::new(pool.clone()),
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresUserRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn planning_a_private_recipe_of_someone_else_errors(pool: PgPool) {
+        __tests__::planning_a_private_recipe_of_someone_else_errors(
+            PostgresMealPlanRepository::new(pool.clone()),
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresUserRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn planning_a_nonexistent_recipe_errors(pool: PgPool) {
+        __tests__::planning_a_nonexistent_recipe_errors(
+            PostgresMealPlanRepository::new(pool.clone()),
+            PostgresRecipeRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn planning_zero_servings_errors(pool: PgPool) {
+        __tests__::planning_zero_servings_errors(
+            PostgresMealPlanRepository::new(pool.clone()),
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresUserRepository::new(pool),
+        )
+        .await
+    }
+}
@@ -0,0 +1,42 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{meal_plan::CalendarFeed, recipe::errors::ValidationError},
+    repositories::meal_plan::{errors::InsertCalendarFeedError, MealPlanRepositoryService},
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum CreateCalendarFeedError {
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Report),
+}
+
+impl From<InsertCalendarFeedError> for CreateCalendarFeedError {
+    fn from(value: InsertCalendarFeedError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+#[tracing::instrument("[COMMAND] Creating a calendar feed", skip(meal_plan_repo))]
+pub async fn create_calendar_feed(
+    meal_plan_repo: MealPlanRepositoryService,
+    user_id: &Uuid,
+    name: &str,
+) -> Result<CalendarFeed, CreateCalendarFeedError> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(ValidationError::EmptyField(vec!["name"]).into());
+    }
+
+    let feed = CalendarFeed::new(*user_id, name.to_string());
+
+    meal_plan_repo.insert_feed(feed.clone()).await?;
+
+    Ok(feed)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use pretty_assertions::assert_eq;
+
+use crate::{
+    domain::{
+        commands::meal_plans::create_feed::{create_calendar_feed, CreateCalendarFeedError},
+        entities::recipe::errors::ValidationError,
+        repositories::{
+            meal_plan::{MealPlanRepository, MealPlanRepositoryService},
+            user::UserRepository,
+        },
+    },
+    test_utils::{insert_users, user_fixture},
+};
+
+pub async fn creating_a_calendar_feed_works(
+    meal_plan_repo: impl MealPlanRepository,
+    user_repo: impl UserRepository,
+) {
+    insert_users(&user_repo).await;
+    let meal_plan_repo: MealPlanRepositoryService = Arc::new(Box::new(meal_plan_repo));
+    let user = user_fixture();
+
+    let result = create_calendar_feed(meal_plan_repo.clone(), &user.id, "  Kitchen tablet ")
+        .await
+        .unwrap();
+
+    assert_eq!(result.name, "Kitchen tablet");
+    assert_eq!(result.user_id, user.id);
+    assert_eq!(result.token.len(), 32);
+
+    let saved = meal_plan_repo
+        .get_feed_by_token(&result.token)
+        .await
+        .unwrap();
+
+    assert_eq!(saved.id, result.id);
+    assert_eq!(saved.user_id, result.user_id);
+    assert_eq!(saved.name, result.name);
+
+    let other = create_calendar_feed(meal_plan_repo, &user.id, "Phone")
+        .await
+        .unwrap();
+
+    assert_ne!(other.token, result.token);
+}
+
+pub async fn creating_a_calendar_feed_without_a_name_errors(
+    meal_plan_repo: impl MealPlanRepository,
+) {
+    let meal_plan_repo: MealPlanRepositoryService = Arc::new(Box::new(meal_plan_repo));
+
+    let error = create_calendar_feed(meal_plan_repo, &user_fixture().id, "   ")
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        error,
+        CreateCalendarFeedError::ValidationError(ValidationError::EmptyField(_))
+    ));
+}
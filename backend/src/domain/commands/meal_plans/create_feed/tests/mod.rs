@@ -0,0 +1,49 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::{
+        meal_plan::in_memory::InMemoryMealPlanRepository, user::in_memory::InMemoryUserRepository,
+    };
+
+    #[tokio::test]
+    async fn creating_a_calendar_feed_works() {
+        __tests__::creating_a_calendar_feed_works(
+            InMemoryMealPlanRepository::new(),
+            InMemoryUserRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn creating_a_calendar_feed_without_a_name_errors() {
+        __tests__::creating_a_calendar_feed_without_a_name_errors(InMemoryMealPlanRepository::new())
+            .await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        meal_plan::postgres::PostgresMealPlanRepository, user::postgres::PostgresUserRepository,
+    };
+
+    #[sqlx::test]
+    async fn creating_a_calendar_feed_works(pool: PgPool) {
+        __tests__::creating_a_calendar_feed_works(
+            PostgresMealPlanRepository::new(pool.clone()),
+            PostgresUserRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn creating_a_calendar_feed_without_a_name_errors(pool: PgPool) {
+        __tests__::creating_a_calendar_feed_without_a_name_errors(PostgresMealPlanRepository::new(
+            pool,
+        ))
+        .await
+    }
+}
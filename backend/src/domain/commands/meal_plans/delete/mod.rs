@@ -0,0 +1,46 @@
+use uuid::Uuid;
+
+use crate::domain::repositories::meal_plan::{
+    errors::{DeleteMealPlanEntryError as DeleteMealPlanEntryErrorInternal, GetMealPlanEntryError},
+    MealPlanRepositoryService,
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum DeleteMealPlanEntryError {
+    #[error("Could not find the meal plan entry with the ID {0}")]
+    NotFound(Uuid),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<DeleteMealPlanEntryErrorInternal> for DeleteMealPlanEntryError {
+    fn from(value: DeleteMealPlanEntryErrorInternal) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+impl From<GetMealPlanEntryError> for DeleteMealPlanEntryError {
+    fn from(value: GetMealPlanEntryError) -> Self {
+        match value {
+            GetMealPlanEntryError::NotFound(id) => Self::NotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+#[tracing::instrument("[COMMAND] Removing a planned meal", skip(meal_plan_repo))]
+pub async fn delete_meal_plan_entry(
+    meal_plan_repo: MealPlanRepositoryService,
+    user_id: &Uuid,
+    id: &Uuid,
+) -> Result<(), DeleteMealPlanEntryError> {
+    let entry = meal_plan_repo.get_by_id(user_id, id).await?;
+
+    meal_plan_repo.delete(&entry).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests;
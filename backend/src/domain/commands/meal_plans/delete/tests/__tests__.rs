@@ -0,0 +1,52 @@
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        commands::meal_plans::delete::{delete_meal_plan_entry, DeleteMealPlanEntryError},
+        repositories::{
+            ingredients::IngredientRepository,
+            meal_plan::{MealPlanRepository, MealPlanRepositoryService},
+            recipe::RecipeRepository,
+            user::UserRepository,
+        },
+    },
+    test_utils::{another_user_fixture, insert_meal_plan, meal_plan_entry_fixture},
+};
+
+pub async fn removing_a_planned_meal_works(
+    meal_plan_repo: impl MealPlanRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let entry = meal_plan_entry_fixture();
+    insert_meal_plan(
+        &meal_plan_repo,
+        &recipe_repo,
+        &ingredient_repo,
+        &user_repo,
+        std::slice::from_ref(&entry),
+    )
+    .await;
+    let meal_plan_repo: MealPlanRepositoryService = Arc::new(Box::new(meal_plan_repo));
+
+    let error = delete_meal_plan_entry(
+        meal_plan_repo.clone(),
+        &another_user_fixture().id,
+        &entry.id,
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(error, DeleteMealPlanEntryError::NotFound(id) if id == entry.id));
+
+    delete_meal_plan_entry(meal_plan_repo.clone(), &entry.user_id, &entry.id)
+        .await
+        .unwrap();
+
+    let error = delete_meal_plan_entry(meal_plan_repo, &entry.user_id, &entry.id)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, DeleteMealPlanEntryError::NotFound(id) if id == entry.id));
+}
@@ -0,0 +1,51 @@
+use uuid::Uuid;
+
+use crate::domain::repositories::meal_plan::{
+    errors::{DeleteCalendarFeedError as DeleteCalendarFeedErrorInternal, GetCalendarFeedError},
+    MealPlanRepositoryService,
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum DeleteCalendarFeedError {
+    #[error("Could not find the calendar feed")]
+    NotFound,
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<DeleteCalendarFeedErrorInternal> for DeleteCalendarFeedError {
+    fn from(value: DeleteCalendarFeedErrorInternal) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+impl From<GetCalendarFeedError> for DeleteCalendarFeedError {
+    fn from(value: GetCalendarFeedError) -> Self {
+        match value {
+            GetCalendarFeedError::NotFound => Self::NotFound,
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+/// Revokes a calendar feed of the user, so that its address stops working
+#[tracing::instrument("[COMMAND] Revoking a calendar feed", skip_all)]
+pub async fn delete_calendar_feed(
+    meal_plan_repo: MealPlanRepositoryService,
+    user_id: &Uuid,
+    token: &str,
+) -> Result<(), DeleteCalendarFeedError> {
+    let feed = meal_plan_repo.get_feed_by_token(token).await?;
+
+    if &feed.user_id != user_id {
+        return Err(DeleteCalendarFeedError::NotFound);
+    }
+
+    meal_plan_repo.delete_feed(&feed).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,67 @@
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        commands::meal_plans::delete_feed::{delete_calendar_feed, DeleteCalendarFeedError},
+        entities::meal_plan::CalendarFeed,
+        repositories::{
+            meal_plan::{
+                errors::GetCalendarFeedError, MealPlanRepository, MealPlanRepositoryService,
+            },
+            user::UserRepository,
+        },
+    },
+    test_utils::{another_user_fixture, insert_users, user_fixture},
+};
+
+pub async fn revoking_a_calendar_feed_works(
+    meal_plan_repo: impl MealPlanRepository,
+    user_repo: impl UserRepository,
+) {
+    insert_users(&user_repo).await;
+    let user = user_fixture();
+    let feed = CalendarFeed::new(user.id, "Kitchen tablet".to_string());
+    meal_plan_repo.insert_feed(feed.clone()).await.unwrap();
+    let meal_plan_repo: MealPlanRepositoryService = Arc::new(Box::new(meal_plan_repo));
+
+    delete_calendar_feed(meal_plan_repo.clone(), &user.id, &feed.token)
+        .await
+        .unwrap();
+
+    let error = meal_plan_repo
+        .get_feed_by_token(&feed.token)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, GetCalendarFeedError::NotFound));
+
+    let error = delete_calendar_feed(meal_plan_repo, &user.id, &feed.token)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, DeleteCalendarFeedError::NotFound));
+}
+
+pub async fn revoking_a_calendar_feed_of_someone_else_errors(
+    meal_plan_repo: impl MealPlanRepository,
+    user_repo: impl UserRepository,
+) {
+    insert_users(&user_repo).await;
+    let feed = CalendarFeed::new(user_fixture().id, "Kitchen tablet".to_string());
+    meal_plan_repo.insert_feed(feed.clone()).await.unwrap();
+    let meal_plan_repo: MealPlanRepositoryService = Arc::new(Box::new(meal_plan_repo));
+
+    let error = delete_calendar_feed(
+        meal_plan_repo.clone(),
+        &another_user_fixture().id,
+        &feed.token,
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(error, DeleteCalendarFeedError::NotFound));
+
+    let saved = meal_plan_repo.get_feed_by_token(&feed.token).await.unwrap();
+
+    assert_eq!(saved.id, feed.id);
+}
@@ -0,0 +1,53 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::{
+        meal_plan::in_memory::InMemoryMealPlanRepository, user::in_memory::InMemoryUserRepository,
+    };
+
+    #[tokio::test]
+    async fn revoking_a_calendar_feed_works() {
+        __tests__::revoking_a_calendar_feed_works(
+            InMemoryMealPlanRepository::new(),
+            InMemoryUserRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn revoking_a_calendar_feed_of_someone_else_errors() {
+        __tests__::revoking_a_calendar_feed_of_someone_else_errors(
+            InMemoryMealPlanRepository::new(),
+            InMemoryUserRepository::new(),
+        )
+        .await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        meal_plan::postgres::PostgresMealPlanRepository, user::postgres::PostgresUserRepository,
+    };
+
+    #[sqlx::test]
+    async fn revoking_a_calendar_feed_works(pool: PgPool) {
+        __tests__::revoking_a_calendar_feed_works(
+            PostgresMealPlanRepository::new(pool.clone()),
+            PostgresUserRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn revoking_a_calendar_feed_of_someone_else_errors(pool: PgPool) {
+        __tests__::revoking_a_calendar_feed_of_someone_else_errors(
+            PostgresMealPlanRepository::new(pool.clone()),
+            PostgresUserRepository::new(pool),
+        )
+        .await
+    }
+}
@@ -0,0 +1,5 @@
+pub mod create;
+pub mod create_feed;
+pub mod delete;
+pub mod delete_feed;
+pub mod update;
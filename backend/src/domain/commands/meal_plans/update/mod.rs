@@ -0,0 +1,104 @@
+use common::UpdateMealPlanEntryDTO;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        meal_plan::{MealPlanEntry, MealPlanEntryChangeset},
+        recipe::errors::ValidationError,
+    },
+    repositories::{
+        meal_plan::{
+            errors::{
+                GetMealPlanEntryError, UpdateMealPlanEntryError as UpdateMealPlanEntryErrorInternal,
+            },
+            MealPlanRepositoryService,
+        },
+        recipe::{errors::GetRecipeByIdError, RecipeRepositoryService},
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum UpdateMealPlanEntryError {
+    #[error("Could not find the meal plan entry with the ID {0}")]
+    NotFound(Uuid),
+
+    #[error("Could not find the recipe with the ID {0}")]
+    RecipeNotFound(Uuid),
+
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Report),
+}
+
+impl From<GetMealPlanEntryError> for UpdateMealPlanEntryError {
+    fn from(value: GetMealPlanEntryError) -> Self {
+        match value {
+            GetMealPlanEntryError::NotFound(id) => Self::NotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<GetRecipeByIdError> for UpdateMealPlanEntryError {
+    fn from(value: GetRecipeByIdError) -> Self {
+        match value {
+            GetRecipeByIdError::NotFound(id) => Self::RecipeNotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<UpdateMealPlanEntryErrorInternal> for UpdateMealPlanEntryError {
+    fn from(value: UpdateMealPlanEntryErrorInternal) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+impl From<UpdateMealPlanEntryDTO> for MealPlanEntryChangeset {
+    fn from(value: UpdateMealPlanEntryDTO) -> Self {
+        Self {
+            date: value.date,
+            slot: value.slot.map(|slot| slot.into()),
+            recipe_id: value.recipe_id,
+            servings: value.servings,
+        }
+    }
+}
+
+#[tracing::instrument("[COMMAND] Updating a planned meal", skip(meal_plan_repo, recipe_repo))]
+pub async fn update_meal_plan_entry(
+    meal_plan_repo: MealPlanRepositoryService,
+    recipe_repo: RecipeRepositoryService,
+    user_id: &Uuid,
+    id: &Uuid,
+    changeset: MealPlanEntryChangeset,
+) -> Result<MealPlanEntry, UpdateMealPlanEntryError> {
+    if changeset.is_empty() {
+        return Err(
+            ValidationError::EmptyField(vec!["date", "slot", "recipe_id", "servings"]).into(),
+        );
+    }
+
+    if changeset.servings == Some(0) {
+        return Err(ValidationError::EmptyField(vec!["servings"]).into());
+    }
+
+    let entry = meal_plan_repo.get_by_id(user_id, id).await?;
+
+    if let Some(recipe_id) = &changeset.recipe_id {
+        recipe_repo
+            .get_visible(Some(user_id), recipe_id, None)
+            .await?;
+    }
+
+    meal_plan_repo.update(&entry, changeset).await?;
+
+    let entry = meal_plan_repo.get_by_id(user_id, id).await?;
+
+    Ok(entry)
+}
+
+#[cfg(test)]
+mod tests;
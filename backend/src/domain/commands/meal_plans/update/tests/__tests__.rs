@@ -0,0 +1,192 @@
+use std::sync::Arc;
+
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        commands::meal_plans::update::{update_meal_plan_entry, UpdateMealPlanEntryError},
+        entities::{
+            meal_plan::{MealPlanEntryChangeset, MealSlot},
+            recipe::{errors::ValidationError, Recipe},
+            visibility::Visibility,
+        },
+        repositories::{
+            ingredients::IngredientRepository,
+            meal_plan::{MealPlanRepository, MealPlanRepositoryService},
+            recipe::{RecipeRepository, RecipeRepositoryService},
+            user::UserRepository,
+        },
+    },
+    test_utils::{
+        another_recipe_fixture, another_user_fixture, insert_meal_plan, meal_plan_entry_fixture,
+    },
+};
+
+pub async fn updating_a_planned_meal_works(
+    meal_plan_repo: impl MealPlanRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let entry = meal_plan_entry_fixture();
+    insert_meal_plan(
+        &meal_plan_repo,
+        &recipe_repo,
+        &ingredient_repo,
+        &user_repo,
+        std::slice::from_ref(&entry),
+    )
+    .await;
+    let meal_plan_repo: MealPlanRepositoryService = Arc::new(Box::new(meal_plan_repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let changeset = MealPlanEntryChangeset {
+        slot: Some(MealSlot::Breakfast),
+        servings: Some(6),
+        ..Default::default()
+    };
+
+    let result = update_meal_plan_entry(
+        meal_plan_repo,
+        recipe_repo,
+        &entry.user_id,
+        &entry.id,
+        changeset,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(result.slot, MealSlot::Breakfast);
+    assert_eq!(result.servings, 6);
+    assert_eq!(result.date, entry.date);
+    assert!(result.updated_at > entry.updated_at);
+}
+
+pub async fn updating_a_planned_meal_with_invalid_changes_errors(
+    meal_plan_repo: impl MealPlanRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let entry = meal_plan_entry_fixture();
+    insert_meal_plan(
+        &meal_plan_repo,
+        &recipe_repo,
+        &ingredient_repo,
+        &user_repo,
+        std::slice::from_ref(&entry),
+    )
+    .await;
+    let meal_plan_repo: MealPlanRepositoryService = Arc::new(Box::new(meal_plan_repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let error = update_meal_plan_entry(
+        meal_plan_repo.clone(),
+        recipe_repo.clone(),
+        &entry.user_id,
+        &entry.id,
+        MealPlanEntryChangeset::default(),
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(
+        error,
+        UpdateMealPlanEntryError::ValidationError(ValidationError::EmptyField(_))
+    ));
+
+    let recipe_id = Uuid::now_v7();
+    let changeset = MealPlanEntryChangeset {
+        recipe_id: Some(recipe_id),
+        ..Default::default()
+    };
+
+    let error = update_meal_plan_entry(
+        meal_plan_repo.clone(),
+        recipe_repo.clone(),
+        &entry.user_id,
+        &entry.id,
+        changeset,
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(error, UpdateMealPlanEntryError::RecipeNotFound(id) if id == recipe_id));
+
+    let changeset = MealPlanEntryChangeset {
+        servings: Some(1),
+        ..Default::default()
+    };
+
+    let error = update_meal_plan_entry(
+        meal_plan_repo.clone(),
+        recipe_repo.clone(),
+        &entry.user_id,
+        &recipe_id,
+        changeset.clone(),
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(error, UpdateMealPlanEntryError::NotFound(id) if id == recipe_id));
+
+    let error = update_meal_plan_entry(
+        meal_plan_repo,
+        recipe_repo,
+        &another_user_fixture().id,
+        &entry.id,
+        changeset,
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(error, UpdateMealPlanEntryError::NotFound(id) if id == entry.id));
+}
+
+pub async fn switching_to_a_private_recipe_of_someone_else_errors(
+    meal_plan_repo: impl MealPlanRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let entry = meal_plan_entry_fixture();
+    insert_meal_plan(
+        &meal_plan_repo,
+        &recipe_repo,
+        &ingredient_repo,
+        &user_repo,
+        std::slice::from_ref(&entry),
+    )
+    .await;
+    let recipe = Recipe {
+        visibility: Visibility::Private,
+        owner_id: Some(another_user_fixture().id),
+        ..another_recipe_fixture(1)
+    };
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+    let meal_plan_repo: MealPlanRepositoryService = Arc::new(Box::new(meal_plan_repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let error = update_meal_plan_entry(
+        meal_plan_repo.clone(),
+        recipe_repo,
+        &entry.user_id,
+        &entry.id,
+        MealPlanEntryChangeset {
+            recipe_id: Some(recipe.id),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(error, UpdateMealPlanEntryError::RecipeNotFound(id) if id == recipe.id));
+    assert_eq!(
+        meal_plan_repo
+            .get_by_id(&entry.user_id, &entry.id)
+            .await
+            .unwrap(),
+        entry
+    );
+}
@@ -0,0 +1,87 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::{
+        ingredients::in_memory::InMemoryIngredientRepository,
+        meal_plan::in_memory::InMemoryMealPlanRepository,
+        recipe::in_memory::InMemoryRecipeRepository, user::in_memory::InMemoryUserRepository,
+    };
+
+    #[tokio::test]
+    async fn updating_a_planned_meal_works() {
+        __tests__::updating_a_planned_meal_works(
+            InMemoryMealPlanRepository::new(),
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+            InMemoryUserRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn switching_to_a_private_recipe_of_someone_else_errors() {
+        __tests__::switching_to_a_private_recipe_of_someone_else_errors(
+            InMemoryMealPlanRepository::new(),
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+            InMemoryUserRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn updating_a_planned_meal_with_invalid_changes_errors() {
+        __tests__::updating_a_planned_meal_with_invalid_changes_errors(
+            InMemoryMealPlanRepository::new(),
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+            InMemoryUserRepository::new(),
+        )
+        .await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        ingredients::postgres::PostgresIngredientRepository,
+        meal_plan::postgres::PostgresMealPlanRepository,
+        recipe::postgres::PostgresRecipeRepository, user::postgres::PostgresUserRepository,
+    };
+
+    #[sqlx::test]
+    async fn updating_a_planned_meal_works(pool: PgPool) {
+        __tests__::updating_a_planned_meal_works(
+            PostgresMealPlanRepository::new(pool.clone()),
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresUserRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn switching_to_a_private_recipe_of_someone_else_errors(pool: PgPool) {
+        __tests__::switching_to_a_private_recipe_of_someone_else_errors(
+            PostgresMealPlanRepository::new(pool.clone()),
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresUserRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn updating_a_planned_meal_with_invalid_changes_errors(pool: PgPool) {
+        __tests__::updating_a_planned_meal_with_invalid_changes_errors(
+            PostgresMealPlanRepository::new(pool.clone()),
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresUserRepository::new(pool),
+        )
+        .await
+    }
+}
@@ -0,0 +1,89 @@
+use chrono::{NaiveDate, Utc};
+use common::CreatePantryItemDTO;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{pantry::PantryItem, recipe::IngredientUnit},
+    repositories::{
+        ingredients::{errors::GetIngredientByIdError, IngredientRepositoryService},
+        pantry::{errors::InsertPantryItemError, PantryRepositoryService},
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum AddPantryItemError {
+    #[error("Could not find the ingredient with the ID {0}")]
+    IngredientNotFound(Uuid),
+
+    #[error("The ingredient with the ID {0} is already in the pantry")]
+    AlreadyInPantry(Uuid),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Report),
+}
+
+impl From<GetIngredientByIdError> for AddPantryItemError {
+    fn from(value: GetIngredientByIdError) -> Self {
+        match value {
+            GetIngredientByIdError::NotFound(id) => Self::IngredientNotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<InsertPantryItemError> for AddPantryItemError {
+    fn from(value: InsertPantryItemError) -> Self {
+        match value {
+            InsertPantryItemError::AlreadyInPantry(id) => Self::AlreadyInPantry(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AddPantryItem {
+    pub ingredient_id: Uuid,
+    pub amount: Option<IngredientUnit>,
+    pub expires_at: Option<NaiveDate>,
+}
+
+impl From<CreatePantryItemDTO> for AddPantryItem {
+    fn from(value: CreatePantryItemDTO) -> Self {
+        Self {
+            ingredient_id: value.ingredient_id,
+            amount: value.amount.map(|amount| amount.into()),
+            expires_at: value.expires_at,
+        }
+    }
+}
+
+#[tracing::instrument(
+    "[COMMAND] Adding an item to the pantry",
+    skip(pantry_repo, ingredient_repo)
+)]
+pub async fn add_pantry_item(
+    pantry_repo: PantryRepositoryService,
+    ingredient_repo: IngredientRepositoryService,
+    user_id: &Uuid,
+    input: &AddPantryItem,
+) -> Result<PantryItem, AddPantryItemError> {
+    let ingredient = ingredient_repo.get_by_id(&input.ingredient_id).await?;
+    let now = Utc::now();
+
+    let item = PantryItem {
+        id: Uuid::now_v7(),
+        user_id: *user_id,
+        ingredient,
+        amount: input.amount.clone(),
+        expires_at: input.expires_at,
+        created_at: now,
+        updated_at: now,
+    };
+
+    pantry_repo.insert(item.clone()).await?;
+
+    Ok(item)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,116 @@
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        commands::pantry::add::{add_pantry_item, AddPantryItem, AddPantryItemError},
+        entities::recipe::IngredientUnit,
+        repositories::{
+            ingredients::{IngredientRepository, IngredientRepositoryService},
+            pantry::{PantryRepository, PantryRepositoryService},
+            user::UserRepository,
+        },
+    },
+    test_utils::{another_user_fixture, insert_pantry, recipe_fixture, user_fixture},
+};
+
+fn input(ingredient_id: Uuid) -> AddPantryItem {
+    AddPantryItem {
+        ingredient_id,
+        amount: Some(IngredientUnit::Grams(250.0)),
+        expires_at: NaiveDate::from_ymd_opt(2024, 10, 25),
+    }
+}
+
+pub async fn adding_an_item_to_the_pantry_works(
+    pantry_repo: impl PantryRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    insert_pantry(&pantry_repo, &ingredient_repo, &user_repo, &[]).await;
+    let pantry_repo: PantryRepositoryService = Arc::new(Box::new(pantry_repo));
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ingredient_repo));
+    let tofu = recipe_fixture().ingredients[0].ingredient.clone();
+
+    let result = add_pantry_item(
+        pantry_repo.clone(),
+        ingredient_repo,
+        &user_fixture().id,
+        &input(tofu.id),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(result.ingredient, tofu);
+    assert_eq!(result.amount, Some(IngredientUnit::Grams(250.0)));
+
+    let saved = pantry_repo
+        .get_by_id(&user_fixture().id, &result.id)
+        .await
+        .unwrap();
+
+    assert_eq!(saved.ingredient, result.ingredient);
+    assert_eq!(saved.amount, result.amount);
+    assert_eq!(saved.expires_at, result.expires_at);
+}
+
+pub async fn adding_a_nonexistent_ingredient_errors(
+    pantry_repo: impl PantryRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    insert_pantry(&pantry_repo, &ingredient_repo, &user_repo, &[]).await;
+    let pantry_repo: PantryRepositoryService = Arc::new(Box::new(pantry_repo));
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ingredient_repo));
+    let id = Uuid::now_v7();
+
+    let error = add_pantry_item(pantry_repo, ingredient_repo, &user_fixture().id, &input(id))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, AddPantryItemError::IngredientNotFound(e) if e == id));
+}
+
+pub async fn adding_an_ingredient_twice_errors(
+    pantry_repo: impl PantryRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    insert_pantry(&pantry_repo, &ingredient_repo, &user_repo, &[]).await;
+    let pantry_repo: PantryRepositoryService = Arc::new(Box::new(pantry_repo));
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ingredient_repo));
+    let id = recipe_fixture().ingredients[0].ingredient.id;
+
+    add_pantry_item(
+        pantry_repo.clone(),
+        ingredient_repo.clone(),
+        &user_fixture().id,
+        &input(id),
+    )
+    .await
+    .unwrap();
+
+    let error = add_pantry_item(
+        pantry_repo.clone(),
+        ingredient_repo.clone(),
+        &user_fixture().id,
+        &input(id),
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(error, AddPantryItemError::AlreadyInPantry(e) if e == id));
+
+    // Somebody else has a pantry of their own
+    add_pantry_item(
+        pantry_repo,
+        ingredient_repo,
+        &another_user_fixture().id,
+        &input(id),
+    )
+    .await
+    .unwrap();
+}
@@ -0,0 +1,79 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::{
+        ingredients::in_memory::InMemoryIngredientRepository,
+        pantry::in_memory::InMemoryPantryRepository, user::in_memory::InMemoryUserRepository,
+    };
+
+    #[tokio::test]
+    async fn adding_an_item_to_the_pantry_works() {
+        __tests__::adding_an_item_to_the_pantry_works(
+            InMemoryPantryRepository::new(),
+            InMemoryIngredientRepository::new(),
+            InMemoryUserRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn adding_a_nonexistent_ingredient_errors() {
+        __tests__::adding_a_nonexistent_ingredient_errors(
+            InMemoryPantryRepository::new(),
+            InMemoryIngredientRepository::new(),
+            InMemoryUserRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn adding_an_ingredient_twice_errors() {
+        __tests__::adding_an_ingredient_twice_errors(
+            InMemoryPantryRepository::new(),
+            InMemoryIngredientRepository::new(),
+            InMemoryUserRepository::new(),
+        )
+        .await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        ingredients::postgres::PostgresIngredientRepository,
+        pantry::postgres::PostgresPantryRepository, user::postgres::PostgresUserRepository,
+    };
+
+    #[sqlx::test]
+    async fn adding_an_item_to_the_pantry_works(pool: PgPool) {
+        __tests__::adding_an_item_to_the_pantry_works(
+            PostgresPantryRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresUserRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn adding_a_nonexistent_ingredient_errors(pool: PgPool) {
+        __tests__::adding_a_nonexistent_ingredient_errors(
+            PostgresPantryRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresUserRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn adding_an_ingredient_twice_errors(pool: PgPool) {
+        __tests__::adding_an_ingredient_twice_errors(
+            PostgresPantryRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresUserRepository::new(pool),
+        )
+        .await
+    }
+}
@@ -0,0 +1,46 @@
+use uuid::Uuid;
+
+use crate::domain::repositories::pantry::{
+    errors::{DeletePantryItemError, GetPantryItemError},
+    PantryRepositoryService,
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum RemovePantryItemError {
+    #[error("Could not find the pantry item with the ID {0}")]
+    NotFound(Uuid),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<DeletePantryItemError> for RemovePantryItemError {
+    fn from(value: DeletePantryItemError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+impl From<GetPantryItemError> for RemovePantryItemError {
+    fn from(value: GetPantryItemError) -> Self {
+        match value {
+            GetPantryItemError::NotFound(id) => Self::NotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+#[tracing::instrument("[COMMAND] Removing an item from the pantry", skip(pantry_repo))]
+pub async fn remove_pantry_item(
+    pantry_repo: PantryRepositoryService,
+    user_id: &Uuid,
+    id: &Uuid,
+) -> Result<(), RemovePantryItemError> {
+    let item = pantry_repo.get_by_id(user_id, id).await?;
+
+    pantry_repo.delete(&item).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests;
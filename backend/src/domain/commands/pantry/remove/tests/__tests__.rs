@@ -0,0 +1,45 @@
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        commands::pantry::remove::{remove_pantry_item, RemovePantryItemError},
+        repositories::{
+            ingredients::IngredientRepository,
+            pantry::{PantryRepository, PantryRepositoryService},
+            user::UserRepository,
+        },
+    },
+    test_utils::{another_user_fixture, insert_pantry, pantry_item_fixture},
+};
+
+pub async fn removing_a_pantry_item_works(
+    pantry_repo: impl PantryRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let item = pantry_item_fixture();
+    insert_pantry(
+        &pantry_repo,
+        &ingredient_repo,
+        &user_repo,
+        std::slice::from_ref(&item),
+    )
+    .await;
+    let pantry_repo: PantryRepositoryService = Arc::new(Box::new(pantry_repo));
+
+    let error = remove_pantry_item(pantry_repo.clone(), &another_user_fixture().id, &item.id)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, RemovePantryItemError::NotFound(id) if id == item.id));
+
+    remove_pantry_item(pantry_repo.clone(), &item.user_id, &item.id)
+        .await
+        .unwrap();
+
+    let error = remove_pantry_item(pantry_repo, &item.user_id, &item.id)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, RemovePantryItemError::NotFound(id) if id == item.id));
+}
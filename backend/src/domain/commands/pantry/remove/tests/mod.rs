@@ -0,0 +1,39 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::{
+        ingredients::in_memory::InMemoryIngredientRepository,
+        pantry::in_memory::InMemoryPantryRepository, user::in_memory::InMemoryUserRepository,
+    };
+
+    #[tokio::test]
+    async fn removing_a_pantry_item_works() {
+        __tests__::removing_a_pantry_item_works(
+            InMemoryPantryRepository::new(),
+            InMemoryIngredientRepository::new(),
+            InMemoryUserRepository::new(),
+        )
+        .await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        ingredients::postgres::PostgresIngredientRepository,
+        pantry::postgres::PostgresPantryRepository, user::postgres::PostgresUserRepository,
+    };
+
+    #[sqlx::test]
+    async fn removing_a_pantry_item_works(pool: PgPool) {
+        __tests__::removing_a_pantry_item_works(
+            PostgresPantryRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresUserRepository::new(pool),
+        )
+        .await
+    }
+}
@@ -0,0 +1,76 @@
+use chrono::NaiveDate;
+use common::UpdatePantryItemDTO;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{pantry::PantryItem, recipe::IngredientUnit},
+    repositories::pantry::{
+        errors::{GetPantryItemError, UpdatePantryItemError as UpdatePantryItemErrorInternal},
+        PantryRepositoryService,
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum UpdatePantryItemError {
+    #[error("Could not find the pantry item with the ID {0}")]
+    NotFound(Uuid),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Report),
+}
+
+impl From<GetPantryItemError> for UpdatePantryItemError {
+    fn from(value: GetPantryItemError) -> Self {
+        match value {
+            GetPantryItemError::NotFound(id) => Self::NotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<UpdatePantryItemErrorInternal> for UpdatePantryItemError {
+    fn from(value: UpdatePantryItemErrorInternal) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+/// Replaces both the amount and the expiry date of an item
+#[derive(Debug, Clone, Default)]
+pub struct UpdatePantryItem {
+    pub amount: Option<IngredientUnit>,
+    pub expires_at: Option<NaiveDate>,
+}
+
+impl From<UpdatePantryItemDTO> for UpdatePantryItem {
+    fn from(value: UpdatePantryItemDTO) -> Self {
+        Self {
+            amount: value.amount.map(|amount| amount.into()),
+            expires_at: value.expires_at,
+        }
+    }
+}
+
+#[tracing::instrument("[COMMAND] Updating a pantry item", skip(pantry_repo))]
+pub async fn update_pantry_item(
+    pantry_repo: PantryRepositoryService,
+    user_id: &Uuid,
+    id: &Uuid,
+    input: UpdatePantryItem,
+) -> Result<PantryItem, UpdatePantryItemError> {
+    let item = pantry_repo.get_by_id(user_id, id).await?;
+
+    pantry_repo
+        .update(&PantryItem {
+            amount: input.amount,
+            expires_at: input.expires_at,
+            ..item
+        })
+        .await?;
+
+    let result = pantry_repo.get_by_id(user_id, id).await?;
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests;
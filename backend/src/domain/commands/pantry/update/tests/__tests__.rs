@@ -0,0 +1,86 @@
+use std::sync::Arc;
+
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        commands::pantry::update::{update_pantry_item, UpdatePantryItem, UpdatePantryItemError},
+        entities::recipe::IngredientUnit,
+        repositories::{
+            ingredients::IngredientRepository,
+            pantry::{PantryRepository, PantryRepositoryService},
+            user::UserRepository,
+        },
+    },
+    test_utils::{another_user_fixture, insert_pantry, pantry_item_fixture, user_fixture},
+};
+
+pub async fn updating_a_pantry_item_works(
+    pantry_repo: impl PantryRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let item = pantry_item_fixture();
+    insert_pantry(
+        &pantry_repo,
+        &ingredient_repo,
+        &user_repo,
+        std::slice::from_ref(&item),
+    )
+    .await;
+    let pantry_repo: PantryRepositoryService = Arc::new(Box::new(pantry_repo));
+
+    let input = UpdatePantryItem {
+        amount: Some(IngredientUnit::Grams(100.0)),
+        expires_at: None,
+    };
+
+    let result = update_pantry_item(pantry_repo, &user_fixture().id, &item.id, input)
+        .await
+        .unwrap();
+
+    assert_eq!(result.amount, Some(IngredientUnit::Grams(100.0)));
+    assert_eq!(result.expires_at, None);
+    assert_eq!(result.ingredient, item.ingredient);
+    assert!(result.updated_at > item.updated_at);
+}
+
+pub async fn updating_a_nonexistent_pantry_item_errors(
+    pantry_repo: impl PantryRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    insert_pantry(
+        &pantry_repo,
+        &ingredient_repo,
+        &user_repo,
+        &[pantry_item_fixture()],
+    )
+    .await;
+    let pantry_repo: PantryRepositoryService = Arc::new(Box::new(pantry_repo));
+    let id = Uuid::now_v7();
+
+    let error = update_pantry_item(
+        pantry_repo.clone(),
+        &user_fixture().id,
+        &id,
+        UpdatePantryItem::default(),
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(error, UpdatePantryItemError::NotFound(e) if e == id));
+
+    let item = pantry_item_fixture();
+    let error = update_pantry_item(
+        pantry_repo,
+        &another_user_fixture().id,
+        &item.id,
+        UpdatePantryItem::default(),
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(error, UpdatePantryItemError::NotFound(e) if e == item.id));
+}
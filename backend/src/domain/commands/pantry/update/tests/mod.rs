@@ -0,0 +1,59 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::{
+        ingredients::in_memory::InMemoryIngredientRepository,
+        pantry::in_memory::InMemoryPantryRepository, user::in_memory::InMemoryUserRepository,
+    };
+
+    #[tokio::test]
+    async fn updating_a_pantry_item_works() {
+        __tests__::updating_a_pantry_item_works(
+            InMemoryPantryRepository::new(),
+            InMemoryIngredientRepository::new(),
+            InMemoryUserRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn updating_a_nonexistent_pantry_item_errors() {
+        __tests__::updating_a_nonexistent_pantry_item_errors(
+            InMemoryPantryRepository::new(),
+            InMemoryIngredientRepository::new(),
+            InMemoryUserRepository::new(),
+        )
+        .await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        ingredients::postgres::PostgresIngredientRepository,
+        pantry::postgres::PostgresPantryRepository, user::postgres::PostgresUserRepository,
+    };
+
+    #[sqlx::test]
+    async fn updating_a_pantry_item_works(pool: PgPool) {
+        __tests__::updating_a_pantry_item_works(
+            PostgresPantryRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresUserRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn updating_a_nonexistent_pantry_item_errors(pool: PgPool) {
+        __tests__::updating_a_nonexistent_pantry_item_errors(
+            PostgresPantryRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresUserRepository::new(pool),
+        )
+        .await
+    }
+}
@@ -0,0 +1,46 @@
+use uuid::Uuid;
+
+use crate::domain::repositories::recipe_note::{
+    errors::{DeleteRecipeNoteError as DeleteRecipeNoteErrorInternal, GetRecipeNoteError},
+    RecipeNoteRepositoryService,
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum DeleteRecipeNoteError {
+    #[error("The recipe with the ID {0} has no notes")]
+    NotFound(Uuid),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Report),
+}
+
+impl From<GetRecipeNoteError> for DeleteRecipeNoteError {
+    fn from(value: GetRecipeNoteError) -> Self {
+        match value {
+            GetRecipeNoteError::NotFound(id) => Self::NotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<DeleteRecipeNoteErrorInternal> for DeleteRecipeNoteError {
+    fn from(value: DeleteRecipeNoteErrorInternal) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+#[tracing::instrument("[COMMAND] Deleting notes on a recipe", skip(note_repo))]
+pub async fn delete_recipe_note(
+    note_repo: RecipeNoteRepositoryService,
+    user_id: &Uuid,
+    recipe_id: &Uuid,
+) -> Result<(), DeleteRecipeNoteError> {
+    let note = note_repo.get(user_id, recipe_id).await?;
+
+    note_repo.delete(&note).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,60 @@
+use std::sync::Arc;
+
+use pretty_assertions::assert_eq;
+
+use crate::{
+    domain::{
+        commands::recipe_notes::delete::{delete_recipe_note, DeleteRecipeNoteError},
+        repositories::{
+            ingredients::IngredientRepository,
+            recipe::RecipeRepository,
+            recipe_note::{
+                errors::GetRecipeNoteError, RecipeNoteRepository, RecipeNoteRepositoryService,
+            },
+            user::UserRepository,
+        },
+    },
+    test_utils::{
+        another_user_fixture, insert_all_ingredients_of_recipe, insert_users, recipe_fixture,
+        recipe_note_fixture,
+    },
+};
+
+pub async fn deleting_notes_works(
+    note_repo: impl RecipeNoteRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let recipe = recipe_fixture();
+    insert_users(&user_repo).await;
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+    note_repo.save(&recipe_note_fixture()).await.unwrap();
+    let note_repo: RecipeNoteRepositoryService = Arc::new(Box::new(note_repo));
+    let user_id = recipe_note_fixture().user_id;
+
+    let error = delete_recipe_note(note_repo.clone(), &another_user_fixture().id, &recipe.id)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, DeleteRecipeNoteError::NotFound(_)));
+
+    delete_recipe_note(note_repo.clone(), &user_id, &recipe.id)
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        note_repo.get(&user_id, &recipe.id).await.unwrap_err(),
+        GetRecipeNoteError::NotFound(_)
+    ));
+
+    let error = delete_recipe_note(note_repo, &user_id, &recipe.id)
+        .await
+        .unwrap_err();
+
+    match error {
+        DeleteRecipeNoteError::NotFound(id) => assert_eq!(id, recipe.id),
+        e => panic!("Wrong error returned: {e}"),
+    }
+}
@@ -0,0 +1,45 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::{
+        ingredients::in_memory::InMemoryIngredientRepository,
+        recipe::in_memory::InMemoryRecipeRepository,
+        recipe_note::in_memory::InMemoryRecipeNoteRepository,
+        user::in_memory::InMemoryUserRepository,
+    };
+
+    #[tokio::test]
+    async fn deleting_notes_works() {
+        __tests__::deleting_notes_works(
+            InMemoryRecipeNoteRepository::new(),
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+            InMemoryUserRepository::new(),
+        )
+        .await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        ingredients::postgres::PostgresIngredientRepository,
+        recipe::postgres::PostgresRecipeRepository,
+        recipe_note::postgres::PostgresRecipeNoteRepository,
+        user::postgres::PostgresUserRepository,
+    };
+
+    #[sqlx::test]
+    async fn deleting_notes_works(pool: PgPool) {
+        __tests__::deleting_notes_works(
+            PostgresRecipeNoteRepository::new(pool.clone()),
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresUserRepository::new(pool),
+        )
+        .await
+    }
+}
@@ -0,0 +1,93 @@
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{recipe::errors::ValidationError, recipe_note::RecipeNote},
+    repositories::{
+        recipe::{errors::GetRecipeByIdError, RecipeRepositoryService},
+        recipe_note::{
+            errors::{GetRecipeNoteError, SaveRecipeNoteError as SaveRecipeNoteErrorInternal},
+            RecipeNoteRepositoryService,
+        },
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum SaveRecipeNoteError {
+    #[error("Could not find the recipe with the ID {0}")]
+    RecipeNotFound(Uuid),
+
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Report),
+}
+
+impl From<GetRecipeByIdError> for SaveRecipeNoteError {
+    fn from(value: GetRecipeByIdError) -> Self {
+        match value {
+            GetRecipeByIdError::NotFound(id) => Self::RecipeNotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<GetRecipeNoteError> for SaveRecipeNoteError {
+    fn from(value: GetRecipeNoteError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+impl From<SaveRecipeNoteErrorInternal> for SaveRecipeNoteError {
+    fn from(value: SaveRecipeNoteErrorInternal) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+/// Writes the notes of a recipe, replacing the previous ones. The recipe itself stays untouched.
+#[tracing::instrument("[COMMAND] Saving notes on a recipe", skip(note_repo, recipe_repo))]
+pub async fn save_recipe_note(
+    note_repo: RecipeNoteRepositoryService,
+    recipe_repo: RecipeRepositoryService,
+    user_id: &Uuid,
+    recipe_id: &Uuid,
+    content: &str,
+) -> Result<RecipeNote, SaveRecipeNoteError> {
+    let content = content.trim();
+
+    if content.is_empty() {
+        return Err(ValidationError::EmptyField(vec!["content"]).into());
+    }
+
+    recipe_repo
+        .get_visible(Some(user_id), recipe_id, None)
+        .await?;
+
+    let now = Utc::now();
+    let note = match note_repo.get(user_id, recipe_id).await {
+        Ok(existing) if existing.content == content => return Ok(existing),
+        Ok(existing) => RecipeNote {
+            content: content.to_string(),
+            updated_at: now,
+            ..existing
+        },
+        Err(GetRecipeNoteError::NotFound(_)) => RecipeNote {
+            user_id: *user_id,
+            recipe_id: *recipe_id,
+            content: content.to_string(),
+            created_at: now,
+            updated_at: now,
+        },
+        Err(e) => return Err(e.into()),
+    };
+
+    note_repo.save(&note).await?;
+
+    let note = note_repo.get(user_id, recipe_id).await?;
+
+    Ok(note)
+}
+
+#[cfg(test)]
+mod tests;
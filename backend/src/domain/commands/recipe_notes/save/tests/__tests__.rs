@@ -0,0 +1,155 @@
+use std::sync::Arc;
+
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        commands::recipe_notes::save::{save_recipe_note, SaveRecipeNoteError},
+        entities::recipe::errors::ValidationError,
+        repositories::{
+            ingredients::IngredientRepository,
+            recipe::{RecipeRepository, RecipeRepositoryService},
+            recipe_note::{RecipeNoteRepository, RecipeNoteRepositoryService},
+            user::UserRepository,
+        },
+    },
+    test_utils::{
+        another_user_fixture, insert_all_ingredients_of_recipe, insert_users,
+        private_recipe_fixture, recipe_fixture, recipe_note_fixture, user_fixture,
+    },
+};
+
+pub async fn saving_notes_works(
+    note_repo: impl RecipeNoteRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let recipe = recipe_fixture();
+    let existing = recipe_note_fixture();
+    insert_users(&user_repo).await;
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+    note_repo.save(&existing).await.unwrap();
+    let note_repo: RecipeNoteRepositoryService = Arc::new(Box::new(note_repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+    let recipe = recipe_repo.get_by_id(&recipe.id).await.unwrap();
+
+    let unchanged = save_recipe_note(
+        note_repo.clone(),
+        recipe_repo.clone(),
+        &existing.user_id,
+        &recipe.id,
+        &format!(" {} ", existing.content),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(unchanged, existing);
+
+    let note = save_recipe_note(
+        note_repo.clone(),
+        recipe_repo.clone(),
+        &existing.user_id,
+        &recipe.id,
+        "Needs more garlic",
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(note.content, "Needs more garlic");
+    assert_eq!(note.created_at, existing.created_at);
+    assert!(note.updated_at > existing.updated_at);
+
+    // Somebody else writes notes of their own, leaving these alone
+    let someone_elses = save_recipe_note(
+        note_repo.clone(),
+        recipe_repo.clone(),
+        &another_user_fixture().id,
+        &recipe.id,
+        "Not spicy enough",
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(someone_elses.user_id, another_user_fixture().id);
+    assert_eq!(
+        note_repo
+            .get(&existing.user_id, &recipe.id)
+            .await
+            .unwrap()
+            .content,
+        "Needs more garlic"
+    );
+
+    let recipe_after = recipe_repo.get_by_id(&recipe.id).await.unwrap();
+
+    assert_eq!(recipe_after.description, recipe.description);
+    assert_eq!(recipe_after.updated_at, recipe.updated_at);
+}
+
+pub async fn saving_invalid_notes_errors(
+    note_repo: impl RecipeNoteRepository,
+    recipe_repo: impl RecipeRepository,
+) {
+    let note_repo: RecipeNoteRepositoryService = Arc::new(Box::new(note_repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+    let recipe_id = Uuid::from_u128(404);
+
+    let error = save_recipe_note(
+        note_repo.clone(),
+        recipe_repo.clone(),
+        &user_fixture().id,
+        &recipe_id,
+        "  ",
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(
+        error,
+        SaveRecipeNoteError::ValidationError(ValidationError::EmptyField(_))
+    ));
+
+    let error = save_recipe_note(
+        note_repo,
+        recipe_repo,
+        &user_fixture().id,
+        &recipe_id,
+        "Too salty",
+    )
+    .await
+    .unwrap_err();
+
+    match error {
+        SaveRecipeNoteError::RecipeNotFound(id) => assert_eq!(id, recipe_id),
+        e => panic!("Wrong error returned: {e}"),
+    }
+}
+
+pub async fn saving_notes_on_a_private_recipe_of_someone_else_errors(
+    note_repo: impl RecipeNoteRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let recipe = private_recipe_fixture();
+    insert_users(&user_repo).await;
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+    let note_repo: RecipeNoteRepositoryService = Arc::new(Box::new(note_repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let error = save_recipe_note(
+        note_repo,
+        recipe_repo,
+        &another_user_fixture().id,
+        &recipe.id,
+        "Needs more garlic",
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(error, SaveRecipeNoteError::RecipeNotFound(id) if id == recipe.id));
+}
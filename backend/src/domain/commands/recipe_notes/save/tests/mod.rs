@@ -0,0 +1,85 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::{
+        ingredients::in_memory::InMemoryIngredientRepository,
+        recipe::in_memory::InMemoryRecipeRepository,
+        recipe_note::in_memory::InMemoryRecipeNoteRepository,
+        user::in_memory::InMemoryUserRepository,
+    };
+
+    #[tokio::test]
+    async fn saving_notes_works() {
+        __tests__::saving_notes_works(
+            InMemoryRecipeNoteRepository::new(),
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+            InMemoryUserRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn saving_notes_on_a_private_recipe_of_someone_else_errors() {
+        __tests__::saving_notes_on_a_private_recipe_of_someone_else_errors(
+            InMemoryRecipeNoteRepository::new(),
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+            InMemoryUserRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn saving_invalid_notes_errors() {
+        __tests__::saving_invalid_notes_errors(
+            InMemoryRecipeNoteRepository::new(),
+            InMemoryRecipeRepository::new(),
+        )
+        .await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        ingredients::postgres::PostgresIngredientRepository,
+        recipe::postgres::PostgresRecipeRepository,
+        recipe_note::postgres::PostgresRecipeNoteRepository,
+        user::postgres::PostgresUserRepository,
+    };
+
+    #[sqlx::test]
+    async fn saving_notes_works(pool: PgPool) {
+        __tests__::saving_notes_works(
+            PostgresRecipeNoteRepository::new(pool.clone()),
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresUserRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn saving_notes_on_a_private_recipe_of_someone_else_errors(pool: PgPool) {
+        __tests__::saving_notes_on_a_private_recipe_of_someone_else_errors(
+            PostgresRecipeNoteRepository::new(pool.clone()),
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresUserRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn saving_invalid_notes_errors(pool: PgPool) {
+        __tests__::saving_invalid_notes_errors(
+            PostgresRecipeNoteRepository::new(pool.clone()),
+            PostgresRecipeRepository::new(pool),
+        )
+        .await
+    }
+}
@@ -0,0 +1,289 @@
+use common::{BatchEditRecipeDTO, IngredientOperationDTO};
+use uuid::Uuid;
+
+use crate::domain::{
+    commands::recipes::update::UpdateRecipe,
+    entities::{
+        precondition::Precondition,
+        recipe::{
+            errors::ValidationError, IngredientAmountData, IngredientInRecipeChangeset,
+            IngredientWithAmount, Recipe, RecipeChangeset,
+        },
+    },
+    repositories::{
+        ingredients::errors::GetIngredientByIdError,
+        recipe::errors::{
+            AddIngredientIntoRecipeError, DeleteIngredientFromRecipeError, GetRecipeByIdError,
+            UpdateIngredientInRecipeError, UpdateRecipeError,
+        },
+        unit_of_work::{
+            errors::{BeginTransactionError, CommitTransactionError},
+            UnitOfWorkService,
+        },
+    },
+};
+
+/// A single change to the ingredient list of a recipe
+#[derive(Debug)]
+pub enum IngredientOperation {
+    Add(IngredientAmountData),
+    Update {
+        entry_id: Uuid,
+        changeset: IngredientInRecipeChangeset,
+    },
+    Remove {
+        entry_id: Uuid,
+    },
+}
+
+impl From<IngredientOperationDTO> for IngredientOperation {
+    fn from(value: IngredientOperationDTO) -> Self {
+        match value {
+            IngredientOperationDTO::Add(ingredient) => Self::Add(ingredient.into()),
+            IngredientOperationDTO::Update { entry_id, changes } => Self::Update {
+                entry_id,
+                changeset: changes.into(),
+            },
+            IngredientOperationDTO::Remove { entry_id } => Self::Remove { entry_id },
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct BatchEditRecipe {
+    pub recipe: UpdateRecipe,
+    /// Applied in order, so moving an entry takes the additions and removals before it into account
+    pub operations: Vec<IngredientOperation>,
+}
+
+impl From<BatchEditRecipeDTO> for BatchEditRecipe {
+    fn from(value: BatchEditRecipeDTO) -> Self {
+        Self {
+            recipe: value.recipe.map(Into::into).unwrap_or_default(),
+            operations: value.operations.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum IngredientOperationError {
+    #[error("Could not find the ingredient with ID {0}")]
+    IngredientNotFound(Uuid),
+
+    #[error("The recipe has no ingredient entry with ID {0}")]
+    NotInRecipe(Uuid),
+
+    #[error("You did not provide any changes to the ingredient entry with ID {0}")]
+    ChangesetEmpty(Uuid),
+
+    #[error("There is only one ingredient left in the recipe. A recipe should have one ingredient at minimum.")]
+    LastIngredient,
+}
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum BatchEditRecipeError {
+    #[error("You did not provide any changes to be made")]
+    ChangesetEmpty,
+
+    #[error("Could not find the recipe with ID {0}")]
+    NotFound(Uuid),
+
+    #[error("The recipe with ID {0} has changed since you last fetched it")]
+    PreconditionFailed(Uuid),
+
+    #[error("Only the owner can make changes to the recipe with ID {0}")]
+    NotOwner(Uuid),
+
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError),
+
+    #[error("{} of the ingredient operations are invalid", .0.len())]
+    InvalidOperations(Vec<(usize, IngredientOperationError)>),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Report),
+}
+
+impl From<GetRecipeByIdError> for BatchEditRecipeError {
+    fn from(value: GetRecipeByIdError) -> Self {
+        match value {
+            GetRecipeByIdError::NotFound(id) => Self::NotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<GetIngredientByIdError> for BatchEditRecipeError {
+    fn from(value: GetIngredientByIdError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+impl From<UpdateRecipeError> for BatchEditRecipeError {
+    fn from(value: UpdateRecipeError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+impl From<AddIngredientIntoRecipeError> for BatchEditRecipeError {
+    fn from(value: AddIngredientIntoRecipeError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+impl From<UpdateIngredientInRecipeError> for BatchEditRecipeError {
+    fn from(value: UpdateIngredientInRecipeError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+impl From<DeleteIngredientFromRecipeError> for BatchEditRecipeError {
+    fn from(value: DeleteIngredientFromRecipeError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+impl From<BeginTransactionError> for BatchEditRecipeError {
+    fn from(value: BeginTransactionError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+impl From<CommitTransactionError> for BatchEditRecipeError {
+    fn from(value: CommitTransactionError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+/// An operation that passed validation, with everything needed to apply it
+enum ValidatedOperation {
+    Add(IngredientWithAmount),
+    Update(IngredientWithAmount, IngredientInRecipeChangeset),
+    Remove(IngredientWithAmount),
+}
+
+fn position_of(ingredients: &[IngredientWithAmount], id: &Uuid) -> Option<usize> {
+    ingredients.iter().position(|i| i.id == *id)
+}
+
+/// Edits the recipe and its ingredients in a single transaction.
+/// Every operation is checked against the recipe before anything is written,
+/// so the batch either goes through as a whole or not at all.
+/// The same people that can update the recipe on its own can edit it in a batch.
+pub async fn batch_edit_recipe(
+    unit_of_work: UnitOfWorkService,
+    user_id: Option<&Uuid>,
+    recipe_id: &Uuid,
+    input: BatchEditRecipe,
+    precondition: &Precondition,
+) -> Result<Recipe, BatchEditRecipeError> {
+    let changeset: RecipeChangeset = input.recipe.try_into()?;
+    if changeset.is_empty() && input.operations.is_empty() {
+        return Err(BatchEditRecipeError::ChangesetEmpty);
+    }
+
+    let tx = unit_of_work.begin().await?;
+    let recipe_repo = tx.recipes();
+    let ingredient_repo = tx.ingredients();
+
+    let recipe = tx.lock_recipe(recipe_id).await?;
+    if !recipe.is_visible_to(user_id, None) {
+        return Err(BatchEditRecipeError::NotFound(*recipe_id));
+    }
+
+    if !recipe.is_editable_by(user_id)
+        || (changeset.visibility.is_some() && !recipe.is_owned_by(user_id))
+    {
+        return Err(BatchEditRecipeError::NotOwner(*recipe_id));
+    }
+
+    if !precondition.holds(&recipe.entity_tag()) {
+        return Err(BatchEditRecipeError::PreconditionFailed(*recipe_id));
+    }
+
+    // The ingredient list as it will look after each operation
+    let mut ingredients: Vec<IngredientWithAmount> = recipe.ingredients.to_vec();
+    let mut operations = vec![];
+    let mut errors = vec![];
+
+    for (index, operation) in input.operations.into_iter().enumerate() {
+        let validated = match operation {
+            IngredientOperation::Add(data) => {
+                match ingredient_repo.get_by_id(&data.ingredient_id).await {
+                    Ok(ingredient) => {
+                        let ingredient = data.into_entry(ingredient);
+                        ingredients.push(ingredient.clone());
+                        Ok(ValidatedOperation::Add(ingredient))
+                    }
+                    Err(GetIngredientByIdError::NotFound(id)) => {
+                        Err(IngredientOperationError::IngredientNotFound(id))
+                    }
+                    Err(e) => return Err(e.into()),
+                }
+            }
+            IngredientOperation::Update {
+                entry_id,
+                changeset,
+            } if changeset.is_empty() => Err(IngredientOperationError::ChangesetEmpty(entry_id)),
+            IngredientOperation::Update {
+                entry_id,
+                changeset,
+            } => match position_of(&ingredients, &entry_id) {
+                Some(i) => {
+                    let previous = ingredients[i].clone();
+                    changeset.apply(&mut ingredients[i]);
+                    changeset.reorder(&mut ingredients, i);
+                    Ok(ValidatedOperation::Update(previous, changeset))
+                }
+                None => Err(IngredientOperationError::NotInRecipe(entry_id)),
+            },
+            IngredientOperation::Remove { entry_id } => {
+                match position_of(&ingredients, &entry_id) {
+                    Some(_) if ingredients.len() == 1 => {
+                        Err(IngredientOperationError::LastIngredient)
+                    }
+                    Some(i) => Ok(ValidatedOperation::Remove(ingredients.remove(i))),
+                    None => Err(IngredientOperationError::NotInRecipe(entry_id)),
+                }
+            }
+        };
+
+        match validated {
+            Ok(operation) => operations.push(operation),
+            Err(error) => errors.push((index, error)),
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(BatchEditRecipeError::InvalidOperations(errors));
+    }
+
+    if !changeset.is_empty() {
+        recipe_repo.update(&recipe, changeset).await?;
+    }
+
+    for operation in operations {
+        match operation {
+            ValidatedOperation::Add(ingredient) => {
+                recipe_repo.add_ingredient(&recipe, ingredient).await?
+            }
+            ValidatedOperation::Update(ingredient, changeset) => {
+                recipe_repo
+                    .update_ingredient(&recipe, &ingredient, changeset)
+                    .await?
+            }
+            ValidatedOperation::Remove(ingredient) => {
+                recipe_repo.delete_ingredient(&recipe, &ingredient).await?
+            }
+        }
+    }
+
+    let recipe = recipe_repo.get_by_id(recipe_id).await?;
+
+    tx.commit().await?;
+
+    Ok(recipe)
+}
+
+#[cfg(test)]
+mod tests;
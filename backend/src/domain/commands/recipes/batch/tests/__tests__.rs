@@ -0,0 +1,277 @@
+use std::sync::Arc;
+
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        commands::recipes::{
+            batch::{
+                batch_edit_recipe, BatchEditRecipe, BatchEditRecipeError, IngredientOperation,
+                IngredientOperationError,
+            },
+            update::UpdateRecipe,
+        },
+        entities::{
+            precondition::Precondition,
+            recipe::{IngredientAmountData, IngredientInRecipeChangeset, IngredientUnit, Recipe},
+            visibility::Visibility,
+        },
+        repositories::{
+            ingredients::IngredientRepository,
+            recipe::RecipeRepository,
+            unit_of_work::{UnitOfWork, UnitOfWorkService},
+            user::UserRepository,
+        },
+    },
+    test_utils::{
+        another_recipe_fixture, another_user_fixture, ingredient_fixture,
+        insert_all_ingredients_of_recipe, insert_users, recipe_fixture, user_fixture,
+    },
+};
+
+pub async fn editing_a_recipe_in_a_batch_applies_every_change(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    unit_of_work: impl UnitOfWork,
+) {
+    let recipe = recipe_fixture();
+    let [tofu, broccoli, garlic] = &recipe.ingredients[..] else {
+        panic!("The recipe fixture should have three ingredients");
+    };
+    let beef = ingredient_fixture();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    ingredient_repo.insert(beef.clone()).await.unwrap();
+    repo.insert(recipe.clone()).await.unwrap();
+
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
+
+    let input = BatchEditRecipe {
+        recipe: UpdateRecipe {
+            name: Some("Hoisin Beef and Broccoli".to_string()),
+            ..Default::default()
+        },
+        operations: vec![
+            IngredientOperation::Add(IngredientAmountData {
+                ingredient_id: beef.id,
+                amount: IngredientUnit::Grams(500.0),
+                optional: false,
+                notes: None,
+                group: None,
+            }),
+            IngredientOperation::Remove { entry_id: tofu.id },
+            IngredientOperation::Update {
+                entry_id: garlic.id,
+                changeset: IngredientInRecipeChangeset {
+                    amount: Some(IngredientUnit::Other {
+                        amount: 6.0,
+                        unit: "clove".to_string(),
+                    }),
+                    position: Some(0),
+                    ..Default::default()
+                },
+            },
+        ],
+    };
+
+    let result = batch_edit_recipe(unit_of_work, None, &recipe.id, input, &Precondition::Any)
+        .await
+        .unwrap();
+
+    assert_eq!(result.name, "Hoisin Beef and Broccoli");
+
+    let ingredients: Vec<_> = result
+        .ingredients
+        .iter()
+        .map(|i| (i.ingredient.id, i.amount.clone()))
+        .collect();
+
+    let expected = vec![
+        (
+            garlic.ingredient.id,
+            IngredientUnit::Other {
+                amount: 6.0,
+                unit: "clove".to_string(),
+            },
+        ),
+        (broccoli.ingredient.id, broccoli.amount.clone()),
+        (beef.id, IngredientUnit::Grams(500.0)),
+    ];
+
+    assert_eq!(ingredients, expected);
+    assert_eq!(repo.get_by_id(&recipe.id).await.unwrap(), result);
+}
+
+pub async fn invalid_operations_leave_the_recipe_untouched(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    unit_of_work: impl UnitOfWork,
+) {
+    let recipe = recipe_fixture();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    repo.insert(recipe.clone()).await.unwrap();
+    let before = repo.get_by_id(&recipe.id).await.unwrap();
+
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
+
+    let input = BatchEditRecipe {
+        recipe: UpdateRecipe {
+            name: Some("This should not be saved".to_string()),
+            ..Default::default()
+        },
+        operations: vec![
+            IngredientOperation::Add(IngredientAmountData {
+                ingredient_id: Uuid::from_u128(999),
+                ..Default::default()
+            }),
+            IngredientOperation::Update {
+                entry_id: recipe.ingredients[1].id,
+                changeset: IngredientInRecipeChangeset {
+                    amount: Some(IngredientUnit::Cups(3.0)),
+                    ..Default::default()
+                },
+            },
+            IngredientOperation::Update {
+                entry_id: recipe.ingredients[2].id,
+                changeset: Default::default(),
+            },
+            IngredientOperation::Remove {
+                entry_id: Uuid::from_u128(999),
+            },
+        ],
+    };
+
+    let error = batch_edit_recipe(unit_of_work, None, &recipe.id, input, &Precondition::Any)
+        .await
+        .unwrap_err();
+
+    let BatchEditRecipeError::InvalidOperations(errors) = error else {
+        panic!("Expected invalid operations, got {error:?}");
+    };
+
+    assert!(matches!(
+        errors.as_slice(),
+        [
+            (0, IngredientOperationError::IngredientNotFound(a)),
+            (2, IngredientOperationError::ChangesetEmpty(b)),
+            (3, IngredientOperationError::NotInRecipe(c)),
+        ] if *a == Uuid::from_u128(999) && *b == recipe.ingredients[2].id && *c == Uuid::from_u128(999)
+    ));
+    assert_eq!(repo.get_by_id(&recipe.id).await.unwrap(), before);
+}
+
+pub async fn removing_every_ingredient_in_a_batch_fails(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    unit_of_work: impl UnitOfWork,
+) {
+    let recipe = recipe_fixture();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    repo.insert(recipe.clone()).await.unwrap();
+
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
+
+    let input = BatchEditRecipe {
+        operations: recipe
+            .ingredients
+            .iter()
+            .map(|i| IngredientOperation::Remove { entry_id: i.id })
+            .collect(),
+        ..Default::default()
+    };
+
+    let error = batch_edit_recipe(unit_of_work, None, &recipe.id, input, &Precondition::Any)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        error,
+        BatchEditRecipeError::InvalidOperations(errors)
+            if matches!(errors.as_slice(), [(2, IngredientOperationError::LastIngredient)])
+    ));
+    assert_eq!(
+        repo.get_by_id(&recipe.id).await.unwrap().ingredients.len(),
+        3
+    );
+}
+
+pub async fn editing_a_recipe_of_someone_else_in_a_batch_errors(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+    unit_of_work: impl UnitOfWork,
+) {
+    let public = Recipe {
+        owner_id: Some(user_fixture().id),
+        ..recipe_fixture()
+    };
+    let private = Recipe {
+        visibility: Visibility::Private,
+        owner_id: Some(user_fixture().id),
+        ..another_recipe_fixture(1)
+    };
+    insert_users(&user_repo).await;
+    insert_all_ingredients_of_recipe(&ingredient_repo, &public).await;
+    repo.insert(public.clone()).await.unwrap();
+    repo.insert(private.clone()).await.unwrap();
+
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
+    let rename = || BatchEditRecipe {
+        recipe: UpdateRecipe {
+            name: Some("Hoisin Beef and Broccoli".to_string()),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let error = batch_edit_recipe(
+        unit_of_work.clone(),
+        Some(&another_user_fixture().id),
+        &private.id,
+        rename(),
+        &Precondition::Any,
+    )
+    .await
+    .unwrap_err();
+    assert!(matches!(error, BatchEditRecipeError::NotFound(id) if id == private.id));
+
+    for user_id in [None, Some(&another_user_fixture().id)] {
+        let error = batch_edit_recipe(
+            unit_of_work.clone(),
+            user_id,
+            &public.id,
+            rename(),
+            &Precondition::Any,
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(error, BatchEditRecipeError::NotOwner(id) if id == public.id));
+    }
+
+    // Recipes without an owner stay public, whoever asks
+    let ownerless = another_recipe_fixture(2);
+    repo.insert(ownerless.clone()).await.unwrap();
+
+    let error = batch_edit_recipe(
+        unit_of_work,
+        Some(&user_fixture().id),
+        &ownerless.id,
+        BatchEditRecipe {
+            recipe: UpdateRecipe {
+                visibility: Some(Visibility::Private),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        &Precondition::Any,
+    )
+    .await
+    .unwrap_err();
+    assert!(matches!(error, BatchEditRecipeError::NotOwner(id) if id == ownerless.id));
+
+    assert_eq!(repo.get_by_id(&public.id).await.unwrap().name, public.name);
+    assert_eq!(
+        repo.get_by_id(&ownerless.id).await.unwrap().visibility,
+        Visibility::Public
+    );
+}
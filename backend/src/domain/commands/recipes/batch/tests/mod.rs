@@ -0,0 +1,122 @@
+mod __tests__;
+mod in_memory {
+    use super::__tests__;
+
+    use crate::domain::repositories::{
+        ingredients::in_memory::InMemoryIngredientRepository,
+        recipe::in_memory::InMemoryRecipeRepository, unit_of_work::in_memory::InMemoryUnitOfWork,
+        user::in_memory::InMemoryUserRepository,
+    };
+
+    #[tokio::test]
+    async fn editing_a_recipe_in_a_batch_applies_every_change() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let recipe_repo = InMemoryRecipeRepository::new();
+        let unit_of_work = InMemoryUnitOfWork::new(ingredient_repo.clone(), recipe_repo.clone());
+
+        __tests__::editing_a_recipe_in_a_batch_applies_every_change(
+            recipe_repo,
+            ingredient_repo,
+            unit_of_work,
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn invalid_operations_leave_the_recipe_untouched() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let recipe_repo = InMemoryRecipeRepository::new();
+        let unit_of_work = InMemoryUnitOfWork::new(ingredient_repo.clone(), recipe_repo.clone());
+
+        __tests__::invalid_operations_leave_the_recipe_untouched(
+            recipe_repo,
+            ingredient_repo,
+            unit_of_work,
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn removing_every_ingredient_in_a_batch_fails() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let recipe_repo = InMemoryRecipeRepository::new();
+        let unit_of_work = InMemoryUnitOfWork::new(ingredient_repo.clone(), recipe_repo.clone());
+
+        __tests__::removing_every_ingredient_in_a_batch_fails(
+            recipe_repo,
+            ingredient_repo,
+            unit_of_work,
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn editing_a_recipe_of_someone_else_in_a_batch_errors() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        let unit_of_work = InMemoryUnitOfWork::new(ingredient_repo.clone(), repo.clone());
+        __tests__::editing_a_recipe_of_someone_else_in_a_batch_errors(
+            repo,
+            ingredient_repo,
+            user_repo,
+            unit_of_work,
+        )
+        .await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        ingredients::postgres::PostgresIngredientRepository,
+        recipe::postgres::PostgresRecipeRepository, unit_of_work::postgres::PostgresUnitOfWork,
+        user::postgres::PostgresUserRepository,
+    };
+
+    #[sqlx::test]
+    async fn editing_a_recipe_in_a_batch_applies_every_change(pool: PgPool) {
+        __tests__::editing_a_recipe_in_a_batch_applies_every_change(
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresUnitOfWork::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn invalid_operations_leave_the_recipe_untouched(pool: PgPool) {
+        __tests__::invalid_operations_leave_the_recipe_untouched(
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresUnitOfWork::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn removing_every_ingredient_in_a_batch_fails(pool: PgPool) {
+        __tests__::removing_every_ingredient_in_a_batch_fails(
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresUnitOfWork::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn editing_a_recipe_of_someone_else_in_a_batch_errors(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        __tests__::editing_a_recipe_of_someone_else_in_a_batch_errors(
+            repo,
+            ingredient_repo,
+            user_repo,
+            PostgresUnitOfWork::new(pool),
+        )
+        .await
+    }
+}
@@ -1,17 +1,23 @@
 use std::collections::BTreeMap;
 
 use chrono::{DateTime, Utc};
-use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+use common::CreateRecipeDTO;
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use uuid::Uuid;
 
 use crate::domain::entities::recipe::IngredientAmountData;
 use crate::domain::entities::recipe::{
     errors::ValidationError, IngredientWithAmount, Recipe, ServingsType,
 };
+use crate::domain::entities::visibility::Visibility;
 use crate::domain::repositories::recipe::errors::GetRecipeByIdError;
 use crate::domain::repositories::{
-    ingredients::{errors::GetAllIngredientsError, IngredientRepositoryService},
-    recipe::{errors::InsertRecipeError, RecipeRepositoryService},
+    ingredients::errors::GetAllIngredientsError,
+    recipe::errors::InsertRecipeError,
+    unit_of_work::{
+        errors::{BeginTransactionError, CommitTransactionError},
+        UnitOfWorkService,
+    },
 };
 
 #[derive(thiserror::Error, Debug, strum::AsRefStr)]
@@ -52,6 +58,18 @@ impl From<GetRecipeByIdError> for CreateRecipeError {
     }
 }
 
+impl From<BeginTransactionError> for CreateRecipeError {
+    fn from(value: BeginTransactionError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+impl From<CommitTransactionError> for CreateRecipeError {
+    fn from(value: CommitTransactionError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
 #[derive(Debug)]
 pub struct CreateRecipe {
     pub name: String,
@@ -75,35 +93,63 @@ impl From<Recipe> for CreateRecipe {
     }
 }
 
+impl From<CreateRecipe> for CreateRecipeDTO {
+    fn from(value: CreateRecipe) -> Self {
+        Self {
+            name: value.name,
+            description: value.description,
+            steps: value.steps,
+            time: value
+                .time
+                .into_iter()
+                .map(|(k, v)| (k, v.as_secs()))
+                .collect(),
+            ingredients: value.ingredients.into_iter().map(Into::into).collect(),
+            servings: value.servings.into(),
+        }
+    }
+}
+
+/// The ingredients are looked up in the same transaction the recipe gets inserted in.
+/// Recipes created without logging in have no owner.
 pub async fn create_recipe(
-    recipe_repo: RecipeRepositoryService,
-    ingredient_repo: IngredientRepositoryService,
+    unit_of_work: UnitOfWorkService,
+    owner_id: Option<&Uuid>,
     input: &CreateRecipe,
 ) -> Result<Recipe, CreateRecipeError> {
     let ingredient_ids: Vec<Uuid> = input.ingredients.iter().map(|i| i.ingredient_id).collect();
 
-    let ingredients_in_recipe: Vec<_> = ingredient_repo
+    let tx = unit_of_work.begin().await?;
+    let recipe_repo = tx.recipes();
+
+    let ingredients = tx
+        .ingredients()
         .get_all_by_id(&ingredient_ids)
         .await
-        .map_err(CreateRecipeError::from)?
-        .into_par_iter()
-        .zip(&input.ingredients)
-        .map(
-            |(
-                ingredient,
-                IngredientAmountData {
-                    amount,
-                    optional,
-                    notes,
-                    ..
-                },
-            )| {
-                IngredientWithAmount {
-                    ingredient,
+        .map_err(CreateRecipeError::from)?;
+
+    // The repository does not keep the order of the IDs, so the amounts have to be matched by ID
+    let ingredients_in_recipe: Vec<_> = input
+        .ingredients
+        .par_iter()
+        .filter_map(
+            |IngredientAmountData {
+                 ingredient_id,
+                 amount,
+                 optional,
+                 notes,
+                 group,
+             }| {
+                let ingredient = ingredients.iter().find(|i| i.id == *ingredient_id)?;
+
+                Some(IngredientWithAmount {
+                    id: Uuid::now_v7(),
+                    ingredient: ingredient.clone(),
                     amount: amount.clone(),
                     notes: notes.clone(),
                     optional: *optional,
-                }
+                    group: group.clone(),
+                })
             },
         )
         .collect();
@@ -122,6 +168,10 @@ pub async fn create_recipe(
             ingredients: ingredients_in_recipe.try_into()?,
             time: input.time.clone(),
             servings: input.servings.clone(),
+            rating: Default::default(),
+            forked_from: None,
+            visibility: Visibility::Public,
+            owner_id: owner_id.copied(),
             created_at,
             updated_at,
         })
@@ -129,6 +179,8 @@ pub async fn create_recipe(
 
     let recipe = recipe_repo.get_by_id(&id).await?;
 
+    tx.commit().await?;
+
     Ok(recipe)
 }
 
@@ -6,48 +6,52 @@ use uuid::Uuid;
 use crate::{
     domain::{
         commands::recipes::create::{create_recipe, CreateRecipeError},
+        entities::event::DomainEvent,
         repositories::{
-            ingredients::{IngredientRepository, IngredientRepositoryService},
-            recipe::{errors::InsertRecipeError, RecipeRepository, RecipeRepositoryService},
+            ingredients::IngredientRepository,
+            outbox::OutboxRepository,
+            recipe::{errors::InsertRecipeError, RecipeRepository},
+            unit_of_work::{UnitOfWork, UnitOfWorkService},
         },
     },
-    test_utils::{insert_all_ingredients_of_recipe, recipe_fixture},
+    test_utils::{insert_all_ingredients_of_recipe, recipe_fixture, unpublished_events},
 };
 
 pub async fn create_recipe_without_proper_ingredients_errors(
     repo: impl RecipeRepository,
-    ing_repo: impl IngredientRepository,
+    unit_of_work: impl UnitOfWork,
 ) {
     let recipe = recipe_fixture();
-    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ing_repo));
-    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
 
-    let result = create_recipe(recipe_repo, ingredient_repo, &recipe.into())
+    let result = create_recipe(unit_of_work, None, &recipe.into())
         .await
         .unwrap_err();
 
     assert!(matches!(result, CreateRecipeError::IngredientsNotFound(_)));
+    assert!(repo.get_all().await.unwrap().is_empty());
 }
 
 pub async fn create_recipe_with_proper_ingredients(
     repo: impl RecipeRepository,
     ing_repo: impl IngredientRepository,
+    unit_of_work: impl UnitOfWork,
 ) {
     let recipe = recipe_fixture();
 
     insert_all_ingredients_of_recipe(&ing_repo, &recipe).await;
 
-    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ing_repo));
-    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
 
-    let result = create_recipe(recipe_repo, ingredient_repo, &recipe.clone().into())
+    let result = create_recipe(unit_of_work, None, &recipe.clone().into())
         .await
         .unwrap();
 
     assert_eq!(Uuid::get_version(&result.id), Some(uuid::Version::SortRand));
     assert_eq!(&result.name, "Hoisin Tofu and Broccoli");
 
-    assert_eq!(result.ingredients.len(), recipe.ingredients.len())
+    assert_eq!(result.ingredients.len(), recipe.ingredients.len());
+    assert_eq!(repo.get_by_id(&result.id).await.unwrap().name, result.name);
 }
 
 pub async fn inserting_recipe_with_same_id_fails(
@@ -64,3 +68,30 @@ pub async fn inserting_recipe_with_same_id_fails(
 
     assert!(matches!(error, InsertRecipeError::Conflict(a) if a == "recipe id"));
 }
+
+pub async fn creating_a_recipe_records_an_event(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    outbox_repo: impl OutboxRepository,
+    unit_of_work: impl UnitOfWork,
+) {
+    let recipe = recipe_fixture();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
+
+    let result = create_recipe(unit_of_work, None, &recipe.clone().into())
+        .await
+        .unwrap();
+
+    // A recipe that could not be saved does not get an event
+    repo.insert(result.clone()).await.unwrap_err();
+
+    let recipe_events: Vec<_> = unpublished_events(&outbox_repo)
+        .await
+        .into_iter()
+        .filter(|e| e.recipe_id().is_some())
+        .collect();
+
+    assert_eq!(recipe_events, vec![DomainEvent::RecipeCreated(result)]);
+}
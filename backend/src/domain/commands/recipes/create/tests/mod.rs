@@ -4,24 +4,31 @@ mod in_memory {
 
     use crate::domain::repositories::{
         ingredients::in_memory::InMemoryIngredientRepository,
-        recipe::in_memory::InMemoryRecipeRepository,
+        outbox::in_memory::InMemoryOutboxRepository, recipe::in_memory::InMemoryRecipeRepository,
+        unit_of_work::in_memory::InMemoryUnitOfWork,
     };
 
     #[tokio::test]
     async fn create_recipe_with_proper_ingredients() {
         let ingredient_repo = InMemoryIngredientRepository::new();
         let recipe_repo = InMemoryRecipeRepository::new();
+        let unit_of_work = InMemoryUnitOfWork::new(ingredient_repo.clone(), recipe_repo.clone());
 
-        __tests__::create_recipe_with_proper_ingredients(recipe_repo, ingredient_repo).await;
+        __tests__::create_recipe_with_proper_ingredients(
+            recipe_repo,
+            ingredient_repo,
+            unit_of_work,
+        )
+        .await;
     }
 
     #[tokio::test]
     async fn create_recipe_without_proper_ingredients_errors() {
         let ingredient_repo = InMemoryIngredientRepository::new();
         let recipe_repo = InMemoryRecipeRepository::new();
+        let unit_of_work = InMemoryUnitOfWork::new(ingredient_repo, recipe_repo.clone());
 
-        __tests__::create_recipe_without_proper_ingredients_errors(recipe_repo, ingredient_repo)
-            .await;
+        __tests__::create_recipe_without_proper_ingredients_errors(recipe_repo, unit_of_work).await;
     }
 
     #[tokio::test]
@@ -30,6 +37,22 @@ mod in_memory {
         let ingredient_repo = InMemoryIngredientRepository::new();
         __tests__::inserting_recipe_with_same_id_fails(repo, ingredient_repo).await
     }
+
+    #[tokio::test]
+    async fn creating_a_recipe_records_an_event() {
+        let outbox = InMemoryOutboxRepository::new();
+        let recipe_repo = InMemoryRecipeRepository::new().with_outbox(outbox.clone());
+        let ingredient_repo = InMemoryIngredientRepository::new().with_outbox(outbox.clone());
+        let unit_of_work = InMemoryUnitOfWork::new(ingredient_repo.clone(), recipe_repo.clone());
+
+        __tests__::creating_a_recipe_records_an_event(
+            recipe_repo,
+            ingredient_repo,
+            outbox,
+            unit_of_work,
+        )
+        .await
+    }
 }
 
 mod sql {
@@ -38,7 +61,8 @@ mod sql {
 
     use crate::domain::repositories::{
         ingredients::postgres::PostgresIngredientRepository,
-        recipe::postgres::PostgresRecipeRepository,
+        outbox::postgres::PostgresOutboxRepository, recipe::postgres::PostgresRecipeRepository,
+        unit_of_work::postgres::PostgresUnitOfWork,
     };
 
     #[sqlx::test]
@@ -46,16 +70,23 @@ mod sql {
         let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
         let recipe_repo = PostgresRecipeRepository::new(pool.clone());
 
-        __tests__::create_recipe_with_proper_ingredients(recipe_repo, ingredient_repo).await;
+        __tests__::create_recipe_with_proper_ingredients(
+            recipe_repo,
+            ingredient_repo,
+            PostgresUnitOfWork::new(pool),
+        )
+        .await;
     }
 
     #[sqlx::test]
     async fn create_recipe_without_proper_ingredients_errors(pool: PgPool) {
-        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
         let recipe_repo = PostgresRecipeRepository::new(pool.clone());
 
-        __tests__::create_recipe_without_proper_ingredients_errors(recipe_repo, ingredient_repo)
-            .await;
+        __tests__::create_recipe_without_proper_ingredients_errors(
+            recipe_repo,
+            PostgresUnitOfWork::new(pool),
+        )
+        .await;
     }
 
     #[sqlx::test]
@@ -64,4 +95,15 @@ mod sql {
         let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
         __tests__::inserting_recipe_with_same_id_fails(repo, ingredient_repo).await
     }
+
+    #[sqlx::test]
+    async fn creating_a_recipe_records_an_event(pool: PgPool) {
+        __tests__::creating_a_recipe_records_an_event(
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresOutboxRepository::new(pool.clone()),
+            PostgresUnitOfWork::new(pool),
+        )
+        .await
+    }
 }
@@ -3,13 +3,19 @@ use uuid::Uuid;
 use crate::domain::repositories::recipe::errors::{
     DeleteRecipeError as DeleteRecipeErrorInternal, GetRecipeByIdError,
 };
-use crate::domain::repositories::recipe::RecipeRepositoryService;
+use crate::domain::repositories::unit_of_work::errors::{
+    BeginTransactionError, CommitTransactionError,
+};
+use crate::domain::repositories::unit_of_work::UnitOfWorkService;
 
 #[derive(thiserror::Error, Debug, strum::AsRefStr)]
 pub enum DeleteRecipeError {
     #[error("Could not found the recipe with the ID {0}")]
     NotFound(Uuid),
 
+    #[error("Only the owner can make changes to the recipe with ID {0}")]
+    NotOwner(Uuid),
+
     #[error(transparent)]
     Unknown(#[from] eyre::Error),
 }
@@ -20,22 +26,48 @@ impl From<DeleteRecipeErrorInternal> for DeleteRecipeError {
     }
 }
 
+impl From<BeginTransactionError> for DeleteRecipeError {
+    fn from(value: BeginTransactionError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+impl From<CommitTransactionError> for DeleteRecipeError {
+    fn from(value: CommitTransactionError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
 impl From<GetRecipeByIdError> for DeleteRecipeError {
     fn from(value: GetRecipeByIdError) -> Self {
         match value {
             GetRecipeByIdError::NotFound(id) => Self::NotFound(id),
-            e => e.into(),
+            e => Self::Unknown(e.into()),
         }
     }
 }
 
+/// Only the owner can delete a recipe, recipes without an owner can be deleted by everyone.
+/// The database takes the recipe out of every collection it was in.
 pub async fn delete_recipe(
-    recipe_repo: RecipeRepositoryService,
+    unit_of_work: UnitOfWorkService,
+    user_id: Option<&Uuid>,
     input: &Uuid,
 ) -> Result<(), DeleteRecipeError> {
-    let recipe = recipe_repo.get_by_id(input).await?;
+    let tx = unit_of_work.begin().await?;
+
+    let recipe = tx.lock_recipe(input).await?;
+    if !recipe.is_visible_to(user_id, None) {
+        return Err(DeleteRecipeError::NotFound(*input));
+    }
+
+    if !recipe.is_editable_by(user_id) {
+        return Err(DeleteRecipeError::NotOwner(*input));
+    }
+
+    tx.recipes().delete(&recipe).await?;
 
-    recipe_repo.delete(&recipe).await?;
+    tx.commit().await?;
 
     Ok(())
 }
@@ -1,35 +1,198 @@
 use std::sync::Arc;
 
+use pretty_assertions::assert_eq;
+
 use crate::{
     domain::{
         commands::recipes::delete::{delete_recipe, DeleteRecipeError},
+        entities::{
+            collection::Collection, event::DomainEvent, recipe::Recipe, visibility::Visibility,
+        },
         repositories::{
+            collection::CollectionRepository,
             ingredients::IngredientRepository,
-            recipe::{RecipeRepository, RecipeRepositoryService},
+            outbox::OutboxRepository,
+            recipe::RecipeRepository,
+            unit_of_work::{UnitOfWork, UnitOfWorkService},
+            user::UserRepository,
         },
     },
-    test_utils::{insert_all_ingredients_of_recipe, recipe_fixture},
+    test_utils::{
+        another_recipe_fixture, another_user_fixture, collection_fixture,
+        insert_all_ingredients_of_recipe, insert_collections, insert_reviews, insert_users,
+        recipe_fixture, review_fixture, unpublished_events, user_fixture,
+    },
 };
 
 pub async fn deleting_a_recipe_succeeds(
     repo: impl RecipeRepository,
     ingredient_repo: impl IngredientRepository,
+    unit_of_work: impl UnitOfWork,
 ) {
-    let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
     let recipe = recipe_fixture();
 
     insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
 
     repo.insert(recipe.clone()).await.unwrap();
 
-    delete_recipe(repo, &recipe.id).await.unwrap();
+    delete_recipe(unit_of_work, None, &recipe.id).await.unwrap();
+
+    repo.get_by_id(&recipe.id).await.unwrap_err();
 }
 
-pub async fn deleting_a_nonexistent_recipe_fails(repo: impl RecipeRepository) {
-    let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+pub async fn deleting_a_nonexistent_recipe_fails(unit_of_work: impl UnitOfWork) {
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
 
     let recipe = recipe_fixture();
-    let result = delete_recipe(repo, &recipe.id).await.unwrap_err();
+    let result = delete_recipe(unit_of_work, None, &recipe.id)
+        .await
+        .unwrap_err();
 
     assert!(matches!(result, DeleteRecipeError::NotFound(id) if id == recipe.id))
 }
+
+pub async fn deleting_a_recipe_removes_it_from_collections(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    collection_repo: impl CollectionRepository,
+    unit_of_work: impl UnitOfWork,
+) {
+    let recipe = recipe_fixture();
+    let other = another_recipe_fixture(1);
+    let collection = collection_fixture();
+    insert_collections(
+        &collection_repo,
+        &repo,
+        &ingredient_repo,
+        std::slice::from_ref(&collection),
+    )
+    .await;
+    repo.insert(other.clone()).await.unwrap();
+    let with_other_recipe = Collection {
+        id: uuid::Uuid::from_u128(1025),
+        recipes: vec![recipe.id, other.id],
+        cover_recipe_id: Some(other.id),
+        ..collection_fixture()
+    };
+    collection_repo
+        .insert(with_other_recipe.clone())
+        .await
+        .unwrap();
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
+
+    delete_recipe(unit_of_work, None, &recipe.id).await.unwrap();
+
+    let collection = collection_repo.get_by_id(&collection.id).await.unwrap();
+
+    assert_eq!(collection.recipes, vec![]);
+    assert_eq!(collection.cover_recipe_id, None);
+
+    let with_other_recipe = collection_repo
+        .get_by_id(&with_other_recipe.id)
+        .await
+        .unwrap();
+
+    assert_eq!(with_other_recipe.recipes, vec![other.id]);
+    assert_eq!(with_other_recipe.cover_recipe_id, Some(other.id));
+    assert_eq!(
+        collection_repo.get_by_recipe(&recipe.id).await.unwrap(),
+        vec![]
+    );
+}
+
+pub async fn deleting_a_recipe_deletes_its_reviews(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    unit_of_work: impl UnitOfWork,
+    user_repo: impl UserRepository,
+) {
+    let recipe = recipe_fixture();
+    insert_reviews(&repo, &ingredient_repo, &user_repo, &[review_fixture()]).await;
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
+
+    delete_recipe(unit_of_work, None, &recipe.id).await.unwrap();
+
+    repo.insert(recipe.clone()).await.unwrap();
+
+    assert_eq!(repo.get_reviews(&recipe).await.unwrap(), vec![]);
+    assert_eq!(repo.get_by_id(&recipe.id).await.unwrap().rating.count, 0);
+}
+
+pub async fn deleting_a_recipe_records_an_event(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    unit_of_work: impl UnitOfWork,
+    outbox_repo: impl OutboxRepository,
+) {
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
+    let recipe = recipe_fixture();
+
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    repo.insert(recipe.clone()).await.unwrap();
+
+    delete_recipe(unit_of_work.clone(), None, &recipe.id)
+        .await
+        .unwrap();
+    // Deleting it again fails and must not record a second event
+    delete_recipe(unit_of_work, None, &recipe.id)
+        .await
+        .unwrap_err();
+
+    let events = unpublished_events(&outbox_repo).await;
+
+    assert_eq!(events.last(), Some(&DomainEvent::RecipeDeleted(recipe.id)));
+    assert_eq!(
+        events
+            .iter()
+            .filter(|e| matches!(e, DomainEvent::RecipeDeleted(_)))
+            .count(),
+        1
+    );
+}
+
+pub async fn deleting_a_recipe_of_someone_else_errors(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    unit_of_work: impl UnitOfWork,
+    user_repo: impl UserRepository,
+) {
+    let recipe_repo = repo;
+    let public = Recipe {
+        owner_id: Some(user_fixture().id),
+        ..recipe_fixture()
+    };
+    let private = Recipe {
+        visibility: Visibility::Private,
+        owner_id: Some(user_fixture().id),
+        ..another_recipe_fixture(1)
+    };
+    insert_users(&user_repo).await;
+    insert_all_ingredients_of_recipe(&ingredient_repo, &public).await;
+    recipe_repo.insert(public.clone()).await.unwrap();
+    recipe_repo.insert(private.clone()).await.unwrap();
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
+
+    let error = delete_recipe(
+        unit_of_work.clone(),
+        Some(&another_user_fixture().id),
+        &private.id,
+    )
+    .await
+    .unwrap_err();
+    assert!(matches!(error, DeleteRecipeError::NotFound(id) if id == private.id));
+
+    for user_id in [None, Some(&another_user_fixture().id)] {
+        let error = delete_recipe(unit_of_work.clone(), user_id, &public.id)
+            .await
+            .unwrap_err();
+        assert!(matches!(error, DeleteRecipeError::NotOwner(id) if id == public.id));
+    }
+
+    assert!(recipe_repo.get_by_id(&public.id).await.is_ok());
+    assert!(recipe_repo.get_by_id(&private.id).await.is_ok());
+
+    delete_recipe(unit_of_work, Some(&user_fixture().id), &private.id)
+        .await
+        .unwrap();
+}
@@ -3,7 +3,8 @@ mod __tests__;
 mod in_memory {
     use crate::domain::repositories::{
         ingredients::in_memory::InMemoryIngredientRepository,
-        recipe::in_memory::InMemoryRecipeRepository,
+        outbox::in_memory::InMemoryOutboxRepository, recipe::in_memory::InMemoryRecipeRepository,
+        unit_of_work::in_memory::InMemoryUnitOfWork, user::in_memory::InMemoryUserRepository,
     };
 
     use super::__tests__;
@@ -12,13 +13,58 @@ mod in_memory {
     async fn deleting_a_recipe_succeeds() {
         let repo = InMemoryRecipeRepository::new();
         let ingredient_repo = InMemoryIngredientRepository::new();
-        __tests__::deleting_a_recipe_succeeds(repo, ingredient_repo).await
+        let unit_of_work = InMemoryUnitOfWork::new(ingredient_repo.clone(), repo.clone());
+        __tests__::deleting_a_recipe_succeeds(repo, ingredient_repo, unit_of_work).await
     }
 
     #[tokio::test]
     async fn deleting_a_nonexistent_recipe_fails() {
+        let unit_of_work = InMemoryUnitOfWork::new(
+            InMemoryIngredientRepository::new(),
+            InMemoryRecipeRepository::new(),
+        );
+        __tests__::deleting_a_nonexistent_recipe_fails(unit_of_work).await
+    }
+
+    // The foreign keys take the recipe out of its collections, which the in memory
+    // repositories know nothing about, so that is only tested against Postgres
+
+    #[tokio::test]
+    async fn deleting_a_recipe_deletes_its_reviews() {
         let repo = InMemoryRecipeRepository::new();
-        __tests__::deleting_a_nonexistent_recipe_fails(repo).await
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let unit_of_work = InMemoryUnitOfWork::new(ingredient_repo.clone(), repo.clone());
+        __tests__::deleting_a_recipe_deletes_its_reviews(
+            repo,
+            ingredient_repo,
+            unit_of_work,
+            InMemoryUserRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn deleting_a_recipe_of_someone_else_errors() {
+        let repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let unit_of_work = InMemoryUnitOfWork::new(ingredient_repo.clone(), repo.clone());
+        __tests__::deleting_a_recipe_of_someone_else_errors(
+            repo,
+            ingredient_repo,
+            unit_of_work,
+            InMemoryUserRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn deleting_a_recipe_records_an_event() {
+        let outbox = InMemoryOutboxRepository::new();
+        let repo = InMemoryRecipeRepository::new().with_outbox(outbox.clone());
+        let ingredient_repo = InMemoryIngredientRepository::new().with_outbox(outbox.clone());
+        let unit_of_work = InMemoryUnitOfWork::new(ingredient_repo.clone(), repo.clone());
+        __tests__::deleting_a_recipe_records_an_event(repo, ingredient_repo, unit_of_work, outbox)
+            .await
     }
 }
 
@@ -26,8 +72,10 @@ mod sql {
     use sqlx::PgPool;
 
     use crate::domain::repositories::{
+        collection::postgres::PostgresCollectionRepository,
         ingredients::postgres::PostgresIngredientRepository,
-        recipe::postgres::PostgresRecipeRepository,
+        outbox::postgres::PostgresOutboxRepository, recipe::postgres::PostgresRecipeRepository,
+        unit_of_work::postgres::PostgresUnitOfWork, user::postgres::PostgresUserRepository,
     };
 
     use super::__tests__;
@@ -36,12 +84,63 @@ mod sql {
     async fn deleting_a_recipe_succeeds(pool: PgPool) {
         let repo = PostgresRecipeRepository::new(pool.clone());
         let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
-        __tests__::deleting_a_recipe_succeeds(repo, ingredient_repo).await
+        let unit_of_work = PostgresUnitOfWork::new(pool);
+        __tests__::deleting_a_recipe_succeeds(repo, ingredient_repo, unit_of_work).await
     }
 
     #[sqlx::test]
     async fn deleting_a_nonexistent_recipe_fails(pool: PgPool) {
+        __tests__::deleting_a_nonexistent_recipe_fails(PostgresUnitOfWork::new(pool)).await
+    }
+
+    #[sqlx::test]
+    async fn deleting_a_recipe_removes_it_from_collections(pool: PgPool) {
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let collection_repo = PostgresCollectionRepository::new(pool.clone());
+        __tests__::deleting_a_recipe_removes_it_from_collections(
+            repo,
+            ingredient_repo,
+            collection_repo,
+            PostgresUnitOfWork::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn deleting_a_recipe_deletes_its_reviews(pool: PgPool) {
         let repo = PostgresRecipeRepository::new(pool.clone());
-        __tests__::deleting_a_nonexistent_recipe_fails(repo).await
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        __tests__::deleting_a_recipe_deletes_its_reviews(
+            repo,
+            ingredient_repo,
+            PostgresUnitOfWork::new(pool.clone()),
+            PostgresUserRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn deleting_a_recipe_of_someone_else_errors(pool: PgPool) {
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        __tests__::deleting_a_recipe_of_someone_else_errors(
+            repo,
+            ingredient_repo,
+            PostgresUnitOfWork::new(pool.clone()),
+            PostgresUserRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn deleting_a_recipe_records_an_event(pool: PgPool) {
+        __tests__::deleting_a_recipe_records_an_event(
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresUnitOfWork::new(pool.clone()),
+            PostgresOutboxRepository::new(pool),
+        )
+        .await
     }
 }
@@ -0,0 +1,65 @@
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::domain::entities::recipe::Recipe;
+use crate::domain::repositories::recipe::errors::{GetRecipeByIdError, InsertRecipeError};
+use crate::domain::repositories::recipe::RecipeRepositoryService;
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum ForkRecipeError {
+    #[error("Could not find the recipe with the ID {0}")]
+    NotFound(Uuid),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetRecipeByIdError> for ForkRecipeError {
+    fn from(value: GetRecipeByIdError) -> Self {
+        match value {
+            GetRecipeByIdError::NotFound(id) => Self::NotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<InsertRecipeError> for ForkRecipeError {
+    fn from(value: InsertRecipeError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+/// Copies the recipe together with its ingredients into a new recipe that remembers where it came from.
+/// Reviews are not copied, the fork has to earn its own, and it belongs to whoever forked it.
+#[tracing::instrument("[COMMAND] Forking a recipe", skip(recipe_repo))]
+pub async fn fork_recipe(
+    recipe_repo: RecipeRepositoryService,
+    user_id: Option<&Uuid>,
+    recipe_id: &Uuid,
+) -> Result<Recipe, ForkRecipeError> {
+    let parent = recipe_repo.get_visible(user_id, recipe_id, None).await?;
+
+    let id = Uuid::now_v7();
+    let now = Utc::now();
+
+    recipe_repo
+        .insert(Recipe {
+            id,
+            forked_from: Some(parent.id),
+            rating: Default::default(),
+            // Only the owner can fork a hidden recipe, so the fork is hidden from the same people
+            visibility: parent.visibility,
+            owner_id: user_id.copied(),
+            created_at: now,
+            updated_at: now,
+            ..parent
+        })
+        .await?;
+
+    let recipe = recipe_repo.get_by_id(&id).await?;
+
+    Ok(recipe)
+}
+
+#[cfg(test)]
+mod tests;
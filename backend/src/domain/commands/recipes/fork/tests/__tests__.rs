@@ -0,0 +1,154 @@
+use std::sync::Arc;
+
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        commands::recipes::fork::{fork_recipe, ForkRecipeError},
+        entities::{
+            recipe::{IngredientUnit, IngredientWithAmount, Recipe, RecipeChangeset},
+            visibility::Visibility,
+        },
+        repositories::{
+            ingredients::IngredientRepository,
+            recipe::{RecipeRepository, RecipeRepositoryService},
+            user::UserRepository,
+        },
+    },
+    test_utils::{
+        another_user_fixture, ingredient_fixture, insert_all_ingredients_of_recipe, insert_reviews,
+        insert_users, recipe_fixture, review_fixture, user_fixture,
+    },
+};
+
+pub async fn forking_a_recipe_works(
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let recipe = recipe_fixture();
+    insert_reviews(
+        &recipe_repo,
+        &ingredient_repo,
+        &user_repo,
+        &[review_fixture()],
+    )
+    .await;
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let fork = fork_recipe(
+        recipe_repo.clone(),
+        Some(&another_user_fixture().id),
+        &recipe.id,
+    )
+    .await
+    .unwrap();
+
+    assert_ne!(fork.id, recipe.id);
+    assert_eq!(fork.owner_id, Some(another_user_fixture().id));
+    assert_eq!(fork.visibility, Visibility::Public);
+    assert_eq!(fork.forked_from, Some(recipe.id));
+    assert_eq!(fork.name, recipe.name);
+    assert_eq!(fork.steps, recipe.steps);
+    assert_eq!(fork.ingredients, recipe.ingredients);
+    assert_eq!(fork.rating.count, 0);
+
+    let forks = recipe_repo
+        .get_forks(&recipe_repo.get_by_id(&recipe.id).await.unwrap())
+        .await
+        .unwrap();
+    assert_eq!(forks, vec![fork]);
+}
+
+pub async fn forking_a_nonexistent_recipe_errors(recipe_repo: impl RecipeRepository) {
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+    let recipe_id = Uuid::from_u128(404);
+
+    let error = fork_recipe(recipe_repo, None, &recipe_id)
+        .await
+        .unwrap_err();
+
+    match error {
+        ForkRecipeError::NotFound(id) => assert_eq!(id, recipe_id),
+        e => panic!("Wrong error returned: {e}"),
+    }
+}
+
+pub async fn forking_a_private_recipe_needs_its_owner(
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let recipe = Recipe {
+        visibility: Visibility::Private,
+        owner_id: Some(user_fixture().id),
+        ..recipe_fixture()
+    };
+    insert_users(&user_repo).await;
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    for user_id in [None, Some(&another_user_fixture().id)] {
+        let error = fork_recipe(recipe_repo.clone(), user_id, &recipe.id)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error, ForkRecipeError::NotFound(id) if id == recipe.id));
+    }
+
+    let fork = fork_recipe(recipe_repo, Some(&user_fixture().id), &recipe.id)
+        .await
+        .unwrap();
+
+    assert_eq!(fork.owner_id, Some(user_fixture().id));
+    assert_eq!(fork.visibility, Visibility::Private);
+}
+
+pub async fn changing_a_fork_leaves_the_original_alone(
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let recipe = recipe_fixture();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+    ingredient_repo.insert(ingredient_fixture()).await.unwrap();
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+    let original = recipe_repo.get_by_id(&recipe.id).await.unwrap();
+
+    let fork = fork_recipe(recipe_repo.clone(), None, &recipe.id)
+        .await
+        .unwrap();
+    recipe_repo
+        .update(
+            &fork,
+            RecipeChangeset {
+                name: Some("Hoisin Tofu and Broccoli, but spicier".to_string()),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    recipe_repo
+        .add_ingredient(
+            &fork,
+            IngredientWithAmount {
+                id: Uuid::now_v7(),
+                ingredient: ingredient_fixture(),
+                amount: IngredientUnit::Grams(200.0),
+                notes: None,
+                optional: true,
+                group: None,
+            },
+        )
+        .await
+        .unwrap();
+
+    let fork = recipe_repo.get_by_id(&fork.id).await.unwrap();
+    assert_eq!(fork.name, "Hoisin Tofu and Broccoli, but spicier");
+    assert_eq!(fork.ingredients.len(), original.ingredients.len() + 1);
+
+    let original_after = recipe_repo.get_by_id(&recipe.id).await.unwrap();
+    assert_eq!(original_after, original);
+}
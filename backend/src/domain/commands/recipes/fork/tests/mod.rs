@@ -0,0 +1,87 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::{
+        ingredients::in_memory::InMemoryIngredientRepository,
+        recipe::in_memory::InMemoryRecipeRepository, user::in_memory::InMemoryUserRepository,
+    };
+
+    #[tokio::test]
+    async fn forking_a_recipe_works() {
+        __tests__::forking_a_recipe_works(
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+            InMemoryUserRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn forking_a_nonexistent_recipe_errors() {
+        __tests__::forking_a_nonexistent_recipe_errors(InMemoryRecipeRepository::new()).await
+    }
+
+    #[tokio::test]
+    async fn forking_a_private_recipe_needs_its_owner() {
+        __tests__::forking_a_private_recipe_needs_its_owner(
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+            InMemoryUserRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn changing_a_fork_leaves_the_original_alone() {
+        __tests__::changing_a_fork_leaves_the_original_alone(
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+        )
+        .await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        ingredients::postgres::PostgresIngredientRepository,
+        recipe::postgres::PostgresRecipeRepository, user::postgres::PostgresUserRepository,
+    };
+
+    #[sqlx::test]
+    async fn forking_a_recipe_works(pool: PgPool) {
+        __tests__::forking_a_recipe_works(
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresUserRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn forking_a_nonexistent_recipe_errors(pool: PgPool) {
+        __tests__::forking_a_nonexistent_recipe_errors(PostgresRecipeRepository::new(pool)).await
+    }
+
+    #[sqlx::test]
+    async fn forking_a_private_recipe_needs_its_owner(pool: PgPool) {
+        __tests__::forking_a_private_recipe_needs_its_owner(
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresUserRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn changing_a_fork_leaves_the_original_alone(pool: PgPool) {
+        __tests__::changing_a_fork_leaves_the_original_alone(
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool),
+        )
+        .await
+    }
+}
@@ -0,0 +1,511 @@
+use std::{collections::BTreeMap, path::Path, time::Duration};
+
+use uuid::Uuid;
+
+use crate::domain::{
+    commands::recipes::create::{create_recipe, CreateRecipe, CreateRecipeError},
+    entities::{
+        ingredient::Ingredient,
+        recipe::{
+            ingredient_line::parse_amount, IngredientAmountData, IngredientUnit, ServingsType,
+        },
+    },
+    repositories::{
+        ingredients::{errors::GetAllIngredientsError, IngredientRepositoryService},
+        unit_of_work::UnitOfWorkService,
+    },
+};
+
+use super::{
+    capitalize, parse_duration_text, parse_servings_text, ImportedRecipe, IngredientMatcher,
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum ImportCooklangError {
+    #[error("The recipe is missing the {0} field")]
+    MissingField(&'static str),
+
+    #[error("None of the ingredients could be matched to existing ingredients: {0:?}")]
+    NoIngredientsMatched(Vec<String>),
+
+    #[error(transparent)]
+    CreateRecipe(#[from] CreateRecipeError),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Report),
+}
+
+impl From<GetAllIngredientsError> for ImportCooklangError {
+    fn from(value: GetAllIngredientsError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+/// A `.cook` file, as uploaded by the user
+#[derive(Debug, Clone)]
+pub struct CooklangFile {
+    pub file_name: Option<String>,
+    pub content: String,
+}
+
+impl CooklangFile {
+    fn is_cooklang(&self) -> bool {
+        self.file_name.as_ref().is_none_or(|name| {
+            Path::new(name)
+                .extension()
+                .is_some_and(|extension| extension.eq_ignore_ascii_case("cook"))
+        })
+    }
+}
+
+/// Cooklang recipes are often named after the file they are in
+fn title_from_file_name(file_name: &str) -> Option<String> {
+    Path::new(file_name)
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().trim().to_string())
+        .filter(|stem| !stem.is_empty())
+}
+
+/// An ingredient written in a step, i.e. `@garlic{2%cloves}(minced)`
+#[derive(Debug, Clone, PartialEq)]
+pub struct CooklangIngredient {
+    pub name: String,
+    pub quantity: Option<String>,
+    pub unit: Option<String>,
+    pub note: Option<String>,
+    pub optional: bool,
+}
+
+impl CooklangIngredient {
+    /// Ingredients without a quantity, like `@salt`, are treated as a single unit of themselves
+    pub fn amount(&self) -> IngredientUnit {
+        parse_amount(&format!(
+            "{} {}",
+            self.quantity.as_deref().unwrap_or("1"),
+            self.unit.as_deref().unwrap_or_default()
+        ))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct CooklangRecipe {
+    pub name: String,
+    pub description: String,
+    pub steps: Vec<String>,
+    pub ingredients: Vec<CooklangIngredient>,
+    pub time: BTreeMap<String, Duration>,
+    pub servings: ServingsType,
+}
+
+/// Removes `-- line comments` and `[- block comments -]`, leaving escaped characters alone
+fn strip_comments(document: &str) -> String {
+    let mut result = String::with_capacity(document.len());
+    let mut chars = document.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                result.push(c);
+                if let Some(next) = chars.next() {
+                    result.push(next);
+                }
+            }
+            '-' if chars.peek() == Some(&'-') => {
+                if chars.by_ref().any(|next| next == '\n') {
+                    result.push('\n');
+                }
+            }
+            '[' if chars.peek() == Some(&'-') => {
+                chars.next();
+                let mut previous = ' ';
+                for next in chars.by_ref() {
+                    if previous == '-' && next == ']' {
+                        break;
+                    }
+                    previous = next;
+                }
+            }
+            _ => result.push(c),
+        }
+    }
+
+    result
+}
+
+/// Splits YAML-like front matter (`---` delimited `key: value` lines) from the rest of the document
+fn split_front_matter(document: &str) -> (Vec<(String, String)>, &str) {
+    let trimmed = document.trim_start();
+    let Some(rest) = trimmed.strip_prefix("---") else {
+        return (vec![], document);
+    };
+
+    let Some((front_matter, body)) = rest.split_once("\n---") else {
+        return (vec![], document);
+    };
+
+    let metadata = front_matter
+        .lines()
+        .filter_map(|line| line.split_once(':'))
+        .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()))
+        .collect();
+
+    (metadata, body)
+}
+
+/// A `@ingredient`, `#cookware` or `~timer` inside of a step
+struct Component {
+    modifiers: String,
+    name: String,
+    amount: Option<String>,
+    note: Option<String>,
+}
+
+impl Component {
+    /// Splits `2%cloves` into the quantity and the unit
+    fn quantity_and_unit(&self) -> (Option<String>, Option<String>) {
+        let Some(amount) = &self.amount else {
+            return (None, None);
+        };
+
+        let (quantity, unit) = amount.split_once('%').unwrap_or((amount, ""));
+        let non_empty = |s: &str| (!s.trim().is_empty()).then(|| s.trim().to_string());
+
+        (non_empty(quantity), non_empty(unit))
+    }
+}
+
+/// Parses a component starting right after its sigil, returning it along with the index after it.
+/// Names with more than one word have to end with `{}`, otherwise only the first word is the name.
+fn parse_component(chars: &[char], start: usize) -> Option<(Component, usize)> {
+    let mut i = start;
+
+    let mut modifiers = String::new();
+    while i < chars.len() && matches!(chars[i], '-' | '?' | '&' | '+') {
+        modifiers.push(chars[i]);
+        i += 1;
+    }
+
+    let name_end = chars[i..]
+        .iter()
+        .position(|c| matches!(c, '@' | '#' | '~' | '{' | '}' | '\\'))
+        .map(|position| i + position)
+        .filter(|&end| chars[end] == '{')
+        .unwrap_or_else(|| {
+            i + chars[i..]
+                .iter()
+                .take_while(|c| c.is_alphanumeric() || **c == '_')
+                .count()
+        });
+
+    let name: String = chars[i..name_end]
+        .iter()
+        .collect::<String>()
+        .trim()
+        .to_string();
+    i = name_end;
+
+    let mut amount = None;
+    if chars.get(i) == Some(&'{') {
+        let end = i + chars[i..].iter().position(|c| *c == '}')?;
+        amount = Some(chars[i + 1..end].iter().collect::<String>());
+        i = end + 1;
+    }
+
+    if name.is_empty() && amount.is_none() {
+        return None;
+    }
+
+    let mut note = None;
+    if chars.get(i) == Some(&'(') {
+        if let Some(position) = chars[i..].iter().position(|c| *c == ')') {
+            note = Some(chars[i + 1..i + position].iter().collect::<String>());
+            i += position + 1;
+        }
+    }
+
+    Some((
+        Component {
+            modifiers,
+            name,
+            amount,
+            note,
+        },
+        i,
+    ))
+}
+
+/// Turns a step into plain text, collecting the ingredients mentioned in it
+fn parse_step(step: &str, ingredients: &mut Vec<CooklangIngredient>) -> String {
+    let chars: Vec<char> = step.chars().collect();
+    let mut result = String::with_capacity(step.len());
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+
+        if c == '\\' && i + 1 < chars.len() {
+            result.push(chars[i + 1]);
+            i += 2;
+            continue;
+        }
+
+        let component = matches!(c, '@' | '#' | '~')
+            .then(|| parse_component(&chars, i + 1))
+            .flatten();
+
+        let Some((component, end)) = component else {
+            result.push(c);
+            i += 1;
+            continue;
+        };
+
+        let (quantity, unit) = component.quantity_and_unit();
+
+        match c {
+            '@' => {
+                if !component.modifiers.contains('-') {
+                    result.push_str(&component.name);
+                }
+                // `@&name` refers back to an ingredient that was already used
+                if !component.modifiers.contains('&') {
+                    ingredients.push(CooklangIngredient {
+                        name: component.name,
+                        quantity,
+                        unit,
+                        note: component.note.map(|note| note.trim().to_string()),
+                        optional: component.modifiers.contains('?'),
+                    });
+                }
+            }
+            '~' if quantity.is_some() => {
+                let timer = [quantity, unit].into_iter().flatten();
+                result.push_str(&timer.collect::<Vec<_>>().join(" "));
+            }
+            _ => result.push_str(&component.name),
+        }
+
+        i = end;
+    }
+
+    result.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Parses a Cooklang document. Recipes without a `title` are named after their file.
+pub fn parse_cooklang(
+    document: &str,
+    file_name: Option<&str>,
+) -> Result<CooklangRecipe, ImportCooklangError> {
+    let (mut metadata, body) = split_front_matter(document);
+    let body = strip_comments(body);
+
+    let mut paragraphs: Vec<Vec<&str>> = vec![vec![]];
+    for line in body.lines().map(str::trim) {
+        if let Some((key, value)) = line.strip_prefix(">>").and_then(|l| l.split_once(':')) {
+            metadata.push((key.trim().to_string(), value.trim().to_string()));
+        } else if line.is_empty() {
+            paragraphs.push(vec![]);
+        } else if let Some(paragraph) = paragraphs.last_mut() {
+            paragraph.push(line);
+        }
+    }
+
+    let mut ingredients = vec![];
+    let steps: Vec<String> = paragraphs
+        .iter()
+        .filter(|lines| !lines.is_empty())
+        .map(|lines| parse_step(&lines.join(" "), &mut ingredients))
+        .filter(|step| !step.is_empty())
+        .collect();
+
+    let mut name = None;
+    let mut description = String::new();
+    let mut servings = None;
+    let mut time = BTreeMap::new();
+
+    for (key, value) in metadata {
+        match key.to_lowercase().as_str() {
+            "title" => name = Some(value).filter(|v| !v.is_empty()),
+            "description" => description = value,
+            "servings" | "serves" | "yield" => servings = parse_servings_text(&value),
+            "time" => {
+                if let Some(duration) = parse_duration_text(&value) {
+                    time.insert("Total time".to_string(), duration);
+                }
+            }
+            lowercase if lowercase.ends_with("time") => {
+                if let Some(duration) = parse_duration_text(&value) {
+                    time.insert(capitalize(&key), duration);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let name = name
+        .or_else(|| file_name.and_then(title_from_file_name))
+        .ok_or(ImportCooklangError::MissingField("title"))?;
+
+    if steps.is_empty() {
+        return Err(ImportCooklangError::MissingField("steps"));
+    }
+
+    Ok(CooklangRecipe {
+        name,
+        description,
+        steps,
+        ingredients,
+        time,
+        // Recipes without servings are assumed to be for a single serving
+        servings: servings.unwrap_or(ServingsType::Exact(1)),
+    })
+}
+
+/// Adds up the amounts of an ingredient used in several steps, as long as they are in the same unit
+fn add_amounts(left: &IngredientUnit, right: &IngredientUnit) -> Option<IngredientUnit> {
+    use IngredientUnit::*;
+
+    match (left, right) {
+        (Grams(a), Grams(b)) => Some(Grams(a + b)),
+        (Mililiters(a), Mililiters(b)) => Some(Mililiters(a + b)),
+        (Teaspoons(a), Teaspoons(b)) => Some(Teaspoons(a + b)),
+        (Tablespoons(a), Tablespoons(b)) => Some(Tablespoons(a + b)),
+        (Cups(a), Cups(b)) => Some(Cups(a + b)),
+        (Pieces(a), Pieces(b)) => Some(Pieces(a + b)),
+        (
+            Other { amount: a, unit },
+            Other {
+                amount: b,
+                unit: other,
+            },
+        ) if unit == other => Some(Other {
+            amount: a + b,
+            unit: unit.clone(),
+        }),
+        _ => None,
+    }
+}
+
+impl CooklangRecipe {
+    /// Turns the parsed recipe into a recipe to be created, along with the names of the ingredients
+    /// that could not be matched to any of the given ingredients.
+    pub fn into_create_recipe(self, ingredients: &[Ingredient]) -> (CreateRecipe, Vec<String>) {
+        let matcher = IngredientMatcher::new(ingredients);
+        let mut matched: Vec<IngredientAmountData> = vec![];
+        let mut unmatched = vec![];
+
+        for ingredient in self.ingredients {
+            let Some(data) = matcher.match_line(&ingredient.name) else {
+                unmatched.push(ingredient.name);
+                continue;
+            };
+
+            let amount = ingredient.amount();
+
+            match matched
+                .iter_mut()
+                .find(|m| m.ingredient_id == data.ingredient_id)
+            {
+                None => matched.push(IngredientAmountData {
+                    amount,
+                    notes: ingredient.note.filter(|note| !note.is_empty()),
+                    optional: ingredient.optional,
+                    ..data
+                }),
+                Some(existing) => match add_amounts(&existing.amount, &amount) {
+                    Some(sum) => existing.amount = sum,
+                    None => unmatched.push(ingredient.name),
+                },
+            }
+        }
+
+        let input = CreateRecipe {
+            name: self.name,
+            description: self.description,
+            steps: self.steps,
+            time: self.time,
+            servings: self.servings,
+            ingredients: matched,
+        };
+
+        (input, unmatched)
+    }
+}
+
+async fn import_with_ingredients(
+    unit_of_work: UnitOfWorkService,
+    ingredients: &[Ingredient],
+    owner_id: Option<&Uuid>,
+    file: &CooklangFile,
+) -> Result<ImportedRecipe, ImportCooklangError> {
+    let parsed = parse_cooklang(&file.content, file.file_name.as_deref())?;
+    let (input, unmatched_ingredients) = parsed.into_create_recipe(ingredients);
+
+    if input.ingredients.is_empty() {
+        return Err(ImportCooklangError::NoIngredientsMatched(
+            unmatched_ingredients,
+        ));
+    }
+
+    let recipe = create_recipe(unit_of_work, owner_id, &input).await?;
+
+    Ok(ImportedRecipe {
+        recipe,
+        unmatched_ingredients,
+    })
+}
+
+#[tracing::instrument(
+    "[COMMAND] Importing a recipe from Cooklang",
+    skip(unit_of_work, ingredient_repo, file)
+)]
+pub async fn import_recipe_from_cooklang(
+    unit_of_work: UnitOfWorkService,
+    ingredient_repo: IngredientRepositoryService,
+    owner_id: Option<&Uuid>,
+    file: &CooklangFile,
+) -> Result<ImportedRecipe, ImportCooklangError> {
+    let ingredients = ingredient_repo.get_all().await?;
+
+    import_with_ingredients(unit_of_work, &ingredients, owner_id, file).await
+}
+
+#[derive(Debug, Default)]
+pub struct BulkImport {
+    pub imported: Vec<ImportedRecipe>,
+    /// Files that could not be imported, by their name
+    pub failed: Vec<(String, ImportCooklangError)>,
+}
+
+/// Imports every `.cook` file out of the uploaded ones, skipping anything else that was in the directory.
+/// A file failing to import does not stop the rest from being imported.
+#[tracing::instrument(
+    "[COMMAND] Importing recipes from Cooklang files",
+    skip(unit_of_work, ingredient_repo, files)
+)]
+pub async fn import_recipes_from_cooklang(
+    unit_of_work: UnitOfWorkService,
+    ingredient_repo: IngredientRepositoryService,
+    owner_id: Option<&Uuid>,
+    files: &[CooklangFile],
+) -> Result<BulkImport, ImportCooklangError> {
+    let ingredients = ingredient_repo.get_all().await?;
+    let mut result = BulkImport::default();
+
+    for (i, file) in files.iter().filter(|f| f.is_cooklang()).enumerate() {
+        match import_with_ingredients(unit_of_work.clone(), &ingredients, owner_id, file).await {
+            Ok(imported) => result.imported.push(imported),
+            Err(error) => {
+                let name = file
+                    .file_name
+                    .clone()
+                    .unwrap_or_else(|| format!("file {}", i + 1));
+                result.failed.push((name, error));
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,208 @@
+use std::{collections::BTreeMap, sync::Arc, time::Duration};
+
+use pretty_assertions::assert_eq;
+
+use crate::{
+    domain::{
+        commands::recipes::import::cooklang::{
+            import_recipe_from_cooklang, import_recipes_from_cooklang, CooklangFile,
+            ImportCooklangError,
+        },
+        entities::recipe::{IngredientUnit, ServingsType},
+        repositories::{
+            ingredients::{IngredientRepository, IngredientRepositoryService},
+            unit_of_work::{UnitOfWork, UnitOfWorkService},
+        },
+    },
+    test_utils::{insert_all_ingredients_of_recipe, recipe_fixture},
+};
+
+const RECIPE_COOK: &str = r#"
+>> title: Hoisin Tofu and Broccoli
+>> description: Tofu with broccoli
+>> servings: 2-4
+>> prep time: 15 minutes
+>> cook time: 10 min
+
+-- The tofu has to be firm, otherwise it falls apart
+Cut the @firm tofu{400%g} into cubes and
+marinate it for ~{30%minutes}.
+
+Heat a #large wok{} and fry the @&tofu. [- add more oil if needed -]
+
+Add @broccoli{1%head}, @?garlic{2%cloves}(minced), @salt and \@home garlic
+@garlic{2%cloves}, then stir.
+"#;
+
+fn file(file_name: Option<&str>, content: &str) -> CooklangFile {
+    CooklangFile {
+        file_name: file_name.map(ToString::to_string),
+        content: content.to_string(),
+    }
+}
+
+pub async fn importing_cooklang_recipe_works(
+    unit_of_work: impl UnitOfWork,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let recipe = recipe_fixture();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ingredient_repo));
+
+    let result = import_recipe_from_cooklang(
+        unit_of_work,
+        ingredient_repo,
+        None,
+        &file(Some("hoisin.cook"), RECIPE_COOK),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(result.unmatched_ingredients, vec!["salt"]);
+
+    let imported = result.recipe;
+    assert_eq!(imported.name, "Hoisin Tofu and Broccoli");
+    assert_eq!(imported.description, "Tofu with broccoli");
+    assert_eq!(
+        imported.steps.as_ref(),
+        &[
+            "Cut the firm tofu into cubes and marinate it for 30 minutes.",
+            "Heat a large wok and fry the tofu.",
+            "Add broccoli, garlic, salt and @home garlic garlic, then stir."
+        ]
+    );
+    assert_eq!(
+        imported.time,
+        BTreeMap::from([
+            ("Cook time".to_string(), Duration::from_secs(10 * 60)),
+            ("Prep time".to_string(), Duration::from_secs(15 * 60)),
+        ])
+    );
+    assert_eq!(imported.servings, ServingsType::FromTo(2, 4));
+
+    let mut amounts: Vec<_> = imported
+        .ingredients
+        .iter()
+        .map(|i| {
+            (
+                i.ingredient.name.to_string(),
+                i.amount.clone(),
+                i.notes.clone(),
+                i.optional,
+            )
+        })
+        .collect();
+    amounts.sort_by(|a, b| a.0.cmp(&b.0));
+
+    assert_eq!(
+        amounts,
+        vec![
+            (
+                "Broccoli".to_string(),
+                IngredientUnit::Other {
+                    amount: 1.0,
+                    unit: "head".to_string()
+                },
+                None,
+                false
+            ),
+            (
+                "Firm tofu".to_string(),
+                IngredientUnit::Grams(400.0),
+                None,
+                false
+            ),
+            (
+                "Garlic".to_string(),
+                IngredientUnit::Other {
+                    amount: 4.0,
+                    unit: "cloves".to_string()
+                },
+                Some("minced".to_string()),
+                true
+            ),
+        ]
+    );
+}
+
+pub async fn importing_recipe_without_title_uses_file_name(
+    unit_of_work: impl UnitOfWork,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let recipe = recipe_fixture();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ingredient_repo));
+
+    let result = import_recipe_from_cooklang(
+        unit_of_work,
+        ingredient_repo,
+        None,
+        &file(
+            Some("recipes/Steamed Broccoli.cook"),
+            "Steam the @broccoli{1%head} for ~{5%minutes}.",
+        ),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(result.recipe.name, "Steamed Broccoli");
+    assert_eq!(result.recipe.servings, ServingsType::Exact(1));
+    assert_eq!(
+        result.recipe.steps.as_ref(),
+        &["Steam the broccoli for 5 minutes."]
+    );
+}
+
+pub async fn importing_recipe_without_title_errors(
+    unit_of_work: impl UnitOfWork,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ingredient_repo));
+
+    let error = import_recipe_from_cooklang(
+        unit_of_work,
+        ingredient_repo,
+        None,
+        &file(None, "Steam the @broccoli{1%head}."),
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(error, ImportCooklangError::MissingField("title")));
+}
+
+pub async fn importing_cooklang_directory_works(
+    unit_of_work: impl UnitOfWork,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let recipe = recipe_fixture();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ingredient_repo));
+
+    let files = [
+        file(Some("Hoisin.cook"), RECIPE_COOK),
+        file(Some("Water.cook"), "Boil some @water{1%l}."),
+        file(Some("photo.jpg"), "not a recipe"),
+    ];
+
+    let result = import_recipes_from_cooklang(unit_of_work, ingredient_repo, None, &files)
+        .await
+        .unwrap();
+
+    assert_eq!(result.imported.len(), 1);
+    assert_eq!(result.imported[0].recipe.name, "Hoisin Tofu and Broccoli");
+
+    assert_eq!(result.failed.len(), 1);
+    assert_eq!(result.failed[0].0, "Water.cook");
+    assert!(matches!(
+        &result.failed[0].1,
+        ImportCooklangError::NoIngredientsMatched(names) if names == &vec!["water".to_string()]
+    ));
+}
@@ -0,0 +1,89 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::{
+        ingredients::in_memory::InMemoryIngredientRepository,
+        recipe::in_memory::InMemoryRecipeRepository, unit_of_work::in_memory::InMemoryUnitOfWork,
+    };
+
+    #[tokio::test]
+    async fn importing_cooklang_recipe_works() {
+        let recipe_repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let unit_of_work = InMemoryUnitOfWork::new(ingredient_repo.clone(), recipe_repo);
+
+        __tests__::importing_cooklang_recipe_works(unit_of_work, ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn importing_recipe_without_title_uses_file_name() {
+        let recipe_repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let unit_of_work = InMemoryUnitOfWork::new(ingredient_repo.clone(), recipe_repo);
+
+        __tests__::importing_recipe_without_title_uses_file_name(unit_of_work, ingredient_repo)
+            .await
+    }
+
+    #[tokio::test]
+    async fn importing_recipe_without_title_errors() {
+        let recipe_repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let unit_of_work = InMemoryUnitOfWork::new(ingredient_repo.clone(), recipe_repo);
+
+        __tests__::importing_recipe_without_title_errors(unit_of_work, ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn importing_cooklang_directory_works() {
+        let recipe_repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let unit_of_work = InMemoryUnitOfWork::new(ingredient_repo.clone(), recipe_repo);
+
+        __tests__::importing_cooklang_directory_works(unit_of_work, ingredient_repo).await
+    }
+}
+
+mod sql {
+    use sqlx::PgPool;
+
+    use super::__tests__;
+    use crate::domain::repositories::{
+        ingredients::postgres::PostgresIngredientRepository,
+        unit_of_work::postgres::PostgresUnitOfWork,
+    };
+
+    #[sqlx::test]
+    async fn importing_cooklang_recipe_works(pool: PgPool) {
+        let unit_of_work = PostgresUnitOfWork::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool);
+
+        __tests__::importing_cooklang_recipe_works(unit_of_work, ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn importing_recipe_without_title_uses_file_name(pool: PgPool) {
+        let unit_of_work = PostgresUnitOfWork::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool);
+
+        __tests__::importing_recipe_without_title_uses_file_name(unit_of_work, ingredient_repo)
+            .await
+    }
+
+    #[sqlx::test]
+    async fn importing_recipe_without_title_errors(pool: PgPool) {
+        let unit_of_work = PostgresUnitOfWork::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool);
+
+        __tests__::importing_recipe_without_title_errors(unit_of_work, ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn importing_cooklang_directory_works(pool: PgPool) {
+        let unit_of_work = PostgresUnitOfWork::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool);
+
+        __tests__::importing_cooklang_directory_works(unit_of_work, ingredient_repo).await
+    }
+}
@@ -0,0 +1,372 @@
+use std::{collections::BTreeMap, time::Duration};
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::domain::{
+    commands::recipes::create::{create_recipe, CreateRecipe, CreateRecipeError},
+    entities::{
+        ingredient::Ingredient,
+        recipe::{IngredientAmountData, ServingsType},
+    },
+    repositories::{
+        ingredients::{errors::GetAllIngredientsError, IngredientRepositoryService},
+        unit_of_work::UnitOfWorkService,
+    },
+};
+
+use super::{parse_servings_text, ImportedRecipe, IngredientMatcher};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum ImportJsonLdError {
+    #[error("The document does not contain valid JSON-LD: {0}")]
+    InvalidJson(#[from] serde_json::Error),
+
+    #[error("Could not find a schema.org Recipe in the document")]
+    RecipeNotFound,
+
+    #[error("The recipe is missing the {0} field")]
+    MissingField(&'static str),
+
+    #[error("None of the ingredients could be matched to existing ingredients: {0:?}")]
+    NoIngredientsMatched(Vec<String>),
+
+    #[error(transparent)]
+    CreateRecipe(#[from] CreateRecipeError),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Report),
+}
+
+impl From<GetAllIngredientsError> for ImportJsonLdError {
+    fn from(value: GetAllIngredientsError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+/// The parts of a schema.org `Recipe` we know how to turn into our own recipe
+#[derive(Debug, PartialEq)]
+pub struct JsonLdRecipe {
+    pub name: String,
+    pub description: String,
+    pub steps: Vec<String>,
+    pub ingredients: Vec<String>,
+    /// The group of every ingredient line, in the same order as the lines
+    pub ingredient_groups: Vec<Option<String>>,
+    pub time: BTreeMap<String, Duration>,
+    pub servings: ServingsType,
+}
+
+/// Maps schema.org time properties onto the keys we use in [`Recipe::time`](crate::domain::entities::recipe::Recipe::time)
+pub const TIME_PROPERTIES: [(&str, &str); 3] = [
+    ("prepTime", "Prep time"),
+    ("cookTime", "Cook time"),
+    ("totalTime", "Total time"),
+];
+
+/// The `propertyID` of the `additionalProperty` entries listing the ingredient lines of a group
+pub const INGREDIENT_GROUP_PROPERTY: &str = "recipeIngredientGroup";
+
+static LD_JSON_SCRIPT: Lazy<Regex> = Lazy::new(|| {
+    #[allow(clippy::unwrap_used)]
+    Regex::new(r#"(?is)<script[^>]*type\s*=\s*["']?application/ld\+json["']?[^>]*>(.*?)</script>"#)
+        .unwrap()
+});
+
+static HTML_TAG: Lazy<Regex> = Lazy::new(|| {
+    #[allow(clippy::unwrap_used)]
+    Regex::new(r"<[^>]+>").unwrap()
+});
+
+static ISO_DURATION: Lazy<Regex> = Lazy::new(|| {
+    #[allow(clippy::unwrap_used)]
+    Regex::new(
+        r"(?i)^P(?:(\d+)D)?(?:T(?:(\d+(?:\.\d+)?)H)?(?:(\d+(?:\.\d+)?)M)?(?:(\d+(?:\.\d+)?)S)?)?$",
+    )
+    .unwrap()
+});
+
+/// Parses an ISO 8601 duration, like `PT1H30M`. Years, months and weeks are not supported,
+/// and neither are durations too long to represent.
+/// ```rust
+/// use std::time::Duration;
+/// use backend::domain::commands::recipes::import::jsonld::parse_iso_duration;
+///
+/// assert_eq!(parse_iso_duration("PT1H30M"), Some(Duration::from_secs(90 * 60)));
+/// assert_eq!(parse_iso_duration("P1DT10S"), Some(Duration::from_secs(24 * 60 * 60 + 10)));
+/// assert_eq!(parse_iso_duration("30 minutes"), None);
+/// assert_eq!(parse_iso_duration("P99999999999999999999D"), None);
+/// ```
+pub fn parse_iso_duration(input: &str) -> Option<Duration> {
+    let captures = ISO_DURATION.captures(input.trim())?;
+    let part = |i: usize, multiplier: f64| -> f64 {
+        captures
+            .get(i)
+            .and_then(|m| m.as_str().parse::<f64>().ok())
+            .unwrap_or_default()
+            * multiplier
+    };
+
+    let seconds = part(1, 86400.0) + part(2, 3600.0) + part(3, 60.0) + part(4, 1.0);
+
+    Duration::try_from_secs_f64(seconds).ok()
+}
+
+fn is_recipe(object: &serde_json::Map<String, Value>) -> bool {
+    match object.get("@type") {
+        Some(Value::String(t)) => t == "Recipe",
+        Some(Value::Array(types)) => types.iter().any(|t| t == "Recipe"),
+        _ => false,
+    }
+}
+
+/// Walks through the whole JSON-LD document, as the recipe is often nested in `@graph` or `mainEntity`
+fn find_recipe(value: &Value) -> Option<&serde_json::Map<String, Value>> {
+    match value {
+        Value::Object(object) if is_recipe(object) => Some(object),
+        Value::Object(object) => object.values().find_map(find_recipe),
+        Value::Array(values) => values.iter().find_map(find_recipe),
+        _ => None,
+    }
+}
+
+fn clean_text(text: &str) -> String {
+    HTML_TAG.replace_all(text, "").trim().to_string()
+}
+
+fn get_text(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(clean_text(s)),
+        Value::Array(values) => values.first().and_then(get_text),
+        _ => None,
+    }
+}
+
+fn collect_text_list(value: &Value, result: &mut Vec<String>) {
+    match value {
+        Value::String(s) => {
+            result.extend(s.lines().map(clean_text).filter(|line| !line.is_empty()))
+        }
+        Value::Array(values) => values.iter().for_each(|v| collect_text_list(v, result)),
+        // HowToSection contains its steps in `itemListElement`, HowToStep keeps its text in `text`
+        Value::Object(object) => {
+            if let Some(items) = object.get("itemListElement") {
+                collect_text_list(items, result)
+            } else if let Some(text) = object.get("text").or_else(|| object.get("name")) {
+                collect_text_list(text, result)
+            }
+        }
+        _ => {}
+    }
+}
+
+fn parse_servings(value: &Value) -> Option<ServingsType> {
+    match value {
+        Value::Number(n) => n
+            .as_u64()
+            .and_then(|n| u16::try_from(n).ok())
+            .filter(|n| *n > 0)
+            .map(ServingsType::Exact),
+        Value::String(s) => parse_servings_text(s),
+        Value::Array(values) => values.iter().find_map(parse_servings),
+        _ => None,
+    }
+}
+
+fn is_ingredient_group(property: &Value) -> bool {
+    property.get("propertyID").and_then(Value::as_str) == Some(INGREDIENT_GROUP_PROPERTY)
+}
+
+/// Puts every line listed by a group into it. A line listed in several groups goes to the
+/// first of its copies that has no group yet.
+fn parse_ingredient_groups(properties: &[Value], ingredients: &[String]) -> Vec<Option<String>> {
+    let mut groups = vec![None; ingredients.len()];
+
+    for property in properties.iter().filter(|p| is_ingredient_group(p)) {
+        let Some(label) = property.get("name").and_then(get_text) else {
+            continue;
+        };
+
+        let mut lines = vec![];
+        if let Some(value) = property.get("value") {
+            collect_text_list(value, &mut lines);
+        }
+
+        for line in lines {
+            let position = ingredients
+                .iter()
+                .zip(&groups)
+                .position(|(ingredient, group)| *ingredient == line && group.is_none());
+
+            if let Some(position) = position {
+                groups[position] = Some(label.clone());
+            }
+        }
+    }
+
+    groups
+}
+
+fn get_json_documents(document: &str) -> Result<Vec<Value>, serde_json::Error> {
+    let trimmed = document.trim();
+
+    if trimmed.starts_with('{') || trimmed.starts_with('[') {
+        return Ok(vec![serde_json::from_str(trimmed)?]);
+    }
+
+    LD_JSON_SCRIPT
+        .captures_iter(document)
+        .map(|c| serde_json::from_str(c[1].trim()))
+        .collect()
+}
+
+/// Extracts a schema.org `Recipe` out of a HTML document, or a raw JSON-LD blob.
+pub fn parse_jsonld(document: &str) -> Result<JsonLdRecipe, ImportJsonLdError> {
+    let documents = get_json_documents(document)?;
+    let recipe = documents
+        .iter()
+        .find_map(find_recipe)
+        .ok_or(ImportJsonLdError::RecipeNotFound)?;
+
+    let name = recipe
+        .get("name")
+        .and_then(get_text)
+        .filter(|name| !name.is_empty())
+        .ok_or(ImportJsonLdError::MissingField("name"))?;
+
+    let description = recipe
+        .get("description")
+        .and_then(get_text)
+        .unwrap_or_default();
+
+    let mut steps = vec![];
+    if let Some(instructions) = recipe.get("recipeInstructions") {
+        collect_text_list(instructions, &mut steps);
+    }
+    if steps.is_empty() {
+        return Err(ImportJsonLdError::MissingField("recipeInstructions"));
+    }
+
+    let mut ingredients = vec![];
+    if let Some(lines) = recipe
+        .get("recipeIngredient")
+        .or_else(|| recipe.get("ingredients"))
+    {
+        collect_text_list(lines, &mut ingredients);
+    }
+    if ingredients.is_empty() {
+        return Err(ImportJsonLdError::MissingField("recipeIngredient"));
+    }
+
+    let mut time: BTreeMap<String, Duration> = TIME_PROPERTIES
+        .iter()
+        .filter_map(|(property, key)| {
+            recipe
+                .get(*property)
+                .and_then(Value::as_str)
+                .and_then(parse_iso_duration)
+                .filter(|duration| !duration.is_zero())
+                .map(|duration| (key.to_string(), duration))
+        })
+        .collect();
+
+    let properties = match recipe.get("additionalProperty") {
+        Some(Value::Array(properties)) => properties.as_slice(),
+        _ => &[],
+    };
+
+    // Times with no schema.org equivalent are exported as PropertyValues
+    time.extend(
+        properties
+            .iter()
+            .filter(|property| !is_ingredient_group(property))
+            .filter_map(|property| {
+                let name = property.get("name").and_then(get_text)?;
+                let duration = property
+                    .get("value")
+                    .and_then(Value::as_str)
+                    .and_then(parse_iso_duration)?;
+                Some((name, duration))
+            }),
+    );
+
+    let ingredient_groups = parse_ingredient_groups(properties, &ingredients);
+
+    // Recipes without a yield are assumed to be for a single serving
+    let servings = recipe
+        .get("recipeYield")
+        .and_then(parse_servings)
+        .unwrap_or(ServingsType::Exact(1));
+
+    Ok(JsonLdRecipe {
+        name,
+        description,
+        steps,
+        ingredients,
+        ingredient_groups,
+        time,
+        servings,
+    })
+}
+
+impl JsonLdRecipe {
+    /// Turns the parsed recipe into a recipe to be created, along with the ingredient lines
+    /// that could not be matched to any of the given ingredients.
+    pub fn into_create_recipe(self, ingredients: &[Ingredient]) -> (CreateRecipe, Vec<String>) {
+        let matcher = IngredientMatcher::new(ingredients);
+        let mut matched = vec![];
+        let mut unmatched = vec![];
+
+        for (line, group) in self.ingredients.into_iter().zip(self.ingredient_groups) {
+            match matcher.match_line(&line) {
+                Some(data) => matched.push(IngredientAmountData { group, ..data }),
+                None => unmatched.push(line),
+            }
+        }
+
+        let input = CreateRecipe {
+            name: self.name,
+            description: self.description,
+            steps: self.steps,
+            time: self.time,
+            servings: self.servings,
+            ingredients: matched,
+        };
+
+        (input, unmatched)
+    }
+}
+
+#[tracing::instrument(
+    "[COMMAND] Importing a recipe from JSON-LD",
+    skip(unit_of_work, ingredient_repo, document)
+)]
+pub async fn import_recipe_from_jsonld(
+    unit_of_work: UnitOfWorkService,
+    ingredient_repo: IngredientRepositoryService,
+    owner_id: Option<&Uuid>,
+    document: &str,
+) -> Result<ImportedRecipe, ImportJsonLdError> {
+    let parsed = parse_jsonld(document)?;
+    let ingredients = ingredient_repo.get_all().await?;
+
+    let (input, unmatched_ingredients) = parsed.into_create_recipe(&ingredients);
+
+    if input.ingredients.is_empty() {
+        return Err(ImportJsonLdError::NoIngredientsMatched(
+            unmatched_ingredients,
+        ));
+    }
+
+    let recipe = create_recipe(unit_of_work, owner_id, &input).await?;
+
+    Ok(ImportedRecipe {
+        recipe,
+        unmatched_ingredients,
+    })
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,235 @@
+use std::{collections::BTreeMap, sync::Arc, time::Duration};
+
+use pretty_assertions::assert_eq;
+
+use crate::{
+    domain::{
+        commands::recipes::import::jsonld::{import_recipe_from_jsonld, ImportJsonLdError},
+        entities::recipe::{IngredientUnit, ServingsType},
+        repositories::{
+            ingredients::{IngredientRepository, IngredientRepositoryService},
+            unit_of_work::{UnitOfWork, UnitOfWorkService},
+        },
+    },
+    test_utils::{insert_all_ingredients_of_recipe, recipe_fixture},
+};
+
+const RECIPE_HTML: &str = r#"
+<!DOCTYPE html>
+<html>
+<head>
+    <title>Hoisin Tofu and Broccoli</title>
+    <script type="application/ld+json">
+    {
+        "@context": "https://schema.org",
+        "@graph": [
+            { "@type": "WebPage", "name": "Hoisin Tofu and Broccoli - a recipe website" },
+            {
+                "@type": "Recipe",
+                "name": "Hoisin Tofu and Broccoli",
+                "description": "<p>Tofu with broccoli</p>",
+                "prepTime": "PT15M",
+                "cookTime": "PT10M",
+                "recipeYield": ["4", "4 servings"],
+                "recipeIngredient": [
+                    "400g firm tofu",
+                    "1 head broccoli",
+                    "4 cloves garlic, minced",
+                    "1 tbsp hoisin sauce"
+                ],
+                "recipeInstructions": [
+                    {
+                        "@type": "HowToSection",
+                        "name": "Tofu",
+                        "itemListElement": [
+                            { "@type": "HowToStep", "text": "Cut the tofu into cubes." },
+                            { "@type": "HowToStep", "text": "Marinate the tofu." }
+                        ]
+                    },
+                    { "@type": "HowToStep", "text": "Stir fry everything together." }
+                ]
+            }
+        ]
+    }
+    </script>
+</head>
+<body></body>
+</html>
+"#;
+
+const RECIPE_JSON: &str = r#"
+{
+    "@context": "https://schema.org",
+    "@type": ["Recipe", "NewsArticle"],
+    "name": "Steamed broccoli",
+    "recipeYield": "2-3",
+    "recipeIngredient": ["1 head broccoli (optional)"],
+    "recipeInstructions": "Steam the broccoli.\nServe."
+}
+"#;
+
+pub async fn importing_recipe_from_html_works(
+    unit_of_work: impl UnitOfWork,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let recipe = recipe_fixture();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ingredient_repo));
+
+    let result = import_recipe_from_jsonld(unit_of_work, ingredient_repo, None, RECIPE_HTML)
+        .await
+        .unwrap();
+
+    assert_eq!(result.unmatched_ingredients, vec!["1 tbsp hoisin sauce"]);
+
+    let imported = result.recipe;
+    assert_eq!(imported.name, "Hoisin Tofu and Broccoli");
+    assert_eq!(imported.description, "Tofu with broccoli");
+    assert_eq!(
+        imported.steps.as_ref(),
+        &[
+            "Cut the tofu into cubes.",
+            "Marinate the tofu.",
+            "Stir fry everything together."
+        ]
+    );
+    assert_eq!(
+        imported.time,
+        BTreeMap::from([
+            ("Cook time".to_string(), Duration::from_secs(10 * 60)),
+            ("Prep time".to_string(), Duration::from_secs(15 * 60)),
+        ])
+    );
+    assert_eq!(imported.servings, ServingsType::Exact(4));
+
+    let mut amounts: Vec<_> = imported
+        .ingredients
+        .iter()
+        .map(|i| {
+            (
+                i.ingredient.name.to_string(),
+                i.amount.clone(),
+                i.notes.clone(),
+            )
+        })
+        .collect();
+    amounts.sort_by(|a, b| a.0.cmp(&b.0));
+
+    assert_eq!(
+        amounts,
+        vec![
+            (
+                "Broccoli".to_string(),
+                IngredientUnit::Other {
+                    amount: 1.0,
+                    unit: "head".to_string()
+                },
+                None
+            ),
+            ("Firm tofu".to_string(), IngredientUnit::Grams(400.0), None),
+            (
+                "Garlic".to_string(),
+                IngredientUnit::Other {
+                    amount: 4.0,
+                    unit: "cloves".to_string()
+                },
+                Some("minced".to_string())
+            ),
+        ]
+    );
+}
+
+pub async fn importing_raw_jsonld_works(
+    unit_of_work: impl UnitOfWork,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let recipe = recipe_fixture();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ingredient_repo));
+
+    let result = import_recipe_from_jsonld(unit_of_work, ingredient_repo, None, RECIPE_JSON)
+        .await
+        .unwrap();
+
+    assert!(result.unmatched_ingredients.is_empty());
+
+    let imported = result.recipe;
+    assert_eq!(imported.servings, ServingsType::FromTo(2, 3));
+    assert_eq!(imported.steps.as_ref(), &["Steam the broccoli.", "Serve."]);
+    assert_eq!(imported.ingredients.len(), 1);
+    assert!(imported.ingredients[0].optional);
+    assert_eq!(imported.ingredients[0].notes, None);
+}
+
+pub async fn importing_recipe_with_out_of_range_values_ignores_them(
+    unit_of_work: impl UnitOfWork,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let recipe = recipe_fixture();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ingredient_repo));
+
+    let document = r#"
+    {
+        "@type": "Recipe",
+        "name": "Slow broccoli",
+        "prepTime": "P99999999999999999999D",
+        "cookTime": "PT10M",
+        "recipeYield": 100000,
+        "recipeIngredient": ["1 head broccoli"],
+        "recipeInstructions": "Wait."
+    }
+    "#;
+
+    let result = import_recipe_from_jsonld(unit_of_work, ingredient_repo, None, document)
+        .await
+        .unwrap();
+
+    let imported = result.recipe;
+    assert_eq!(imported.servings, ServingsType::Exact(1));
+    assert_eq!(
+        imported.time,
+        BTreeMap::from([("Cook time".to_string(), Duration::from_secs(10 * 60))])
+    );
+}
+
+pub async fn importing_document_without_recipe_errors(
+    unit_of_work: impl UnitOfWork,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ingredient_repo));
+
+    let error = import_recipe_from_jsonld(
+        unit_of_work,
+        ingredient_repo,
+        None,
+        "<html><body>No recipes here</body></html>",
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(error, ImportJsonLdError::RecipeNotFound));
+}
+
+pub async fn importing_recipe_without_known_ingredients_errors(
+    unit_of_work: impl UnitOfWork,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ingredient_repo));
+
+    let error = import_recipe_from_jsonld(unit_of_work, ingredient_repo, None, RECIPE_JSON)
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(error, ImportJsonLdError::NoIngredientsMatched(lines) if lines == vec!["1 head broccoli (optional)"])
+    );
+}
@@ -0,0 +1,114 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::{
+        ingredients::in_memory::InMemoryIngredientRepository,
+        recipe::in_memory::InMemoryRecipeRepository, unit_of_work::in_memory::InMemoryUnitOfWork,
+    };
+
+    #[tokio::test]
+    async fn importing_recipe_from_html_works() {
+        let recipe_repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let unit_of_work = InMemoryUnitOfWork::new(ingredient_repo.clone(), recipe_repo);
+
+        __tests__::importing_recipe_from_html_works(unit_of_work, ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn importing_raw_jsonld_works() {
+        let recipe_repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let unit_of_work = InMemoryUnitOfWork::new(ingredient_repo.clone(), recipe_repo);
+
+        __tests__::importing_raw_jsonld_works(unit_of_work, ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn importing_recipe_with_out_of_range_values_ignores_them() {
+        let recipe_repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let unit_of_work = InMemoryUnitOfWork::new(ingredient_repo.clone(), recipe_repo);
+
+        __tests__::importing_recipe_with_out_of_range_values_ignores_them(
+            unit_of_work,
+            ingredient_repo,
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn importing_document_without_recipe_errors() {
+        let recipe_repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let unit_of_work = InMemoryUnitOfWork::new(ingredient_repo.clone(), recipe_repo);
+
+        __tests__::importing_document_without_recipe_errors(unit_of_work, ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn importing_recipe_without_known_ingredients_errors() {
+        let recipe_repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let unit_of_work = InMemoryUnitOfWork::new(ingredient_repo.clone(), recipe_repo);
+
+        __tests__::importing_recipe_without_known_ingredients_errors(unit_of_work, ingredient_repo)
+            .await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        ingredients::postgres::PostgresIngredientRepository,
+        unit_of_work::postgres::PostgresUnitOfWork,
+    };
+
+    #[sqlx::test]
+    async fn importing_recipe_from_html_works(pool: PgPool) {
+        let unit_of_work = PostgresUnitOfWork::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+
+        __tests__::importing_recipe_from_html_works(unit_of_work, ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn importing_raw_jsonld_works(pool: PgPool) {
+        let unit_of_work = PostgresUnitOfWork::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+
+        __tests__::importing_raw_jsonld_works(unit_of_work, ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn importing_recipe_with_out_of_range_values_ignores_them(pool: PgPool) {
+        let unit_of_work = PostgresUnitOfWork::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+
+        __tests__::importing_recipe_with_out_of_range_values_ignores_them(
+            unit_of_work,
+            ingredient_repo,
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn importing_document_without_recipe_errors(pool: PgPool) {
+        let unit_of_work = PostgresUnitOfWork::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+
+        __tests__::importing_document_without_recipe_errors(unit_of_work, ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn importing_recipe_without_known_ingredients_errors(pool: PgPool) {
+        let unit_of_work = PostgresUnitOfWork::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+
+        __tests__::importing_recipe_without_known_ingredients_errors(unit_of_work, ingredient_repo)
+            .await
+    }
+}
@@ -0,0 +1,188 @@
+pub mod cooklang;
+pub mod jsonld;
+pub mod text;
+
+use std::time::Duration;
+
+use common::ImportedRecipeDTO;
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::domain::entities::{
+    ingredient::Ingredient,
+    recipe::{ingredient_line::parse_amount, IngredientAmountData, Recipe, ServingsType},
+};
+
+#[derive(Debug)]
+pub struct ImportedRecipe {
+    pub recipe: Recipe,
+    /// Ingredient lines from the source that could not be matched to any existing ingredient
+    pub unmatched_ingredients: Vec<String>,
+}
+
+impl From<ImportedRecipe> for ImportedRecipeDTO {
+    fn from(value: ImportedRecipe) -> Self {
+        Self {
+            recipe: value.recipe.into(),
+            unmatched_ingredients: value.unmatched_ingredients,
+        }
+    }
+}
+
+static SERVINGS_RANGE: Lazy<Regex> = Lazy::new(|| {
+    #[allow(clippy::unwrap_used)]
+    Regex::new(r"(\d+)\s*(?:-|–|to)\s*(\d+)").unwrap()
+});
+
+static NUMBER: Lazy<Regex> = Lazy::new(|| {
+    #[allow(clippy::unwrap_used)]
+    Regex::new(r"\d+").unwrap()
+});
+
+/// Parses servings written as text, i.e. `4 servings`, `Serves 2-3` or `6`
+fn parse_servings_text(text: &str) -> Option<ServingsType> {
+    if let Some(range) = SERVINGS_RANGE.captures(text) {
+        let from = range[1].parse().ok().filter(|from| *from > 0)?;
+        let to = range[2].parse().ok()?;
+        Some(ServingsType::FromTo(from, to))
+    } else {
+        NUMBER
+            .find(text)
+            .and_then(|n| n.as_str().parse().ok())
+            .filter(|n| *n > 0)
+            .map(ServingsType::Exact)
+    }
+}
+
+static DURATION_PART: Lazy<Regex> = Lazy::new(|| {
+    #[allow(clippy::unwrap_used)]
+    Regex::new(r"(\d+(?:[.,]\d+)?)\s*([[:alpha:]]*)").unwrap()
+});
+
+/// Parses a duration written for people, i.e. `1 hour 30 minutes` or `1h30m`.
+/// A number without a unit is treated as minutes, and durations too long to represent are ignored.
+/// ```rust
+/// use std::time::Duration;
+/// use backend::domain::commands::recipes::import::parse_duration_text;
+///
+/// assert_eq!(parse_duration_text("1 h 30 min"), Some(Duration::from_secs(90 * 60)));
+/// assert_eq!(parse_duration_text("1h30m"), Some(Duration::from_secs(90 * 60)));
+/// assert_eq!(parse_duration_text("15"), Some(Duration::from_secs(15 * 60)));
+/// assert_eq!(parse_duration_text("a while"), None);
+/// assert_eq!(parse_duration_text("99999999999999999999 days"), None);
+/// ```
+pub fn parse_duration_text(text: &str) -> Option<Duration> {
+    let mut seconds = 0.0;
+    let mut found = false;
+
+    for captures in DURATION_PART.captures_iter(text) {
+        let amount: f64 = captures[1].replace(',', ".").parse().ok()?;
+        let multiplier = match captures[2].to_lowercase().as_str() {
+            "d" | "day" | "days" => 86400.0,
+            "h" | "hr" | "hrs" | "hour" | "hours" => 3600.0,
+            "" | "m" | "min" | "mins" | "minute" | "minutes" => 60.0,
+            "s" | "sec" | "secs" | "second" | "seconds" => 1.0,
+            _ => return None,
+        };
+
+        seconds += amount * multiplier;
+        found = true;
+    }
+
+    found
+        .then(|| Duration::try_from_secs_f64(seconds).ok())
+        .flatten()
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+static OPTIONAL: Lazy<Regex> = Lazy::new(|| {
+    #[allow(clippy::unwrap_used)]
+    Regex::new(r"(?i)\(?\boptional\b\)?").unwrap()
+});
+
+/// Matches free-form ingredient lines (`2 cups flour, sifted`) to the known ingredients.
+pub struct IngredientMatcher<'a> {
+    patterns: Vec<(Regex, &'a Ingredient)>,
+}
+
+impl<'a> IngredientMatcher<'a> {
+    pub fn new(ingredients: &'a [Ingredient]) -> Self {
+        let patterns = ingredients
+            .iter()
+            .filter_map(|ingredient| {
+                let pattern = format!(
+                    r"(?i)(?:^|\W)({}(?:es|s)?)(?:\W|$)",
+                    regex::escape(&ingredient.name)
+                );
+                Regex::new(&pattern).ok().map(|regex| (regex, ingredient))
+            })
+            .collect();
+
+        Self { patterns }
+    }
+
+    /// The longest ingredient name found in the text wins
+    fn find_match<'t>(&self, text: &'t str) -> Option<(regex::Match<'t>, &'a Ingredient)> {
+        self.patterns
+            .iter()
+            .filter_map(|(regex, ingredient)| {
+                regex
+                    .captures(text)
+                    .and_then(|c| c.get(1))
+                    .map(|name| (name, *ingredient))
+            })
+            .max_by_key(|(name, _)| name.len())
+    }
+
+    /// Finds the ingredient mentioned in an ingredient's name, i.e. `Tofu` in `firm tofu`
+    pub fn find(&self, name: &str) -> Option<&'a Ingredient> {
+        self.find_match(name).map(|(_, ingredient)| ingredient)
+    }
+
+    /// The text before the ingredient's name is the quantity, the text after it becomes the notes.
+    pub fn match_line(&self, line: &str) -> Option<IngredientAmountData> {
+        let (name, ingredient) = self.find_match(line)?;
+
+        let quantity = &line[..name.start()];
+        let rest = &line[name.end()..];
+
+        let optional = OPTIONAL.is_match(rest);
+        let notes = OPTIONAL.replace(rest, "");
+        let notes = notes
+            .trim()
+            .trim_matches([',', ';', '-'])
+            .trim()
+            .to_string();
+
+        Some(IngredientAmountData {
+            ingredient_id: ingredient.id,
+            amount: parse_amount(quantity),
+            optional,
+            notes: (!notes.is_empty()).then_some(notes),
+            group: None,
+        })
+    }
+
+    /// Matches every line, returning the matched ingredients and the lines that could not be matched.
+    /// An ingredient that shows up on several lines is kept as several entries.
+    pub fn match_lines(&self, lines: &[String]) -> (Vec<IngredientAmountData>, Vec<String>) {
+        let mut matched: Vec<IngredientAmountData> = vec![];
+        let mut unmatched = vec![];
+
+        for line in lines {
+            match self.match_line(line) {
+                Some(data) => matched.push(data),
+                None => unmatched.push(line.clone()),
+            }
+        }
+
+        (matched, unmatched)
+    }
+}
@@ -0,0 +1,231 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use super::{parse_servings_text, strip_list_marker, ImportTextError, TextRecipe, LIST_MARKER};
+
+static HEADER: Lazy<Regex> = Lazy::new(|| {
+    #[allow(clippy::unwrap_used)]
+    Regex::new(r"(?im)^\s*(?:MMMMM|-----).*meal-master.*$").unwrap()
+});
+
+static FOOTER: Lazy<Regex> = Lazy::new(|| {
+    #[allow(clippy::unwrap_used)]
+    Regex::new(r"^\s*(?:MMMMM|-----)\s*$").unwrap()
+});
+
+/// Ingredient groups, like `MMMMM-----------------------FROSTING---------------------------`
+static GROUP_HEADING: Lazy<Regex> = Lazy::new(|| {
+    #[allow(clippy::unwrap_used)]
+    Regex::new(r"^\s*(?:MMMMM|-----)-*.*-+\s*$").unwrap()
+});
+
+static METADATA: Lazy<Regex> = Lazy::new(|| {
+    #[allow(clippy::unwrap_used)]
+    Regex::new(r"(?i)^\s*(title|categories|yield|servings)\s*:\s*(.*)$").unwrap()
+});
+
+static QUANTITY_COLUMN: Lazy<Regex> = Lazy::new(|| {
+    #[allow(clippy::unwrap_used)]
+    Regex::new(r"^[\d\s/.]*$").unwrap()
+});
+
+/// Width of a single column of ingredients, when they are laid out in two columns
+const COLUMN_WIDTH: usize = 41;
+
+pub fn is_mealmaster(document: &str) -> bool {
+    HEADER.is_match(document)
+}
+
+/// Expands the two letter MealMaster unit codes into names [`IngredientUnit::from_unit_name`](crate::domain::entities::recipe::IngredientUnit::from_unit_name) understands
+fn unit_name(code: &str) -> Option<&'static str> {
+    let name = match code {
+        // `t` and `T` are the only codes where the case matters
+        "t" => "tsp",
+        "T" => "tbsp",
+        _ => match code.to_lowercase().as_str() {
+            "" | "x" | "ea" => "",
+            "ts" => "tsp",
+            "tb" => "tbsp",
+            "c" => "cup",
+            "sm" => "small",
+            "md" => "medium",
+            "lg" => "large",
+            "cn" => "can",
+            "pk" => "package",
+            "pn" => "pinch",
+            "dr" => "drop",
+            "ds" => "dash",
+            "ct" => "carton",
+            "bn" => "bunch",
+            "sl" => "slice",
+            "fl" => "fl oz",
+            "pt" => "pint",
+            "qt" => "quart",
+            "ga" => "gallon",
+            "oz" => "oz",
+            "lb" => "lb",
+            "ml" => "ml",
+            "cb" => "cubic cm",
+            "cl" => "cl",
+            "dl" => "dl",
+            "l" => "l",
+            "mg" => "mg",
+            "cg" => "cg",
+            "dg" => "dg",
+            "g" => "g",
+            "kg" => "kg",
+            _ => return None,
+        },
+    };
+
+    Some(name)
+}
+
+enum IngredientLine {
+    Ingredient(String),
+    /// Lines starting with a `-` continue the previous ingredient
+    Continuation(String),
+}
+
+/// Parses an ingredient laid out in MealMaster's columns: 7 characters of quantity, 2 of unit, then the text
+fn parse_ingredient_column(chars: &[char]) -> Option<IngredientLine> {
+    if chars.len() < 12 || chars[7] != ' ' || chars[10] != ' ' {
+        return None;
+    }
+
+    let quantity: String = chars[..7].iter().collect();
+    let code: String = chars[8..10].iter().collect();
+    let text: String = chars[11..].iter().collect();
+    let (quantity, code, text) = (quantity.trim(), code.trim(), text.trim());
+
+    if !QUANTITY_COLUMN.is_match(quantity) || text.is_empty() {
+        return None;
+    }
+
+    if quantity.is_empty() && code.is_empty() {
+        if let Some(continuation) = text.strip_prefix('-') {
+            return Some(IngredientLine::Continuation(
+                continuation.trim().to_string(),
+            ));
+        }
+    }
+
+    let unit = unit_name(code)?;
+    let line = [quantity, unit, text]
+        .iter()
+        .filter(|part| !part.is_empty())
+        .copied()
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    Some(IngredientLine::Ingredient(line))
+}
+
+fn parse_ingredient_line(line: &str) -> Option<Vec<IngredientLine>> {
+    let chars: Vec<char> = line.trim_end().chars().collect();
+
+    if chars.len() > COLUMN_WIDTH + 11 {
+        let left = parse_ingredient_column(&chars[..COLUMN_WIDTH]);
+        let right = parse_ingredient_column(&chars[COLUMN_WIDTH..]);
+
+        if let (Some(left), Some(right)) = (left, right) {
+            return Some(vec![left, right]);
+        }
+    }
+
+    parse_ingredient_column(&chars).map(|line| vec![line])
+}
+
+fn parse_recipe(lines: &[&str]) -> Result<TextRecipe, ImportTextError> {
+    let mut recipe = TextRecipe::default();
+    let mut in_directions = false;
+    let mut steps: Vec<String> = vec![];
+    let mut continues = false;
+
+    for line in lines {
+        if let Some(captures) = METADATA.captures(line).filter(|_| !in_directions) {
+            match captures[1].to_lowercase().as_str() {
+                "title" => recipe.name = captures[2].trim().to_string(),
+                "yield" | "servings" => recipe.servings = parse_servings_text(&captures[2]),
+                _ => {}
+            }
+            continue;
+        }
+
+        if line.trim().is_empty() {
+            continues = false;
+            continue;
+        }
+
+        if GROUP_HEADING.is_match(line) {
+            continue;
+        }
+
+        if !in_directions {
+            if let Some(ingredients) = parse_ingredient_line(line) {
+                for ingredient in ingredients {
+                    match (ingredient, recipe.ingredients.last_mut()) {
+                        (IngredientLine::Continuation(text), Some(last)) => {
+                            last.push_str(", ");
+                            last.push_str(&text);
+                        }
+                        (IngredientLine::Continuation(text), None)
+                        | (IngredientLine::Ingredient(text), _) => recipe.ingredients.push(text),
+                    }
+                }
+                continue;
+            }
+
+            in_directions = true;
+        }
+
+        // Directions are wrapped, so a paragraph is a step - unless the steps are numbered
+        let text = strip_list_marker(line);
+        match steps.last_mut() {
+            Some(step) if continues && !LIST_MARKER.is_match(line.trim()) => {
+                step.push(' ');
+                step.push_str(&text);
+            }
+            _ => steps.push(text),
+        }
+        continues = true;
+    }
+
+    recipe.steps = steps;
+
+    if recipe.name.is_empty() {
+        return Err(ImportTextError::MissingSection(recipe.name, "title"));
+    }
+
+    recipe.ensure_complete()
+}
+
+/// Parses every recipe in a MealMaster file. A recipe that can't be parsed doesn't stop the rest from being parsed.
+pub fn parse(document: &str) -> Result<Vec<Result<TextRecipe, ImportTextError>>, ImportTextError> {
+    let mut recipes = vec![];
+    let mut current: Option<Vec<&str>> = None;
+
+    for line in document.lines() {
+        if HEADER.is_match(line) {
+            if let Some(lines) = current.replace(vec![]) {
+                recipes.push(parse_recipe(&lines));
+            }
+        } else if FOOTER.is_match(line) {
+            if let Some(lines) = current.take() {
+                recipes.push(parse_recipe(&lines));
+            }
+        } else if let Some(lines) = current.as_mut() {
+            lines.push(line);
+        }
+    }
+
+    if let Some(lines) = current {
+        recipes.push(parse_recipe(&lines));
+    }
+
+    if recipes.is_empty() {
+        return Err(ImportTextError::RecipeNotFound);
+    }
+
+    Ok(recipes)
+}
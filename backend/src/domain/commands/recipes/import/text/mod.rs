@@ -0,0 +1,317 @@
+mod mealmaster;
+
+use std::{collections::BTreeMap, time::Duration};
+
+use common::RecipePreviewDTO;
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::domain::{
+    commands::recipes::create::CreateRecipe,
+    entities::{ingredient::Ingredient, recipe::ServingsType},
+    repositories::ingredients::{errors::GetAllIngredientsError, IngredientRepositoryService},
+};
+
+use super::{capitalize, parse_duration_text, parse_servings_text, IngredientMatcher};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum ImportTextError {
+    #[error("Could not find a recipe in the document")]
+    RecipeNotFound,
+
+    #[error("The recipe {0:?} is missing its {1}")]
+    MissingSection(String, &'static str),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Report),
+}
+
+impl From<GetAllIngredientsError> for ImportTextError {
+    fn from(value: GetAllIngredientsError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+/// A recipe split out of a text document, with its ingredient lines not yet matched to ingredients
+#[derive(Debug, PartialEq, Default)]
+pub struct TextRecipe {
+    pub name: String,
+    pub description: String,
+    pub steps: Vec<String>,
+    pub ingredients: Vec<String>,
+    pub time: BTreeMap<String, Duration>,
+    pub servings: Option<ServingsType>,
+}
+
+/// A recipe that is ready to be created, once the user confirms it
+#[derive(Debug)]
+pub struct RecipePreview {
+    pub recipe: CreateRecipe,
+    /// Ingredient lines from the source that could not be matched to any existing ingredient
+    pub unmatched_ingredients: Vec<String>,
+}
+
+impl From<RecipePreview> for RecipePreviewDTO {
+    fn from(value: RecipePreview) -> Self {
+        Self {
+            recipe: value.recipe.into(),
+            unmatched_ingredients: value.unmatched_ingredients,
+        }
+    }
+}
+
+static SERVINGS_LINE: Lazy<Regex> = Lazy::new(|| {
+    #[allow(clippy::unwrap_used)]
+    Regex::new(r"(?i)^(?:serves|servings|yield|yields|makes)\s*:?\s*(.+)$").unwrap()
+});
+
+static TIME_LINE: Lazy<Regex> = Lazy::new(|| {
+    #[allow(clippy::unwrap_used)]
+    Regex::new(r"(?i)^([\w ]*time)\s*:\s*(.+)$").unwrap()
+});
+
+static LIST_MARKER: Lazy<Regex> = Lazy::new(|| {
+    #[allow(clippy::unwrap_used)]
+    Regex::new(r"^(?:[-*•]|(?i:step)?\s*\d+[.):])\s*").unwrap()
+});
+
+static INGREDIENT_LIKE: Lazy<Regex> = Lazy::new(|| {
+    #[allow(clippy::unwrap_used)]
+    Regex::new(r"^(?:[-*•]\s*)?[\d½⅓⅔¼¾⅛]").unwrap()
+});
+
+#[derive(Clone, Copy, PartialEq)]
+enum Section {
+    Preamble,
+    Ingredients,
+    Directions,
+}
+
+fn get_section(line: &str) -> Option<Section> {
+    let heading = line
+        .trim_start_matches('#')
+        .trim()
+        .trim_end_matches(':')
+        .trim()
+        .to_lowercase();
+
+    match heading.as_str() {
+        "ingredients" | "ingredient list" => Some(Section::Ingredients),
+        "directions" | "instructions" | "method" | "steps" | "preparation" => {
+            Some(Section::Directions)
+        }
+        _ => None,
+    }
+}
+
+fn strip_list_marker(line: &str) -> String {
+    LIST_MARKER.replace(line.trim(), "").trim().to_string()
+}
+
+/// Turns the lines of the directions into steps. When the steps are numbered,
+/// unnumbered lines continue the previous step - otherwise every line is a step.
+fn collect_steps(lines: &[&str]) -> Vec<String> {
+    let numbered = lines.iter().any(|line| LIST_MARKER.is_match(line.trim()));
+    let mut steps: Vec<String> = vec![];
+    let mut continues = false;
+
+    for line in lines {
+        let line = line.trim();
+        if line.is_empty() {
+            continues = false;
+            continue;
+        }
+
+        let text = strip_list_marker(line);
+        match steps.last_mut() {
+            Some(step) if numbered && continues && !LIST_MARKER.is_match(line) => {
+                step.push(' ');
+                step.push_str(&text);
+            }
+            _ => steps.push(text),
+        }
+        continues = true;
+    }
+
+    steps
+}
+
+impl TextRecipe {
+    /// Picks up `Serves: 4` and `Prep time: 10 minutes` lines, returning whether the line was one of them
+    fn parse_metadata(&mut self, line: &str) -> bool {
+        if let Some(captures) = SERVINGS_LINE.captures(line) {
+            if let Some(servings) = parse_servings_text(&captures[1]) {
+                self.servings = Some(servings);
+            }
+            return true;
+        }
+
+        if let Some(captures) = TIME_LINE.captures(line) {
+            if let Some(duration) = parse_duration_text(&captures[2]) {
+                let key = capitalize(&captures[1].trim().to_lowercase());
+                self.time.insert(key, duration);
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn ensure_complete(self) -> Result<Self, ImportTextError> {
+        if self.ingredients.is_empty() {
+            return Err(ImportTextError::MissingSection(self.name, "ingredients"));
+        }
+        if self.steps.is_empty() {
+            return Err(ImportTextError::MissingSection(self.name, "directions"));
+        }
+
+        Ok(self)
+    }
+
+    /// Turns the parsed recipe into a recipe to be created, along with the ingredient lines
+    /// that could not be matched to any of the given ingredients.
+    pub fn into_create_recipe(self, ingredients: &[Ingredient]) -> (CreateRecipe, Vec<String>) {
+        let (matched, unmatched) =
+            IngredientMatcher::new(ingredients).match_lines(&self.ingredients);
+
+        let input = CreateRecipe {
+            name: self.name,
+            description: self.description,
+            steps: self.steps,
+            time: self.time,
+            // Recipes without servings are assumed to be for a single serving
+            servings: self.servings.unwrap_or(ServingsType::Exact(1)),
+            ingredients: matched,
+        };
+
+        (input, unmatched)
+    }
+}
+
+/// Parses a pasted recipe: the title on the first line, followed by the description,
+/// the ingredients and the directions - preferably under their own headings.
+fn parse_plain_text(document: &str) -> Result<TextRecipe, ImportTextError> {
+    let mut lines = document.lines().map(str::trim);
+
+    let name = lines
+        .by_ref()
+        .find(|line| !line.is_empty())
+        .map(|line| {
+            let line = line.trim_start_matches('#').trim();
+            line.strip_prefix("Title:")
+                .unwrap_or(line)
+                .trim()
+                .to_string()
+        })
+        .ok_or(ImportTextError::RecipeNotFound)?;
+
+    let mut recipe = TextRecipe {
+        name,
+        ..Default::default()
+    };
+
+    let mut section = Section::Preamble;
+    let mut preamble: Vec<&str> = vec![];
+    let mut directions: Vec<&str> = vec![];
+
+    for line in lines {
+        if let Some(next) = get_section(line) {
+            section = next;
+            continue;
+        }
+
+        if section != Section::Directions && recipe.parse_metadata(line) {
+            continue;
+        }
+
+        match section {
+            Section::Preamble => preamble.push(line),
+            Section::Ingredients if line.is_empty() || line.ends_with(':') => {}
+            Section::Ingredients => recipe.ingredients.push(strip_list_marker(line)),
+            Section::Directions => directions.push(line),
+        }
+    }
+
+    // Without an ingredients heading, the first paragraph that looks like a list of amounts is the ingredients
+    if recipe.ingredients.is_empty() {
+        let paragraphs: Vec<&[&str]> = preamble
+            .split(|line| line.is_empty())
+            .filter(|paragraph| !paragraph.is_empty())
+            .collect();
+
+        let position = paragraphs
+            .iter()
+            .position(|paragraph| paragraph.iter().all(|line| INGREDIENT_LIKE.is_match(line)));
+
+        if let Some(position) = position {
+            recipe.ingredients = paragraphs[position]
+                .iter()
+                .map(|line| strip_list_marker(line))
+                .collect();
+
+            let rest: Vec<&str> = paragraphs[position + 1..].join(&"");
+            directions.splice(0..0, rest);
+            preamble = paragraphs[..position].join(&"");
+        }
+    }
+
+    recipe.description = preamble
+        .iter()
+        .filter(|line| !line.is_empty())
+        .copied()
+        .collect::<Vec<_>>()
+        .join(" ");
+    recipe.steps = collect_steps(&directions);
+
+    recipe.ensure_complete()
+}
+
+/// Splits a text document into recipes. MealMaster files can contain many recipes, each parsed on its own,
+/// anything else is treated as a single pasted recipe.
+pub fn parse_text(
+    document: &str,
+) -> Result<Vec<Result<TextRecipe, ImportTextError>>, ImportTextError> {
+    if mealmaster::is_mealmaster(document) {
+        mealmaster::parse(document)
+    } else {
+        Ok(vec![Ok(parse_plain_text(document)?)])
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct TextPreview {
+    pub previews: Vec<RecipePreview>,
+    /// Recipes that could not be parsed, by their position in the document, i.e. `recipe 2`
+    pub failed: Vec<(String, ImportTextError)>,
+}
+
+#[tracing::instrument(
+    "[COMMAND] Previewing recipes imported from text",
+    skip(ingredient_repo, document)
+)]
+pub async fn preview_recipes_from_text(
+    ingredient_repo: IngredientRepositoryService,
+    document: &str,
+) -> Result<TextPreview, ImportTextError> {
+    let recipes = parse_text(document)?;
+    let ingredients = ingredient_repo.get_all().await?;
+
+    let mut result = TextPreview::default();
+    for (i, recipe) in recipes.into_iter().enumerate() {
+        match recipe {
+            Ok(recipe) => {
+                let (recipe, unmatched_ingredients) = recipe.into_create_recipe(&ingredients);
+                result.previews.push(RecipePreview {
+                    recipe,
+                    unmatched_ingredients,
+                });
+            }
+            Err(error) => result.failed.push((format!("recipe {}", i + 1), error)),
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,256 @@
+use std::{collections::BTreeMap, sync::Arc, time::Duration};
+
+use pretty_assertions::assert_eq;
+
+use crate::{
+    domain::{
+        commands::recipes::import::text::{preview_recipes_from_text, ImportTextError},
+        entities::recipe::{IngredientUnit, ServingsType},
+        repositories::ingredients::{IngredientRepository, IngredientRepositoryService},
+    },
+    test_utils::{insert_all_ingredients_of_recipe, recipe_fixture},
+};
+
+const PLAIN_TEXT_RECIPE: &str = r#"
+Hoisin Tofu and Broccoli
+
+Tofu with broccoli, in a hoisin sauce.
+
+Serves: 2-3
+Prep time: 15 minutes
+Cook time: 10 min
+
+Ingredients:
+- 400g firm tofu
+- 1 head broccoli
+- 4 cloves garlic, minced
+- 1 tbsp hoisin sauce
+
+Directions:
+1. Cut the tofu into cubes
+   and marinate it.
+2. Steam the broccoli.
+3. Stir fry everything together.
+"#;
+
+const PLAIN_TEXT_WITHOUT_HEADINGS: &str = r#"
+Steamed broccoli
+A side dish.
+
+1 head broccoli (optional)
+2 cloves garlic
+
+Steam the broccoli.
+Serve with the garlic.
+"#;
+
+const MEALMASTER_FILE: &str = r#"
+MMMMM----- Recipe via Meal-Master (tm) v8.05
+
+      Title: Garlic Tofu
+ Categories: Vegan, Tofu
+      Yield: 4 servings
+
+    400 g  Firm tofu                           4    Garlic
+                                                    -minced
+      1 ts Salt
+MMMMM-----------------------SAUCE--------------------------------
+  1 1/2 c  Water
+
+  Fry the tofu until golden, then
+  add the garlic.
+
+  Pour over the water and simmer.
+
+MMMMM
+
+MMMMM----- Recipe via Meal-Master (tm) v8.05
+
+      Title: Broccoli
+      Yield: 2
+
+      1    Broccoli
+
+  Steam it.
+
+MMMMM
+"#;
+
+async fn setup(ingredient_repo: impl IngredientRepository) -> IngredientRepositoryService {
+    let recipe = recipe_fixture();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+
+    Arc::new(Box::new(ingredient_repo))
+}
+
+pub async fn previewing_plain_text_recipe_works(ingredient_repo: impl IngredientRepository) {
+    let ingredient_repo = setup(ingredient_repo).await;
+
+    let mut result = preview_recipes_from_text(ingredient_repo, PLAIN_TEXT_RECIPE)
+        .await
+        .unwrap();
+
+    assert_eq!(result.previews.len(), 1);
+    assert!(result.failed.is_empty());
+    let preview = result.previews.remove(0);
+
+    assert_eq!(preview.unmatched_ingredients, vec!["1 tbsp hoisin sauce"]);
+    assert_eq!(preview.recipe.name, "Hoisin Tofu and Broccoli");
+    assert_eq!(
+        preview.recipe.description,
+        "Tofu with broccoli, in a hoisin sauce."
+    );
+    assert_eq!(preview.recipe.servings, ServingsType::FromTo(2, 3));
+    assert_eq!(
+        preview.recipe.time,
+        BTreeMap::from([
+            ("Cook time".to_string(), Duration::from_secs(10 * 60)),
+            ("Prep time".to_string(), Duration::from_secs(15 * 60)),
+        ])
+    );
+    assert_eq!(
+        preview.recipe.steps,
+        vec![
+            "Cut the tofu into cubes and marinate it.",
+            "Steam the broccoli.",
+            "Stir fry everything together."
+        ]
+    );
+
+    let amounts: Vec<_> = preview
+        .recipe
+        .ingredients
+        .iter()
+        .map(|i| (i.amount.clone(), i.notes.clone()))
+        .collect();
+
+    assert_eq!(
+        amounts,
+        vec![
+            (IngredientUnit::Grams(400.0), None),
+            (
+                IngredientUnit::Other {
+                    amount: 1.0,
+                    unit: "head".to_string()
+                },
+                None
+            ),
+            (
+                IngredientUnit::Other {
+                    amount: 4.0,
+                    unit: "cloves".to_string()
+                },
+                Some("minced".to_string())
+            ),
+        ]
+    );
+}
+
+pub async fn previewing_plain_text_without_headings_works(
+    ingredient_repo: impl IngredientRepository,
+) {
+    let ingredient_repo = setup(ingredient_repo).await;
+
+    let mut result = preview_recipes_from_text(ingredient_repo, PLAIN_TEXT_WITHOUT_HEADINGS)
+        .await
+        .unwrap();
+
+    let preview = result.previews.remove(0);
+
+    assert!(preview.unmatched_ingredients.is_empty());
+    assert_eq!(preview.recipe.name, "Steamed broccoli");
+    assert_eq!(preview.recipe.description, "A side dish.");
+    assert_eq!(preview.recipe.servings, ServingsType::Exact(1));
+    assert_eq!(
+        preview.recipe.steps,
+        vec!["Steam the broccoli.", "Serve with the garlic."]
+    );
+    assert_eq!(preview.recipe.ingredients.len(), 2);
+    assert!(preview.recipe.ingredients[0].optional);
+}
+
+pub async fn previewing_mealmaster_file_works(ingredient_repo: impl IngredientRepository) {
+    let ingredient_repo = setup(ingredient_repo).await;
+
+    let result = preview_recipes_from_text(ingredient_repo, MEALMASTER_FILE)
+        .await
+        .unwrap();
+
+    assert_eq!(result.previews.len(), 2);
+    assert!(result.failed.is_empty());
+
+    let tofu = &result.previews[0];
+    assert_eq!(tofu.recipe.name, "Garlic Tofu");
+    assert_eq!(tofu.recipe.servings, ServingsType::Exact(4));
+    assert_eq!(
+        tofu.recipe.steps,
+        vec![
+            "Fry the tofu until golden, then add the garlic.",
+            "Pour over the water and simmer."
+        ]
+    );
+    assert_eq!(
+        tofu.unmatched_ingredients,
+        vec!["1 tsp Salt", "1 1/2 cup Water"]
+    );
+
+    let amounts: Vec<_> = tofu
+        .recipe
+        .ingredients
+        .iter()
+        .map(|i| (i.amount.clone(), i.notes.clone()))
+        .collect();
+
+    assert_eq!(
+        amounts,
+        vec![
+            (IngredientUnit::Grams(400.0), None),
+            (IngredientUnit::Pieces(4.0), Some("minced".to_string())),
+        ]
+    );
+
+    let broccoli = &result.previews[1];
+    assert_eq!(broccoli.recipe.name, "Broccoli");
+    assert_eq!(broccoli.recipe.servings, ServingsType::Exact(2));
+    assert_eq!(broccoli.recipe.steps, vec!["Steam it."]);
+    assert_eq!(
+        broccoli.recipe.ingredients[0].amount,
+        IngredientUnit::Pieces(1.0)
+    );
+}
+
+pub async fn previewing_mealmaster_file_with_a_broken_recipe_keeps_the_rest(
+    ingredient_repo: impl IngredientRepository,
+) {
+    let ingredient_repo = setup(ingredient_repo).await;
+
+    let document = MEALMASTER_FILE.replacen("  Steam it.\n", "", 1);
+    let result = preview_recipes_from_text(ingredient_repo, &document)
+        .await
+        .unwrap();
+
+    assert_eq!(result.previews.len(), 1);
+    assert_eq!(result.previews[0].recipe.name, "Garlic Tofu");
+
+    assert_eq!(result.failed.len(), 1);
+    let (recipe, error) = &result.failed[0];
+    assert_eq!(recipe, "recipe 2");
+    assert!(
+        matches!(error, ImportTextError::MissingSection(name, "directions") if name == "Broccoli")
+    );
+}
+
+pub async fn previewing_text_without_directions_errors(ingredient_repo: impl IngredientRepository) {
+    let ingredient_repo = setup(ingredient_repo).await;
+
+    let error = preview_recipes_from_text(
+        ingredient_repo,
+        "Broccoli\n\nIngredients:\n1 head broccoli\n",
+    )
+    .await
+    .unwrap_err();
+
+    assert!(
+        matches!(error, ImportTextError::MissingSection(name, "directions") if name == "Broccoli")
+    );
+}
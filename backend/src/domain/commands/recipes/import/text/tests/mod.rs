@@ -0,0 +1,85 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::ingredients::in_memory::InMemoryIngredientRepository;
+
+    #[tokio::test]
+    async fn previewing_plain_text_recipe_works() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+
+        __tests__::previewing_plain_text_recipe_works(ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn previewing_plain_text_without_headings_works() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+
+        __tests__::previewing_plain_text_without_headings_works(ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn previewing_mealmaster_file_works() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+
+        __tests__::previewing_mealmaster_file_works(ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn previewing_mealmaster_file_with_a_broken_recipe_keeps_the_rest() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+
+        __tests__::previewing_mealmaster_file_with_a_broken_recipe_keeps_the_rest(ingredient_repo)
+            .await
+    }
+
+    #[tokio::test]
+    async fn previewing_text_without_directions_errors() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+
+        __tests__::previewing_text_without_directions_errors(ingredient_repo).await
+    }
+}
+
+mod sql {
+    use sqlx::PgPool;
+
+    use super::__tests__;
+    use crate::domain::repositories::ingredients::postgres::PostgresIngredientRepository;
+
+    #[sqlx::test]
+    async fn previewing_plain_text_recipe_works(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool);
+
+        __tests__::previewing_plain_text_recipe_works(ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn previewing_plain_text_without_headings_works(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool);
+
+        __tests__::previewing_plain_text_without_headings_works(ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn previewing_mealmaster_file_works(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool);
+
+        __tests__::previewing_mealmaster_file_works(ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn previewing_mealmaster_file_with_a_broken_recipe_keeps_the_rest(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool);
+
+        __tests__::previewing_mealmaster_file_with_a_broken_recipe_keeps_the_rest(ingredient_repo)
+            .await
+    }
+
+    #[sqlx::test]
+    async fn previewing_text_without_directions_errors(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool);
+
+        __tests__::previewing_text_without_directions_errors(ingredient_repo).await
+    }
+}
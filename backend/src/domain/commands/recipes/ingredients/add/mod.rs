@@ -1,15 +1,24 @@
 use uuid::Uuid;
 
 use crate::domain::{
-    entities::recipe::{IngredientAmountData, IngredientWithAmount, Recipe},
+    commands::recipes::import::IngredientMatcher,
+    entities::precondition::Precondition,
+    entities::recipe::{
+        errors::ParseIngredientLineError, ingredient_line::ParsedIngredientLine,
+        IngredientAmountData, Recipe,
+    },
     repositories::{
-        ingredients::{errors::GetIngredientByIdError, IngredientRepositoryService},
-        recipe::{
-            errors::{
-                AddIngredientIntoRecipeError as AddIngredientIntoRecipeErrorInternal,
-                GetRecipeByIdError,
-            },
-            RecipeRepositoryService,
+        ingredients::{
+            errors::{GetAllIngredientsError, GetIngredientByIdError},
+            IngredientRepositoryService,
+        },
+        recipe::errors::{
+            AddIngredientIntoRecipeError as AddIngredientIntoRecipeErrorInternal,
+            GetRecipeByIdError,
+        },
+        unit_of_work::{
+            errors::{BeginTransactionError, CommitTransactionError},
+            UnitOfWorkService,
         },
     },
 };
@@ -22,6 +31,18 @@ pub enum AddIngredientToRecipeError {
     #[error(transparent)]
     GetRecipe(#[from] GetRecipeByIdError),
 
+    #[error(transparent)]
+    InvalidLine(#[from] ParseIngredientLineError),
+
+    #[error("Could not find an ingredient matching {0:?}")]
+    IngredientNotMatched(String),
+
+    #[error("The recipe with ID {0} has changed since you last fetched it")]
+    PreconditionFailed(Uuid),
+
+    #[error("Only the owner can make changes to the recipe with ID {0}")]
+    NotOwner(Uuid),
+
     #[error(transparent)]
     Unknown(#[from] eyre::Report),
 }
@@ -32,39 +53,97 @@ impl From<AddIngredientIntoRecipeErrorInternal> for AddIngredientToRecipeError {
     }
 }
 
+impl From<BeginTransactionError> for AddIngredientToRecipeError {
+    fn from(value: BeginTransactionError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+impl From<CommitTransactionError> for AddIngredientToRecipeError {
+    fn from(value: CommitTransactionError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+impl From<GetAllIngredientsError> for AddIngredientToRecipeError {
+    fn from(value: GetAllIngredientsError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+/// Only the owner can add ingredients to a recipe that has one.
+/// The recipe stays locked from checking the precondition until the ingredient is in
 pub async fn add_ingredient_to_recipe(
-    recipe_repo: RecipeRepositoryService,
-    ingredient_repo: IngredientRepositoryService,
+    unit_of_work: UnitOfWorkService,
+    user_id: Option<&Uuid>,
     recipe_id: &Uuid,
     ingredient_amount: IngredientAmountData,
+    precondition: &Precondition,
 ) -> Result<Recipe, AddIngredientToRecipeError> {
-    let recipe = recipe_repo.get_by_id(recipe_id).await?;
+    let tx = unit_of_work.begin().await?;
+    let recipe_repo = tx.recipes();
+    let ingredient_repo = tx.ingredients();
+
+    let recipe = tx.lock_recipe(recipe_id).await?;
+    if !recipe.is_visible_to(user_id, None) {
+        return Err(GetRecipeByIdError::NotFound(*recipe_id).into());
+    }
+    if !recipe.is_editable_by(user_id) {
+        return Err(AddIngredientToRecipeError::NotOwner(*recipe_id));
+    }
+    if !precondition.holds(&recipe.entity_tag()) {
+        return Err(AddIngredientToRecipeError::PreconditionFailed(*recipe_id));
+    }
     let ingredient = ingredient_repo
         .get_by_id(&ingredient_amount.ingredient_id)
         .await?;
-    let IngredientAmountData {
-        amount,
-        notes,
-        optional,
-        ..
-    } = ingredient_amount;
 
     recipe_repo
-        .add_ingredient(
-            &recipe,
-            IngredientWithAmount {
-                ingredient,
-                amount,
-                optional,
-                notes,
-            },
-        )
+        .add_ingredient(&recipe, ingredient_amount.into_entry(ingredient))
         .await?;
 
     let recipe = recipe_repo.get_by_id(recipe_id).await?;
 
+    tx.commit().await?;
+
     Ok(recipe)
 }
 
+/// Adds an ingredient written as a single line, i.e. `2 cloves garlic, minced`,
+/// to the recipe. The ingredient has to exist already.
+pub async fn add_ingredient_line_to_recipe(
+    unit_of_work: UnitOfWorkService,
+    ingredient_repo: IngredientRepositoryService,
+    user_id: Option<&Uuid>,
+    recipe_id: &Uuid,
+    line: &str,
+    precondition: &Precondition,
+) -> Result<Recipe, AddIngredientToRecipeError> {
+    let parsed: ParsedIngredientLine = line.parse()?;
+
+    let ingredients = ingredient_repo.get_all().await?;
+    let ingredient_id = IngredientMatcher::new(&ingredients)
+        .find(&parsed.name)
+        .map(|ingredient| ingredient.id)
+        .ok_or_else(|| AddIngredientToRecipeError::IngredientNotMatched(parsed.name.clone()))?;
+
+    let ingredient_amount = IngredientAmountData {
+        ingredient_id,
+        amount: parsed.amount(),
+        notes: parsed.notes,
+        optional: parsed.optional,
+        group: None,
+    };
+
+    add_ingredient_to_recipe(
+        unit_of_work,
+        user_id,
+        recipe_id,
+        ingredient_amount,
+        precondition,
+    )
+    .await
+}
+
 #[cfg(test)]
 mod tests;
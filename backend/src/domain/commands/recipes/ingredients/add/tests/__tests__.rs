@@ -1,34 +1,49 @@
 use std::{collections::HashSet, sync::Arc};
 
+use uuid::Uuid;
+
 use crate::{
     domain::{
         commands::recipes::ingredients::add::{
-            add_ingredient_to_recipe, AddIngredientToRecipeError,
+            add_ingredient_line_to_recipe, add_ingredient_to_recipe, AddIngredientToRecipeError,
+        },
+        entities::{
+            event::DomainEvent,
+            precondition::Precondition,
+            recipe::{IngredientAmountData, IngredientUnit, IngredientWithAmount, Recipe},
+            visibility::Visibility,
         },
-        entities::recipe::{IngredientAmountData, IngredientUnit, IngredientWithAmount},
         repositories::{
             ingredients::{
                 errors::GetIngredientByIdError, IngredientRepository, IngredientRepositoryService,
             },
-            recipe::{RecipeRepository, RecipeRepositoryService},
+            outbox::OutboxRepository,
+            recipe::{errors::GetRecipeByIdError, RecipeRepository, RecipeRepositoryService},
+            unit_of_work::{UnitOfWork, UnitOfWorkService},
+            user::UserRepository,
         },
     },
     test_utils::{
-        ingredient_fixture, insert_all_ingredients, insert_all_ingredients_of_recipe,
-        recipe_fixture,
+        another_recipe_fixture, another_user_fixture, ingredient_fixture, insert_all_ingredients,
+        insert_all_ingredients_of_recipe, insert_users, recipe_fixture, unpublished_events,
+        user_fixture,
     },
 };
 
 pub async fn adding_an_ingredient_to_a_recipe_works(
     repo: impl RecipeRepository,
     ing_repo: impl IngredientRepository,
+    unit_of_work: impl UnitOfWork,
 ) {
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
     let recipe = recipe_fixture();
     let ingredient = IngredientWithAmount {
+        id: Uuid::now_v7(),
         ingredient: ingredient_fixture(),
         amount: IngredientUnit::Grams(666.0),
         notes: None,
         optional: true,
+        group: None,
     };
     let mut all_ingredients = recipe.ingredients.to_vec().clone();
     all_ingredients.push(ingredient.clone());
@@ -36,16 +51,20 @@ pub async fn adding_an_ingredient_to_a_recipe_works(
     insert_all_ingredients(&ing_repo, &all_ingredients).await;
 
     let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
-    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ing_repo));
 
     let ingredient_payload = IngredientAmountData::from(ingredient.clone());
 
     recipe_repo.insert(recipe.clone()).await.unwrap();
 
-    let updated_recipe =
-        add_ingredient_to_recipe(recipe_repo, ingredient_repo, &recipe.id, ingredient_payload)
-            .await
-            .unwrap();
+    let updated_recipe = add_ingredient_to_recipe(
+        unit_of_work.clone(),
+        None,
+        &recipe.id,
+        ingredient_payload,
+        &Precondition::Any,
+    )
+    .await
+    .unwrap();
 
     let expected: HashSet<_> = all_ingredients
         .iter()
@@ -58,33 +77,264 @@ pub async fn adding_an_ingredient_to_a_recipe_works(
         .all(|item| expected.contains(&item.ingredient.id)));
 }
 
+pub async fn adding_an_ingredient_twice_keeps_both_entries(
+    repo: impl RecipeRepository,
+    ing_repo: impl IngredientRepository,
+    unit_of_work: impl UnitOfWork,
+) {
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
+    let recipe = recipe_fixture();
+    let garlic = recipe.ingredients.last().unwrap().clone();
+
+    insert_all_ingredients_of_recipe(&ing_repo, &recipe).await;
+
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+
+    let updated_recipe = add_ingredient_to_recipe(
+        unit_of_work.clone(),
+        None,
+        &recipe.id,
+        IngredientAmountData {
+            ingredient_id: garlic.ingredient.id,
+            amount: IngredientUnit::Other {
+                amount: 2.0,
+                unit: "clove".to_string(),
+            },
+            optional: false,
+            notes: None,
+            group: Some("For the sauce".to_string()),
+        },
+        &Precondition::Any,
+    )
+    .await
+    .unwrap();
+
+    let entries: Vec<_> = updated_recipe
+        .ingredients
+        .iter()
+        .filter(|item| item.ingredient.id == garlic.ingredient.id)
+        .collect();
+
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0], &garlic);
+    assert_ne!(entries[1].id, garlic.id);
+    assert_eq!(entries[1].group.as_deref(), Some("For the sauce"));
+    assert_eq!(updated_recipe.ingredients.last(), Some(entries[1]));
+}
+
 pub async fn adding_a_nonexistent_ingredient_to_a_recipe_errors(
     recipe_repo: impl RecipeRepository,
     ing_repo: impl IngredientRepository,
+    unit_of_work: impl UnitOfWork,
 ) {
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
     let recipe = recipe_fixture();
     let ingredient = IngredientWithAmount {
+        id: Uuid::now_v7(),
         ingredient: ingredient_fixture(),
         amount: IngredientUnit::Grams(666.0),
         notes: None,
         optional: true,
+        group: None,
     };
 
     insert_all_ingredients_of_recipe(&ing_repo, &recipe).await;
     recipe_repo.insert(recipe.clone()).await.unwrap();
 
-    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
-    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ing_repo));
-
     let ingredient_payload = IngredientAmountData::from(ingredient.clone());
 
-    let error =
-        add_ingredient_to_recipe(recipe_repo, ingredient_repo, &recipe.id, ingredient_payload)
-            .await
-            .unwrap_err();
+    let error = add_ingredient_to_recipe(
+        unit_of_work.clone(),
+        None,
+        &recipe.id,
+        ingredient_payload,
+        &Precondition::Any,
+    )
+    .await
+    .unwrap_err();
 
     assert!(matches!(
         error,
         AddIngredientToRecipeError::GetIngredient(GetIngredientByIdError::NotFound(_))
     ))
 }
+
+pub async fn adding_an_ingredient_line_to_a_recipe_works(
+    repo: impl RecipeRepository,
+    ing_repo: impl IngredientRepository,
+    unit_of_work: impl UnitOfWork,
+) {
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
+    let recipe = recipe_fixture();
+    let ingredient = ingredient_fixture();
+
+    insert_all_ingredients_of_recipe(&ing_repo, &recipe).await;
+    ing_repo.insert(ingredient.clone()).await.unwrap();
+
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ing_repo));
+
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+
+    let updated_recipe = add_ingredient_line_to_recipe(
+        unit_of_work.clone(),
+        ingredient_repo.clone(),
+        None,
+        &recipe.id,
+        "1 1/2 lb ground beef (optional), browned",
+        &Precondition::Any,
+    )
+    .await
+    .unwrap();
+
+    let added = updated_recipe
+        .ingredients
+        .iter()
+        .find(|item| item.ingredient.id == ingredient.id)
+        .unwrap();
+
+    assert_eq!(
+        added.amount,
+        IngredientUnit::Other {
+            amount: 1.5,
+            unit: "lb".to_string()
+        }
+    );
+    assert_eq!(added.notes.as_deref(), Some("browned"));
+    assert!(added.optional);
+}
+
+pub async fn adding_an_unmatched_ingredient_line_errors(
+    repo: impl RecipeRepository,
+    ing_repo: impl IngredientRepository,
+    unit_of_work: impl UnitOfWork,
+) {
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
+    let recipe = recipe_fixture();
+
+    insert_all_ingredients_of_recipe(&ing_repo, &recipe).await;
+
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ing_repo));
+
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+
+    let error = add_ingredient_line_to_recipe(
+        unit_of_work.clone(),
+        ingredient_repo.clone(),
+        None,
+        &recipe.id,
+        "2 tbsp hoisin sauce",
+        &Precondition::Any,
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(
+        error,
+        AddIngredientToRecipeError::IngredientNotMatched(name) if name == "hoisin sauce"
+    ))
+}
+
+pub async fn adding_an_ingredient_to_a_recipe_records_an_event(
+    repo: impl RecipeRepository,
+    ing_repo: impl IngredientRepository,
+    outbox_repo: impl OutboxRepository,
+    unit_of_work: impl UnitOfWork,
+) {
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
+    let recipe = recipe_fixture();
+    let ingredient = IngredientWithAmount {
+        id: Uuid::now_v7(),
+        ingredient: ingredient_fixture(),
+        amount: IngredientUnit::Grams(666.0),
+        notes: None,
+        optional: false,
+        group: None,
+    };
+
+    insert_all_ingredients_of_recipe(&ing_repo, &recipe).await;
+    insert_all_ingredients(&ing_repo, std::slice::from_ref(&ingredient)).await;
+
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+
+    add_ingredient_to_recipe(
+        unit_of_work.clone(),
+        None,
+        &recipe.id,
+        IngredientAmountData::from(ingredient.clone()),
+        &Precondition::Any,
+    )
+    .await
+    .unwrap();
+
+    let events = unpublished_events(&outbox_repo).await;
+
+    assert_eq!(
+        events.last(),
+        Some(&DomainEvent::IngredientAddedToRecipe {
+            recipe_id: recipe.id,
+            ingredient_id: ingredient.ingredient.id,
+        })
+    );
+}
+
+pub async fn adding_an_ingredient_to_a_recipe_of_someone_else_errors(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+    unit_of_work: impl UnitOfWork,
+) {
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
+    let recipe_repo = repo;
+    let public = Recipe {
+        owner_id: Some(user_fixture().id),
+        ..recipe_fixture()
+    };
+    let private = Recipe {
+        visibility: Visibility::Private,
+        owner_id: Some(user_fixture().id),
+        ..another_recipe_fixture(1)
+    };
+    insert_users(&user_repo).await;
+    insert_all_ingredients_of_recipe(&ingredient_repo, &public).await;
+    recipe_repo.insert(public.clone()).await.unwrap();
+    recipe_repo.insert(private.clone()).await.unwrap();
+    let payload = || IngredientAmountData::from(public.ingredients[0].clone());
+
+    let error = add_ingredient_to_recipe(
+        unit_of_work.clone(),
+        Some(&another_user_fixture().id),
+        &private.id,
+        payload(),
+        &Precondition::Any,
+    )
+    .await
+    .unwrap_err();
+    assert!(matches!(
+        error,
+        AddIngredientToRecipeError::GetRecipe(GetRecipeByIdError::NotFound(id)) if id == private.id
+    ));
+
+    for user_id in [None, Some(&another_user_fixture().id)] {
+        let error = add_ingredient_to_recipe(
+            unit_of_work.clone(),
+            user_id,
+            &public.id,
+            payload(),
+            &Precondition::Any,
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(error, AddIngredientToRecipeError::NotOwner(id) if id == public.id));
+    }
+
+    assert_eq!(
+        recipe_repo.get_by_id(&public.id).await.unwrap().ingredients,
+        public.ingredients
+    );
+}
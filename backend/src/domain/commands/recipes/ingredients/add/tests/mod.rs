@@ -4,21 +4,90 @@ mod in_memory {
     use super::__tests__;
     use crate::domain::repositories::{
         ingredients::in_memory::InMemoryIngredientRepository,
-        recipe::in_memory::InMemoryRecipeRepository,
+        outbox::in_memory::InMemoryOutboxRepository, recipe::in_memory::InMemoryRecipeRepository,
+        unit_of_work::in_memory::InMemoryUnitOfWork, user::in_memory::InMemoryUserRepository,
     };
 
     #[tokio::test]
     async fn adding_an_ingredient_to_a_recipe_works() {
         let ingredient_repo = InMemoryIngredientRepository::new();
         let repo = InMemoryRecipeRepository::new();
-        __tests__::adding_an_ingredient_to_a_recipe_works(repo, ingredient_repo).await
+        let unit_of_work = InMemoryUnitOfWork::new(ingredient_repo.clone(), repo.clone());
+        __tests__::adding_an_ingredient_to_a_recipe_works(repo, ingredient_repo, unit_of_work).await
+    }
+
+    #[tokio::test]
+    async fn adding_an_ingredient_twice_keeps_both_entries() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        let unit_of_work = InMemoryUnitOfWork::new(ingredient_repo.clone(), repo.clone());
+        __tests__::adding_an_ingredient_twice_keeps_both_entries(
+            repo,
+            ingredient_repo,
+            unit_of_work,
+        )
+        .await
     }
 
     #[tokio::test]
     async fn adding_a_nonexistent_ingredient_to_a_recipe_errors() {
         let ingredient_repo = InMemoryIngredientRepository::new();
         let repo = InMemoryRecipeRepository::new();
-        __tests__::adding_a_nonexistent_ingredient_to_a_recipe_errors(repo, ingredient_repo).await
+        let unit_of_work = InMemoryUnitOfWork::new(ingredient_repo.clone(), repo.clone());
+        __tests__::adding_a_nonexistent_ingredient_to_a_recipe_errors(
+            repo,
+            ingredient_repo,
+            unit_of_work,
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn adding_an_ingredient_line_to_a_recipe_works() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        let unit_of_work = InMemoryUnitOfWork::new(ingredient_repo.clone(), repo.clone());
+        __tests__::adding_an_ingredient_line_to_a_recipe_works(repo, ingredient_repo, unit_of_work)
+            .await
+    }
+
+    #[tokio::test]
+    async fn adding_an_unmatched_ingredient_line_errors() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        let unit_of_work = InMemoryUnitOfWork::new(ingredient_repo.clone(), repo.clone());
+        __tests__::adding_an_unmatched_ingredient_line_errors(repo, ingredient_repo, unit_of_work)
+            .await
+    }
+
+    #[tokio::test]
+    async fn adding_an_ingredient_to_a_recipe_records_an_event() {
+        let outbox = InMemoryOutboxRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new().with_outbox(outbox.clone());
+        let repo = InMemoryRecipeRepository::new().with_outbox(outbox.clone());
+        let unit_of_work = InMemoryUnitOfWork::new(ingredient_repo.clone(), repo.clone());
+        __tests__::adding_an_ingredient_to_a_recipe_records_an_event(
+            repo,
+            ingredient_repo,
+            outbox,
+            unit_of_work,
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn adding_an_ingredient_to_a_recipe_of_someone_else_errors() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        let unit_of_work = InMemoryUnitOfWork::new(ingredient_repo.clone(), repo.clone());
+        __tests__::adding_an_ingredient_to_a_recipe_of_someone_else_errors(
+            repo,
+            ingredient_repo,
+            user_repo,
+            unit_of_work,
+        )
+        .await
     }
 }
 
@@ -28,20 +97,92 @@ mod sql {
 
     use crate::domain::repositories::{
         ingredients::postgres::PostgresIngredientRepository,
-        recipe::postgres::PostgresRecipeRepository,
+        outbox::postgres::PostgresOutboxRepository, recipe::postgres::PostgresRecipeRepository,
+        unit_of_work::postgres::PostgresUnitOfWork, user::postgres::PostgresUserRepository,
     };
 
     #[sqlx::test]
     async fn adding_an_ingredient_to_a_recipe_works(pool: PgPool) {
         let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
-        let repo = PostgresRecipeRepository::new(pool);
-        __tests__::adding_an_ingredient_to_a_recipe_works(repo, ingredient_repo).await
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        __tests__::adding_an_ingredient_to_a_recipe_works(
+            repo,
+            ingredient_repo,
+            PostgresUnitOfWork::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn adding_an_ingredient_twice_keeps_both_entries(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        __tests__::adding_an_ingredient_twice_keeps_both_entries(
+            repo,
+            ingredient_repo,
+            PostgresUnitOfWork::new(pool),
+        )
+        .await
     }
 
     #[sqlx::test]
     async fn adding_a_nonexistent_ingredient_to_a_recipe_errors(pool: PgPool) {
         let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
-        let repo = PostgresRecipeRepository::new(pool);
-        __tests__::adding_a_nonexistent_ingredient_to_a_recipe_errors(repo, ingredient_repo).await
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        __tests__::adding_a_nonexistent_ingredient_to_a_recipe_errors(
+            repo,
+            ingredient_repo,
+            PostgresUnitOfWork::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn adding_an_ingredient_line_to_a_recipe_works(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        __tests__::adding_an_ingredient_line_to_a_recipe_works(
+            repo,
+            ingredient_repo,
+            PostgresUnitOfWork::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn adding_an_unmatched_ingredient_line_errors(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        __tests__::adding_an_unmatched_ingredient_line_errors(
+            repo,
+            ingredient_repo,
+            PostgresUnitOfWork::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn adding_an_ingredient_to_a_recipe_records_an_event(pool: PgPool) {
+        __tests__::adding_an_ingredient_to_a_recipe_records_an_event(
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresOutboxRepository::new(pool.clone()),
+            PostgresUnitOfWork::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn adding_an_ingredient_to_a_recipe_of_someone_else_errors(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        __tests__::adding_an_ingredient_to_a_recipe_of_someone_else_errors(
+            repo,
+            ingredient_repo,
+            user_repo,
+            PostgresUnitOfWork::new(pool),
+        )
+        .await
     }
 }
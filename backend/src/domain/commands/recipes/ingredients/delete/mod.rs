@@ -2,23 +2,33 @@ use strum::AsRefStr;
 use thiserror::Error;
 use uuid::Uuid;
 
+use crate::domain::entities::precondition::Precondition;
 use crate::domain::entities::recipe::errors::ValidationError;
 use crate::domain::repositories::recipe::errors::{
     DeleteIngredientFromRecipeError as DeleteIngredientFromRecipeErrorInternal, GetRecipeByIdError,
 };
-use crate::domain::repositories::recipe::RecipeRepositoryService;
+use crate::domain::repositories::unit_of_work::errors::{
+    BeginTransactionError, CommitTransactionError,
+};
+use crate::domain::repositories::unit_of_work::UnitOfWorkService;
 
 #[derive(Error, Debug, AsRefStr)]
 pub enum DeleteIngredientFromRecipeError {
     #[error("Could not found recipe with ID {0}")]
     RecipeNotFoundError(Uuid),
 
-    #[error("The recipe has no ingredient with ID of {0}")]
+    #[error("The recipe has no ingredient entry with ID of {0}")]
     RecipeHasNoIngredientError(Uuid),
 
     #[error("There is only one ingredient in the recipe. A recipe should have one ingredient at minimum.")]
     LastIngredientError,
 
+    #[error("The recipe with ID {0} has changed since you last fetched it")]
+    PreconditionFailedError(Uuid),
+
+    #[error("Only the owner can make changes to the recipe with ID {0}")]
+    NotOwnerError(Uuid),
+
     #[error(transparent)]
     UnknownError(#[from] eyre::Error),
 }
@@ -34,38 +44,69 @@ impl From<DeleteIngredientFromRecipeErrorInternal> for DeleteIngredientFromRecip
     }
 }
 
+impl From<BeginTransactionError> for DeleteIngredientFromRecipeError {
+    fn from(value: BeginTransactionError) -> Self {
+        Self::UnknownError(value.into())
+    }
+}
+
+impl From<CommitTransactionError> for DeleteIngredientFromRecipeError {
+    fn from(value: CommitTransactionError) -> Self {
+        Self::UnknownError(value.into())
+    }
+}
+
 impl From<GetRecipeByIdError> for DeleteIngredientFromRecipeError {
     fn from(value: GetRecipeByIdError) -> Self {
         match value {
             GetRecipeByIdError::NotFound(id) => Self::RecipeNotFoundError(id),
-            e => e.into(),
+            e => Self::UnknownError(e.into()),
         }
     }
 }
 
+/// Only the owner can remove ingredients from a recipe that has one.
+/// The recipe stays locked from checking the precondition until the ingredient is gone
 pub async fn delete_ingredient_from_recipe(
-    recipe_repo: RecipeRepositoryService,
+    unit_of_work: UnitOfWorkService,
+    user_id: Option<&Uuid>,
     recipe_id: &Uuid,
-    ingredient_id: &Uuid,
+    entry_id: &Uuid,
+    precondition: &Precondition,
 ) -> Result<(), DeleteIngredientFromRecipeError> {
-    let recipe = recipe_repo.get_by_id(recipe_id).await?;
+    let tx = unit_of_work.begin().await?;
+    let recipe_repo = tx.recipes();
+
+    let recipe = tx.lock_recipe(recipe_id).await?;
+    if !recipe.is_visible_to(user_id, None) {
+        return Err(DeleteIngredientFromRecipeError::RecipeNotFoundError(
+            *recipe_id,
+        ));
+    }
+    if !recipe.is_editable_by(user_id) {
+        return Err(DeleteIngredientFromRecipeError::NotOwnerError(*recipe_id));
+    }
+    if !precondition.holds(&recipe.entity_tag()) {
+        return Err(DeleteIngredientFromRecipeError::PreconditionFailedError(
+            *recipe_id,
+        ));
+    }
 
     if recipe.ingredients.len() == 1 {
         return Err(DeleteIngredientFromRecipeError::LastIngredientError);
     };
 
-    let ingredient_in_recipe = &recipe
+    let ingredient_in_recipe = recipe
         .ingredients
-        .iter()
-        .find(|x| x.ingredient.id == *ingredient_id)
-        .ok_or_else(|| {
-            DeleteIngredientFromRecipeError::RecipeHasNoIngredientError(*ingredient_id)
-        })?;
+        .get_entry(entry_id)
+        .ok_or_else(|| DeleteIngredientFromRecipeError::RecipeHasNoIngredientError(*entry_id))?;
 
     recipe_repo
         .delete_ingredient(&recipe, ingredient_in_recipe)
         .await?;
 
+    tx.commit().await?;
+
     Ok(())
 }
 
@@ -9,16 +9,30 @@ use uuid::Uuid;
 
 use crate::{
     domain::{
-        entities::recipe::{IngredientWithAmount, Recipe},
-        repositories::{ingredients::IngredientRepository, recipe::RecipeRepository},
+        entities::{
+            precondition::Precondition,
+            recipe::{IngredientWithAmount, Recipe},
+            visibility::Visibility,
+        },
+        repositories::{
+            ingredients::IngredientRepository,
+            recipe::RecipeRepository,
+            unit_of_work::{UnitOfWork, UnitOfWorkService},
+            user::UserRepository,
+        },
+    },
+    test_utils::{
+        another_recipe_fixture, another_user_fixture, ingredient_fixture,
+        insert_all_ingredients_of_recipe, insert_users, recipe_fixture, user_fixture,
     },
-    test_utils::{ingredient_fixture, insert_all_ingredients_of_recipe, recipe_fixture},
 };
 
 pub async fn deleting_an_existing_ingredient_works(
     repo: impl RecipeRepository,
     ingredient_repo: impl IngredientRepository,
+    unit_of_work: impl UnitOfWork,
 ) {
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
     let initial_recipe = recipe_fixture();
     insert_all_ingredients_of_recipe(&ingredient_repo, &initial_recipe).await;
     repo.insert(initial_recipe.clone()).await.unwrap();
@@ -27,9 +41,11 @@ pub async fn deleting_an_existing_ingredient_works(
 
     let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
     delete_ingredient_from_recipe(
-        repo.clone(),
+        unit_of_work.clone(),
+        None,
         &initial_recipe.id,
-        &ingredient_to_delete.ingredient.id,
+        &ingredient_to_delete.id,
+        &Precondition::Any,
     )
     .await
     .unwrap();
@@ -42,17 +58,22 @@ pub async fn deleting_an_existing_ingredient_works(
 pub async fn deleting_an_ingredient_that_doesnt_appear_in_recipe_errors(
     repo: impl RecipeRepository,
     ingredient_repo: impl IngredientRepository,
+    unit_of_work: impl UnitOfWork,
 ) {
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
     let initial_recipe = recipe_fixture();
     insert_all_ingredients_of_recipe(&ingredient_repo, &initial_recipe).await;
     repo.insert(initial_recipe.clone()).await.unwrap();
 
-    let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
-
-    let error =
-        delete_ingredient_from_recipe(repo.clone(), &initial_recipe.id, &Uuid::from_u128(999))
-            .await
-            .unwrap_err();
+    let error = delete_ingredient_from_recipe(
+        unit_of_work.clone(),
+        None,
+        &initial_recipe.id,
+        &Uuid::from_u128(999),
+        &Precondition::Any,
+    )
+    .await
+    .unwrap_err();
 
     assert!(
         matches!(error, DeleteIngredientFromRecipeError::RecipeHasNoIngredientError(id) if id == Uuid::from_u128(999))
@@ -60,16 +81,22 @@ pub async fn deleting_an_ingredient_that_doesnt_appear_in_recipe_errors(
 }
 
 pub async fn deleting_an_ingredient_in_recipe_that_doesnt_exist_errors(
-    repo: impl RecipeRepository,
     ingredient_repo: impl IngredientRepository,
+    unit_of_work: impl UnitOfWork,
 ) {
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
     let initial_recipe = recipe_fixture();
     insert_all_ingredients_of_recipe(&ingredient_repo, &initial_recipe).await;
 
-    let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
-    let error = delete_ingredient_from_recipe(repo.clone(), &initial_recipe.id, &Uuid::nil())
-        .await
-        .unwrap_err();
+    let error = delete_ingredient_from_recipe(
+        unit_of_work.clone(),
+        None,
+        &initial_recipe.id,
+        &Uuid::nil(),
+        &Precondition::Any,
+    )
+    .await
+    .unwrap_err();
 
     assert!(
         matches!(error, DeleteIngredientFromRecipeError::RecipeNotFoundError(id) if id == initial_recipe.id)
@@ -79,12 +106,16 @@ pub async fn deleting_an_ingredient_in_recipe_that_doesnt_exist_errors(
 pub async fn deleting_the_last_ingredient_in_recipe_errors(
     repo: impl RecipeRepository,
     ingredient_repo: impl IngredientRepository,
+    unit_of_work: impl UnitOfWork,
 ) {
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
     let ingredient = IngredientWithAmount {
+        id: Uuid::now_v7(),
         ingredient: ingredient_fixture(),
         amount: crate::domain::entities::recipe::IngredientUnit::Grams(10.0),
         notes: None,
         optional: false,
+        group: None,
     };
 
     let initial_recipe = Recipe {
@@ -98,11 +129,12 @@ pub async fn deleting_the_last_ingredient_in_recipe_errors(
 
     let ingredient_to_delete = initial_recipe.ingredients.first().unwrap();
 
-    let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
     let error = delete_ingredient_from_recipe(
-        repo.clone(),
+        unit_of_work.clone(),
+        None,
         &initial_recipe.id,
-        &ingredient_to_delete.ingredient.id,
+        &ingredient_to_delete.id,
+        &Precondition::Any,
     )
     .await
     .unwrap_err();
@@ -112,3 +144,59 @@ pub async fn deleting_the_last_ingredient_in_recipe_errors(
         DeleteIngredientFromRecipeError::LastIngredientError
     ))
 }
+
+pub async fn deleting_an_ingredient_from_a_recipe_of_someone_else_errors(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+    unit_of_work: impl UnitOfWork,
+) {
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
+    let recipe_repo = repo;
+    let public = Recipe {
+        owner_id: Some(user_fixture().id),
+        ..recipe_fixture()
+    };
+    let private = Recipe {
+        visibility: Visibility::Private,
+        owner_id: Some(user_fixture().id),
+        ..another_recipe_fixture(1)
+    };
+    insert_users(&user_repo).await;
+    insert_all_ingredients_of_recipe(&ingredient_repo, &public).await;
+    recipe_repo.insert(public.clone()).await.unwrap();
+    recipe_repo.insert(private.clone()).await.unwrap();
+
+    let error = delete_ingredient_from_recipe(
+        unit_of_work.clone(),
+        Some(&another_user_fixture().id),
+        &private.id,
+        &private.ingredients[0].id,
+        &Precondition::Any,
+    )
+    .await
+    .unwrap_err();
+    assert!(
+        matches!(error, DeleteIngredientFromRecipeError::RecipeNotFoundError(id) if id == private.id)
+    );
+
+    for user_id in [None, Some(&another_user_fixture().id)] {
+        let error = delete_ingredient_from_recipe(
+            unit_of_work.clone(),
+            user_id,
+            &public.id,
+            &public.ingredients[0].id,
+            &Precondition::Any,
+        )
+        .await
+        .unwrap_err();
+        assert!(
+            matches!(error, DeleteIngredientFromRecipeError::NotOwnerError(id) if id == public.id)
+        );
+    }
+
+    assert_eq!(
+        recipe_repo.get_by_id(&public.id).await.unwrap().ingredients,
+        public.ingredients
+    );
+}
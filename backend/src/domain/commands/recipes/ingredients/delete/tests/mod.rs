@@ -3,34 +3,44 @@ mod __tests__;
 mod in_memory {
     use crate::domain::repositories::{
         ingredients::in_memory::InMemoryIngredientRepository,
-        recipe::in_memory::InMemoryRecipeRepository,
+        recipe::in_memory::InMemoryRecipeRepository, unit_of_work::in_memory::InMemoryUnitOfWork,
+        user::in_memory::InMemoryUserRepository,
     };
 
     #[tokio::test]
     async fn deleting_an_existing_ingredient_works() {
         let repo = InMemoryRecipeRepository::new();
         let ingredient_repo = InMemoryIngredientRepository::new();
-        super::__tests__::deleting_an_existing_ingredient_works(repo, ingredient_repo).await;
+        let unit_of_work = InMemoryUnitOfWork::new(ingredient_repo.clone(), repo.clone());
+        super::__tests__::deleting_an_existing_ingredient_works(
+            repo,
+            ingredient_repo,
+            unit_of_work,
+        )
+        .await;
     }
 
     #[tokio::test]
     async fn deleting_an_ingredient_that_doesnt_appear_in_recipe_errors() {
         let repo = InMemoryRecipeRepository::new();
         let ingredient_repo = InMemoryIngredientRepository::new();
+        let unit_of_work = InMemoryUnitOfWork::new(ingredient_repo.clone(), repo.clone());
         super::__tests__::deleting_an_ingredient_that_doesnt_appear_in_recipe_errors(
             repo,
             ingredient_repo,
+            unit_of_work,
         )
         .await;
     }
 
     #[tokio::test]
     async fn deleting_an_ingredient_in_recipe_that_doesnt_exist_errors() {
-        let repo = InMemoryRecipeRepository::new();
         let ingredient_repo = InMemoryIngredientRepository::new();
+        let unit_of_work =
+            InMemoryUnitOfWork::new(ingredient_repo.clone(), InMemoryRecipeRepository::new());
         super::__tests__::deleting_an_ingredient_in_recipe_that_doesnt_exist_errors(
-            repo,
             ingredient_repo,
+            unit_of_work,
         )
         .await;
     }
@@ -39,8 +49,28 @@ mod in_memory {
     async fn deleting_the_last_ingredient_in_recipe_errors() {
         let repo = InMemoryRecipeRepository::new();
         let ingredient_repo = InMemoryIngredientRepository::new();
-        super::__tests__::deleting_the_last_ingredient_in_recipe_errors(repo, ingredient_repo)
-            .await;
+        let unit_of_work = InMemoryUnitOfWork::new(ingredient_repo.clone(), repo.clone());
+        super::__tests__::deleting_the_last_ingredient_in_recipe_errors(
+            repo,
+            ingredient_repo,
+            unit_of_work,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn deleting_an_ingredient_from_a_recipe_of_someone_else_errors() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        let unit_of_work = InMemoryUnitOfWork::new(ingredient_repo.clone(), repo.clone());
+        super::__tests__::deleting_an_ingredient_from_a_recipe_of_someone_else_errors(
+            repo,
+            ingredient_repo,
+            user_repo,
+            unit_of_work,
+        )
+        .await
     }
 }
 
@@ -49,34 +79,43 @@ mod sql {
 
     use crate::domain::repositories::{
         ingredients::postgres::PostgresIngredientRepository,
-        recipe::postgres::PostgresRecipeRepository,
+        recipe::postgres::PostgresRecipeRepository, unit_of_work::postgres::PostgresUnitOfWork,
+        user::postgres::PostgresUserRepository,
     };
 
     #[sqlx::test]
     async fn deleting_an_existing_ingredient_works(pool: PgPool) {
         let repo = PostgresRecipeRepository::new(pool.clone());
         let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
-        super::__tests__::deleting_an_existing_ingredient_works(repo, ingredient_repo).await;
+        let unit_of_work = PostgresUnitOfWork::new(pool);
+        super::__tests__::deleting_an_existing_ingredient_works(
+            repo,
+            ingredient_repo,
+            unit_of_work,
+        )
+        .await;
     }
 
     #[sqlx::test]
     async fn deleting_an_ingredient_that_doesnt_appear_in_recipe_errors(pool: PgPool) {
         let repo = PostgresRecipeRepository::new(pool.clone());
         let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let unit_of_work = PostgresUnitOfWork::new(pool);
         super::__tests__::deleting_an_ingredient_that_doesnt_appear_in_recipe_errors(
             repo,
             ingredient_repo,
+            unit_of_work,
         )
         .await;
     }
 
     #[sqlx::test]
     async fn deleting_an_ingredient_in_recipe_that_doesnt_exist_errors(pool: PgPool) {
-        let repo = PostgresRecipeRepository::new(pool.clone());
         let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let unit_of_work = PostgresUnitOfWork::new(pool);
         super::__tests__::deleting_an_ingredient_in_recipe_that_doesnt_exist_errors(
-            repo,
             ingredient_repo,
+            unit_of_work,
         )
         .await;
     }
@@ -85,7 +124,26 @@ mod sql {
     async fn deleting_the_last_ingredient_in_recipe_errors(pool: PgPool) {
         let repo = PostgresRecipeRepository::new(pool.clone());
         let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
-        super::__tests__::deleting_the_last_ingredient_in_recipe_errors(repo, ingredient_repo)
-            .await;
+        let unit_of_work = PostgresUnitOfWork::new(pool);
+        super::__tests__::deleting_the_last_ingredient_in_recipe_errors(
+            repo,
+            ingredient_repo,
+            unit_of_work,
+        )
+        .await;
+    }
+
+    #[sqlx::test]
+    async fn deleting_an_ingredient_from_a_recipe_of_someone_else_errors(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        super::__tests__::deleting_an_ingredient_from_a_recipe_of_someone_else_errors(
+            repo,
+            ingredient_repo,
+            user_repo,
+            PostgresUnitOfWork::new(pool),
+        )
+        .await
     }
 }
@@ -1,25 +1,41 @@
+use common::UpdateIngredientInRecipeDTO;
 use thiserror::Error;
 use uuid::Uuid;
 
 use crate::domain::{
-    entities::recipe::{IngredientUnit, Recipe},
-    repositories::recipe::{
-        errors::{
+    entities::{
+        precondition::Precondition,
+        recipe::{IngredientInRecipeChangeset, Recipe},
+    },
+    repositories::{
+        recipe::errors::{
             GetRecipeByIdError,
             UpdateIngredientInRecipeError as UpdateIngredientInRecipeErrorInternal,
         },
-        RecipeRepositoryService,
+        unit_of_work::{
+            errors::{BeginTransactionError, CommitTransactionError},
+            UnitOfWorkService,
+        },
     },
 };
 
 #[derive(Error, Debug, strum::AsRefStr)]
 pub enum UpdateIngredientInRecipeError {
+    #[error("You did not provide any changes to be made")]
+    ChangesetEmpty,
+
     #[error(transparent)]
     GetRecipe(#[from] GetRecipeByIdError),
 
-    #[error("Could not find ingredient with ID {0} in this recipe.")]
+    #[error("Could not find ingredient entry with ID {0} in this recipe.")]
     MissingIngredient(Uuid),
 
+    #[error("The recipe with ID {0} has changed since you last fetched it")]
+    PreconditionFailed(Uuid),
+
+    #[error("Only the owner can make changes to the recipe with ID {0}")]
+    NotOwner(Uuid),
+
     #[error(transparent)]
     Unknown(#[from] eyre::Report),
 }
@@ -30,28 +46,77 @@ impl From<UpdateIngredientInRecipeErrorInternal> for UpdateIngredientInRecipeErr
     }
 }
 
+impl From<BeginTransactionError> for UpdateIngredientInRecipeError {
+    fn from(value: BeginTransactionError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+impl From<CommitTransactionError> for UpdateIngredientInRecipeError {
+    fn from(value: CommitTransactionError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+impl From<UpdateIngredientInRecipeDTO> for IngredientInRecipeChangeset {
+    fn from(value: UpdateIngredientInRecipeDTO) -> Self {
+        Self {
+            amount: value.amount.map(Into::into),
+            notes: value
+                .notes
+                .map(|notes| Some(notes.trim().to_string()).filter(|notes| !notes.is_empty())),
+            optional: value.optional,
+            group: value
+                .group
+                .map(|group| Some(group.trim().to_string()).filter(|group| !group.is_empty())),
+            position: value.position,
+        }
+    }
+}
+
+/// Only the owner can change the ingredients of a recipe that has one.
+/// The recipe stays locked from checking the precondition until the change is committed
 pub async fn update_ingredient_in_recipe(
-    recipe_repo: RecipeRepositoryService,
+    unit_of_work: UnitOfWorkService,
+    user_id: Option<&Uuid>,
     recipe_id: &Uuid,
-    ingredient_id: &Uuid,
-    amount: IngredientUnit,
+    entry_id: &Uuid,
+    changeset: IngredientInRecipeChangeset,
+    precondition: &Precondition,
 ) -> Result<Recipe, UpdateIngredientInRecipeError> {
-    let recipe = recipe_repo.get_by_id(recipe_id).await?;
+    if changeset.is_empty() {
+        return Err(UpdateIngredientInRecipeError::ChangesetEmpty);
+    }
 
-    let ingredient_in_recipe = &recipe
+    let tx = unit_of_work.begin().await?;
+    let recipe_repo = tx.recipes();
+
+    let recipe = tx.lock_recipe(recipe_id).await?;
+    if !recipe.is_visible_to(user_id, None) {
+        return Err(GetRecipeByIdError::NotFound(*recipe_id).into());
+    }
+    if !recipe.is_editable_by(user_id) {
+        return Err(UpdateIngredientInRecipeError::NotOwner(*recipe_id));
+    }
+    if !precondition.holds(&recipe.entity_tag()) {
+        return Err(UpdateIngredientInRecipeError::PreconditionFailed(
+            *recipe_id,
+        ));
+    }
+
+    let ingredient_in_recipe = recipe
         .ingredients
-        .iter()
-        .find(|x| x.ingredient.id == *ingredient_id)
-        .ok_or(UpdateIngredientInRecipeError::MissingIngredient(
-            *ingredient_id,
-        ))?;
+        .get_entry(entry_id)
+        .ok_or(UpdateIngredientInRecipeError::MissingIngredient(*entry_id))?;
 
     recipe_repo
-        .update_ingredient_amount(&recipe, ingredient_in_recipe, &amount)
+        .update_ingredient(&recipe, ingredient_in_recipe, changeset)
         .await?;
 
     let recipe = recipe_repo.get_by_id(recipe_id).await?;
 
+    tx.commit().await?;
+
     Ok(recipe)
 }
 
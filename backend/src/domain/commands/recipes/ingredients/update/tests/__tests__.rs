@@ -5,20 +5,31 @@ use crate::{
         commands::recipes::ingredients::update::{
             update_ingredient_in_recipe, UpdateIngredientInRecipeError,
         },
-        entities::recipe::IngredientUnit,
+        entities::{
+            precondition::Precondition,
+            recipe::{IngredientInRecipeChangeset, IngredientUnit, Recipe},
+            visibility::Visibility,
+        },
         repositories::{
             ingredients::IngredientRepository,
-            recipe::{errors::GetRecipeByIdError, RecipeRepository, RecipeRepositoryService},
+            recipe::{errors::GetRecipeByIdError, RecipeRepository},
+            unit_of_work::{UnitOfWork, UnitOfWorkService},
+            user::UserRepository,
         },
     },
-    test_utils::{insert_all_ingredients_of_recipe, recipe_fixture},
+    test_utils::{
+        another_recipe_fixture, another_user_fixture, insert_all_ingredients_of_recipe,
+        insert_users, recipe_fixture, user_fixture,
+    },
 };
 use std::sync::Arc;
 
 pub async fn updating_ingredient_in_recipe_works(
     recipe_repo: impl RecipeRepository,
     ingredient_repo: impl IngredientRepository,
+    unit_of_work: impl UnitOfWork,
 ) {
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
     let initial_recipe = recipe_fixture();
     insert_all_ingredients_of_recipe(&ingredient_repo, &initial_recipe).await;
     recipe_repo.insert(initial_recipe.clone()).await.unwrap();
@@ -26,13 +37,16 @@ pub async fn updating_ingredient_in_recipe_works(
     let ingredient_to_update = initial_recipe.ingredients.first().unwrap();
     let amount = IngredientUnit::Cups(2.0);
 
-    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
-
     let result = update_ingredient_in_recipe(
-        recipe_repo,
+        unit_of_work.clone(),
+        None,
         &initial_recipe.id,
-        &ingredient_to_update.ingredient.id,
-        amount.clone(),
+        &ingredient_to_update.id,
+        IngredientInRecipeChangeset {
+            amount: Some(amount.clone()),
+            ..Default::default()
+        },
+        &Precondition::Any,
     )
     .await
     .unwrap();
@@ -41,22 +55,26 @@ pub async fn updating_ingredient_in_recipe_works(
 }
 
 pub async fn updating_ingredient_in_nonexistent_recipe_errors(
-    recipe_repo: impl RecipeRepository,
     ingredient_repo: impl IngredientRepository,
+    unit_of_work: impl UnitOfWork,
 ) {
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
     let initial_recipe = recipe_fixture();
     insert_all_ingredients_of_recipe(&ingredient_repo, &initial_recipe).await;
 
     let ingredient_to_update = initial_recipe.ingredients.first().unwrap();
     let amount = IngredientUnit::Cups(2.0);
 
-    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
-
     let error = update_ingredient_in_recipe(
-        recipe_repo,
+        unit_of_work.clone(),
+        None,
         &initial_recipe.id,
-        &ingredient_to_update.ingredient.id,
-        amount.clone(),
+        &ingredient_to_update.id,
+        IngredientInRecipeChangeset {
+            amount: Some(amount.clone()),
+            ..Default::default()
+        },
+        &Precondition::Any,
     )
     .await
     .unwrap_err();
@@ -69,20 +87,25 @@ pub async fn updating_ingredient_in_nonexistent_recipe_errors(
 pub async fn updating_nonexistent_ingredient_in_recipe_errors(
     recipe_repo: impl RecipeRepository,
     ingredient_repo: impl IngredientRepository,
+    unit_of_work: impl UnitOfWork,
 ) {
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
     let initial_recipe = recipe_fixture();
     insert_all_ingredients_of_recipe(&ingredient_repo, &initial_recipe).await;
     recipe_repo.insert(initial_recipe.clone()).await.unwrap();
 
     let amount = IngredientUnit::Cups(2.0);
 
-    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
-
     let error = update_ingredient_in_recipe(
-        recipe_repo,
+        unit_of_work.clone(),
+        None,
         &initial_recipe.id,
         &Uuid::from_u128(0xff),
-        amount.clone(),
+        IngredientInRecipeChangeset {
+            amount: Some(amount.clone()),
+            ..Default::default()
+        },
+        &Precondition::Any,
     )
     .await
     .unwrap_err();
@@ -91,3 +114,211 @@ pub async fn updating_nonexistent_ingredient_in_recipe_errors(
         matches!(error, UpdateIngredientInRecipeError::MissingIngredient(id) if id == Uuid::from_u128(0xff))
     )
 }
+
+pub async fn updating_ingredient_in_outdated_recipe_errors(
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    unit_of_work: impl UnitOfWork,
+) {
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
+    let initial_recipe = recipe_fixture();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &initial_recipe).await;
+    recipe_repo.insert(initial_recipe.clone()).await.unwrap();
+
+    let ingredient_to_update = initial_recipe.ingredients.first().unwrap();
+
+    let error = update_ingredient_in_recipe(
+        unit_of_work.clone(),
+        None,
+        &initial_recipe.id,
+        &ingredient_to_update.id,
+        IngredientInRecipeChangeset {
+            amount: Some(IngredientUnit::Cups(2.0)),
+            ..Default::default()
+        },
+        &Precondition::Tags(vec!["outdated".to_string()]),
+    )
+    .await
+    .unwrap_err();
+
+    assert!(
+        matches!(error, UpdateIngredientInRecipeError::PreconditionFailed(id) if id == initial_recipe.id)
+    )
+}
+
+pub async fn updating_notes_and_optional_keeps_the_amount(
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    unit_of_work: impl UnitOfWork,
+) {
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
+    let initial_recipe = recipe_fixture();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &initial_recipe).await;
+    recipe_repo.insert(initial_recipe.clone()).await.unwrap();
+
+    let ingredient_to_update = initial_recipe.ingredients.first().unwrap();
+
+    let result = update_ingredient_in_recipe(
+        unit_of_work.clone(),
+        None,
+        &initial_recipe.id,
+        &ingredient_to_update.id,
+        IngredientInRecipeChangeset {
+            notes: Some(Some("pressed".to_string())),
+            optional: Some(true),
+            group: Some(Some("For the marinade".to_string())),
+            ..Default::default()
+        },
+        &Precondition::Any,
+    )
+    .await
+    .unwrap();
+
+    let updated = result
+        .ingredients
+        .get_entry(&ingredient_to_update.id)
+        .unwrap();
+
+    assert_eq!(updated.amount, ingredient_to_update.amount);
+    assert_eq!(updated.notes.as_deref(), Some("pressed"));
+    assert_eq!(updated.group.as_deref(), Some("For the marinade"));
+    assert!(updated.optional);
+
+    let result = update_ingredient_in_recipe(
+        unit_of_work.clone(),
+        None,
+        &initial_recipe.id,
+        &ingredient_to_update.id,
+        IngredientInRecipeChangeset {
+            notes: Some(None),
+            group: Some(None),
+            ..Default::default()
+        },
+        &Precondition::Any,
+    )
+    .await
+    .unwrap();
+
+    let updated = result
+        .ingredients
+        .get_entry(&ingredient_to_update.id)
+        .unwrap();
+
+    assert_eq!(updated.notes, None);
+    assert_eq!(updated.group, None);
+    assert!(updated.optional);
+}
+
+pub async fn moving_an_ingredient_changes_the_order(
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    unit_of_work: impl UnitOfWork,
+) {
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
+    let initial_recipe = recipe_fixture();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &initial_recipe).await;
+    recipe_repo.insert(initial_recipe.clone()).await.unwrap();
+
+    let initial_order: Vec<Uuid> = initial_recipe.ingredients.iter().map(|i| i.id).collect();
+
+    let result = update_ingredient_in_recipe(
+        unit_of_work.clone(),
+        None,
+        &initial_recipe.id,
+        &initial_order[2],
+        IngredientInRecipeChangeset {
+            position: Some(0),
+            ..Default::default()
+        },
+        &Precondition::Any,
+    )
+    .await
+    .unwrap();
+
+    let order: Vec<Uuid> = result.ingredients.iter().map(|i| i.id).collect();
+
+    assert_eq!(
+        order,
+        vec![initial_order[2], initial_order[0], initial_order[1]]
+    );
+}
+
+pub async fn updating_with_an_empty_changeset_errors(unit_of_work: impl UnitOfWork) {
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
+
+    let error = update_ingredient_in_recipe(
+        unit_of_work.clone(),
+        None,
+        &Uuid::nil(),
+        &Uuid::nil(),
+        IngredientInRecipeChangeset::default(),
+        &Precondition::Any,
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(
+        error,
+        UpdateIngredientInRecipeError::ChangesetEmpty
+    ))
+}
+
+pub async fn updating_ingredient_in_a_recipe_of_someone_else_errors(
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+    unit_of_work: impl UnitOfWork,
+) {
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
+    let public = Recipe {
+        owner_id: Some(user_fixture().id),
+        ..recipe_fixture()
+    };
+    let private = Recipe {
+        visibility: Visibility::Private,
+        owner_id: Some(user_fixture().id),
+        ..another_recipe_fixture(1)
+    };
+    insert_users(&user_repo).await;
+    insert_all_ingredients_of_recipe(&ingredient_repo, &public).await;
+    recipe_repo.insert(public.clone()).await.unwrap();
+    recipe_repo.insert(private.clone()).await.unwrap();
+    let changeset = || IngredientInRecipeChangeset {
+        amount: Some(IngredientUnit::Cups(2.0)),
+        ..Default::default()
+    };
+
+    let error = update_ingredient_in_recipe(
+        unit_of_work.clone(),
+        Some(&another_user_fixture().id),
+        &private.id,
+        &private.ingredients[0].id,
+        changeset(),
+        &Precondition::Any,
+    )
+    .await
+    .unwrap_err();
+    assert!(matches!(
+        error,
+        UpdateIngredientInRecipeError::GetRecipe(GetRecipeByIdError::NotFound(id)) if id == private.id
+    ));
+
+    for user_id in [None, Some(&another_user_fixture().id)] {
+        let error = update_ingredient_in_recipe(
+            unit_of_work.clone(),
+            user_id,
+            &public.id,
+            &public.ingredients[0].id,
+            changeset(),
+            &Precondition::Any,
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(error, UpdateIngredientInRecipeError::NotOwner(id) if id == public.id));
+    }
+
+    assert_eq!(
+        recipe_repo.get_by_id(&public.id).await.unwrap().ingredients,
+        public.ingredients
+    );
+}
@@ -4,7 +4,8 @@ mod in_memory {
     use super::__tests__;
     use crate::domain::repositories::{
         ingredients::in_memory::InMemoryIngredientRepository,
-        recipe::in_memory::InMemoryRecipeRepository,
+        recipe::in_memory::InMemoryRecipeRepository, unit_of_work::in_memory::InMemoryUnitOfWork,
+        user::in_memory::InMemoryUserRepository,
     };
 
     #[tokio::test]
@@ -12,15 +13,20 @@ mod in_memory {
         let recipe_repo = InMemoryRecipeRepository::new();
         let ingredient_repo = InMemoryIngredientRepository::new();
 
-        __tests__::updating_ingredient_in_recipe_works(recipe_repo, ingredient_repo).await
+        let unit_of_work = InMemoryUnitOfWork::new(ingredient_repo.clone(), recipe_repo.clone());
+
+        __tests__::updating_ingredient_in_recipe_works(recipe_repo, ingredient_repo, unit_of_work)
+            .await
     }
 
     #[tokio::test]
     async fn updating_ingredient_in_nonexistent_recipe_errors() {
-        let recipe_repo = InMemoryRecipeRepository::new();
         let ingredient_repo = InMemoryIngredientRepository::new();
 
-        __tests__::updating_ingredient_in_nonexistent_recipe_errors(recipe_repo, ingredient_repo)
+        let unit_of_work =
+            InMemoryUnitOfWork::new(ingredient_repo.clone(), InMemoryRecipeRepository::new());
+
+        __tests__::updating_ingredient_in_nonexistent_recipe_errors(ingredient_repo, unit_of_work)
             .await
     }
 
@@ -29,8 +35,84 @@ mod in_memory {
         let recipe_repo = InMemoryRecipeRepository::new();
         let ingredient_repo = InMemoryIngredientRepository::new();
 
-        __tests__::updating_nonexistent_ingredient_in_recipe_errors(recipe_repo, ingredient_repo)
-            .await
+        let unit_of_work = InMemoryUnitOfWork::new(ingredient_repo.clone(), recipe_repo.clone());
+
+        __tests__::updating_nonexistent_ingredient_in_recipe_errors(
+            recipe_repo,
+            ingredient_repo,
+            unit_of_work,
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn updating_ingredient_in_outdated_recipe_errors() {
+        let recipe_repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+
+        let unit_of_work = InMemoryUnitOfWork::new(ingredient_repo.clone(), recipe_repo.clone());
+
+        __tests__::updating_ingredient_in_outdated_recipe_errors(
+            recipe_repo,
+            ingredient_repo,
+            unit_of_work,
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn updating_notes_and_optional_keeps_the_amount() {
+        let recipe_repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+
+        let unit_of_work = InMemoryUnitOfWork::new(ingredient_repo.clone(), recipe_repo.clone());
+
+        __tests__::updating_notes_and_optional_keeps_the_amount(
+            recipe_repo,
+            ingredient_repo,
+            unit_of_work,
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn moving_an_ingredient_changes_the_order() {
+        let recipe_repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+
+        let unit_of_work = InMemoryUnitOfWork::new(ingredient_repo.clone(), recipe_repo.clone());
+
+        __tests__::moving_an_ingredient_changes_the_order(
+            recipe_repo,
+            ingredient_repo,
+            unit_of_work,
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn updating_with_an_empty_changeset_errors() {
+        let unit_of_work = InMemoryUnitOfWork::new(
+            InMemoryIngredientRepository::new(),
+            InMemoryRecipeRepository::new(),
+        );
+
+        __tests__::updating_with_an_empty_changeset_errors(unit_of_work).await
+    }
+
+    #[tokio::test]
+    async fn updating_ingredient_in_a_recipe_of_someone_else_errors() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        let unit_of_work = InMemoryUnitOfWork::new(ingredient_repo.clone(), repo.clone());
+        __tests__::updating_ingredient_in_a_recipe_of_someone_else_errors(
+            repo,
+            ingredient_repo,
+            user_repo,
+            unit_of_work,
+        )
+        .await
     }
 }
 
@@ -40,7 +122,8 @@ mod sql {
 
     use crate::domain::repositories::{
         ingredients::postgres::PostgresIngredientRepository,
-        recipe::postgres::PostgresRecipeRepository,
+        recipe::postgres::PostgresRecipeRepository, unit_of_work::postgres::PostgresUnitOfWork,
+        user::postgres::PostgresUserRepository,
     };
 
     #[sqlx::test]
@@ -48,16 +131,23 @@ mod sql {
         let recipe_repo = PostgresRecipeRepository::new(pool.clone());
         let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
 
-        __tests__::updating_ingredient_in_recipe_works(recipe_repo, ingredient_repo).await
+        __tests__::updating_ingredient_in_recipe_works(
+            recipe_repo,
+            ingredient_repo,
+            PostgresUnitOfWork::new(pool),
+        )
+        .await
     }
 
     #[sqlx::test]
     async fn updating_ingredient_in_nonexistent_recipe_errors(pool: PgPool) {
-        let recipe_repo = PostgresRecipeRepository::new(pool.clone());
         let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
 
-        __tests__::updating_ingredient_in_nonexistent_recipe_errors(recipe_repo, ingredient_repo)
-            .await
+        __tests__::updating_ingredient_in_nonexistent_recipe_errors(
+            ingredient_repo,
+            PostgresUnitOfWork::new(pool),
+        )
+        .await
     }
 
     #[sqlx::test]
@@ -65,7 +155,69 @@ mod sql {
         let recipe_repo = PostgresRecipeRepository::new(pool.clone());
         let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
 
-        __tests__::updating_nonexistent_ingredient_in_recipe_errors(recipe_repo, ingredient_repo)
-            .await
+        __tests__::updating_nonexistent_ingredient_in_recipe_errors(
+            recipe_repo,
+            ingredient_repo,
+            PostgresUnitOfWork::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn updating_ingredient_in_outdated_recipe_errors(pool: PgPool) {
+        let recipe_repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+
+        __tests__::updating_ingredient_in_outdated_recipe_errors(
+            recipe_repo,
+            ingredient_repo,
+            PostgresUnitOfWork::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn updating_notes_and_optional_keeps_the_amount(pool: PgPool) {
+        let recipe_repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+
+        __tests__::updating_notes_and_optional_keeps_the_amount(
+            recipe_repo,
+            ingredient_repo,
+            PostgresUnitOfWork::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn moving_an_ingredient_changes_the_order(pool: PgPool) {
+        let recipe_repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+
+        __tests__::moving_an_ingredient_changes_the_order(
+            recipe_repo,
+            ingredient_repo,
+            PostgresUnitOfWork::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn updating_with_an_empty_changeset_errors(pool: PgPool) {
+        __tests__::updating_with_an_empty_changeset_errors(PostgresUnitOfWork::new(pool)).await
+    }
+
+    #[sqlx::test]
+    async fn updating_ingredient_in_a_recipe_of_someone_else_errors(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        __tests__::updating_ingredient_in_a_recipe_of_someone_else_errors(
+            repo,
+            ingredient_repo,
+            user_repo,
+            PostgresUnitOfWork::new(pool),
+        )
+        .await
     }
 }
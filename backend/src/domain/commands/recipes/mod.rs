@@ -1,4 +1,9 @@
+pub mod batch;
 pub mod create;
 pub mod delete;
+pub mod fork;
+pub mod import;
 pub mod ingredients;
+pub mod reviews;
+pub mod share;
 pub mod update;
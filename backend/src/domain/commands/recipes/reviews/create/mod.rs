@@ -0,0 +1,109 @@
+use chrono::Utc;
+use common::CreateReviewDTO;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        recipe::errors::ValidationError,
+        review::{Rating, Review},
+        user::User,
+    },
+    repositories::recipe::{
+        errors::{GetRecipeByIdError, GetReviewError, InsertReviewError},
+        RecipeRepositoryService,
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum CreateReviewError {
+    #[error("Could not find the recipe with the ID {0}")]
+    RecipeNotFound(Uuid),
+
+    #[error("{0} has already reviewed this recipe")]
+    AlreadyReviewed(String),
+
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Report),
+}
+
+impl From<GetRecipeByIdError> for CreateReviewError {
+    fn from(value: GetRecipeByIdError) -> Self {
+        match value {
+            GetRecipeByIdError::NotFound(id) => Self::RecipeNotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<GetReviewError> for CreateReviewError {
+    fn from(value: GetReviewError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateReview {
+    pub rating: Rating,
+    pub body: Option<String>,
+}
+
+impl TryFrom<CreateReviewDTO> for CreateReview {
+    type Error = ValidationError;
+    fn try_from(value: CreateReviewDTO) -> Result<Self, Self::Error> {
+        Ok(Self {
+            rating: Rating::new(value.rating)?,
+            body: value.body,
+        })
+    }
+}
+
+/// Trims the text of a review, treating a blank one as no text at all
+pub(super) fn review_body(body: &str) -> Option<String> {
+    Some(body.trim())
+        .filter(|body| !body.is_empty())
+        .map(String::from)
+}
+
+#[tracing::instrument("[COMMAND] Reviewing a recipe", skip(recipe_repo, author))]
+pub async fn create_review(
+    recipe_repo: RecipeRepositoryService,
+    author: &User,
+    recipe_id: &Uuid,
+    input: &CreateReview,
+) -> Result<Review, CreateReviewError> {
+    let recipe = recipe_repo
+        .get_visible(Some(&author.id), recipe_id, None)
+        .await?;
+
+    let now = Utc::now();
+    let review = Review {
+        id: Uuid::now_v7(),
+        recipe_id: recipe.id,
+        user_id: Some(author.id),
+        author: author.username.clone(),
+        rating: input.rating,
+        body: input.body.as_deref().and_then(review_body),
+        created_at: now,
+        updated_at: now,
+    };
+
+    recipe_repo
+        .add_review(&recipe, review.clone())
+        .await
+        .map_err(|e| match e {
+            InsertReviewError::Conflict(_) => {
+                CreateReviewError::AlreadyReviewed(review.author.clone())
+            }
+            e => CreateReviewError::Unknown(e.into()),
+        })?;
+
+    let review = recipe_repo.get_review(&recipe, &review.id).await?;
+
+    Ok(review)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,140 @@
+use std::sync::Arc;
+
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        commands::recipes::reviews::create::{create_review, CreateReview, CreateReviewError},
+        entities::review::Rating,
+        repositories::{
+            ingredients::IngredientRepository,
+            recipe::{RecipeRepository, RecipeRepositoryService},
+            user::UserRepository,
+        },
+    },
+    test_utils::{
+        another_user_fixture, insert_all_ingredients_of_recipe, insert_reviews, insert_users,
+        private_recipe_fixture, recipe_fixture, review_fixture, user_fixture,
+    },
+};
+
+fn create_review_input(rating: u8) -> CreateReview {
+    CreateReview {
+        rating: Rating::new(rating).unwrap(),
+        body: Some("  ".to_string()),
+    }
+}
+
+pub async fn reviewing_a_recipe_updates_its_rating(
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    insert_reviews(
+        &recipe_repo,
+        &ingredient_repo,
+        &user_repo,
+        &[review_fixture()],
+    )
+    .await;
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+    let recipe_id = recipe_fixture().id;
+
+    let review = create_review(
+        recipe_repo.clone(),
+        &another_user_fixture(),
+        &recipe_id,
+        &create_review_input(2),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(review.user_id, Some(another_user_fixture().id));
+    assert_eq!(review.author, "baker");
+    assert_eq!(review.rating.value(), 2);
+    assert_eq!(review.body, None);
+    assert_eq!(review.created_at, review.updated_at);
+
+    let recipe = recipe_repo.get_by_id(&recipe_id).await.unwrap();
+
+    assert_eq!(recipe.rating.count, 2);
+    assert_eq!(recipe.rating.average, Some(3.5));
+}
+
+pub async fn reviewing_a_recipe_twice_errors(
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let existing = review_fixture();
+    insert_reviews(
+        &recipe_repo,
+        &ingredient_repo,
+        &user_repo,
+        std::slice::from_ref(&existing),
+    )
+    .await;
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let error = create_review(
+        recipe_repo.clone(),
+        &user_fixture(),
+        &existing.recipe_id,
+        &create_review_input(1),
+    )
+    .await
+    .unwrap_err();
+
+    match error {
+        CreateReviewError::AlreadyReviewed(author) => assert_eq!(author, existing.author),
+        e => panic!("Wrong error returned: {e}"),
+    }
+
+    let reviews = recipe_repo.get_reviews(&recipe_fixture()).await.unwrap();
+
+    assert_eq!(reviews, vec![existing]);
+}
+
+pub async fn reviewing_a_nonexistent_recipe_errors(recipe_repo: impl RecipeRepository) {
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+    let recipe_id = Uuid::from_u128(404);
+
+    let error = create_review(
+        recipe_repo,
+        &user_fixture(),
+        &recipe_id,
+        &create_review_input(4),
+    )
+    .await
+    .unwrap_err();
+
+    match error {
+        CreateReviewError::RecipeNotFound(id) => assert_eq!(id, recipe_id),
+        e => panic!("Wrong error returned: {e}"),
+    }
+}
+
+pub async fn reviewing_a_private_recipe_of_someone_else_errors(
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let recipe = private_recipe_fixture();
+    insert_users(&user_repo).await;
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let error = create_review(
+        recipe_repo.clone(),
+        &another_user_fixture(),
+        &recipe.id,
+        &create_review_input(1),
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(error, CreateReviewError::RecipeNotFound(id) if id == recipe.id));
+    assert_eq!(recipe_repo.get_reviews(&recipe).await.unwrap(), vec![]);
+}
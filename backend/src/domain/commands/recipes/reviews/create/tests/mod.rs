@@ -0,0 +1,89 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::{
+        ingredients::in_memory::InMemoryIngredientRepository,
+        recipe::in_memory::InMemoryRecipeRepository, user::in_memory::InMemoryUserRepository,
+    };
+
+    #[tokio::test]
+    async fn reviewing_a_recipe_updates_its_rating() {
+        __tests__::reviewing_a_recipe_updates_its_rating(
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+            InMemoryUserRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn reviewing_a_recipe_twice_errors() {
+        __tests__::reviewing_a_recipe_twice_errors(
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+            InMemoryUserRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn reviewing_a_private_recipe_of_someone_else_errors() {
+        __tests__::reviewing_a_private_recipe_of_someone_else_errors(
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+            InMemoryUserRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn reviewing_a_nonexistent_recipe_errors() {
+        __tests__::reviewing_a_nonexistent_recipe_errors(InMemoryRecipeRepository::new()).await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        ingredients::postgres::PostgresIngredientRepository,
+        recipe::postgres::PostgresRecipeRepository, user::postgres::PostgresUserRepository,
+    };
+
+    #[sqlx::test]
+    async fn reviewing_a_recipe_updates_its_rating(pool: PgPool) {
+        __tests__::reviewing_a_recipe_updates_its_rating(
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresUserRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn reviewing_a_recipe_twice_errors(pool: PgPool) {
+        __tests__::reviewing_a_recipe_twice_errors(
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresUserRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn reviewing_a_private_recipe_of_someone_else_errors(pool: PgPool) {
+        __tests__::reviewing_a_private_recipe_of_someone_else_errors(
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresUserRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn reviewing_a_nonexistent_recipe_errors(pool: PgPool) {
+        __tests__::reviewing_a_nonexistent_recipe_errors(PostgresRecipeRepository::new(pool)).await
+    }
+}
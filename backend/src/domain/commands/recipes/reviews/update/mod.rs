@@ -0,0 +1,100 @@
+use common::UpdateReviewDTO;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        recipe::errors::ValidationError,
+        review::{Rating, Review, ReviewChangeset},
+    },
+    repositories::recipe::{
+        errors::{
+            GetRecipeByIdError, GetReviewError, UpdateReviewError as UpdateReviewErrorInternal,
+        },
+        RecipeRepositoryService,
+    },
+};
+
+use super::create::review_body;
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum UpdateReviewError {
+    #[error("Could not find the recipe with the ID {0}")]
+    RecipeNotFound(Uuid),
+
+    #[error("Could not find the review with the ID {0}")]
+    NotFound(Uuid),
+
+    #[error("Only the author of the review with the ID {0} can edit it")]
+    NotAuthor(Uuid),
+
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Report),
+}
+
+impl From<GetRecipeByIdError> for UpdateReviewError {
+    fn from(value: GetRecipeByIdError) -> Self {
+        match value {
+            GetRecipeByIdError::NotFound(id) => Self::RecipeNotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<GetReviewError> for UpdateReviewError {
+    fn from(value: GetReviewError) -> Self {
+        match value {
+            GetReviewError::NotFound(id) => Self::NotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<UpdateReviewErrorInternal> for UpdateReviewError {
+    fn from(value: UpdateReviewErrorInternal) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+impl TryFrom<UpdateReviewDTO> for ReviewChangeset {
+    type Error = ValidationError;
+    fn try_from(value: UpdateReviewDTO) -> Result<Self, Self::Error> {
+        Ok(Self {
+            rating: value.rating.map(Rating::new).transpose()?,
+            body: value.body.map(|body| review_body(&body)),
+        })
+    }
+}
+
+#[tracing::instrument("[COMMAND] Editing a review", skip(recipe_repo))]
+pub async fn update_review(
+    recipe_repo: RecipeRepositoryService,
+    user_id: &Uuid,
+    recipe_id: &Uuid,
+    review_id: &Uuid,
+    changeset: ReviewChangeset,
+) -> Result<Review, UpdateReviewError> {
+    if changeset.is_empty() {
+        return Err(ValidationError::EmptyField(vec!["rating", "body"]).into());
+    }
+
+    let recipe = recipe_repo
+        .get_visible(Some(user_id), recipe_id, None)
+        .await?;
+    let review = recipe_repo.get_review(&recipe, review_id).await?;
+
+    if review.user_id.as_ref() != Some(user_id) {
+        return Err(UpdateReviewError::NotAuthor(review.id));
+    }
+
+    recipe_repo.update_review(&review, changeset).await?;
+
+    let review = recipe_repo.get_review(&recipe, review_id).await?;
+
+    Ok(review)
+}
+
+#[cfg(test)]
+mod tests;
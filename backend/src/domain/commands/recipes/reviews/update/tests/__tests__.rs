@@ -0,0 +1,240 @@
+use std::sync::Arc;
+
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        commands::recipes::reviews::update::{update_review, UpdateReviewError},
+        entities::{
+            recipe::{errors::ValidationError, RecipeChangeset},
+            review::{Rating, Review, ReviewChangeset},
+            visibility::Visibility,
+        },
+        repositories::{
+            ingredients::IngredientRepository,
+            recipe::{RecipeRepository, RecipeRepositoryService},
+            user::UserRepository,
+        },
+    },
+    test_utils::{
+        another_user_fixture, insert_reviews, recipe_fixture, review_fixture, user_fixture,
+    },
+};
+
+pub async fn editing_a_review_works(
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let review = review_fixture();
+    insert_reviews(
+        &recipe_repo,
+        &ingredient_repo,
+        &user_repo,
+        std::slice::from_ref(&review),
+    )
+    .await;
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let changeset = ReviewChangeset {
+        rating: Some(Rating::new(3).unwrap()),
+        body: Some(None),
+    };
+
+    let result = update_review(
+        recipe_repo.clone(),
+        &user_fixture().id,
+        &review.recipe_id,
+        &review.id,
+        changeset,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(result.rating.value(), 3);
+    assert_eq!(result.body, None);
+    assert_eq!(result.created_at, review.created_at);
+    assert!(result.updated_at > review.updated_at);
+
+    let recipe = recipe_repo.get_by_id(&review.recipe_id).await.unwrap();
+
+    assert_eq!(recipe.rating.count, 1);
+    assert_eq!(recipe.rating.average, Some(3.0));
+}
+
+pub async fn editing_a_review_without_changes_keeps_its_timestamp(
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let review = review_fixture();
+    insert_reviews(
+        &recipe_repo,
+        &ingredient_repo,
+        &user_repo,
+        std::slice::from_ref(&review),
+    )
+    .await;
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let changeset = ReviewChangeset {
+        rating: Some(review.rating),
+        ..Default::default()
+    };
+
+    let result = update_review(
+        recipe_repo,
+        &user_fixture().id,
+        &review.recipe_id,
+        &review.id,
+        changeset,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(result, review);
+}
+
+pub async fn editing_a_review_with_invalid_changes_errors(
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let review = review_fixture();
+    insert_reviews(
+        &recipe_repo,
+        &ingredient_repo,
+        &user_repo,
+        std::slice::from_ref(&review),
+    )
+    .await;
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let error = update_review(
+        recipe_repo.clone(),
+        &user_fixture().id,
+        &review.recipe_id,
+        &review.id,
+        ReviewChangeset::default(),
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(
+        error,
+        UpdateReviewError::ValidationError(ValidationError::EmptyField(_))
+    ));
+
+    let missing = Uuid::from_u128(404);
+    let error = update_review(
+        recipe_repo.clone(),
+        &user_fixture().id,
+        &review.recipe_id,
+        &missing,
+        ReviewChangeset {
+            rating: Some(Rating::new(1).unwrap()),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap_err();
+
+    match error {
+        UpdateReviewError::NotFound(id) => assert_eq!(id, missing),
+        e => panic!("Wrong error returned: {e}"),
+    }
+
+    let error = update_review(
+        recipe_repo,
+        &another_user_fixture().id,
+        &review.recipe_id,
+        &review.id,
+        ReviewChangeset {
+            rating: Some(Rating::new(1).unwrap()),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap_err();
+
+    match error {
+        UpdateReviewError::NotAuthor(id) => assert_eq!(id, review.id),
+        e => panic!("Wrong error returned: {e}"),
+    }
+}
+
+pub async fn reviews_without_an_author_can_not_be_edited(
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let review = Review {
+        user_id: None,
+        ..review_fixture()
+    };
+    insert_reviews(
+        &recipe_repo,
+        &ingredient_repo,
+        &user_repo,
+        std::slice::from_ref(&review),
+    )
+    .await;
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let error = update_review(
+        recipe_repo,
+        &user_fixture().id,
+        &review.recipe_id,
+        &review.id,
+        ReviewChangeset {
+            rating: Some(Rating::new(1).unwrap()),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(error, UpdateReviewError::NotAuthor(id) if id == review.id));
+}
+
+pub async fn editing_a_review_of_a_recipe_hidden_since_errors(
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let review = review_fixture();
+    insert_reviews(
+        &recipe_repo,
+        &ingredient_repo,
+        &user_repo,
+        std::slice::from_ref(&review),
+    )
+    .await;
+    recipe_repo
+        .update(
+            &recipe_fixture(),
+            RecipeChangeset {
+                visibility: Some(Visibility::Private),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let error = update_review(
+        recipe_repo,
+        &user_fixture().id,
+        &review.recipe_id,
+        &review.id,
+        ReviewChangeset {
+            rating: Some(Rating::new(1).unwrap()),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(error, UpdateReviewError::RecipeNotFound(id) if id == review.recipe_id));
+}
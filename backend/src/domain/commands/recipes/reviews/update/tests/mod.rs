@@ -0,0 +1,119 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::{
+        ingredients::in_memory::InMemoryIngredientRepository,
+        recipe::in_memory::InMemoryRecipeRepository, user::in_memory::InMemoryUserRepository,
+    };
+
+    #[tokio::test]
+    async fn editing_a_review_works() {
+        __tests__::editing_a_review_works(
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+            InMemoryUserRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn editing_a_review_of_a_recipe_hidden_since_errors() {
+        __tests__::editing_a_review_of_a_recipe_hidden_since_errors(
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+            InMemoryUserRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn editing_a_review_without_changes_keeps_its_timestamp() {
+        __tests__::editing_a_review_without_changes_keeps_its_timestamp(
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+            InMemoryUserRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn editing_a_review_with_invalid_changes_errors() {
+        __tests__::editing_a_review_with_invalid_changes_errors(
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+            InMemoryUserRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn reviews_without_an_author_can_not_be_edited() {
+        __tests__::reviews_without_an_author_can_not_be_edited(
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+            InMemoryUserRepository::new(),
+        )
+        .await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        ingredients::postgres::PostgresIngredientRepository,
+        recipe::postgres::PostgresRecipeRepository, user::postgres::PostgresUserRepository,
+    };
+
+    #[sqlx::test]
+    async fn editing_a_review_works(pool: PgPool) {
+        __tests__::editing_a_review_works(
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresUserRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn editing_a_review_of_a_recipe_hidden_since_errors(pool: PgPool) {
+        __tests__::editing_a_review_of_a_recipe_hidden_since_errors(
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresUserRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn editing_a_review_without_changes_keeps_its_timestamp(pool: PgPool) {
+        __tests__::editing_a_review_without_changes_keeps_its_timestamp(
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresUserRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn editing_a_review_with_invalid_changes_errors(pool: PgPool) {
+        __tests__::editing_a_review_with_invalid_changes_errors(
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresUserRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn reviews_without_an_author_can_not_be_edited(pool: PgPool) {
+        __tests__::reviews_without_an_author_can_not_be_edited(
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresUserRepository::new(pool),
+        )
+        .await
+    }
+}
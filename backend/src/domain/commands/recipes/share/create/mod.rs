@@ -0,0 +1,69 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::share_token::ShareToken,
+    repositories::recipe::{
+        errors::{GetRecipeByIdError, GetShareTokenError, InsertShareTokenError},
+        RecipeRepositoryService,
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum CreateShareTokenError {
+    #[error("Could not find the recipe with the ID {0}")]
+    RecipeNotFound(Uuid),
+
+    #[error("Only the owner of the recipe with the ID {0} can share it")]
+    NotOwner(Uuid),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetRecipeByIdError> for CreateShareTokenError {
+    fn from(value: GetRecipeByIdError) -> Self {
+        match value {
+            GetRecipeByIdError::NotFound(id) => Self::RecipeNotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<GetShareTokenError> for CreateShareTokenError {
+    fn from(value: GetShareTokenError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+impl From<InsertShareTokenError> for CreateShareTokenError {
+    fn from(value: InsertShareTokenError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+/// Makes a new secret link to the recipe, which lets anybody see it while it is unlisted.
+/// Only the owner of the recipe can share it.
+#[tracing::instrument("[COMMAND] Sharing a recipe", skip(recipe_repo))]
+pub async fn create_share_token(
+    recipe_repo: RecipeRepositoryService,
+    user_id: &Uuid,
+    recipe_id: &Uuid,
+) -> Result<ShareToken, CreateShareTokenError> {
+    let recipe = recipe_repo
+        .get_visible(Some(user_id), recipe_id, None)
+        .await?;
+
+    if !recipe.is_owned_by(Some(user_id)) {
+        return Err(CreateShareTokenError::NotOwner(recipe.id));
+    }
+
+    let share_token = ShareToken::new(recipe.id);
+    recipe_repo.add_share_token(share_token.clone()).await?;
+
+    let share_token = recipe_repo.get_share_token(&share_token.token).await?;
+
+    Ok(share_token)
+}
+
+#[cfg(test)]
+mod tests;
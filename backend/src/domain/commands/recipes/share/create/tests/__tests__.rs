@@ -0,0 +1,72 @@
+use std::sync::Arc;
+
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        commands::recipes::share::create::{create_share_token, CreateShareTokenError},
+        entities::recipe::Recipe,
+        repositories::{
+            ingredients::IngredientRepository,
+            recipe::{RecipeRepository, RecipeRepositoryService},
+            user::UserRepository,
+        },
+    },
+    test_utils::{
+        another_user_fixture, insert_all_ingredients_of_recipe, insert_users, recipe_fixture,
+        user_fixture,
+    },
+};
+
+pub async fn sharing_a_recipe_works(
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let recipe = Recipe {
+        owner_id: Some(user_fixture().id),
+        ..recipe_fixture()
+    };
+    insert_users(&user_repo).await;
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let result = create_share_token(recipe_repo.clone(), &user_fixture().id, &recipe.id)
+        .await
+        .unwrap();
+
+    assert_eq!(result.recipe_id, recipe.id);
+    assert_eq!(result.token.len(), 32);
+
+    let saved = recipe_repo.get_share_token(&result.token).await.unwrap();
+    assert_eq!(saved.recipe_id, recipe.id);
+
+    let other = create_share_token(recipe_repo.clone(), &user_fixture().id, &recipe.id)
+        .await
+        .unwrap();
+    assert_ne!(other.token, result.token);
+
+    let error = create_share_token(recipe_repo, &another_user_fixture().id, &recipe.id)
+        .await
+        .unwrap_err();
+    match error {
+        CreateShareTokenError::NotOwner(id) => assert_eq!(id, recipe.id),
+        e => panic!("Wrong error returned: {e}"),
+    }
+}
+
+pub async fn sharing_a_nonexistent_recipe_errors(recipe_repo: impl RecipeRepository) {
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+    let recipe_id = Uuid::from_u128(404);
+
+    let error = create_share_token(recipe_repo, &user_fixture().id, &recipe_id)
+        .await
+        .unwrap_err();
+
+    match error {
+        CreateShareTokenError::RecipeNotFound(id) => assert_eq!(id, recipe_id),
+        e => panic!("Wrong error returned: {e}"),
+    }
+}
@@ -0,0 +1,75 @@
+use uuid::Uuid;
+
+use crate::domain::repositories::recipe::{
+    errors::{DeleteShareTokenError, GetRecipeByIdError, GetShareTokenError},
+    RecipeRepositoryService,
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum RevokeShareTokenError {
+    #[error("Could not find the recipe with the ID {0}")]
+    RecipeNotFound(Uuid),
+
+    #[error("Only the owner of the recipe with the ID {0} can revoke its share tokens")]
+    NotOwner(Uuid),
+
+    #[error("Could not find the share token")]
+    NotFound,
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetRecipeByIdError> for RevokeShareTokenError {
+    fn from(value: GetRecipeByIdError) -> Self {
+        match value {
+            GetRecipeByIdError::NotFound(id) => Self::RecipeNotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<GetShareTokenError> for RevokeShareTokenError {
+    fn from(value: GetShareTokenError) -> Self {
+        match value {
+            GetShareTokenError::NotFound => Self::NotFound,
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<DeleteShareTokenError> for RevokeShareTokenError {
+    fn from(value: DeleteShareTokenError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+/// Revokes a share token, so that its link stops working. Only the owner of the recipe can revoke them.
+#[tracing::instrument("[COMMAND] Revoking a share token", skip(recipe_repo, token))]
+pub async fn revoke_share_token(
+    recipe_repo: RecipeRepositoryService,
+    user_id: &Uuid,
+    recipe_id: &Uuid,
+    token: &str,
+) -> Result<(), RevokeShareTokenError> {
+    let recipe = recipe_repo
+        .get_visible(Some(user_id), recipe_id, None)
+        .await?;
+
+    if !recipe.is_owned_by(Some(user_id)) {
+        return Err(RevokeShareTokenError::NotOwner(recipe.id));
+    }
+
+    let share_token = recipe_repo.get_share_token(token).await?;
+
+    if share_token.recipe_id != recipe.id {
+        return Err(RevokeShareTokenError::NotFound);
+    }
+
+    recipe_repo.delete_share_token(&share_token).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests;
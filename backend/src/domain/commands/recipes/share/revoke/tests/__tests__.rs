@@ -0,0 +1,112 @@
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        commands::recipes::share::revoke::{revoke_share_token, RevokeShareTokenError},
+        entities::{recipe::Recipe, share_token::ShareToken},
+        repositories::{
+            ingredients::IngredientRepository,
+            recipe::{errors::GetShareTokenError, RecipeRepository, RecipeRepositoryService},
+            user::UserRepository,
+        },
+    },
+    test_utils::{
+        another_recipe_fixture, another_user_fixture, insert_all_ingredients_of_recipe,
+        insert_users, recipe_fixture, user_fixture,
+    },
+};
+
+pub async fn revoking_a_share_token_works(
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let recipe = Recipe {
+        owner_id: Some(user_fixture().id),
+        ..recipe_fixture()
+    };
+    insert_users(&user_repo).await;
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+    let share_token = ShareToken::new(recipe.id);
+    recipe_repo
+        .add_share_token(share_token.clone())
+        .await
+        .unwrap();
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let error = revoke_share_token(
+        recipe_repo.clone(),
+        &another_user_fixture().id,
+        &recipe.id,
+        &share_token.token,
+    )
+    .await
+    .unwrap_err();
+    assert!(matches!(error, RevokeShareTokenError::NotOwner(id) if id == recipe.id));
+
+    revoke_share_token(
+        recipe_repo.clone(),
+        &user_fixture().id,
+        &recipe.id,
+        &share_token.token,
+    )
+    .await
+    .unwrap();
+
+    let error = recipe_repo
+        .get_share_token(&share_token.token)
+        .await
+        .unwrap_err();
+    assert!(matches!(error, GetShareTokenError::NotFound));
+
+    let error = revoke_share_token(
+        recipe_repo,
+        &user_fixture().id,
+        &recipe.id,
+        &share_token.token,
+    )
+    .await
+    .unwrap_err();
+    assert!(matches!(error, RevokeShareTokenError::NotFound));
+}
+
+pub async fn revoking_a_share_token_of_another_recipe_errors(
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let recipe = Recipe {
+        owner_id: Some(user_fixture().id),
+        ..recipe_fixture()
+    };
+    let other = Recipe {
+        owner_id: Some(user_fixture().id),
+        ..another_recipe_fixture(1)
+    };
+    insert_users(&user_repo).await;
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+    recipe_repo.insert(other.clone()).await.unwrap();
+    let share_token = ShareToken::new(other.id);
+    recipe_repo
+        .add_share_token(share_token.clone())
+        .await
+        .unwrap();
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let error = revoke_share_token(
+        recipe_repo.clone(),
+        &user_fixture().id,
+        &recipe.id,
+        &share_token.token,
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(error, RevokeShareTokenError::NotFound));
+    recipe_repo
+        .get_share_token(&share_token.token)
+        .await
+        .unwrap();
+}
@@ -3,12 +3,17 @@ use std::collections::BTreeMap;
 use common::{ServingsTypeDTO, UpdateRecipeDTO};
 use uuid::Uuid;
 
+use crate::domain::entities::precondition::Precondition;
 use crate::domain::entities::recipe::errors::ValidationError;
 use crate::domain::entities::recipe::{Recipe, RecipeChangeset};
+use crate::domain::entities::visibility::Visibility;
 use crate::domain::repositories::recipe::errors::{
     GetRecipeByIdError, UpdateRecipeError as UpdateRecipeErrorInternal,
 };
-use crate::domain::repositories::recipe::RecipeRepositoryService;
+use crate::domain::repositories::unit_of_work::errors::{
+    BeginTransactionError, CommitTransactionError,
+};
+use crate::domain::repositories::unit_of_work::UnitOfWorkService;
 
 #[derive(thiserror::Error, Debug, strum::AsRefStr)]
 pub enum UpdateRecipeError {
@@ -18,6 +23,12 @@ pub enum UpdateRecipeError {
     #[error("Could not find the ingredient with ID {0}")]
     NotFound(Uuid),
 
+    #[error("The recipe with ID {0} has changed since you last fetched it")]
+    PreconditionFailed(Uuid),
+
+    #[error("Only the owner can make changes to the recipe with ID {0}")]
+    NotOwner(Uuid),
+
     #[error(transparent)]
     ValidationError(#[from] ValidationError),
 
@@ -31,6 +42,18 @@ impl From<UpdateRecipeErrorInternal> for UpdateRecipeError {
     }
 }
 
+impl From<BeginTransactionError> for UpdateRecipeError {
+    fn from(value: BeginTransactionError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+impl From<CommitTransactionError> for UpdateRecipeError {
+    fn from(value: CommitTransactionError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
 impl From<GetRecipeByIdError> for UpdateRecipeError {
     fn from(value: GetRecipeByIdError) -> Self {
         match value {
@@ -48,6 +71,7 @@ pub struct UpdateRecipe {
     pub steps: Option<Vec<String>>,
     pub time: Option<BTreeMap<String, std::time::Duration>>,
     pub servings: Option<ServingsTypeDTO>,
+    pub visibility: Option<Visibility>,
 }
 
 impl TryFrom<UpdateRecipe> for RecipeChangeset {
@@ -62,6 +86,7 @@ impl TryFrom<UpdateRecipe> for RecipeChangeset {
             name: value.name,
             servings: value.servings.map(|s| s.into()),
             description: value.description,
+            visibility: value.visibility,
         })
     }
 }
@@ -79,21 +104,42 @@ impl From<UpdateRecipeDTO> for UpdateRecipe {
             description: value.description,
             steps: value.steps,
             servings: value.servings,
+            visibility: value.visibility.map(Into::into),
         }
     }
 }
 
+/// Only the owner can change a recipe or who sees it, so recipes without an owner stay public.
+/// The recipe stays locked from checking the precondition until the change is committed.
 pub async fn update_recipe(
-    recipe_repo: RecipeRepositoryService,
+    unit_of_work: UnitOfWorkService,
+    user_id: Option<&Uuid>,
     input: &Uuid,
     update: UpdateRecipe,
+    precondition: &Precondition,
 ) -> Result<Recipe, UpdateRecipeError> {
     let changeset: RecipeChangeset = update.try_into()?;
     if changeset.is_empty() {
         return Err(UpdateRecipeError::ChangesetEmpty);
     };
 
-    let recipe = recipe_repo.get_by_id(input).await?;
+    let tx = unit_of_work.begin().await?;
+    let recipe_repo = tx.recipes();
+
+    let recipe = tx.lock_recipe(input).await?;
+    if !recipe.is_visible_to(user_id, None) {
+        return Err(UpdateRecipeError::NotFound(*input));
+    }
+
+    if !recipe.is_editable_by(user_id)
+        || (changeset.visibility.is_some() && !recipe.is_owned_by(user_id))
+    {
+        return Err(UpdateRecipeError::NotOwner(*input));
+    }
+
+    if !precondition.holds(&recipe.entity_tag()) {
+        return Err(UpdateRecipeError::PreconditionFailed(*input));
+    }
 
     recipe_repo.update(&recipe, changeset).await?;
 
@@ -102,6 +148,8 @@ pub async fn update_recipe(
         .await
         .map_err(UpdateRecipeError::from)?;
 
+    tx.commit().await?;
+
     Ok(recipe)
 }
 
@@ -6,26 +6,59 @@ use chrono::Utc;
 use pretty_assertions::assert_eq;
 
 use crate::domain::commands::recipes::update::{update_recipe, UpdateRecipe, UpdateRecipeError};
+use crate::domain::entities::precondition::Precondition;
 use crate::domain::entities::recipe::{Recipe, ServingsType};
+use crate::domain::entities::visibility::Visibility;
 use crate::domain::repositories::ingredients::IngredientRepository;
 
 use crate::domain::repositories::recipe::{RecipeRepository, RecipeRepositoryService};
-use crate::test_utils::{insert_all_ingredients_of_recipe, recipe_changeset, recipe_fixture};
+use crate::domain::repositories::unit_of_work::{UnitOfWork, UnitOfWorkService};
+use crate::domain::repositories::user::UserRepository;
+use crate::test_utils::{
+    another_recipe_fixture, another_user_fixture, insert_all_ingredients_of_recipe, insert_reviews,
+    insert_users, recipe_changeset, recipe_fixture, review_fixture, user_fixture,
+};
 
 pub async fn updating_a_recipe_succeeds(
     repo: impl RecipeRepository,
     ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+    unit_of_work: impl UnitOfWork,
 ) {
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
     let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
-    let recipe = recipe_fixture();
-    let changeset = recipe_changeset();
+    let recipe = Recipe {
+        owner_id: Some(user_fixture().id),
+        ..recipe_fixture()
+    };
+    insert_users(&user_repo).await;
     insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
 
     recipe_repo.insert(recipe.clone()).await.unwrap();
 
-    let result = update_recipe(recipe_repo, &recipe.id, changeset)
+    for user_id in [None, Some(&another_user_fixture().id)] {
+        let error = update_recipe(
+            unit_of_work.clone(),
+            user_id,
+            &recipe.id,
+            recipe_changeset(),
+            &Precondition::Any,
+        )
         .await
-        .unwrap();
+        .unwrap_err();
+
+        assert!(matches!(error, UpdateRecipeError::NotOwner(id) if id == recipe.id));
+    }
+
+    let result = update_recipe(
+        unit_of_work.clone(),
+        Some(&user_fixture().id),
+        &recipe.id,
+        recipe_changeset(),
+        &Precondition::Any,
+    )
+    .await
+    .unwrap();
 
     let now = Utc::now();
 
@@ -47,6 +80,7 @@ pub async fn updating_a_recipe_succeeds(
                 .unwrap(),
             time: BTreeMap::from([("Prep time".to_string(), Duration::from_secs(60))]),
             servings: ServingsType::Exact(4),
+            visibility: Visibility::Unlisted,
             updated_at: now,
             created_at: now,
             ..recipe
@@ -54,8 +88,8 @@ pub async fn updating_a_recipe_succeeds(
     );
 }
 
-pub async fn updating_a_nonexistent_recipe_fails(repo: impl RecipeRepository) {
-    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+pub async fn updating_a_nonexistent_recipe_fails(unit_of_work: impl UnitOfWork) {
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
 
     let recipe = recipe_fixture();
     let changeset = UpdateRecipe {
@@ -63,9 +97,15 @@ pub async fn updating_a_nonexistent_recipe_fails(repo: impl RecipeRepository) {
         ..Default::default()
     };
 
-    let result = update_recipe(recipe_repo, &recipe.id, changeset)
-        .await
-        .unwrap_err();
+    let result = update_recipe(
+        unit_of_work.clone(),
+        None,
+        &recipe.id,
+        changeset,
+        &Precondition::Any,
+    )
+    .await
+    .unwrap_err();
 
     assert!(matches!(result, UpdateRecipeError::NotFound(id) if id == recipe.id))
 }
@@ -73,7 +113,9 @@ pub async fn updating_a_nonexistent_recipe_fails(repo: impl RecipeRepository) {
 pub async fn updating_a_recipe_with_empty_changeset_errors(
     repo: impl RecipeRepository,
     ingredient_repo: impl IngredientRepository,
+    unit_of_work: impl UnitOfWork,
 ) {
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
     let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
 
     let recipe = recipe_fixture();
@@ -83,9 +125,195 @@ pub async fn updating_a_recipe_with_empty_changeset_errors(
     insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
     recipe_repo.insert(recipe.clone()).await.unwrap();
 
-    let result = update_recipe(recipe_repo, &recipe.id, changeset)
+    let result = update_recipe(
+        unit_of_work.clone(),
+        None,
+        &recipe.id,
+        changeset,
+        &Precondition::Any,
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(result, UpdateRecipeError::ChangesetEmpty))
+}
+
+pub async fn updating_a_recipe_with_a_current_precondition_succeeds(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    unit_of_work: impl UnitOfWork,
+) {
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let recipe = recipe_fixture();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+    let stored = recipe_repo.get_by_id(&recipe.id).await.unwrap();
+
+    let result = update_recipe(
+        unit_of_work.clone(),
+        None,
+        &recipe.id,
+        UpdateRecipe {
+            visibility: None,
+            ..recipe_changeset()
+        },
+        &Precondition::Tags(vec![stored.entity_tag()]),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(result.name, "WE UPDATED THIS THING");
+}
+
+pub async fn updating_a_recipe_with_an_outdated_precondition_fails(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    unit_of_work: impl UnitOfWork,
+) {
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let recipe = recipe_fixture();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+
+    let result = update_recipe(
+        unit_of_work.clone(),
+        None,
+        &recipe.id,
+        UpdateRecipe {
+            visibility: None,
+            ..recipe_changeset()
+        },
+        &Precondition::Tags(vec!["outdated".to_string()]),
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(result, UpdateRecipeError::PreconditionFailed(id) if id == recipe.id));
+
+    let stored = recipe_repo.get_by_id(&recipe.id).await.unwrap();
+    assert_eq!(stored.name, recipe.name);
+}
+
+pub async fn updating_a_recipe_reviewed_in_the_meantime_fails(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+    unit_of_work: impl UnitOfWork,
+) {
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
+    insert_reviews(&repo, &ingredient_repo, &user_repo, &[]).await;
+    let recipe = repo.get_by_id(&recipe_fixture().id).await.unwrap();
+    let precondition = Precondition::Tags(vec![recipe.entity_tag()]);
+
+    // Reviews leave the recipe itself alone, but not what clients see of it
+    repo.add_review(&recipe, review_fixture()).await.unwrap();
+
+    let result = update_recipe(
+        unit_of_work.clone(),
+        None,
+        &recipe.id,
+        UpdateRecipe {
+            visibility: None,
+            ..recipe_changeset()
+        },
+        &precondition,
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(result, UpdateRecipeError::PreconditionFailed(id) if id == recipe.id));
+}
+
+pub async fn concurrent_updates_with_the_same_precondition_let_only_one_through(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    unit_of_work: impl UnitOfWork,
+) {
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
+    let recipe = recipe_fixture();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    repo.insert(recipe.clone()).await.unwrap();
+    let stored = repo.get_by_id(&recipe.id).await.unwrap();
+    let precondition = Precondition::Tags(vec![stored.entity_tag()]);
+
+    let update = |name: &str| {
+        update_recipe(
+            unit_of_work.clone(),
+            None,
+            &recipe.id,
+            UpdateRecipe {
+                name: Some(name.to_string()),
+                ..Default::default()
+            },
+            &precondition,
+        )
+    };
+    let (first, second) = tokio::join!(update("FIRST"), update("SECOND"));
+
+    // Whichever came second has to see the change of the other one
+    let (updated, error) = match (first, second) {
+        (Ok(updated), Err(error)) | (Err(error), Ok(updated)) => (updated, error),
+        (first, second) => panic!("Expected exactly one update to fail: {first:?}, {second:?}"),
+    };
+    assert!(matches!(error, UpdateRecipeError::PreconditionFailed(id) if id == recipe.id));
+    assert_eq!(repo.get_by_id(&recipe.id).await.unwrap().name, updated.name);
+}
+
+pub async fn editing_a_recipe_of_someone_else_errors(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+    unit_of_work: impl UnitOfWork,
+) {
+    let unit_of_work: UnitOfWorkService = Arc::new(Box::new(unit_of_work));
+    let recipe_repo = repo;
+    let public = Recipe {
+        owner_id: Some(user_fixture().id),
+        ..recipe_fixture()
+    };
+    let private = Recipe {
+        visibility: Visibility::Private,
+        owner_id: Some(user_fixture().id),
+        ..another_recipe_fixture(1)
+    };
+    insert_users(&user_repo).await;
+    insert_all_ingredients_of_recipe(&ingredient_repo, &public).await;
+    recipe_repo.insert(public.clone()).await.unwrap();
+    recipe_repo.insert(private.clone()).await.unwrap();
+    let changeset = || UpdateRecipe {
+        name: Some("WE UPDATED THIS THING".to_string()),
+        ..Default::default()
+    };
+
+    let error = update_recipe(
+        unit_of_work.clone(),
+        Some(&another_user_fixture().id),
+        &private.id,
+        changeset(),
+        &Precondition::Any,
+    )
+    .await
+    .unwrap_err();
+    assert!(matches!(error, UpdateRecipeError::NotFound(id) if id == private.id));
+
+    // Even when only the name changes, the recipe is not theirs to change
+    for user_id in [None, Some(&another_user_fixture().id)] {
+        let error = update_recipe(
+            unit_of_work.clone(),
+            user_id,
+            &public.id,
+            changeset(),
+            &Precondition::Any,
+        )
         .await
         .unwrap_err();
+        assert!(matches!(error, UpdateRecipeError::NotOwner(id) if id == public.id));
+    }
 
-    assert!(matches!(result, UpdateRecipeError::ChangesetEmpty))
+    assert_eq!(
+        recipe_repo.get_by_id(&public.id).await.unwrap().name,
+        public.name
+    );
 }
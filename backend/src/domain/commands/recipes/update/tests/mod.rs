@@ -2,7 +2,8 @@ mod __tests__;
 mod in_memory {
     use crate::domain::repositories::{
         ingredients::in_memory::InMemoryIngredientRepository,
-        recipe::in_memory::InMemoryRecipeRepository,
+        recipe::in_memory::InMemoryRecipeRepository, unit_of_work::in_memory::InMemoryUnitOfWork,
+        user::in_memory::InMemoryUserRepository,
     };
 
     use super::__tests__;
@@ -11,20 +12,100 @@ mod in_memory {
     async fn updating_a_recipe_succeeds() {
         let ingredient_repo = InMemoryIngredientRepository::new();
         let repo = InMemoryRecipeRepository::new();
-        __tests__::updating_a_recipe_succeeds(repo, ingredient_repo).await
+        let user_repo = InMemoryUserRepository::new();
+        let unit_of_work = InMemoryUnitOfWork::new(ingredient_repo.clone(), repo.clone());
+        __tests__::updating_a_recipe_succeeds(repo, ingredient_repo, user_repo, unit_of_work).await
     }
 
     #[tokio::test]
-    async fn updating_a_nonexistent_recipe_fails() {
+    async fn updating_a_recipe_reviewed_in_the_meantime_fails() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
         let repo = InMemoryRecipeRepository::new();
-        __tests__::updating_a_nonexistent_recipe_fails(repo).await
+        let user_repo = InMemoryUserRepository::new();
+        let unit_of_work = InMemoryUnitOfWork::new(ingredient_repo.clone(), repo.clone());
+        __tests__::updating_a_recipe_reviewed_in_the_meantime_fails(
+            repo,
+            ingredient_repo,
+            user_repo,
+            unit_of_work,
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn updating_a_nonexistent_recipe_fails() {
+        let unit_of_work = InMemoryUnitOfWork::new(
+            InMemoryIngredientRepository::new(),
+            InMemoryRecipeRepository::new(),
+        );
+        __tests__::updating_a_nonexistent_recipe_fails(unit_of_work).await
     }
 
     #[tokio::test]
     async fn updating_a_recipe_with_empty_changeset_does_nothing() {
         let ingredient_repo = InMemoryIngredientRepository::new();
         let repo = InMemoryRecipeRepository::new();
-        __tests__::updating_a_recipe_with_empty_changeset_errors(repo, ingredient_repo).await
+        let unit_of_work = InMemoryUnitOfWork::new(ingredient_repo.clone(), repo.clone());
+        __tests__::updating_a_recipe_with_empty_changeset_errors(
+            repo,
+            ingredient_repo,
+            unit_of_work,
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn updating_a_recipe_with_a_current_precondition_succeeds() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        let unit_of_work = InMemoryUnitOfWork::new(ingredient_repo.clone(), repo.clone());
+        __tests__::updating_a_recipe_with_a_current_precondition_succeeds(
+            repo,
+            ingredient_repo,
+            unit_of_work,
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn updating_a_recipe_with_an_outdated_precondition_fails() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        let unit_of_work = InMemoryUnitOfWork::new(ingredient_repo.clone(), repo.clone());
+        __tests__::updating_a_recipe_with_an_outdated_precondition_fails(
+            repo,
+            ingredient_repo,
+            unit_of_work,
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn concurrent_updates_with_the_same_precondition_let_only_one_through() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        let unit_of_work = InMemoryUnitOfWork::new(ingredient_repo.clone(), repo.clone());
+        __tests__::concurrent_updates_with_the_same_precondition_let_only_one_through(
+            repo,
+            ingredient_repo,
+            unit_of_work,
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn editing_a_recipe_of_someone_else_errors() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        let unit_of_work = InMemoryUnitOfWork::new(ingredient_repo.clone(), repo.clone());
+        __tests__::editing_a_recipe_of_someone_else_errors(
+            repo,
+            ingredient_repo,
+            user_repo,
+            unit_of_work,
+        )
+        .await
     }
 }
 
@@ -34,26 +115,102 @@ mod sql {
 
     use crate::domain::repositories::{
         ingredients::postgres::PostgresIngredientRepository,
-        recipe::postgres::PostgresRecipeRepository,
+        recipe::postgres::PostgresRecipeRepository, unit_of_work::postgres::PostgresUnitOfWork,
+        user::postgres::PostgresUserRepository,
     };
 
     #[sqlx::test]
     async fn updating_a_recipe_succeeds(pool: PgPool) {
         let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
-        let repo = PostgresRecipeRepository::new(pool);
-        __tests__::updating_a_recipe_succeeds(repo, ingredient_repo).await
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        __tests__::updating_a_recipe_succeeds(
+            repo,
+            ingredient_repo,
+            user_repo,
+            PostgresUnitOfWork::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn updating_a_recipe_reviewed_in_the_meantime_fails(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        __tests__::updating_a_recipe_reviewed_in_the_meantime_fails(
+            repo,
+            ingredient_repo,
+            user_repo,
+            PostgresUnitOfWork::new(pool),
+        )
+        .await
     }
 
     #[sqlx::test]
     async fn updating_a_nonexistent_recipe_fails(pool: PgPool) {
-        let repo = PostgresRecipeRepository::new(pool);
-        __tests__::updating_a_nonexistent_recipe_fails(repo).await
+        __tests__::updating_a_nonexistent_recipe_fails(PostgresUnitOfWork::new(pool)).await
     }
 
     #[sqlx::test]
     async fn updating_a_recipe_with_empty_changeset_does_nothing(pool: PgPool) {
         let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
-        let repo = PostgresRecipeRepository::new(pool);
-        __tests__::updating_a_recipe_with_empty_changeset_errors(repo, ingredient_repo).await
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        __tests__::updating_a_recipe_with_empty_changeset_errors(
+            repo,
+            ingredient_repo,
+            PostgresUnitOfWork::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn updating_a_recipe_with_a_current_precondition_succeeds(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        __tests__::updating_a_recipe_with_a_current_precondition_succeeds(
+            repo,
+            ingredient_repo,
+            PostgresUnitOfWork::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn updating_a_recipe_with_an_outdated_precondition_fails(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        __tests__::updating_a_recipe_with_an_outdated_precondition_fails(
+            repo,
+            ingredient_repo,
+            PostgresUnitOfWork::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn concurrent_updates_with_the_same_precondition_let_only_one_through(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        __tests__::concurrent_updates_with_the_same_precondition_let_only_one_through(
+            repo,
+            ingredient_repo,
+            PostgresUnitOfWork::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn editing_a_recipe_of_someone_else_errors(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        __tests__::editing_a_recipe_of_someone_else_errors(
+            repo,
+            ingredient_repo,
+            user_repo,
+            PostgresUnitOfWork::new(pool),
+        )
+        .await
     }
 }
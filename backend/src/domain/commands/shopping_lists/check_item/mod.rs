@@ -0,0 +1,63 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::shopping_list::ShoppingList,
+    repositories::shopping_list::{
+        errors::{GetShoppingListByIdError, UpdateShoppingListItemError},
+        ShoppingListRepositoryService,
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum CheckShoppingListItemError {
+    #[error("Could not find the shopping list with the ID {0}")]
+    NotFound(Uuid),
+
+    #[error("The shopping list does not have an item with the ID {0}")]
+    ItemNotFound(Uuid),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Report),
+}
+
+impl From<GetShoppingListByIdError> for CheckShoppingListItemError {
+    fn from(value: GetShoppingListByIdError) -> Self {
+        match value {
+            GetShoppingListByIdError::NotFound(id) => Self::NotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<UpdateShoppingListItemError> for CheckShoppingListItemError {
+    fn from(value: UpdateShoppingListItemError) -> Self {
+        match value {
+            UpdateShoppingListItemError::NotFound(id) => Self::ItemNotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+#[tracing::instrument(
+    "[COMMAND] Checking off a shopping list item",
+    skip(shopping_list_repo)
+)]
+pub async fn check_shopping_list_item(
+    shopping_list_repo: ShoppingListRepositoryService,
+    shopping_list_id: &Uuid,
+    item_id: &Uuid,
+    checked: bool,
+) -> Result<ShoppingList, CheckShoppingListItemError> {
+    let shopping_list = shopping_list_repo.get_by_id(shopping_list_id).await?;
+
+    shopping_list_repo
+        .set_item_checked(&shopping_list, item_id, checked)
+        .await?;
+
+    let shopping_list = shopping_list_repo.get_by_id(shopping_list_id).await?;
+
+    Ok(shopping_list)
+}
+
+#[cfg(test)]
+mod tests;
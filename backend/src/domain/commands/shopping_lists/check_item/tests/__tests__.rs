@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        commands::shopping_lists::check_item::{
+            check_shopping_list_item, CheckShoppingListItemError,
+        },
+        repositories::{
+            ingredients::IngredientRepository,
+            shopping_list::{ShoppingListRepository, ShoppingListRepositoryService},
+        },
+    },
+    test_utils::{insert_all_ingredients_of_recipe, recipe_fixture, shopping_list_fixture},
+};
+
+pub async fn checking_off_an_item_works(
+    shopping_list_repo: impl ShoppingListRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let shopping_list = shopping_list_fixture();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe_fixture()).await;
+    shopping_list_repo
+        .insert(shopping_list.clone())
+        .await
+        .unwrap();
+
+    let shopping_list_repo: ShoppingListRepositoryService = Arc::new(Box::new(shopping_list_repo));
+    let item = &shopping_list.items[1];
+
+    let result = check_shopping_list_item(
+        shopping_list_repo.clone(),
+        &shopping_list.id,
+        &item.id,
+        true,
+    )
+    .await
+    .unwrap();
+
+    assert!(result.items[1].checked);
+    assert!(!result.items[0].checked);
+    assert!(result.updated_at > shopping_list.updated_at);
+
+    let result = check_shopping_list_item(shopping_list_repo, &shopping_list.id, &item.id, false)
+        .await
+        .unwrap();
+
+    assert!(result.items.iter().all(|i| !i.checked));
+}
+
+pub async fn checking_off_a_nonexistent_item_errors(
+    shopping_list_repo: impl ShoppingListRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let shopping_list = shopping_list_fixture();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe_fixture()).await;
+    shopping_list_repo
+        .insert(shopping_list.clone())
+        .await
+        .unwrap();
+
+    let shopping_list_repo: ShoppingListRepositoryService = Arc::new(Box::new(shopping_list_repo));
+    let id = Uuid::now_v7();
+
+    let error = check_shopping_list_item(shopping_list_repo.clone(), &shopping_list.id, &id, true)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, CheckShoppingListItemError::ItemNotFound(e) if e == id));
+
+    let error = check_shopping_list_item(shopping_list_repo, &id, &id, true)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, CheckShoppingListItemError::NotFound(e) if e == id));
+}
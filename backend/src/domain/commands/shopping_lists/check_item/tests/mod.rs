@@ -0,0 +1,55 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::{
+        ingredients::in_memory::InMemoryIngredientRepository,
+        shopping_list::in_memory::InMemoryShoppingListRepository,
+    };
+
+    #[tokio::test]
+    async fn checking_off_an_item_works() {
+        __tests__::checking_off_an_item_works(
+            InMemoryShoppingListRepository::new(),
+            InMemoryIngredientRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn checking_off_a_nonexistent_item_errors() {
+        __tests__::checking_off_a_nonexistent_item_errors(
+            InMemoryShoppingListRepository::new(),
+            InMemoryIngredientRepository::new(),
+        )
+        .await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        ingredients::postgres::PostgresIngredientRepository,
+        shopping_list::postgres::PostgresShoppingListRepository,
+    };
+
+    #[sqlx::test]
+    async fn checking_off_an_item_works(pool: PgPool) {
+        __tests__::checking_off_an_item_works(
+            PostgresShoppingListRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn checking_off_a_nonexistent_item_errors(pool: PgPool) {
+        __tests__::checking_off_a_nonexistent_item_errors(
+            PostgresShoppingListRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool),
+        )
+        .await
+    }
+}
@@ -0,0 +1,107 @@
+use chrono::Utc;
+use common::CreateShoppingListDTO;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        recipe::errors::ValidationError,
+        shopping_list::{items_from_recipes, ShoppingList},
+    },
+    repositories::{
+        recipe::{errors::GetRecipeByIdError, RecipeRepositoryService},
+        shopping_list::{errors::InsertShoppingListError, ShoppingListRepositoryService},
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum CreateShoppingListError {
+    #[error("Could not find the recipe with the ID {0}")]
+    RecipeNotFound(Uuid),
+
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Report),
+}
+
+impl From<GetRecipeByIdError> for CreateShoppingListError {
+    fn from(value: GetRecipeByIdError) -> Self {
+        match value {
+            GetRecipeByIdError::NotFound(id) => Self::RecipeNotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<InsertShoppingListError> for CreateShoppingListError {
+    fn from(value: InsertShoppingListError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateShoppingList {
+    pub name: String,
+    /// Recipes to shop for, along with how many servings to make. `None` keeps the servings of the recipe.
+    pub recipes: Vec<(Uuid, Option<u16>)>,
+    pub exclude_optional: bool,
+}
+
+impl From<CreateShoppingListDTO> for CreateShoppingList {
+    fn from(value: CreateShoppingListDTO) -> Self {
+        Self {
+            name: value.name,
+            recipes: value
+                .recipes
+                .into_iter()
+                .map(|r| (r.recipe_id, r.servings))
+                .collect(),
+            exclude_optional: value.exclude_optional,
+        }
+    }
+}
+
+#[tracing::instrument(
+    "[COMMAND] Creating a new shopping list",
+    skip(shopping_list_repo, recipe_repo)
+)]
+pub async fn create_shopping_list(
+    shopping_list_repo: ShoppingListRepositoryService,
+    recipe_repo: RecipeRepositoryService,
+    user_id: Option<&Uuid>,
+    input: &CreateShoppingList,
+) -> Result<ShoppingList, CreateShoppingListError> {
+    let mut empty_fields = vec![];
+    if input.name.trim().is_empty() {
+        empty_fields.push("name");
+    }
+    if input.recipes.is_empty() {
+        empty_fields.push("recipes");
+    }
+    if !empty_fields.is_empty() {
+        return Err(ValidationError::EmptyField(empty_fields).into());
+    }
+
+    let mut recipes = vec![];
+    for (id, servings) in &input.recipes {
+        recipes.push((recipe_repo.get_visible(user_id, id, None).await?, *servings));
+    }
+
+    let now = Utc::now();
+    let shopping_list = ShoppingList {
+        id: Uuid::now_v7(),
+        name: input.name.trim().to_string(),
+        recipe_ids: input.recipes.iter().map(|(id, _)| *id).collect(),
+        items: items_from_recipes(&recipes, input.exclude_optional),
+        created_at: now,
+        updated_at: now,
+    };
+
+    shopping_list_repo.insert(shopping_list.clone()).await?;
+
+    Ok(shopping_list)
+}
+
+#[cfg(test)]
+mod tests;
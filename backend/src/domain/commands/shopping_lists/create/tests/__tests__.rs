@@ -0,0 +1,141 @@
+use std::sync::Arc;
+
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        commands::shopping_lists::create::{
+            create_shopping_list, CreateShoppingList, CreateShoppingListError,
+        },
+        entities::recipe::{errors::ValidationError, ServingsType},
+        repositories::{
+            ingredients::IngredientRepository,
+            recipe::{RecipeRepository, RecipeRepositoryService},
+            shopping_list::{ShoppingListRepository, ShoppingListRepositoryService},
+            user::UserRepository,
+        },
+    },
+    test_utils::{
+        another_user_fixture, insert_all_ingredients_of_recipe, insert_users,
+        private_recipe_fixture, recipe_fixture,
+    },
+};
+
+pub async fn creating_a_shopping_list_works(
+    shopping_list_repo: impl ShoppingListRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let mut recipe = recipe_fixture();
+    recipe.servings = ServingsType::Exact(2);
+    recipe.ingredients[2].optional = true;
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+
+    let shopping_list_repo: ShoppingListRepositoryService = Arc::new(Box::new(shopping_list_repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let input = CreateShoppingList {
+        name: "Weekend".to_string(),
+        recipes: vec![(recipe.id, Some(4)), (recipe.id, None)],
+        exclude_optional: true,
+    };
+
+    let result = create_shopping_list(shopping_list_repo.clone(), recipe_repo, None, &input)
+        .await
+        .unwrap();
+
+    assert_eq!(result.name, "Weekend");
+    assert_eq!(result.recipe_ids, vec![recipe.id, recipe.id]);
+    assert_eq!(result.items.len(), 2);
+    assert_eq!(result.items[0].ingredient, recipe.ingredients[0].ingredient);
+    assert_eq!(
+        result.items[0].amount,
+        recipe.ingredients[0].amount.scale(3.0)
+    );
+
+    let saved = shopping_list_repo.get_by_id(&result.id).await.unwrap();
+
+    assert_eq!(saved.items, result.items);
+}
+
+pub async fn creating_a_shopping_list_for_a_nonexistent_recipe_errors(
+    shopping_list_repo: impl ShoppingListRepository,
+    recipe_repo: impl RecipeRepository,
+) {
+    let shopping_list_repo: ShoppingListRepositoryService = Arc::new(Box::new(shopping_list_repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+    let id = Uuid::now_v7();
+
+    let input = CreateShoppingList {
+        name: "Weekend".to_string(),
+        recipes: vec![(id, None)],
+        exclude_optional: false,
+    };
+
+    let error = create_shopping_list(shopping_list_repo, recipe_repo, None, &input)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, CreateShoppingListError::RecipeNotFound(e) if e == id));
+}
+
+pub async fn creating_an_empty_shopping_list_errors(
+    shopping_list_repo: impl ShoppingListRepository,
+    recipe_repo: impl RecipeRepository,
+) {
+    let shopping_list_repo: ShoppingListRepositoryService = Arc::new(Box::new(shopping_list_repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let input = CreateShoppingList {
+        name: " ".to_string(),
+        recipes: vec![],
+        exclude_optional: false,
+    };
+
+    let error = create_shopping_list(shopping_list_repo, recipe_repo, None, &input)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        error,
+        CreateShoppingListError::ValidationError(ValidationError::EmptyField(fields)) if fields == vec!["name", "recipes"]
+    ));
+}
+
+pub async fn creating_a_shopping_list_for_a_private_recipe_of_someone_else_errors(
+    shopping_list_repo: impl ShoppingListRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let recipe = private_recipe_fixture();
+    insert_users(&user_repo).await;
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+
+    let shopping_list_repo: ShoppingListRepositoryService = Arc::new(Box::new(shopping_list_repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let input = CreateShoppingList {
+        name: "Weekend".to_string(),
+        recipes: vec![(recipe.id, None)],
+        exclude_optional: false,
+    };
+
+    let error = create_shopping_list(
+        shopping_list_repo.clone(),
+        recipe_repo.clone(),
+        Some(&another_user_fixture().id),
+        &input,
+    )
+    .await
+    .unwrap_err();
+    assert!(matches!(error, CreateShoppingListError::RecipeNotFound(e) if e == recipe.id));
+
+    let error = create_shopping_list(shopping_list_repo, recipe_repo, None, &input)
+        .await
+        .unwrap_err();
+    assert!(matches!(error, CreateShoppingListError::RecipeNotFound(e) if e == recipe.id));
+}
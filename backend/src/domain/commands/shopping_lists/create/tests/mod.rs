@@ -0,0 +1,101 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::{
+        ingredients::in_memory::InMemoryIngredientRepository,
+        recipe::in_memory::InMemoryRecipeRepository,
+        shopping_list::in_memory::InMemoryShoppingListRepository,
+        user::in_memory::InMemoryUserRepository,
+    };
+
+    #[tokio::test]
+    async fn creating_a_shopping_list_works() {
+        __tests__::creating_a_shopping_list_works(
+            InMemoryShoppingListRepository::new(),
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn creating_a_shopping_list_for_a_nonexistent_recipe_errors() {
+        __tests__::creating_a_shopping_list_for_a_nonexistent_recipe_errors(
+            InMemoryShoppingListRepository::new(),
+            InMemoryRecipeRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn creating_an_empty_shopping_list_errors() {
+        __tests__::creating_an_empty_shopping_list_errors(
+            InMemoryShoppingListRepository::new(),
+            InMemoryRecipeRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn creating_a_shopping_list_for_a_private_recipe_of_someone_else_errors() {
+        __tests__::creating_a_shopping_list_for_a_private_recipe_of_someone_else_errors(
+            InMemoryShoppingListRepository::new(),
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+            InMemoryUserRepository::new(),
+        )
+        .await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        ingredients::postgres::PostgresIngredientRepository,
+        recipe::postgres::PostgresRecipeRepository,
+        shopping_list::postgres::PostgresShoppingListRepository,
+        user::postgres::PostgresUserRepository,
+    };
+
+    #[sqlx::test]
+    async fn creating_a_shopping_list_works(pool: PgPool) {
+        __tests__::creating_a_shopping_list_works(
+            PostgresShoppingListRepository::new(pool.clone()),
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn creating_a_shopping_list_for_a_nonexistent_recipe_errors(pool: PgPool) {
+        __tests__::creating_a_shopping_list_for_a_nonexistent_recipe_errors(
+            PostgresShoppingListRepository::new(pool.clone()),
+            PostgresRecipeRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn creating_an_empty_shopping_list_errors(pool: PgPool) {
+        __tests__::creating_an_empty_shopping_list_errors(
+            PostgresShoppingListRepository::new(pool.clone()),
+            PostgresRecipeRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn creating_a_shopping_list_for_a_private_recipe_of_someone_else_errors(pool: PgPool) {
+        __tests__::creating_a_shopping_list_for_a_private_recipe_of_someone_else_errors(
+            PostgresShoppingListRepository::new(pool.clone()),
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresUserRepository::new(pool),
+        )
+        .await
+    }
+}
@@ -0,0 +1,47 @@
+use uuid::Uuid;
+
+use crate::domain::repositories::shopping_list::{
+    errors::{
+        DeleteShoppingListError as DeleteShoppingListErrorInternal, GetShoppingListByIdError,
+    },
+    ShoppingListRepositoryService,
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum DeleteShoppingListError {
+    #[error("Could not find the shopping list with the ID {0}")]
+    NotFound(Uuid),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<DeleteShoppingListErrorInternal> for DeleteShoppingListError {
+    fn from(value: DeleteShoppingListErrorInternal) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+impl From<GetShoppingListByIdError> for DeleteShoppingListError {
+    fn from(value: GetShoppingListByIdError) -> Self {
+        match value {
+            GetShoppingListByIdError::NotFound(id) => Self::NotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+#[tracing::instrument("[COMMAND] Deleting a shopping list", skip(shopping_list_repo))]
+pub async fn delete_shopping_list(
+    shopping_list_repo: ShoppingListRepositoryService,
+    id: &Uuid,
+) -> Result<(), DeleteShoppingListError> {
+    let shopping_list = shopping_list_repo.get_by_id(id).await?;
+
+    shopping_list_repo.delete(&shopping_list).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,36 @@
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        commands::shopping_lists::delete::{delete_shopping_list, DeleteShoppingListError},
+        repositories::{
+            ingredients::IngredientRepository,
+            shopping_list::{ShoppingListRepository, ShoppingListRepositoryService},
+        },
+    },
+    test_utils::{insert_all_ingredients_of_recipe, recipe_fixture, shopping_list_fixture},
+};
+
+pub async fn deleting_a_shopping_list_succeeds(
+    shopping_list_repo: impl ShoppingListRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let shopping_list = shopping_list_fixture();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe_fixture()).await;
+    shopping_list_repo
+        .insert(shopping_list.clone())
+        .await
+        .unwrap();
+
+    let shopping_list_repo: ShoppingListRepositoryService = Arc::new(Box::new(shopping_list_repo));
+
+    delete_shopping_list(shopping_list_repo.clone(), &shopping_list.id)
+        .await
+        .unwrap();
+
+    let error = delete_shopping_list(shopping_list_repo, &shopping_list.id)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, DeleteShoppingListError::NotFound(id) if id == shopping_list.id));
+}
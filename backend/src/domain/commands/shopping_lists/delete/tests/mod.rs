@@ -0,0 +1,37 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::{
+        ingredients::in_memory::InMemoryIngredientRepository,
+        shopping_list::in_memory::InMemoryShoppingListRepository,
+    };
+
+    #[tokio::test]
+    async fn deleting_a_shopping_list_succeeds() {
+        __tests__::deleting_a_shopping_list_succeeds(
+            InMemoryShoppingListRepository::new(),
+            InMemoryIngredientRepository::new(),
+        )
+        .await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        ingredients::postgres::PostgresIngredientRepository,
+        shopping_list::postgres::PostgresShoppingListRepository,
+    };
+
+    #[sqlx::test]
+    async fn deleting_a_shopping_list_succeeds(pool: PgPool) {
+        __tests__::deleting_a_shopping_list_succeeds(
+            PostgresShoppingListRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool),
+        )
+        .await
+    }
+}
@@ -0,0 +1,3 @@
+pub mod check_item;
+pub mod create;
+pub mod delete;
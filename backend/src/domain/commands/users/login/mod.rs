@@ -0,0 +1,60 @@
+use crate::domain::{
+    entities::user::{LoggedInUser, Session},
+    repositories::{
+        session::{errors::InsertSessionError, SessionRepositoryService},
+        user::{errors::GetUserError, UserRepositoryService},
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum LoginError {
+    /// Whether the username or the password was wrong is not told apart,
+    /// so that nobody can find out who has an account by trying to log in
+    #[error("The username or the password is wrong")]
+    InvalidCredentials,
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Report),
+}
+
+impl From<GetUserError> for LoginError {
+    fn from(value: GetUserError) -> Self {
+        match value {
+            GetUserError::NotFound => Self::InvalidCredentials,
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<InsertSessionError> for LoginError {
+    fn from(value: InsertSessionError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+/// Checks the password of the user and opens a new session for them
+#[tracing::instrument("[COMMAND] Logging in", skip(user_repo, session_repo, password))]
+pub async fn login(
+    user_repo: UserRepositoryService,
+    session_repo: SessionRepositoryService,
+    username: &str,
+    password: &str,
+) -> Result<LoggedInUser, LoginError> {
+    let user = user_repo.get_by_username(username.trim()).await?;
+
+    if !user.verify_password(password) {
+        return Err(LoginError::InvalidCredentials);
+    }
+
+    let (token, session) = Session::new(user.id);
+    session_repo.insert(&session).await?;
+
+    Ok(LoggedInUser {
+        token,
+        user,
+        expires_at: session.expires_at,
+    })
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use pretty_assertions::assert_eq;
+
+use crate::{
+    domain::{
+        commands::users::login::{login, LoginError},
+        entities::user::Session,
+        repositories::{
+            session::{in_memory::InMemorySessionRepository, SessionRepositoryService},
+            user::{UserRepository, UserRepositoryService},
+        },
+    },
+    test_utils::user_fixture,
+};
+
+pub async fn logging_in_works(user_repo: impl UserRepository) {
+    let user = user_fixture();
+    user_repo.insert(&user).await.unwrap();
+    let user_repo: UserRepositoryService = Arc::new(Box::new(user_repo));
+    let session_repo: SessionRepositoryService = InMemorySessionRepository::new().service();
+
+    let first = login(user_repo.clone(), session_repo.clone(), "cook", "hunter2")
+        .await
+        .unwrap();
+    let second = login(user_repo.clone(), session_repo.clone(), "cook", "hunter2")
+        .await
+        .unwrap();
+
+    assert_eq!(first.user, user);
+    assert_ne!(first.token, second.token);
+
+    for logged_in in [first, second] {
+        let session = session_repo
+            .get(&Session::hash(&logged_in.token))
+            .await
+            .unwrap();
+
+        assert_eq!(session.user_id, user.id);
+        assert_eq!(session.expires_at, logged_in.expires_at);
+    }
+}
+
+pub async fn logging_in_with_wrong_credentials_errors(user_repo: impl UserRepository) {
+    let user = user_fixture();
+    user_repo.insert(&user).await.unwrap();
+    let user_repo: UserRepositoryService = Arc::new(Box::new(user_repo));
+    let session_repo: SessionRepositoryService = InMemorySessionRepository::new().service();
+
+    let error = login(user_repo.clone(), session_repo.clone(), "cook", "hunter3")
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, LoginError::InvalidCredentials));
+
+    let error = login(user_repo, session_repo, "nobody", "hunter2")
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, LoginError::InvalidCredentials));
+}
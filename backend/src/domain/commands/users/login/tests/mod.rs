@@ -0,0 +1,33 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::user::in_memory::InMemoryUserRepository;
+
+    #[tokio::test]
+    async fn logging_in_works() {
+        __tests__::logging_in_works(InMemoryUserRepository::new()).await
+    }
+
+    #[tokio::test]
+    async fn logging_in_with_wrong_credentials_errors() {
+        __tests__::logging_in_with_wrong_credentials_errors(InMemoryUserRepository::new()).await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::user::postgres::PostgresUserRepository;
+
+    #[sqlx::test]
+    async fn logging_in_works(pool: PgPool) {
+        __tests__::logging_in_works(PostgresUserRepository::new(pool)).await
+    }
+
+    #[sqlx::test]
+    async fn logging_in_with_wrong_credentials_errors(pool: PgPool) {
+        __tests__::logging_in_with_wrong_credentials_errors(PostgresUserRepository::new(pool)).await
+    }
+}
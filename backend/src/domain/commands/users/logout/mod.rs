@@ -0,0 +1,30 @@
+use crate::domain::{
+    entities::user::Session,
+    repositories::session::{errors::DeleteSessionError, SessionRepositoryService},
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum LogoutError {
+    #[error(transparent)]
+    Unknown(#[from] eyre::Report),
+}
+
+impl From<DeleteSessionError> for LogoutError {
+    fn from(value: DeleteSessionError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+/// Ends the session, so that its token can't be used anymore
+#[tracing::instrument("[COMMAND] Logging out", skip_all)]
+pub async fn logout(
+    session_repo: SessionRepositoryService,
+    session: &Session,
+) -> Result<(), LogoutError> {
+    session_repo.delete(&session.token_hash).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        commands::users::{login::login, logout::logout},
+        entities::user::Session,
+        repositories::{
+            session::{
+                errors::GetSessionError, in_memory::InMemorySessionRepository,
+                SessionRepositoryService,
+            },
+            user::{UserRepository, UserRepositoryService},
+        },
+    },
+    test_utils::user_fixture,
+};
+
+pub async fn logging_out_ends_the_session(user_repo: impl UserRepository) {
+    user_repo.insert(&user_fixture()).await.unwrap();
+    let user_repo: UserRepositoryService = Arc::new(Box::new(user_repo));
+    let session_repo: SessionRepositoryService = InMemorySessionRepository::new().service();
+
+    let first = login(user_repo.clone(), session_repo.clone(), "cook", "hunter2")
+        .await
+        .unwrap();
+    let second = login(user_repo, session_repo.clone(), "cook", "hunter2")
+        .await
+        .unwrap();
+    let session = session_repo
+        .get(&Session::hash(&first.token))
+        .await
+        .unwrap();
+
+    logout(session_repo.clone(), &session).await.unwrap();
+
+    let error = session_repo
+        .get(&Session::hash(&first.token))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, GetSessionError::NotFound));
+
+    // Other devices stay logged in
+    session_repo
+        .get(&Session::hash(&second.token))
+        .await
+        .unwrap();
+}
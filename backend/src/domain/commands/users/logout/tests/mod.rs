@@ -0,0 +1,23 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::user::in_memory::InMemoryUserRepository;
+
+    #[tokio::test]
+    async fn logging_out_ends_the_session() {
+        __tests__::logging_out_ends_the_session(InMemoryUserRepository::new()).await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::user::postgres::PostgresUserRepository;
+
+    #[sqlx::test]
+    async fn logging_out_ends_the_session(pool: PgPool) {
+        __tests__::logging_out_ends_the_session(PostgresUserRepository::new(pool)).await
+    }
+}
@@ -0,0 +1,42 @@
+use crate::domain::{
+    entities::{recipe::errors::ValidationError, user::User},
+    repositories::user::{errors::InsertUserError, UserRepositoryService},
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum SignupError {
+    #[error("The {0} is already taken")]
+    Taken(&'static str),
+
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Report),
+}
+
+impl From<InsertUserError> for SignupError {
+    fn from(value: InsertUserError) -> Self {
+        match value {
+            InsertUserError::Conflict(field) => Self::Taken(field),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+#[tracing::instrument("[COMMAND] Signing up", skip(user_repo, password))]
+pub async fn signup(
+    user_repo: UserRepositoryService,
+    username: &str,
+    email: &str,
+    password: &str,
+) -> Result<User, SignupError> {
+    let user = User::new(username, email, password)?;
+
+    user_repo.insert(&user).await?;
+
+    Ok(user)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,44 @@
+use std::sync::Arc;
+
+use pretty_assertions::assert_eq;
+
+use crate::domain::{
+    commands::users::signup::{signup, SignupError},
+    repositories::user::{UserRepository, UserRepositoryService},
+};
+
+pub async fn signing_up_works(user_repo: impl UserRepository) {
+    let user_repo: UserRepositoryService = Arc::new(Box::new(user_repo));
+
+    let user = signup(user_repo.clone(), "cook", "cook@example.com", "hunter2")
+        .await
+        .unwrap();
+
+    assert_eq!(user.username, "cook");
+    assert!(user.verify_password("hunter2"));
+
+    let stored = user_repo.get_by_username("cook").await.unwrap();
+
+    assert_eq!(stored.id, user.id);
+    assert_eq!(stored.email, user.email);
+    assert_eq!(stored.password_hash, user.password_hash);
+}
+
+pub async fn signing_up_with_a_taken_username_or_email_errors(user_repo: impl UserRepository) {
+    let user_repo: UserRepositoryService = Arc::new(Box::new(user_repo));
+    signup(user_repo.clone(), "cook", "cook@example.com", "hunter2")
+        .await
+        .unwrap();
+
+    let error = signup(user_repo.clone(), "cook", "other@example.com", "hunter2")
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, SignupError::Taken("username")));
+
+    let error = signup(user_repo, "other", "cook@example.com", "hunter2")
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, SignupError::Taken("email")));
+}
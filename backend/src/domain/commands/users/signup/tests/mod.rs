@@ -0,0 +1,37 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::user::in_memory::InMemoryUserRepository;
+
+    #[tokio::test]
+    async fn signing_up_works() {
+        __tests__::signing_up_works(InMemoryUserRepository::new()).await
+    }
+
+    #[tokio::test]
+    async fn signing_up_with_a_taken_username_or_email_errors() {
+        __tests__::signing_up_with_a_taken_username_or_email_errors(InMemoryUserRepository::new())
+            .await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::user::postgres::PostgresUserRepository;
+
+    #[sqlx::test]
+    async fn signing_up_works(pool: PgPool) {
+        __tests__::signing_up_works(PostgresUserRepository::new(pool)).await
+    }
+
+    #[sqlx::test]
+    async fn signing_up_with_a_taken_username_or_email_errors(pool: PgPool) {
+        __tests__::signing_up_with_a_taken_username_or_email_errors(PostgresUserRepository::new(
+            pool,
+        ))
+        .await
+    }
+}
@@ -0,0 +1,118 @@
+use chrono::Utc;
+use common::CreateWebhookDTO;
+use reqwest::Url;
+use strum::VariantNames;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{event::DomainEvent, recipe::errors::ValidationError, webhook::Webhook},
+    repositories::webhook::{
+        errors::{GetWebhookError, InsertWebhookError},
+        WebhookRepositoryService,
+    },
+    services::webhooks::WebhookTargets,
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum CreateWebhookError {
+    #[error("{0} is not a valid HTTP or HTTPS URL")]
+    InvalidUrl(String),
+
+    #[error("{0} does not point to a public address")]
+    NotPublic(String),
+
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Report),
+}
+
+impl From<InsertWebhookError> for CreateWebhookError {
+    fn from(value: InsertWebhookError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+impl From<GetWebhookError> for CreateWebhookError {
+    fn from(value: GetWebhookError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateWebhook {
+    pub url: String,
+    pub secret: String,
+    pub event_types: Vec<String>,
+}
+
+impl From<CreateWebhookDTO> for CreateWebhook {
+    fn from(value: CreateWebhookDTO) -> Self {
+        Self {
+            url: value.url,
+            secret: value.secret,
+            event_types: value.event_types,
+        }
+    }
+}
+
+/// The webhook belongs to the user registering it, and only gets to point to the addresses
+/// the targets allow
+#[tracing::instrument("[COMMAND] Registering a webhook", skip(webhook_repo, input))]
+pub async fn create_webhook(
+    webhook_repo: WebhookRepositoryService,
+    targets: WebhookTargets,
+    user_id: &Uuid,
+    input: &CreateWebhook,
+) -> Result<Webhook, CreateWebhookError> {
+    let url = input.url.trim();
+    let parsed = match Url::parse(url) {
+        Ok(parsed) if ["http", "https"].contains(&parsed.scheme()) => parsed,
+        _ => return Err(CreateWebhookError::InvalidUrl(url.to_string())),
+    };
+
+    if input.secret.is_empty() {
+        return Err(ValidationError::EmptyField(vec!["secret"]).into());
+    }
+
+    if input.event_types.is_empty() {
+        return Err(ValidationError::EmptyField(vec!["event_types"]).into());
+    }
+
+    let mut event_types: Vec<String> = vec![];
+    for event_type in &input.event_types {
+        if !DomainEvent::VARIANTS.contains(&event_type.as_str()) {
+            return Err(ValidationError::DoesNotMatch("event_types", DomainEvent::VARIANTS).into());
+        }
+
+        if !event_types.contains(event_type) {
+            event_types.push(event_type.clone());
+        }
+    }
+
+    if let Err(e) = targets.resolve(&parsed).await {
+        tracing::info!("Refusing to register a webhook for {url}: {e}");
+        return Err(CreateWebhookError::NotPublic(url.to_string()));
+    }
+
+    let id = Uuid::now_v7();
+
+    webhook_repo
+        .insert(Webhook {
+            id,
+            url: url.to_string(),
+            secret: input.secret.clone(),
+            event_types,
+            owner_id: Some(*user_id),
+            created_at: Utc::now(),
+        })
+        .await?;
+
+    let webhook = webhook_repo.get_by_id(&id).await?;
+
+    Ok(webhook)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,145 @@
+use std::sync::Arc;
+
+use pretty_assertions::assert_eq;
+use strum::VariantNames;
+
+use crate::{
+    domain::{
+        commands::webhooks::create::{create_webhook, CreateWebhook, CreateWebhookError},
+        entities::{event::DomainEvent, recipe::errors::ValidationError},
+        repositories::{
+            user::UserRepository,
+            webhook::{WebhookRepository, WebhookRepositoryService},
+        },
+        services::webhooks::WebhookTargets,
+    },
+    test_utils::{insert_users, user_fixture},
+};
+
+fn input(url: &str, event_types: &[&str]) -> CreateWebhook {
+    CreateWebhook {
+        url: url.to_string(),
+        secret: "keep it secret".to_string(),
+        event_types: event_types.iter().map(|t| t.to_string()).collect(),
+    }
+}
+
+pub async fn creating_a_webhook_works(
+    webhook_repo: impl WebhookRepository,
+    user_repo: impl UserRepository,
+) {
+    insert_users(&user_repo).await;
+    let webhook_repo: WebhookRepositoryService = Arc::new(Box::new(webhook_repo));
+    let user = user_fixture();
+
+    let result = create_webhook(
+        webhook_repo.clone(),
+        WebhookTargets::Any,
+        &user.id,
+        &input(
+            " https://example.com/hooks ",
+            &["recipe_created", "recipe_deleted", "recipe_created"],
+        ),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(result.url, "https://example.com/hooks");
+    assert_eq!(result.event_types, vec!["recipe_created", "recipe_deleted"]);
+    assert_eq!(result.owner_id, Some(user.id));
+
+    let saved = webhook_repo.get_by_id(&result.id).await.unwrap();
+
+    assert_eq!(saved, result);
+}
+
+pub async fn creating_a_webhook_with_an_invalid_url_fails(webhook_repo: impl WebhookRepository) {
+    let webhook_repo: WebhookRepositoryService = Arc::new(Box::new(webhook_repo));
+
+    for url in ["example.com/hooks", "ftp://example.com/hooks"] {
+        let error = create_webhook(
+            webhook_repo.clone(),
+            WebhookTargets::Any,
+            &user_fixture().id,
+            &input(url, &["recipe_created"]),
+        )
+        .await
+        .unwrap_err();
+
+        assert!(matches!(error, CreateWebhookError::InvalidUrl(u) if u == url));
+    }
+
+    assert!(webhook_repo.get_all().await.unwrap().is_empty());
+}
+
+pub async fn creating_a_webhook_for_a_private_address_fails(webhook_repo: impl WebhookRepository) {
+    let webhook_repo: WebhookRepositoryService = Arc::new(Box::new(webhook_repo));
+
+    for url in [
+        "http://localhost:8080/hooks",
+        "http://127.0.0.1/hooks",
+        "http://10.0.0.1/hooks",
+        "http://169.254.169.254/latest/meta-data",
+        "http://100.64.0.1/hooks",
+        "http://[::1]/hooks",
+        "http://[fd00::1]/hooks",
+        "http://[::ffff:192.168.0.1]/hooks",
+    ] {
+        let error = create_webhook(
+            webhook_repo.clone(),
+            WebhookTargets::Public,
+            &user_fixture().id,
+            &input(url, &["recipe_created"]),
+        )
+        .await
+        .unwrap_err();
+
+        assert!(
+            matches!(&error, CreateWebhookError::NotPublic(u) if u == url),
+            "{url}: {error:?}"
+        );
+    }
+
+    assert!(webhook_repo.get_all().await.unwrap().is_empty());
+}
+
+pub async fn creating_a_webhook_with_unknown_event_types_fails(
+    webhook_repo: impl WebhookRepository,
+) {
+    let webhook_repo: WebhookRepositoryService = Arc::new(Box::new(webhook_repo));
+
+    let error = create_webhook(
+        webhook_repo.clone(),
+        WebhookTargets::Any,
+        &user_fixture().id,
+        &input(
+            "https://example.com/hooks",
+            &["recipe_created", "recipe_eaten"],
+        ),
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(
+        error,
+        CreateWebhookError::ValidationError(ValidationError::DoesNotMatch("event_types", variants))
+            if variants == DomainEvent::VARIANTS
+    ));
+
+    let error = create_webhook(
+        webhook_repo.clone(),
+        WebhookTargets::Any,
+        &user_fixture().id,
+        &input("https://example.com/hooks", &[]),
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(
+        error,
+        CreateWebhookError::ValidationError(ValidationError::EmptyField(fields))
+            if fields == vec!["event_types"]
+    ));
+
+    assert!(webhook_repo.get_all().await.unwrap().is_empty());
+}
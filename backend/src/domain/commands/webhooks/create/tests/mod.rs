@@ -0,0 +1,79 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::{
+        user::in_memory::InMemoryUserRepository, webhook::in_memory::InMemoryWebhookRepository,
+    };
+
+    #[tokio::test]
+    async fn creating_a_webhook_works() {
+        __tests__::creating_a_webhook_works(
+            InMemoryWebhookRepository::new(),
+            InMemoryUserRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn creating_a_webhook_with_an_invalid_url_fails() {
+        __tests__::creating_a_webhook_with_an_invalid_url_fails(InMemoryWebhookRepository::new())
+            .await
+    }
+
+    #[tokio::test]
+    async fn creating_a_webhook_for_a_private_address_fails() {
+        __tests__::creating_a_webhook_for_a_private_address_fails(InMemoryWebhookRepository::new())
+            .await
+    }
+
+    #[tokio::test]
+    async fn creating_a_webhook_with_unknown_event_types_fails() {
+        __tests__::creating_a_webhook_with_unknown_event_types_fails(
+            InMemoryWebhookRepository::new(),
+        )
+        .await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        user::postgres::PostgresUserRepository, webhook::postgres::PostgresWebhookRepository,
+    };
+
+    #[sqlx::test]
+    async fn creating_a_webhook_works(pool: PgPool) {
+        __tests__::creating_a_webhook_works(
+            PostgresWebhookRepository::new(pool.clone()),
+            PostgresUserRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn creating_a_webhook_with_an_invalid_url_fails(pool: PgPool) {
+        __tests__::creating_a_webhook_with_an_invalid_url_fails(PostgresWebhookRepository::new(
+            pool,
+        ))
+        .await
+    }
+
+    #[sqlx::test]
+    async fn creating_a_webhook_for_a_private_address_fails(pool: PgPool) {
+        __tests__::creating_a_webhook_for_a_private_address_fails(PostgresWebhookRepository::new(
+            pool,
+        ))
+        .await
+    }
+
+    #[sqlx::test]
+    async fn creating_a_webhook_with_unknown_event_types_fails(pool: PgPool) {
+        __tests__::creating_a_webhook_with_unknown_event_types_fails(
+            PostgresWebhookRepository::new(pool),
+        )
+        .await
+    }
+}
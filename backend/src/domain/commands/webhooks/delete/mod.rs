@@ -0,0 +1,51 @@
+use uuid::Uuid;
+
+use crate::domain::repositories::webhook::{
+    errors::{DeleteWebhookError as DeleteWebhookErrorInternal, GetWebhookError},
+    WebhookRepositoryService,
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum DeleteWebhookError {
+    #[error("Could not find the webhook with the ID {0}")]
+    NotFound(Uuid),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Report),
+}
+
+impl From<GetWebhookError> for DeleteWebhookError {
+    fn from(value: GetWebhookError) -> Self {
+        match value {
+            GetWebhookError::NotFound(id) => Self::NotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<DeleteWebhookErrorInternal> for DeleteWebhookError {
+    fn from(value: DeleteWebhookErrorInternal) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+/// Stops the deliveries and forgets the delivery log of the webhook.
+/// Webhooks of someone else are as good as missing.
+#[tracing::instrument("[COMMAND] Deleting a webhook", skip(webhook_repo))]
+pub async fn delete_webhook(
+    webhook_repo: WebhookRepositoryService,
+    user_id: &Uuid,
+    webhook_id: &Uuid,
+) -> Result<(), DeleteWebhookError> {
+    let webhook = webhook_repo.get_by_id(webhook_id).await?;
+    if !webhook.is_managed_by(user_id) {
+        return Err(DeleteWebhookError::NotFound(*webhook_id));
+    }
+
+    webhook_repo.delete(&webhook).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests;
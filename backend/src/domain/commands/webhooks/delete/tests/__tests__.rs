@@ -0,0 +1,60 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        commands::webhooks::delete::{delete_webhook, DeleteWebhookError},
+        entities::webhook::Webhook,
+        repositories::{
+            user::UserRepository,
+            webhook::{errors::GetWebhookError, WebhookRepository, WebhookRepositoryService},
+        },
+    },
+    test_utils::{another_user_fixture, insert_users, user_fixture, webhook_fixture},
+};
+
+pub async fn deleting_a_webhook_works(webhook_repo: impl WebhookRepository) {
+    let webhook = webhook_fixture();
+    webhook_repo.insert(webhook.clone()).await.unwrap();
+    let webhook_repo: WebhookRepositoryService = Arc::new(Box::new(webhook_repo));
+
+    delete_webhook(webhook_repo.clone(), &user_fixture().id, &webhook.id)
+        .await
+        .unwrap();
+
+    let error = webhook_repo.get_by_id(&webhook.id).await.unwrap_err();
+
+    assert!(matches!(error, GetWebhookError::NotFound(id) if id == webhook.id));
+}
+
+pub async fn deleting_a_nonexistent_webhook_fails(webhook_repo: impl WebhookRepository) {
+    let webhook_repo: WebhookRepositoryService = Arc::new(Box::new(webhook_repo));
+    let id = Uuid::from_u128(404);
+
+    let error = delete_webhook(webhook_repo, &user_fixture().id, &id)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, DeleteWebhookError::NotFound(i) if i == id));
+}
+
+pub async fn deleting_a_webhook_of_someone_else_fails(
+    webhook_repo: impl WebhookRepository,
+    user_repo: impl UserRepository,
+) {
+    insert_users(&user_repo).await;
+    let webhook = Webhook {
+        owner_id: Some(another_user_fixture().id),
+        ..webhook_fixture()
+    };
+    webhook_repo.insert(webhook.clone()).await.unwrap();
+    let webhook_repo: WebhookRepositoryService = Arc::new(Box::new(webhook_repo));
+
+    let error = delete_webhook(webhook_repo.clone(), &user_fixture().id, &webhook.id)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, DeleteWebhookError::NotFound(id) if id == webhook.id));
+    assert_eq!(webhook_repo.get_by_id(&webhook.id).await.unwrap(), webhook);
+}
@@ -0,0 +1,55 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::{
+        user::in_memory::InMemoryUserRepository, webhook::in_memory::InMemoryWebhookRepository,
+    };
+
+    #[tokio::test]
+    async fn deleting_a_webhook_works() {
+        __tests__::deleting_a_webhook_works(InMemoryWebhookRepository::new()).await
+    }
+
+    #[tokio::test]
+    async fn deleting_a_nonexistent_webhook_fails() {
+        __tests__::deleting_a_nonexistent_webhook_fails(InMemoryWebhookRepository::new()).await
+    }
+
+    #[tokio::test]
+    async fn deleting_a_webhook_of_someone_else_fails() {
+        __tests__::deleting_a_webhook_of_someone_else_fails(
+            InMemoryWebhookRepository::new(),
+            InMemoryUserRepository::new(),
+        )
+        .await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        user::postgres::PostgresUserRepository, webhook::postgres::PostgresWebhookRepository,
+    };
+
+    #[sqlx::test]
+    async fn deleting_a_webhook_works(pool: PgPool) {
+        __tests__::deleting_a_webhook_works(PostgresWebhookRepository::new(pool)).await
+    }
+
+    #[sqlx::test]
+    async fn deleting_a_nonexistent_webhook_fails(pool: PgPool) {
+        __tests__::deleting_a_nonexistent_webhook_fails(PostgresWebhookRepository::new(pool)).await
+    }
+
+    #[sqlx::test]
+    async fn deleting_a_webhook_of_someone_else_fails(pool: PgPool) {
+        __tests__::deleting_a_webhook_of_someone_else_fails(
+            PostgresWebhookRepository::new(pool.clone()),
+            PostgresUserRepository::new(pool),
+        )
+        .await
+    }
+}
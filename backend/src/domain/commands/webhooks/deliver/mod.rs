@@ -0,0 +1,162 @@
+use std::{net::SocketAddr, time::Duration};
+
+use chrono::Utc;
+use common::DomainEventDTO;
+use reqwest::{header::CONTENT_TYPE, redirect, Client, Url};
+
+use crate::domain::{
+    entities::{
+        event::OutboxEvent,
+        webhook::{Webhook, WebhookDelivery},
+    },
+    repositories::webhook::{errors::InsertWebhookDeliveryError, WebhookRepositoryService},
+    services::webhooks::WebhookTargets,
+};
+
+pub const SIGNATURE_HEADER: &str = "X-Webhook-Signature";
+pub const EVENT_HEADER: &str = "X-Webhook-Event";
+pub const DELIVERY_HEADER: &str = "X-Webhook-Delivery";
+
+/// How long to wait for the partner to answer a single attempt
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum DeliverEventError {
+    #[error(transparent)]
+    Unknown(#[from] eyre::Report),
+}
+
+impl From<InsertWebhookDeliveryError> for DeliverEventError {
+    fn from(value: InsertWebhookDeliveryError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+impl From<reqwest::Error> for DeliverEventError {
+    fn from(value: reqwest::Error) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+impl From<serde_json::Error> for DeliverEventError {
+    fn from(value: serde_json::Error) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+/// How many times to try delivering an event, and how long to wait in between
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryPolicy {
+    pub max_attempts: u32,
+    /// Doubled after every failed attempt
+    pub base_delay: Duration,
+}
+
+impl RetryPolicy {
+    /// How long to wait before the given attempt, starting at 1
+    pub fn delay_before(&self, attempt: u32) -> Duration {
+        match attempt {
+            0 | 1 => Duration::ZERO,
+            n => self.base_delay * 2u32.saturating_pow(n - 2),
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            base_delay: Duration::from_secs(1),
+        }
+    }
+}
+
+/// A client that only talks to the given addresses, when there are any, and does not follow
+/// redirects, which could lead anywhere
+fn client_for(url: &Url, addrs: &[SocketAddr]) -> reqwest::Result<Client> {
+    let mut builder = Client::builder()
+        .redirect(redirect::Policy::none())
+        .timeout(TIMEOUT);
+
+    if let (Some(host), false) = (url.host_str(), addrs.is_empty()) {
+        builder = builder.resolve_to_addrs(host, addrs);
+    }
+
+    builder.build()
+}
+
+/// Sends the event to the webhook as signed JSON, trying again with exponential backoff until
+/// the partner answers with a success or the attempts run out. The address of the webhook is
+/// checked again before every attempt, since where a host points can change. Every attempt ends
+/// up in the delivery log of the webhook under the ID of the event. Returns the last attempt.
+#[tracing::instrument("[COMMAND] Delivering an event to a webhook", skip(webhook_repo))]
+pub async fn deliver_event(
+    webhook_repo: WebhookRepositoryService,
+    targets: WebhookTargets,
+    policy: RetryPolicy,
+    webhook: &Webhook,
+    event: &OutboxEvent,
+) -> Result<WebhookDelivery, DeliverEventError> {
+    let id = event.id;
+    let url = Url::parse(&webhook.url).map_err(|e| DeliverEventError::Unknown(e.into()))?;
+    let event_type = event.event.as_ref().to_string();
+    let dto: DomainEventDTO = event.event.clone().into();
+    let payload = serde_json::to_value(&dto)?;
+    let body = serde_json::to_vec(&payload)?;
+    let signature = webhook.sign(&body);
+
+    let mut attempt = 1;
+
+    loop {
+        tokio::time::sleep(policy.delay_before(attempt)).await;
+
+        let response = match targets.resolve(&url).await {
+            Ok(addrs) => client_for(&url, &addrs)?
+                .post(url.clone())
+                .header(CONTENT_TYPE, "application/json")
+                .header(SIGNATURE_HEADER, &signature)
+                .header(EVENT_HEADER, &event_type)
+                .header(DELIVERY_HEADER, id.to_string())
+                .body(body.clone())
+                .send()
+                .await
+                .map_err(|e| e.to_string()),
+            Err(e) => Err(e.to_string()),
+        };
+
+        let (status_code, error, succeeded) = match response {
+            Ok(response) if response.status().is_success() => {
+                (Some(response.status().as_u16()), None, true)
+            }
+            Ok(response) => (
+                Some(response.status().as_u16()),
+                Some(format!("The webhook answered with {}", response.status())),
+                false,
+            ),
+            Err(e) => (None, Some(e), false),
+        };
+
+        let delivery = WebhookDelivery {
+            id,
+            webhook_id: webhook.id,
+            event_type: event_type.clone(),
+            payload: payload.clone(),
+            attempt,
+            status_code,
+            error,
+            succeeded,
+            created_at: Utc::now(),
+        };
+
+        webhook_repo.add_delivery(delivery.clone()).await?;
+
+        if succeeded || attempt >= policy.max_attempts {
+            return Ok(delivery);
+        }
+
+        attempt += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,174 @@
+use std::{sync::Arc, time::Duration};
+
+use axum::http::StatusCode;
+use common::DomainEventDTO;
+use pretty_assertions::assert_eq;
+
+use crate::{
+    domain::{
+        commands::webhooks::deliver::{
+            deliver_event, RetryPolicy, DELIVERY_HEADER, EVENT_HEADER, SIGNATURE_HEADER,
+        },
+        entities::{
+            event::{DomainEvent, OutboxEvent},
+            webhook::Webhook,
+        },
+        repositories::webhook::{WebhookRepository, WebhookRepositoryService},
+        services::webhooks::WebhookTargets,
+    },
+    test_utils::{recipe_fixture, spawn_webhook_receiver, webhook_fixture},
+};
+
+fn policy(max_attempts: u32) -> RetryPolicy {
+    RetryPolicy {
+        max_attempts,
+        base_delay: Duration::from_millis(10),
+    }
+}
+
+async fn insert_webhook(webhook_repo: &impl WebhookRepository, url: String) -> Webhook {
+    let webhook = Webhook {
+        url,
+        ..webhook_fixture()
+    };
+    webhook_repo.insert(webhook.clone()).await.unwrap();
+
+    webhook
+}
+
+pub async fn delivering_an_event_signs_it(webhook_repo: impl WebhookRepository) {
+    let (url, received) = spawn_webhook_receiver(vec![]).await;
+    let webhook = insert_webhook(&webhook_repo, url).await;
+    let webhook_repo: WebhookRepositoryService = Arc::new(Box::new(webhook_repo));
+    let event = OutboxEvent::new(DomainEvent::RecipeCreated(recipe_fixture()));
+
+    let delivery = deliver_event(
+        webhook_repo.clone(),
+        WebhookTargets::Any,
+        policy(3),
+        &webhook,
+        &event,
+    )
+    .await
+    .unwrap();
+
+    assert!(delivery.succeeded);
+    assert_eq!(delivery.attempt, 1);
+    assert_eq!(delivery.status_code, Some(200));
+    assert_eq!(delivery.event_type, "recipe_created");
+    assert_eq!(delivery.id, event.id);
+
+    let received = received.lock().unwrap().clone();
+    assert_eq!(received.len(), 1);
+
+    let request = &received[0];
+    assert_eq!(
+        request.headers[SIGNATURE_HEADER].to_str().unwrap(),
+        webhook.sign(&request.body)
+    );
+    assert_eq!(request.headers[EVENT_HEADER], "recipe_created");
+    assert_eq!(
+        request.headers[DELIVERY_HEADER].to_str().unwrap(),
+        delivery.id.to_string()
+    );
+
+    let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+    assert_eq!(
+        body,
+        serde_json::to_value(DomainEventDTO::from(event.event)).unwrap()
+    );
+
+    let log = webhook_repo.get_deliveries(&webhook).await.unwrap();
+    assert_eq!(log.len(), 1);
+    assert_eq!(log[0].id, delivery.id);
+    assert_eq!(log[0].payload, delivery.payload);
+    assert!(log[0].succeeded);
+}
+
+pub async fn failed_deliveries_are_retried(webhook_repo: impl WebhookRepository) {
+    let (url, received) = spawn_webhook_receiver(vec![
+        StatusCode::INTERNAL_SERVER_ERROR,
+        StatusCode::SERVICE_UNAVAILABLE,
+    ])
+    .await;
+    let webhook = insert_webhook(&webhook_repo, url).await;
+    let webhook_repo: WebhookRepositoryService = Arc::new(Box::new(webhook_repo));
+
+    let delivery = deliver_event(
+        webhook_repo.clone(),
+        WebhookTargets::Any,
+        policy(5),
+        &webhook,
+        &OutboxEvent::new(DomainEvent::RecipeCreated(recipe_fixture())),
+    )
+    .await
+    .unwrap();
+
+    assert!(delivery.succeeded);
+    assert_eq!(delivery.attempt, 3);
+    assert_eq!(received.lock().unwrap().len(), 3);
+
+    let log = webhook_repo.get_deliveries(&webhook).await.unwrap();
+    let attempts: Vec<_> = log
+        .iter()
+        .map(|d| (d.id, d.attempt, d.status_code, d.succeeded))
+        .collect();
+
+    assert_eq!(
+        attempts,
+        vec![
+            (delivery.id, 1, Some(500), false),
+            (delivery.id, 2, Some(503), false),
+            (delivery.id, 3, Some(200), true),
+        ]
+    );
+    assert!(log[0].error.is_some());
+    assert!(log[2].error.is_none());
+}
+
+pub async fn deliveries_give_up_after_the_last_attempt(webhook_repo: impl WebhookRepository) {
+    let (url, received) = spawn_webhook_receiver(vec![StatusCode::BAD_GATEWAY; 5]).await;
+    let webhook = insert_webhook(&webhook_repo, url).await;
+    let webhook_repo: WebhookRepositoryService = Arc::new(Box::new(webhook_repo));
+
+    let delivery = deliver_event(
+        webhook_repo.clone(),
+        WebhookTargets::Any,
+        policy(2),
+        &webhook,
+        &OutboxEvent::new(DomainEvent::RecipeDeleted(recipe_fixture().id)),
+    )
+    .await
+    .unwrap();
+
+    assert!(!delivery.succeeded);
+    assert_eq!(delivery.attempt, 2);
+    assert_eq!(delivery.status_code, Some(502));
+    assert_eq!(received.lock().unwrap().len(), 2);
+
+    let log = webhook_repo.get_deliveries(&webhook).await.unwrap();
+    assert_eq!(log.len(), 2);
+    assert!(log.iter().all(|d| !d.succeeded));
+}
+
+pub async fn deliveries_to_private_addresses_are_refused(webhook_repo: impl WebhookRepository) {
+    let (url, received) = spawn_webhook_receiver(vec![]).await;
+    let webhook = insert_webhook(&webhook_repo, url).await;
+    let webhook_repo: WebhookRepositoryService = Arc::new(Box::new(webhook_repo));
+
+    let delivery = deliver_event(
+        webhook_repo.clone(),
+        WebhookTargets::Public,
+        policy(2),
+        &webhook,
+        &OutboxEvent::new(DomainEvent::RecipeDeleted(recipe_fixture().id)),
+    )
+    .await
+    .unwrap();
+
+    assert!(!delivery.succeeded);
+    assert_eq!(delivery.attempt, 2);
+    assert_eq!(delivery.status_code, None);
+    assert!(delivery.error.unwrap().contains("not public"));
+    assert!(received.lock().unwrap().is_empty());
+}
@@ -0,0 +1,56 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::webhook::in_memory::InMemoryWebhookRepository;
+
+    #[tokio::test]
+    async fn delivering_an_event_signs_it() {
+        __tests__::delivering_an_event_signs_it(InMemoryWebhookRepository::new()).await
+    }
+
+    #[tokio::test]
+    async fn failed_deliveries_are_retried() {
+        __tests__::failed_deliveries_are_retried(InMemoryWebhookRepository::new()).await
+    }
+
+    #[tokio::test]
+    async fn deliveries_give_up_after_the_last_attempt() {
+        __tests__::deliveries_give_up_after_the_last_attempt(InMemoryWebhookRepository::new()).await
+    }
+
+    #[tokio::test]
+    async fn deliveries_to_private_addresses_are_refused() {
+        __tests__::deliveries_to_private_addresses_are_refused(InMemoryWebhookRepository::new())
+            .await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::webhook::postgres::PostgresWebhookRepository;
+
+    #[sqlx::test]
+    async fn delivering_an_event_signs_it(pool: PgPool) {
+        __tests__::delivering_an_event_signs_it(PostgresWebhookRepository::new(pool)).await
+    }
+
+    #[sqlx::test]
+    async fn failed_deliveries_are_retried(pool: PgPool) {
+        __tests__::failed_deliveries_are_retried(PostgresWebhookRepository::new(pool)).await
+    }
+
+    #[sqlx::test]
+    async fn deliveries_give_up_after_the_last_attempt(pool: PgPool) {
+        __tests__::deliveries_give_up_after_the_last_attempt(PostgresWebhookRepository::new(pool))
+            .await
+    }
+
+    #[sqlx::test]
+    async fn deliveries_to_private_addresses_are_refused(pool: PgPool) {
+        __tests__::deliveries_to_private_addresses_are_refused(PostgresWebhookRepository::new(pool))
+            .await
+    }
+}
@@ -0,0 +1,145 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use common::CollectionDTO;
+use sqlx::FromRow;
+use strum::VariantNames;
+use uuid::Uuid;
+
+use super::{recipe::errors::ValidationError, visibility::Visibility};
+
+/// A named, ordered set of recipes, like a cookbook
+#[derive(PartialEq, Debug, Clone)]
+pub struct Collection {
+    pub id: Uuid,
+    pub name: String,
+    pub description: String,
+    pub visibility: Visibility,
+    /// Collections from before there were accounts have no owner
+    pub owner_id: Option<Uuid>,
+    /// IDs of the recipes, in order
+    pub recipes: Vec<Uuid>,
+    /// Recipe shown on the cover, always one of the recipes of the collection
+    pub cover_recipe_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Collection {
+    /// Whether the collection belongs to the user, visitors that are not logged in own nothing
+    pub fn is_owned_by(&self, user_id: Option<&Uuid>) -> bool {
+        user_id.is_some() && self.owner_id.as_ref() == user_id
+    }
+
+    /// Whether the user can make changes to the collection. Collections without an owner were
+    /// made before there were accounts, so they stay open to everyone.
+    pub fn is_editable_by(&self, user_id: Option<&Uuid>) -> bool {
+        self.owner_id.is_none() || self.is_owned_by(user_id)
+    }
+
+    /// Whether the user can open the collection. There are no share tokens for collections,
+    /// so anybody with the link to an unlisted one sees it.
+    pub fn is_visible_to(&self, user_id: Option<&Uuid>) -> bool {
+        self.is_owned_by(user_id) || self.visibility != Visibility::Private
+    }
+
+    /// Whether the collection shows up when the user lists collections
+    pub fn is_listed_for(&self, user_id: Option<&Uuid>) -> bool {
+        self.is_owned_by(user_id) || self.visibility.is_listed()
+    }
+
+    pub fn contains(&self, recipe_id: &Uuid) -> bool {
+        self.recipes.contains(recipe_id)
+    }
+
+    /// Puts the recipe at the given position, or at the end if the position is past the end.
+    /// Returns the position the recipe ended up at.
+    pub fn insert_recipe(&mut self, recipe_id: Uuid, position: Option<usize>) -> usize {
+        let position = position
+            .unwrap_or(self.recipes.len())
+            .min(self.recipes.len());
+
+        self.recipes.insert(position, recipe_id);
+
+        position
+    }
+
+    /// Takes the recipe out of the collection, removing it from the cover too.
+    /// Returns whether the recipe was in the collection.
+    pub fn remove_recipe(&mut self, recipe_id: &Uuid) -> bool {
+        if self.cover_recipe_id.as_ref() == Some(recipe_id) {
+            self.cover_recipe_id = None;
+        }
+
+        let length = self.recipes.len();
+        self.recipes.retain(|id| id != recipe_id);
+
+        self.recipes.len() != length
+    }
+}
+
+impl From<Collection> for CollectionDTO {
+    fn from(value: Collection) -> Self {
+        Self {
+            id: value.id,
+            name: value.name,
+            description: value.description,
+            visibility: value.visibility.into(),
+            recipes: value.recipes,
+            cover_recipe_id: value.cover_recipe_id,
+            created_at: value.created_at.to_rfc3339(),
+            updated_at: value.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(FromRow, PartialEq, Debug, Clone)]
+pub struct CollectionModel {
+    pub id: Uuid,
+    pub name: String,
+    pub description: String,
+    pub visibility: String,
+    pub owner_id: Option<Uuid>,
+    pub recipes: Vec<Uuid>,
+    pub cover_recipe_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl TryFrom<CollectionModel> for Collection {
+    type Error = ValidationError;
+    fn try_from(value: CollectionModel) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: value.id,
+            name: value.name,
+            description: value.description,
+            visibility: Visibility::from_str(&value.visibility)
+                .map_err(|_| ValidationError::DoesNotMatch("visibility", Visibility::VARIANTS))?,
+            owner_id: value.owner_id,
+            recipes: value.recipes,
+            cover_recipe_id: value.cover_recipe_id,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct CollectionChangeset {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub visibility: Option<Visibility>,
+    pub cover_recipe_id: Option<Uuid>,
+}
+
+impl CollectionChangeset {
+    pub fn is_empty(&self) -> bool {
+        self.name.is_none()
+            && self.description.is_none()
+            && self.visibility.is_none()
+            && self.cover_recipe_id.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,73 @@
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+use crate::{
+    domain::entities::{collection::Collection, visibility::Visibility},
+    test_utils::{another_user_fixture, collection_fixture, user_fixture},
+};
+
+#[test]
+fn inserting_recipes_keeps_the_order() {
+    let mut collection = collection_fixture();
+    let first = Uuid::from_u128(1);
+    let second = Uuid::from_u128(2);
+    let third = Uuid::from_u128(3);
+    collection.recipes = vec![];
+
+    assert_eq!(collection.insert_recipe(second, None), 0);
+    assert_eq!(collection.insert_recipe(first, Some(0)), 0);
+    assert_eq!(collection.insert_recipe(third, Some(100)), 2);
+
+    assert_eq!(collection.recipes, vec![first, second, third]);
+}
+
+#[test]
+fn removing_the_cover_recipe_clears_the_cover() {
+    let mut collection = collection_fixture();
+    let cover = collection.cover_recipe_id.unwrap();
+
+    assert!(collection.remove_recipe(&cover));
+    assert!(!collection.contains(&cover));
+    assert_eq!(collection.cover_recipe_id, None);
+
+    assert!(!collection.remove_recipe(&cover));
+}
+
+#[test]
+fn private_collections_are_only_seen_by_their_owner() {
+    let owner = user_fixture().id;
+    let other = another_user_fixture().id;
+    let collection = Collection {
+        visibility: Visibility::Private,
+        owner_id: Some(owner),
+        ..collection_fixture()
+    };
+
+    assert!(collection.is_visible_to(Some(&owner)));
+    assert!(collection.is_listed_for(Some(&owner)));
+    assert!(collection.is_editable_by(Some(&owner)));
+
+    for user_id in [None, Some(&other)] {
+        assert!(!collection.is_visible_to(user_id));
+        assert!(!collection.is_listed_for(user_id));
+        assert!(!collection.is_editable_by(user_id));
+    }
+
+    let unlisted = Collection {
+        visibility: Visibility::Unlisted,
+        ..collection
+    };
+
+    assert!(unlisted.is_visible_to(Some(&other)));
+    assert!(!unlisted.is_listed_for(Some(&other)));
+    assert!(!unlisted.is_editable_by(Some(&other)));
+}
+
+#[test]
+fn collections_without_an_owner_are_editable_by_everyone() {
+    let collection = collection_fixture();
+
+    assert!(collection.is_editable_by(None));
+    assert!(collection.is_editable_by(Some(&user_fixture().id)));
+    assert!(!collection.is_owned_by(None));
+}
@@ -0,0 +1,278 @@
+use std::{collections::BTreeSet, time::Duration};
+
+use chrono::{DateTime, TimeDelta, Utc};
+use common::{CookingActionDTO, CookingSessionDTO, CookingTimerDTO};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::recipe::Recipe;
+
+/// Durations mentioned in a step, like `simmer for 10 minutes` or `bake 25-30 min`
+static STEP_DURATION: Lazy<Regex> = Lazy::new(|| {
+    #[allow(clippy::unwrap_used)]
+    Regex::new(
+        r"(?i)\b(\d+)(?:\s*(?:-|–|to)\s*\d+)?\s*(hours?|hrs?|minutes?|mins?|seconds?|secs?)\b",
+    )
+    .unwrap()
+});
+
+/// How long the step takes, if the step says so.
+/// For a range, the shorter end is used, so that the cook gets to check on the food in time.
+pub fn step_duration(step: &str) -> Option<Duration> {
+    let captures = STEP_DURATION.captures(step)?;
+    let amount: u64 = captures.get(1)?.as_str().parse().ok()?;
+    let unit = captures.get(2)?.as_str().to_lowercase();
+
+    let seconds = match unit.chars().next()? {
+        'h' => amount.checked_mul(60 * 60)?,
+        'm' => amount.checked_mul(60)?,
+        _ => amount,
+    };
+
+    Some(Duration::from_secs(seconds))
+}
+
+/// Timers that can be started while cooking the recipe: one for every entry of its time,
+/// and one for every step that mentions how long it takes
+pub fn available_timers(recipe: &Recipe) -> Vec<(String, Duration)> {
+    let from_time = recipe
+        .time
+        .iter()
+        .map(|(label, duration)| (label.clone(), *duration));
+
+    let from_steps = recipe
+        .steps
+        .as_ref()
+        .iter()
+        .enumerate()
+        .filter_map(|(i, step)| Some((format!("Step {}", i + 1), step_duration(step)?)));
+
+    from_time.chain(from_steps).collect()
+}
+
+#[derive(thiserror::Error, Debug, PartialEq)]
+pub enum CookingActionError {
+    #[error("The recipe does not have a step number {0}")]
+    StepOutOfRange(usize),
+
+    #[error("The ingredient with the ID {0} is not a part of the recipe")]
+    IngredientNotInRecipe(Uuid),
+
+    #[error("The recipe does not have a timer called {0}")]
+    UnknownTimer(String),
+
+    #[error("Could not find the timer with the ID {0}")]
+    TimerNotFound(Uuid),
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct CookingTimer {
+    pub id: Uuid,
+    pub label: String,
+    pub duration: Duration,
+    pub started_at: DateTime<Utc>,
+    /// Set by the server once the time is up
+    pub finished: bool,
+}
+
+impl CookingTimer {
+    /// A timer too long to end before the latest date there is ends then instead
+    pub fn ends_at(&self) -> DateTime<Utc> {
+        TimeDelta::from_std(self.duration)
+            .ok()
+            .and_then(|duration| self.started_at.checked_add_signed(duration))
+            .unwrap_or(DateTime::<Utc>::MAX_UTC)
+    }
+}
+
+impl From<CookingTimer> for CookingTimerDTO {
+    fn from(value: CookingTimer) -> Self {
+        Self {
+            id: value.id,
+            ends_at: value.ends_at().to_rfc3339(),
+            label: value.label,
+            duration: value.duration.as_secs(),
+            started_at: value.started_at.to_rfc3339(),
+            finished: value.finished,
+        }
+    }
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub enum CookingAction {
+    GoToStep(usize),
+    NextStep,
+    PreviousStep,
+    CheckIngredient(Uuid),
+    UncheckIngredient(Uuid),
+    StartTimer(String),
+    CancelTimer(Uuid),
+}
+
+impl From<CookingActionDTO> for CookingAction {
+    fn from(value: CookingActionDTO) -> Self {
+        match value {
+            CookingActionDTO::GoToStep { step } => Self::GoToStep(step as usize),
+            CookingActionDTO::NextStep => Self::NextStep,
+            CookingActionDTO::PreviousStep => Self::PreviousStep,
+            CookingActionDTO::CheckIngredient { ingredient_id } => {
+                Self::CheckIngredient(ingredient_id)
+            }
+            CookingActionDTO::UncheckIngredient { ingredient_id } => {
+                Self::UncheckIngredient(ingredient_id)
+            }
+            CookingActionDTO::StartTimer { label } => Self::StartTimer(label),
+            CookingActionDTO::CancelTimer { timer_id } => Self::CancelTimer(timer_id),
+        }
+    }
+}
+
+/// Somebody cooking a recipe, shared between all of their devices
+#[derive(PartialEq, Debug, Clone)]
+pub struct CookingSession {
+    pub id: Uuid,
+    pub recipe_id: Uuid,
+    /// Index of the step being cooked, starting at 0
+    pub current_step: usize,
+    pub checked_ingredients: BTreeSet<Uuid>,
+    pub timers: Vec<CookingTimer>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl CookingSession {
+    pub fn new(recipe: &Recipe) -> Self {
+        let now = Utc::now();
+
+        Self {
+            id: Uuid::now_v7(),
+            recipe_id: recipe.id,
+            current_step: 0,
+            checked_ingredients: BTreeSet::new(),
+            timers: vec![],
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Applies what the cook did, returning the timer that was started, if any.
+    /// Moving past the first or the last step keeps the cook where they are.
+    pub fn apply(
+        &mut self,
+        recipe: &Recipe,
+        action: CookingAction,
+        now: DateTime<Utc>,
+    ) -> Result<Option<CookingTimer>, CookingActionError> {
+        let last_step = recipe.steps.as_ref().len().saturating_sub(1);
+        let mut started = None;
+
+        match action {
+            CookingAction::GoToStep(step) if step > last_step => {
+                return Err(CookingActionError::StepOutOfRange(step));
+            }
+            CookingAction::GoToStep(step) => self.current_step = step,
+            CookingAction::NextStep => self.current_step = (self.current_step + 1).min(last_step),
+            CookingAction::PreviousStep => self.current_step = self.current_step.saturating_sub(1),
+            CookingAction::CheckIngredient(id) | CookingAction::UncheckIngredient(id)
+                if !recipe.ingredients.iter().any(|i| i.ingredient.id == id) =>
+            {
+                return Err(CookingActionError::IngredientNotInRecipe(id));
+            }
+            CookingAction::CheckIngredient(id) => {
+                self.checked_ingredients.insert(id);
+            }
+            CookingAction::UncheckIngredient(id) => {
+                self.checked_ingredients.remove(&id);
+            }
+            CookingAction::StartTimer(label) => {
+                let (label, duration) = available_timers(recipe)
+                    .into_iter()
+                    .find(|(l, _)| l == &label)
+                    .ok_or(CookingActionError::UnknownTimer(label))?;
+
+                let timer = CookingTimer {
+                    id: Uuid::now_v7(),
+                    label,
+                    duration,
+                    started_at: now,
+                    finished: false,
+                };
+                self.timers.push(timer.clone());
+                started = Some(timer);
+            }
+            CookingAction::CancelTimer(id) => {
+                let length = self.timers.len();
+                self.timers.retain(|timer| timer.id != id);
+
+                if self.timers.len() == length {
+                    return Err(CookingActionError::TimerNotFound(id));
+                }
+            }
+        };
+
+        self.updated_at = now;
+
+        Ok(started)
+    }
+
+    /// Marks the timer as done, returning whether it was still running
+    pub fn finish_timer(&mut self, id: &Uuid, now: DateTime<Utc>) -> bool {
+        match self
+            .timers
+            .iter_mut()
+            .find(|timer| &timer.id == id && !timer.finished)
+        {
+            Some(timer) => {
+                timer.finished = true;
+                self.updated_at = now;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl From<CookingSession> for CookingSessionDTO {
+    fn from(value: CookingSession) -> Self {
+        Self {
+            id: value.id,
+            recipe_id: value.recipe_id,
+            current_step: value.current_step as u32,
+            checked_ingredients: value.checked_ingredients.into_iter().collect(),
+            timers: value.timers.into_iter().map(Into::into).collect(),
+            created_at: value.created_at.to_rfc3339(),
+            updated_at: value.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(sqlx::FromRow, PartialEq, Debug, Clone)]
+pub struct CookingSessionModel {
+    pub id: Uuid,
+    pub recipe_id: Uuid,
+    pub current_step: i32,
+    pub checked_ingredients: Vec<Uuid>,
+    pub timers: serde_json::Value,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl TryFrom<CookingSessionModel> for CookingSession {
+    type Error = serde_json::Error;
+    fn try_from(value: CookingSessionModel) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: value.id,
+            recipe_id: value.recipe_id,
+            current_step: value.current_step.try_into().unwrap_or_default(),
+            checked_ingredients: value.checked_ingredients.into_iter().collect(),
+            timers: serde_json::from_value(value.timers)?,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,160 @@
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+use crate::test_utils::recipe_fixture;
+
+use super::{
+    available_timers, step_duration, CookingAction, CookingActionError, CookingSession,
+    CookingTimer,
+};
+
+fn now() -> DateTime<Utc> {
+    DateTime::<Utc>::from_timestamp_nanos(1_700_000_000_000_000_000)
+}
+
+#[test]
+fn durations_are_found_in_steps() {
+    assert_eq!(
+        step_duration("Simmer for 10 minutes"),
+        Some(Duration::from_secs(600))
+    );
+    assert_eq!(
+        step_duration("Bake 25-30 min, until golden"),
+        Some(Duration::from_secs(25 * 60))
+    );
+    assert_eq!(
+        step_duration("Let it rest for 2 hours"),
+        Some(Duration::from_secs(2 * 60 * 60))
+    );
+    assert_eq!(
+        step_duration("Blend for 30 secs"),
+        Some(Duration::from_secs(30))
+    );
+    assert_eq!(step_duration("Add 2 tbsp of oil"), None);
+    assert_eq!(step_duration("Stir for a minute"), None);
+    assert_eq!(step_duration("Leave for 9999999999999999 hours"), None);
+}
+
+#[test]
+fn timers_come_from_the_time_and_the_steps() {
+    let labels: Vec<_> = available_timers(&recipe_fixture())
+        .into_iter()
+        .map(|(label, duration)| (label, duration.as_secs()))
+        .collect();
+
+    assert_eq!(
+        labels,
+        vec![
+            ("Cook time".to_string(), 10 * 60),
+            ("Prep time".to_string(), 15 * 60),
+            ("Step 6".to_string(), 2 * 60),
+        ]
+    );
+}
+
+#[test]
+fn moving_between_steps_stays_within_the_recipe() {
+    let recipe = recipe_fixture();
+    let mut session = CookingSession::new(&recipe);
+
+    session
+        .apply(&recipe, CookingAction::PreviousStep, now())
+        .unwrap();
+    assert_eq!(session.current_step, 0);
+
+    session
+        .apply(&recipe, CookingAction::GoToStep(8), now())
+        .unwrap();
+    session
+        .apply(&recipe, CookingAction::NextStep, now())
+        .unwrap();
+    assert_eq!(session.current_step, 8);
+    assert_eq!(session.updated_at, now());
+
+    let error = session
+        .apply(&recipe, CookingAction::GoToStep(9), now())
+        .unwrap_err();
+    assert_eq!(error, CookingActionError::StepOutOfRange(9));
+}
+
+#[test]
+fn only_ingredients_of_the_recipe_can_be_checked() {
+    let recipe = recipe_fixture();
+    let mut session = CookingSession::new(&recipe);
+    let tofu = recipe.ingredients[0].ingredient.id;
+
+    session
+        .apply(&recipe, CookingAction::CheckIngredient(tofu), now())
+        .unwrap();
+    assert!(session.checked_ingredients.contains(&tofu));
+
+    session
+        .apply(&recipe, CookingAction::UncheckIngredient(tofu), now())
+        .unwrap();
+    assert!(session.checked_ingredients.is_empty());
+
+    let stranger = Uuid::from_u128(404);
+    let error = session
+        .apply(&recipe, CookingAction::CheckIngredient(stranger), now())
+        .unwrap_err();
+    assert_eq!(error, CookingActionError::IngredientNotInRecipe(stranger));
+}
+
+#[test]
+fn timers_can_be_started_finished_and_cancelled() {
+    let recipe = recipe_fixture();
+    let mut session = CookingSession::new(&recipe);
+
+    let timer = session
+        .apply(
+            &recipe,
+            CookingAction::StartTimer("Step 6".to_string()),
+            now(),
+        )
+        .unwrap()
+        .unwrap();
+    assert_eq!(timer.ends_at(), now() + Duration::from_secs(120));
+    assert_eq!(session.timers, vec![timer.clone()]);
+
+    assert!(session.finish_timer(&timer.id, now()));
+    assert!(!session.finish_timer(&timer.id, now()));
+    assert!(session.timers[0].finished);
+
+    session
+        .apply(&recipe, CookingAction::CancelTimer(timer.id), now())
+        .unwrap();
+    assert!(session.timers.is_empty());
+
+    let error = session
+        .apply(&recipe, CookingAction::CancelTimer(timer.id), now())
+        .unwrap_err();
+    assert_eq!(error, CookingActionError::TimerNotFound(timer.id));
+
+    let error = session
+        .apply(
+            &recipe,
+            CookingAction::StartTimer("Rest time".to_string()),
+            now(),
+        )
+        .unwrap_err();
+    assert_eq!(
+        error,
+        CookingActionError::UnknownTimer("Rest time".to_string())
+    );
+}
+
+#[test]
+fn timers_too_long_for_the_calendar_end_at_the_latest_date() {
+    let timer = CookingTimer {
+        id: Uuid::from_u128(1),
+        label: "Prep time".to_string(),
+        duration: Duration::from_secs(u64::MAX),
+        started_at: now(),
+        finished: false,
+    };
+
+    assert_eq!(timer.ends_at(), DateTime::<Utc>::MAX_UTC);
+}
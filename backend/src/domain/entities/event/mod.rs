@@ -0,0 +1,174 @@
+use chrono::{DateTime, Utc};
+use common::DomainEventDTO;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use strum::{AsRefStr, VariantNames};
+use uuid::Uuid;
+
+use super::{ingredient::Ingredient, recipe::Recipe};
+
+/// Something that happened to a recipe or an ingredient, as a result of a command
+#[derive(Debug, Clone, PartialEq, AsRefStr, VariantNames, Serialize, Deserialize)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum DomainEvent {
+    RecipeCreated(Recipe),
+    RecipeUpdated(Recipe),
+    RecipeDeleted(Uuid),
+    IngredientAddedToRecipe {
+        recipe_id: Uuid,
+        ingredient_id: Uuid,
+    },
+    IngredientInRecipeUpdated {
+        recipe_id: Uuid,
+        ingredient_id: Uuid,
+    },
+    IngredientRemovedFromRecipe {
+        recipe_id: Uuid,
+        ingredient_id: Uuid,
+    },
+    IngredientCreated(Ingredient),
+    IngredientUpdated(Ingredient),
+    IngredientDeleted(Uuid),
+}
+
+impl DomainEvent {
+    /// The recipe the event is about, if it is about a recipe
+    pub fn recipe_id(&self) -> Option<Uuid> {
+        match self {
+            Self::RecipeCreated(recipe) | Self::RecipeUpdated(recipe) => Some(recipe.id),
+            Self::RecipeDeleted(recipe_id)
+            | Self::IngredientAddedToRecipe { recipe_id, .. }
+            | Self::IngredientInRecipeUpdated { recipe_id, .. }
+            | Self::IngredientRemovedFromRecipe { recipe_id, .. } => Some(*recipe_id),
+            Self::IngredientCreated(_)
+            | Self::IngredientUpdated(_)
+            | Self::IngredientDeleted(_) => None,
+        }
+    }
+}
+
+impl From<DomainEvent> for DomainEventDTO {
+    fn from(value: DomainEvent) -> Self {
+        match value {
+            DomainEvent::RecipeCreated(recipe) => Self::RecipeCreated {
+                recipe: recipe.into(),
+            },
+            DomainEvent::RecipeUpdated(recipe) => Self::RecipeUpdated {
+                recipe: recipe.into(),
+            },
+            DomainEvent::RecipeDeleted(recipe_id) => Self::RecipeDeleted { recipe_id },
+            DomainEvent::IngredientAddedToRecipe {
+                recipe_id,
+                ingredient_id,
+            } => Self::IngredientAddedToRecipe {
+                recipe_id,
+                ingredient_id,
+            },
+            DomainEvent::IngredientInRecipeUpdated {
+                recipe_id,
+                ingredient_id,
+            } => Self::IngredientInRecipeUpdated {
+                recipe_id,
+                ingredient_id,
+            },
+            DomainEvent::IngredientRemovedFromRecipe {
+                recipe_id,
+                ingredient_id,
+            } => Self::IngredientRemovedFromRecipe {
+                recipe_id,
+                ingredient_id,
+            },
+            DomainEvent::IngredientCreated(ingredient) => Self::IngredientCreated {
+                ingredient: ingredient.into(),
+            },
+            DomainEvent::IngredientUpdated(ingredient) => Self::IngredientUpdated {
+                ingredient: ingredient.into(),
+            },
+            DomainEvent::IngredientDeleted(ingredient_id) => {
+                Self::IngredientDeleted { ingredient_id }
+            }
+        }
+    }
+}
+
+/// An event recorded together with the change it is about, waiting to be published
+#[derive(PartialEq, Debug, Clone)]
+pub struct OutboxEvent {
+    /// Stays the same when the event is published again, so that subscribers can tell
+    pub id: Uuid,
+    pub event: DomainEvent,
+    pub created_at: DateTime<Utc>,
+    /// Missing until the event reaches the event bus
+    pub published_at: Option<DateTime<Utc>>,
+}
+
+impl OutboxEvent {
+    pub fn new(event: DomainEvent) -> Self {
+        Self {
+            id: Uuid::now_v7(),
+            event,
+            created_at: Utc::now(),
+            published_at: None,
+        }
+    }
+}
+
+/// Version of the payloads written to the outbox. Events can sit in the outbox while a newer
+/// version of the app starts, so whenever the way events are serialized changes, this goes up
+/// and the payloads of the versions before are converted when they are read.
+pub const OUTBOX_PAYLOAD_VERSION: i16 = 1;
+
+#[derive(thiserror::Error, Debug)]
+pub enum DecodeOutboxEventError {
+    #[error("Version {0} of the outbox payload is unknown")]
+    UnknownVersion(i16),
+
+    #[error(transparent)]
+    InvalidPayload(#[from] serde_json::Error),
+}
+
+#[derive(FromRow, PartialEq, Debug, Clone)]
+pub struct OutboxEventModel {
+    pub id: Uuid,
+    pub event_type: String,
+    /// How the payload was written, see [`OUTBOX_PAYLOAD_VERSION`]
+    pub version: i16,
+    pub payload: serde_json::Value,
+    pub created_at: DateTime<Utc>,
+    pub published_at: Option<DateTime<Utc>>,
+}
+
+impl TryFrom<&OutboxEvent> for OutboxEventModel {
+    type Error = serde_json::Error;
+    fn try_from(value: &OutboxEvent) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: value.id,
+            event_type: value.event.as_ref().to_string(),
+            version: OUTBOX_PAYLOAD_VERSION,
+            payload: serde_json::to_value(&value.event)?,
+            created_at: value.created_at,
+            published_at: value.published_at,
+        })
+    }
+}
+
+impl TryFrom<OutboxEventModel> for OutboxEvent {
+    type Error = DecodeOutboxEventError;
+    fn try_from(value: OutboxEventModel) -> Result<Self, Self::Error> {
+        let event = match value.version {
+            1 => serde_json::from_value(value.payload)?,
+            version => return Err(DecodeOutboxEventError::UnknownVersion(version)),
+        };
+
+        Ok(Self {
+            id: value.id,
+            event,
+            created_at: value.created_at,
+            published_at: value.published_at,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests;
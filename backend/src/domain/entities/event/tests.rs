@@ -0,0 +1,69 @@
+use common::DomainEventDTO;
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+use crate::test_utils::{ingredient_fixture, recipe_fixture};
+
+use super::{
+    DecodeOutboxEventError, DomainEvent, OutboxEvent, OutboxEventModel, OUTBOX_PAYLOAD_VERSION,
+};
+
+#[test]
+fn events_know_which_recipe_they_are_about() {
+    let recipe = recipe_fixture();
+    let ingredient = ingredient_fixture();
+
+    assert_eq!(
+        DomainEvent::RecipeUpdated(recipe.clone()).recipe_id(),
+        Some(recipe.id)
+    );
+    assert_eq!(
+        DomainEvent::IngredientRemovedFromRecipe {
+            recipe_id: recipe.id,
+            ingredient_id: ingredient.id,
+        }
+        .recipe_id(),
+        Some(recipe.id)
+    );
+    assert_eq!(DomainEvent::IngredientUpdated(ingredient).recipe_id(), None);
+}
+
+#[test]
+fn event_names_match_the_serialized_type() {
+    let event = DomainEvent::IngredientAddedToRecipe {
+        recipe_id: Uuid::from_u128(1),
+        ingredient_id: Uuid::from_u128(2),
+    };
+    let name = event.as_ref().to_string();
+
+    let dto: DomainEventDTO = event.into();
+    let json = serde_json::to_value(&dto).unwrap();
+
+    assert_eq!(name, "ingredient_added_to_recipe");
+    assert_eq!(json["type"], name);
+}
+
+#[test]
+fn events_survive_a_trip_through_the_outbox() {
+    let outbox_event = OutboxEvent::new(DomainEvent::RecipeCreated(recipe_fixture()));
+
+    let model = OutboxEventModel::try_from(&outbox_event).unwrap();
+
+    assert_eq!(model.event_type, "recipe_created");
+    assert_eq!(OutboxEvent::try_from(model).unwrap(), outbox_event);
+}
+
+#[test]
+fn outbox_payloads_of_unknown_versions_are_refused() {
+    let outbox_event = OutboxEvent::new(DomainEvent::RecipeDeleted(recipe_fixture().id));
+
+    let model = OutboxEventModel {
+        version: OUTBOX_PAYLOAD_VERSION + 1,
+        ..OutboxEventModel::try_from(&outbox_event).unwrap()
+    };
+
+    assert!(matches!(
+        OutboxEvent::try_from(model),
+        Err(DecodeOutboxEventError::UnknownVersion(v)) if v == OUTBOX_PAYLOAD_VERSION + 1
+    ));
+}
@@ -0,0 +1,41 @@
+use chrono::{DateTime, Utc};
+use common::{FavoriteDTO, FavoriteRecipeDTO};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use super::{recipe::Recipe, recipe_note::RecipeNote};
+
+/// A recipe bookmarked by a user
+#[derive(FromRow, PartialEq, Debug, Clone)]
+pub struct Favorite {
+    pub user_id: Uuid,
+    pub recipe_id: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<Favorite> for FavoriteDTO {
+    fn from(value: Favorite) -> Self {
+        Self {
+            recipe_id: value.recipe_id,
+            created_at: value.created_at.to_rfc3339(),
+        }
+    }
+}
+
+/// A favorite along with the recipe itself and the notes taken on it
+#[derive(PartialEq, Debug, Clone)]
+pub struct FavoriteRecipe {
+    pub recipe: Recipe,
+    pub favorited_at: DateTime<Utc>,
+    pub notes: Option<RecipeNote>,
+}
+
+impl From<FavoriteRecipe> for FavoriteRecipeDTO {
+    fn from(value: FavoriteRecipe) -> Self {
+        Self {
+            recipe: value.recipe.into(),
+            favorited_at: value.favorited_at.to_rfc3339(),
+            notes: value.notes.map(|note| note.content),
+        }
+    }
+}
@@ -1,10 +1,14 @@
 pub mod errors;
 pub mod types;
 
+use chrono::{DateTime, Utc};
 use common::IngredientDTO;
+use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 use uuid::Uuid;
 
+use super::precondition::entity_tag;
+
 use self::{
     errors::ValidationError,
     types::{DietViolations, IngredientDescription, IngredientName, WhichDiets},
@@ -14,13 +18,21 @@ use self::{
 // i.e.: soy sauce (dark, light)
 
 // TODO: Include possible substitutes
-#[derive(FromRow, Debug, Clone, PartialEq, Eq)]
+#[derive(FromRow, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Ingredient {
     pub id: Uuid,
     pub name: IngredientName,
     pub description: IngredientDescription,
     // TODO: change to diet_violations for easier filtering
     pub diet_violations: WhichDiets,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Ingredient {
+    /// Tag of the ingredient as clients see it
+    pub fn entity_tag(&self) -> String {
+        entity_tag(&IngredientDTO::from(self))
+    }
 }
 
 impl From<Ingredient> for IngredientDTO {
@@ -51,6 +63,7 @@ pub struct IngredientModel {
     pub name: String,
     pub description: String,
     pub diet_violations: Vec<String>,
+    pub updated_at: DateTime<Utc>,
 }
 
 impl TryFrom<&IngredientModel> for Ingredient {
@@ -61,6 +74,7 @@ impl TryFrom<&IngredientModel> for Ingredient {
             name: value.name.clone().try_into()?,
             description: value.description.clone().try_into()?,
             diet_violations: value.diet_violations.clone().into(),
+            updated_at: value.updated_at,
         })
     }
 }
@@ -89,6 +103,7 @@ impl From<Ingredient> for IngredientModel {
             name,
             description,
             diet_violations,
+            updated_at,
         }: Ingredient,
     ) -> Self {
         Self {
@@ -96,6 +111,7 @@ impl From<Ingredient> for IngredientModel {
             name: name.to_string(),
             description: description.to_string(),
             diet_violations: diet_violations.into(),
+            updated_at,
         }
     }
 }
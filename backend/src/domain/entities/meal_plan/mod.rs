@@ -0,0 +1,183 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use common::{CalendarFeedDTO, MealPlanEntryDTO, MealSlotDTO};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use strum::{Display, EnumString, VariantNames};
+use uuid::Uuid;
+
+use super::recipe::errors::ValidationError;
+
+#[derive(
+    Serialize,
+    Deserialize,
+    VariantNames,
+    EnumString,
+    Display,
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Clone,
+    Copy,
+)]
+#[strum(serialize_all = "snake_case")]
+pub enum MealSlot {
+    Breakfast,
+    Lunch,
+    Dinner,
+    Snack,
+}
+
+impl MealSlot {
+    /// The time of day the meal is usually eaten at
+    pub fn serving_time(&self) -> NaiveTime {
+        let (hour, minute) = match self {
+            Self::Breakfast => (8, 0),
+            Self::Lunch => (13, 0),
+            Self::Snack => (16, 0),
+            Self::Dinner => (19, 0),
+        };
+
+        NaiveTime::from_hms_opt(hour, minute, 0).unwrap_or_default()
+    }
+}
+
+impl From<MealSlotDTO> for MealSlot {
+    fn from(value: MealSlotDTO) -> Self {
+        match value {
+            MealSlotDTO::Breakfast => Self::Breakfast,
+            MealSlotDTO::Lunch => Self::Lunch,
+            MealSlotDTO::Dinner => Self::Dinner,
+            MealSlotDTO::Snack => Self::Snack,
+        }
+    }
+}
+
+impl From<MealSlot> for MealSlotDTO {
+    fn from(value: MealSlot) -> Self {
+        match value {
+            MealSlot::Breakfast => Self::Breakfast,
+            MealSlot::Lunch => Self::Lunch,
+            MealSlot::Dinner => Self::Dinner,
+            MealSlot::Snack => Self::Snack,
+        }
+    }
+}
+
+/// A recipe planned to be cooked for one of the meals of a day
+#[derive(PartialEq, Debug, Clone)]
+pub struct MealPlanEntry {
+    pub id: Uuid,
+    /// Whose meal plan the entry is part of
+    pub user_id: Uuid,
+    pub date: NaiveDate,
+    pub slot: MealSlot,
+    pub recipe_id: Uuid,
+    pub servings: u16,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<MealPlanEntry> for MealPlanEntryDTO {
+    fn from(value: MealPlanEntry) -> Self {
+        Self {
+            id: value.id,
+            date: value.date,
+            slot: value.slot.into(),
+            recipe_id: value.recipe_id,
+            servings: value.servings,
+            created_at: value.created_at.to_rfc3339(),
+            updated_at: value.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(FromRow, PartialEq, Debug, Clone)]
+pub struct MealPlanEntryModel {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub date: NaiveDate,
+    pub slot: String,
+    pub recipe_id: Uuid,
+    pub servings: i16,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl TryFrom<MealPlanEntryModel> for MealPlanEntry {
+    type Error = ValidationError;
+    fn try_from(value: MealPlanEntryModel) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: value.id,
+            user_id: value.user_id,
+            date: value.date,
+            slot: MealSlot::from_str(&value.slot)
+                .map_err(|_| ValidationError::DoesNotMatch("slot", MealSlot::VARIANTS))?,
+            recipe_id: value.recipe_id,
+            servings: value
+                .servings
+                .try_into()
+                .map_err(|_| ValidationError::EmptyField(vec!["servings"]))?,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct MealPlanEntryChangeset {
+    pub date: Option<NaiveDate>,
+    pub slot: Option<MealSlot>,
+    pub recipe_id: Option<Uuid>,
+    pub servings: Option<u16>,
+}
+
+impl MealPlanEntryChangeset {
+    pub fn is_empty(&self) -> bool {
+        self.date.is_none()
+            && self.slot.is_none()
+            && self.recipe_id.is_none()
+            && self.servings.is_none()
+    }
+}
+
+/// A secret address that calendar clients can subscribe to in order to see the meal plan of a user
+#[derive(FromRow, PartialEq, Debug, Clone)]
+pub struct CalendarFeed {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    pub token: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl CalendarFeed {
+    pub fn new(user_id: Uuid, name: String) -> Self {
+        Self {
+            id: Uuid::now_v7(),
+            user_id,
+            name,
+            token: Uuid::new_v4().simple().to_string(),
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn path(&self) -> String {
+        format!("/meal-plan/feed/{}/calendar.ics", self.token)
+    }
+}
+
+impl From<CalendarFeed> for CalendarFeedDTO {
+    fn from(value: CalendarFeed) -> Self {
+        Self {
+            id: value.id,
+            url: value.path(),
+            name: value.name,
+            token: value.token,
+            created_at: value.created_at.to_rfc3339(),
+        }
+    }
+}
@@ -1,2 +1,16 @@
+pub mod collection;
+pub mod cooking_session;
+pub mod event;
+pub mod favorite;
 pub mod ingredient;
+pub mod meal_plan;
+pub mod pantry;
+pub mod precondition;
 pub mod recipe;
+pub mod recipe_note;
+pub mod review;
+pub mod share_token;
+pub mod shopping_list;
+pub mod user;
+pub mod visibility;
+pub mod webhook;
@@ -0,0 +1,181 @@
+use chrono::{DateTime, Days, NaiveDate, Utc};
+use common::{CookableRecipeDTO, PantryItemDTO};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use super::{
+    ingredient::{Ingredient, IngredientModel},
+    recipe::{errors::ValidationError, IngredientUnit, IngredientWithAmount, Recipe},
+};
+
+/// Items that expire within this many days should be used up first
+const EXPIRING_SOON_DAYS: u64 = 3;
+
+/// An ingredient that is at hand
+#[derive(PartialEq, Debug, Clone)]
+pub struct PantryItem {
+    pub id: Uuid,
+    /// Whose pantry the item is in
+    pub user_id: Uuid,
+    pub ingredient: Ingredient,
+    /// How much of the ingredient there is, if it matters
+    pub amount: Option<IngredientUnit>,
+    pub expires_at: Option<NaiveDate>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl PantryItem {
+    pub fn is_expired(&self, today: &NaiveDate) -> bool {
+        self.expires_at.is_some_and(|date| date < *today)
+    }
+
+    pub fn expires_soon(&self, today: &NaiveDate) -> bool {
+        let soon = *today + Days::new(EXPIRING_SOON_DAYS);
+
+        !self.is_expired(today) && self.expires_at.is_some_and(|date| date <= soon)
+    }
+
+    /// Whether there is enough of the item to cook with.
+    /// Items without an amount, or with an amount that can't be compared, are assumed to be enough.
+    fn covers(&self, needed: &IngredientWithAmount) -> bool {
+        self.amount
+            .as_ref()
+            .and_then(|amount| amount.covers(&needed.amount))
+            .unwrap_or(true)
+    }
+}
+
+impl From<PantryItem> for PantryItemDTO {
+    fn from(value: PantryItem) -> Self {
+        Self {
+            id: value.id,
+            ingredient: value.ingredient.into(),
+            amount: value.amount.map(|amount| amount.into()),
+            expires_at: value.expires_at,
+            created_at: value.created_at.to_rfc3339(),
+            updated_at: value.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(FromRow, PartialEq, Debug, Clone)]
+pub struct PantryItemModel {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub ingredient: IngredientModel,
+    pub amount: Option<serde_json::Value>,
+    pub expires_at: Option<NaiveDate>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl TryFrom<PantryItemModel> for PantryItem {
+    type Error = ValidationError;
+    fn try_from(value: PantryItemModel) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: value.id,
+            user_id: value.user_id,
+            ingredient: value.ingredient.try_into()?,
+            amount: value
+                .amount
+                .map(serde_json::from_value)
+                .transpose()
+                .map_err(|e| ValidationError::DeserializationFailed("amount", e))?,
+            expires_at: value.expires_at,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        })
+    }
+}
+
+/// How well a recipe can be cooked with what is in the pantry
+#[derive(PartialEq, Debug, Clone)]
+pub struct CookableRecipe {
+    pub recipe: Recipe,
+    /// How many of the required ingredients are in the pantry
+    pub covered: usize,
+    pub required: usize,
+    /// Required ingredients that are not in the pantry, or not in a sufficient amount
+    pub missing: Vec<IngredientWithAmount>,
+    /// Ingredients of the recipe that should be used up soon
+    pub expiring: Vec<Ingredient>,
+}
+
+impl From<CookableRecipe> for CookableRecipeDTO {
+    fn from(value: CookableRecipe) -> Self {
+        Self {
+            recipe: value.recipe.into(),
+            covered: value.covered,
+            required: value.required,
+            missing: value.missing.into_iter().map(|i| i.into()).collect(),
+            expiring: value.expiring.into_iter().map(|i| i.into()).collect(),
+        }
+    }
+}
+
+/// Ranks the recipes by how little is missing to cook them, preferring the ones that use up
+/// items which expire soon. Expired items are treated as if they were not in the pantry.
+/// Recipes that neither have a required ingredient covered nor use up an expiring item are left out.
+pub fn rank_recipes(
+    recipes: Vec<Recipe>,
+    pantry: &[PantryItem],
+    today: &NaiveDate,
+) -> Vec<CookableRecipe> {
+    let available: Vec<&PantryItem> = pantry
+        .iter()
+        .filter(|item| !item.is_expired(today))
+        .collect();
+    let find = |ingredient: &Ingredient| {
+        available
+            .iter()
+            .find(|item| item.ingredient.id == ingredient.id)
+    };
+
+    let mut result: Vec<CookableRecipe> = recipes
+        .into_iter()
+        .map(|recipe| {
+            let required: Vec<&IngredientWithAmount> = recipe
+                .ingredients
+                .iter()
+                .filter(|ingredient| !ingredient.optional)
+                .collect();
+
+            let missing: Vec<IngredientWithAmount> = required
+                .iter()
+                .filter(|needed| find(&needed.ingredient).is_none_or(|item| !item.covers(needed)))
+                .map(|needed| (*needed).clone())
+                .collect();
+
+            let expiring: Vec<Ingredient> = recipe
+                .ingredients
+                .iter()
+                .filter(|used| find(&used.ingredient).is_some_and(|item| item.expires_soon(today)))
+                .map(|used| used.ingredient.clone())
+                .collect();
+
+            CookableRecipe {
+                covered: required.len() - missing.len(),
+                required: required.len(),
+                missing,
+                expiring,
+                recipe,
+            }
+        })
+        .filter(|cookable| cookable.covered > 0 || !cookable.expiring.is_empty())
+        .collect();
+
+    result.sort_by(|a, b| {
+        a.missing
+            .len()
+            .cmp(&b.missing.len())
+            .then(b.expiring.len().cmp(&a.expiring.len()))
+            .then(b.covered.cmp(&a.covered))
+            .then(a.recipe.name.cmp(&b.recipe.name))
+    });
+
+    result
+}
+
+#[cfg(test)]
+mod tests;
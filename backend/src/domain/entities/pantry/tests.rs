@@ -0,0 +1,122 @@
+use chrono::NaiveDate;
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+use crate::{
+    domain::entities::{
+        pantry::PantryItem,
+        recipe::{IngredientUnit, Recipe},
+    },
+    test_utils::{pantry_item_fixture, recipe_fixture},
+};
+
+use super::rank_recipes;
+
+fn today() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2024, 10, 20).unwrap()
+}
+
+/// Every ingredient of the recipe fixture, with plenty of each and no expiry date
+fn stocked_pantry() -> Vec<PantryItem> {
+    recipe_fixture()
+        .ingredients
+        .iter()
+        .enumerate()
+        .map(|(i, ingredient)| PantryItem {
+            id: Uuid::from_u128(i as u128),
+            ingredient: ingredient.ingredient.clone(),
+            amount: Some(ingredient.amount.scale(2.0)),
+            expires_at: None,
+            ..pantry_item_fixture()
+        })
+        .collect()
+}
+
+fn tofu_only_recipe() -> Recipe {
+    let recipe = recipe_fixture();
+
+    Recipe {
+        id: Uuid::from_u128(1024),
+        name: "Plain tofu".to_string(),
+        ingredients: vec![recipe.ingredients[0].clone()].try_into().unwrap(),
+        ..recipe
+    }
+}
+
+#[test]
+fn recipes_with_nothing_missing_rank_first() {
+    let mut pantry = stocked_pantry();
+    // Only one of the four cloves of garlic is left
+    pantry[2].amount = Some(IngredientUnit::Other {
+        amount: 1.0,
+        unit: "cloves".to_string(),
+    });
+    let recipe = recipe_fixture();
+
+    let result = rank_recipes(vec![recipe.clone(), tofu_only_recipe()], &pantry, &today());
+
+    assert_eq!(result.len(), 2);
+    assert_eq!(result[0].recipe.name, "Plain tofu");
+    assert_eq!(result[0].missing, vec![]);
+    assert_eq!(result[1].recipe.name, recipe.name);
+    assert_eq!((result[1].covered, result[1].required), (2, 3));
+    assert_eq!(result[1].missing, vec![recipe.ingredients[2].clone()]);
+}
+
+#[test]
+fn optional_ingredients_are_not_required() {
+    let mut recipe = recipe_fixture();
+    recipe.ingredients = recipe
+        .ingredients
+        .iter()
+        .cloned()
+        .enumerate()
+        .map(|(i, mut ingredient)| {
+            ingredient.optional = i > 0;
+            ingredient
+        })
+        .collect::<Vec<_>>()
+        .try_into()
+        .unwrap();
+
+    let result = rank_recipes(vec![recipe], &[pantry_item_fixture()], &today());
+
+    assert_eq!(result.len(), 1);
+    assert_eq!((result[0].covered, result[0].required), (1, 1));
+    assert_eq!(result[0].missing, vec![]);
+}
+
+#[test]
+fn items_that_expire_soon_are_prioritised() {
+    let mut pantry = stocked_pantry();
+    let tofu_recipe = tofu_only_recipe();
+    let recipe = Recipe {
+        ingredients: vec![recipe_fixture().ingredients[1].clone()]
+            .try_into()
+            .unwrap(),
+        name: "Plain broccoli".to_string(),
+        ..recipe_fixture()
+    };
+
+    let result = rank_recipes(vec![recipe.clone(), tofu_recipe.clone()], &pantry, &today());
+
+    assert_eq!(result[0].recipe.name, "Plain broccoli");
+    assert_eq!(result[0].expiring, vec![]);
+
+    pantry[0].expires_at = NaiveDate::from_ymd_opt(2024, 10, 22);
+
+    let result = rank_recipes(vec![recipe, tofu_recipe], &pantry, &today());
+
+    assert_eq!(result[0].recipe.name, "Plain tofu");
+    assert_eq!(result[0].expiring, vec![pantry[0].ingredient.clone()]);
+}
+
+#[test]
+fn expired_items_are_not_available() {
+    let mut item = pantry_item_fixture();
+    item.expires_at = NaiveDate::from_ymd_opt(2024, 10, 19);
+
+    let result = rank_recipes(vec![tofu_only_recipe()], &[item], &today());
+
+    assert_eq!(result, vec![]);
+}
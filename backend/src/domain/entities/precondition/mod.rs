@@ -0,0 +1,31 @@
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+/// Tag of a version of a resource, taken from everything the client gets to see of it. A change
+/// that leaves the resource itself alone, like a new review or a renamed ingredient, still
+/// changes what the client sees, and with it the tag.
+pub fn entity_tag(representation: &impl Serialize) -> String {
+    // Serializing the DTOs can't fail, they are plain data with string keys
+    let json = serde_json::to_vec(representation).unwrap_or_default();
+
+    hex::encode(&Sha256::digest(json)[..16])
+}
+
+/// What the client expects the current version of a resource to be before a write is applied
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum Precondition {
+    /// Apply the write no matter what has changed in the meantime
+    #[default]
+    Any,
+    /// Only apply the write if the resource still has one of these tags, see [`entity_tag`]
+    Tags(Vec<String>),
+}
+
+impl Precondition {
+    pub fn holds(&self, tag: &str) -> bool {
+        match self {
+            Self::Any => true,
+            Self::Tags(expected) => expected.iter().any(|expected| expected == tag),
+        }
+    }
+}
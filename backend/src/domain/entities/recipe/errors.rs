@@ -8,12 +8,18 @@ pub enum ValidationError {
     #[error("Field {0} does not match any of: {1:?}")]
     DoesNotMatch(&'static str, &'static [&'static str]),
 
+    #[error("Field {0} has to be between {1} and {2}")]
+    OutOfRange(&'static str, i64, i64),
+
     #[error("Failed to deserialize field {0}")]
     DeserializationFailed(&'static str, #[source] serde_json::Error),
 
     #[error("Failed to compute measurement from the following string: {0}")]
     MeasurementComputation(String),
 
+    #[error("A range has to start at an amount that is measured, and cannot be a range itself")]
+    InvalidRange,
+
     #[error(transparent)]
     Unknown(#[from] eyre::Error),
 }
@@ -27,3 +33,12 @@ impl From<IngredientValidationError> for ValidationError {
         }
     }
 }
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum ParseIngredientLineError {
+    #[error("The ingredient line was empty")]
+    Empty,
+
+    #[error("Could not find the name of the ingredient in {0:?}")]
+    MissingName(String),
+}
@@ -0,0 +1,280 @@
+use std::str::FromStr;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use super::{errors::ParseIngredientLineError, IngredientUnit};
+
+const UNICODE_FRACTIONS: &str = "½⅓⅔¼¾⅕⅖⅗⅘⅙⅚⅛⅜⅝⅞";
+
+/// A single number: `2 1/2`, `2½`, `1/2`, `½` or `1.5`
+const NUMBER: &str =
+    r"\d+\s*[½⅓⅔¼¾⅕⅖⅗⅘⅙⅚⅛⅜⅝⅞]|\d+\s+\d+/\d+|\d+/\d+|[½⅓⅔¼¾⅕⅖⅗⅘⅙⅚⅛⅜⅝⅞]|\d+(?:[.,]\d+)?";
+
+static QUANTITY: Lazy<Regex> = Lazy::new(|| {
+    #[allow(clippy::unwrap_used)]
+    Regex::new(&format!(
+        r"^(?:({NUMBER})(?:\s*(?:-|–|—|to)\s*({NUMBER}))?|(?i:an?)\b)\s*"
+    ))
+    .unwrap()
+});
+
+static UNIT: Lazy<Regex> = Lazy::new(|| {
+    #[allow(clippy::unwrap_used)]
+    Regex::new(
+        r"^(?:(?i:fl\.?\s*oz|fluid ounces?|millilit(?:er|re)s?|mililiters?|ml|lit(?:er|re)s?|dl|cl|l|kilograms?|kg|grams?|grammes?|mg|g|ounces?|oz|pounds?|lbs?|teaspoons?|tsps?|tablespoons?|tbsps?|tbs|tbl|cups?|pints?|quarts?|gallons?|pinch(?:es)?|dash(?:es)?|cloves?|cans?|packages?|packs?|slices?|bunch(?:es)?|heads?|sprigs?|handfuls?|pieces?|sticks?|stalks?|drops?)|t|T|c)(?:\.|\b)\s*",
+    )
+    .unwrap()
+});
+
+static PARENTHESES: Lazy<Regex> = Lazy::new(|| {
+    #[allow(clippy::unwrap_used)]
+    Regex::new(r"\(([^)]*)\)").unwrap()
+});
+
+static OPTIONAL: Lazy<Regex> = Lazy::new(|| {
+    #[allow(clippy::unwrap_used)]
+    Regex::new(r"(?i)\boptional(?:ly)?\b").unwrap()
+});
+
+static TO_TASTE: Lazy<Regex> = Lazy::new(|| {
+    #[allow(clippy::unwrap_used)]
+    Regex::new(r"(?i)\b(?:to taste|as needed)\b").unwrap()
+});
+
+static LIST_MARKER: Lazy<Regex> = Lazy::new(|| {
+    #[allow(clippy::unwrap_used)]
+    Regex::new(r"^[-*•]\s+").unwrap()
+});
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Quantity {
+    Exact(f64),
+    /// i.e. `2-3 cloves`
+    Range(f64, f64),
+    /// i.e. `salt, to taste`
+    ToTaste,
+}
+
+/// An ingredient written the way people write them, i.e. `2 1/2 tbsp light soy sauce, optional`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedIngredientLine {
+    pub quantity: Option<Quantity>,
+    pub unit: Option<String>,
+    pub name: String,
+    pub notes: Option<String>,
+    pub optional: bool,
+}
+
+fn parse_number(text: &str) -> Option<f64> {
+    let text = text.trim();
+
+    if let Some(fraction) = text.chars().last().and_then(unicode_fraction) {
+        let whole = text
+            .trim_end_matches(|c| UNICODE_FRACTIONS.contains(c))
+            .trim();
+        let whole: f64 = if whole.is_empty() {
+            0.0
+        } else {
+            whole.parse().ok()?
+        };
+        return Some(whole + fraction);
+    }
+
+    let (whole, fraction) = match text.split_once(char::is_whitespace) {
+        Some((whole, fraction)) => (whole.parse().ok()?, fraction.trim()),
+        None => (0.0, text),
+    };
+
+    match fraction.split_once('/') {
+        Some((numerator, denominator)) => {
+            let numerator: f64 = numerator.parse().ok()?;
+            let denominator: f64 = denominator.parse().ok()?;
+            (denominator != 0.0).then(|| whole + numerator / denominator)
+        }
+        None => fraction
+            .replace(',', ".")
+            .parse::<f64>()
+            .ok()
+            .map(|n| whole + n),
+    }
+}
+
+fn unicode_fraction(c: char) -> Option<f64> {
+    let value = match c {
+        '½' => 1.0 / 2.0,
+        '⅓' => 1.0 / 3.0,
+        '⅔' => 2.0 / 3.0,
+        '¼' => 1.0 / 4.0,
+        '¾' => 3.0 / 4.0,
+        '⅕' => 1.0 / 5.0,
+        '⅖' => 2.0 / 5.0,
+        '⅗' => 3.0 / 5.0,
+        '⅘' => 4.0 / 5.0,
+        '⅙' => 1.0 / 6.0,
+        '⅚' => 5.0 / 6.0,
+        '⅛' => 1.0 / 8.0,
+        '⅜' => 3.0 / 8.0,
+        '⅝' => 5.0 / 8.0,
+        '⅞' => 7.0 / 8.0,
+        _ => return None,
+    };
+
+    Some(value)
+}
+
+/// Parses the quantity at the start of the text, returning it along with the rest of the text
+fn parse_quantity(text: &str) -> (Option<Quantity>, &str) {
+    let Some(captures) = QUANTITY.captures(text) else {
+        return (None, text);
+    };
+
+    let rest = &text[captures.get(0).map_or(0, |m| m.end())..];
+    let from = captures.get(1).and_then(|m| parse_number(m.as_str()));
+    let to = captures.get(2).and_then(|m| parse_number(m.as_str()));
+
+    let quantity = match (from, to) {
+        (Some(from), Some(to)) => Quantity::Range(from, to),
+        (Some(amount), None) => Quantity::Exact(amount),
+        // `a pinch of salt`
+        _ => Quantity::Exact(1.0),
+    };
+
+    (Some(quantity), rest)
+}
+
+/// Abbreviations that [`IngredientUnit::from_unit_name`] would not recognize are spelled out
+fn normalize_unit(unit: &str) -> String {
+    let unit = unit.trim().trim_end_matches('.');
+
+    match unit {
+        "t" => "tsp".to_string(),
+        "T" => "tbsp".to_string(),
+        "c" => "cup".to_string(),
+        _ => match unit.to_lowercase().as_str() {
+            "tsps" => "tsp".to_string(),
+            "tbsps" | "tbs" | "tbl" => "tbsp".to_string(),
+            "lbs" => "lb".to_string(),
+            "ounce" | "ounces" => "oz".to_string(),
+            lowercase if lowercase.starts_with("fl") => "fl oz".to_string(),
+            _ => unit.to_string(),
+        },
+    }
+}
+
+fn amount_of(quantity: Option<Quantity>, unit: &str) -> IngredientUnit {
+    match quantity {
+        Some(Quantity::Exact(amount)) => IngredientUnit::from_unit_name(amount, unit),
+        // Both ends go through the unit, so `1-2 kg` is a range of grams
+        Some(Quantity::Range(from, to)) => IngredientUnit::range(
+            IngredientUnit::from_unit_name(from, unit),
+            IngredientUnit::from_unit_name(to, unit).amount(),
+        ),
+        Some(Quantity::ToTaste) => IngredientUnit::Unquantified("to taste".to_string()),
+        None => IngredientUnit::from_unit_name(1.0, unit),
+    }
+}
+
+/// Parses an amount without the ingredient, i.e. `1 1/2 cups` or `4 cloves`.
+/// Text without a leading number is treated as a single unit of whatever it says, unless it says `to taste`.
+/// ```rust
+/// use backend::domain::entities::recipe::{ingredient_line::parse_amount, IngredientUnit};
+///
+/// assert_eq!(parse_amount("1 ½ cups"), IngredientUnit::Cups(1.5));
+/// assert_eq!(parse_amount("200g"), IngredientUnit::Grams(200.0));
+/// assert_eq!(parse_amount("pinch"), IngredientUnit::Other { amount: 1.0, unit: "pinch".to_string() });
+/// assert_eq!(parse_amount("to taste"), IngredientUnit::Unquantified("to taste".to_string()));
+/// ```
+pub fn parse_amount(text: &str) -> IngredientUnit {
+    let (quantity, rest) = parse_quantity(text.trim());
+    if quantity.is_none() && TO_TASTE.is_match(rest) {
+        return amount_of(Some(Quantity::ToTaste), "");
+    }
+
+    amount_of(quantity, &normalize_unit(rest))
+}
+
+impl ParsedIngredientLine {
+    pub fn amount(&self) -> IngredientUnit {
+        amount_of(self.quantity, self.unit.as_deref().unwrap_or_default())
+    }
+}
+
+impl FromStr for ParsedIngredientLine {
+    type Err = ParseIngredientLineError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let line = LIST_MARKER.replace(line.trim(), "");
+        if line.is_empty() {
+            return Err(ParseIngredientLineError::Empty);
+        }
+
+        let mut notes: Vec<String> = PARENTHESES
+            .captures_iter(&line)
+            .map(|c| c[1].to_string())
+            .collect();
+        let line = PARENTHESES.replace_all(&line, " ");
+
+        let (head, tail) = line.split_once([',', ';']).unwrap_or((&line, ""));
+        notes.insert(0, tail.to_string());
+
+        let optional = OPTIONAL.is_match(head) || notes.iter().any(|n| OPTIONAL.is_match(n));
+        let head = OPTIONAL.replace_all(head, "");
+
+        let (quantity, rest) = parse_quantity(head.trim());
+
+        // `salt, to taste` has no quantity to speak of, but `1 tsp salt, or to taste` keeps its note
+        let to_taste = quantity.is_none()
+            && (TO_TASTE.is_match(rest) || notes.iter().any(|n| TO_TASTE.is_match(n)));
+        let (quantity, rest) = if to_taste {
+            (Some(Quantity::ToTaste), TO_TASTE.replace_all(rest, ""))
+        } else {
+            (quantity, rest.into())
+        };
+
+        let notes = notes
+            .iter()
+            .map(|note| {
+                let note = OPTIONAL.replace_all(note, "");
+                if to_taste {
+                    TO_TASTE.replace_all(&note, "").into_owned()
+                } else {
+                    note.into_owned()
+                }
+            })
+            .map(|note| note.trim().trim_matches([',', ';', '-']).trim().to_string())
+            .filter(|note| !note.is_empty())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let (unit, rest) = match UNIT.find(&rest) {
+            Some(unit) => (Some(normalize_unit(unit.as_str())), &rest[unit.end()..]),
+            None => (None, rest.as_ref()),
+        };
+
+        let rest = rest.trim();
+        let name = rest
+            .strip_prefix("of ")
+            .unwrap_or(rest)
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        if name.is_empty() {
+            return Err(ParseIngredientLineError::MissingName(
+                line.trim().to_string(),
+            ));
+        }
+
+        Ok(Self {
+            quantity,
+            unit,
+            name,
+            notes: (!notes.is_empty()).then_some(notes),
+            optional,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,158 @@
+use pretty_assertions::assert_eq;
+
+use crate::domain::entities::recipe::{errors::ParseIngredientLineError, IngredientUnit};
+
+use super::{ParsedIngredientLine, Quantity};
+
+fn parse(line: &str) -> ParsedIngredientLine {
+    line.parse().unwrap()
+}
+
+#[test]
+fn parsing_mixed_fractions_with_notes_works() {
+    let result = parse("2 1/2 tbsp light soy sauce, optional");
+
+    assert_eq!(
+        result,
+        ParsedIngredientLine {
+            quantity: Some(Quantity::Exact(2.5)),
+            unit: Some("tbsp".to_string()),
+            name: "light soy sauce".to_string(),
+            notes: None,
+            optional: true,
+        }
+    );
+    assert_eq!(result.amount(), IngredientUnit::Tablespoons(2.5));
+}
+
+#[test]
+fn parsing_units_attached_to_the_amount_works() {
+    let result = parse("200g flour (sifted)");
+
+    assert_eq!(
+        result,
+        ParsedIngredientLine {
+            quantity: Some(Quantity::Exact(200.0)),
+            unit: Some("g".to_string()),
+            name: "flour".to_string(),
+            notes: Some("sifted".to_string()),
+            optional: false,
+        }
+    );
+    assert_eq!(result.amount(), IngredientUnit::Grams(200.0));
+}
+
+#[test]
+fn parsing_unicode_fractions_works() {
+    assert_eq!(parse("½ cup milk").quantity, Some(Quantity::Exact(0.5)));
+    assert_eq!(parse("1½ cups milk").amount(), IngredientUnit::Cups(1.5));
+    assert_eq!(parse("1 ¼ c. milk").amount(), IngredientUnit::Cups(1.25));
+}
+
+#[test]
+fn parsing_ranges_works() {
+    let result = parse("2-3 cloves garlic, minced");
+
+    assert_eq!(result.quantity, Some(Quantity::Range(2.0, 3.0)));
+    assert_eq!(result.unit.as_deref(), Some("cloves"));
+    assert_eq!(result.name, "garlic");
+    assert_eq!(result.notes.as_deref(), Some("minced"));
+    assert_eq!(
+        result.amount(),
+        IngredientUnit::range(
+            IngredientUnit::Other {
+                amount: 2.0,
+                unit: "cloves".to_string()
+            },
+            3.0
+        )
+    );
+
+    assert_eq!(
+        parse("1 to 2 tsp salt").quantity,
+        Some(Quantity::Range(1.0, 2.0))
+    );
+    assert_eq!(
+        parse("1-2 kg potatoes").amount(),
+        IngredientUnit::range(IngredientUnit::Grams(1000.0), 2000.0)
+    );
+}
+
+#[test]
+fn parsing_abbreviations_works() {
+    assert_eq!(
+        parse("1 T butter").amount(),
+        IngredientUnit::Tablespoons(1.0)
+    );
+    assert_eq!(parse("1 t salt").amount(), IngredientUnit::Teaspoons(1.0));
+    assert_eq!(parse("2 tbs. oil").unit.as_deref(), Some("tbsp"));
+    assert_eq!(parse("1 lbs beef").unit.as_deref(), Some("lb"));
+    assert_eq!(
+        parse("1.5 l water").amount(),
+        IngredientUnit::Mililiters(1500.0)
+    );
+}
+
+#[test]
+fn parsing_lines_without_units_works() {
+    let result = parse("- 2 large eggs");
+
+    assert_eq!(result.quantity, Some(Quantity::Exact(2.0)));
+    assert_eq!(result.unit, None);
+    assert_eq!(result.name, "large eggs");
+    assert_eq!(result.amount(), IngredientUnit::Pieces(2.0));
+
+    let result = parse("a pinch of salt");
+
+    assert_eq!(result.quantity, Some(Quantity::Exact(1.0)));
+    assert_eq!(result.unit.as_deref(), Some("pinch"));
+    assert_eq!(result.name, "salt");
+
+    let result = parse("Salt (optional)");
+
+    assert_eq!(result.quantity, None);
+    assert_eq!(result.name, "Salt");
+    assert!(result.optional);
+}
+
+#[test]
+fn parsing_lines_without_a_name_errors() {
+    assert!(matches!(
+        "  ".parse::<ParsedIngredientLine>(),
+        Err(ParseIngredientLineError::Empty)
+    ));
+    assert!(matches!(
+        "2 cups".parse::<ParsedIngredientLine>(),
+        Err(ParseIngredientLineError::MissingName(line)) if line == "2 cups"
+    ));
+}
+
+#[test]
+fn parsing_to_taste_works() {
+    let result = parse("Salt, to taste");
+
+    assert_eq!(
+        result,
+        ParsedIngredientLine {
+            quantity: Some(Quantity::ToTaste),
+            unit: None,
+            name: "Salt".to_string(),
+            notes: None,
+            optional: false,
+        }
+    );
+    assert_eq!(
+        result.amount(),
+        IngredientUnit::Unquantified("to taste".to_string())
+    );
+
+    let result = parse("black pepper to taste");
+
+    assert_eq!(result.quantity, Some(Quantity::ToTaste));
+    assert_eq!(result.name, "black pepper");
+
+    let result = parse("1 tsp salt, or to taste");
+
+    assert_eq!(result.quantity, Some(Quantity::Exact(1.0)));
+    assert_eq!(result.notes.as_deref(), Some("or to taste"));
+}
@@ -1,4 +1,5 @@
 pub mod errors;
+pub mod ingredient_line;
 use chrono::{DateTime, Utc};
 use derive_more::DerefMut;
 use std::collections::{BTreeMap, BTreeSet};
@@ -6,7 +7,10 @@ use std::collections::{BTreeMap, BTreeSet};
 use common::{
     IngredientAmountDTO, IngredientUnitDTO, IngredientWithAmountDTO, RecipeDTO, ServingsTypeDTO,
 };
-use rayon::{iter::{IntoParallelRefIterator, ParallelIterator}, slice::ParallelSliceMut};
+use rayon::{
+    iter::{IntoParallelRefIterator, ParallelIterator},
+    slice::ParallelSliceMut,
+};
 use serde::{Deserialize, Serialize};
 use shrinkwraprs::Shrinkwrap;
 use sqlx::FromRow;
@@ -14,9 +18,15 @@ use uuid::Uuid;
 
 use self::errors::ValidationError;
 
-use super::ingredient::{Ingredient, IngredientModel};
+use super::{
+    ingredient::{Ingredient, IngredientModel},
+    precondition::entity_tag,
+    review::RecipeRating,
+    share_token::ShareToken,
+    visibility::Visibility,
+};
 
-#[derive(PartialEq, Debug, Clone)]
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
 pub struct Recipe {
     pub id: Uuid,
     pub name: String,
@@ -25,11 +35,24 @@ pub struct Recipe {
     pub ingredients: RecipeIngredients,
     pub time: BTreeMap<String, std::time::Duration>,
     pub servings: ServingsType,
+    /// Summary of the reviews, the reviews themselves are fetched separately
+    pub rating: RecipeRating,
+    /// The recipe this one is a variant of, kept for attribution
+    pub forked_from: Option<Uuid>,
+    pub visibility: Visibility,
+    /// The user who created the recipe, the only one who can hide it from others.
+    /// Recipes made without logging in have no owner and stay public.
+    pub owner_id: Option<Uuid>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
 impl Recipe {
+    /// Tag of the recipe as clients see it
+    pub fn entity_tag(&self) -> String {
+        entity_tag(&RecipeDTO::from(self.clone()))
+    }
+
     fn get_time(&self) -> BTreeMap<String, u64> {
         self.time
             .clone()
@@ -38,7 +61,32 @@ impl Recipe {
             .collect()
     }
 
-    fn get_diet_violations(&self) -> Vec<String> {
+    /// Whether the recipe belongs to the user, visitors that are not logged in own nothing
+    pub fn is_owned_by(&self, user_id: Option<&Uuid>) -> bool {
+        user_id.is_some() && self.owner_id.as_ref() == user_id
+    }
+
+    /// Whether the user can make changes to the recipe. Recipes without an owner were written
+    /// before there were accounts, so they stay open to everyone.
+    pub fn is_editable_by(&self, user_id: Option<&Uuid>) -> bool {
+        self.owner_id.is_none() || self.is_owned_by(user_id)
+    }
+
+    /// Whether the user can see the recipe, given the share token they came with.
+    /// The owner sees their recipe whatever its visibility.
+    pub fn is_visible_to(&self, user_id: Option<&Uuid>, share_token: Option<&ShareToken>) -> bool {
+        if self.is_owned_by(user_id) {
+            return true;
+        }
+
+        match self.visibility {
+            Visibility::Public => true,
+            Visibility::Unlisted => share_token.is_some_and(|t| t.recipe_id == self.id),
+            Visibility::Private => false,
+        }
+    }
+
+    pub fn get_diet_violations(&self) -> Vec<String> {
         self.ingredients
             .iter()
             .fold(BTreeSet::new(), |mut acc, curr| {
@@ -69,11 +117,17 @@ impl From<Recipe> for RecipeDTO {
             updated_at: value.updated_at.to_rfc3339(),
             created_at: value.created_at.to_rfc3339(),
             diet_violations: value.get_diet_violations(),
+            rating_average: value.rating.average,
+            rating_count: value.rating.count,
+            forked_from: value.forked_from.map(|id| id.to_string()),
+            visibility: value.visibility.into(),
         }
     }
 }
 
-#[derive(Debug, Clone, Shrinkwrap, DerefMut)]
+/// The ingredients in the order they are listed in the recipe.
+/// The same ingredient can show up more than once, i.e. salt for both the dough and the sauce.
+#[derive(PartialEq, Debug, Clone, Shrinkwrap, DerefMut, Serialize, Deserialize)]
 pub struct RecipeIngredients(Vec<IngredientWithAmount>);
 
 impl AsRef<[IngredientWithAmount]> for RecipeIngredients {
@@ -91,19 +145,16 @@ impl From<RecipeIngredients> for Vec<IngredientAmountData> {
                 notes: i.notes,
                 amount: i.amount,
                 optional: i.optional,
+                group: i.group,
             })
             .collect()
     }
 }
 
-impl PartialEq for RecipeIngredients {
-    fn eq(&self, other: &Self) -> bool {
-        let mut a = self.0.clone();
-        let mut b = other.0.clone();
-        a.sort_by_key(|f| f.ingredient.id);
-        b.sort_by_key(|f| f.ingredient.id);
-
-        a.eq(&b)
+impl RecipeIngredients {
+    /// Finds the entry by its own ID, not the ID of the ingredient
+    pub fn get_entry(&self, id: &Uuid) -> Option<&IngredientWithAmount> {
+        self.0.iter().find(|entry| entry.id == *id)
     }
 }
 
@@ -118,7 +169,7 @@ impl TryFrom<Vec<IngredientWithAmount>> for RecipeIngredients {
     }
 }
 
-#[derive(PartialEq, Debug, Clone)]
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
 pub struct RecipeSteps(Vec<String>);
 
 impl AsRef<[String]> for RecipeSteps {
@@ -160,6 +211,39 @@ pub enum ServingsType {
     Exact(u16),
 }
 
+impl std::fmt::Display for ServingsType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Exact(1) => write!(f, "1 serving"),
+            Self::Exact(amount) => write!(f, "{} servings", amount),
+            Self::FromTo(from, to) => write!(f, "{}-{} servings", from, to),
+        }
+    }
+}
+
+impl ServingsType {
+    /// How much the amounts have to be multiplied by to make the given number of servings.
+    /// Recipes for a range of servings are assumed to make the middle of that range.
+    /// ```rust
+    /// use backend::domain::entities::recipe::ServingsType;
+    ///
+    /// assert_eq!(ServingsType::Exact(2).scale_factor(6), 3.0);
+    /// assert_eq!(ServingsType::FromTo(2, 6).scale_factor(2), 0.5);
+    /// ```
+    pub fn scale_factor(&self, servings: u16) -> f64 {
+        let base = match self {
+            Self::Exact(amount) => *amount as f64,
+            Self::FromTo(from, to) => (*from as f64 + *to as f64) / 2.0,
+        };
+
+        if base == 0.0 {
+            return 1.0;
+        }
+
+        servings as f64 / base
+    }
+}
+
 impl From<ServingsType> for ServingsTypeDTO {
     fn from(value: ServingsType) -> Self {
         match value {
@@ -184,31 +268,63 @@ impl From<&ServingsTypeDTO> for ServingsType {
     }
 }
 
-#[derive(PartialEq, Debug, Clone)]
+/// A single entry of the ingredient list of a recipe
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
 pub struct IngredientWithAmount {
+    /// ID of the entry, unique within the recipe
+    pub id: Uuid,
     pub ingredient: Ingredient,
     pub amount: IngredientUnit,
     pub notes: Option<String>,
     pub optional: bool,
+    /// Label of the part of the recipe the ingredient is used in, i.e. `For the sauce`
+    pub group: Option<String>,
+}
+
+/// Renders the ingredient the way it would be written in a cookbook, i.e. `4 clove Garlic, minced (optional)`
+/// or `Salt, to taste`
+impl std::fmt::Display for IngredientWithAmount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.amount {
+            IngredientUnit::Unquantified(amount) => {
+                write!(f, "{}, {}", self.ingredient.name, amount)?
+            }
+            amount => write!(f, "{} {}", amount, self.ingredient.name)?,
+        }
+
+        if let Some(notes) = self.notes.as_ref().filter(|n| !n.is_empty()) {
+            write!(f, ", {}", notes)?;
+        }
+
+        if self.optional {
+            write!(f, " (optional)")?;
+        }
+
+        Ok(())
+    }
 }
 
 impl From<IngredientWithAmount> for IngredientWithAmountDTO {
     fn from(value: IngredientWithAmount) -> Self {
         Self {
+            id: value.id,
             ingredient: value.ingredient.into(),
             optional: value.optional,
             notes: value.notes,
             amount: value.amount.into(),
+            group: value.group,
         }
     }
 }
 
 #[derive(FromRow, PartialEq, Debug, Clone)]
 pub struct IngredientWithAmountModel {
+    pub id: Uuid,
     pub ingredient: IngredientModel,
     pub amount: serde_json::Value,
     pub notes: Option<String>,
     pub optional: bool,
+    pub group_label: Option<String>,
 }
 
 // TODO: make more graceful errors
@@ -216,11 +332,13 @@ impl TryFrom<&IngredientWithAmountModel> for IngredientWithAmount {
     type Error = ValidationError;
     fn try_from(value: &IngredientWithAmountModel) -> Result<Self, Self::Error> {
         Ok(Self {
+            id: value.id,
             optional: value.optional,
             notes: value.notes.clone(),
             amount: serde_json::from_value(value.amount.clone())
                 .map_err(|e| ValidationError::DeserializationFailed("amount", e))?,
             ingredient: value.ingredient.clone().try_into()?,
+            group: value.group_label.clone(),
         })
     }
 }
@@ -233,13 +351,27 @@ impl TryFrom<IngredientWithAmountModel> for IngredientWithAmount {
 }
 
 #[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
+#[serde(rename_all = "snake_case", try_from = "IngredientUnitModel")]
 pub enum IngredientUnit {
     Mililiters(f64),
     Grams(f64),
     Teaspoons(f64),
+    Tablespoons(f64),
     Cups(f64),
-    Other { amount: f64, unit: String },
+    /// A number of whole things, i.e. `3 eggs`
+    Pieces(f64),
+    Other {
+        amount: f64,
+        unit: String,
+    },
+    /// Anything from `from` up to `to` of the same unit, i.e. `2–3 cloves`.
+    /// Use [`IngredientUnit::range`] to make one.
+    Range {
+        from: Box<IngredientUnit>,
+        to: f64,
+    },
+    /// An amount nobody measures, i.e. `a pinch` or `to taste`
+    Unquantified(String),
 }
 
 impl Default for IngredientUnit {
@@ -248,6 +380,44 @@ impl Default for IngredientUnit {
     }
 }
 
+/// How an [`IngredientUnit`] is stored, so that ranges read back are checked the same way
+/// [`IngredientUnit::range`] checks the ones made in code
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum IngredientUnitModel {
+    Mililiters(f64),
+    Grams(f64),
+    Teaspoons(f64),
+    Tablespoons(f64),
+    Cups(f64),
+    Pieces(f64),
+    Other { amount: f64, unit: String },
+    Range { from: Box<IngredientUnit>, to: f64 },
+    Unquantified(String),
+}
+
+impl TryFrom<IngredientUnitModel> for IngredientUnit {
+    type Error = ValidationError;
+    fn try_from(value: IngredientUnitModel) -> Result<Self, Self::Error> {
+        Ok(match value {
+            IngredientUnitModel::Mililiters(amount) => Self::Mililiters(amount),
+            IngredientUnitModel::Grams(amount) => Self::Grams(amount),
+            IngredientUnitModel::Teaspoons(amount) => Self::Teaspoons(amount),
+            IngredientUnitModel::Tablespoons(amount) => Self::Tablespoons(amount),
+            IngredientUnitModel::Cups(amount) => Self::Cups(amount),
+            IngredientUnitModel::Pieces(amount) => Self::Pieces(amount),
+            IngredientUnitModel::Other { amount, unit } => Self::Other { amount, unit },
+            IngredientUnitModel::Range { from, to } => match *from {
+                Self::Range { .. } | Self::Unquantified(_) => {
+                    return Err(ValidationError::InvalidRange)
+                }
+                from => Self::range(from, to),
+            },
+            IngredientUnitModel::Unquantified(amount) => Self::Unquantified(amount),
+        })
+    }
+}
+
 impl From<IngredientUnitDTO> for IngredientUnit {
     fn from(value: IngredientUnitDTO) -> Self {
         match value {
@@ -255,7 +425,11 @@ impl From<IngredientUnitDTO> for IngredientUnit {
             IngredientUnitDTO::Grams(amount) => Self::Grams(amount),
             IngredientUnitDTO::Mililiters(amount) => Self::Mililiters(amount),
             IngredientUnitDTO::Teaspoons(amount) => Self::Teaspoons(amount),
+            IngredientUnitDTO::Tablespoons(amount) => Self::Tablespoons(amount),
+            IngredientUnitDTO::Pieces(amount) => Self::Pieces(amount),
             IngredientUnitDTO::Other { amount, unit } => Self::Other { amount, unit },
+            IngredientUnitDTO::Range { from, to } => Self::range((*from).into(), to),
+            IngredientUnitDTO::Unquantified(amount) => Self::Unquantified(amount),
         }
     }
 }
@@ -267,21 +441,338 @@ impl From<IngredientUnit> for IngredientUnitDTO {
             IngredientUnit::Grams(amount) => Self::Grams(amount),
             IngredientUnit::Mililiters(amount) => Self::Mililiters(amount),
             IngredientUnit::Teaspoons(amount) => Self::Teaspoons(amount),
+            IngredientUnit::Tablespoons(amount) => Self::Tablespoons(amount),
+            IngredientUnit::Pieces(amount) => Self::Pieces(amount),
             IngredientUnit::Other { amount, unit } => Self::Other { amount, unit },
+            IngredientUnit::Range { from, to } => Self::Range {
+                from: Box::new((*from).into()),
+                to,
+            },
+            IngredientUnit::Unquantified(amount) => Self::Unquantified(amount),
+        }
+    }
+}
+
+/// Rounds the amount to three decimal places, so converted amounts do not show as `0.33333333333333`
+fn format_amount(amount: f64) -> String {
+    let rounded = (amount * 1000.0).round() / 1000.0;
+    rounded.to_string()
+}
+
+/// ```rust
+/// use backend::domain::entities::recipe::IngredientUnit;
+///
+/// assert_eq!(IngredientUnit::Grams(400.0).to_string(), "400 g");
+/// assert_eq!(IngredientUnit::Cups(0.5).to_string(), "0.5 cup");
+/// assert_eq!(IngredientUnit::Other { amount: 2.0, unit: "".to_string() }.to_string(), "2");
+/// assert_eq!(IngredientUnit::Pieces(3.0).to_string(), "3");
+/// assert_eq!(IngredientUnit::range(IngredientUnit::Cups(1.0), 2.0).to_string(), "1–2 cups");
+/// ```
+impl std::fmt::Display for IngredientUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Grams(amount) => write!(f, "{} g", format_amount(*amount)),
+            Self::Mililiters(amount) => write!(f, "{} ml", format_amount(*amount)),
+            Self::Teaspoons(amount) => write!(f, "{} tsp", format_amount(*amount)),
+            Self::Tablespoons(amount) => write!(f, "{} tbsp", format_amount(*amount)),
+            Self::Cups(amount) if *amount > 1.0 => write!(f, "{} cups", format_amount(*amount)),
+            Self::Cups(amount) => write!(f, "{} cup", format_amount(*amount)),
+            Self::Pieces(amount) => write!(f, "{}", format_amount(*amount)),
+            Self::Other { amount, unit } if unit.is_empty() => {
+                write!(f, "{}", format_amount(*amount))
+            }
+            Self::Other { amount, unit } => write!(f, "{} {}", format_amount(*amount), unit),
+            // The unit goes with the upper bound, so it is pluralized the way it is read
+            Self::Range { from, to } => write!(
+                f,
+                "{}–{}",
+                format_amount(from.amount()),
+                from.with_amount(*to)
+            ),
+            Self::Unquantified(amount) => write!(f, "{}", amount),
         }
     }
 }
 
+const TEASPOONS_IN_TABLESPOON: f64 = 3.0;
+const TEASPOONS_IN_CUP: f64 = 48.0;
+const MILILITERS_IN_TEASPOON: f64 = 4.928_92;
+
+/// Units that are not stored as their own variant, but can still be converted to one
+fn convert_other_unit(amount: f64, unit: &str) -> Option<IngredientUnit> {
+    let result = match unit.trim().trim_end_matches('.').to_lowercase().as_str() {
+        "oz" | "ounce" | "ounces" => IngredientUnit::Grams(amount * 28.349_5),
+        "lb" | "lbs" | "pound" | "pounds" => IngredientUnit::Grams(amount * 453.592),
+        "fl oz" | "fluid ounce" | "fluid ounces" => IngredientUnit::Mililiters(amount * 29.573_5),
+        "pint" | "pints" => IngredientUnit::Cups(amount * 2.0),
+        "quart" | "quarts" => IngredientUnit::Cups(amount * 4.0),
+        "gallon" | "gallons" => IngredientUnit::Cups(amount * 16.0),
+        "" | "piece" | "pieces" => IngredientUnit::Pieces(amount),
+        _ => return None,
+    };
+
+    Some(result)
+}
+
+/// `clove` and `cloves` are the same unit
+fn is_same_unit(a: &str, b: &str) -> bool {
+    let normalize = |unit: &str| unit.trim().trim_end_matches('.').to_lowercase();
+    let (a, b) = (normalize(a), normalize(b));
+    let is_plural_of = |plural: &str, singular: &str| {
+        plural
+            .strip_prefix(singular)
+            .is_some_and(|suffix| suffix == "s" || suffix == "es")
+    };
+
+    a == b || is_plural_of(&a, &b) || is_plural_of(&b, &a)
+}
+
 impl IngredientUnit {
-    /// Converts tablespoons to teaspoons
-    /// 1 tbsp = 3 tsp
+    /// Makes a range of the amount up to `to` of the same unit.
+    /// The bounds are swapped if they are the wrong way round, and equal bounds are not a range at all.
+    /// ```rust
+    /// use backend::domain::entities::recipe::IngredientUnit;
+    ///
+    /// assert_eq!(
+    ///     IngredientUnit::range(IngredientUnit::Grams(200.0), 100.0),
+    ///     IngredientUnit::range(IngredientUnit::Grams(100.0), 200.0)
+    /// );
+    /// assert_eq!(IngredientUnit::range(IngredientUnit::Pieces(2.0), 2.0), IngredientUnit::Pieces(2.0));
+    /// ```
+    pub fn range(from: Self, to: f64) -> Self {
+        let from = match from {
+            Self::Range { from, .. } => *from,
+            Self::Unquantified(_) => return from,
+            from => from,
+        };
+
+        let amount = from.amount();
+        if (amount - to).abs() < f64::EPSILON {
+            return from;
+        }
+
+        if to < amount {
+            return Self::Range {
+                from: Box::new(from.with_amount(to)),
+                to: amount,
+            };
+        }
+
+        Self::Range {
+            from: Box::new(from),
+            to,
+        }
+    }
+
+    /// The amount in the unit. Ranges give their lower bound, and amounts that are not measured give zero.
+    pub fn amount(&self) -> f64 {
+        match self {
+            Self::Mililiters(amount)
+            | Self::Grams(amount)
+            | Self::Teaspoons(amount)
+            | Self::Tablespoons(amount)
+            | Self::Cups(amount)
+            | Self::Pieces(amount)
+            | Self::Other { amount, .. } => *amount,
+            Self::Range { from, .. } => from.amount(),
+            Self::Unquantified(_) => 0.0,
+        }
+    }
+
+    /// The same unit with a different amount
+    fn with_amount(&self, amount: f64) -> Self {
+        match self {
+            Self::Mililiters(_) => Self::Mililiters(amount),
+            Self::Grams(_) => Self::Grams(amount),
+            Self::Teaspoons(_) => Self::Teaspoons(amount),
+            Self::Tablespoons(_) => Self::Tablespoons(amount),
+            Self::Cups(_) => Self::Cups(amount),
+            Self::Pieces(_) => Self::Pieces(amount),
+            Self::Other { unit, .. } => Self::Other {
+                amount,
+                unit: unit.clone(),
+            },
+            Self::Range { from, .. } => from.with_amount(amount),
+            Self::Unquantified(_) => self.clone(),
+        }
+    }
+
+    /// The lowest and the highest the amount can be
+    fn bounds(&self) -> (Self, Self) {
+        match self {
+            Self::Range { from, to } => ((**from).clone(), from.with_amount(*to)),
+            unit => (unit.clone(), unit.clone()),
+        }
+    }
+
+    /// Multiplies the amount, keeping the unit
+    /// ```rust
+    /// use backend::domain::entities::recipe::IngredientUnit;
+    ///
+    /// assert_eq!(IngredientUnit::Grams(200.0).scale(1.5), IngredientUnit::Grams(300.0));
+    /// ```
+    pub fn scale(&self, factor: f64) -> Self {
+        match self {
+            Self::Mililiters(amount) => Self::Mililiters(amount * factor),
+            Self::Grams(amount) => Self::Grams(amount * factor),
+            Self::Teaspoons(amount) => Self::Teaspoons(amount * factor),
+            Self::Tablespoons(amount) => Self::Tablespoons(amount * factor),
+            Self::Cups(amount) => Self::Cups(amount * factor),
+            Self::Pieces(amount) => Self::Pieces(amount * factor),
+            Self::Other { amount, unit } => Self::Other {
+                amount: amount * factor,
+                unit: unit.clone(),
+            },
+            Self::Range { from, to } => Self::Range {
+                from: Box::new(from.scale(factor)),
+                to: to * factor,
+            },
+            Self::Unquantified(_) => self.clone(),
+        }
+    }
+
+    fn in_teaspoons(&self) -> Option<f64> {
+        match self {
+            Self::Teaspoons(amount) => Some(*amount),
+            Self::Tablespoons(amount) => Some(amount * TEASPOONS_IN_TABLESPOON),
+            Self::Cups(amount) => Some(amount * TEASPOONS_IN_CUP),
+            _ => None,
+        }
+    }
+
+    fn in_mililiters(&self) -> Option<f64> {
+        match self {
+            Self::Mililiters(amount) => Some(*amount),
+            unit => Some(unit.in_teaspoons()? * MILILITERS_IN_TEASPOON),
+        }
+    }
+
+    fn add_same_unit(&self, other: &Self) -> Option<Self> {
+        let result = match (self, other) {
+            (Self::Grams(a), Self::Grams(b)) => Self::Grams(a + b),
+            (Self::Mililiters(a), Self::Mililiters(b)) => Self::Mililiters(a + b),
+            (Self::Teaspoons(a), Self::Teaspoons(b)) => Self::Teaspoons(a + b),
+            (Self::Tablespoons(a), Self::Tablespoons(b)) => Self::Tablespoons(a + b),
+            (Self::Cups(a), Self::Cups(b)) => Self::Cups(a + b),
+            (Self::Pieces(a), Self::Pieces(b)) => Self::Pieces(a + b),
+            (Self::Unquantified(a), Self::Unquantified(b)) if a.eq_ignore_ascii_case(b) => {
+                Self::Unquantified(a.clone())
+            }
+            (
+                Self::Other { amount: a, unit },
+                Self::Other {
+                    amount: b,
+                    unit: other_unit,
+                },
+            ) if is_same_unit(unit, other_unit) => Self::Other {
+                amount: a + b,
+                unit: unit.clone(),
+            },
+            (a, b) if a.in_teaspoons().is_some() && b.in_teaspoons().is_some() => {
+                Self::Teaspoons(a.in_teaspoons()? + b.in_teaspoons()?)
+            }
+            (a, b) => Self::Mililiters(a.in_mililiters()? + b.in_mililiters()?),
+        };
+
+        Some(result)
+    }
+
+    /// Adds two amounts together, converting between units where needed.
+    /// The bounds of ranges are added up separately.
+    /// Returns `None` when the units can't be converted into each other, i.e. grams and cups.
+    /// ```rust
+    /// use backend::domain::entities::recipe::IngredientUnit;
+    ///
+    /// assert_eq!(
+    ///     IngredientUnit::Teaspoons(3.0).checked_add(&IngredientUnit::Cups(0.5)),
+    ///     Some(IngredientUnit::Teaspoons(27.0))
+    /// );
+    /// assert_eq!(
+    ///     IngredientUnit::Grams(100.0).checked_add(&IngredientUnit::Other { amount: 1.0, unit: "lb".to_string() }),
+    ///     Some(IngredientUnit::Grams(553.592))
+    /// );
+    /// assert_eq!(
+    ///     IngredientUnit::range(IngredientUnit::Pieces(2.0), 3.0).checked_add(&IngredientUnit::Pieces(1.0)),
+    ///     Some(IngredientUnit::range(IngredientUnit::Pieces(3.0), 4.0))
+    /// );
+    /// assert_eq!(IngredientUnit::Grams(100.0).checked_add(&IngredientUnit::Cups(1.0)), None);
+    /// ```
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        if matches!(self, Self::Range { .. }) || matches!(other, Self::Range { .. }) {
+            let (low, high) = self.bounds();
+            let (other_low, other_high) = other.bounds();
+
+            let low = low.checked_add(&other_low)?;
+            let high = high.checked_add(&other_high)?;
+
+            return Some(Self::range(low, high.amount()));
+        }
+
+        if let Some(result) = self.add_same_unit(other) {
+            return Some(result);
+        }
+
+        let normalize = |unit: &Self| match unit {
+            Self::Other { amount, unit } => convert_other_unit(*amount, unit),
+            unit => Some(unit.clone()),
+        };
+
+        normalize(self)?.add_same_unit(&normalize(other)?)
+    }
+
+    /// Whether there is at least as much of this as `needed`.
+    /// Ranges count with their lower bound, and any amount is enough for one that is not measured.
+    /// Returns `None` when the units can't be converted into each other.
+    /// ```rust
+    /// use backend::domain::entities::recipe::IngredientUnit;
+    ///
+    /// assert_eq!(IngredientUnit::Cups(1.0).covers(&IngredientUnit::Teaspoons(12.0)), Some(true));
+    /// assert_eq!(IngredientUnit::Grams(100.0).covers(&IngredientUnit::Grams(400.0)), Some(false));
+    /// assert_eq!(IngredientUnit::Grams(100.0).covers(&IngredientUnit::Cups(1.0)), None);
+    /// assert_eq!(IngredientUnit::Grams(1.0).covers(&IngredientUnit::Unquantified("to taste".to_string())), Some(true));
+    /// ```
+    pub fn covers(&self, needed: &Self) -> Option<bool> {
+        if let Self::Unquantified(_) = needed {
+            return Some(true);
+        }
+
+        let (available, _) = self.bounds();
+        let (needed, _) = needed.bounds();
+        let rest = available.checked_add(&needed.scale(-1.0))?;
+
+        Some(rest.amount() >= -f64::EPSILON)
+    }
+
+    /// Picks the unit from its name (or abbreviation), as written in a recipe.
+    /// Kilograms and liters are converted to grams and mililiters, unknown units are kept as-is.
+    /// Amounts without a unit are a number of pieces.
     /// ```rust
-    /// use crate::backend::domain::entities::recipe::IngredientUnit;
+    /// use backend::domain::entities::recipe::IngredientUnit;
     ///
-    /// assert_eq!(IngredientUnit::from_tablespoons(4.0), IngredientUnit::Teaspoons(12.0))
+    /// assert_eq!(IngredientUnit::from_unit_name(2.0, "Tbsp"), IngredientUnit::Tablespoons(2.0));
+    /// assert_eq!(IngredientUnit::from_unit_name(3.0, ""), IngredientUnit::Pieces(3.0));
+    /// assert_eq!(IngredientUnit::from_unit_name(1.5, "kg"), IngredientUnit::Grams(1500.0));
+    /// assert_eq!(
+    ///     IngredientUnit::from_unit_name(4.0, "cloves"),
+    ///     IngredientUnit::Other { amount: 4.0, unit: "cloves".to_string() }
+    /// );
     /// ```
-    pub fn from_tablespoons(tablespoons: f64) -> Self {
-        Self::Teaspoons(tablespoons * 3.0)
+    pub fn from_unit_name(amount: f64, unit: &str) -> Self {
+        match unit.trim().trim_end_matches('.').to_lowercase().as_str() {
+            "g" | "gram" | "grams" | "gramme" | "grammes" => Self::Grams(amount),
+            "kg" | "kilogram" | "kilograms" => Self::Grams(amount * 1000.0),
+            "ml" | "mililiter" | "mililiters" | "milliliter" | "milliliters" | "millilitre"
+            | "millilitres" => Self::Mililiters(amount),
+            "l" | "liter" | "liters" | "litre" | "litres" => Self::Mililiters(amount * 1000.0),
+            "tsp" | "teaspoon" | "teaspoons" => Self::Teaspoons(amount),
+            "tbsp" | "tablespoon" | "tablespoons" => Self::Tablespoons(amount),
+            "cup" | "cups" => Self::Cups(amount),
+            "" | "piece" | "pieces" => Self::Pieces(amount),
+            _ => Self::Other {
+                amount,
+                unit: unit.trim().to_string(),
+            },
+        }
     }
 }
 
@@ -292,6 +783,7 @@ pub struct RecipeChangeset {
     pub steps: Option<RecipeSteps>,
     pub time: Option<BTreeMap<String, std::time::Duration>>,
     pub servings: Option<ServingsType>,
+    pub visibility: Option<Visibility>,
 }
 
 impl RecipeChangeset {
@@ -302,6 +794,7 @@ impl RecipeChangeset {
             steps,
             time,
             servings,
+            visibility,
         } = self;
 
         name.is_none()
@@ -309,6 +802,68 @@ impl RecipeChangeset {
             && steps.is_none()
             && time.is_none()
             && servings.is_none()
+            && visibility.is_none()
+    }
+}
+
+/// Changes to a single ingredient of a recipe
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct IngredientInRecipeChangeset {
+    pub amount: Option<IngredientUnit>,
+    /// `Some(None)` removes the notes
+    pub notes: Option<Option<String>>,
+    pub optional: Option<bool>,
+    /// `Some(None)` takes the ingredient out of its group
+    pub group: Option<Option<String>>,
+    /// Where to move the ingredient in the list, starting from zero
+    pub position: Option<usize>,
+}
+
+impl IngredientInRecipeChangeset {
+    pub fn is_empty(&self) -> bool {
+        let IngredientInRecipeChangeset {
+            amount,
+            notes,
+            optional,
+            group,
+            position,
+        } = self;
+
+        amount.is_none()
+            && notes.is_none()
+            && optional.is_none()
+            && group.is_none()
+            && position.is_none()
+    }
+
+    /// Applies everything except the position, which depends on the rest of the list
+    pub fn apply(&self, ingredient: &mut IngredientWithAmount) {
+        if let Some(amount) = &self.amount {
+            ingredient.amount = amount.clone();
+        }
+        if let Some(notes) = &self.notes {
+            ingredient.notes = notes.clone();
+        }
+        if let Some(optional) = self.optional {
+            ingredient.optional = optional;
+        }
+        if let Some(group) = &self.group {
+            ingredient.group = group.clone();
+        }
+    }
+
+    /// Moves the item at `from` to the requested position, keeping the rest in order.
+    /// Positions past the end move the item to the end of the list.
+    pub fn reorder<T>(&self, items: &mut Vec<T>, from: usize) {
+        let Some(to) = self.position else {
+            return;
+        };
+        if from >= items.len() {
+            return;
+        }
+
+        let item = items.remove(from);
+        items.insert(to.min(items.len()), item);
     }
 }
 
@@ -318,6 +873,21 @@ pub struct IngredientAmountData {
     pub amount: IngredientUnit,
     pub optional: bool,
     pub notes: Option<String>,
+    pub group: Option<String>,
+}
+
+impl IngredientAmountData {
+    /// Turns the data into a new entry of the ingredient list
+    pub fn into_entry(self, ingredient: Ingredient) -> IngredientWithAmount {
+        IngredientWithAmount {
+            id: Uuid::now_v7(),
+            ingredient,
+            amount: self.amount,
+            notes: self.notes,
+            optional: self.optional,
+            group: self.group,
+        }
+    }
 }
 
 impl From<IngredientWithAmount> for IngredientAmountData {
@@ -327,6 +897,19 @@ impl From<IngredientWithAmount> for IngredientAmountData {
             amount: value.amount,
             notes: value.notes,
             optional: value.optional,
+            group: value.group,
+        }
+    }
+}
+
+impl From<IngredientAmountData> for IngredientAmountDTO {
+    fn from(value: IngredientAmountData) -> Self {
+        Self {
+            ingredient_id: value.ingredient_id,
+            amount: value.amount.into(),
+            optional: value.optional,
+            notes: value.notes,
+            group: value.group,
         }
     }
 }
@@ -338,6 +921,7 @@ impl From<IngredientAmountDTO> for IngredientAmountData {
             amount,
             optional,
             notes,
+            group,
         }: IngredientAmountDTO,
     ) -> Self {
         let amount = amount.into();
@@ -346,6 +930,9 @@ impl From<IngredientAmountDTO> for IngredientAmountData {
             amount,
             optional,
             notes,
+            group: group
+                .map(|group| group.trim().to_string())
+                .filter(|group| !group.is_empty()),
         }
     }
 }
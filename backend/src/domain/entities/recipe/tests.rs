@@ -8,34 +8,44 @@ use crate::domain::entities::{
     recipe::errors::ValidationError,
 };
 
-use super::{IngredientWithAmount, IngredientWithAmountModel};
+use common::IngredientUnitDTO;
+
+use super::{
+    IngredientInRecipeChangeset, IngredientUnit, IngredientWithAmount, IngredientWithAmountModel,
+};
 
 #[test]
 fn converting_ingredient_with_amount_works() {
     let input = IngredientWithAmountModel {
+        id: Uuid::from_u128(1),
         ingredient: IngredientModel {
             id: Uuid::nil(),
             name: "Ingredient name".to_owned(),
             description: "Ingredient description".to_owned(),
             diet_violations: vec![],
+            updated_at: Default::default(),
         },
         amount: serde_json::json!({
             "grams": 20
         }),
         notes: None,
         optional: false,
+        group_label: Some("For the sauce".to_owned()),
     };
 
     let expected = IngredientWithAmount {
+        id: Uuid::from_u128(1),
         ingredient: Ingredient {
             id: Uuid::nil(),
             name: IngredientName("Ingredient name".to_owned()),
             description: IngredientDescription("Ingredient description".to_owned()),
             diet_violations: WhichDiets::new(),
+            updated_at: Default::default(),
         },
         amount: super::IngredientUnit::Grams(20.0),
         notes: None,
         optional: false,
+        group: Some("For the sauce".to_owned()),
     };
 
     let result: IngredientWithAmount = input.try_into().unwrap();
@@ -46,11 +56,13 @@ fn converting_ingredient_with_amount_works() {
 #[test]
 fn converting_ingredient_with_custom_amount_unit_works() {
     let input = IngredientWithAmountModel {
+        id: Uuid::from_u128(1),
         ingredient: IngredientModel {
             id: Uuid::nil(),
             name: "Ingredient name".to_owned(),
             description: "Ingredient description".to_owned(),
             diet_violations: vec![],
+            updated_at: Default::default(),
         },
         amount: serde_json::json!({
             "other": {
@@ -60,14 +72,17 @@ fn converting_ingredient_with_custom_amount_unit_works() {
         }),
         notes: None,
         optional: false,
+        group_label: None,
     };
 
     let expected = IngredientWithAmount {
+        id: Uuid::from_u128(1),
         ingredient: Ingredient {
             id: Uuid::nil(),
             name: IngredientName("Ingredient name".to_owned()),
             description: IngredientDescription("Ingredient description".to_owned()),
             diet_violations: WhichDiets::new(),
+            updated_at: Default::default(),
         },
         amount: super::IngredientUnit::Other {
             unit: "cloves".to_owned(),
@@ -75,6 +90,7 @@ fn converting_ingredient_with_custom_amount_unit_works() {
         },
         notes: None,
         optional: false,
+        group: None,
     };
 
     let result: IngredientWithAmount = input.try_into().unwrap();
@@ -85,11 +101,13 @@ fn converting_ingredient_with_custom_amount_unit_works() {
 #[test]
 fn converting_ingredient_with_custom_amount_unit_but_without_unit_descriptor_fails() {
     let input = IngredientWithAmountModel {
+        id: Uuid::from_u128(1),
         ingredient: IngredientModel {
             id: Uuid::nil(),
             name: "Ingredient name".to_owned(),
             description: "Ingredient description".to_owned(),
             diet_violations: vec![],
+            updated_at: Default::default(),
         },
         amount: serde_json::json!({
             "other": {
@@ -98,6 +116,7 @@ fn converting_ingredient_with_custom_amount_unit_but_without_unit_descriptor_fai
         }),
         notes: None,
         optional: false,
+        group_label: None,
     };
 
     let result: ValidationError =
@@ -112,15 +131,18 @@ fn converting_ingredient_with_custom_amount_unit_but_without_unit_descriptor_fai
 #[test]
 fn malformed_ingredient_amount_fails() {
     let input = IngredientWithAmountModel {
+        id: Uuid::from_u128(1),
         ingredient: IngredientModel {
             id: Uuid::nil(),
             name: "Ingredient name".to_owned(),
             description: "Ingredient description".to_owned(),
             diet_violations: vec![],
+            updated_at: Default::default(),
         },
         amount: serde_json::json!("10 grams"),
         notes: None,
         optional: false,
+        group_label: None,
     };
 
     let result: ValidationError =
@@ -131,3 +153,181 @@ fn malformed_ingredient_amount_fails() {
         ValidationError::DeserializationFailed("amount", _)
     ))
 }
+
+#[test]
+fn converting_ingredient_with_range_amount_works() {
+    let input = IngredientWithAmountModel {
+        id: Uuid::from_u128(1),
+        ingredient: IngredientModel {
+            id: Uuid::nil(),
+            name: "Garlic".to_owned(),
+            description: "Ingredient description".to_owned(),
+            diet_violations: vec![],
+            updated_at: Default::default(),
+        },
+        amount: serde_json::json!({
+            "range": {
+                "from": { "other": { "amount": 2, "unit": "cloves" } },
+                "to": 3
+            }
+        }),
+        notes: None,
+        optional: false,
+        group_label: None,
+    };
+
+    let result: IngredientWithAmount = input.try_into().unwrap();
+
+    assert_eq!(
+        result.amount,
+        IngredientUnit::range(
+            IngredientUnit::Other {
+                amount: 2.0,
+                unit: "cloves".to_owned()
+            },
+            3.0
+        )
+    );
+    assert_eq!(result.to_string(), "2–3 cloves Garlic");
+}
+
+#[test]
+fn unquantified_amounts_are_written_after_the_ingredient() {
+    let input = IngredientWithAmountModel {
+        id: Uuid::from_u128(1),
+        ingredient: IngredientModel {
+            id: Uuid::nil(),
+            name: "Salt".to_owned(),
+            description: "Ingredient description".to_owned(),
+            diet_violations: vec![],
+            updated_at: Default::default(),
+        },
+        amount: serde_json::json!({ "unquantified": "to taste" }),
+        notes: None,
+        optional: false,
+        group_label: None,
+    };
+
+    let result: IngredientWithAmount = input.try_into().unwrap();
+
+    assert_eq!(result.to_string(), "Salt, to taste");
+}
+
+#[test]
+fn ranges_round_trip_through_the_dto() {
+    let amount = IngredientUnit::range(IngredientUnit::Tablespoons(1.0), 2.0);
+
+    let dto = IngredientUnitDTO::from(amount.clone());
+
+    assert_eq!(
+        serde_json::to_value(&dto).unwrap(),
+        serde_json::json!({
+            "_type": "range",
+            "amount": {
+                "from": { "_type": "tablespoons", "amount": 1.0 },
+                "to": 2.0
+            }
+        })
+    );
+    assert_eq!(IngredientUnit::from(dto), amount);
+}
+
+#[test]
+fn ranges_from_the_dto_are_normalized() {
+    let dto = IngredientUnitDTO::Range {
+        from: Box::new(IngredientUnitDTO::Pieces(3.0)),
+        to: 2.0,
+    };
+
+    assert_eq!(
+        IngredientUnit::from(dto),
+        IngredientUnit::range(IngredientUnit::Pieces(2.0), 3.0)
+    );
+}
+
+#[test]
+fn stored_ranges_are_normalized() {
+    let amount: IngredientUnit = serde_json::from_value(serde_json::json!({
+        "range": { "from": { "pieces": 3 }, "to": 3 }
+    }))
+    .unwrap();
+
+    assert_eq!(amount, IngredientUnit::Pieces(3.0));
+
+    let amount: IngredientUnit = serde_json::from_value(serde_json::json!({
+        "range": { "from": { "grams": 200 }, "to": 100 }
+    }))
+    .unwrap();
+
+    assert_eq!(
+        amount,
+        IngredientUnit::range(IngredientUnit::Grams(100.0), 200.0)
+    );
+}
+
+#[test]
+fn stored_ranges_must_start_at_a_measured_amount() {
+    for from in [
+        serde_json::json!({ "range": { "from": { "pieces": 1 }, "to": 2 } }),
+        serde_json::json!({ "unquantified": "a handful" }),
+    ] {
+        let input = IngredientWithAmountModel {
+            id: Uuid::from_u128(1),
+            ingredient: IngredientModel {
+                id: Uuid::nil(),
+                name: "Ingredient name".to_owned(),
+                description: "Ingredient description".to_owned(),
+                diet_violations: vec![],
+                updated_at: Default::default(),
+            },
+            amount: serde_json::json!({ "range": { "from": from, "to": 3 } }),
+            notes: None,
+            optional: false,
+            group_label: None,
+        };
+
+        let result: ValidationError =
+            std::convert::TryInto::<IngredientWithAmount>::try_into(input).unwrap_err();
+
+        assert!(matches!(
+            result,
+            ValidationError::DeserializationFailed("amount", _)
+        ))
+    }
+}
+
+#[test]
+fn scaling_a_range_scales_both_ends() {
+    let amount = IngredientUnit::range(IngredientUnit::Pieces(2.0), 3.0);
+
+    assert_eq!(
+        amount.scale(2.0),
+        IngredientUnit::range(IngredientUnit::Pieces(4.0), 6.0)
+    );
+    assert_eq!(
+        IngredientUnit::Unquantified("a pinch".to_owned()).scale(2.0),
+        IngredientUnit::Unquantified("a pinch".to_owned())
+    );
+}
+
+#[test]
+fn reordering_moves_a_single_item() {
+    let changeset = IngredientInRecipeChangeset {
+        position: Some(0),
+        ..Default::default()
+    };
+    let mut items = vec!['a', 'b', 'c', 'd'];
+
+    changeset.reorder(&mut items, 2);
+    assert_eq!(items, vec!['c', 'a', 'b', 'd']);
+
+    let changeset = IngredientInRecipeChangeset {
+        position: Some(100),
+        ..Default::default()
+    };
+    changeset.reorder(&mut items, 0);
+    assert_eq!(items, vec!['a', 'b', 'd', 'c']);
+
+    IngredientInRecipeChangeset::default().reorder(&mut items, 0);
+    assert_eq!(items, vec!['a', 'b', 'd', 'c']);
+}
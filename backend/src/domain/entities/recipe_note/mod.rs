@@ -0,0 +1,25 @@
+use chrono::{DateTime, Utc};
+use common::RecipeNoteDTO;
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Private notes of a user on a recipe, kept apart from the recipe so that writing them doesn't change it
+#[derive(FromRow, PartialEq, Debug, Clone)]
+pub struct RecipeNote {
+    pub user_id: Uuid,
+    pub recipe_id: Uuid,
+    pub content: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<RecipeNote> for RecipeNoteDTO {
+    fn from(value: RecipeNote) -> Self {
+        Self {
+            recipe_id: value.recipe_id,
+            content: value.content,
+            created_at: value.created_at.to_rfc3339(),
+            updated_at: value.updated_at.to_rfc3339(),
+        }
+    }
+}
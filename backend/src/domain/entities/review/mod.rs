@@ -0,0 +1,164 @@
+use std::cmp::Ordering;
+
+use chrono::{DateTime, Utc};
+use common::ReviewDTO;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use super::recipe::errors::ValidationError;
+
+/// Amount of stars given to a recipe, from 1 to 5
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy)]
+pub struct Rating(u8);
+
+impl Rating {
+    pub const MIN: u8 = 1;
+    pub const MAX: u8 = 5;
+
+    pub fn new(value: impl Into<i64>) -> Result<Self, ValidationError> {
+        let value = value.into();
+
+        if (Self::MIN.into()..=Self::MAX.into()).contains(&value) {
+            Ok(Self(value as u8))
+        } else {
+            Err(ValidationError::OutOfRange(
+                "rating",
+                Self::MIN.into(),
+                Self::MAX.into(),
+            ))
+        }
+    }
+
+    pub fn value(&self) -> u8 {
+        self.0
+    }
+}
+
+/// A rating of a recipe by a user, with an optional text.
+/// Every user can review a recipe only once, but they can edit their review later.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Review {
+    pub id: Uuid,
+    pub recipe_id: Uuid,
+    /// The user who wrote the review, the only one allowed to edit it.
+    /// Reviews from before there were accounts have no author that can be told apart, so nobody can edit them.
+    pub user_id: Option<Uuid>,
+    /// Username of the author when they wrote the review
+    pub author: String,
+    pub rating: Rating,
+    pub body: Option<String>,
+    pub created_at: DateTime<Utc>,
+    /// Moves forward only when the rating or the body changes, so that moderators can tell edited reviews apart
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Review {
+    /// Applies the changes to the review, returning whether anything was actually changed
+    pub fn apply(&mut self, changeset: &ReviewChangeset) -> bool {
+        let mut updated = false;
+
+        if let Some(rating) = changeset.rating {
+            updated |= self.rating != rating;
+            self.rating = rating;
+        }
+
+        if let Some(body) = &changeset.body {
+            updated |= &self.body != body;
+            self.body.clone_from(body);
+        }
+
+        updated
+    }
+}
+
+impl From<Review> for ReviewDTO {
+    fn from(value: Review) -> Self {
+        Self {
+            id: value.id,
+            recipe_id: value.recipe_id,
+            author: value.author,
+            rating: value.rating.value(),
+            body: value.body,
+            created_at: value.created_at.to_rfc3339(),
+            updated_at: value.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(FromRow, PartialEq, Debug, Clone)]
+pub struct ReviewModel {
+    pub id: Uuid,
+    pub recipe_id: Uuid,
+    pub user_id: Option<Uuid>,
+    pub author: String,
+    pub rating: i16,
+    pub body: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl TryFrom<ReviewModel> for Review {
+    type Error = ValidationError;
+    fn try_from(value: ReviewModel) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: value.id,
+            recipe_id: value.recipe_id,
+            user_id: value.user_id,
+            author: value.author,
+            rating: Rating::new(value.rating)?,
+            body: value.body,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct ReviewChangeset {
+    pub rating: Option<Rating>,
+    /// `Some(None)` removes the text of the review
+    pub body: Option<Option<String>>,
+}
+
+impl ReviewChangeset {
+    pub fn is_empty(&self) -> bool {
+        self.rating.is_none() && self.body.is_none()
+    }
+}
+
+/// All the reviews of a recipe, summed up
+#[derive(PartialEq, Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct RecipeRating {
+    /// Missing if nobody reviewed the recipe yet
+    pub average: Option<f64>,
+    pub count: u32,
+}
+
+impl RecipeRating {
+    pub fn from_reviews<'a>(reviews: impl IntoIterator<Item = &'a Review>) -> Self {
+        let (sum, count) = reviews
+            .into_iter()
+            .fold((0u32, 0u32), |(sum, count), review| {
+                (sum + u32::from(review.rating.value()), count + 1)
+            });
+
+        Self {
+            average: (count > 0).then(|| f64::from(sum) / f64::from(count)),
+            count,
+        }
+    }
+
+    /// Ordering for listings: best rated first, with more reviews winning ties, and unrated last
+    pub fn rank(&self, other: &Self) -> Ordering {
+        match (self.average, other.average) {
+            (Some(a), Some(b)) => b.total_cmp(&a).then(other.count.cmp(&self.count)),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,69 @@
+use std::cmp::Ordering;
+
+use pretty_assertions::assert_eq;
+
+use crate::test_utils::review_fixture;
+
+use super::{Rating, RecipeRating, ReviewChangeset};
+
+#[test]
+fn ratings_outside_of_the_scale_are_rejected() {
+    assert!(Rating::new(0).is_err());
+    assert!(Rating::new(6).is_err());
+    assert!(Rating::new(-1).is_err());
+    assert_eq!(Rating::new(1).unwrap().value(), 1);
+    assert_eq!(Rating::new(5).unwrap().value(), 5);
+}
+
+#[test]
+fn rating_is_averaged_over_the_reviews() {
+    let mut other = review_fixture();
+    other.rating = Rating::new(2).unwrap();
+
+    let rating = RecipeRating::from_reviews(&[review_fixture(), other]);
+
+    assert_eq!(rating.count, 2);
+    assert_eq!(rating.average, Some(3.5));
+
+    assert_eq!(RecipeRating::from_reviews(&[]), RecipeRating::default());
+}
+
+#[test]
+fn unrated_recipes_are_ranked_last() {
+    let unrated = RecipeRating::default();
+    let good = RecipeRating {
+        average: Some(4.5),
+        count: 2,
+    };
+    let good_and_popular = RecipeRating {
+        average: Some(4.5),
+        count: 10,
+    };
+    let bad = RecipeRating {
+        average: Some(1.0),
+        count: 30,
+    };
+
+    let mut ratings = vec![unrated, bad, good, good_and_popular];
+    ratings.sort_by(RecipeRating::rank);
+
+    assert_eq!(ratings, vec![good_and_popular, good, bad, unrated]);
+    assert_eq!(unrated.rank(&unrated), Ordering::Equal);
+}
+
+#[test]
+fn applying_a_changeset_reports_changes() {
+    let mut review = review_fixture();
+
+    assert!(!review.apply(&ReviewChangeset::default()));
+    assert!(!review.apply(&ReviewChangeset {
+        rating: Some(review.rating),
+        body: Some(review.body.clone()),
+    }));
+
+    assert!(review.apply(&ReviewChangeset {
+        rating: None,
+        body: Some(None),
+    }));
+    assert_eq!(review.body, None);
+}
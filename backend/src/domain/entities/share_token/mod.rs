@@ -0,0 +1,37 @@
+use chrono::{DateTime, Utc};
+use common::ShareTokenDTO;
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// A secret that lets anybody holding it see an unlisted recipe
+#[derive(FromRow, PartialEq, Debug, Clone)]
+pub struct ShareToken {
+    pub token: String,
+    pub recipe_id: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ShareToken {
+    pub fn new(recipe_id: Uuid) -> Self {
+        Self {
+            token: Uuid::new_v4().simple().to_string(),
+            recipe_id,
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn path(&self) -> String {
+        format!("/recipe/{}?share={}", self.recipe_id, self.token)
+    }
+}
+
+impl From<ShareToken> for ShareTokenDTO {
+    fn from(value: ShareToken) -> Self {
+        Self {
+            url: value.path(),
+            token: value.token,
+            recipe_id: value.recipe_id,
+            created_at: value.created_at.to_rfc3339(),
+        }
+    }
+}
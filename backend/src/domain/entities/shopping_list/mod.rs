@@ -0,0 +1,150 @@
+use chrono::{DateTime, Utc};
+use common::{ShoppingListDTO, ShoppingListItemDTO};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use super::{
+    ingredient::{Ingredient, IngredientModel},
+    recipe::{errors::ValidationError, IngredientUnit, IngredientWithAmount, Recipe},
+};
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct ShoppingList {
+    pub id: Uuid,
+    pub name: String,
+    /// The recipes the list was made for
+    pub recipe_ids: Vec<Uuid>,
+    pub items: Vec<ShoppingListItem>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<ShoppingList> for ShoppingListDTO {
+    fn from(value: ShoppingList) -> Self {
+        Self {
+            id: value.id,
+            name: value.name,
+            recipe_ids: value.recipe_ids,
+            items: value.items.into_iter().map(|i| i.into()).collect(),
+            created_at: value.created_at.to_rfc3339(),
+            updated_at: value.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct ShoppingListItem {
+    pub id: Uuid,
+    pub ingredient: Ingredient,
+    pub amount: IngredientUnit,
+    pub checked: bool,
+}
+
+impl From<ShoppingListItem> for ShoppingListItemDTO {
+    fn from(value: ShoppingListItem) -> Self {
+        Self {
+            id: value.id,
+            ingredient: value.ingredient.into(),
+            amount: value.amount.into(),
+            checked: value.checked,
+        }
+    }
+}
+
+/// Renders the item the way it would be written on a shopping list, i.e. `400 g Firm tofu`
+impl std::fmt::Display for ShoppingListItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.amount, self.ingredient.name)
+    }
+}
+
+#[derive(FromRow, PartialEq, Debug, Clone)]
+pub struct ShoppingListItemModel {
+    pub id: Uuid,
+    pub ingredient: IngredientModel,
+    pub amount: serde_json::Value,
+    pub checked: bool,
+}
+
+impl TryFrom<ShoppingListItemModel> for ShoppingListItem {
+    type Error = ValidationError;
+    fn try_from(value: ShoppingListItemModel) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: value.id,
+            amount: serde_json::from_value(value.amount)
+                .map_err(|e| ValidationError::DeserializationFailed("amount", e))?,
+            ingredient: value.ingredient.try_into()?,
+            checked: value.checked,
+        })
+    }
+}
+
+/// Sums up the amounts of every ingredient. Amounts in units that can't be converted
+/// into each other, i.e. grams and cups of flour, are kept as separate entries.
+pub fn aggregate_ingredients(
+    ingredients: impl IntoIterator<Item = IngredientWithAmount>,
+) -> Vec<(Ingredient, IngredientUnit)> {
+    let mut result: Vec<(Ingredient, IngredientUnit)> = vec![];
+
+    for IngredientWithAmount {
+        ingredient, amount, ..
+    } in ingredients
+    {
+        let total = result.iter_mut().find_map(|(existing, total)| {
+            if existing.id != ingredient.id {
+                return None;
+            }
+            total.checked_add(&amount).map(|sum| (total, sum))
+        });
+
+        match total {
+            Some((total, sum)) => *total = sum,
+            None => result.push((ingredient, amount)),
+        }
+    }
+
+    result
+}
+
+/// Sums up the ingredients of the recipes, each scaled to the number of servings given with it.
+/// `None` keeps the servings of the recipe.
+pub fn aggregate_recipes(
+    recipes: &[(Recipe, Option<u16>)],
+    exclude_optional: bool,
+) -> Vec<(Ingredient, IngredientUnit)> {
+    let ingredients = recipes.iter().flat_map(|(recipe, servings)| {
+        let factor = servings
+            .map(|servings| recipe.servings.scale_factor(servings))
+            .unwrap_or(1.0);
+
+        recipe
+            .ingredients
+            .iter()
+            .filter(move |i| !(exclude_optional && i.optional))
+            .map(move |i| IngredientWithAmount {
+                amount: i.amount.scale(factor),
+                ..i.clone()
+            })
+    });
+
+    aggregate_ingredients(ingredients)
+}
+
+/// Makes the items of a shopping list out of recipes, each scaled to the number of servings given with it
+pub fn items_from_recipes(
+    recipes: &[(Recipe, Option<u16>)],
+    exclude_optional: bool,
+) -> Vec<ShoppingListItem> {
+    aggregate_recipes(recipes, exclude_optional)
+        .into_iter()
+        .map(|(ingredient, amount)| ShoppingListItem {
+            id: Uuid::now_v7(),
+            ingredient,
+            amount,
+            checked: false,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests;
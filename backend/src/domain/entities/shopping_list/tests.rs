@@ -0,0 +1,106 @@
+use pretty_assertions::assert_eq;
+
+use crate::{
+    domain::entities::recipe::{IngredientUnit, IngredientWithAmount, ServingsType},
+    test_utils::recipe_fixture,
+};
+
+use super::{aggregate_ingredients, items_from_recipes};
+
+#[test]
+fn aggregating_converts_compatible_units() {
+    let recipe = recipe_fixture();
+    let tofu = recipe.ingredients[0].clone();
+
+    let ingredients = vec![
+        tofu.clone(),
+        IngredientWithAmount {
+            amount: IngredientUnit::Other {
+                amount: 1.0,
+                unit: "lb".to_string(),
+            },
+            ..tofu.clone()
+        },
+        IngredientWithAmount {
+            amount: IngredientUnit::Cups(1.0),
+            ..tofu.clone()
+        },
+        IngredientWithAmount {
+            amount: IngredientUnit::Teaspoons(6.0),
+            ..tofu.clone()
+        },
+    ];
+
+    let result = aggregate_ingredients(ingredients);
+
+    assert_eq!(
+        result,
+        vec![
+            (tofu.ingredient.clone(), IngredientUnit::Grams(853.592)),
+            (tofu.ingredient, IngredientUnit::Teaspoons(54.0)),
+        ]
+    );
+}
+
+#[test]
+fn aggregating_keeps_matching_custom_units_together() {
+    let recipe = recipe_fixture();
+    let garlic = recipe.ingredients[2].clone();
+
+    let ingredients = vec![
+        IngredientWithAmount {
+            amount: IngredientUnit::Other {
+                amount: 1.0,
+                unit: "clove".to_string(),
+            },
+            ..garlic.clone()
+        },
+        IngredientWithAmount {
+            amount: IngredientUnit::Other {
+                amount: 3.0,
+                unit: "Cloves".to_string(),
+            },
+            ..garlic.clone()
+        },
+        IngredientWithAmount {
+            amount: IngredientUnit::Other {
+                amount: 1.0,
+                unit: "head".to_string(),
+            },
+            ..garlic.clone()
+        },
+    ];
+
+    let result = aggregate_ingredients(ingredients);
+
+    assert_eq!(result.len(), 2);
+    assert_eq!(
+        result[0].1,
+        IngredientUnit::Other {
+            amount: 4.0,
+            unit: "clove".to_string()
+        }
+    );
+}
+
+#[test]
+fn making_items_from_recipes_scales_servings() {
+    let mut recipe = recipe_fixture();
+    recipe.servings = ServingsType::Exact(2);
+    recipe.ingredients[1].optional = true;
+    let first = recipe.ingredients[0].clone();
+
+    let result = items_from_recipes(&[(recipe.clone(), Some(4)), (recipe.clone(), None)], true);
+
+    assert_eq!(result.len(), recipe.ingredients.len() - 1);
+    assert!(result
+        .iter()
+        .all(|item| item.ingredient.id != recipe.ingredients[1].ingredient.id));
+    assert_eq!(result[0].ingredient, first.ingredient);
+    assert_eq!(result[0].amount, first.amount.scale(3.0));
+    assert!(!result[0].checked);
+
+    let result = items_from_recipes(&[(recipe.clone(), None)], false);
+
+    assert_eq!(result.len(), recipe.ingredients.len());
+}
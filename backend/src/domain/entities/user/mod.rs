@@ -0,0 +1,137 @@
+use argon2::{
+    password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString},
+    Argon2,
+};
+use chrono::{DateTime, Duration, Utc};
+use common::user::{SessionDTO, UserDataDTO};
+use eyre::eyre;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use super::recipe::errors::ValidationError;
+
+/// Somebody with an account, who owns their meal plan, pantry, favorites and reviews
+#[derive(FromRow, PartialEq, Debug, Clone)]
+pub struct User {
+    pub id: Uuid,
+    pub username: String,
+    pub email: String,
+    /// Argon2 hash of the password in the PHC string format, the password itself is never stored
+    pub password_hash: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl User {
+    pub fn new(username: &str, email: &str, password: &str) -> Result<Self, ValidationError> {
+        let username = username.trim();
+        let email = email.trim();
+
+        let empty_fields: Vec<_> = [
+            ("username", username),
+            ("email", email),
+            ("password", password),
+        ]
+        .into_iter()
+        .filter(|(_, value)| value.is_empty())
+        .map(|(field, _)| field)
+        .collect();
+
+        if !empty_fields.is_empty() {
+            return Err(ValidationError::EmptyField(empty_fields));
+        }
+
+        // A random UUID is as good a salt as any, and saves pulling in a random number generator
+        let salt = SaltString::encode_b64(Uuid::new_v4().as_bytes())
+            .map_err(|e| eyre!("Could not encode the salt: {e}"))?;
+        let password_hash = Argon2::default()
+            .hash_password(password.as_bytes(), &salt)
+            .map_err(|e| eyre!("Could not hash the password: {e}"))?
+            .to_string();
+
+        Ok(Self {
+            id: Uuid::now_v7(),
+            username: username.to_string(),
+            email: email.to_string(),
+            password_hash,
+            created_at: Utc::now(),
+        })
+    }
+
+    pub fn verify_password(&self, password: &str) -> bool {
+        PasswordHash::new(&self.password_hash).is_ok_and(|hash| {
+            Argon2::default()
+                .verify_password(password.as_bytes(), &hash)
+                .is_ok()
+        })
+    }
+}
+
+impl From<User> for UserDataDTO {
+    fn from(value: User) -> Self {
+        Self {
+            id: value.id,
+            username: value.username,
+            email: value.email,
+        }
+    }
+}
+
+/// How long a session lasts before the user has to log in again
+pub const SESSION_DURATION: Duration = Duration::days(30);
+
+/// Proof that the user logged in, sent back by the client as a bearer token
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct Session {
+    /// SHA-256 of the token, so that a leaked session store can't be used to log in
+    pub token_hash: String,
+    pub user_id: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl Session {
+    /// Opens a session for the user, returning the token to hand out along with it
+    pub fn new(user_id: Uuid) -> (String, Self) {
+        let token = Uuid::new_v4().simple().to_string();
+        let created_at = Utc::now();
+        let session = Self {
+            token_hash: Self::hash(&token),
+            user_id,
+            created_at,
+            expires_at: created_at + SESSION_DURATION,
+        };
+
+        (token, session)
+    }
+
+    pub fn hash(token: &str) -> String {
+        hex::encode(Sha256::digest(token.as_bytes()))
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.expires_at <= Utc::now()
+    }
+}
+
+/// A token handed out on login, along with who it belongs to
+#[derive(PartialEq, Debug, Clone)]
+pub struct LoggedInUser {
+    pub token: String,
+    pub user: User,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl From<LoggedInUser> for SessionDTO {
+    fn from(value: LoggedInUser) -> Self {
+        Self {
+            token: value.token,
+            user: value.user.into(),
+            expires_at: value.expires_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,45 @@
+use pretty_assertions::assert_eq;
+
+use crate::domain::entities::recipe::errors::ValidationError;
+
+use super::{Session, User, SESSION_DURATION};
+
+#[test]
+fn passwords_are_hashed_and_can_be_verified() {
+    let user = User::new(" cook ", "cook@example.com", "hunter2").unwrap();
+
+    assert_eq!(user.username, "cook");
+    assert_ne!(user.password_hash, "hunter2");
+    assert!(user.verify_password("hunter2"));
+    assert!(!user.verify_password("hunter3"));
+}
+
+#[test]
+fn users_need_a_username_an_email_and_a_password() {
+    let error = User::new("  ", "cook@example.com", "").unwrap_err();
+
+    match error {
+        ValidationError::EmptyField(fields) => assert_eq!(fields, vec!["username", "password"]),
+        e => panic!("Wrong error returned: {e}"),
+    }
+}
+
+#[test]
+fn sessions_only_keep_the_hash_of_their_token() {
+    let (token, session) = Session::new(uuid::Uuid::nil());
+
+    assert_ne!(session.token_hash, token);
+    assert_eq!(session.token_hash, Session::hash(&token));
+}
+
+#[test]
+fn sessions_expire_after_a_while() {
+    let (_, mut session) = Session::new(uuid::Uuid::nil());
+
+    assert_eq!(session.expires_at - session.created_at, SESSION_DURATION);
+    assert!(!session.is_expired());
+
+    session.expires_at = chrono::Utc::now() - chrono::Duration::seconds(1);
+
+    assert!(session.is_expired());
+}
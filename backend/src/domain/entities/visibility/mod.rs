@@ -0,0 +1,42 @@
+use common::VisibilityDTO;
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString, VariantNames};
+
+#[derive(
+    Serialize, Deserialize, VariantNames, EnumString, Display, Debug, PartialEq, Eq, Clone, Copy,
+)]
+#[strum(serialize_all = "snake_case")]
+pub enum Visibility {
+    /// Only visible to the owner
+    Private,
+    /// Visible to anyone with the link, but not listed anywhere
+    Unlisted,
+    Public,
+}
+
+impl Visibility {
+    /// Whether the thing shows up in listings and search
+    pub fn is_listed(&self) -> bool {
+        matches!(self, Self::Public)
+    }
+}
+
+impl From<VisibilityDTO> for Visibility {
+    fn from(value: VisibilityDTO) -> Self {
+        match value {
+            VisibilityDTO::Private => Self::Private,
+            VisibilityDTO::Unlisted => Self::Unlisted,
+            VisibilityDTO::Public => Self::Public,
+        }
+    }
+}
+
+impl From<Visibility> for VisibilityDTO {
+    fn from(value: Visibility) -> Self {
+        match value {
+            Visibility::Private => Self::Private,
+            Visibility::Unlisted => Self::Unlisted,
+            Visibility::Public => Self::Public,
+        }
+    }
+}
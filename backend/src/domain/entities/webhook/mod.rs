@@ -0,0 +1,119 @@
+use chrono::{DateTime, Utc};
+use common::{WebhookDTO, WebhookDeliveryDTO};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use super::event::DomainEvent;
+
+/// A partner service that wants to know when things happen
+#[derive(FromRow, PartialEq, Debug, Clone)]
+pub struct Webhook {
+    pub id: Uuid,
+    pub url: String,
+    /// Shared with the partner, so that it can tell the deliveries really come from us
+    pub secret: String,
+    /// Names of the events to deliver, as in [`DomainEvent::VARIANTS`]
+    pub event_types: Vec<String>,
+    /// Webhooks from before there were accounts have no owner
+    pub owner_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Webhook {
+    /// Whether the user can see and delete the webhook along with its deliveries. Webhooks
+    /// without an owner were registered before there were accounts, so every user can.
+    pub fn is_managed_by(&self, user_id: &Uuid) -> bool {
+        self.owner_id.is_none_or(|owner_id| owner_id == *user_id)
+    }
+
+    pub fn wants(&self, event: &DomainEvent) -> bool {
+        self.event_types.iter().any(|t| t == event.as_ref())
+    }
+
+    /// HMAC-SHA256 of the body, keyed with the secret, in the form of `sha256=<hex>`
+    pub fn sign(&self, body: &[u8]) -> String {
+        #[allow(clippy::unwrap_used)]
+        // HMAC accepts keys of any length
+        let mut mac = Hmac::<Sha256>::new_from_slice(self.secret.as_bytes()).unwrap();
+        mac.update(body);
+
+        format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+    }
+}
+
+impl From<Webhook> for WebhookDTO {
+    fn from(value: Webhook) -> Self {
+        Self {
+            id: value.id,
+            url: value.url,
+            event_types: value.event_types,
+            created_at: value.created_at.to_rfc3339(),
+        }
+    }
+}
+
+/// A single attempt at delivering an event to a webhook
+#[derive(PartialEq, Debug, Clone)]
+pub struct WebhookDelivery {
+    /// ID of the event in the outbox, the same for every attempt at delivering it
+    pub id: Uuid,
+    pub webhook_id: Uuid,
+    pub event_type: String,
+    pub payload: serde_json::Value,
+    /// Starting at 1
+    pub attempt: u32,
+    pub status_code: Option<u16>,
+    pub error: Option<String>,
+    pub succeeded: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<WebhookDelivery> for WebhookDeliveryDTO {
+    fn from(value: WebhookDelivery) -> Self {
+        Self {
+            id: value.id,
+            webhook_id: value.webhook_id,
+            event_type: value.event_type,
+            payload: value.payload,
+            attempt: value.attempt,
+            status_code: value.status_code,
+            error: value.error,
+            succeeded: value.succeeded,
+            created_at: value.created_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(FromRow, PartialEq, Debug, Clone)]
+pub struct WebhookDeliveryModel {
+    pub id: Uuid,
+    pub webhook_id: Uuid,
+    pub event_type: String,
+    pub payload: serde_json::Value,
+    pub attempt: i32,
+    pub status_code: Option<i32>,
+    pub error: Option<String>,
+    pub succeeded: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<WebhookDeliveryModel> for WebhookDelivery {
+    fn from(value: WebhookDeliveryModel) -> Self {
+        Self {
+            id: value.id,
+            webhook_id: value.webhook_id,
+            event_type: value.event_type,
+            payload: value.payload,
+            attempt: value.attempt.try_into().unwrap_or_default(),
+            status_code: value.status_code.and_then(|code| code.try_into().ok()),
+            error: value.error,
+            succeeded: value.succeeded,
+            created_at: value.created_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,26 @@
+use pretty_assertions::assert_eq;
+
+use crate::{
+    domain::entities::event::DomainEvent,
+    test_utils::{recipe_fixture, webhook_fixture},
+};
+
+#[test]
+fn webhooks_only_want_the_events_they_asked_for() {
+    let webhook = webhook_fixture();
+    let recipe = recipe_fixture();
+
+    assert!(webhook.wants(&DomainEvent::RecipeCreated(recipe.clone())));
+    assert!(!webhook.wants(&DomainEvent::RecipeDeleted(recipe.id)));
+}
+
+#[test]
+fn signatures_are_hmac_sha256_of_the_body() {
+    let webhook = webhook_fixture();
+
+    // echo -n '{"type":"recipe_deleted"}' | openssl dgst -sha256 -hmac 'keep it secret'
+    assert_eq!(
+        webhook.sign(br#"{"type":"recipe_deleted"}"#),
+        "sha256=f0a702e16bfa5a835fdec216a02377547aaaefb125262b44a2e38990ced89dda"
+    );
+}
@@ -2,3 +2,4 @@ pub mod commands;
 pub mod entities;
 pub mod queries;
 pub mod repositories;
+pub mod services;
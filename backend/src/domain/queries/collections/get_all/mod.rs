@@ -0,0 +1,33 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::collection::Collection,
+    repositories::collection::{errors::GetCollectionError, CollectionRepositoryService},
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum GetAllCollectionsError {
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetCollectionError> for GetAllCollectionsError {
+    fn from(value: GetCollectionError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+/// Lists every public collection along with the user's own
+#[tracing::instrument("[QUERY] Get all collections", skip(collection_repo))]
+pub async fn get_all_collections(
+    collection_repo: CollectionRepositoryService,
+    user_id: Option<&Uuid>,
+) -> Result<Vec<Collection>, GetAllCollectionsError> {
+    let mut result = collection_repo.get_all().await?;
+    result.retain(|collection| collection.is_listed_for(user_id));
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,92 @@
+use std::sync::Arc;
+
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::{collection::Collection, visibility::Visibility},
+        queries::collections::get_all::get_all_collections,
+        repositories::{
+            collection::{CollectionRepository, CollectionRepositoryService},
+            ingredients::IngredientRepository,
+            recipe::RecipeRepository,
+            user::UserRepository,
+        },
+    },
+    test_utils::{
+        another_user_fixture, collection_fixture, insert_collections, insert_users,
+        private_collection_fixture, user_fixture,
+    },
+};
+
+pub async fn getting_all_collections_works(
+    collection_repo: impl CollectionRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let weeknight = collection_fixture();
+    let christmas = Collection {
+        id: Uuid::from_u128(1025),
+        name: "Christmas 2026".to_string(),
+        recipes: vec![],
+        cover_recipe_id: None,
+        ..collection_fixture()
+    };
+    insert_collections(
+        &collection_repo,
+        &recipe_repo,
+        &ingredient_repo,
+        &[weeknight.clone(), christmas.clone()],
+    )
+    .await;
+    let collection_repo: CollectionRepositoryService = Arc::new(Box::new(collection_repo));
+
+    let result = get_all_collections(collection_repo, None).await.unwrap();
+
+    assert_eq!(result, vec![christmas, weeknight]);
+}
+
+pub async fn only_the_owner_sees_their_private_and_unlisted_collections(
+    collection_repo: impl CollectionRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let public = Collection {
+        id: Uuid::from_u128(1025),
+        name: "Christmas 2026".to_string(),
+        owner_id: Some(user_fixture().id),
+        ..collection_fixture()
+    };
+    let unlisted = Collection {
+        id: Uuid::from_u128(1026),
+        name: "Birthday cakes".to_string(),
+        visibility: Visibility::Unlisted,
+        ..private_collection_fixture()
+    };
+    let private = private_collection_fixture();
+    insert_users(&user_repo).await;
+    insert_collections(
+        &collection_repo,
+        &recipe_repo,
+        &ingredient_repo,
+        &[public.clone(), unlisted.clone(), private.clone()],
+    )
+    .await;
+    let collection_repo: CollectionRepositoryService = Arc::new(Box::new(collection_repo));
+
+    for user_id in [None, Some(&another_user_fixture().id)] {
+        let result = get_all_collections(collection_repo.clone(), user_id)
+            .await
+            .unwrap();
+
+        assert_eq!(result, vec![public.clone()]);
+    }
+
+    let result = get_all_collections(collection_repo, Some(&user_fixture().id))
+        .await
+        .unwrap();
+
+    assert_eq!(result, vec![unlisted, public, private]);
+}
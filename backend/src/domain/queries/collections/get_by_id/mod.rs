@@ -0,0 +1,46 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{collection::Collection, recipe::errors::ValidationError},
+    repositories::collection::{errors::GetCollectionError, CollectionRepositoryService},
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum GetCollectionByIdError {
+    #[error("Could not find the collection with the ID {0}")]
+    NotFound(Uuid),
+
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetCollectionError> for GetCollectionByIdError {
+    fn from(value: GetCollectionError) -> Self {
+        match value {
+            GetCollectionError::NotFound(id) => Self::NotFound(id),
+            GetCollectionError::ValidationError(e) => Self::ValidationError(e),
+            GetCollectionError::UnknownError(e) => Self::Unknown(e),
+        }
+    }
+}
+
+/// Private collections of somebody else are reported as missing, so that nobody can tell they exist
+#[tracing::instrument("[QUERY] Get collection", skip(collection_repo))]
+pub async fn get_collection_by_id(
+    collection_repo: CollectionRepositoryService,
+    user_id: Option<&Uuid>,
+    id: &Uuid,
+) -> Result<Collection, GetCollectionByIdError> {
+    let result = collection_repo.get_by_id(id).await?;
+    if !result.is_visible_to(user_id) {
+        return Err(GetCollectionByIdError::NotFound(*id));
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests;
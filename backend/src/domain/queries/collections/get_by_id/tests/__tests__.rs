@@ -0,0 +1,96 @@
+use std::sync::Arc;
+
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        queries::collections::get_by_id::{get_collection_by_id, GetCollectionByIdError},
+        repositories::{
+            collection::{CollectionRepository, CollectionRepositoryService},
+            ingredients::IngredientRepository,
+            recipe::RecipeRepository,
+            user::UserRepository,
+        },
+    },
+    test_utils::{
+        another_user_fixture, collection_fixture, insert_collections, insert_users,
+        private_collection_fixture, user_fixture,
+    },
+};
+
+pub async fn getting_a_collection_works(
+    collection_repo: impl CollectionRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let collection = collection_fixture();
+    insert_collections(
+        &collection_repo,
+        &recipe_repo,
+        &ingredient_repo,
+        std::slice::from_ref(&collection),
+    )
+    .await;
+    let collection_repo: CollectionRepositoryService = Arc::new(Box::new(collection_repo));
+
+    let result = get_collection_by_id(collection_repo, None, &collection.id)
+        .await
+        .unwrap();
+
+    assert_eq!(result, collection);
+}
+
+pub async fn getting_a_nonexistent_collection_errors(
+    collection_repo: impl CollectionRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    insert_collections(
+        &collection_repo,
+        &recipe_repo,
+        &ingredient_repo,
+        &[collection_fixture()],
+    )
+    .await;
+    let collection_repo: CollectionRepositoryService = Arc::new(Box::new(collection_repo));
+    let id = Uuid::now_v7();
+
+    let error = get_collection_by_id(collection_repo, None, &id)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, GetCollectionByIdError::NotFound(e) if e == id));
+}
+
+pub async fn getting_a_private_collection_of_someone_else_errors(
+    collection_repo: impl CollectionRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let collection = private_collection_fixture();
+    insert_users(&user_repo).await;
+    insert_collections(
+        &collection_repo,
+        &recipe_repo,
+        &ingredient_repo,
+        std::slice::from_ref(&collection),
+    )
+    .await;
+    let collection_repo: CollectionRepositoryService = Arc::new(Box::new(collection_repo));
+
+    for user_id in [None, Some(&another_user_fixture().id)] {
+        let error = get_collection_by_id(collection_repo.clone(), user_id, &collection.id)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error, GetCollectionByIdError::NotFound(e) if e == collection.id));
+    }
+
+    let result = get_collection_by_id(collection_repo, Some(&user_fixture().id), &collection.id)
+        .await
+        .unwrap();
+
+    assert_eq!(result, collection);
+}
@@ -0,0 +1,54 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::collection::Collection,
+    repositories::{
+        collection::{errors::GetCollectionError, CollectionRepositoryService},
+        recipe::{errors::GetRecipeByIdError, RecipeRepositoryService},
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum GetCollectionsOfRecipeError {
+    #[error("Could not find the recipe with the ID {0}")]
+    RecipeNotFound(Uuid),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetRecipeByIdError> for GetCollectionsOfRecipeError {
+    fn from(value: GetRecipeByIdError) -> Self {
+        match value {
+            GetRecipeByIdError::NotFound(id) => Self::RecipeNotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<GetCollectionError> for GetCollectionsOfRecipeError {
+    fn from(value: GetCollectionError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+#[tracing::instrument(
+    "[QUERY] Get collections a recipe belongs to",
+    skip(collection_repo, recipe_repo)
+)]
+pub async fn get_collections_of_recipe(
+    collection_repo: CollectionRepositoryService,
+    recipe_repo: RecipeRepositoryService,
+    user_id: Option<&Uuid>,
+    recipe_id: &Uuid,
+) -> Result<Vec<Collection>, GetCollectionsOfRecipeError> {
+    recipe_repo.get_visible(user_id, recipe_id, None).await?;
+
+    let mut result = collection_repo.get_by_recipe(recipe_id).await?;
+    result.retain(|collection| collection.is_listed_for(user_id));
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,161 @@
+use std::sync::Arc;
+
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::{collection::Collection, recipe::Recipe, visibility::Visibility},
+        queries::collections::get_by_recipe::{
+            get_collections_of_recipe, GetCollectionsOfRecipeError,
+        },
+        repositories::{
+            collection::{CollectionRepository, CollectionRepositoryService},
+            ingredients::IngredientRepository,
+            recipe::{RecipeRepository, RecipeRepositoryService},
+            user::UserRepository,
+        },
+    },
+    test_utils::{
+        another_recipe_fixture, another_user_fixture, collection_fixture, insert_collections,
+        insert_users, private_collection_fixture, recipe_fixture, user_fixture,
+    },
+};
+
+pub async fn getting_the_collections_of_a_recipe_works(
+    collection_repo: impl CollectionRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let with_recipe = collection_fixture();
+    let without_recipe = Collection {
+        id: Uuid::from_u128(1025),
+        name: "Christmas 2026".to_string(),
+        recipes: vec![],
+        cover_recipe_id: None,
+        ..collection_fixture()
+    };
+    insert_collections(
+        &collection_repo,
+        &recipe_repo,
+        &ingredient_repo,
+        &[with_recipe.clone(), without_recipe],
+    )
+    .await;
+    let collection_repo: CollectionRepositoryService = Arc::new(Box::new(collection_repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let result =
+        get_collections_of_recipe(collection_repo, recipe_repo, None, &recipe_fixture().id)
+            .await
+            .unwrap();
+
+    assert_eq!(result, vec![with_recipe]);
+}
+
+pub async fn getting_the_collections_of_a_nonexistent_recipe_errors(
+    collection_repo: impl CollectionRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    insert_collections(
+        &collection_repo,
+        &recipe_repo,
+        &ingredient_repo,
+        &[collection_fixture()],
+    )
+    .await;
+    let collection_repo: CollectionRepositoryService = Arc::new(Box::new(collection_repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+    let id = Uuid::now_v7();
+
+    let error = get_collections_of_recipe(collection_repo, recipe_repo, None, &id)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, GetCollectionsOfRecipeError::RecipeNotFound(e) if e == id));
+}
+
+pub async fn getting_the_collections_of_a_private_recipe_of_someone_else_errors(
+    collection_repo: impl CollectionRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    insert_users(&user_repo).await;
+    insert_collections(&collection_repo, &recipe_repo, &ingredient_repo, &[]).await;
+    let private = Recipe {
+        visibility: Visibility::Private,
+        owner_id: Some(user_fixture().id),
+        ..another_recipe_fixture(1)
+    };
+    recipe_repo.insert(private.clone()).await.unwrap();
+    let collection_repo: CollectionRepositoryService = Arc::new(Box::new(collection_repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let result = get_collections_of_recipe(
+        collection_repo.clone(),
+        recipe_repo.clone(),
+        Some(&user_fixture().id),
+        &private.id,
+    )
+    .await
+    .unwrap();
+    assert_eq!(result, vec![]);
+
+    let error = get_collections_of_recipe(
+        collection_repo,
+        recipe_repo,
+        Some(&another_user_fixture().id),
+        &private.id,
+    )
+    .await
+    .unwrap_err();
+    assert!(matches!(error, GetCollectionsOfRecipeError::RecipeNotFound(id) if id == private.id));
+}
+
+pub async fn private_collections_of_someone_else_are_left_out(
+    collection_repo: impl CollectionRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let public = Collection {
+        id: Uuid::from_u128(1025),
+        name: "Christmas 2026".to_string(),
+        ..collection_fixture()
+    };
+    let private = private_collection_fixture();
+    insert_users(&user_repo).await;
+    insert_collections(
+        &collection_repo,
+        &recipe_repo,
+        &ingredient_repo,
+        &[public.clone(), private.clone()],
+    )
+    .await;
+    let collection_repo: CollectionRepositoryService = Arc::new(Box::new(collection_repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let result = get_collections_of_recipe(
+        collection_repo.clone(),
+        recipe_repo.clone(),
+        Some(&another_user_fixture().id),
+        &recipe_fixture().id,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(result, vec![public.clone()]);
+
+    let result = get_collections_of_recipe(
+        collection_repo,
+        recipe_repo,
+        Some(&user_fixture().id),
+        &recipe_fixture().id,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(result, vec![public, private]);
+}
@@ -0,0 +1,3 @@
+pub mod get_all;
+pub mod get_by_id;
+pub mod get_by_recipe;
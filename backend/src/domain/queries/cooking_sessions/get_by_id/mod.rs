@@ -0,0 +1,39 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::cooking_session::CookingSession,
+    repositories::cooking_session::{
+        errors::GetCookingSessionError, CookingSessionRepositoryService,
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum GetCookingSessionByIdError {
+    #[error("Could not find the cooking session with the ID {0}")]
+    NotFound(Uuid),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetCookingSessionError> for GetCookingSessionByIdError {
+    fn from(value: GetCookingSessionError) -> Self {
+        match value {
+            GetCookingSessionError::NotFound(id) => Self::NotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+#[tracing::instrument("[QUERY] Get cooking session by ID", skip(session_repo))]
+pub async fn get_cooking_session_by_id(
+    session_repo: CookingSessionRepositoryService,
+    session_id: &Uuid,
+) -> Result<CookingSession, GetCookingSessionByIdError> {
+    let result = session_repo.get_by_id(session_id).await?;
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,46 @@
+use std::sync::Arc;
+
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        queries::cooking_sessions::get_by_id::{
+            get_cooking_session_by_id, GetCookingSessionByIdError,
+        },
+        repositories::{
+            cooking_session::CookingSessionRepository, ingredients::IngredientRepository,
+            recipe::RecipeRepository,
+        },
+    },
+    test_utils::insert_cooking_session,
+};
+
+pub async fn getting_a_cooking_session_works(
+    session_repo: impl CookingSessionRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let session = insert_cooking_session(&session_repo, &recipe_repo, &ingredient_repo).await;
+
+    let result = get_cooking_session_by_id(Arc::new(Box::new(session_repo)), &session.id)
+        .await
+        .unwrap();
+
+    assert_eq!(result, session);
+}
+
+pub async fn getting_a_nonexistent_cooking_session_errors(
+    session_repo: impl CookingSessionRepository,
+) {
+    let session_id = Uuid::from_u128(404);
+
+    let error = get_cooking_session_by_id(Arc::new(Box::new(session_repo)), &session_id)
+        .await
+        .unwrap_err();
+
+    match error {
+        GetCookingSessionByIdError::NotFound(id) => assert_eq!(id, session_id),
+        e => panic!("Wrong error returned: {e}"),
+    }
+}
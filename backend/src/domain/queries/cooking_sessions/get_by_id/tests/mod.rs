@@ -0,0 +1,57 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::{
+        cooking_session::in_memory::InMemoryCookingSessionRepository,
+        ingredients::in_memory::InMemoryIngredientRepository,
+        recipe::in_memory::InMemoryRecipeRepository,
+    };
+
+    #[tokio::test]
+    async fn getting_a_cooking_session_works() {
+        __tests__::getting_a_cooking_session_works(
+            InMemoryCookingSessionRepository::new(),
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn getting_a_nonexistent_cooking_session_errors() {
+        __tests__::getting_a_nonexistent_cooking_session_errors(
+            InMemoryCookingSessionRepository::new(),
+        )
+        .await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        cooking_session::postgres::PostgresCookingSessionRepository,
+        ingredients::postgres::PostgresIngredientRepository,
+        recipe::postgres::PostgresRecipeRepository,
+    };
+
+    #[sqlx::test]
+    async fn getting_a_cooking_session_works(pool: PgPool) {
+        __tests__::getting_a_cooking_session_works(
+            PostgresCookingSessionRepository::new(pool.clone()),
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn getting_a_nonexistent_cooking_session_errors(pool: PgPool) {
+        __tests__::getting_a_nonexistent_cooking_session_errors(
+            PostgresCookingSessionRepository::new(pool),
+        )
+        .await
+    }
+}
@@ -0,0 +1,50 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::event::DomainEvent,
+    repositories::recipe::{errors::GetRecipeByIdError, RecipeRepositoryService},
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum IsEventVisibleError {
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetRecipeByIdError> for IsEventVisibleError {
+    fn from(value: GetRecipeByIdError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+/// Whether the user gets to hear about the event. Events about a recipe only reach those who can
+/// see the recipe, those that just name it look the recipe up. A deleted recipe can't be looked
+/// up anymore, but all that is left of it is its ID.
+#[tracing::instrument("[QUERY] Is event visible", skip(recipe_repo, event))]
+pub async fn is_event_visible(
+    recipe_repo: RecipeRepositoryService,
+    user_id: Option<&Uuid>,
+    event: &DomainEvent,
+) -> Result<bool, IsEventVisibleError> {
+    match event {
+        DomainEvent::RecipeCreated(recipe) | DomainEvent::RecipeUpdated(recipe) => {
+            Ok(recipe.is_visible_to(user_id, None))
+        }
+        DomainEvent::IngredientAddedToRecipe { recipe_id, .. }
+        | DomainEvent::IngredientInRecipeUpdated { recipe_id, .. }
+        | DomainEvent::IngredientRemovedFromRecipe { recipe_id, .. } => {
+            match recipe_repo.get_visible(user_id, recipe_id, None).await {
+                Ok(_) => Ok(true),
+                Err(GetRecipeByIdError::NotFound(_)) => Ok(false),
+                Err(e) => Err(e.into()),
+            }
+        }
+        DomainEvent::RecipeDeleted(_)
+        | DomainEvent::IngredientCreated(_)
+        | DomainEvent::IngredientUpdated(_)
+        | DomainEvent::IngredientDeleted(_) => Ok(true),
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,75 @@
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        entities::event::DomainEvent,
+        queries::events::is_visible::is_event_visible,
+        repositories::{
+            ingredients::IngredientRepository,
+            recipe::{RecipeRepository, RecipeRepositoryService},
+            user::UserRepository,
+        },
+    },
+    test_utils::{
+        another_user_fixture, ingredient_fixture, insert_all_ingredients_of_recipe, insert_users,
+        private_recipe_fixture, recipe_fixture, user_fixture,
+    },
+};
+
+pub async fn events_about_a_private_recipe_only_reach_its_owner(
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let recipe = private_recipe_fixture();
+    insert_users(&user_repo).await;
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+    let events = [
+        DomainEvent::RecipeUpdated(recipe.clone()),
+        DomainEvent::IngredientAddedToRecipe {
+            recipe_id: recipe.id,
+            ingredient_id: ingredient_fixture().id,
+        },
+    ];
+
+    for event in &events {
+        for user_id in [None, Some(&another_user_fixture().id)] {
+            assert!(!is_event_visible(recipe_repo.clone(), user_id, event)
+                .await
+                .unwrap());
+        }
+
+        assert!(
+            is_event_visible(recipe_repo.clone(), Some(&user_fixture().id), event)
+                .await
+                .unwrap()
+        );
+    }
+}
+
+pub async fn events_about_public_recipes_and_ingredients_reach_everyone(
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let recipe = recipe_fixture();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+    let events = [
+        DomainEvent::RecipeCreated(recipe.clone()),
+        DomainEvent::IngredientRemovedFromRecipe {
+            recipe_id: recipe.id,
+            ingredient_id: ingredient_fixture().id,
+        },
+        DomainEvent::RecipeDeleted(private_recipe_fixture().id),
+        DomainEvent::IngredientCreated(ingredient_fixture()),
+    ];
+
+    for event in &events {
+        assert!(is_event_visible(recipe_repo.clone(), None, event)
+            .await
+            .unwrap());
+    }
+}
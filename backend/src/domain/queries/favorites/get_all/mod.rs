@@ -0,0 +1,78 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::favorite::FavoriteRecipe,
+    repositories::{
+        favorite::{errors::GetFavoriteError, FavoriteRepositoryService},
+        recipe::{errors::GetRecipeByIdError, RecipeRepositoryService},
+        recipe_note::{errors::GetRecipeNoteError, RecipeNoteRepositoryService},
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum GetFavoriteRecipesError {
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetFavoriteError> for GetFavoriteRecipesError {
+    fn from(value: GetFavoriteError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+impl From<GetRecipeByIdError> for GetFavoriteRecipesError {
+    fn from(value: GetRecipeByIdError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+impl From<GetRecipeNoteError> for GetFavoriteRecipesError {
+    fn from(value: GetRecipeNoteError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+/// Favorite recipes with their notes, the most recently added first
+#[tracing::instrument(
+    "[QUERY] Get favorite recipes",
+    skip(favorite_repo, recipe_repo, note_repo)
+)]
+pub async fn get_favorite_recipes(
+    favorite_repo: FavoriteRepositoryService,
+    recipe_repo: RecipeRepositoryService,
+    note_repo: RecipeNoteRepositoryService,
+    user_id: &Uuid,
+) -> Result<Vec<FavoriteRecipe>, GetFavoriteRecipesError> {
+    let favorites = favorite_repo.get_all(user_id).await?;
+
+    let mut result = Vec::with_capacity(favorites.len());
+    for favorite in favorites {
+        let recipe = match recipe_repo
+            .get_visible(Some(user_id), &favorite.recipe_id, None)
+            .await
+        {
+            Ok(recipe) => recipe,
+            // The recipe was deleted or hidden since, so there's nothing to show
+            Err(GetRecipeByIdError::NotFound(_)) => continue,
+            Err(e) => return Err(e.into()),
+        };
+
+        let notes = match note_repo.get(user_id, &favorite.recipe_id).await {
+            Ok(note) => Some(note),
+            Err(GetRecipeNoteError::NotFound(_)) => None,
+            Err(e) => return Err(e.into()),
+        };
+
+        result.push(FavoriteRecipe {
+            recipe,
+            favorited_at: favorite.created_at,
+            notes,
+        });
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests;
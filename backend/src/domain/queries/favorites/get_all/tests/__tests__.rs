@@ -0,0 +1,109 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use pretty_assertions::assert_eq;
+
+use crate::{
+    domain::{
+        entities::favorite::Favorite,
+        queries::favorites::get_all::get_favorite_recipes,
+        repositories::{
+            favorite::FavoriteRepository, ingredients::IngredientRepository,
+            recipe::RecipeRepository, recipe_note::RecipeNoteRepository, user::UserRepository,
+        },
+    },
+    test_utils::{
+        another_recipe_fixture, another_user_fixture, favorite_fixture,
+        insert_all_ingredients_of_recipe, insert_users, private_recipe_fixture, recipe_fixture,
+        recipe_note_fixture,
+    },
+};
+
+pub async fn getting_favorite_recipes_works(
+    favorite_repo: impl FavoriteRepository,
+    note_repo: impl RecipeNoteRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let recipe = recipe_fixture();
+    let newer = another_recipe_fixture(1);
+    let not_a_favorite = another_recipe_fixture(2);
+    insert_users(&user_repo).await;
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    for r in [&recipe, &newer, &not_a_favorite] {
+        recipe_repo.insert(r.clone()).await.unwrap();
+    }
+
+    let newer_favorite = Favorite {
+        recipe_id: newer.id,
+        created_at: DateTime::<Utc>::from_timestamp_nanos(1_700_000_000_000_000_000),
+        ..favorite_fixture()
+    };
+    let someone_elses = Favorite {
+        user_id: another_user_fixture().id,
+        recipe_id: not_a_favorite.id,
+        ..favorite_fixture()
+    };
+    favorite_repo.insert(favorite_fixture()).await.unwrap();
+    favorite_repo.insert(newer_favorite.clone()).await.unwrap();
+    favorite_repo.insert(someone_elses).await.unwrap();
+    note_repo.save(&recipe_note_fixture()).await.unwrap();
+
+    let result = get_favorite_recipes(
+        Arc::new(Box::new(favorite_repo)),
+        Arc::new(Box::new(recipe_repo)),
+        Arc::new(Box::new(note_repo)),
+        &favorite_fixture().user_id,
+    )
+    .await
+    .unwrap();
+
+    let result: Vec<_> = result
+        .into_iter()
+        .map(|favorite| (favorite.recipe.id, favorite.favorited_at, favorite.notes))
+        .collect();
+
+    assert_eq!(
+        result,
+        vec![
+            (newer.id, newer_favorite.created_at, None),
+            (
+                recipe.id,
+                favorite_fixture().created_at,
+                Some(recipe_note_fixture())
+            ),
+        ]
+    );
+}
+
+pub async fn favorites_of_recipes_hidden_since_are_left_out(
+    favorite_repo: impl FavoriteRepository,
+    note_repo: impl RecipeNoteRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let recipe = private_recipe_fixture();
+    insert_users(&user_repo).await;
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+    favorite_repo
+        .insert(Favorite {
+            user_id: another_user_fixture().id,
+            ..favorite_fixture()
+        })
+        .await
+        .unwrap();
+
+    let result = get_favorite_recipes(
+        Arc::new(Box::new(favorite_repo)),
+        Arc::new(Box::new(recipe_repo)),
+        Arc::new(Box::new(note_repo)),
+        &another_user_fixture().id,
+    )
+    .await
+    .unwrap();
+
+    assert!(result.is_empty());
+}
@@ -0,0 +1,73 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::{
+        favorite::in_memory::InMemoryFavoriteRepository,
+        ingredients::in_memory::InMemoryIngredientRepository,
+        recipe::in_memory::InMemoryRecipeRepository,
+        recipe_note::in_memory::InMemoryRecipeNoteRepository,
+        user::in_memory::InMemoryUserRepository,
+    };
+
+    #[tokio::test]
+    async fn getting_favorite_recipes_works() {
+        __tests__::getting_favorite_recipes_works(
+            InMemoryFavoriteRepository::new(),
+            InMemoryRecipeNoteRepository::new(),
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+            InMemoryUserRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn favorites_of_recipes_hidden_since_are_left_out() {
+        __tests__::favorites_of_recipes_hidden_since_are_left_out(
+            InMemoryFavoriteRepository::new(),
+            InMemoryRecipeNoteRepository::new(),
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+            InMemoryUserRepository::new(),
+        )
+        .await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        favorite::postgres::PostgresFavoriteRepository,
+        ingredients::postgres::PostgresIngredientRepository,
+        recipe::postgres::PostgresRecipeRepository,
+        recipe_note::postgres::PostgresRecipeNoteRepository,
+        user::postgres::PostgresUserRepository,
+    };
+
+    #[sqlx::test]
+    async fn getting_favorite_recipes_works(pool: PgPool) {
+        __tests__::getting_favorite_recipes_works(
+            PostgresFavoriteRepository::new(pool.clone()),
+            PostgresRecipeNoteRepository::new(pool.clone()),
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresUserRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn favorites_of_recipes_hidden_since_are_left_out(pool: PgPool) {
+        __tests__::favorites_of_recipes_hidden_since_are_left_out(
+            PostgresFavoriteRepository::new(pool.clone()),
+            PostgresRecipeNoteRepository::new(pool.clone()),
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresUserRepository::new(pool),
+        )
+        .await
+    }
+}
@@ -32,6 +32,7 @@ pub async fn returns_vec_of_items_inside(repo: impl IngredientRepository) {
         name: IngredientName("Tomato".into()),
         description: IngredientDescription("Description of a tomato".into()),
         diet_violations: vec![DietViolations::Vegan, DietViolations::Vegetarian].into(),
+        updated_at: Default::default(),
     };
 
     let given_2 = Ingredient {
@@ -39,6 +40,7 @@ pub async fn returns_vec_of_items_inside(repo: impl IngredientRepository) {
         name: IngredientName("Meat fries".into()),
         description: IngredientDescription("Description of meat fries (whatever they are)".into()),
         diet_violations: WhichDiets::new(),
+        updated_at: Default::default(),
     };
 
     repo.insert(given_1.clone()).await.unwrap();
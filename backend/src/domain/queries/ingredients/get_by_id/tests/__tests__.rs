@@ -14,6 +14,7 @@ pub async fn get_by_id_returns_ingredient(repo: impl IngredientRepository) {
         name: "Ingredient name".try_into().unwrap(),
         description: "Ingredient description".try_into().unwrap(),
         diet_violations: WhichDiets::new(),
+        updated_at: Default::default(),
     })
     .await
     .unwrap();
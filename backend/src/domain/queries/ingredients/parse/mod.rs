@@ -0,0 +1,79 @@
+use common::ParsedIngredientLineDTO;
+
+use crate::domain::{
+    commands::recipes::import::IngredientMatcher,
+    entities::{
+        ingredient::Ingredient,
+        recipe::{errors::ParseIngredientLineError, ingredient_line::ParsedIngredientLine},
+    },
+    repositories::ingredients::{errors::GetAllIngredientsError, IngredientRepositoryService},
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum ParseIngredientLinesError {
+    #[error("Could not parse line {0}: {1}")]
+    InvalidLine(usize, ParseIngredientLineError),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Report),
+}
+
+impl From<GetAllIngredientsError> for ParseIngredientLinesError {
+    fn from(value: GetAllIngredientsError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+/// An ingredient line split into its parts, along with the ingredient it most likely refers to
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedIngredient {
+    pub line: String,
+    pub parsed: ParsedIngredientLine,
+    pub ingredient: Option<Ingredient>,
+}
+
+impl From<ParsedIngredient> for ParsedIngredientLineDTO {
+    fn from(value: ParsedIngredient) -> Self {
+        Self {
+            amount: value.parsed.amount().into(),
+            line: value.line,
+            name: value.parsed.name,
+            notes: value.parsed.notes,
+            optional: value.parsed.optional,
+            ingredient: value.ingredient.map(|i| i.into()),
+        }
+    }
+}
+
+#[tracing::instrument("[QUERY] Parse ingredient lines", skip(ingredient_repo))]
+pub async fn parse_ingredient_lines(
+    ingredient_repo: IngredientRepositoryService,
+    lines: &[String],
+) -> Result<Vec<ParsedIngredient>, ParseIngredientLinesError> {
+    let parsed = lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            line.parse::<ParsedIngredientLine>()
+                .map_err(|e| ParseIngredientLinesError::InvalidLine(i + 1, e))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let ingredients = ingredient_repo.get_all().await?;
+    let matcher = IngredientMatcher::new(&ingredients);
+
+    let result = lines
+        .iter()
+        .zip(parsed)
+        .map(|(line, parsed)| ParsedIngredient {
+            line: line.clone(),
+            ingredient: matcher.find(&parsed.name).cloned(),
+            parsed,
+        })
+        .collect();
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests;
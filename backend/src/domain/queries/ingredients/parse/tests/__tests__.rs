@@ -0,0 +1,70 @@
+use std::sync::Arc;
+
+use pretty_assertions::assert_eq;
+
+use crate::{
+    domain::{
+        entities::recipe::{errors::ParseIngredientLineError, IngredientUnit},
+        queries::ingredients::parse::{parse_ingredient_lines, ParseIngredientLinesError},
+        repositories::ingredients::{IngredientRepository, IngredientRepositoryService},
+    },
+    test_utils::{insert_all_ingredients_of_recipe, recipe_fixture},
+};
+
+async fn setup(ingredient_repo: impl IngredientRepository) -> IngredientRepositoryService {
+    let recipe = recipe_fixture();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+
+    Arc::new(Box::new(ingredient_repo))
+}
+
+pub async fn parsing_lines_matches_existing_ingredients(
+    ingredient_repo: impl IngredientRepository,
+) {
+    let ingredient_repo = setup(ingredient_repo).await;
+    let recipe = recipe_fixture();
+    let lines = vec![
+        "400g firm tofu (pressed)".to_string(),
+        "1 1/2 cups broccoli florets, optional".to_string(),
+        "1 tbsp hoisin sauce".to_string(),
+    ];
+
+    let result = parse_ingredient_lines(ingredient_repo, &lines)
+        .await
+        .unwrap();
+
+    assert_eq!(result.len(), 3);
+
+    assert_eq!(result[0].line, "400g firm tofu (pressed)");
+    assert_eq!(result[0].parsed.amount(), IngredientUnit::Grams(400.0));
+    assert_eq!(result[0].parsed.notes.as_deref(), Some("pressed"));
+    assert_eq!(
+        result[0].ingredient,
+        Some(recipe.ingredients[0].ingredient.clone())
+    );
+
+    assert_eq!(result[1].parsed.amount(), IngredientUnit::Cups(1.5));
+    assert!(result[1].parsed.optional);
+    assert_eq!(
+        result[1].ingredient,
+        Some(recipe.ingredients[1].ingredient.clone())
+    );
+
+    assert_eq!(result[2].parsed.name, "hoisin sauce");
+    assert_eq!(result[2].ingredient, None);
+}
+
+pub async fn parsing_invalid_line_errors(ingredient_repo: impl IngredientRepository) {
+    let ingredient_repo = setup(ingredient_repo).await;
+    let lines = vec!["400g firm tofu".to_string(), "2 cups".to_string()];
+
+    let result = parse_ingredient_lines(ingredient_repo, &lines).await;
+
+    assert!(matches!(
+        result,
+        Err(ParseIngredientLinesError::InvalidLine(
+            2,
+            ParseIngredientLineError::MissingName(_)
+        ))
+    ));
+}
@@ -0,0 +1,40 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::ingredients::in_memory::InMemoryIngredientRepository;
+
+    #[tokio::test]
+    async fn parsing_lines_matches_existing_ingredients() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+
+        __tests__::parsing_lines_matches_existing_ingredients(ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn parsing_invalid_line_errors() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+
+        __tests__::parsing_invalid_line_errors(ingredient_repo).await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use crate::domain::repositories::ingredients::postgres::PostgresIngredientRepository;
+    use sqlx::PgPool;
+
+    #[sqlx::test]
+    async fn parsing_lines_matches_existing_ingredients(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool);
+
+        __tests__::parsing_lines_matches_existing_ingredients(ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn parsing_invalid_line_errors(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool);
+
+        __tests__::parsing_invalid_line_errors(ingredient_repo).await
+    }
+}
@@ -0,0 +1,118 @@
+use std::time::Duration;
+
+use chrono::{NaiveDateTime, TimeDelta};
+
+use crate::domain::{
+    entities::{
+        meal_plan::MealPlanEntry,
+        recipe::{Recipe, ServingsType},
+    },
+    queries::recipes::export::format_duration,
+};
+
+const PRODUCT_ID: &str = "-//deepdi.sh//Meal planner//EN";
+/// Lines longer than this many octets have to be folded (RFC 5545, section 3.1)
+const MAX_LINE_LENGTH: usize = 75;
+/// Used for recipes that do not say how long they take
+const DEFAULT_PREPARATION_MINUTES: i64 = 30;
+const DATE_TIME_FORMAT: &str = "%Y%m%dT%H%M%S";
+
+pub fn render(events: &[(&MealPlanEntry, &Recipe)], base_url: &str) -> String {
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        format!("PRODID:{}", PRODUCT_ID),
+        "CALSCALE:GREGORIAN".to_string(),
+        "METHOD:PUBLISH".to_string(),
+        "X-WR-CALNAME:Meal plan".to_string(),
+    ];
+
+    for (entry, recipe) in events {
+        lines.extend(render_event(entry, recipe, base_url));
+    }
+
+    lines.push("END:VCALENDAR".to_string());
+
+    lines.iter().map(|line| fold(line) + "\r\n").collect()
+}
+
+/// The meal is done at the time it is served at, and cooking starts as long before that as the
+/// recipe takes in total. Recipes that take too long to fit in a calendar get the default time.
+fn event_times(entry: &MealPlanEntry, recipe: &Recipe) -> (NaiveDateTime, NaiveDateTime) {
+    let end = entry.date.and_time(entry.slot.serving_time());
+
+    let total = recipe
+        .time
+        .values()
+        .fold(Duration::ZERO, |total, duration| {
+            total.saturating_add(*duration)
+        });
+    let start = TimeDelta::from_std(total)
+        .ok()
+        .filter(|preparation| !preparation.is_zero())
+        .and_then(|preparation| end.checked_sub_signed(preparation))
+        .unwrap_or(end - TimeDelta::minutes(DEFAULT_PREPARATION_MINUTES));
+
+    (start, end)
+}
+
+fn render_event(entry: &MealPlanEntry, recipe: &Recipe, base_url: &str) -> Vec<String> {
+    let link = format!("{}/recipe/{}", base_url.trim_end_matches('/'), recipe.id);
+    let (start, end) = event_times(entry, recipe);
+
+    let mut description = vec![ServingsType::Exact(entry.servings).to_string()];
+    description.extend(
+        recipe
+            .time
+            .iter()
+            .map(|(name, duration)| format!("{}: {}", name, format_duration(duration))),
+    );
+    description.push(link.clone());
+
+    vec![
+        "BEGIN:VEVENT".to_string(),
+        format!("UID:{}", entry.id),
+        format!("DTSTAMP:{}Z", entry.updated_at.format(DATE_TIME_FORMAT)),
+        format!("DTSTART:{}", start.format(DATE_TIME_FORMAT)),
+        format!("DTEND:{}", end.format(DATE_TIME_FORMAT)),
+        format!("SUMMARY:{}", escape(&recipe.name)),
+        format!("DESCRIPTION:{}", escape(&description.join("\n"))),
+        format!("URL:{}", link),
+        "BEGIN:VALARM".to_string(),
+        "ACTION:DISPLAY".to_string(),
+        "TRIGGER:PT0S".to_string(),
+        format!(
+            "DESCRIPTION:{}",
+            escape(&format!("Time to start cooking {}", recipe.name))
+        ),
+        "END:VALARM".to_string(),
+        "END:VEVENT".to_string(),
+    ]
+}
+
+/// Escapes the characters that have a special meaning in TEXT values
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace('\n', "\\n")
+}
+
+/// Splits a content line into multiple physical lines, each starting with a space after the first
+fn fold(line: &str) -> String {
+    let mut result = String::with_capacity(line.len());
+    let mut length = 0;
+
+    for character in line.chars() {
+        if length + character.len_utf8() > MAX_LINE_LENGTH {
+            result.push_str("\r\n ");
+            length = 1;
+        }
+
+        result.push(character);
+        length += character.len_utf8();
+    }
+
+    result
+}
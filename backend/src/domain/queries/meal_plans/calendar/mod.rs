@@ -0,0 +1,97 @@
+mod ics;
+
+use std::collections::{hash_map::Entry, HashMap};
+
+use chrono::{Days, NaiveDate};
+
+use crate::domain::repositories::{
+    meal_plan::{
+        errors::{GetCalendarFeedError, GetMealPlanEntryError},
+        MealPlanRepositoryService,
+    },
+    recipe::{errors::GetRecipeByIdError, RecipeRepositoryService},
+};
+
+/// How far into the past the feed reaches, so that recent meals do not disappear from calendars
+const DAYS_BEFORE: u64 = 30;
+/// How far into the future the feed reaches
+const DAYS_AFTER: u64 = 365;
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum GetMealPlanCalendarError {
+    #[error("Could not find the calendar feed")]
+    NotFound,
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetCalendarFeedError> for GetMealPlanCalendarError {
+    fn from(value: GetCalendarFeedError) -> Self {
+        match value {
+            GetCalendarFeedError::NotFound => Self::NotFound,
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<GetMealPlanEntryError> for GetMealPlanCalendarError {
+    fn from(value: GetMealPlanEntryError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+impl From<GetRecipeByIdError> for GetMealPlanCalendarError {
+    fn from(value: GetRecipeByIdError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+/// Renders the meals that the owner of the feed planned around `today` as an iCalendar feed.
+/// Recipes are linked to by appending their path to `base_url`.
+#[tracing::instrument(
+    "[QUERY] Get meal plan calendar",
+    skip(meal_plan_repo, recipe_repo, token)
+)]
+pub async fn get_meal_plan_calendar(
+    meal_plan_repo: MealPlanRepositoryService,
+    recipe_repo: RecipeRepositoryService,
+    token: &str,
+    today: &NaiveDate,
+    base_url: &str,
+) -> Result<String, GetMealPlanCalendarError> {
+    let feed = meal_plan_repo.get_feed_by_token(token).await?;
+
+    let from = *today - Days::new(DAYS_BEFORE);
+    let to = *today + Days::new(DAYS_AFTER);
+    let entries = meal_plan_repo
+        .get_in_range(&feed.user_id, &from, &to)
+        .await?;
+
+    let mut recipes = HashMap::new();
+    for entry in &entries {
+        if let Entry::Vacant(slot) = recipes.entry(entry.recipe_id) {
+            match recipe_repo
+                .get_visible(Some(&feed.user_id), &entry.recipe_id, None)
+                .await
+            {
+                Ok(recipe) => {
+                    slot.insert(recipe);
+                }
+                // The recipe was hidden since it was planned, so it is left out
+                Err(GetRecipeByIdError::NotFound(_)) => {}
+                Err(e) => return Err(e.into()),
+            }
+        }
+    }
+
+    let events: Vec<_> = entries
+        .iter()
+        .filter_map(|entry| recipes.get(&entry.recipe_id).map(|recipe| (entry, recipe)))
+        .collect();
+
+    Ok(ics::render(&events, base_url))
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,212 @@
+use std::{collections::BTreeMap, sync::Arc, time::Duration};
+
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::{
+            meal_plan::{CalendarFeed, MealPlanEntry},
+            recipe::Recipe,
+            visibility::Visibility,
+        },
+        queries::meal_plans::calendar::{get_meal_plan_calendar, GetMealPlanCalendarError},
+        repositories::{
+            ingredients::IngredientRepository,
+            meal_plan::{MealPlanRepository, MealPlanRepositoryService},
+            recipe::{RecipeRepository, RecipeRepositoryService},
+            user::UserRepository,
+        },
+    },
+    test_utils::{
+        another_recipe_fixture, another_user_fixture, insert_all_ingredients_of_recipe,
+        insert_meal_plan, insert_users, meal_plan_entry_fixture, recipe_fixture,
+    },
+};
+
+pub async fn getting_a_meal_plan_calendar_works(
+    meal_plan_repo: impl MealPlanRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let entry = meal_plan_entry_fixture();
+    let far_away = MealPlanEntry {
+        id: Uuid::from_u128(257),
+        date: NaiveDate::from_ymd_opt(2025, 12, 1).unwrap(),
+        ..entry.clone()
+    };
+    let someone_elses = MealPlanEntry {
+        id: Uuid::from_u128(258),
+        user_id: another_user_fixture().id,
+        ..entry.clone()
+    };
+    insert_meal_plan(
+        &meal_plan_repo,
+        &recipe_repo,
+        &ingredient_repo,
+        &user_repo,
+        &[entry.clone(), far_away.clone(), someone_elses.clone()],
+    )
+    .await;
+    let feed = CalendarFeed::new(entry.user_id, "Kitchen tablet".to_string());
+    meal_plan_repo.insert_feed(feed.clone()).await.unwrap();
+    let meal_plan_repo: MealPlanRepositoryService = Arc::new(Box::new(meal_plan_repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let result = get_meal_plan_calendar(
+        meal_plan_repo,
+        recipe_repo,
+        &feed.token,
+        &NaiveDate::from_ymd_opt(2024, 10, 20).unwrap(),
+        "https://example.com/",
+    )
+    .await
+    .unwrap();
+
+    assert!(result.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+    assert!(result.ends_with("END:VCALENDAR\r\n"));
+    assert!(result.split_terminator("\r\n").all(|line| line.len() <= 75));
+
+    let unfolded = result.replace("\r\n ", "");
+    let lines: Vec<&str> = unfolded.split_terminator("\r\n").collect();
+
+    for expected in [
+        "UID:00000000-0000-0000-0000-000000000100",
+        "DTSTAMP:20220911T183448Z",
+        // Dinner is at 19:00 and the recipe takes 25 minutes in total
+        "DTSTART:20241021T183500",
+        "DTEND:20241021T190000",
+        "SUMMARY:Hoisin Tofu and Broccoli",
+        "DESCRIPTION:2 servings\\nCook time: 10 min\\nPrep time: 15 min\\nhttps://example.com/recipe/00000000-0000-0000-0000-000000000000",
+        "URL:https://example.com/recipe/00000000-0000-0000-0000-000000000000",
+        "TRIGGER:PT0S",
+    ] {
+        assert!(lines.contains(&expected), "{} is missing", expected);
+    }
+
+    assert!(!unfolded.contains(&far_away.id.to_string()));
+    assert!(!unfolded.contains(&someone_elses.id.to_string()));
+}
+
+pub async fn getting_a_calendar_with_overlong_recipe_times_works(
+    meal_plan_repo: impl MealPlanRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    insert_users(&user_repo).await;
+    let recipe = recipe_fixture();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    recipe_repo
+        .insert(Recipe {
+            time: BTreeMap::from([
+                ("Prep time".to_string(), Duration::from_secs(u64::MAX)),
+                ("Rising time".to_string(), Duration::from_secs(u64::MAX / 2)),
+            ]),
+            ..recipe
+        })
+        .await
+        .unwrap();
+    let entry = meal_plan_entry_fixture();
+    meal_plan_repo.insert(entry.clone()).await.unwrap();
+    let feed = CalendarFeed::new(entry.user_id, "Kitchen tablet".to_string());
+    meal_plan_repo.insert_feed(feed.clone()).await.unwrap();
+    let meal_plan_repo: MealPlanRepositoryService = Arc::new(Box::new(meal_plan_repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let result = get_meal_plan_calendar(
+        meal_plan_repo,
+        recipe_repo,
+        &feed.token,
+        &NaiveDate::from_ymd_opt(2024, 10, 20).unwrap(),
+        "https://example.com/",
+    )
+    .await
+    .unwrap();
+
+    let unfolded = result.replace("\r\n ", "");
+    let lines: Vec<&str> = unfolded.split_terminator("\r\n").collect();
+
+    // The recipe takes too long, so it gets the default 30 minutes
+    assert!(lines.contains(&"DTSTART:20241021T183000"));
+    assert!(lines.contains(&"DTEND:20241021T190000"));
+}
+
+pub async fn getting_a_calendar_with_a_wrong_token_errors(
+    meal_plan_repo: impl MealPlanRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    insert_meal_plan(
+        &meal_plan_repo,
+        &recipe_repo,
+        &ingredient_repo,
+        &user_repo,
+        &[meal_plan_entry_fixture()],
+    )
+    .await;
+    let meal_plan_repo: MealPlanRepositoryService = Arc::new(Box::new(meal_plan_repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let error = get_meal_plan_calendar(
+        meal_plan_repo,
+        recipe_repo,
+        "not a token",
+        &NaiveDate::from_ymd_opt(2024, 10, 20).unwrap(),
+        "https://example.com",
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(error, GetMealPlanCalendarError::NotFound));
+}
+
+pub async fn recipes_hidden_since_they_were_planned_are_left_out_of_the_calendar(
+    meal_plan_repo: impl MealPlanRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let recipe = Recipe {
+        visibility: Visibility::Private,
+        owner_id: Some(another_user_fixture().id),
+        ..another_recipe_fixture(1)
+    };
+    let entry = meal_plan_entry_fixture();
+    let hidden = MealPlanEntry {
+        id: Uuid::from_u128(257),
+        recipe_id: recipe.id,
+        ..entry.clone()
+    };
+    insert_meal_plan(
+        &meal_plan_repo,
+        &recipe_repo,
+        &ingredient_repo,
+        &user_repo,
+        &[],
+    )
+    .await;
+    recipe_repo.insert(recipe).await.unwrap();
+    meal_plan_repo.insert(entry.clone()).await.unwrap();
+    meal_plan_repo.insert(hidden.clone()).await.unwrap();
+    let feed = CalendarFeed::new(entry.user_id, "Kitchen tablet".to_string());
+    meal_plan_repo.insert_feed(feed.clone()).await.unwrap();
+    let meal_plan_repo: MealPlanRepositoryService = Arc::new(Box::new(meal_plan_repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let result = get_meal_plan_calendar(
+        meal_plan_repo,
+        recipe_repo,
+        &feed.token,
+        &NaiveDate::from_ymd_opt(2024, 10, 20).unwrap(),
+        "https://example.com/",
+    )
+    .await
+    .unwrap();
+
+    assert!(result.contains(&entry.id.to_string()));
+    assert!(!result.contains(&hidden.id.to_string()));
+    assert!(!result.contains("Hoisin Tofu and Broccoli #1"));
+}
@@ -0,0 +1,109 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::{
+        ingredients::in_memory::InMemoryIngredientRepository,
+        meal_plan::in_memory::InMemoryMealPlanRepository,
+        recipe::in_memory::InMemoryRecipeRepository, user::in_memory::InMemoryUserRepository,
+    };
+
+    #[tokio::test]
+    async fn getting_a_meal_plan_calendar_works() {
+        __tests__::getting_a_meal_plan_calendar_works(
+            InMemoryMealPlanRepository::new(),
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+            InMemoryUserRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn recipes_hidden_since_they_were_planned_are_left_out_of_the_calendar() {
+        __tests__::recipes_hidden_since_they_were_planned_are_left_out_of_the_calendar(
+            InMemoryMealPlanRepository::new(),
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+            InMemoryUserRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn getting_a_calendar_with_overlong_recipe_times_works() {
+        __tests__::getting_a_calendar_with_overlong_recipe_times_works(
+            InMemoryMealPlanRepository::new(),
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+            InMemoryUserRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn getting_a_calendar_with_a_wrong_token_errors() {
+        __tests__::getting_a_calendar_with_a_wrong_token_errors(
+            InMemoryMealPlanRepository::new(),
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+            InMemoryUserRepository::new(),
+        )
+        .await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        ingredients::postgres::PostgresIngredientRepository,
+        meal_plan::postgres::PostgresMealPlanRepository,
+        recipe::postgres::PostgresRecipeRepository, user::postgres::PostgresUserRepository,
+    };
+
+    #[sqlx::test]
+    async fn getting_a_meal_plan_calendar_works(pool: PgPool) {
+        __tests__::getting_a_meal_plan_calendar_works(
+            PostgresMealPlanRepository::new(pool.clone()),
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresUserRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn recipes_hidden_since_they_were_planned_are_left_out_of_the_calendar(pool: PgPool) {
+        __tests__::recipes_hidden_since_they_were_planned_are_left_out_of_the_calendar(
+            PostgresMealPlanRepository::new(pool.clone()),
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresUserRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn getting_a_calendar_with_overlong_recipe_times_works(pool: PgPool) {
+        __tests__::getting_a_calendar_with_overlong_recipe_times_works(
+            PostgresMealPlanRepository::new(pool.clone()),
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresUserRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn getting_a_calendar_with_a_wrong_token_errors(pool: PgPool) {
+        __tests__::getting_a_calendar_with_a_wrong_token_errors(
+            PostgresMealPlanRepository::new(pool.clone()),
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresUserRepository::new(pool),
+        )
+        .await
+    }
+}
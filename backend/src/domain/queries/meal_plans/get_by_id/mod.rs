@@ -0,0 +1,42 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{meal_plan::MealPlanEntry, recipe::errors::ValidationError},
+    repositories::meal_plan::{errors::GetMealPlanEntryError, MealPlanRepositoryService},
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum GetMealPlanEntryByIdError {
+    #[error("Could not find the meal plan entry with the ID {0}")]
+    NotFound(Uuid),
+
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetMealPlanEntryError> for GetMealPlanEntryByIdError {
+    fn from(value: GetMealPlanEntryError) -> Self {
+        match value {
+            GetMealPlanEntryError::NotFound(id) => Self::NotFound(id),
+            GetMealPlanEntryError::ValidationError(e) => Self::ValidationError(e),
+            GetMealPlanEntryError::UnknownError(e) => Self::Unknown(e),
+        }
+    }
+}
+
+#[tracing::instrument("[QUERY] Get meal plan entry", skip(meal_plan_repo))]
+pub async fn get_meal_plan_entry_by_id(
+    meal_plan_repo: MealPlanRepositoryService,
+    user_id: &Uuid,
+    id: &Uuid,
+) -> Result<MealPlanEntry, GetMealPlanEntryByIdError> {
+    let result = meal_plan_repo.get_by_id(user_id, id).await?;
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests;
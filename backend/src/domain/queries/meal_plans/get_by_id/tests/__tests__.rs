@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        queries::meal_plans::get_by_id::{get_meal_plan_entry_by_id, GetMealPlanEntryByIdError},
+        repositories::{
+            ingredients::IngredientRepository,
+            meal_plan::{MealPlanRepository, MealPlanRepositoryService},
+            recipe::RecipeRepository,
+            user::UserRepository,
+        },
+    },
+    test_utils::{another_user_fixture, insert_meal_plan, meal_plan_entry_fixture, user_fixture},
+};
+
+pub async fn getting_a_planned_meal_works(
+    meal_plan_repo: impl MealPlanRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let entry = meal_plan_entry_fixture();
+    insert_meal_plan(
+        &meal_plan_repo,
+        &recipe_repo,
+        &ingredient_repo,
+        &user_repo,
+        std::slice::from_ref(&entry),
+    )
+    .await;
+    let meal_plan_repo: MealPlanRepositoryService = Arc::new(Box::new(meal_plan_repo));
+
+    let result = get_meal_plan_entry_by_id(meal_plan_repo.clone(), &entry.user_id, &entry.id)
+        .await
+        .unwrap();
+
+    assert_eq!(result, entry);
+
+    let error = get_meal_plan_entry_by_id(meal_plan_repo, &another_user_fixture().id, &entry.id)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, GetMealPlanEntryByIdError::NotFound(e) if e == entry.id));
+}
+
+pub async fn getting_a_nonexistent_planned_meal_errors(
+    meal_plan_repo: impl MealPlanRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    insert_meal_plan(
+        &meal_plan_repo,
+        &recipe_repo,
+        &ingredient_repo,
+        &user_repo,
+        &[],
+    )
+    .await;
+    let meal_plan_repo: MealPlanRepositoryService = Arc::new(Box::new(meal_plan_repo));
+    let id = Uuid::now_v7();
+
+    let error = get_meal_plan_entry_by_id(meal_plan_repo, &user_fixture().id, &id)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, GetMealPlanEntryByIdError::NotFound(e) if e == id));
+}
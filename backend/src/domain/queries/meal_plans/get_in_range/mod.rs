@@ -0,0 +1,56 @@
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{meal_plan::MealPlanEntry, recipe::errors::ValidationError},
+    repositories::{
+        meal_plan::{errors::GetMealPlanEntryError, MealPlanRepositoryService},
+        recipe::errors::GetRecipeByIdError,
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum GetMealPlanError {
+    #[error("The start of the range ({0}) is after its end ({1})")]
+    InvalidRange(NaiveDate, NaiveDate),
+
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetMealPlanEntryError> for GetMealPlanError {
+    fn from(value: GetMealPlanEntryError) -> Self {
+        match value {
+            GetMealPlanEntryError::ValidationError(e) => Self::ValidationError(e),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<GetRecipeByIdError> for GetMealPlanError {
+    fn from(value: GetRecipeByIdError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+#[tracing::instrument("[QUERY] Get meal plan for a date range", skip(meal_plan_repo))]
+pub async fn get_meal_plan_in_range(
+    meal_plan_repo: MealPlanRepositoryService,
+    user_id: &Uuid,
+    from: &NaiveDate,
+    to: &NaiveDate,
+) -> Result<Vec<MealPlanEntry>, GetMealPlanError> {
+    if from > to {
+        return Err(GetMealPlanError::InvalidRange(*from, *to));
+    }
+
+    let result = meal_plan_repo.get_in_range(user_id, from, to).await?;
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,97 @@
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::meal_plan::{MealPlanEntry, MealSlot},
+        queries::meal_plans::get_in_range::{get_meal_plan_in_range, GetMealPlanError},
+        repositories::{
+            ingredients::IngredientRepository,
+            meal_plan::{MealPlanRepository, MealPlanRepositoryService},
+            recipe::RecipeRepository,
+            user::UserRepository,
+        },
+    },
+    test_utils::{another_user_fixture, insert_meal_plan, meal_plan_entry_fixture},
+};
+
+fn entries() -> Vec<MealPlanEntry> {
+    let dinner = meal_plan_entry_fixture();
+    let breakfast = MealPlanEntry {
+        id: Uuid::from_u128(257),
+        slot: MealSlot::Breakfast,
+        ..dinner.clone()
+    };
+    let next_week = MealPlanEntry {
+        id: Uuid::from_u128(258),
+        date: NaiveDate::from_ymd_opt(2024, 10, 28).unwrap(),
+        ..dinner.clone()
+    };
+    let someone_elses = MealPlanEntry {
+        id: Uuid::from_u128(259),
+        user_id: another_user_fixture().id,
+        ..dinner.clone()
+    };
+
+    vec![dinner, breakfast, next_week, someone_elses]
+}
+
+pub async fn getting_meals_in_range_works(
+    meal_plan_repo: impl MealPlanRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let entries = entries();
+    insert_meal_plan(
+        &meal_plan_repo,
+        &recipe_repo,
+        &ingredient_repo,
+        &user_repo,
+        &entries,
+    )
+    .await;
+    let meal_plan_repo: MealPlanRepositoryService = Arc::new(Box::new(meal_plan_repo));
+
+    let result = get_meal_plan_in_range(
+        meal_plan_repo,
+        &entries[0].user_id,
+        &NaiveDate::from_ymd_opt(2024, 10, 21).unwrap(),
+        &NaiveDate::from_ymd_opt(2024, 10, 27).unwrap(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(result, vec![entries[1].clone(), entries[0].clone()]);
+}
+
+pub async fn getting_meals_in_an_invalid_range_errors(
+    meal_plan_repo: impl MealPlanRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    insert_meal_plan(
+        &meal_plan_repo,
+        &recipe_repo,
+        &ingredient_repo,
+        &user_repo,
+        &entries(),
+    )
+    .await;
+    let meal_plan_repo: MealPlanRepositoryService = Arc::new(Box::new(meal_plan_repo));
+
+    let error = get_meal_plan_in_range(
+        meal_plan_repo,
+        &entries()[0].user_id,
+        &NaiveDate::from_ymd_opt(2024, 10, 27).unwrap(),
+        &NaiveDate::from_ymd_opt(2024, 10, 21).unwrap(),
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(error, GetMealPlanError::InvalidRange(_, _)));
+}
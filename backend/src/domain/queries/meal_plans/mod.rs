@@ -0,0 +1,4 @@
+pub mod calendar;
+pub mod get_by_id;
+pub mod get_in_range;
+pub mod summary;
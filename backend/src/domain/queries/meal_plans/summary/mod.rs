@@ -0,0 +1,107 @@
+use std::collections::{hash_map::Entry, BTreeSet, HashMap};
+
+use chrono::NaiveDate;
+use common::{AggregatedIngredientDTO, MealPlanSummaryDTO};
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        ingredient::Ingredient, meal_plan::MealPlanEntry, recipe::IngredientUnit,
+        shopping_list::aggregate_recipes,
+    },
+    repositories::{
+        meal_plan::MealPlanRepositoryService,
+        recipe::{errors::GetRecipeByIdError, RecipeRepositoryService},
+    },
+};
+
+use super::get_in_range::{get_meal_plan_in_range, GetMealPlanError};
+
+/// Everything that is needed to cook the planned meals
+#[derive(Debug, Clone, PartialEq)]
+pub struct MealPlanSummary {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub entries: Vec<MealPlanEntry>,
+    /// Ingredients of every planned recipe, scaled to the planned servings
+    pub ingredients: Vec<(Ingredient, IngredientUnit)>,
+    /// Every diet that one of the planned recipes violates
+    pub diet_violations: Vec<String>,
+}
+
+impl From<MealPlanSummary> for MealPlanSummaryDTO {
+    fn from(value: MealPlanSummary) -> Self {
+        Self {
+            from: value.from,
+            to: value.to,
+            entries: value.entries.into_iter().map(|e| e.into()).collect(),
+            ingredients: value
+                .ingredients
+                .into_iter()
+                .map(|(ingredient, amount)| AggregatedIngredientDTO {
+                    ingredient: ingredient.into(),
+                    amount: amount.into(),
+                })
+                .collect(),
+            diet_violations: value.diet_violations,
+        }
+    }
+}
+
+#[tracing::instrument(
+    "[QUERY] Summarize meal plan for a date range",
+    skip(meal_plan_repo, recipe_repo)
+)]
+pub async fn get_meal_plan_summary(
+    meal_plan_repo: MealPlanRepositoryService,
+    recipe_repo: RecipeRepositoryService,
+    user_id: &Uuid,
+    from: &NaiveDate,
+    to: &NaiveDate,
+) -> Result<MealPlanSummary, GetMealPlanError> {
+    let mut entries = get_meal_plan_in_range(meal_plan_repo, user_id, from, to).await?;
+
+    let mut recipes = HashMap::new();
+    for entry in &entries {
+        if let Entry::Vacant(slot) = recipes.entry(entry.recipe_id) {
+            match recipe_repo
+                .get_visible(Some(user_id), &entry.recipe_id, None)
+                .await
+            {
+                Ok(recipe) => {
+                    slot.insert(recipe);
+                }
+                // The recipe was hidden since it was planned, so it is left out
+                Err(GetRecipeByIdError::NotFound(_)) => {}
+                Err(e) => return Err(e.into()),
+            }
+        }
+    }
+
+    entries.retain(|entry| recipes.contains_key(&entry.recipe_id));
+
+    let planned: Vec<_> = entries
+        .iter()
+        .filter_map(|entry| {
+            recipes
+                .get(&entry.recipe_id)
+                .map(|recipe| (recipe.clone(), Some(entry.servings)))
+        })
+        .collect();
+
+    let diet_violations: BTreeSet<String> = recipes
+        .values()
+        .flat_map(|recipe| recipe.get_diet_violations())
+        .collect();
+
+    Ok(MealPlanSummary {
+        from: *from,
+        to: *to,
+        ingredients: aggregate_recipes(&planned, false),
+        diet_violations: diet_violations.into_iter().collect(),
+        entries,
+    })
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,147 @@
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::{
+            meal_plan::{MealPlanEntry, MealSlot},
+            recipe::{IngredientUnit, Recipe},
+            visibility::Visibility,
+        },
+        queries::meal_plans::summary::get_meal_plan_summary,
+        repositories::{
+            ingredients::IngredientRepository,
+            meal_plan::{MealPlanRepository, MealPlanRepositoryService},
+            recipe::{RecipeRepository, RecipeRepositoryService},
+            user::UserRepository,
+        },
+    },
+    test_utils::{
+        another_recipe_fixture, another_user_fixture, insert_meal_plan, meal_plan_entry_fixture,
+        recipe_fixture,
+    },
+};
+
+pub async fn summarizing_a_meal_plan_works(
+    meal_plan_repo: impl MealPlanRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let dinner = meal_plan_entry_fixture();
+    let lunch = MealPlanEntry {
+        id: Uuid::from_u128(257),
+        date: NaiveDate::from_ymd_opt(2024, 10, 22).unwrap(),
+        slot: MealSlot::Lunch,
+        servings: 4,
+        ..dinner.clone()
+    };
+    let someone_elses = MealPlanEntry {
+        id: Uuid::from_u128(258),
+        user_id: another_user_fixture().id,
+        ..dinner.clone()
+    };
+    insert_meal_plan(
+        &meal_plan_repo,
+        &recipe_repo,
+        &ingredient_repo,
+        &user_repo,
+        &[dinner.clone(), lunch, someone_elses],
+    )
+    .await;
+    let meal_plan_repo: MealPlanRepositoryService = Arc::new(Box::new(meal_plan_repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let result = get_meal_plan_summary(
+        meal_plan_repo,
+        recipe_repo,
+        &dinner.user_id,
+        &NaiveDate::from_ymd_opt(2024, 10, 21).unwrap(),
+        &NaiveDate::from_ymd_opt(2024, 10, 27).unwrap(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(result.entries.len(), 2);
+
+    // The recipe serves 4, so 2 + 4 servings is one and a half of the recipe
+    let mut amounts: Vec<_> = result
+        .ingredients
+        .iter()
+        .map(|(ingredient, amount)| (ingredient.name.to_string(), amount.clone()))
+        .collect();
+    amounts.sort_by(|a, b| a.0.cmp(&b.0));
+
+    assert_eq!(
+        amounts,
+        vec![
+            (
+                "Broccoli".to_string(),
+                IngredientUnit::Other {
+                    unit: "head".to_string(),
+                    amount: 1.5
+                }
+            ),
+            ("Firm tofu".to_string(), IngredientUnit::Grams(600.0)),
+            (
+                "Garlic".to_string(),
+                IngredientUnit::Other {
+                    unit: "clove".to_string(),
+                    amount: 6.0
+                }
+            ),
+        ]
+    );
+    assert_eq!(
+        result.diet_violations,
+        recipe_fixture().get_diet_violations()
+    );
+}
+
+pub async fn recipes_hidden_since_they_were_planned_are_left_out_of_the_summary(
+    meal_plan_repo: impl MealPlanRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let recipe = Recipe {
+        visibility: Visibility::Private,
+        owner_id: Some(another_user_fixture().id),
+        ..another_recipe_fixture(1)
+    };
+    let dinner = meal_plan_entry_fixture();
+    let hidden = MealPlanEntry {
+        id: Uuid::from_u128(257),
+        recipe_id: recipe.id,
+        ..dinner.clone()
+    };
+    insert_meal_plan(
+        &meal_plan_repo,
+        &recipe_repo,
+        &ingredient_repo,
+        &user_repo,
+        &[],
+    )
+    .await;
+    recipe_repo.insert(recipe).await.unwrap();
+    meal_plan_repo.insert(dinner.clone()).await.unwrap();
+    meal_plan_repo.insert(hidden).await.unwrap();
+    let meal_plan_repo: MealPlanRepositoryService = Arc::new(Box::new(meal_plan_repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let result = get_meal_plan_summary(
+        meal_plan_repo,
+        recipe_repo,
+        &dinner.user_id,
+        &NaiveDate::from_ymd_opt(2024, 10, 21).unwrap(),
+        &NaiveDate::from_ymd_opt(2024, 10, 27).unwrap(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(result.entries, vec![dinner]);
+    assert_eq!(result.ingredients.len(), recipe_fixture().ingredients.len());
+}
@@ -0,0 +1,56 @@
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::pantry::{rank_recipes, CookableRecipe},
+    repositories::{
+        pantry::{errors::GetPantryItemError, PantryRepositoryService},
+        recipe::{errors::GetRecipeByIdError, RecipeRepositoryService},
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum GetCookableRecipesError {
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetPantryItemError> for GetCookableRecipesError {
+    fn from(value: GetPantryItemError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+impl From<GetRecipeByIdError> for GetCookableRecipesError {
+    fn from(value: GetRecipeByIdError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+/// Public recipes that can be cooked with what is in the pantry, the ones missing the least coming first
+#[tracing::instrument("[QUERY] Get cookable recipes", skip(pantry_repo, recipe_repo))]
+pub async fn get_cookable_recipes(
+    pantry_repo: PantryRepositoryService,
+    recipe_repo: RecipeRepositoryService,
+    user_id: &Uuid,
+    today: &NaiveDate,
+) -> Result<Vec<CookableRecipe>, GetCookableRecipesError> {
+    let pantry = pantry_repo.get_all(user_id).await?;
+    let ingredient_ids: Vec<_> = pantry
+        .iter()
+        .filter(|item| !item.is_expired(today))
+        .map(|item| item.ingredient.id)
+        .collect();
+
+    if ingredient_ids.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let mut recipes = recipe_repo.get_by_ingredients(&ingredient_ids).await?;
+    recipes.retain(|recipe| recipe.visibility.is_listed() || recipe.is_owned_by(Some(user_id)));
+
+    Ok(rank_recipes(recipes, &pantry, today))
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,82 @@
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::pantry::PantryItem,
+        queries::pantry::cookable::get_cookable_recipes,
+        repositories::{
+            ingredients::IngredientRepository,
+            pantry::{PantryRepository, PantryRepositoryService},
+            recipe::{RecipeRepository, RecipeRepositoryService},
+            user::UserRepository,
+        },
+    },
+    test_utils::{insert_pantry, pantry_item_fixture, recipe_fixture, user_fixture},
+};
+
+fn today() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2024, 10, 20).unwrap()
+}
+
+pub async fn getting_cookable_recipes_works(
+    pantry_repo: impl PantryRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let recipe = recipe_fixture();
+    let tofu = pantry_item_fixture();
+    let broccoli = PantryItem {
+        id: Uuid::from_u128(513),
+        ingredient: recipe.ingredients[1].ingredient.clone(),
+        amount: None,
+        expires_at: None,
+        ..tofu.clone()
+    };
+    insert_pantry(
+        &pantry_repo,
+        &ingredient_repo,
+        &user_repo,
+        &[tofu.clone(), broccoli],
+    )
+    .await;
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+    let pantry_repo: PantryRepositoryService = Arc::new(Box::new(pantry_repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let result = get_cookable_recipes(pantry_repo, recipe_repo, &user_fixture().id, &today())
+        .await
+        .unwrap();
+
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].recipe.id, recipe.id);
+    assert_eq!((result[0].covered, result[0].required), (2, 3));
+    assert_eq!(result[0].missing.len(), 1);
+    assert_eq!(
+        result[0].missing[0].ingredient,
+        recipe.ingredients[2].ingredient
+    );
+    assert_eq!(result[0].expiring, vec![tofu.ingredient]);
+}
+
+pub async fn getting_cookable_recipes_with_an_empty_pantry_works(
+    pantry_repo: impl PantryRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    insert_pantry(&pantry_repo, &ingredient_repo, &user_repo, &[]).await;
+    recipe_repo.insert(recipe_fixture()).await.unwrap();
+    let pantry_repo: PantryRepositoryService = Arc::new(Box::new(pantry_repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let result = get_cookable_recipes(pantry_repo, recipe_repo, &user_fixture().id, &today())
+        .await
+        .unwrap();
+
+    assert_eq!(result, vec![]);
+}
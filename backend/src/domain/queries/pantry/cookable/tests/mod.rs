@@ -0,0 +1,65 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::{
+        ingredients::in_memory::InMemoryIngredientRepository,
+        pantry::in_memory::InMemoryPantryRepository, recipe::in_memory::InMemoryRecipeRepository,
+        user::in_memory::InMemoryUserRepository,
+    };
+
+    #[tokio::test]
+    async fn getting_cookable_recipes_works() {
+        __tests__::getting_cookable_recipes_works(
+            InMemoryPantryRepository::new(),
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+            InMemoryUserRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn getting_cookable_recipes_with_an_empty_pantry_works() {
+        __tests__::getting_cookable_recipes_with_an_empty_pantry_works(
+            InMemoryPantryRepository::new(),
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+            InMemoryUserRepository::new(),
+        )
+        .await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        ingredients::postgres::PostgresIngredientRepository,
+        pantry::postgres::PostgresPantryRepository, recipe::postgres::PostgresRecipeRepository,
+        user::postgres::PostgresUserRepository,
+    };
+
+    #[sqlx::test]
+    async fn getting_cookable_recipes_works(pool: PgPool) {
+        __tests__::getting_cookable_recipes_works(
+            PostgresPantryRepository::new(pool.clone()),
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresUserRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn getting_cookable_recipes_with_an_empty_pantry_works(pool: PgPool) {
+        __tests__::getting_cookable_recipes_with_an_empty_pantry_works(
+            PostgresPantryRepository::new(pool.clone()),
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresUserRepository::new(pool),
+        )
+        .await
+    }
+}
@@ -0,0 +1,31 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::pantry::PantryItem,
+    repositories::pantry::{errors::GetPantryItemError, PantryRepositoryService},
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum GetPantryError {
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetPantryItemError> for GetPantryError {
+    fn from(value: GetPantryItemError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+#[tracing::instrument("[QUERY] Get all pantry items", skip(pantry_repo))]
+pub async fn get_pantry(
+    pantry_repo: PantryRepositoryService,
+    user_id: &Uuid,
+) -> Result<Vec<PantryItem>, GetPantryError> {
+    let result = pantry_repo.get_all(user_id).await?;
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests;
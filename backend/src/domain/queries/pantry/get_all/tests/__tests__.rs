@@ -0,0 +1,67 @@
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::pantry::PantryItem,
+        queries::pantry::get_all::get_pantry,
+        repositories::{
+            ingredients::IngredientRepository,
+            pantry::{PantryRepository, PantryRepositoryService},
+            user::UserRepository,
+        },
+    },
+    test_utils::{
+        another_user_fixture, insert_pantry, pantry_item_fixture, recipe_fixture, user_fixture,
+    },
+};
+
+pub async fn getting_the_pantry_works(
+    pantry_repo: impl PantryRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let ingredients = recipe_fixture().ingredients;
+    let tofu = pantry_item_fixture();
+    let broccoli = PantryItem {
+        id: Uuid::from_u128(513),
+        ingredient: ingredients[1].ingredient.clone(),
+        amount: None,
+        expires_at: None,
+        ..tofu.clone()
+    };
+    let garlic = PantryItem {
+        id: Uuid::from_u128(514),
+        ingredient: ingredients[2].ingredient.clone(),
+        expires_at: NaiveDate::from_ymd_opt(2024, 10, 21),
+        ..tofu.clone()
+    };
+    let someone_elses = PantryItem {
+        id: Uuid::from_u128(515),
+        user_id: another_user_fixture().id,
+        ..tofu.clone()
+    };
+    insert_pantry(
+        &pantry_repo,
+        &ingredient_repo,
+        &user_repo,
+        &[
+            broccoli.clone(),
+            tofu.clone(),
+            garlic.clone(),
+            someone_elses,
+        ],
+    )
+    .await;
+    let pantry_repo: PantryRepositoryService = Arc::new(Box::new(pantry_repo));
+
+    let result = get_pantry(pantry_repo, &user_fixture().id).await.unwrap();
+
+    assert_eq!(
+        result.iter().map(|item| item.id).collect::<Vec<_>>(),
+        vec![garlic.id, tofu.id, broccoli.id]
+    );
+}
@@ -0,0 +1,2 @@
+pub mod cookable;
+pub mod get_all;
@@ -0,0 +1,38 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::recipe_note::RecipeNote,
+    repositories::recipe_note::{errors::GetRecipeNoteError, RecipeNoteRepositoryService},
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum GetRecipeNoteByRecipeError {
+    #[error("The recipe with the ID {0} has no notes")]
+    NotFound(Uuid),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetRecipeNoteError> for GetRecipeNoteByRecipeError {
+    fn from(value: GetRecipeNoteError) -> Self {
+        match value {
+            GetRecipeNoteError::NotFound(id) => Self::NotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+#[tracing::instrument("[QUERY] Get notes on a recipe", skip(note_repo))]
+pub async fn get_recipe_note(
+    note_repo: RecipeNoteRepositoryService,
+    user_id: &Uuid,
+    recipe_id: &Uuid,
+) -> Result<RecipeNote, GetRecipeNoteByRecipeError> {
+    let result = note_repo.get(user_id, recipe_id).await?;
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        queries::recipe_notes::get_by_recipe::{get_recipe_note, GetRecipeNoteByRecipeError},
+        repositories::{
+            ingredients::IngredientRepository,
+            recipe::RecipeRepository,
+            recipe_note::{RecipeNoteRepository, RecipeNoteRepositoryService},
+            user::UserRepository,
+        },
+    },
+    test_utils::{
+        another_user_fixture, insert_all_ingredients_of_recipe, insert_users, recipe_fixture,
+        recipe_note_fixture,
+    },
+};
+
+pub async fn getting_notes_works(
+    note_repo: impl RecipeNoteRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let recipe = recipe_fixture();
+    insert_users(&user_repo).await;
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+    note_repo.save(&recipe_note_fixture()).await.unwrap();
+    let note_repo: RecipeNoteRepositoryService = Arc::new(Box::new(note_repo));
+
+    let user_id = recipe_note_fixture().user_id;
+
+    let result = get_recipe_note(note_repo.clone(), &user_id, &recipe.id)
+        .await
+        .unwrap();
+
+    assert_eq!(result, recipe_note_fixture());
+
+    let missing = Uuid::from_u128(404);
+    let error = get_recipe_note(note_repo.clone(), &user_id, &missing)
+        .await
+        .unwrap_err();
+
+    match error {
+        GetRecipeNoteByRecipeError::NotFound(id) => assert_eq!(id, missing),
+        e => panic!("Wrong error returned: {e}"),
+    }
+
+    let error = get_recipe_note(note_repo, &another_user_fixture().id, &recipe.id)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, GetRecipeNoteByRecipeError::NotFound(_)));
+}
@@ -0,0 +1,260 @@
+//! Renders recipes as an EPUB 3 book, with an EPUB 2 table of contents for older readers
+
+use std::io::{Cursor, Write};
+
+use chrono::{DateTime, Utc};
+use strum::VariantNames;
+use uuid::Uuid;
+use zip::{result::ZipResult, write::FileOptions, CompressionMethod, ZipWriter};
+
+use crate::domain::{
+    entities::{ingredient::types::DietViolations, recipe::Recipe},
+    queries::recipes::export::format_duration,
+};
+
+const STYLESHEET: &str = "body { font-family: serif; line-height: 1.4; }
+h1 { margin-bottom: 0.25em; }
+ul.badges { list-style: none; padding: 0; }
+ul.badges li { display: inline-block; margin-right: 0.5em; padding: 0 0.4em; border: 1px solid #2e7d32; border-radius: 0.3em; color: #2e7d32; font-size: 0.8em; }
+dl.facts dt { font-weight: bold; }
+ol.steps li { margin-bottom: 0.5em; }
+";
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+fn chapter_file(index: usize) -> String {
+    format!("recipe-{:03}.xhtml", index + 1)
+}
+
+fn chapter_id(index: usize) -> String {
+    format!("recipe-{:03}", index + 1)
+}
+
+/// `gluten_free` becomes `Gluten free`
+fn diet_label(diet: &str) -> String {
+    let label = diet.replace('_', " ");
+    let mut chars = label.chars();
+
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => label,
+    }
+}
+
+/// Diets that none of the ingredients of the recipe violate
+fn diet_badges(recipe: &Recipe) -> Vec<String> {
+    let violations = recipe.get_diet_violations();
+
+    DietViolations::VARIANTS
+        .iter()
+        .filter(|diet| !violations.iter().any(|violation| violation == *diet))
+        .map(|diet| diet_label(diet))
+        .collect()
+}
+
+fn xhtml(title: &str, body: &str) -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE html>
+<html xmlns="http://www.w3.org/1999/xhtml" xmlns:epub="http://www.idpf.org/2007/ops" xml:lang="en" lang="en">
+<head>
+<meta charset="UTF-8"/>
+<title>{}</title>
+<link rel="stylesheet" type="text/css" href="style.css"/>
+</head>
+<body>
+{}</body>
+</html>
+"#,
+        escape(title),
+        body
+    )
+}
+
+fn render_chapter(recipe: &Recipe) -> String {
+    let mut body = format!(
+        "<section epub:type=\"chapter\">\n<h1>{}</h1>\n",
+        escape(&recipe.name)
+    );
+
+    let badges = diet_badges(recipe);
+    if !badges.is_empty() {
+        body.push_str("<ul class=\"badges\">\n");
+        for badge in badges {
+            body.push_str(&format!("<li>{}</li>\n", escape(&badge)));
+        }
+        body.push_str("</ul>\n");
+    }
+
+    if !recipe.description.is_empty() {
+        body.push_str(&format!("<p>{}</p>\n", escape(&recipe.description)));
+    }
+
+    body.push_str("<dl class=\"facts\">\n");
+    body.push_str(&format!(
+        "<dt>Servings</dt>\n<dd>{}</dd>\n",
+        escape(&recipe.servings.to_string())
+    ));
+    for (name, duration) in &recipe.time {
+        body.push_str(&format!(
+            "<dt>{}</dt>\n<dd>{}</dd>\n",
+            escape(name),
+            format_duration(duration)
+        ));
+    }
+    body.push_str("</dl>\n");
+
+    body.push_str("<h2>Ingredients</h2>\n<ul class=\"ingredients\">\n");
+    for ingredient in recipe.ingredients.iter() {
+        body.push_str(&format!("<li>{}</li>\n", escape(&ingredient.to_string())));
+    }
+    body.push_str("</ul>\n");
+
+    body.push_str("<h2>Steps</h2>\n<ol class=\"steps\">\n");
+    for step in recipe.steps.as_ref().iter() {
+        body.push_str(&format!("<li>{}</li>\n", escape(step)));
+    }
+    body.push_str("</ol>\n</section>\n");
+
+    xhtml(&recipe.name, &body)
+}
+
+fn render_nav(title: &str, recipes: &[Recipe]) -> String {
+    let mut body = format!(
+        "<nav epub:type=\"toc\" id=\"toc\">\n<h1>{}</h1>\n<ol>\n",
+        escape(title)
+    );
+    for (index, recipe) in recipes.iter().enumerate() {
+        body.push_str(&format!(
+            "<li><a href=\"{}\">{}</a></li>\n",
+            chapter_file(index),
+            escape(&recipe.name)
+        ));
+    }
+    body.push_str("</ol>\n</nav>\n");
+
+    xhtml(title, &body)
+}
+
+fn render_ncx(title: &str, id: &Uuid, recipes: &[Recipe]) -> String {
+    let mut nav_points = String::new();
+    for (index, recipe) in recipes.iter().enumerate() {
+        nav_points.push_str(&format!(
+            "<navPoint id=\"{}\" playOrder=\"{}\">\n<navLabel><text>{}</text></navLabel>\n<content src=\"{}\"/>\n</navPoint>\n",
+            chapter_id(index),
+            index + 1,
+            escape(&recipe.name),
+            chapter_file(index)
+        ));
+    }
+
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<ncx xmlns="http://www.daisy.org/z3986/2005/ncx/" version="2005-1">
+<head>
+<meta name="dtb:uid" content="urn:uuid:{}"/>
+</head>
+<docTitle><text>{}</text></docTitle>
+<navMap>
+{}</navMap>
+</ncx>
+"#,
+        id,
+        escape(title),
+        nav_points
+    )
+}
+
+fn render_package(title: &str, id: &Uuid, modified: &DateTime<Utc>, recipes: &[Recipe]) -> String {
+    let mut manifest = String::new();
+    let mut spine = String::new();
+    for index in 0..recipes.len() {
+        manifest.push_str(&format!(
+            "<item id=\"{}\" href=\"{}\" media-type=\"application/xhtml+xml\"/>\n",
+            chapter_id(index),
+            chapter_file(index)
+        ));
+        spine.push_str(&format!("<itemref idref=\"{}\"/>\n", chapter_id(index)));
+    }
+
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<package xmlns="http://www.idpf.org/2007/opf" version="3.0" unique-identifier="book-id" xml:lang="en">
+<metadata xmlns:dc="http://purl.org/dc/elements/1.1/">
+<dc:identifier id="book-id">urn:uuid:{}</dc:identifier>
+<dc:title>{}</dc:title>
+<dc:language>en</dc:language>
+<meta property="dcterms:modified">{}</meta>
+</metadata>
+<manifest>
+<item id="nav" href="nav.xhtml" media-type="application/xhtml+xml" properties="nav"/>
+<item id="ncx" href="toc.ncx" media-type="application/x-dtbncx+xml"/>
+<item id="style" href="style.css" media-type="text/css"/>
+{}</manifest>
+<spine toc="ncx">
+<itemref idref="nav"/>
+{}</spine>
+</package>
+"#,
+        id,
+        escape(title),
+        modified.format("%Y-%m-%dT%H:%M:%SZ"),
+        manifest,
+        spine
+    )
+}
+
+const CONTAINER: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<container version="1.0" xmlns="urn:oasis:names:tc:opendocument:xmlns:container">
+<rootfiles>
+<rootfile full-path="OEBPS/content.opf" media-type="application/oebps-package+xml"/>
+</rootfiles>
+</container>
+"#;
+
+pub fn render(
+    title: &str,
+    id: &Uuid,
+    modified: &DateTime<Utc>,
+    recipes: &[Recipe],
+) -> ZipResult<Vec<u8>> {
+    let mut files: Vec<(String, String)> = vec![
+        ("META-INF/container.xml".to_string(), CONTAINER.to_string()),
+        (
+            "OEBPS/content.opf".to_string(),
+            render_package(title, id, modified, recipes),
+        ),
+        ("OEBPS/nav.xhtml".to_string(), render_nav(title, recipes)),
+        ("OEBPS/toc.ncx".to_string(), render_ncx(title, id, recipes)),
+        ("OEBPS/style.css".to_string(), STYLESHEET.to_string()),
+    ];
+    for (index, recipe) in recipes.iter().enumerate() {
+        files.push((
+            format!("OEBPS/{}", chapter_file(index)),
+            render_chapter(recipe),
+        ));
+    }
+
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+
+    // The mimetype has to be the first file in the archive, and it can't be compressed
+    zip.start_file(
+        "mimetype",
+        FileOptions::default().compression_method(CompressionMethod::Stored),
+    )?;
+    zip.write_all(b"application/epub+zip")?;
+
+    let options = FileOptions::default().compression_method(CompressionMethod::Deflated);
+    for (path, content) in files {
+        zip.start_file(path, options)?;
+        zip.write_all(content.as_bytes())?;
+    }
+
+    Ok(zip.finish()?.into_inner())
+}
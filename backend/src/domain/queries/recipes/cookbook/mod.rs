@@ -0,0 +1,177 @@
+mod epub;
+
+use chrono::{DateTime, Utc};
+use common::ExportCookbookDTO;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        ingredient::types::DietViolations,
+        recipe::{errors::ValidationError, Recipe},
+    },
+    repositories::{
+        collection::{errors::GetCollectionError, CollectionRepositoryService},
+        recipe::{errors::GetRecipeByIdError, RecipeRepositoryService},
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum ExportCookbookError {
+    #[error("Could not find the recipe with the ID {0}")]
+    RecipeNotFound(Uuid),
+
+    #[error("Could not find the collection with the ID {0}")]
+    CollectionNotFound(Uuid),
+
+    #[error("None of the chosen recipes match the filters")]
+    NoRecipes,
+
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetRecipeByIdError> for ExportCookbookError {
+    fn from(value: GetRecipeByIdError) -> Self {
+        match value {
+            GetRecipeByIdError::NotFound(id) => Self::RecipeNotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<GetCollectionError> for ExportCookbookError {
+    fn from(value: GetCollectionError) -> Self {
+        match value {
+            GetCollectionError::NotFound(id) => Self::CollectionNotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ExportCookbook {
+    pub title: Option<String>,
+    pub recipe_ids: Vec<Uuid>,
+    /// The recipes of the collection go first, followed by the ones picked by their IDs
+    pub collection_id: Option<Uuid>,
+    /// Recipes that violate any of these diets are left out
+    pub diets: Vec<DietViolations>,
+}
+
+impl TryFrom<ExportCookbookDTO> for ExportCookbook {
+    type Error = ValidationError;
+    fn try_from(value: ExportCookbookDTO) -> Result<Self, Self::Error> {
+        Ok(Self {
+            title: value.title,
+            recipe_ids: value.recipe_ids,
+            collection_id: value.collection_id,
+            diets: value
+                .diets
+                .into_iter()
+                .map(DietViolations::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Cookbook {
+    pub title: String,
+    /// The EPUB file itself
+    pub content: Vec<u8>,
+}
+
+impl Cookbook {
+    /// ```rust
+    /// use backend::domain::queries::recipes::cookbook::Cookbook;
+    ///
+    /// let cookbook = Cookbook { title: "Christmas 2026: Mains!".to_string(), content: vec![] };
+    /// assert_eq!(cookbook.file_name(), "christmas-2026-mains.epub");
+    /// ```
+    pub fn file_name(&self) -> String {
+        let slug = self
+            .title
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .map(|word| word.to_ascii_lowercase())
+            .collect::<Vec<_>>()
+            .join("-");
+
+        if slug.is_empty() {
+            "cookbook.epub".to_string()
+        } else {
+            format!("{}.epub", slug)
+        }
+    }
+}
+
+#[tracing::instrument("[QUERY] Exporting a cookbook", skip(recipe_repo, collection_repo))]
+pub async fn export_cookbook(
+    recipe_repo: RecipeRepositoryService,
+    collection_repo: CollectionRepositoryService,
+    user_id: Option<&Uuid>,
+    input: &ExportCookbook,
+    now: &DateTime<Utc>,
+) -> Result<Cookbook, ExportCookbookError> {
+    if input.recipe_ids.is_empty() && input.collection_id.is_none() {
+        return Err(ValidationError::EmptyField(vec!["recipe_ids", "collection_id"]).into());
+    }
+
+    let collection = match &input.collection_id {
+        Some(id) => Some(collection_repo.get_by_id(id).await?),
+        None => None,
+    };
+
+    let mut recipe_ids: Vec<Uuid> = collection
+        .as_ref()
+        .map(|collection| collection.recipes.clone())
+        .unwrap_or_default();
+    for id in &input.recipe_ids {
+        if !recipe_ids.contains(id) {
+            recipe_ids.push(*id);
+        }
+    }
+
+    let excluded: Vec<String> = input.diets.iter().map(|diet| diet.to_string()).collect();
+    let mut recipes: Vec<Recipe> = vec![];
+    for id in &recipe_ids {
+        let recipe = match recipe_repo.get_visible(user_id, id, None).await {
+            Ok(recipe) => recipe,
+            // Recipes in the collection can belong to somebody else, who may have hidden them since
+            Err(GetRecipeByIdError::NotFound(_)) if !input.recipe_ids.contains(id) => continue,
+            Err(e) => return Err(e.into()),
+        };
+
+        if !recipe
+            .get_diet_violations()
+            .iter()
+            .any(|violation| excluded.contains(violation))
+        {
+            recipes.push(recipe);
+        }
+    }
+
+    if recipes.is_empty() {
+        return Err(ExportCookbookError::NoRecipes);
+    }
+
+    let title = input
+        .title
+        .as_deref()
+        .map(str::trim)
+        .filter(|title| !title.is_empty())
+        .map(str::to_string)
+        .or(collection.map(|collection| collection.name))
+        .unwrap_or_else(|| "Cookbook".to_string());
+
+    let content = epub::render(&title, &Uuid::now_v7(), now, &recipes)
+        .map_err(|e| ExportCookbookError::Unknown(e.into()))?;
+
+    Ok(Cookbook { title, content })
+}
+
+#[cfg(test)]
+mod tests;
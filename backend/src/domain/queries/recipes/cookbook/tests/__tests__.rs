@@ -0,0 +1,240 @@
+use std::{
+    io::{Cursor, Read},
+    sync::Arc,
+};
+
+use chrono::{DateTime, Utc};
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+use zip::{CompressionMethod, ZipArchive};
+
+use crate::{
+    domain::{
+        entities::{
+            ingredient::{types::DietViolations, Ingredient},
+            recipe::{IngredientUnit, IngredientWithAmount, Recipe},
+        },
+        queries::recipes::cookbook::{export_cookbook, ExportCookbook, ExportCookbookError},
+        repositories::{
+            collection::{CollectionRepository, CollectionRepositoryService},
+            ingredients::IngredientRepository,
+            recipe::{RecipeRepository, RecipeRepositoryService},
+        },
+    },
+    test_utils::{
+        another_recipe_fixture, collection_fixture, insert_all_ingredients_of_recipe,
+        recipe_fixture,
+    },
+};
+
+fn now() -> DateTime<Utc> {
+    DateTime::<Utc>::from_timestamp_nanos(1_729_500_000_000_000_000)
+}
+
+/// A recipe that is fine for every diet
+fn rice_recipe() -> Recipe {
+    Recipe {
+        name: "Rice & <Beans>".to_string(),
+        ingredients: vec![IngredientWithAmount {
+            id: Uuid::now_v7(),
+            ingredient: Ingredient {
+                id: Uuid::from_u128(3),
+                name: "Rice".try_into().unwrap(),
+                description: "Plain white rice".try_into().unwrap(),
+                diet_violations: Vec::<DietViolations>::new().into(),
+                updated_at: Default::default(),
+            },
+            amount: IngredientUnit::Grams(200.0),
+            notes: Some("rinsed".to_string()),
+            optional: false,
+            group: None,
+        }]
+        .try_into()
+        .unwrap(),
+        ..another_recipe_fixture(1)
+    }
+}
+
+async fn insert_recipes(
+    recipe_repo: &impl RecipeRepository,
+    ingredient_repo: &impl IngredientRepository,
+) {
+    for recipe in [recipe_fixture(), rice_recipe()] {
+        insert_all_ingredients_of_recipe(ingredient_repo, &recipe).await;
+        recipe_repo.insert(recipe).await.unwrap();
+    }
+}
+
+fn read_file(archive: &mut ZipArchive<Cursor<Vec<u8>>>, name: &str) -> String {
+    let mut result = String::new();
+    archive
+        .by_name(name)
+        .unwrap()
+        .read_to_string(&mut result)
+        .unwrap();
+
+    result
+}
+
+pub async fn exporting_a_cookbook_works(
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    collection_repo: impl CollectionRepository,
+) {
+    insert_recipes(&recipe_repo, &ingredient_repo).await;
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+    let collection_repo: CollectionRepositoryService = Arc::new(Box::new(collection_repo));
+
+    let input = ExportCookbook {
+        title: Some("Family favourites".to_string()),
+        recipe_ids: vec![recipe_fixture().id, rice_recipe().id],
+        ..Default::default()
+    };
+
+    let result = export_cookbook(recipe_repo, collection_repo, None, &input, &now())
+        .await
+        .unwrap();
+
+    assert_eq!(result.title, "Family favourites");
+
+    let mut archive = ZipArchive::new(Cursor::new(result.content)).unwrap();
+
+    {
+        let mimetype = archive.by_index(0).unwrap();
+        assert_eq!(mimetype.name(), "mimetype");
+        assert_eq!(mimetype.compression(), CompressionMethod::Stored);
+    }
+    assert_eq!(read_file(&mut archive, "mimetype"), "application/epub+zip");
+    assert!(read_file(&mut archive, "META-INF/container.xml").contains("OEBPS/content.opf"));
+
+    let package = read_file(&mut archive, "OEBPS/content.opf");
+    assert!(package.contains("<dc:title>Family favourites</dc:title>"));
+    assert!(package.contains("<meta property=\"dcterms:modified\">2024-10-21T08:40:00Z</meta>"));
+    assert!(package.contains("<itemref idref=\"recipe-002\"/>"));
+
+    let nav = read_file(&mut archive, "OEBPS/nav.xhtml");
+    assert!(nav.contains("<a href=\"recipe-001.xhtml\">Hoisin Tofu and Broccoli</a>"));
+    assert!(nav.contains("<a href=\"recipe-002.xhtml\">Rice &amp; &lt;Beans&gt;</a>"));
+
+    let tofu = read_file(&mut archive, "OEBPS/recipe-001.xhtml");
+    assert!(tofu.contains("<li>400 g Firm tofu</li>"));
+    assert!(tofu.contains("<li>4 clove Garlic</li>"));
+    assert!(tofu.contains("<dd>15 min</dd>"));
+    assert!(tofu.contains("<dd>4 servings</dd>"));
+    assert!(!tofu.contains("class=\"badges\""));
+    assert_eq!(
+        tofu.split("<ol class=\"steps\">")
+            .nth(1)
+            .unwrap()
+            .matches("<li>")
+            .count(),
+        recipe_fixture().steps.as_ref().len()
+    );
+
+    let rice = read_file(&mut archive, "OEBPS/recipe-002.xhtml");
+    assert!(rice.contains("<h1>Rice &amp; &lt;Beans&gt;</h1>"));
+    assert!(rice.contains("<li>200 g Rice, rinsed</li>"));
+    assert!(rice.contains(
+        "<ul class=\"badges\">\n<li>Vegan</li>\n<li>Vegetarian</li>\n<li>Gluten free</li>\n</ul>"
+    ));
+}
+
+pub async fn exporting_a_collection_filtered_by_diet_works(
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    collection_repo: impl CollectionRepository,
+) {
+    insert_recipes(&recipe_repo, &ingredient_repo).await;
+    let collection = collection_fixture();
+    collection_repo.insert(collection.clone()).await.unwrap();
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+    let collection_repo: CollectionRepositoryService = Arc::new(Box::new(collection_repo));
+
+    let input = ExportCookbook {
+        collection_id: Some(collection.id),
+        recipe_ids: vec![rice_recipe().id],
+        diets: vec![DietViolations::Vegan],
+        ..Default::default()
+    };
+
+    let result = export_cookbook(
+        recipe_repo.clone(),
+        collection_repo.clone(),
+        None,
+        &input,
+        &now(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(result.title, collection.name);
+
+    let mut archive = ZipArchive::new(Cursor::new(result.content)).unwrap();
+    let nav = read_file(&mut archive, "OEBPS/nav.xhtml");
+
+    assert!(nav.contains("Rice &amp; &lt;Beans&gt;"));
+    assert!(!nav.contains("Hoisin Tofu and Broccoli"));
+    assert!(archive.by_name("OEBPS/recipe-002.xhtml").is_err());
+
+    let input = ExportCookbook {
+        recipe_ids: vec![],
+        ..input
+    };
+
+    let error = export_cookbook(recipe_repo, collection_repo, None, &input, &now())
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, ExportCookbookError::NoRecipes));
+}
+
+pub async fn exporting_a_cookbook_of_nonexistent_recipes_errors(
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    collection_repo: impl CollectionRepository,
+) {
+    insert_recipes(&recipe_repo, &ingredient_repo).await;
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+    let collection_repo: CollectionRepositoryService = Arc::new(Box::new(collection_repo));
+    let missing = Uuid::now_v7();
+
+    let error = export_cookbook(
+        recipe_repo.clone(),
+        collection_repo.clone(),
+        None,
+        &ExportCookbook::default(),
+        &now(),
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(error, ExportCookbookError::ValidationError(_)));
+
+    let input = ExportCookbook {
+        recipe_ids: vec![recipe_fixture().id, missing],
+        ..Default::default()
+    };
+
+    let error = export_cookbook(
+        recipe_repo.clone(),
+        collection_repo.clone(),
+        None,
+        &input,
+        &now(),
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(error, ExportCookbookError::RecipeNotFound(id) if id == missing));
+
+    let input = ExportCookbook {
+        collection_id: Some(missing),
+        ..Default::default()
+    };
+
+    let error = export_cookbook(recipe_repo, collection_repo, None, &input, &now())
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, ExportCookbookError::CollectionNotFound(id) if id == missing));
+}
@@ -0,0 +1,81 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::{
+        collection::in_memory::InMemoryCollectionRepository,
+        ingredients::in_memory::InMemoryIngredientRepository,
+        recipe::in_memory::InMemoryRecipeRepository,
+    };
+
+    #[tokio::test]
+    async fn exporting_a_cookbook_works() {
+        __tests__::exporting_a_cookbook_works(
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+            InMemoryCollectionRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn exporting_a_collection_filtered_by_diet_works() {
+        __tests__::exporting_a_collection_filtered_by_diet_works(
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+            InMemoryCollectionRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn exporting_a_cookbook_of_nonexistent_recipes_errors() {
+        __tests__::exporting_a_cookbook_of_nonexistent_recipes_errors(
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+            InMemoryCollectionRepository::new(),
+        )
+        .await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        collection::postgres::PostgresCollectionRepository,
+        ingredients::postgres::PostgresIngredientRepository,
+        recipe::postgres::PostgresRecipeRepository,
+    };
+
+    #[sqlx::test]
+    async fn exporting_a_cookbook_works(pool: PgPool) {
+        __tests__::exporting_a_cookbook_works(
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresCollectionRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn exporting_a_collection_filtered_by_diet_works(pool: PgPool) {
+        __tests__::exporting_a_collection_filtered_by_diet_works(
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresCollectionRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn exporting_a_cookbook_of_nonexistent_recipes_errors(pool: PgPool) {
+        __tests__::exporting_a_cookbook_of_nonexistent_recipes_errors(
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresCollectionRepository::new(pool),
+        )
+        .await
+    }
+}
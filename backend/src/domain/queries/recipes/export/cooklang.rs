@@ -0,0 +1,145 @@
+use regex::Regex;
+
+use crate::domain::entities::recipe::{IngredientUnit, IngredientWithAmount, Recipe, ServingsType};
+
+use super::format_duration;
+
+/// Escapes the characters that Cooklang would otherwise treat as markup
+fn escape(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        let starts_comment = matches!(c, '-' | '[') && chars.peek() == Some(&'-');
+        if starts_comment || matches!(c, '@' | '#' | '~' | '{' | '}' | '\\') {
+            result.push('\\');
+        }
+        result.push(c);
+    }
+
+    if result.starts_with(">>") {
+        result.insert(0, '\\');
+    }
+
+    result
+}
+
+/// Metadata values have to fit on a single line
+fn metadata_value(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Writes the ingredient as `@name{amount%unit}(notes)`, using `name` as it is written in the step
+fn annotate(name: &str, ingredient: &IngredientWithAmount, hidden: bool) -> String {
+    let mut modifiers = String::new();
+    if hidden {
+        modifiers.push('-');
+    }
+    if ingredient.optional {
+        modifiers.push('?');
+    }
+
+    // The amount is displayed as `400 g`, and Cooklang separates the unit with a `%`.
+    // Amounts like `to taste` have no unit to separate.
+    let amount = match &ingredient.amount {
+        IngredientUnit::Unquantified(amount) => amount.clone(),
+        amount => amount.to_string().replacen(' ', "%", 1),
+    };
+
+    let mut result = format!("@{}{}{{{}}}", modifiers, name, amount);
+    if let Some(notes) = ingredient.notes.as_ref().filter(|n| !n.is_empty()) {
+        result.push_str(&format!("({})", notes));
+    }
+
+    result
+}
+
+/// Renders the recipe as Cooklang. Ingredients are annotated where they are first mentioned in the steps,
+/// and ones that are never mentioned are added to the first step as hidden ingredients.
+pub fn render(recipe: &Recipe) -> String {
+    let mut result = format!(">> title: {}\n", metadata_value(&recipe.name));
+
+    if !recipe.description.is_empty() {
+        result.push_str(&format!(
+            ">> description: {}\n",
+            metadata_value(&recipe.description)
+        ));
+    }
+
+    let servings = match recipe.servings {
+        ServingsType::Exact(amount) => amount.to_string(),
+        ServingsType::FromTo(from, to) => format!("{}-{}", from, to),
+    };
+    result.push_str(&format!(">> servings: {}\n", servings));
+
+    for (name, duration) in &recipe.time {
+        result.push_str(&format!(
+            ">> {}: {}\n",
+            metadata_value(name),
+            format_duration(duration)
+        ));
+    }
+
+    let steps: &[String] = recipe.steps.as_ref();
+    // The positions in every step where an ingredient is mentioned, in the order they appear in
+    let mut mentions: Vec<Vec<(usize, usize, &IngredientWithAmount)>> = vec![vec![]; steps.len()];
+    let mut hidden = vec![];
+
+    for ingredient in recipe.ingredients.iter() {
+        let pattern = format!(
+            r"(?i)\b{}\b",
+            regex::escape(ingredient.ingredient.name.as_ref())
+        );
+        let Ok(regex) = Regex::new(&pattern) else {
+            hidden.push(ingredient);
+            continue;
+        };
+
+        let mention = steps.iter().enumerate().find_map(|(i, step)| {
+            regex
+                .find_iter(step)
+                .find(|m| {
+                    !mentions[i]
+                        .iter()
+                        .any(|(start, end, _)| m.start() < *end && *start < m.end())
+                })
+                .map(|m| (i, m.start(), m.end()))
+        });
+
+        match mention {
+            Some((i, start, end)) => mentions[i].push((start, end, ingredient)),
+            None => hidden.push(ingredient),
+        }
+    }
+
+    for (i, step) in steps.iter().enumerate() {
+        let mut step_mentions = std::mem::take(&mut mentions[i]);
+        step_mentions.sort_by_key(|(start, _, _)| *start);
+
+        let mut text = String::new();
+        let mut position = 0;
+        for (start, end, ingredient) in step_mentions {
+            text.push_str(&escape(&step[position..start]));
+            text.push_str(&annotate(&step[start..end], ingredient, false));
+            position = end;
+        }
+        text.push_str(&escape(&step[position..]));
+
+        if i == 0 {
+            for ingredient in &hidden {
+                text.push(' ');
+                text.push_str(&annotate(
+                    ingredient.ingredient.name.as_ref(),
+                    ingredient,
+                    true,
+                ));
+            }
+        }
+
+        result.push('\n');
+        result.push_str(&text);
+        result.push('\n');
+    }
+
+    result
+}
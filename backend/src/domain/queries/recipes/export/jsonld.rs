@@ -0,0 +1,120 @@
+use std::time::Duration;
+
+use serde_json::{json, Value};
+
+use crate::domain::{
+    commands::recipes::import::jsonld::{INGREDIENT_GROUP_PROPERTY, TIME_PROPERTIES},
+    entities::{ingredient::types::DietViolations, recipe::Recipe},
+};
+
+const SUITABLE_FOR_DIET: [(DietViolations, &str); 3] = [
+    (DietViolations::Vegan, "https://schema.org/VeganDiet"),
+    (
+        DietViolations::Vegetarian,
+        "https://schema.org/VegetarianDiet",
+    ),
+    (
+        DietViolations::GlutenFree,
+        "https://schema.org/GlutenFreeDiet",
+    ),
+];
+
+/// Formats the duration as ISO 8601, i.e. `PT1H30M`
+/// ```rust
+/// use std::time::Duration;
+/// use backend::domain::queries::recipes::export::format_iso_duration;
+///
+/// assert_eq!(format_iso_duration(&Duration::from_secs(90 * 60)), "PT1H30M");
+/// assert_eq!(format_iso_duration(&Duration::from_secs(0)), "PT0S");
+/// ```
+pub fn format_iso_duration(duration: &Duration) -> String {
+    let total = duration.as_secs();
+    let (hours, minutes, seconds) = (total / 3600, (total % 3600) / 60, total % 60);
+
+    let mut result = "PT".to_string();
+    if hours > 0 {
+        result.push_str(&format!("{}H", hours));
+    }
+    if minutes > 0 {
+        result.push_str(&format!("{}M", minutes));
+    }
+    if seconds > 0 || total == 0 {
+        result.push_str(&format!("{}S", seconds));
+    }
+
+    result
+}
+
+/// Renders the recipe as a schema.org `Recipe`.
+/// Times that do not map onto schema.org properties and ingredient groups, which schema.org
+/// has no notion of, are kept in `additionalProperty`, so that the importer can restore them.
+pub fn render(recipe: &Recipe) -> Value {
+    let diet_violations = recipe.get_diet_violations();
+
+    let mut document = json!({
+        "@context": "https://schema.org",
+        "@type": "Recipe",
+        "identifier": recipe.id,
+        "name": recipe.name,
+        "description": recipe.description,
+        "recipeYield": recipe.servings.to_string(),
+        "recipeIngredient": recipe
+            .ingredients
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>(),
+        "recipeInstructions": recipe
+            .steps
+            .as_ref()
+            .iter()
+            .map(|step| json!({ "@type": "HowToStep", "text": step }))
+            .collect::<Vec<_>>(),
+        "suitableForDiet": SUITABLE_FOR_DIET
+            .iter()
+            .filter(|(diet, _)| !diet_violations.contains(&diet.to_string()))
+            .map(|(_, url)| url)
+            .collect::<Vec<_>>(),
+        "dateCreated": recipe.created_at.to_rfc3339(),
+        "dateModified": recipe.updated_at.to_rfc3339(),
+    });
+
+    let mut additional_properties = vec![];
+
+    for (key, duration) in &recipe.time {
+        match TIME_PROPERTIES.iter().find(|(_, name)| name == key) {
+            Some((property, _)) => document[property] = json!(format_iso_duration(duration)),
+            None => additional_properties.push(json!({
+                "@type": "PropertyValue",
+                "name": key,
+                "value": format_iso_duration(duration),
+            })),
+        }
+    }
+
+    let mut groups: Vec<(&String, Vec<String>)> = vec![];
+    for ingredient in recipe.ingredients.iter() {
+        let Some(label) = &ingredient.group else {
+            continue;
+        };
+
+        match groups.iter_mut().find(|(group, _)| *group == label) {
+            Some((_, lines)) => lines.push(ingredient.to_string()),
+            None => groups.push((label, vec![ingredient.to_string()])),
+        }
+    }
+
+    additional_properties.extend(groups.into_iter().map(|(label, lines)| {
+        json!({
+            "@type": "PropertyValue",
+            "propertyID": INGREDIENT_GROUP_PROPERTY,
+            "name": label,
+            "value": lines,
+        })
+    }));
+
+    if !additional_properties.is_empty() {
+        document["additionalProperty"] = json!(additional_properties);
+    }
+
+    document
+}
@@ -0,0 +1,37 @@
+use crate::domain::entities::recipe::Recipe;
+
+use super::format_duration;
+
+pub fn render(recipe: &Recipe) -> String {
+    let mut result = format!("# {}\n\n", recipe.name);
+
+    if !recipe.description.is_empty() {
+        result.push_str(&format!("{}\n\n", recipe.description));
+    }
+
+    result.push_str(&format!("- **Servings:** {}\n", recipe.servings));
+    for (name, duration) in &recipe.time {
+        result.push_str(&format!("- **{}:** {}\n", name, format_duration(duration)));
+    }
+
+    result.push_str("\n## Ingredients\n\n");
+    let mut group = None;
+    for ingredient in recipe.ingredients.iter() {
+        // Ingredients of the same group are listed next to each other under a heading
+        if ingredient.group != group {
+            if let Some(label) = &ingredient.group {
+                result.push_str(&format!("\n### {}\n\n", label));
+            }
+            group = ingredient.group.clone();
+        }
+
+        result.push_str(&format!("- {}\n", ingredient));
+    }
+
+    result.push_str("\n## Steps\n\n");
+    for (i, step) in recipe.steps.as_ref().iter().enumerate() {
+        result.push_str(&format!("{}. {}\n", i + 1, step));
+    }
+
+    result
+}
@@ -0,0 +1,81 @@
+mod cooklang;
+mod jsonld;
+mod markdown;
+mod text;
+
+use std::time::Duration;
+
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::domain::repositories::recipe::{errors::GetRecipeByIdError, RecipeRepositoryService};
+
+pub use jsonld::format_iso_duration;
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum ExportRecipeError {
+    #[error("Could not found the recipe with the ID {0}")]
+    NotFound(Uuid),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetRecipeByIdError> for ExportRecipeError {
+    fn from(value: GetRecipeByIdError) -> Self {
+        match value {
+            GetRecipeByIdError::NotFound(id) => Self::NotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    #[default]
+    JsonLd,
+    Markdown,
+    Text,
+    Cooklang,
+}
+
+/// Formats a duration for people to read, i.e. `1 h 30 min`
+pub fn format_duration(duration: &Duration) -> String {
+    let total = duration.as_secs();
+    let (hours, minutes, seconds) = (total / 3600, (total % 3600) / 60, total % 60);
+
+    let parts: Vec<String> = [(hours, "h"), (minutes, "min"), (seconds, "s")]
+        .into_iter()
+        .filter(|(value, _)| *value > 0)
+        .map(|(value, unit)| format!("{} {}", value, unit))
+        .collect();
+
+    if parts.is_empty() {
+        "0 min".to_string()
+    } else {
+        parts.join(" ")
+    }
+}
+
+#[tracing::instrument("[QUERY] Exporting a recipe", skip(recipe_repo))]
+pub async fn export_recipe(
+    recipe_repo: RecipeRepositoryService,
+    user_id: Option<&Uuid>,
+    id: &Uuid,
+    format: ExportFormat,
+) -> Result<String, ExportRecipeError> {
+    let recipe = recipe_repo.get_visible(user_id, id, None).await?;
+
+    let result = match format {
+        ExportFormat::JsonLd => format!("{:#}", jsonld::render(&recipe)),
+        ExportFormat::Markdown => markdown::render(&recipe),
+        ExportFormat::Text => text::render(&recipe),
+        ExportFormat::Cooklang => cooklang::render(&recipe),
+    };
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests;
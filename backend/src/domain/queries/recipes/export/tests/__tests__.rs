@@ -0,0 +1,208 @@
+use std::{sync::Arc, time::Duration};
+
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        commands::recipes::import::{cooklang::parse_cooklang, jsonld::parse_jsonld},
+        entities::{
+            ingredient::Ingredient,
+            recipe::{IngredientWithAmount, Recipe},
+        },
+        queries::recipes::export::{export_recipe, ExportFormat, ExportRecipeError},
+        repositories::{
+            ingredients::IngredientRepository,
+            recipe::{RecipeRepository, RecipeRepositoryService},
+        },
+    },
+    test_utils::{insert_all_ingredients_of_recipe, recipe_fixture},
+};
+
+fn exported_recipe() -> Recipe {
+    let mut recipe = recipe_fixture();
+    recipe
+        .time
+        .insert("Marinating time".to_string(), Duration::from_secs(30 * 60));
+
+    let ingredients: Vec<IngredientWithAmount> = recipe
+        .ingredients
+        .iter()
+        .cloned()
+        .map(
+            |ingredient| match ingredient.ingredient.name.as_ref().as_str() {
+                "Garlic" => IngredientWithAmount {
+                    notes: Some("minced".to_string()),
+                    group: Some("For the sauce".to_string()),
+                    ..ingredient
+                },
+                "Broccoli" => IngredientWithAmount {
+                    optional: true,
+                    ..ingredient
+                },
+                _ => ingredient,
+            },
+        )
+        .collect();
+    recipe.ingredients = ingredients.try_into().unwrap();
+
+    recipe
+}
+
+async fn insert_recipe(
+    repo: impl RecipeRepository,
+    ingredient_repo: &impl IngredientRepository,
+    recipe: &Recipe,
+) -> RecipeRepositoryService {
+    insert_all_ingredients_of_recipe(ingredient_repo, recipe).await;
+    repo.insert(recipe.clone()).await.unwrap();
+    Arc::new(Box::new(repo))
+}
+
+pub async fn exporting_recipe_as_jsonld_round_trips(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let recipe = exported_recipe();
+    let recipe_repo = insert_recipe(repo, &ingredient_repo, &recipe).await;
+
+    let document = export_recipe(recipe_repo, None, &recipe.id, ExportFormat::JsonLd)
+        .await
+        .unwrap();
+
+    let ingredients: Vec<Ingredient> = recipe
+        .ingredients
+        .iter()
+        .map(|i| i.ingredient.clone())
+        .collect();
+    let (input, unmatched) = parse_jsonld(&document)
+        .unwrap()
+        .into_create_recipe(&ingredients);
+
+    assert!(unmatched.is_empty());
+    assert_eq!(input.name, recipe.name);
+    assert_eq!(input.description, recipe.description);
+    assert_eq!(&input.steps, recipe.steps.as_ref());
+    assert_eq!(input.time, recipe.time);
+    assert_eq!(input.servings, recipe.servings);
+
+    let expected: Vec<_> = recipe
+        .ingredients
+        .iter()
+        .map(|i| {
+            (
+                i.ingredient.id,
+                i.amount.clone(),
+                i.notes.clone(),
+                i.optional,
+                i.group.clone(),
+            )
+        })
+        .collect();
+    let result: Vec<_> = input
+        .ingredients
+        .into_iter()
+        .map(|i| (i.ingredient_id, i.amount, i.notes, i.optional, i.group))
+        .collect();
+
+    assert_eq!(result, expected);
+}
+
+pub async fn exporting_recipe_as_cooklang_round_trips(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let recipe = exported_recipe();
+    let recipe_repo = insert_recipe(repo, &ingredient_repo, &recipe).await;
+
+    let document = export_recipe(recipe_repo, None, &recipe.id, ExportFormat::Cooklang)
+        .await
+        .unwrap();
+
+    assert!(document.contains("@?broccoli{1%head}"));
+    assert!(document.contains("@garlic{4%clove}(minced)"));
+    assert!(document.contains("@-Firm tofu{400%g}"));
+
+    let ingredients: Vec<Ingredient> = recipe
+        .ingredients
+        .iter()
+        .map(|i| i.ingredient.clone())
+        .collect();
+    let (input, unmatched) = parse_cooklang(&document, None)
+        .unwrap()
+        .into_create_recipe(&ingredients);
+
+    assert!(unmatched.is_empty());
+    assert_eq!(input.name, recipe.name);
+    assert_eq!(input.description, recipe.description);
+    assert_eq!(&input.steps, recipe.steps.as_ref());
+    assert_eq!(input.time, recipe.time);
+    assert_eq!(input.servings, recipe.servings);
+
+    let mut expected: Vec<_> = recipe
+        .ingredients
+        .iter()
+        .map(|i| {
+            (
+                i.ingredient.id,
+                i.amount.clone(),
+                i.notes.clone(),
+                i.optional,
+            )
+        })
+        .collect();
+    let mut result: Vec<_> = input
+        .ingredients
+        .into_iter()
+        .map(|i| (i.ingredient_id, i.amount, i.notes, i.optional))
+        .collect();
+    expected.sort_by_key(|i| i.0);
+    result.sort_by_key(|i| i.0);
+
+    assert_eq!(result, expected);
+}
+
+pub async fn exporting_recipe_as_markdown_works(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let recipe = exported_recipe();
+    let recipe_repo = insert_recipe(repo, &ingredient_repo, &recipe).await;
+
+    let document = export_recipe(recipe_repo, None, &recipe.id, ExportFormat::Markdown)
+        .await
+        .unwrap();
+
+    assert!(document.starts_with("# Hoisin Tofu and Broccoli\n"));
+    assert!(document.contains("- **Servings:** 4 servings\n"));
+    assert!(document.contains("- **Marinating time:** 30 min\n"));
+    assert!(document.contains("- 400 g Firm tofu\n"));
+    assert!(document.contains("\n### For the sauce\n\n- 4 clove Garlic, minced\n"));
+    assert!(document.contains("- 1 head Broccoli (optional)\n"));
+    assert!(document.contains("\n1. Cut the tofu"));
+}
+
+pub async fn exporting_recipe_as_text_works(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let recipe = exported_recipe();
+    let recipe_repo = insert_recipe(repo, &ingredient_repo, &recipe).await;
+
+    let document = export_recipe(recipe_repo, None, &recipe.id, ExportFormat::Text)
+        .await
+        .unwrap();
+
+    assert!(document.starts_with("Hoisin Tofu and Broccoli\n========================\n"));
+    assert!(document.contains("Prep time: 15 min\n"));
+    assert!(document.contains("  * 400 g Firm tofu\n"));
+    assert!(!document.contains('#'));
+}
+
+pub async fn exporting_a_nonexistent_recipe_errors(repo: impl RecipeRepository) {
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let error = export_recipe(recipe_repo, None, &Uuid::nil(), ExportFormat::JsonLd)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, ExportRecipeError::NotFound(id) if id == Uuid::nil()));
+}
@@ -0,0 +1,32 @@
+use crate::domain::entities::recipe::Recipe;
+
+use super::format_duration;
+
+pub fn render(recipe: &Recipe) -> String {
+    let mut result = format!(
+        "{}\n{}\n\n",
+        recipe.name,
+        "=".repeat(recipe.name.chars().count())
+    );
+
+    if !recipe.description.is_empty() {
+        result.push_str(&format!("{}\n\n", recipe.description));
+    }
+
+    result.push_str(&format!("Servings: {}\n", recipe.servings));
+    for (name, duration) in &recipe.time {
+        result.push_str(&format!("{}: {}\n", name, format_duration(duration)));
+    }
+
+    result.push_str("\nIngredients:\n");
+    for ingredient in recipe.ingredients.iter() {
+        result.push_str(&format!("  * {}\n", ingredient));
+    }
+
+    result.push_str("\nSteps:\n");
+    for (i, step) in recipe.steps.as_ref().iter().enumerate() {
+        result.push_str(&format!("  {}. {}\n", i + 1, step));
+    }
+
+    result
+}
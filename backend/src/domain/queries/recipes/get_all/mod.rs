@@ -0,0 +1,40 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::recipe::Recipe,
+    repositories::recipe::{errors::GetRecipeByIdError, RecipeRepositoryService},
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum GetAllRecipesError {
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetRecipeByIdError> for GetAllRecipesError {
+    fn from(value: GetRecipeByIdError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+/// Lists every public recipe along with the user's own, the best rated ones first
+#[tracing::instrument("[QUERY] Get all recipes", skip(recipe_repo))]
+pub async fn get_all_recipes(
+    recipe_repo: RecipeRepositoryService,
+    user_id: Option<&Uuid>,
+) -> Result<Vec<Recipe>, GetAllRecipesError> {
+    let mut result = recipe_repo.get_all().await?;
+    result.retain(|recipe| recipe.visibility.is_listed() || recipe.is_owned_by(user_id));
+
+    result.sort_by(|a, b| {
+        a.rating
+            .rank(&b.rating)
+            .then_with(|| a.name.cmp(&b.name))
+            .then_with(|| a.id.cmp(&b.id))
+    });
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests;
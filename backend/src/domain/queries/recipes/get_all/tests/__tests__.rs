@@ -0,0 +1,105 @@
+use std::sync::Arc;
+
+use pretty_assertions::assert_eq;
+
+use crate::{
+    domain::{
+        entities::{
+            recipe::Recipe,
+            review::{Rating, Review},
+            visibility::Visibility,
+        },
+        queries::recipes::get_all::get_all_recipes,
+        repositories::{
+            ingredients::IngredientRepository,
+            recipe::{RecipeRepository, RecipeRepositoryService},
+            user::UserRepository,
+        },
+    },
+    test_utils::{
+        another_recipe_fixture, another_user_fixture, insert_all_ingredients_of_recipe,
+        insert_reviews, insert_users, recipe_fixture, review_fixture, user_fixture,
+    },
+};
+
+pub async fn recipes_are_listed_by_rating(
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let mediocre = Review {
+        rating: Rating::new(3).unwrap(),
+        ..review_fixture()
+    };
+    insert_reviews(&recipe_repo, &ingredient_repo, &user_repo, &[mediocre]).await;
+
+    let unrated = another_recipe_fixture(1);
+    let loved = another_recipe_fixture(2);
+    recipe_repo.insert(unrated.clone()).await.unwrap();
+    recipe_repo.insert(loved.clone()).await.unwrap();
+    recipe_repo
+        .add_review(
+            &loved,
+            Review {
+                id: uuid::Uuid::from_u128(4097),
+                recipe_id: loved.id,
+                ..review_fixture()
+            },
+        )
+        .await
+        .unwrap();
+
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let result = get_all_recipes(recipe_repo, None).await.unwrap();
+
+    let ids: Vec<_> = result.iter().map(|recipe| recipe.id).collect();
+    assert_eq!(ids, vec![loved.id, recipe_fixture().id, unrated.id]);
+
+    assert_eq!(result[0].rating.average, Some(5.0));
+    assert_eq!(result[1].rating.average, Some(3.0));
+    assert_eq!(result[2].rating.count, 0);
+}
+
+pub async fn hidden_recipes_are_not_listed(
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let public = recipe_fixture();
+    insert_users(&user_repo).await;
+    insert_all_ingredients_of_recipe(&ingredient_repo, &public).await;
+    recipe_repo.insert(public.clone()).await.unwrap();
+    recipe_repo
+        .insert(Recipe {
+            visibility: Visibility::Unlisted,
+            ..another_recipe_fixture(1)
+        })
+        .await
+        .unwrap();
+    recipe_repo
+        .insert(Recipe {
+            visibility: Visibility::Private,
+            owner_id: Some(user_fixture().id),
+            ..another_recipe_fixture(2)
+        })
+        .await
+        .unwrap();
+
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    for user_id in [None, Some(&another_user_fixture().id)] {
+        let result = get_all_recipes(recipe_repo.clone(), user_id).await.unwrap();
+
+        let ids: Vec<_> = result.iter().map(|recipe| recipe.id).collect();
+        assert_eq!(ids, vec![public.id]);
+    }
+
+    // The owner sees their private recipe next to the public ones
+    let result = get_all_recipes(recipe_repo, Some(&user_fixture().id))
+        .await
+        .unwrap();
+
+    let ids: Vec<_> = result.iter().map(|recipe| recipe.id).collect();
+    assert_eq!(ids, vec![public.id, another_recipe_fixture(2).id]);
+}
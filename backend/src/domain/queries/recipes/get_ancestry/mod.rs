@@ -0,0 +1,55 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::recipe::Recipe,
+    repositories::recipe::{errors::GetRecipeByIdError, RecipeRepositoryService},
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum GetAncestryOfRecipeError {
+    #[error("Could not find the recipe with the ID {0}")]
+    NotFound(Uuid),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetRecipeByIdError> for GetAncestryOfRecipeError {
+    fn from(value: GetRecipeByIdError) -> Self {
+        match value {
+            GetRecipeByIdError::NotFound(id) => Self::NotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+/// Recipes the recipe was forked from, starting with its parent and ending with the original.
+/// The walk stops at a deleted recipe, since forks of it no longer point anywhere,
+/// and at a recipe the user can't see, so that the ancestry doesn't give it away.
+#[tracing::instrument("[QUERY] Get ancestry of a recipe", skip(recipe_repo))]
+pub async fn get_ancestry_of_recipe(
+    recipe_repo: RecipeRepositoryService,
+    user_id: Option<&Uuid>,
+    recipe_id: &Uuid,
+) -> Result<Vec<Recipe>, GetAncestryOfRecipeError> {
+    let recipe = recipe_repo.get_visible(user_id, recipe_id, None).await?;
+
+    let mut result: Vec<Recipe> = vec![];
+    let mut parent_id = recipe.forked_from;
+
+    while let Some(id) = parent_id {
+        let parent = match recipe_repo.get_visible(user_id, &id, None).await {
+            Ok(parent) => parent,
+            Err(GetRecipeByIdError::NotFound(_)) => break,
+            Err(e) => return Err(e.into()),
+        };
+
+        parent_id = parent.forked_from;
+        result.push(parent);
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests;
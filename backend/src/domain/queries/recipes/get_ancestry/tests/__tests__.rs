@@ -0,0 +1,94 @@
+use std::sync::Arc;
+
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        commands::recipes::fork::fork_recipe,
+        queries::recipes::get_ancestry::{get_ancestry_of_recipe, GetAncestryOfRecipeError},
+        repositories::{
+            ingredients::IngredientRepository,
+            recipe::{RecipeRepository, RecipeRepositoryService},
+        },
+    },
+    test_utils::{insert_all_ingredients_of_recipe, recipe_fixture},
+};
+
+pub async fn getting_ancestry_of_a_recipe_works(
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let recipe = recipe_fixture();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let child = fork_recipe(recipe_repo.clone(), None, &recipe.id)
+        .await
+        .unwrap();
+    let grandchild = fork_recipe(recipe_repo.clone(), None, &child.id)
+        .await
+        .unwrap();
+
+    let result = get_ancestry_of_recipe(recipe_repo.clone(), None, &grandchild.id)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        result.iter().map(|r| r.id).collect::<Vec<_>>(),
+        vec![child.id, recipe.id]
+    );
+
+    let result = get_ancestry_of_recipe(recipe_repo, None, &recipe.id)
+        .await
+        .unwrap();
+
+    assert!(result.is_empty());
+}
+
+pub async fn ancestry_stops_at_a_deleted_recipe(
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let recipe = recipe_fixture();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let child = fork_recipe(recipe_repo.clone(), None, &recipe.id)
+        .await
+        .unwrap();
+    let grandchild = fork_recipe(recipe_repo.clone(), None, &child.id)
+        .await
+        .unwrap();
+    recipe_repo.delete(&child).await.unwrap();
+
+    let result = get_ancestry_of_recipe(recipe_repo.clone(), None, &grandchild.id)
+        .await
+        .unwrap();
+
+    assert!(result.is_empty());
+    assert_eq!(
+        recipe_repo
+            .get_by_id(&grandchild.id)
+            .await
+            .unwrap()
+            .forked_from,
+        None
+    );
+}
+
+pub async fn getting_ancestry_of_a_nonexistent_recipe_errors(recipe_repo: impl RecipeRepository) {
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+    let recipe_id = Uuid::from_u128(404);
+
+    let error = get_ancestry_of_recipe(recipe_repo, None, &recipe_id)
+        .await
+        .unwrap_err();
+
+    match error {
+        GetAncestryOfRecipeError::NotFound(id) => assert_eq!(id, recipe_id),
+        e => panic!("Wrong error returned: {e}"),
+    }
+}
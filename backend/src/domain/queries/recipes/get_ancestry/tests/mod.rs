@@ -0,0 +1,69 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::{
+        ingredients::in_memory::InMemoryIngredientRepository,
+        recipe::in_memory::InMemoryRecipeRepository,
+    };
+
+    #[tokio::test]
+    async fn getting_ancestry_of_a_recipe_works() {
+        __tests__::getting_ancestry_of_a_recipe_works(
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn ancestry_stops_at_a_deleted_recipe() {
+        __tests__::ancestry_stops_at_a_deleted_recipe(
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn getting_ancestry_of_a_nonexistent_recipe_errors() {
+        __tests__::getting_ancestry_of_a_nonexistent_recipe_errors(InMemoryRecipeRepository::new())
+            .await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        ingredients::postgres::PostgresIngredientRepository,
+        recipe::postgres::PostgresRecipeRepository,
+    };
+
+    #[sqlx::test]
+    async fn getting_ancestry_of_a_recipe_works(pool: PgPool) {
+        __tests__::getting_ancestry_of_a_recipe_works(
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn ancestry_stops_at_a_deleted_recipe(pool: PgPool) {
+        __tests__::ancestry_stops_at_a_deleted_recipe(
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn getting_ancestry_of_a_nonexistent_recipe_errors(pool: PgPool) {
+        __tests__::getting_ancestry_of_a_nonexistent_recipe_errors(PostgresRecipeRepository::new(
+            pool,
+        ))
+        .await
+    }
+}
@@ -23,17 +23,21 @@ impl From<GetRecipeByIdErrorInternal> for GetRecipeError {
     fn from(value: GetRecipeByIdErrorInternal) -> Self {
         match value {
             GetRecipeByIdErrorInternal::NotFound(id) => GetRecipeError::NotFound(id),
-            e => e.into(),
+            e => Self::Unknown(e.into()),
         }
     }
 }
 
+/// Gets the recipe the way the user sees it, visitors that are not logged in have no user.
+/// Unlisted recipes need a share token and private ones are only found by their owner.
 pub async fn get_recipe_by_id(
     recipe_repo: RecipeRepositoryService,
+    user_id: Option<&Uuid>,
     input: &Uuid,
+    share_token: Option<&str>,
 ) -> Result<Recipe, GetRecipeError> {
     let result = recipe_repo
-        .get_by_id(input)
+        .get_visible(user_id, input, share_token)
         .await
         .map_err(GetRecipeError::from)?;
 
@@ -1,4 +1,7 @@
-use crate::domain::{entities::recipe::Recipe, queries::recipes::get_by_id::GetRecipeError};
+use crate::domain::{
+    entities::{recipe::Recipe, share_token::ShareToken, visibility::Visibility},
+    queries::recipes::get_by_id::GetRecipeError,
+};
 use std::sync::Arc;
 
 use chrono::Utc;
@@ -10,9 +13,13 @@ use crate::{
         repositories::{
             ingredients::IngredientRepository,
             recipe::{RecipeRepository, RecipeRepositoryService},
+            user::UserRepository,
         },
     },
-    test_utils::{insert_all_ingredients_of_recipe, recipe_fixture},
+    test_utils::{
+        another_recipe_fixture, another_user_fixture, insert_all_ingredients_of_recipe,
+        insert_users, recipe_fixture, user_fixture,
+    },
 };
 
 pub async fn getting_recipe_by_id_works(
@@ -26,7 +33,9 @@ pub async fn getting_recipe_by_id_works(
     repo.insert(recipe.clone()).await.unwrap();
 
     let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
-    let result = get_recipe_by_id(recipe_repo, &recipe.id).await.unwrap();
+    let result = get_recipe_by_id(recipe_repo, None, &recipe.id, None)
+        .await
+        .unwrap();
 
     let now = Utc::now();
 
@@ -47,9 +56,86 @@ pub async fn getting_recipe_by_id_works(
 
 pub async fn getting_a_nonexistent_recipe_errors(repo: impl RecipeRepository) {
     let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
-    let error = get_recipe_by_id(recipe_repo, &Uuid::nil())
+    let error = get_recipe_by_id(recipe_repo, None, &Uuid::nil(), None)
         .await
         .unwrap_err();
 
     assert!(matches!(error, GetRecipeError::NotFound(id) if id == Uuid::nil()));
 }
+
+pub async fn getting_an_unlisted_recipe_needs_a_share_token(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let recipe = Recipe {
+        visibility: Visibility::Unlisted,
+        ..recipe_fixture()
+    };
+    let other = another_recipe_fixture(1);
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    repo.insert(recipe.clone()).await.unwrap();
+    repo.insert(other.clone()).await.unwrap();
+
+    let share_token = ShareToken::new(recipe.id);
+    let other_share_token = ShareToken::new(other.id);
+    repo.add_share_token(share_token.clone()).await.unwrap();
+    repo.add_share_token(other_share_token.clone())
+        .await
+        .unwrap();
+
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+
+    for token in [
+        None,
+        Some("made up"),
+        Some(other_share_token.token.as_str()),
+    ] {
+        let error = get_recipe_by_id(recipe_repo.clone(), None, &recipe.id, token)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error, GetRecipeError::NotFound(id) if id == recipe.id));
+    }
+
+    let result = get_recipe_by_id(recipe_repo, None, &recipe.id, Some(&share_token.token))
+        .await
+        .unwrap();
+
+    assert_eq!(result.id, recipe.id);
+}
+
+pub async fn getting_a_private_recipe_needs_its_owner(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let recipe = Recipe {
+        visibility: Visibility::Private,
+        owner_id: Some(user_fixture().id),
+        ..recipe_fixture()
+    };
+    insert_users(&user_repo).await;
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    repo.insert(recipe.clone()).await.unwrap();
+
+    let share_token = ShareToken::new(recipe.id);
+    repo.add_share_token(share_token.clone()).await.unwrap();
+
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+
+    for user_id in [None, Some(&another_user_fixture().id)] {
+        for token in [None, Some(share_token.token.as_str())] {
+            let error = get_recipe_by_id(recipe_repo.clone(), user_id, &recipe.id, token)
+                .await
+                .unwrap_err();
+
+            assert!(matches!(error, GetRecipeError::NotFound(id) if id == recipe.id));
+        }
+    }
+
+    let result = get_recipe_by_id(recipe_repo, Some(&user_fixture().id), &recipe.id, None)
+        .await
+        .unwrap();
+
+    assert_eq!(result.id, recipe.id);
+}
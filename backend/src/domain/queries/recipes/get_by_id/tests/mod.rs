@@ -4,7 +4,7 @@ mod in_memory {
 
     use crate::domain::repositories::{
         ingredients::in_memory::InMemoryIngredientRepository,
-        recipe::in_memory::InMemoryRecipeRepository,
+        recipe::in_memory::InMemoryRecipeRepository, user::in_memory::InMemoryUserRepository,
     };
 
     use super::__tests__;
@@ -21,6 +21,21 @@ mod in_memory {
         let repo = InMemoryRecipeRepository::new();
         __tests__::getting_a_nonexistent_recipe_errors(repo).await
     }
+
+    #[tokio::test]
+    async fn getting_an_unlisted_recipe_needs_a_share_token() {
+        let repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        __tests__::getting_an_unlisted_recipe_needs_a_share_token(repo, ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn getting_a_private_recipe_needs_its_owner() {
+        let repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        __tests__::getting_a_private_recipe_needs_its_owner(repo, ingredient_repo, user_repo).await
+    }
 }
 
 mod sql {
@@ -29,7 +44,7 @@ mod sql {
     use super::__tests__;
     use crate::domain::repositories::{
         ingredients::postgres::PostgresIngredientRepository,
-        recipe::postgres::PostgresRecipeRepository,
+        recipe::postgres::PostgresRecipeRepository, user::postgres::PostgresUserRepository,
     };
 
     #[sqlx::test]
@@ -44,4 +59,19 @@ mod sql {
         let repo = PostgresRecipeRepository::new(pool.clone());
         __tests__::getting_a_nonexistent_recipe_errors(repo).await
     }
+
+    #[sqlx::test]
+    async fn getting_an_unlisted_recipe_needs_a_share_token(pool: PgPool) {
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool);
+        __tests__::getting_an_unlisted_recipe_needs_a_share_token(repo, ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn getting_a_private_recipe_needs_its_owner(pool: PgPool) {
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool);
+        __tests__::getting_a_private_recipe_needs_its_owner(repo, ingredient_repo, user_repo).await
+    }
 }
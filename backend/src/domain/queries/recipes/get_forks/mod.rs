@@ -0,0 +1,45 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::recipe::Recipe,
+    repositories::recipe::{errors::GetRecipeByIdError, RecipeRepositoryService},
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum GetForksOfRecipeError {
+    #[error("Could not find the recipe with the ID {0}")]
+    NotFound(Uuid),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetRecipeByIdError> for GetForksOfRecipeError {
+    fn from(value: GetRecipeByIdError) -> Self {
+        match value {
+            GetRecipeByIdError::NotFound(id) => Self::NotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+/// Recipes forked directly from the recipe that the user can see, oldest first
+#[tracing::instrument("[QUERY] Get forks of a recipe", skip(recipe_repo))]
+pub async fn get_forks_of_recipe(
+    recipe_repo: RecipeRepositoryService,
+    user_id: Option<&Uuid>,
+    recipe_id: &Uuid,
+) -> Result<Vec<Recipe>, GetForksOfRecipeError> {
+    let recipe = recipe_repo.get_visible(user_id, recipe_id, None).await?;
+    let result = recipe_repo
+        .get_forks(&recipe)
+        .await?
+        .into_iter()
+        .filter(|fork| fork.is_visible_to(user_id, None))
+        .collect();
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests;
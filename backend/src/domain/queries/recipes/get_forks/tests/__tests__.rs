@@ -0,0 +1,86 @@
+use std::sync::Arc;
+
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        commands::recipes::fork::fork_recipe,
+        entities::{recipe::RecipeChangeset, visibility::Visibility},
+        queries::recipes::get_forks::{get_forks_of_recipe, GetForksOfRecipeError},
+        repositories::{
+            ingredients::IngredientRepository,
+            recipe::{RecipeRepository, RecipeRepositoryService},
+            user::UserRepository,
+        },
+    },
+    test_utils::{insert_all_ingredients_of_recipe, insert_users, recipe_fixture, user_fixture},
+};
+
+pub async fn getting_forks_of_a_recipe_works(
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let recipe = recipe_fixture();
+    insert_users(&user_repo).await;
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let first = fork_recipe(recipe_repo.clone(), None, &recipe.id)
+        .await
+        .unwrap();
+    let second = fork_recipe(recipe_repo.clone(), None, &recipe.id)
+        .await
+        .unwrap();
+    // Forks of forks are not direct forks of the original
+    fork_recipe(recipe_repo.clone(), None, &first.id)
+        .await
+        .unwrap();
+    let hidden = fork_recipe(recipe_repo.clone(), Some(&user_fixture().id), &recipe.id)
+        .await
+        .unwrap();
+    recipe_repo
+        .update(
+            &hidden,
+            RecipeChangeset {
+                visibility: Some(Visibility::Private),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    let result = get_forks_of_recipe(recipe_repo.clone(), None, &recipe.id)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        result.iter().map(|r| r.id).collect::<Vec<_>>(),
+        vec![first.id, second.id]
+    );
+
+    let result = get_forks_of_recipe(recipe_repo, Some(&user_fixture().id), &recipe.id)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        result.iter().map(|r| r.id).collect::<Vec<_>>(),
+        vec![first.id, second.id, hidden.id]
+    );
+}
+
+pub async fn getting_forks_of_a_nonexistent_recipe_errors(recipe_repo: impl RecipeRepository) {
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+    let recipe_id = Uuid::from_u128(404);
+
+    let error = get_forks_of_recipe(recipe_repo, None, &recipe_id)
+        .await
+        .unwrap_err();
+
+    match error {
+        GetForksOfRecipeError::NotFound(id) => assert_eq!(id, recipe_id),
+        e => panic!("Wrong error returned: {e}"),
+    }
+}
@@ -0,0 +1,51 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::{
+        ingredients::in_memory::InMemoryIngredientRepository,
+        recipe::in_memory::InMemoryRecipeRepository, user::in_memory::InMemoryUserRepository,
+    };
+
+    #[tokio::test]
+    async fn getting_forks_of_a_recipe_works() {
+        __tests__::getting_forks_of_a_recipe_works(
+            InMemoryRecipeRepository::new(),
+            InMemoryIngredientRepository::new(),
+            InMemoryUserRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn getting_forks_of_a_nonexistent_recipe_errors() {
+        __tests__::getting_forks_of_a_nonexistent_recipe_errors(InMemoryRecipeRepository::new())
+            .await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        ingredients::postgres::PostgresIngredientRepository,
+        recipe::postgres::PostgresRecipeRepository, user::postgres::PostgresUserRepository,
+    };
+
+    #[sqlx::test]
+    async fn getting_forks_of_a_recipe_works(pool: PgPool) {
+        __tests__::getting_forks_of_a_recipe_works(
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresUserRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn getting_forks_of_a_nonexistent_recipe_errors(pool: PgPool) {
+        __tests__::getting_forks_of_a_nonexistent_recipe_errors(PostgresRecipeRepository::new(pool))
+            .await
+    }
+}
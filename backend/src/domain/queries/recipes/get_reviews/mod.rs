@@ -0,0 +1,48 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::review::Review,
+    repositories::recipe::{
+        errors::{GetRecipeByIdError, GetReviewError},
+        RecipeRepositoryService,
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum GetReviewsOfRecipeError {
+    #[error("Could not find the recipe with the ID {0}")]
+    RecipeNotFound(Uuid),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetRecipeByIdError> for GetReviewsOfRecipeError {
+    fn from(value: GetRecipeByIdError) -> Self {
+        match value {
+            GetRecipeByIdError::NotFound(id) => Self::RecipeNotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<GetReviewError> for GetReviewsOfRecipeError {
+    fn from(value: GetReviewError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+#[tracing::instrument("[QUERY] Get reviews of a recipe", skip(recipe_repo))]
+pub async fn get_reviews_of_recipe(
+    recipe_repo: RecipeRepositoryService,
+    user_id: Option<&Uuid>,
+    recipe_id: &Uuid,
+) -> Result<Vec<Review>, GetReviewsOfRecipeError> {
+    let recipe = recipe_repo.get_visible(user_id, recipe_id, None).await?;
+    let result = recipe_repo.get_reviews(&recipe).await?;
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests;
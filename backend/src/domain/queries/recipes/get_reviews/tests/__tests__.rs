@@ -0,0 +1,63 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::review::{Rating, Review},
+        queries::recipes::get_reviews::{get_reviews_of_recipe, GetReviewsOfRecipeError},
+        repositories::{
+            ingredients::IngredientRepository,
+            recipe::{RecipeRepository, RecipeRepositoryService},
+            user::UserRepository,
+        },
+    },
+    test_utils::{another_user_fixture, insert_reviews, recipe_fixture, review_fixture},
+};
+
+pub async fn getting_reviews_of_a_recipe_works(
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let older = Review {
+        id: Uuid::from_u128(4097),
+        user_id: Some(another_user_fixture().id),
+        author: another_user_fixture().username,
+        rating: Rating::new(4).unwrap(),
+        body: None,
+        created_at: DateTime::<Utc>::from_timestamp_nanos(1_600_000_000_000_000_000),
+        updated_at: DateTime::<Utc>::from_timestamp_nanos(1_600_000_000_000_000_000),
+        ..review_fixture()
+    };
+    insert_reviews(
+        &recipe_repo,
+        &ingredient_repo,
+        &user_repo,
+        &[review_fixture(), older.clone()],
+    )
+    .await;
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let result = get_reviews_of_recipe(recipe_repo, None, &recipe_fixture().id)
+        .await
+        .unwrap();
+
+    assert_eq!(result, vec![older, review_fixture()]);
+}
+
+pub async fn getting_reviews_of_a_nonexistent_recipe_errors(recipe_repo: impl RecipeRepository) {
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+    let recipe_id = Uuid::from_u128(404);
+
+    let error = get_reviews_of_recipe(recipe_repo, None, &recipe_id)
+        .await
+        .unwrap_err();
+
+    match error {
+        GetReviewsOfRecipeError::RecipeNotFound(id) => assert_eq!(id, recipe_id),
+        e => panic!("Wrong error returned: {e}"),
+    }
+}
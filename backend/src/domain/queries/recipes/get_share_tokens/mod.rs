@@ -0,0 +1,59 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::share_token::ShareToken,
+    repositories::recipe::{
+        errors::{GetRecipeByIdError, GetShareTokenError},
+        RecipeRepositoryService,
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum GetShareTokensOfRecipeError {
+    #[error("Could not find the recipe with the ID {0}")]
+    RecipeNotFound(Uuid),
+
+    #[error("Only the owner of the recipe with the ID {0} can see its share tokens")]
+    NotOwner(Uuid),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetRecipeByIdError> for GetShareTokensOfRecipeError {
+    fn from(value: GetRecipeByIdError) -> Self {
+        match value {
+            GetRecipeByIdError::NotFound(id) => Self::RecipeNotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<GetShareTokenError> for GetShareTokensOfRecipeError {
+    fn from(value: GetShareTokenError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+/// Only the owner of the recipe can see who it is shared with
+#[tracing::instrument("[QUERY] Get share tokens of a recipe", skip(recipe_repo))]
+pub async fn get_share_tokens_of_recipe(
+    recipe_repo: RecipeRepositoryService,
+    user_id: &Uuid,
+    recipe_id: &Uuid,
+) -> Result<Vec<ShareToken>, GetShareTokensOfRecipeError> {
+    let recipe = recipe_repo
+        .get_visible(Some(user_id), recipe_id, None)
+        .await?;
+
+    if !recipe.is_owned_by(Some(user_id)) {
+        return Err(GetShareTokensOfRecipeError::NotOwner(recipe.id));
+    }
+
+    let result = recipe_repo.get_share_tokens(&recipe).await?;
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,81 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::{recipe::Recipe, share_token::ShareToken},
+        queries::recipes::get_share_tokens::{
+            get_share_tokens_of_recipe, GetShareTokensOfRecipeError,
+        },
+        repositories::{
+            ingredients::IngredientRepository,
+            recipe::{RecipeRepository, RecipeRepositoryService},
+            user::UserRepository,
+        },
+    },
+    test_utils::{
+        another_recipe_fixture, another_user_fixture, insert_all_ingredients_of_recipe,
+        insert_users, recipe_fixture, user_fixture,
+    },
+};
+
+pub async fn getting_share_tokens_of_a_recipe_works(
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let recipe = Recipe {
+        owner_id: Some(user_fixture().id),
+        ..recipe_fixture()
+    };
+    let other = another_recipe_fixture(1);
+    insert_users(&user_repo).await;
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+    recipe_repo.insert(other.clone()).await.unwrap();
+
+    let newer = ShareToken {
+        token: "b".repeat(32),
+        recipe_id: recipe.id,
+        created_at: DateTime::<Utc>::from_timestamp_nanos(1_700_000_000_000_000_000),
+    };
+    let older = ShareToken {
+        token: "a".repeat(32),
+        created_at: DateTime::<Utc>::from_timestamp_nanos(1_600_000_000_000_000_000),
+        ..newer.clone()
+    };
+    for share_token in [newer.clone(), older.clone(), ShareToken::new(other.id)] {
+        recipe_repo.add_share_token(share_token).await.unwrap();
+    }
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let result = get_share_tokens_of_recipe(recipe_repo.clone(), &user_fixture().id, &recipe.id)
+        .await
+        .unwrap();
+
+    assert_eq!(result, vec![older, newer]);
+
+    let error = get_share_tokens_of_recipe(recipe_repo, &another_user_fixture().id, &recipe.id)
+        .await
+        .unwrap_err();
+    assert!(matches!(error, GetShareTokensOfRecipeError::NotOwner(id) if id == recipe.id));
+}
+
+pub async fn getting_share_tokens_of_a_nonexistent_recipe_errors(
+    recipe_repo: impl RecipeRepository,
+) {
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+    let recipe_id = Uuid::from_u128(404);
+
+    let error = get_share_tokens_of_recipe(recipe_repo, &user_fixture().id, &recipe_id)
+        .await
+        .unwrap_err();
+
+    match error {
+        GetShareTokensOfRecipeError::RecipeNotFound(id) => assert_eq!(id, recipe_id),
+        e => panic!("Wrong error returned: {e}"),
+    }
+}
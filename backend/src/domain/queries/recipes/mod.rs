@@ -1 +1,8 @@
+pub mod cookbook;
+pub mod export;
+pub mod get_all;
+pub mod get_ancestry;
 pub mod get_by_id;
+pub mod get_forks;
+pub mod get_reviews;
+pub mod get_share_tokens;
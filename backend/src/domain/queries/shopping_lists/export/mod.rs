@@ -0,0 +1,39 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::shopping_list::ShoppingList,
+    repositories::shopping_list::ShoppingListRepositoryService,
+};
+
+use super::get_by_id::GetShoppingListError;
+
+/// Renders the list as plain text, with a checkbox in front of every item:
+/// ```text
+/// Groceries
+///
+/// [ ] 400 g Firm tofu
+/// [x] 1 head Broccoli
+/// ```
+pub fn render_text(shopping_list: &ShoppingList) -> String {
+    let mut result = format!("{}\n\n", shopping_list.name);
+
+    for item in &shopping_list.items {
+        let checkbox = if item.checked { "[x]" } else { "[ ]" };
+        result.push_str(&format!("{} {}\n", checkbox, item));
+    }
+
+    result
+}
+
+#[tracing::instrument("[QUERY] Exporting a shopping list", skip(shopping_list_repo))]
+pub async fn export_shopping_list(
+    shopping_list_repo: ShoppingListRepositoryService,
+    id: &Uuid,
+) -> Result<String, GetShoppingListError> {
+    let shopping_list = shopping_list_repo.get_by_id(id).await?;
+
+    Ok(render_text(&shopping_list))
+}
+
+#[cfg(test)]
+mod tests;
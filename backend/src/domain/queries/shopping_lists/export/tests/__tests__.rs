@@ -0,0 +1,38 @@
+use std::sync::Arc;
+
+use pretty_assertions::assert_eq;
+
+use crate::{
+    domain::{
+        queries::shopping_lists::export::export_shopping_list,
+        repositories::{
+            ingredients::IngredientRepository,
+            shopping_list::{ShoppingListRepository, ShoppingListRepositoryService},
+        },
+    },
+    test_utils::{insert_all_ingredients_of_recipe, recipe_fixture, shopping_list_fixture},
+};
+
+pub async fn exporting_a_shopping_list_works(
+    shopping_list_repo: impl ShoppingListRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let mut shopping_list = shopping_list_fixture();
+    shopping_list.items[1].checked = true;
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe_fixture()).await;
+    shopping_list_repo
+        .insert(shopping_list.clone())
+        .await
+        .unwrap();
+
+    let shopping_list_repo: ShoppingListRepositoryService = Arc::new(Box::new(shopping_list_repo));
+
+    let result = export_shopping_list(shopping_list_repo, &shopping_list.id)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        result,
+        "Groceries\n\n[ ] 400 g Firm tofu\n[x] 1 head Broccoli\n[ ] 4 clove Garlic\n"
+    );
+}
@@ -0,0 +1,44 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{recipe::errors::ValidationError, shopping_list::ShoppingList},
+    repositories::shopping_list::{
+        errors::GetShoppingListByIdError as GetShoppingListByIdErrorInternal,
+        ShoppingListRepositoryService,
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum GetShoppingListError {
+    #[error("Could not find the shopping list with the ID {0}")]
+    NotFound(Uuid),
+
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetShoppingListByIdErrorInternal> for GetShoppingListError {
+    fn from(value: GetShoppingListByIdErrorInternal) -> Self {
+        match value {
+            GetShoppingListByIdErrorInternal::NotFound(id) => Self::NotFound(id),
+            GetShoppingListByIdErrorInternal::ValidationError(e) => Self::ValidationError(e),
+            GetShoppingListByIdErrorInternal::UnknownError(e) => Self::Unknown(e),
+        }
+    }
+}
+
+#[tracing::instrument("[QUERY] Get shopping list", skip(shopping_list_repo))]
+pub async fn get_shopping_list_by_id(
+    shopping_list_repo: ShoppingListRepositoryService,
+    id: &Uuid,
+) -> Result<ShoppingList, GetShoppingListError> {
+    let result = shopping_list_repo.get_by_id(id).await?;
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests;
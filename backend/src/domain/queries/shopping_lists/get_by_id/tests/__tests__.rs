@@ -0,0 +1,47 @@
+use std::sync::Arc;
+
+use pretty_assertions::assert_eq;
+
+use crate::{
+    domain::{
+        queries::shopping_lists::get_by_id::{get_shopping_list_by_id, GetShoppingListError},
+        repositories::{
+            ingredients::IngredientRepository,
+            shopping_list::{ShoppingListRepository, ShoppingListRepositoryService},
+        },
+    },
+    test_utils::{insert_all_ingredients_of_recipe, recipe_fixture, shopping_list_fixture},
+};
+
+pub async fn getting_a_shopping_list_works(
+    shopping_list_repo: impl ShoppingListRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let shopping_list = shopping_list_fixture();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe_fixture()).await;
+    shopping_list_repo
+        .insert(shopping_list.clone())
+        .await
+        .unwrap();
+
+    let shopping_list_repo: ShoppingListRepositoryService = Arc::new(Box::new(shopping_list_repo));
+
+    let result = get_shopping_list_by_id(shopping_list_repo, &shopping_list.id)
+        .await
+        .unwrap();
+
+    assert_eq!(result, shopping_list);
+}
+
+pub async fn getting_a_nonexistent_shopping_list_errors(
+    shopping_list_repo: impl ShoppingListRepository,
+) {
+    let shopping_list = shopping_list_fixture();
+    let shopping_list_repo: ShoppingListRepositoryService = Arc::new(Box::new(shopping_list_repo));
+
+    let error = get_shopping_list_by_id(shopping_list_repo, &shopping_list.id)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, GetShoppingListError::NotFound(id) if id == shopping_list.id));
+}
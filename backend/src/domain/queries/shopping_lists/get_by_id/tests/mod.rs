@@ -0,0 +1,51 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::{
+        ingredients::in_memory::InMemoryIngredientRepository,
+        shopping_list::in_memory::InMemoryShoppingListRepository,
+    };
+
+    #[tokio::test]
+    async fn getting_a_shopping_list_works() {
+        __tests__::getting_a_shopping_list_works(
+            InMemoryShoppingListRepository::new(),
+            InMemoryIngredientRepository::new(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn getting_a_nonexistent_shopping_list_errors() {
+        __tests__::getting_a_nonexistent_shopping_list_errors(InMemoryShoppingListRepository::new())
+            .await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        ingredients::postgres::PostgresIngredientRepository,
+        shopping_list::postgres::PostgresShoppingListRepository,
+    };
+
+    #[sqlx::test]
+    async fn getting_a_shopping_list_works(pool: PgPool) {
+        __tests__::getting_a_shopping_list_works(
+            PostgresShoppingListRepository::new(pool.clone()),
+            PostgresIngredientRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn getting_a_nonexistent_shopping_list_errors(pool: PgPool) {
+        __tests__::getting_a_nonexistent_shopping_list_errors(PostgresShoppingListRepository::new(
+            pool,
+        ))
+        .await
+    }
+}
@@ -0,0 +1,2 @@
+pub mod export;
+pub mod get_by_id;
@@ -0,0 +1,35 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::webhook::Webhook,
+    repositories::webhook::{
+        errors::GetAllWebhooksError as GetAllWebhooksErrorInternal, WebhookRepositoryService,
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum GetAllWebhooksError {
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetAllWebhooksErrorInternal> for GetAllWebhooksError {
+    fn from(value: GetAllWebhooksErrorInternal) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+/// The webhooks the user can manage
+#[tracing::instrument("[QUERY] Get all webhooks", skip(webhook_repo))]
+pub async fn get_all_webhooks(
+    webhook_repo: WebhookRepositoryService,
+    user_id: &Uuid,
+) -> Result<Vec<Webhook>, GetAllWebhooksError> {
+    let mut result = webhook_repo.get_all().await?;
+    result.retain(|webhook| webhook.is_managed_by(user_id));
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests;
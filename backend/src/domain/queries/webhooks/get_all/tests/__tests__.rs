@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::webhook::Webhook,
+        queries::webhooks::get_all::get_all_webhooks,
+        repositories::{
+            user::UserRepository,
+            webhook::{WebhookRepository, WebhookRepositoryService},
+        },
+    },
+    test_utils::{another_user_fixture, insert_users, user_fixture, webhook_fixture},
+};
+
+pub async fn getting_all_webhooks_works(webhook_repo: impl WebhookRepository) {
+    let first = webhook_fixture();
+    let second = Webhook {
+        id: Uuid::from_u128(16385),
+        url: "https://example.com/hooks".to_string(),
+        event_types: vec!["ingredient_created".to_string()],
+        ..webhook_fixture()
+    };
+    webhook_repo.insert(first.clone()).await.unwrap();
+    webhook_repo.insert(second.clone()).await.unwrap();
+    let webhook_repo: WebhookRepositoryService = Arc::new(Box::new(webhook_repo));
+
+    let mut result = get_all_webhooks(webhook_repo, &user_fixture().id)
+        .await
+        .unwrap();
+    result.sort_by_key(|w| w.id);
+
+    assert_eq!(result, vec![first, second]);
+}
+
+pub async fn webhooks_of_someone_else_are_left_out(
+    webhook_repo: impl WebhookRepository,
+    user_repo: impl UserRepository,
+) {
+    insert_users(&user_repo).await;
+    let mine = Webhook {
+        owner_id: Some(user_fixture().id),
+        ..webhook_fixture()
+    };
+    let theirs = Webhook {
+        id: Uuid::from_u128(16385),
+        owner_id: Some(another_user_fixture().id),
+        ..webhook_fixture()
+    };
+    webhook_repo.insert(mine.clone()).await.unwrap();
+    webhook_repo.insert(theirs).await.unwrap();
+    let webhook_repo: WebhookRepositoryService = Arc::new(Box::new(webhook_repo));
+
+    let result = get_all_webhooks(webhook_repo, &user_fixture().id)
+        .await
+        .unwrap();
+
+    assert_eq!(result, vec![mine]);
+}
@@ -0,0 +1,45 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::{
+        user::in_memory::InMemoryUserRepository, webhook::in_memory::InMemoryWebhookRepository,
+    };
+
+    #[tokio::test]
+    async fn getting_all_webhooks_works() {
+        __tests__::getting_all_webhooks_works(InMemoryWebhookRepository::new()).await
+    }
+
+    #[tokio::test]
+    async fn webhooks_of_someone_else_are_left_out() {
+        __tests__::webhooks_of_someone_else_are_left_out(
+            InMemoryWebhookRepository::new(),
+            InMemoryUserRepository::new(),
+        )
+        .await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        user::postgres::PostgresUserRepository, webhook::postgres::PostgresWebhookRepository,
+    };
+
+    #[sqlx::test]
+    async fn getting_all_webhooks_works(pool: PgPool) {
+        __tests__::getting_all_webhooks_works(PostgresWebhookRepository::new(pool)).await
+    }
+
+    #[sqlx::test]
+    async fn webhooks_of_someone_else_are_left_out(pool: PgPool) {
+        __tests__::webhooks_of_someone_else_are_left_out(
+            PostgresWebhookRepository::new(pool.clone()),
+            PostgresUserRepository::new(pool),
+        )
+        .await
+    }
+}
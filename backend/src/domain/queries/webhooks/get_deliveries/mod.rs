@@ -0,0 +1,54 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::webhook::WebhookDelivery,
+    repositories::webhook::{
+        errors::{GetWebhookDeliveriesError as GetWebhookDeliveriesErrorInternal, GetWebhookError},
+        WebhookRepositoryService,
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum GetWebhookDeliveriesError {
+    #[error("Could not find the webhook with the ID {0}")]
+    NotFound(Uuid),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetWebhookError> for GetWebhookDeliveriesError {
+    fn from(value: GetWebhookError) -> Self {
+        match value {
+            GetWebhookError::NotFound(id) => Self::NotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<GetWebhookDeliveriesErrorInternal> for GetWebhookDeliveriesError {
+    fn from(value: GetWebhookDeliveriesErrorInternal) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+/// Every attempt at delivering events to the webhook, oldest first.
+/// Webhooks of someone else are as good as missing.
+#[tracing::instrument("[QUERY] Get deliveries of a webhook", skip(webhook_repo))]
+pub async fn get_webhook_deliveries(
+    webhook_repo: WebhookRepositoryService,
+    user_id: &Uuid,
+    webhook_id: &Uuid,
+) -> Result<Vec<WebhookDelivery>, GetWebhookDeliveriesError> {
+    let webhook = webhook_repo.get_by_id(webhook_id).await?;
+    if !webhook.is_managed_by(user_id) {
+        return Err(GetWebhookDeliveriesError::NotFound(*webhook_id));
+    }
+
+    let result = webhook_repo.get_deliveries(&webhook).await?;
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests;
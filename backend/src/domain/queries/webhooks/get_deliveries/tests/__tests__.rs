@@ -0,0 +1,81 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::webhook::{Webhook, WebhookDelivery},
+        queries::webhooks::get_deliveries::{get_webhook_deliveries, GetWebhookDeliveriesError},
+        repositories::{
+            user::UserRepository,
+            webhook::{WebhookRepository, WebhookRepositoryService},
+        },
+    },
+    test_utils::{another_user_fixture, insert_users, user_fixture, webhook_fixture},
+};
+
+pub async fn getting_deliveries_of_a_webhook_works(webhook_repo: impl WebhookRepository) {
+    let webhook = webhook_fixture();
+    webhook_repo.insert(webhook.clone()).await.unwrap();
+
+    let deliveries: Vec<_> = (1..=2)
+        .map(|attempt| WebhookDelivery {
+            id: Uuid::from_u128(32768),
+            webhook_id: webhook.id,
+            event_type: "recipe_deleted".to_string(),
+            payload: serde_json::json!({ "type": "recipe_deleted", "recipe_id": Uuid::nil() }),
+            attempt,
+            status_code: None,
+            error: Some("Connection refused".to_string()),
+            succeeded: false,
+            created_at: DateTime::<Utc>::from_timestamp_nanos(
+                1_700_000_000_000_000_000 + attempt as i64 * 1_000_000_000,
+            ),
+        })
+        .collect();
+
+    for delivery in deliveries.iter().rev() {
+        webhook_repo.add_delivery(delivery.clone()).await.unwrap();
+    }
+    let webhook_repo: WebhookRepositoryService = Arc::new(Box::new(webhook_repo));
+
+    let result = get_webhook_deliveries(webhook_repo, &user_fixture().id, &webhook.id)
+        .await
+        .unwrap();
+
+    assert_eq!(result, deliveries);
+}
+
+pub async fn getting_deliveries_of_a_nonexistent_webhook_fails(
+    webhook_repo: impl WebhookRepository,
+) {
+    let webhook_repo: WebhookRepositoryService = Arc::new(Box::new(webhook_repo));
+    let id = Uuid::from_u128(404);
+
+    let error = get_webhook_deliveries(webhook_repo, &user_fixture().id, &id)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, GetWebhookDeliveriesError::NotFound(i) if i == id));
+}
+
+pub async fn getting_deliveries_of_a_webhook_of_someone_else_fails(
+    webhook_repo: impl WebhookRepository,
+    user_repo: impl UserRepository,
+) {
+    insert_users(&user_repo).await;
+    let webhook = Webhook {
+        owner_id: Some(another_user_fixture().id),
+        ..webhook_fixture()
+    };
+    webhook_repo.insert(webhook.clone()).await.unwrap();
+    let webhook_repo: WebhookRepositoryService = Arc::new(Box::new(webhook_repo));
+
+    let error = get_webhook_deliveries(webhook_repo, &user_fixture().id, &webhook.id)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, GetWebhookDeliveriesError::NotFound(id) if id == webhook.id));
+}
@@ -0,0 +1,139 @@
+use eyre::eyre;
+use sqlx::Error as SQLXError;
+use std::sync::PoisonError;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::domain::entities::recipe::errors::ValidationError;
+
+const POISONED: &str =
+    "Collection repository lock was poisoned during a previous access and can no longer be locked";
+
+#[derive(Error, Debug)]
+pub enum InsertCollectionError {
+    #[error("The collection with ID of {0} already exists")]
+    Conflict(Uuid),
+
+    #[error(transparent)]
+    UnknownError(#[from] eyre::Error),
+}
+
+impl<T> From<PoisonError<T>> for InsertCollectionError {
+    fn from(_value: PoisonError<T>) -> Self {
+        eyre!(POISONED).into()
+    }
+}
+
+impl From<SQLXError> for InsertCollectionError {
+    fn from(e: SQLXError) -> Self {
+        Self::UnknownError(e.into())
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum GetCollectionError {
+    #[error("The collection with ID of {0} was not found")]
+    NotFound(Uuid),
+
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError),
+
+    #[error(transparent)]
+    UnknownError(#[from] eyre::Error),
+}
+
+impl GetCollectionError {
+    pub fn with_id(id: &Uuid, e: SQLXError) -> Self {
+        match e {
+            SQLXError::RowNotFound => Self::NotFound(*id),
+            _ => Self::UnknownError(e.into()),
+        }
+    }
+}
+
+impl<T> From<PoisonError<T>> for GetCollectionError {
+    fn from(_value: PoisonError<T>) -> Self {
+        eyre!(POISONED).into()
+    }
+}
+
+impl From<SQLXError> for GetCollectionError {
+    fn from(e: SQLXError) -> Self {
+        Self::UnknownError(e.into())
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum UpdateCollectionError {
+    #[error(transparent)]
+    UnknownError(#[from] eyre::Error),
+}
+
+impl<T> From<PoisonError<T>> for UpdateCollectionError {
+    fn from(_value: PoisonError<T>) -> Self {
+        eyre!(POISONED).into()
+    }
+}
+
+impl From<SQLXError> for UpdateCollectionError {
+    fn from(e: SQLXError) -> Self {
+        Self::UnknownError(e.into())
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum DeleteCollectionError {
+    #[error(transparent)]
+    UnknownError(#[from] eyre::Error),
+}
+
+impl<T> From<PoisonError<T>> for DeleteCollectionError {
+    fn from(_value: PoisonError<T>) -> Self {
+        eyre!(POISONED).into()
+    }
+}
+
+impl From<SQLXError> for DeleteCollectionError {
+    fn from(e: SQLXError) -> Self {
+        Self::UnknownError(e.into())
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum AddRecipeToCollectionError {
+    #[error("The recipe with ID of {0} is already in the collection")]
+    AlreadyInCollection(Uuid),
+
+    #[error(transparent)]
+    UnknownError(#[from] eyre::Error),
+}
+
+impl<T> From<PoisonError<T>> for AddRecipeToCollectionError {
+    fn from(_value: PoisonError<T>) -> Self {
+        eyre!(POISONED).into()
+    }
+}
+
+impl From<SQLXError> for AddRecipeToCollectionError {
+    fn from(e: SQLXError) -> Self {
+        Self::UnknownError(e.into())
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum RemoveRecipeFromCollectionError {
+    #[error(transparent)]
+    UnknownError(#[from] eyre::Error),
+}
+
+impl<T> From<PoisonError<T>> for RemoveRecipeFromCollectionError {
+    fn from(_value: PoisonError<T>) -> Self {
+        eyre!(POISONED).into()
+    }
+}
+
+impl From<SQLXError> for RemoveRecipeFromCollectionError {
+    fn from(e: SQLXError) -> Self {
+        Self::UnknownError(e.into())
+    }
+}
@@ -0,0 +1,165 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use async_trait::async_trait;
+use chrono::Utc;
+use eyre::eyre;
+use uuid::Uuid;
+
+use crate::domain::entities::collection::{Collection, CollectionChangeset};
+
+use super::{
+    errors::{
+        AddRecipeToCollectionError, DeleteCollectionError, GetCollectionError,
+        InsertCollectionError, RemoveRecipeFromCollectionError, UpdateCollectionError,
+    },
+    CollectionRepository, CollectionRepositoryService,
+};
+
+pub struct InMemoryCollectionRepository(pub Mutex<HashMap<Uuid, Collection>>);
+
+fn sorted_by_name(mut collections: Vec<Collection>) -> Vec<Collection> {
+    collections.sort_by(|a, b| a.name.cmp(&b.name).then(a.id.cmp(&b.id)));
+
+    collections
+}
+
+#[async_trait]
+impl CollectionRepository for InMemoryCollectionRepository {
+    async fn insert(&self, collection: Collection) -> Result<(), InsertCollectionError> {
+        let mut lock = self.0.lock()?;
+
+        if lock.contains_key(&collection.id) {
+            return Err(InsertCollectionError::Conflict(collection.id));
+        }
+
+        lock.insert(collection.id, collection);
+
+        Ok(())
+    }
+
+    async fn get_by_id(&self, id: &Uuid) -> Result<Collection, GetCollectionError> {
+        let lock = self.0.lock()?;
+
+        lock.get(id)
+            .cloned()
+            .ok_or(GetCollectionError::NotFound(*id))
+    }
+
+    async fn get_all(&self) -> Result<Vec<Collection>, GetCollectionError> {
+        let lock = self.0.lock()?;
+
+        Ok(sorted_by_name(lock.values().cloned().collect()))
+    }
+
+    async fn get_by_recipe(&self, recipe_id: &Uuid) -> Result<Vec<Collection>, GetCollectionError> {
+        let lock = self.0.lock()?;
+
+        Ok(sorted_by_name(
+            lock.values()
+                .filter(|collection| collection.contains(recipe_id))
+                .cloned()
+                .collect(),
+        ))
+    }
+
+    async fn update(
+        &self,
+        collection: &Collection,
+        changeset: CollectionChangeset,
+    ) -> Result<(), UpdateCollectionError> {
+        let mut lock = self.0.lock()?;
+        let collection =
+            lock.get_mut(&collection.id)
+                .ok_or(UpdateCollectionError::UnknownError(eyre!(
+                    "The collection could not be found somehow"
+                )))?;
+
+        if let Some(name) = changeset.name {
+            collection.name = name;
+        }
+        if let Some(description) = changeset.description {
+            collection.description = description;
+        }
+        if let Some(visibility) = changeset.visibility {
+            collection.visibility = visibility;
+        }
+        if let Some(cover_recipe_id) = changeset.cover_recipe_id {
+            collection.cover_recipe_id = Some(cover_recipe_id);
+        }
+        collection.updated_at = Utc::now();
+
+        Ok(())
+    }
+
+    async fn delete(&self, collection: &Collection) -> Result<(), DeleteCollectionError> {
+        let mut lock = self.0.lock()?;
+
+        lock.remove(&collection.id)
+            .ok_or(DeleteCollectionError::UnknownError(eyre!(
+                "The collection could not be found somehow"
+            )))?;
+
+        Ok(())
+    }
+
+    async fn add_recipe(
+        &self,
+        collection: &Collection,
+        recipe_id: &Uuid,
+        position: usize,
+    ) -> Result<(), AddRecipeToCollectionError> {
+        let mut lock = self.0.lock()?;
+        let collection =
+            lock.get_mut(&collection.id)
+                .ok_or(AddRecipeToCollectionError::UnknownError(eyre!(
+                    "The collection could not be found somehow"
+                )))?;
+
+        if collection.contains(recipe_id) {
+            return Err(AddRecipeToCollectionError::AlreadyInCollection(*recipe_id));
+        }
+
+        collection.insert_recipe(*recipe_id, Some(position));
+        collection.updated_at = Utc::now();
+
+        Ok(())
+    }
+
+    async fn remove_recipe(
+        &self,
+        collection: &Collection,
+        recipe_id: &Uuid,
+    ) -> Result<(), RemoveRecipeFromCollectionError> {
+        let mut lock = self.0.lock()?;
+        let collection =
+            lock.get_mut(&collection.id)
+                .ok_or(RemoveRecipeFromCollectionError::UnknownError(eyre!(
+                    "The collection could not be found somehow"
+                )))?;
+
+        if collection.remove_recipe(recipe_id) {
+            collection.updated_at = Utc::now();
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for InMemoryCollectionRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InMemoryCollectionRepository {
+    pub fn new() -> Self {
+        Self(Mutex::new(HashMap::new()))
+    }
+
+    pub fn service(self) -> CollectionRepositoryService {
+        Arc::new(Box::new(self))
+    }
+}
@@ -0,0 +1,52 @@
+pub mod errors;
+pub mod in_memory;
+pub mod postgres;
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::collection::{Collection, CollectionChangeset};
+
+use self::errors::{
+    AddRecipeToCollectionError, DeleteCollectionError, GetCollectionError, InsertCollectionError,
+    RemoveRecipeFromCollectionError, UpdateCollectionError,
+};
+
+#[async_trait]
+pub trait CollectionRepository: Send + Sync + 'static {
+    async fn insert(&self, collection: Collection) -> Result<(), InsertCollectionError>;
+
+    async fn get_by_id(&self, id: &Uuid) -> Result<Collection, GetCollectionError>;
+
+    /// Every collection, ordered by name
+    async fn get_all(&self) -> Result<Vec<Collection>, GetCollectionError>;
+
+    /// Every collection the recipe belongs to, ordered by name
+    async fn get_by_recipe(&self, recipe_id: &Uuid) -> Result<Vec<Collection>, GetCollectionError>;
+
+    async fn update(
+        &self,
+        collection: &Collection,
+        changeset: CollectionChangeset,
+    ) -> Result<(), UpdateCollectionError>;
+
+    async fn delete(&self, collection: &Collection) -> Result<(), DeleteCollectionError>;
+
+    /// Puts the recipe at the given position, moving the recipes after it one place further
+    async fn add_recipe(
+        &self,
+        collection: &Collection,
+        recipe_id: &Uuid,
+        position: usize,
+    ) -> Result<(), AddRecipeToCollectionError>;
+
+    async fn remove_recipe(
+        &self,
+        collection: &Collection,
+        recipe_id: &Uuid,
+    ) -> Result<(), RemoveRecipeFromCollectionError>;
+}
+
+pub type CollectionRepositoryService = Arc<Box<dyn CollectionRepository>>;
@@ -0,0 +1,227 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sqlx::{error::Error as SQLXError, PgPool};
+use uuid::Uuid;
+
+use crate::domain::entities::collection::{Collection, CollectionChangeset, CollectionModel};
+
+use super::{
+    errors::{
+        AddRecipeToCollectionError, DeleteCollectionError, GetCollectionError,
+        InsertCollectionError, RemoveRecipeFromCollectionError, UpdateCollectionError,
+    },
+    CollectionRepository, CollectionRepositoryService,
+};
+
+pub struct PostgresCollectionRepository(pub PgPool);
+
+#[async_trait]
+impl CollectionRepository for PostgresCollectionRepository {
+    #[tracing::instrument(
+        "[COLLECTION REPOSITORY] [POSTGRES] Insert a new collection",
+        skip(self)
+    )]
+    async fn insert(&self, collection: Collection) -> Result<(), InsertCollectionError> {
+        let mut tx = self.0.begin().await?;
+
+        sqlx::query_file!(
+            "queries/collections/insert_collection.sql",
+            collection.id,
+            collection.name,
+            collection.description,
+            collection.visibility.to_string(),
+            collection.owner_id,
+            collection.cover_recipe_id,
+            collection.created_at,
+            collection.updated_at
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| match e {
+            SQLXError::Database(dberror) if dberror.is_unique_violation() => {
+                InsertCollectionError::Conflict(collection.id)
+            }
+            e => e.into(),
+        })?;
+
+        sqlx::query_file!(
+            "queries/collections/insert_collection_recipes.sql",
+            collection.id,
+            &collection.recipes
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(
+        "[COLLECTION REPOSITORY] [POSTGRES] Get collection with ID",
+        skip(self)
+    )]
+    async fn get_by_id(&self, id: &Uuid) -> Result<Collection, GetCollectionError> {
+        let result = sqlx::query_file_as!(
+            CollectionModel,
+            "queries/collections/get_collection.sql",
+            id
+        )
+        .fetch_one(&self.0)
+        .await
+        .map_err(|e| GetCollectionError::with_id(id, e))?;
+
+        Ok(result.try_into()?)
+    }
+
+    #[tracing::instrument("[COLLECTION REPOSITORY] [POSTGRES] Get all collections", skip(self))]
+    async fn get_all(&self) -> Result<Vec<Collection>, GetCollectionError> {
+        let result =
+            sqlx::query_file_as!(CollectionModel, "queries/collections/get_collections.sql")
+                .fetch_all(&self.0)
+                .await?
+                .into_iter()
+                .map(Collection::try_from)
+                .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(result)
+    }
+
+    #[tracing::instrument(
+        "[COLLECTION REPOSITORY] [POSTGRES] Get collections with a recipe",
+        skip(self)
+    )]
+    async fn get_by_recipe(&self, recipe_id: &Uuid) -> Result<Vec<Collection>, GetCollectionError> {
+        let result = sqlx::query_file_as!(
+            CollectionModel,
+            "queries/collections/get_collections_with_recipe.sql",
+            recipe_id
+        )
+        .fetch_all(&self.0)
+        .await?
+        .into_iter()
+        .map(Collection::try_from)
+        .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(result)
+    }
+
+    #[tracing::instrument("[COLLECTION REPOSITORY] [POSTGRES] Update collection", skip(self))]
+    async fn update(
+        &self,
+        collection: &Collection,
+        changeset: CollectionChangeset,
+    ) -> Result<(), UpdateCollectionError> {
+        sqlx::query_file!(
+            "queries/collections/update_collection.sql",
+            collection.id,
+            changeset.name,
+            changeset.description,
+            changeset
+                .visibility
+                .map(|visibility| visibility.to_string()),
+            changeset.cover_recipe_id
+        )
+        .execute(&self.0)
+        .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument("[COLLECTION REPOSITORY] [POSTGRES] Delete collection", skip(self))]
+    async fn delete(&self, collection: &Collection) -> Result<(), DeleteCollectionError> {
+        sqlx::query_file!("queries/collections/delete_collection.sql", collection.id)
+            .execute(&self.0)
+            .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(
+        "[COLLECTION REPOSITORY] [POSTGRES] Add recipe to collection",
+        skip(self)
+    )]
+    async fn add_recipe(
+        &self,
+        collection: &Collection,
+        recipe_id: &Uuid,
+        position: usize,
+    ) -> Result<(), AddRecipeToCollectionError> {
+        let position = position as i32;
+        let mut tx = self.0.begin().await?;
+
+        sqlx::query_file!(
+            "queries/collections/make_room_in_collection.sql",
+            collection.id,
+            position
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query_file!(
+            "queries/collections/add_recipe_to_collection.sql",
+            collection.id,
+            recipe_id,
+            position
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| match e {
+            SQLXError::Database(dberror) if dberror.is_unique_violation() => {
+                AddRecipeToCollectionError::AlreadyInCollection(*recipe_id)
+            }
+            e => e.into(),
+        })?;
+
+        sqlx::query_file!("queries/collections/touch_collection.sql", collection.id)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(
+        "[COLLECTION REPOSITORY] [POSTGRES] Remove recipe from collection",
+        skip(self)
+    )]
+    async fn remove_recipe(
+        &self,
+        collection: &Collection,
+        recipe_id: &Uuid,
+    ) -> Result<(), RemoveRecipeFromCollectionError> {
+        let mut tx = self.0.begin().await?;
+
+        sqlx::query_file!(
+            "queries/collections/unset_cover_recipe.sql",
+            collection.id,
+            recipe_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query_file!(
+            "queries/collections/remove_recipe_from_collection.sql",
+            collection.id,
+            recipe_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+}
+
+impl PostgresCollectionRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self(pool)
+    }
+
+    pub fn service(self) -> CollectionRepositoryService {
+        Arc::new(Box::new(self))
+    }
+}
@@ -0,0 +1,97 @@
+use eyre::eyre;
+use sqlx::Error as SQLXError;
+use std::sync::PoisonError;
+use thiserror::Error;
+use uuid::Uuid;
+
+const POISONED: &str = "Cooking session repository lock was poisoned during a previous access and can no longer be locked";
+
+#[derive(Error, Debug)]
+pub enum InsertCookingSessionError {
+    #[error(transparent)]
+    UnknownError(#[from] eyre::Error),
+}
+
+impl<T> From<PoisonError<T>> for InsertCookingSessionError {
+    fn from(_value: PoisonError<T>) -> Self {
+        eyre!(POISONED).into()
+    }
+}
+
+impl From<SQLXError> for InsertCookingSessionError {
+    fn from(e: SQLXError) -> Self {
+        Self::UnknownError(e.into())
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum GetCookingSessionError {
+    #[error("The cooking session with ID of {0} was not found")]
+    NotFound(Uuid),
+
+    #[error(transparent)]
+    UnknownError(#[from] eyre::Error),
+}
+
+impl GetCookingSessionError {
+    pub fn with_id(id: &Uuid, e: SQLXError) -> Self {
+        match e {
+            SQLXError::RowNotFound => Self::NotFound(*id),
+            _ => Self::UnknownError(e.into()),
+        }
+    }
+}
+
+impl<T> From<PoisonError<T>> for GetCookingSessionError {
+    fn from(_value: PoisonError<T>) -> Self {
+        eyre!(POISONED).into()
+    }
+}
+
+impl From<serde_json::Error> for GetCookingSessionError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::UnknownError(e.into())
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum UpdateCookingSessionError {
+    #[error(transparent)]
+    UnknownError(#[from] eyre::Error),
+}
+
+impl<T> From<PoisonError<T>> for UpdateCookingSessionError {
+    fn from(_value: PoisonError<T>) -> Self {
+        eyre!(POISONED).into()
+    }
+}
+
+impl From<SQLXError> for UpdateCookingSessionError {
+    fn from(e: SQLXError) -> Self {
+        Self::UnknownError(e.into())
+    }
+}
+
+impl From<serde_json::Error> for UpdateCookingSessionError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::UnknownError(e.into())
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum DeleteCookingSessionError {
+    #[error(transparent)]
+    UnknownError(#[from] eyre::Error),
+}
+
+impl<T> From<PoisonError<T>> for DeleteCookingSessionError {
+    fn from(_value: PoisonError<T>) -> Self {
+        eyre!(POISONED).into()
+    }
+}
+
+impl From<SQLXError> for DeleteCookingSessionError {
+    fn from(e: SQLXError) -> Self {
+        Self::UnknownError(e.into())
+    }
+}
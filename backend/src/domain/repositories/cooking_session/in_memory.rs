@@ -0,0 +1,89 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use async_trait::async_trait;
+use eyre::eyre;
+use uuid::Uuid;
+
+use crate::domain::entities::cooking_session::CookingSession;
+
+use super::{
+    errors::{
+        DeleteCookingSessionError, GetCookingSessionError, InsertCookingSessionError,
+        UpdateCookingSessionError,
+    },
+    CookingSessionRepository, CookingSessionRepositoryService,
+};
+
+pub struct InMemoryCookingSessionRepository(pub Mutex<HashMap<Uuid, CookingSession>>);
+
+#[async_trait]
+impl CookingSessionRepository for InMemoryCookingSessionRepository {
+    async fn insert(&self, session: CookingSession) -> Result<(), InsertCookingSessionError> {
+        let mut lock = self.0.lock()?;
+
+        lock.insert(session.id, session);
+
+        Ok(())
+    }
+
+    async fn get_by_id(&self, id: &Uuid) -> Result<CookingSession, GetCookingSessionError> {
+        let lock = self.0.lock()?;
+
+        lock.get(id)
+            .cloned()
+            .ok_or(GetCookingSessionError::NotFound(*id))
+    }
+
+    async fn get_with_running_timers(&self) -> Result<Vec<CookingSession>, GetCookingSessionError> {
+        let lock = self.0.lock()?;
+
+        Ok(lock
+            .values()
+            .filter(|session| session.timers.iter().any(|timer| !timer.finished))
+            .cloned()
+            .collect())
+    }
+
+    async fn update(&self, session: &CookingSession) -> Result<(), UpdateCookingSessionError> {
+        let mut lock = self.0.lock()?;
+
+        let stored = lock
+            .get_mut(&session.id)
+            .ok_or(UpdateCookingSessionError::UnknownError(eyre!(
+                "The cooking session could not be found somehow"
+            )))?;
+        *stored = session.clone();
+
+        Ok(())
+    }
+
+    async fn delete(&self, session: &CookingSession) -> Result<(), DeleteCookingSessionError> {
+        let mut lock = self.0.lock()?;
+
+        lock.remove(&session.id)
+            .ok_or(DeleteCookingSessionError::UnknownError(eyre!(
+                "The cooking session could not be found somehow"
+            )))?;
+
+        Ok(())
+    }
+}
+
+impl Default for InMemoryCookingSessionRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InMemoryCookingSessionRepository {
+    pub fn new() -> Self {
+        Self(Mutex::new(HashMap::new()))
+    }
+
+    pub fn service(self) -> CookingSessionRepositoryService {
+        Arc::new(Box::new(self))
+    }
+}
@@ -0,0 +1,33 @@
+pub mod errors;
+pub mod in_memory;
+pub mod postgres;
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::cooking_session::CookingSession;
+
+use self::errors::{
+    DeleteCookingSessionError, GetCookingSessionError, InsertCookingSessionError,
+    UpdateCookingSessionError,
+};
+
+#[async_trait]
+pub trait CookingSessionRepository: Send + Sync + 'static {
+    // TODO: Include user information
+    async fn insert(&self, session: CookingSession) -> Result<(), InsertCookingSessionError>;
+
+    async fn get_by_id(&self, id: &Uuid) -> Result<CookingSession, GetCookingSessionError>;
+
+    /// The sessions with at least one timer that has not finished yet
+    async fn get_with_running_timers(&self) -> Result<Vec<CookingSession>, GetCookingSessionError>;
+
+    /// Saves the whole state of the session
+    async fn update(&self, session: &CookingSession) -> Result<(), UpdateCookingSessionError>;
+
+    async fn delete(&self, session: &CookingSession) -> Result<(), DeleteCookingSessionError>;
+}
+
+pub type CookingSessionRepositoryService = Arc<Box<dyn CookingSessionRepository>>;
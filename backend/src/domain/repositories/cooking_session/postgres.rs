@@ -0,0 +1,128 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::entities::cooking_session::{CookingSession, CookingSessionModel};
+
+use super::{
+    errors::{
+        DeleteCookingSessionError, GetCookingSessionError, InsertCookingSessionError,
+        UpdateCookingSessionError,
+    },
+    CookingSessionRepository, CookingSessionRepositoryService,
+};
+
+pub struct PostgresCookingSessionRepository(pub PgPool);
+
+#[async_trait]
+impl CookingSessionRepository for PostgresCookingSessionRepository {
+    #[tracing::instrument(
+        "[COOKING SESSION REPOSITORY] [POSTGRES] Start a cooking session",
+        skip(self)
+    )]
+    async fn insert(&self, session: CookingSession) -> Result<(), InsertCookingSessionError> {
+        let timers = serde_json::to_value(&session.timers)
+            .map_err(|e| InsertCookingSessionError::UnknownError(e.into()))?;
+        let checked_ingredients: Vec<Uuid> = session.checked_ingredients.into_iter().collect();
+
+        sqlx::query_file!(
+            "queries/cooking_sessions/insert_cooking_session.sql",
+            session.id,
+            session.recipe_id,
+            session.current_step as i32,
+            &checked_ingredients,
+            timers,
+            session.created_at,
+            session.updated_at
+        )
+        .execute(&self.0)
+        .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(
+        "[COOKING SESSION REPOSITORY] [POSTGRES] Get cooking session by ID",
+        skip(self)
+    )]
+    async fn get_by_id(&self, id: &Uuid) -> Result<CookingSession, GetCookingSessionError> {
+        let result = sqlx::query_file_as!(
+            CookingSessionModel,
+            "queries/cooking_sessions/get_cooking_session.sql",
+            id
+        )
+        .fetch_one(&self.0)
+        .await
+        .map_err(|e| GetCookingSessionError::with_id(id, e))?;
+
+        Ok(result.try_into()?)
+    }
+
+    #[tracing::instrument(
+        "[COOKING SESSION REPOSITORY] [POSTGRES] Get cooking sessions with running timers",
+        skip(self)
+    )]
+    async fn get_with_running_timers(&self) -> Result<Vec<CookingSession>, GetCookingSessionError> {
+        let result = sqlx::query_file_as!(
+            CookingSessionModel,
+            "queries/cooking_sessions/get_cooking_sessions_with_running_timers.sql"
+        )
+        .fetch_all(&self.0)
+        .await
+        .map_err(|e| GetCookingSessionError::UnknownError(e.into()))?;
+
+        Ok(result
+            .into_iter()
+            .map(CookingSession::try_from)
+            .collect::<Result<_, _>>()?)
+    }
+
+    #[tracing::instrument(
+        "[COOKING SESSION REPOSITORY] [POSTGRES] Update a cooking session",
+        skip(self)
+    )]
+    async fn update(&self, session: &CookingSession) -> Result<(), UpdateCookingSessionError> {
+        let timers = serde_json::to_value(&session.timers)?;
+        let checked_ingredients: Vec<Uuid> = session.checked_ingredients.iter().copied().collect();
+
+        sqlx::query_file!(
+            "queries/cooking_sessions/update_cooking_session.sql",
+            session.id,
+            session.current_step as i32,
+            &checked_ingredients,
+            timers,
+            session.updated_at
+        )
+        .execute(&self.0)
+        .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(
+        "[COOKING SESSION REPOSITORY] [POSTGRES] Delete a cooking session",
+        skip(self)
+    )]
+    async fn delete(&self, session: &CookingSession) -> Result<(), DeleteCookingSessionError> {
+        sqlx::query_file!(
+            "queries/cooking_sessions/delete_cooking_session.sql",
+            session.id
+        )
+        .execute(&self.0)
+        .await?;
+
+        Ok(())
+    }
+}
+
+impl PostgresCookingSessionRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self(pool)
+    }
+
+    pub fn service(self) -> CookingSessionRepositoryService {
+        Arc::new(Box::new(self))
+    }
+}
@@ -0,0 +1,77 @@
+use eyre::eyre;
+use sqlx::Error as SQLXError;
+use std::sync::PoisonError;
+use thiserror::Error;
+use uuid::Uuid;
+
+const POISONED: &str =
+    "Favorite repository lock was poisoned during a previous access and can no longer be locked";
+
+#[derive(Error, Debug)]
+pub enum InsertFavoriteError {
+    #[error("The recipe with ID of {0} is already a favorite")]
+    AlreadyFavorite(Uuid),
+
+    #[error(transparent)]
+    UnknownError(#[from] eyre::Error),
+}
+
+impl<T> From<PoisonError<T>> for InsertFavoriteError {
+    fn from(_value: PoisonError<T>) -> Self {
+        eyre!(POISONED).into()
+    }
+}
+
+impl From<SQLXError> for InsertFavoriteError {
+    fn from(e: SQLXError) -> Self {
+        Self::UnknownError(e.into())
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum GetFavoriteError {
+    #[error("The recipe with ID of {0} is not a favorite")]
+    NotFound(Uuid),
+
+    #[error(transparent)]
+    UnknownError(#[from] eyre::Error),
+}
+
+impl GetFavoriteError {
+    pub fn with_id(id: &Uuid, e: SQLXError) -> Self {
+        match e {
+            SQLXError::RowNotFound => Self::NotFound(*id),
+            _ => Self::UnknownError(e.into()),
+        }
+    }
+}
+
+impl<T> From<PoisonError<T>> for GetFavoriteError {
+    fn from(_value: PoisonError<T>) -> Self {
+        eyre!(POISONED).into()
+    }
+}
+
+impl From<SQLXError> for GetFavoriteError {
+    fn from(e: SQLXError) -> Self {
+        Self::UnknownError(e.into())
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum DeleteFavoriteError {
+    #[error(transparent)]
+    UnknownError(#[from] eyre::Error),
+}
+
+impl<T> From<PoisonError<T>> for DeleteFavoriteError {
+    fn from(_value: PoisonError<T>) -> Self {
+        eyre!(POISONED).into()
+    }
+}
+
+impl From<SQLXError> for DeleteFavoriteError {
+    fn from(e: SQLXError) -> Self {
+        Self::UnknownError(e.into())
+    }
+}
@@ -0,0 +1,86 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use async_trait::async_trait;
+use eyre::eyre;
+use uuid::Uuid;
+
+use crate::domain::entities::favorite::Favorite;
+
+use super::{
+    errors::{DeleteFavoriteError, GetFavoriteError, InsertFavoriteError},
+    FavoriteRepository, FavoriteRepositoryService,
+};
+
+/// Favorites by user and recipe
+pub struct InMemoryFavoriteRepository(pub Mutex<HashMap<(Uuid, Uuid), Favorite>>);
+
+#[async_trait]
+impl FavoriteRepository for InMemoryFavoriteRepository {
+    async fn insert(&self, favorite: Favorite) -> Result<(), InsertFavoriteError> {
+        let mut lock = self.0.lock()?;
+
+        let key = (favorite.user_id, favorite.recipe_id);
+
+        if lock.contains_key(&key) {
+            return Err(InsertFavoriteError::AlreadyFavorite(favorite.recipe_id));
+        }
+
+        lock.insert(key, favorite);
+
+        Ok(())
+    }
+
+    async fn get(&self, user_id: &Uuid, recipe_id: &Uuid) -> Result<Favorite, GetFavoriteError> {
+        let lock = self.0.lock()?;
+
+        lock.get(&(*user_id, *recipe_id))
+            .cloned()
+            .ok_or(GetFavoriteError::NotFound(*recipe_id))
+    }
+
+    async fn get_all(&self, user_id: &Uuid) -> Result<Vec<Favorite>, GetFavoriteError> {
+        let lock = self.0.lock()?;
+
+        let mut result: Vec<Favorite> = lock
+            .values()
+            .filter(|favorite| &favorite.user_id == user_id)
+            .cloned()
+            .collect();
+        result.sort_by(|a, b| {
+            b.created_at
+                .cmp(&a.created_at)
+                .then_with(|| a.recipe_id.cmp(&b.recipe_id))
+        });
+
+        Ok(result)
+    }
+
+    async fn delete(&self, favorite: &Favorite) -> Result<(), DeleteFavoriteError> {
+        let mut lock = self.0.lock()?;
+
+        lock.remove(&(favorite.user_id, favorite.recipe_id)).ok_or(
+            DeleteFavoriteError::UnknownError(eyre!("The favorite could not be found somehow")),
+        )?;
+
+        Ok(())
+    }
+}
+
+impl Default for InMemoryFavoriteRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InMemoryFavoriteRepository {
+    pub fn new() -> Self {
+        Self(Mutex::new(HashMap::new()))
+    }
+
+    pub fn service(self) -> FavoriteRepositoryService {
+        Arc::new(Box::new(self))
+    }
+}
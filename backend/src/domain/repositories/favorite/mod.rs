@@ -0,0 +1,26 @@
+pub mod errors;
+pub mod in_memory;
+pub mod postgres;
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::favorite::Favorite;
+
+use self::errors::{DeleteFavoriteError, GetFavoriteError, InsertFavoriteError};
+
+#[async_trait]
+pub trait FavoriteRepository: Send + Sync + 'static {
+    async fn insert(&self, favorite: Favorite) -> Result<(), InsertFavoriteError>;
+
+    async fn get(&self, user_id: &Uuid, recipe_id: &Uuid) -> Result<Favorite, GetFavoriteError>;
+
+    /// Every favorite of the user, the most recently added first
+    async fn get_all(&self, user_id: &Uuid) -> Result<Vec<Favorite>, GetFavoriteError>;
+
+    async fn delete(&self, favorite: &Favorite) -> Result<(), DeleteFavoriteError>;
+}
+
+pub type FavoriteRepositoryService = Arc<Box<dyn FavoriteRepository>>;
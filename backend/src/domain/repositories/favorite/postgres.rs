@@ -0,0 +1,84 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sqlx::{error::Error as SQLXError, PgPool};
+use uuid::Uuid;
+
+use crate::domain::entities::favorite::Favorite;
+
+use super::{
+    errors::{DeleteFavoriteError, GetFavoriteError, InsertFavoriteError},
+    FavoriteRepository, FavoriteRepositoryService,
+};
+
+pub struct PostgresFavoriteRepository(pub PgPool);
+
+#[async_trait]
+impl FavoriteRepository for PostgresFavoriteRepository {
+    #[tracing::instrument("[FAVORITE REPOSITORY] [POSTGRES] Add a favorite", skip(self))]
+    async fn insert(&self, favorite: Favorite) -> Result<(), InsertFavoriteError> {
+        sqlx::query_file!(
+            "queries/favorites/insert_favorite.sql",
+            favorite.user_id,
+            favorite.recipe_id,
+            favorite.created_at
+        )
+        .execute(&self.0)
+        .await
+        .map_err(|e| match e {
+            SQLXError::Database(dberror) if dberror.is_unique_violation() => {
+                InsertFavoriteError::AlreadyFavorite(favorite.recipe_id)
+            }
+            e => e.into(),
+        })?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument("[FAVORITE REPOSITORY] [POSTGRES] Get favorite of recipe", skip(self))]
+    async fn get(&self, user_id: &Uuid, recipe_id: &Uuid) -> Result<Favorite, GetFavoriteError> {
+        let result = sqlx::query_file_as!(
+            Favorite,
+            "queries/favorites/get_favorite.sql",
+            user_id,
+            recipe_id
+        )
+        .fetch_one(&self.0)
+        .await
+        .map_err(|e| GetFavoriteError::with_id(recipe_id, e))?;
+
+        Ok(result)
+    }
+
+    #[tracing::instrument("[FAVORITE REPOSITORY] [POSTGRES] Get all favorites", skip(self))]
+    async fn get_all(&self, user_id: &Uuid) -> Result<Vec<Favorite>, GetFavoriteError> {
+        let result = sqlx::query_file_as!(Favorite, "queries/favorites/get_favorites.sql", user_id)
+            .fetch_all(&self.0)
+            .await?;
+
+        Ok(result)
+    }
+
+    #[tracing::instrument("[FAVORITE REPOSITORY] [POSTGRES] Remove a favorite", skip(self))]
+    async fn delete(&self, favorite: &Favorite) -> Result<(), DeleteFavoriteError> {
+        sqlx::query_file!(
+            "queries/favorites/delete_favorite.sql",
+            favorite.user_id,
+            favorite.recipe_id
+        )
+        .execute(&self.0)
+        .await?;
+
+        Ok(())
+    }
+}
+
+impl PostgresFavoriteRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self(pool)
+    }
+
+    pub fn service(self) -> FavoriteRepositoryService {
+        Arc::new(Box::new(self))
+    }
+}
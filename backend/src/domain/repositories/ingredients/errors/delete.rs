@@ -1,3 +1,4 @@
+use crate::domain::repositories::outbox::errors::InsertOutboxEventError;
 use eyre::eyre;
 use std::sync::PoisonError;
 use thiserror::Error;
@@ -19,3 +20,9 @@ impl From<sqlx::Error> for DeleteIngredientError {
         Self::UnknownError(e.into())
     }
 }
+
+impl From<InsertOutboxEventError> for DeleteIngredientError {
+    fn from(e: InsertOutboxEventError) -> Self {
+        Self::UnknownError(e.into())
+    }
+}
@@ -3,6 +3,7 @@ use std::sync::PoisonError;
 use thiserror::Error;
 
 use crate::domain::entities::ingredient::errors::ValidationError;
+use crate::domain::repositories::outbox::errors::InsertOutboxEventError;
 
 #[derive(Error, Debug)]
 pub enum InsertIngredientError {
@@ -21,3 +22,15 @@ impl<T> From<PoisonError<T>> for InsertIngredientError {
         eyre!("Ingredient repository lock was poisoned during a previous access and can no longer be locked").into()
     }
 }
+
+impl From<InsertOutboxEventError> for InsertIngredientError {
+    fn from(e: InsertOutboxEventError) -> Self {
+        Self::UnknownError(e.into())
+    }
+}
+
+impl From<sqlx::Error> for InsertIngredientError {
+    fn from(value: sqlx::Error) -> Self {
+        Self::UnknownError(value.into())
+    }
+}
@@ -3,6 +3,7 @@ use std::sync::PoisonError;
 use thiserror::Error;
 
 use crate::domain::entities::ingredient::errors::ValidationError;
+use crate::domain::repositories::outbox::errors::InsertOutboxEventError;
 
 #[derive(Error, Debug)]
 pub enum UpdateIngredientError {
@@ -27,3 +28,9 @@ impl From<sqlx::Error> for UpdateIngredientError {
         Self::UnknownError(value.into())
     }
 }
+
+impl From<InsertOutboxEventError> for UpdateIngredientError {
+    fn from(e: InsertOutboxEventError) -> Self {
+        Self::UnknownError(e.into())
+    }
+}
@@ -1,14 +1,17 @@
 use std::{
     collections::{BTreeMap, HashSet},
-    sync::Mutex,
+    sync::{Arc, Mutex},
 };
 
 use async_trait::async_trait;
+use chrono::Utc;
 use uuid::Uuid;
 
-use crate::domain::entities::ingredient::{
-    errors::ValidationError, Ingredient, IngredientChangeset,
+use crate::domain::entities::{
+    event::DomainEvent,
+    ingredient::{errors::ValidationError, Ingredient, IngredientChangeset},
 };
+use crate::domain::repositories::outbox::in_memory::InMemoryOutboxRepository;
 
 use super::{
     errors::{
@@ -18,7 +21,12 @@ use super::{
     IngredientRepository,
 };
 
-pub struct InMemoryIngredientRepository(pub Mutex<BTreeMap<Uuid, Ingredient>>);
+/// Clones share the same ingredients, so that a unit of work can see them
+#[derive(Clone)]
+pub struct InMemoryIngredientRepository(
+    pub Arc<Mutex<BTreeMap<Uuid, Ingredient>>>,
+    pub InMemoryOutboxRepository,
+);
 
 #[async_trait]
 impl IngredientRepository for InMemoryIngredientRepository {
@@ -43,6 +51,8 @@ impl IngredientRepository for InMemoryIngredientRepository {
         };
 
         lock.insert(ingredient.id, ingredient.clone());
+        self.1
+            .record(DomainEvent::IngredientCreated(ingredient.clone()))?;
 
         Ok(ingredient)
     }
@@ -106,6 +116,10 @@ impl IngredientRepository for InMemoryIngredientRepository {
             ingredient.diet_violations = new_diets.into();
         }
 
+        ingredient.updated_at = Utc::now();
+        self.1
+            .record(DomainEvent::IngredientUpdated(ingredient.clone()))?;
+
         Ok(())
     }
 
@@ -113,6 +127,8 @@ impl IngredientRepository for InMemoryIngredientRepository {
     async fn delete(&self, ingredient: Ingredient) -> Result<(), DeleteIngredientError> {
         let mut lock = self.0.lock()?;
         lock.remove(&ingredient.id);
+        self.1
+            .record(DomainEvent::IngredientDeleted(ingredient.id))?;
 
         Ok(())
     }
@@ -149,6 +165,12 @@ impl InMemoryIngredientRepository {
     pub fn new() -> Self {
         BTreeMap::new().into()
     }
+
+    /// Records the events into the given outbox instead of one of its own
+    pub fn with_outbox(mut self, outbox: InMemoryOutboxRepository) -> Self {
+        self.1 = outbox;
+        self
+    }
 }
 
 impl Default for InMemoryIngredientRepository {
@@ -159,6 +181,6 @@ impl Default for InMemoryIngredientRepository {
 
 impl From<BTreeMap<Uuid, Ingredient>> for InMemoryIngredientRepository {
     fn from(value: BTreeMap<Uuid, Ingredient>) -> Self {
-        Self(value.into())
+        Self(Arc::new(Mutex::new(value)), InMemoryOutboxRepository::new())
     }
 }
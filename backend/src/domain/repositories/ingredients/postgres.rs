@@ -1,11 +1,14 @@
 use std::{collections::HashMap, sync::OnceLock};
 
-use crate::domain::entities::ingredient::{
-    errors::ValidationError, Ingredient, IngredientChangeset, IngredientModel,
+use crate::domain::entities::{
+    event::DomainEvent,
+    ingredient::{errors::ValidationError, Ingredient, IngredientChangeset, IngredientModel},
 };
+use crate::domain::repositories::outbox::postgres::record_event;
+use crate::domain::repositories::unit_of_work::postgres::PostgresExecutor;
 use async_trait::async_trait;
 use rayon::iter::{IntoParallelIterator, IntoParallelRefIterator, ParallelIterator};
-use sqlx::{error::Error as SQLXError, PgPool};
+use sqlx::{error::Error as SQLXError, Connection, PgPool};
 use uuid::Uuid;
 
 use super::{
@@ -16,7 +19,7 @@ use super::{
     IngredientRepository,
 };
 
-pub struct PostgresIngredientRepository(pub PgPool);
+pub struct PostgresIngredientRepository(pub PostgresExecutor);
 
 /// Turns out Postgres doesn't return the column name for unique constraints isn't returned.
 /// This function maps constraints to fields
@@ -43,15 +46,19 @@ impl IngredientRepository for PostgresIngredientRepository {
             .map(|d| d.to_string())
             .collect();
 
-        let ingredient = sqlx::query_file_as!(
+        let mut conn = self.0.acquire().await?;
+        let mut tx = conn.begin().await?;
+
+        let ingredient: Ingredient = sqlx::query_file_as!(
             IngredientModel,
             "queries/ingredients/insert_ingredient.sql",
             ingredient.id,
             &ingredient.name,
             &ingredient.description,
-            &diet_violations
+            &diet_violations,
+            ingredient.updated_at
         )
-        .fetch_one(&self.0)
+        .fetch_one(&mut *tx)
         .await
         .map_err(|e| match e {
             SQLXError::Database(dberror) if dberror.is_unique_violation() => {
@@ -60,9 +67,14 @@ impl IngredientRepository for PostgresIngredientRepository {
                 )
             }
             _ => InsertIngredientError::UnknownError(e.into()),
-        })?;
+        })?
+        .try_into()?;
 
-        Ok(ingredient.try_into()?)
+        record_event(&mut tx, DomainEvent::IngredientCreated(ingredient.clone())).await?;
+
+        tx.commit().await?;
+
+        Ok(ingredient)
     }
 
     #[tracing::instrument(
@@ -70,12 +82,18 @@ impl IngredientRepository for PostgresIngredientRepository {
         skip(self)
     )]
     async fn get_by_id(&self, id: &Uuid) -> Result<Ingredient, GetIngredientByIdError> {
+        let mut conn = self
+            .0
+            .acquire()
+            .await
+            .map_err(|e| GetIngredientByIdError::UnknownError(e.into()))?;
+
         let ingredient = sqlx::query_file_as!(
             IngredientModel,
             "queries/ingredients/get_ingredient_by_id.sql",
             id
         )
-        .fetch_one(&self.0)
+        .fetch_one(&mut *conn)
         .await
         .map_err(|e| match e {
             SQLXError::RowNotFound => GetIngredientByIdError::NotFound(*id),
@@ -87,11 +105,13 @@ impl IngredientRepository for PostgresIngredientRepository {
 
     #[tracing::instrument("[INGREDIENT REPOSITORY] [POSTGRES] Get all ingredients", skip(self))]
     async fn get_all(&self) -> Result<Vec<Ingredient>, GetAllIngredientsError> {
+        let mut conn = self.0.acquire().await?;
+
         let ingredients = sqlx::query_file_as!(
             IngredientModel,
             "queries/ingredients/get_all_ingredients.sql",
         )
-        .fetch_all(&self.0)
+        .fetch_all(&mut *conn)
         .await?
         .par_iter()
         .filter_map(|i| i.try_into().ok())
@@ -119,7 +139,9 @@ impl IngredientRepository for PostgresIngredientRepository {
             ));
         };
 
-        let tx = self.0.begin().await?;
+        let mut conn = self.0.acquire().await?;
+        let mut tx = conn.begin().await?;
+        let mut updated = false;
 
         if let Some(name) = name {
             if name != ingredient_to_update.name {
@@ -133,8 +155,9 @@ impl IngredientRepository for PostgresIngredientRepository {
                     id,
                     name,
                 )
-                .execute(&self.0)
+                .execute(&mut *tx)
                 .await?;
+                updated = true;
             }
         };
 
@@ -150,8 +173,9 @@ impl IngredientRepository for PostgresIngredientRepository {
                     id,
                     description,
                 )
-                .execute(&self.0)
+                .execute(&mut *tx)
                 .await?;
+                updated = true;
             }
         };
 
@@ -167,11 +191,29 @@ impl IngredientRepository for PostgresIngredientRepository {
                     id,
                     &diet_violations
                 )
-                .execute(&self.0)
+                .execute(&mut *tx)
                 .await?;
+                updated = true;
             }
         };
 
+        if updated {
+            sqlx::query_file!("queries/ingredients/update_ingredient_timestamps.sql", id)
+                .execute(&mut *tx)
+                .await?;
+
+            let ingredient: Ingredient = sqlx::query_file_as!(
+                IngredientModel,
+                "queries/ingredients/get_ingredient_by_id.sql",
+                id
+            )
+            .fetch_one(&mut *tx)
+            .await?
+            .try_into()?;
+
+            record_event(&mut tx, DomainEvent::IngredientUpdated(ingredient)).await?;
+        }
+
         tx.commit().await?;
 
         Ok(())
@@ -179,20 +221,29 @@ impl IngredientRepository for PostgresIngredientRepository {
 
     #[tracing::instrument("[INGREDIENT REPOSITORY] [POSTGRES] Delete an ingredient", skip(self))]
     async fn delete(&self, ingredient: Ingredient) -> Result<(), DeleteIngredientError> {
+        let mut conn = self.0.acquire().await?;
+        let mut tx = conn.begin().await?;
+
         sqlx::query_file!("queries/ingredients/delete_ingredient.sql", ingredient.id)
-            .execute(&self.0)
+            .execute(&mut *tx)
             .await?;
 
+        record_event(&mut tx, DomainEvent::IngredientDeleted(ingredient.id)).await?;
+
+        tx.commit().await?;
+
         Ok(())
     }
 
     async fn get_all_by_id(&self, ids: &[Uuid]) -> Result<Vec<Ingredient>, GetAllIngredientsError> {
+        let mut conn = self.0.acquire().await?;
+
         let results: Result<Vec<Ingredient>, GetAllIngredientsError> = sqlx::query_file_as!(
             IngredientModel,
             "queries/ingredients/get_all_ingredients_by_id.sql",
             ids
         )
-        .fetch_all(&self.0)
+        .fetch_all(&mut *conn)
         .await
         .map_err(|e| match e {
             SQLXError::RowNotFound => {
@@ -227,6 +278,34 @@ impl IngredientRepository for PostgresIngredientRepository {
 
 impl PostgresIngredientRepository {
     pub fn new(pool: PgPool) -> Self {
-        Self(pool)
+        Self(pool.into())
+    }
+
+    /// Like `get_by_id`, but nobody else can change the ingredient until the transaction the
+    /// repository runs in ends
+    #[tracing::instrument(
+        "[INGREDIENT REPOSITORY] [POSTGRES] Lock ingredient with ID",
+        skip(self)
+    )]
+    pub(crate) async fn lock(&self, id: &Uuid) -> Result<Ingredient, GetIngredientByIdError> {
+        let mut conn = self
+            .0
+            .acquire()
+            .await
+            .map_err(|e| GetIngredientByIdError::UnknownError(e.into()))?;
+
+        let ingredient = sqlx::query_file_as!(
+            IngredientModel,
+            "queries/ingredients/get_ingredient_by_id_for_update.sql",
+            id
+        )
+        .fetch_one(&mut *conn)
+        .await
+        .map_err(|e| match e {
+            SQLXError::RowNotFound => GetIngredientByIdError::NotFound(*id),
+            _ => GetIngredientByIdError::UnknownError(e.into()),
+        })?;
+
+        Ok(ingredient.try_into()?)
     }
 }
@@ -0,0 +1,163 @@
+use eyre::eyre;
+use sqlx::Error as SQLXError;
+use std::sync::PoisonError;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::domain::entities::recipe::errors::ValidationError;
+
+const POISONED: &str =
+    "Meal plan repository lock was poisoned during a previous access and can no longer be locked";
+
+#[derive(Error, Debug)]
+pub enum InsertMealPlanEntryError {
+    #[error("The meal plan entry with ID of {0} already exists")]
+    Conflict(Uuid),
+
+    #[error(transparent)]
+    UnknownError(#[from] eyre::Error),
+}
+
+impl<T> From<PoisonError<T>> for InsertMealPlanEntryError {
+    fn from(_value: PoisonError<T>) -> Self {
+        eyre!(POISONED).into()
+    }
+}
+
+impl From<SQLXError> for InsertMealPlanEntryError {
+    fn from(e: SQLXError) -> Self {
+        Self::UnknownError(e.into())
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum GetMealPlanEntryError {
+    #[error("The meal plan entry with ID of {0} was not found")]
+    NotFound(Uuid),
+
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError),
+
+    #[error(transparent)]
+    UnknownError(#[from] eyre::Error),
+}
+
+impl GetMealPlanEntryError {
+    pub fn with_id(id: &Uuid, e: SQLXError) -> Self {
+        match e {
+            SQLXError::RowNotFound => Self::NotFound(*id),
+            _ => Self::UnknownError(e.into()),
+        }
+    }
+}
+
+impl<T> From<PoisonError<T>> for GetMealPlanEntryError {
+    fn from(_value: PoisonError<T>) -> Self {
+        eyre!(POISONED).into()
+    }
+}
+
+impl From<SQLXError> for GetMealPlanEntryError {
+    fn from(e: SQLXError) -> Self {
+        Self::UnknownError(e.into())
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum UpdateMealPlanEntryError {
+    #[error(transparent)]
+    UnknownError(#[from] eyre::Error),
+}
+
+impl<T> From<PoisonError<T>> for UpdateMealPlanEntryError {
+    fn from(_value: PoisonError<T>) -> Self {
+        eyre!(POISONED).into()
+    }
+}
+
+impl From<SQLXError> for UpdateMealPlanEntryError {
+    fn from(e: SQLXError) -> Self {
+        Self::UnknownError(e.into())
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum DeleteMealPlanEntryError {
+    #[error(transparent)]
+    UnknownError(#[from] eyre::Error),
+}
+
+impl<T> From<PoisonError<T>> for DeleteMealPlanEntryError {
+    fn from(_value: PoisonError<T>) -> Self {
+        eyre!(POISONED).into()
+    }
+}
+
+impl From<SQLXError> for DeleteMealPlanEntryError {
+    fn from(e: SQLXError) -> Self {
+        Self::UnknownError(e.into())
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum InsertCalendarFeedError {
+    #[error("The calendar feed with ID of {0} already exists")]
+    Conflict(Uuid),
+
+    #[error(transparent)]
+    UnknownError(#[from] eyre::Error),
+}
+
+impl<T> From<PoisonError<T>> for InsertCalendarFeedError {
+    fn from(_value: PoisonError<T>) -> Self {
+        eyre!(POISONED).into()
+    }
+}
+
+impl From<SQLXError> for InsertCalendarFeedError {
+    fn from(e: SQLXError) -> Self {
+        Self::UnknownError(e.into())
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum GetCalendarFeedError {
+    #[error("The calendar feed was not found")]
+    NotFound,
+
+    #[error(transparent)]
+    UnknownError(#[from] eyre::Error),
+}
+
+impl<T> From<PoisonError<T>> for GetCalendarFeedError {
+    fn from(_value: PoisonError<T>) -> Self {
+        eyre!(POISONED).into()
+    }
+}
+
+impl From<SQLXError> for GetCalendarFeedError {
+    fn from(e: SQLXError) -> Self {
+        match e {
+            SQLXError::RowNotFound => Self::NotFound,
+            _ => Self::UnknownError(e.into()),
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum DeleteCalendarFeedError {
+    #[error(transparent)]
+    UnknownError(#[from] eyre::Error),
+}
+
+impl<T> From<PoisonError<T>> for DeleteCalendarFeedError {
+    fn from(_value: PoisonError<T>) -> Self {
+        eyre!(POISONED).into()
+    }
+}
+
+impl From<SQLXError> for DeleteCalendarFeedError {
+    fn from(e: SQLXError) -> Self {
+        Self::UnknownError(e.into())
+    }
+}
@@ -0,0 +1,163 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use async_trait::async_trait;
+use chrono::{NaiveDate, Utc};
+use eyre::eyre;
+use uuid::Uuid;
+
+use crate::domain::entities::meal_plan::{CalendarFeed, MealPlanEntry, MealPlanEntryChangeset};
+
+use super::{
+    errors::{
+        DeleteCalendarFeedError, DeleteMealPlanEntryError, GetCalendarFeedError,
+        GetMealPlanEntryError, InsertCalendarFeedError, InsertMealPlanEntryError,
+        UpdateMealPlanEntryError,
+    },
+    MealPlanRepository, MealPlanRepositoryService,
+};
+
+pub struct InMemoryMealPlanRepository {
+    entries: Mutex<HashMap<Uuid, MealPlanEntry>>,
+    feeds: Mutex<HashMap<Uuid, CalendarFeed>>,
+}
+
+#[async_trait]
+impl MealPlanRepository for InMemoryMealPlanRepository {
+    async fn insert(&self, entry: MealPlanEntry) -> Result<(), InsertMealPlanEntryError> {
+        let mut lock = self.entries.lock()?;
+
+        if lock.contains_key(&entry.id) {
+            return Err(InsertMealPlanEntryError::Conflict(entry.id));
+        }
+
+        lock.insert(entry.id, entry);
+
+        Ok(())
+    }
+
+    async fn get_by_id(
+        &self,
+        user_id: &Uuid,
+        id: &Uuid,
+    ) -> Result<MealPlanEntry, GetMealPlanEntryError> {
+        let lock = self.entries.lock()?;
+
+        lock.get(id)
+            .filter(|entry| &entry.user_id == user_id)
+            .cloned()
+            .ok_or(GetMealPlanEntryError::NotFound(*id))
+    }
+
+    async fn get_in_range(
+        &self,
+        user_id: &Uuid,
+        from: &NaiveDate,
+        to: &NaiveDate,
+    ) -> Result<Vec<MealPlanEntry>, GetMealPlanEntryError> {
+        let lock = self.entries.lock()?;
+
+        let mut result: Vec<MealPlanEntry> = lock
+            .values()
+            .filter(|entry| &entry.user_id == user_id)
+            .filter(|entry| from <= &entry.date && &entry.date <= to)
+            .cloned()
+            .collect();
+        result.sort_by_key(|entry| (entry.date, entry.slot, entry.created_at));
+
+        Ok(result)
+    }
+
+    async fn update(
+        &self,
+        entry: &MealPlanEntry,
+        changeset: MealPlanEntryChangeset,
+    ) -> Result<(), UpdateMealPlanEntryError> {
+        let mut lock = self.entries.lock()?;
+        let entry = lock
+            .get_mut(&entry.id)
+            .ok_or(UpdateMealPlanEntryError::UnknownError(eyre!(
+                "The meal plan entry could not be found somehow"
+            )))?;
+
+        if let Some(date) = changeset.date {
+            entry.date = date;
+        }
+        if let Some(slot) = changeset.slot {
+            entry.slot = slot;
+        }
+        if let Some(recipe_id) = changeset.recipe_id {
+            entry.recipe_id = recipe_id;
+        }
+        if let Some(servings) = changeset.servings {
+            entry.servings = servings;
+        }
+        entry.updated_at = Utc::now();
+
+        Ok(())
+    }
+
+    async fn delete(&self, entry: &MealPlanEntry) -> Result<(), DeleteMealPlanEntryError> {
+        let mut lock = self.entries.lock()?;
+
+        lock.remove(&entry.id)
+            .ok_or(DeleteMealPlanEntryError::UnknownError(eyre!(
+                "The meal plan entry could not be found somehow"
+            )))?;
+
+        Ok(())
+    }
+
+    async fn insert_feed(&self, feed: CalendarFeed) -> Result<(), InsertCalendarFeedError> {
+        let mut lock = self.feeds.lock()?;
+
+        if lock.contains_key(&feed.id) {
+            return Err(InsertCalendarFeedError::Conflict(feed.id));
+        }
+
+        lock.insert(feed.id, feed);
+
+        Ok(())
+    }
+
+    async fn get_feed_by_token(&self, token: &str) -> Result<CalendarFeed, GetCalendarFeedError> {
+        let lock = self.feeds.lock()?;
+
+        lock.values()
+            .find(|feed| feed.token == token)
+            .cloned()
+            .ok_or(GetCalendarFeedError::NotFound)
+    }
+
+    async fn delete_feed(&self, feed: &CalendarFeed) -> Result<(), DeleteCalendarFeedError> {
+        let mut lock = self.feeds.lock()?;
+
+        lock.remove(&feed.id)
+            .ok_or(DeleteCalendarFeedError::UnknownError(eyre!(
+                "The calendar feed could not be found somehow"
+            )))?;
+
+        Ok(())
+    }
+}
+
+impl Default for InMemoryMealPlanRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InMemoryMealPlanRepository {
+    pub fn new() -> Self {
+        Self {
+            entries: Mutex::new(HashMap::new()),
+            feeds: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn service(self) -> MealPlanRepositoryService {
+        Arc::new(Box::new(self))
+    }
+}
@@ -0,0 +1,52 @@
+pub mod errors;
+pub mod in_memory;
+pub mod postgres;
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+use crate::domain::entities::meal_plan::{CalendarFeed, MealPlanEntry, MealPlanEntryChangeset};
+
+use self::errors::{
+    DeleteCalendarFeedError, DeleteMealPlanEntryError, GetCalendarFeedError, GetMealPlanEntryError,
+    InsertCalendarFeedError, InsertMealPlanEntryError, UpdateMealPlanEntryError,
+};
+
+#[async_trait]
+pub trait MealPlanRepository: Send + Sync + 'static {
+    async fn insert(&self, entry: MealPlanEntry) -> Result<(), InsertMealPlanEntryError>;
+
+    /// The entry with the given ID, as long as it is part of the meal plan of the user
+    async fn get_by_id(
+        &self,
+        user_id: &Uuid,
+        id: &Uuid,
+    ) -> Result<MealPlanEntry, GetMealPlanEntryError>;
+
+    /// Every entry the user planned between the two dates, both inclusive, in the order they will be eaten
+    async fn get_in_range(
+        &self,
+        user_id: &Uuid,
+        from: &NaiveDate,
+        to: &NaiveDate,
+    ) -> Result<Vec<MealPlanEntry>, GetMealPlanEntryError>;
+
+    async fn update(
+        &self,
+        entry: &MealPlanEntry,
+        changeset: MealPlanEntryChangeset,
+    ) -> Result<(), UpdateMealPlanEntryError>;
+
+    async fn delete(&self, entry: &MealPlanEntry) -> Result<(), DeleteMealPlanEntryError>;
+
+    async fn insert_feed(&self, feed: CalendarFeed) -> Result<(), InsertCalendarFeedError>;
+
+    async fn get_feed_by_token(&self, token: &str) -> Result<CalendarFeed, GetCalendarFeedError>;
+
+    async fn delete_feed(&self, feed: &CalendarFeed) -> Result<(), DeleteCalendarFeedError>;
+}
+
+pub type MealPlanRepositoryService = Arc<Box<dyn MealPlanRepository>>;
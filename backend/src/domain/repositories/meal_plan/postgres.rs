@@ -0,0 +1,192 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use sqlx::{error::Error as SQLXError, PgPool};
+use uuid::Uuid;
+
+use crate::domain::entities::meal_plan::{
+    CalendarFeed, MealPlanEntry, MealPlanEntryChangeset, MealPlanEntryModel,
+};
+
+use super::{
+    errors::{
+        DeleteCalendarFeedError, DeleteMealPlanEntryError, GetCalendarFeedError,
+        GetMealPlanEntryError, InsertCalendarFeedError, InsertMealPlanEntryError,
+        UpdateMealPlanEntryError,
+    },
+    MealPlanRepository, MealPlanRepositoryService,
+};
+
+pub struct PostgresMealPlanRepository(pub PgPool);
+
+#[async_trait]
+impl MealPlanRepository for PostgresMealPlanRepository {
+    #[tracing::instrument(
+        "[MEAL PLAN REPOSITORY] [POSTGRES] Insert a new meal plan entry",
+        skip(self)
+    )]
+    async fn insert(&self, entry: MealPlanEntry) -> Result<(), InsertMealPlanEntryError> {
+        sqlx::query_file!(
+            "queries/meal_plans/insert_meal_plan_entry.sql",
+            entry.id,
+            entry.user_id,
+            entry.date,
+            entry.slot.to_string(),
+            entry.recipe_id,
+            entry.servings as i16,
+            entry.created_at,
+            entry.updated_at
+        )
+        .execute(&self.0)
+        .await
+        .map_err(|e| match e {
+            SQLXError::Database(dberror) if dberror.is_unique_violation() => {
+                InsertMealPlanEntryError::Conflict(entry.id)
+            }
+            e => e.into(),
+        })?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(
+        "[MEAL PLAN REPOSITORY] [POSTGRES] Get meal plan entry with ID",
+        skip(self)
+    )]
+    async fn get_by_id(
+        &self,
+        user_id: &Uuid,
+        id: &Uuid,
+    ) -> Result<MealPlanEntry, GetMealPlanEntryError> {
+        let result = sqlx::query_file_as!(
+            MealPlanEntryModel,
+            "queries/meal_plans/get_meal_plan_entry.sql",
+            id,
+            user_id
+        )
+        .fetch_one(&self.0)
+        .await
+        .map_err(|e| GetMealPlanEntryError::with_id(id, e))?;
+
+        Ok(result.try_into()?)
+    }
+
+    #[tracing::instrument(
+        "[MEAL PLAN REPOSITORY] [POSTGRES] Get meal plan entries in range",
+        skip(self)
+    )]
+    async fn get_in_range(
+        &self,
+        user_id: &Uuid,
+        from: &NaiveDate,
+        to: &NaiveDate,
+    ) -> Result<Vec<MealPlanEntry>, GetMealPlanEntryError> {
+        let result = sqlx::query_file_as!(
+            MealPlanEntryModel,
+            "queries/meal_plans/get_meal_plan_entries_in_range.sql",
+            user_id,
+            from,
+            to
+        )
+        .fetch_all(&self.0)
+        .await?
+        .into_iter()
+        .map(MealPlanEntry::try_from)
+        .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(result)
+    }
+
+    #[tracing::instrument("[MEAL PLAN REPOSITORY] [POSTGRES] Update meal plan entry", skip(self))]
+    async fn update(
+        &self,
+        entry: &MealPlanEntry,
+        changeset: MealPlanEntryChangeset,
+    ) -> Result<(), UpdateMealPlanEntryError> {
+        sqlx::query_file!(
+            "queries/meal_plans/update_meal_plan_entry.sql",
+            entry.id,
+            changeset.date,
+            changeset.slot.map(|slot| slot.to_string()),
+            changeset.recipe_id,
+            changeset.servings.map(|servings| servings as i16)
+        )
+        .execute(&self.0)
+        .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument("[MEAL PLAN REPOSITORY] [POSTGRES] Delete meal plan entry", skip(self))]
+    async fn delete(&self, entry: &MealPlanEntry) -> Result<(), DeleteMealPlanEntryError> {
+        sqlx::query_file!("queries/meal_plans/delete_meal_plan_entry.sql", entry.id)
+            .execute(&self.0)
+            .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(
+        "[MEAL PLAN REPOSITORY] [POSTGRES] Insert a new calendar feed",
+        skip(self, feed)
+    )]
+    async fn insert_feed(&self, feed: CalendarFeed) -> Result<(), InsertCalendarFeedError> {
+        sqlx::query_file!(
+            "queries/meal_plans/insert_calendar_feed.sql",
+            feed.id,
+            feed.user_id,
+            feed.name,
+            feed.token,
+            feed.created_at
+        )
+        .execute(&self.0)
+        .await
+        .map_err(|e| match e {
+            SQLXError::Database(dberror) if dberror.is_unique_violation() => {
+                InsertCalendarFeedError::Conflict(feed.id)
+            }
+            e => e.into(),
+        })?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(
+        "[MEAL PLAN REPOSITORY] [POSTGRES] Get calendar feed by its token",
+        skip(self, token)
+    )]
+    async fn get_feed_by_token(&self, token: &str) -> Result<CalendarFeed, GetCalendarFeedError> {
+        let result = sqlx::query_file_as!(
+            CalendarFeed,
+            "queries/meal_plans/get_calendar_feed_by_token.sql",
+            token
+        )
+        .fetch_one(&self.0)
+        .await?;
+
+        Ok(result)
+    }
+
+    #[tracing::instrument(
+        "[MEAL PLAN REPOSITORY] [POSTGRES] Delete calendar feed",
+        skip(self, feed)
+    )]
+    async fn delete_feed(&self, feed: &CalendarFeed) -> Result<(), DeleteCalendarFeedError> {
+        sqlx::query_file!("queries/meal_plans/delete_calendar_feed.sql", feed.id)
+            .execute(&self.0)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl PostgresMealPlanRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self(pool)
+    }
+
+    pub fn service(self) -> MealPlanRepositoryService {
+        Arc::new(Box::new(self))
+    }
+}
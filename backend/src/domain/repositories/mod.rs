@@ -1,2 +1,14 @@
+pub mod collection;
+pub mod cooking_session;
+pub mod favorite;
 pub mod ingredients;
+pub mod meal_plan;
+pub mod outbox;
+pub mod pantry;
 pub mod recipe;
+pub mod recipe_note;
+pub mod session;
+pub mod shopping_list;
+pub mod unit_of_work;
+pub mod user;
+pub mod webhook;
@@ -0,0 +1,93 @@
+use eyre::eyre;
+use sqlx::Error as SQLXError;
+use std::sync::PoisonError;
+use thiserror::Error;
+
+use crate::domain::entities::event::DecodeOutboxEventError;
+
+const POISONED: &str =
+    "Outbox repository lock was poisoned during a previous access and can no longer be locked";
+
+#[derive(Error, Debug)]
+pub enum InsertOutboxEventError {
+    #[error(transparent)]
+    UnknownError(#[from] eyre::Error),
+}
+
+impl<T> From<PoisonError<T>> for InsertOutboxEventError {
+    fn from(_value: PoisonError<T>) -> Self {
+        eyre!(POISONED).into()
+    }
+}
+
+impl From<SQLXError> for InsertOutboxEventError {
+    fn from(e: SQLXError) -> Self {
+        Self::UnknownError(e.into())
+    }
+}
+
+impl From<serde_json::Error> for InsertOutboxEventError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::UnknownError(e.into())
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum GetOutboxEventsError {
+    #[error(transparent)]
+    UnknownError(#[from] eyre::Error),
+}
+
+impl<T> From<PoisonError<T>> for GetOutboxEventsError {
+    fn from(_value: PoisonError<T>) -> Self {
+        eyre!(POISONED).into()
+    }
+}
+
+impl From<SQLXError> for GetOutboxEventsError {
+    fn from(e: SQLXError) -> Self {
+        Self::UnknownError(e.into())
+    }
+}
+
+impl From<DecodeOutboxEventError> for GetOutboxEventsError {
+    fn from(e: DecodeOutboxEventError) -> Self {
+        Self::UnknownError(e.into())
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum PublishOutboxEventsError {
+    #[error(transparent)]
+    UnknownError(#[from] eyre::Error),
+}
+
+impl<T> From<PoisonError<T>> for PublishOutboxEventsError {
+    fn from(_value: PoisonError<T>) -> Self {
+        eyre!(POISONED).into()
+    }
+}
+
+impl From<SQLXError> for PublishOutboxEventsError {
+    fn from(e: SQLXError) -> Self {
+        Self::UnknownError(e.into())
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum PruneOutboxEventsError {
+    #[error(transparent)]
+    UnknownError(#[from] eyre::Error),
+}
+
+impl<T> From<PoisonError<T>> for PruneOutboxEventsError {
+    fn from(_value: PoisonError<T>) -> Self {
+        eyre!(POISONED).into()
+    }
+}
+
+impl From<SQLXError> for PruneOutboxEventsError {
+    fn from(e: SQLXError) -> Self {
+        Self::UnknownError(e.into())
+    }
+}
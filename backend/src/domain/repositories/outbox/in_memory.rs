@@ -0,0 +1,88 @@
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::entities::event::{DomainEvent, OutboxEvent};
+
+use super::{
+    errors::{
+        GetOutboxEventsError, InsertOutboxEventError, PruneOutboxEventsError,
+        PublishOutboxEventsError,
+    },
+    OutboxRepository, OutboxRepositoryService,
+};
+
+/// Cloning it shares the events, so that the recipe and the ingredient repositories can record
+/// into the same outbox that the relay reads from
+#[derive(Clone, Default)]
+pub struct InMemoryOutboxRepository(pub Arc<Mutex<Vec<OutboxEvent>>>);
+
+#[async_trait]
+impl OutboxRepository for InMemoryOutboxRepository {
+    async fn get_unpublished(
+        &self,
+        limit: usize,
+    ) -> Result<Vec<OutboxEvent>, GetOutboxEventsError> {
+        let lock = self.0.lock()?;
+
+        Ok(lock
+            .iter()
+            .filter(|e| e.published_at.is_none())
+            .take(limit)
+            .cloned()
+            .collect())
+    }
+
+    async fn publish(
+        &self,
+        limit: usize,
+        publish: &(dyn Fn(OutboxEvent) -> bool + Send + Sync),
+    ) -> Result<usize, PublishOutboxEventsError> {
+        let mut lock = self.0.lock()?;
+        let now = Utc::now();
+        let mut published = 0;
+
+        for outbox_event in lock.iter_mut().filter(|e| e.published_at.is_none()) {
+            if published == limit || !publish(outbox_event.clone()) {
+                break;
+            }
+
+            outbox_event.published_at = Some(now);
+            published += 1;
+        }
+
+        Ok(published)
+    }
+
+    async fn prune_published(&self, before: &DateTime<Utc>) -> Result<u64, PruneOutboxEventsError> {
+        let mut lock = self.0.lock()?;
+        let count = lock.len();
+
+        lock.retain(|e| {
+            e.published_at
+                .is_none_or(|published_at| published_at >= *before)
+        });
+
+        Ok((count - lock.len()) as u64)
+    }
+}
+
+impl InMemoryOutboxRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Meant to be called while the repository making the change still holds its lock, so that
+    /// nobody sees the change without the event
+    pub fn record(&self, event: DomainEvent) -> Result<(), InsertOutboxEventError> {
+        let mut lock = self.0.lock()?;
+        lock.push(OutboxEvent::new(event));
+
+        Ok(())
+    }
+
+    pub fn service(self) -> OutboxRepositoryService {
+        Arc::new(Box::new(self))
+    }
+}
@@ -0,0 +1,40 @@
+pub mod errors;
+pub mod in_memory;
+pub mod postgres;
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::entities::event::OutboxEvent;
+
+use self::errors::{GetOutboxEventsError, PruneOutboxEventsError, PublishOutboxEventsError};
+
+/// Events recorded by the recipe and ingredient repositories, in the same transaction as the
+/// changes they are about. Writing them is up to those repositories, this only reads them back.
+#[async_trait]
+pub trait OutboxRepository: Send + Sync + 'static {
+    /// Events that did not reach the event bus yet, oldest first
+    async fn get_unpublished(&self, limit: usize)
+        -> Result<Vec<OutboxEvent>, GetOutboxEventsError>;
+
+    /// Hands the oldest unpublished events to `publish` one at a time, oldest first, and marks the
+    /// ones it took as published. Stops at the first event `publish` refuses, which stays in the
+    /// outbox for the next time. Events another relay is busy with are skipped rather than
+    /// published twice. Events that can't be read are marked as failed and never published, so
+    /// that they don't hold up the rest. Returns how many events were published.
+    async fn publish(
+        &self,
+        limit: usize,
+        publish: &(dyn Fn(OutboxEvent) -> bool + Send + Sync),
+    ) -> Result<usize, PublishOutboxEventsError>;
+
+    /// Forgets the events published before `before`, returning how many there were
+    async fn prune_published(&self, before: &DateTime<Utc>) -> Result<u64, PruneOutboxEventsError>;
+}
+
+pub type OutboxRepositoryService = Arc<Box<dyn OutboxRepository>>;
+
+#[cfg(test)]
+mod tests;
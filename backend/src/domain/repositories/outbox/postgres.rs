@@ -0,0 +1,135 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+use crate::domain::entities::event::{DomainEvent, OutboxEvent, OutboxEventModel};
+
+use super::{
+    errors::{
+        GetOutboxEventsError, InsertOutboxEventError, PruneOutboxEventsError,
+        PublishOutboxEventsError,
+    },
+    OutboxRepository, OutboxRepositoryService,
+};
+
+pub struct PostgresOutboxRepository(pub PgPool);
+
+/// Records the event on the connection of the transaction that made the change, so that the
+/// event is only ever committed along with it
+pub async fn record_event(
+    conn: &mut PgConnection,
+    event: DomainEvent,
+) -> Result<(), InsertOutboxEventError> {
+    let model = OutboxEventModel::try_from(&OutboxEvent::new(event))?;
+
+    sqlx::query_file!(
+        "queries/outbox/insert_outbox_event.sql",
+        model.id,
+        model.event_type,
+        model.version,
+        model.payload,
+        model.created_at
+    )
+    .execute(conn)
+    .await?;
+
+    Ok(())
+}
+
+#[async_trait]
+impl OutboxRepository for PostgresOutboxRepository {
+    #[tracing::instrument("[OUTBOX REPOSITORY] [POSTGRES] Get unpublished events", skip(self))]
+    async fn get_unpublished(
+        &self,
+        limit: usize,
+    ) -> Result<Vec<OutboxEvent>, GetOutboxEventsError> {
+        let result = sqlx::query_file_as!(
+            OutboxEventModel,
+            "queries/outbox/get_unpublished_outbox_events.sql",
+            i64::try_from(limit).unwrap_or(i64::MAX)
+        )
+        .fetch_all(&self.0)
+        .await?;
+
+        let result = result
+            .into_iter()
+            .map(OutboxEvent::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(result)
+    }
+
+    #[tracing::instrument("[OUTBOX REPOSITORY] [POSTGRES] Publish events", skip(self, publish))]
+    async fn publish(
+        &self,
+        limit: usize,
+        publish: &(dyn Fn(OutboxEvent) -> bool + Send + Sync),
+    ) -> Result<usize, PublishOutboxEventsError> {
+        // The rows stay locked until they are marked, so other relays skip them in the meantime
+        let mut tx = self.0.begin().await?;
+
+        let batch = sqlx::query_file_as!(
+            OutboxEventModel,
+            "queries/outbox/claim_unpublished_outbox_events.sql",
+            i64::try_from(limit).unwrap_or(i64::MAX)
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let mut ids: Vec<Uuid> = Vec::with_capacity(batch.len());
+        for model in batch {
+            let id = model.id;
+            let outbox_event = match OutboxEvent::try_from(model) {
+                Ok(outbox_event) => outbox_event,
+                Err(e) => {
+                    // Left where it is, it would keep the events after it from ever being published
+                    tracing::error!("Could not read the event {id} from the outbox: {e}");
+                    sqlx::query_file!(
+                        "queries/outbox/mark_outbox_event_failed.sql",
+                        id,
+                        e.to_string()
+                    )
+                    .execute(&mut *tx)
+                    .await?;
+                    continue;
+                }
+            };
+
+            if !publish(outbox_event) {
+                break;
+            }
+
+            ids.push(id);
+        }
+
+        sqlx::query_file!("queries/outbox/mark_outbox_events_published.sql", &ids)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+
+        Ok(ids.len())
+    }
+
+    #[tracing::instrument("[OUTBOX REPOSITORY] [POSTGRES] Prune published events", skip(self))]
+    async fn prune_published(&self, before: &DateTime<Utc>) -> Result<u64, PruneOutboxEventsError> {
+        let result = sqlx::query_file!("queries/outbox/delete_published_outbox_events.sql", before)
+            .execute(&self.0)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+}
+
+impl PostgresOutboxRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self(pool)
+    }
+
+    pub fn service(self) -> OutboxRepositoryService {
+        Arc::new(Box::new(self))
+    }
+}
@@ -0,0 +1,140 @@
+use chrono::{TimeDelta, Utc};
+use pretty_assertions::assert_eq;
+use sqlx::Row;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::event::DomainEvent,
+        repositories::{
+            ingredients::IngredientRepository,
+            outbox::{postgres::PostgresOutboxRepository, OutboxRepository},
+            recipe::RecipeRepository,
+        },
+        services::events::EventBus,
+    },
+    test_utils::{insert_all_ingredients_of_recipe, recipe_fixture, unpublished_events},
+};
+
+pub async fn refused_events_stay_in_the_outbox(
+    ingredient_repo: impl IngredientRepository,
+    recipe_repo: impl RecipeRepository,
+    outbox_repo: impl OutboxRepository,
+) {
+    let recipe = recipe_fixture();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+    let recorded = unpublished_events(&outbox_repo).await;
+
+    // Nobody is listening yet
+    let events = EventBus::new();
+    let published = outbox_repo
+        .publish(usize::MAX, &|event| events.publish(event))
+        .await
+        .unwrap();
+
+    assert_eq!(published, 0);
+    assert_eq!(unpublished_events(&outbox_repo).await, recorded);
+
+    let mut subscriber = events.subscribe();
+    let published = outbox_repo
+        .publish(usize::MAX, &|event| events.publish(event))
+        .await
+        .unwrap();
+
+    assert_eq!(published, recorded.len());
+    assert!(unpublished_events(&outbox_repo).await.is_empty());
+    for event in recorded {
+        assert_eq!(subscriber.recv().await.unwrap().event, event);
+    }
+}
+
+pub async fn publishing_stops_at_the_first_refused_event(
+    ingredient_repo: impl IngredientRepository,
+    recipe_repo: impl RecipeRepository,
+    outbox_repo: impl OutboxRepository,
+) {
+    let recipe = recipe_fixture();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+
+    let published = outbox_repo
+        .publish(usize::MAX, &|event| {
+            !matches!(event.event, DomainEvent::RecipeCreated(_))
+        })
+        .await
+        .unwrap();
+
+    let left = unpublished_events(&outbox_repo).await;
+    assert!(matches!(left.first(), Some(DomainEvent::RecipeCreated(_))));
+    assert_eq!(published, recipe.ingredients.len());
+}
+
+pub async fn only_events_published_long_enough_ago_are_pruned(
+    ingredient_repo: impl IngredientRepository,
+    recipe_repo: impl RecipeRepository,
+    outbox_repo: impl OutboxRepository,
+) {
+    let recipe = recipe_fixture();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+    let recorded = unpublished_events(&outbox_repo).await;
+
+    let published = outbox_repo.publish(1, &|_| true).await.unwrap();
+    assert_eq!(published, 1);
+
+    let pruned = outbox_repo
+        .prune_published(&(Utc::now() - TimeDelta::days(1)))
+        .await
+        .unwrap();
+    assert_eq!(pruned, 0);
+
+    let pruned = outbox_repo
+        .prune_published(&(Utc::now() + TimeDelta::minutes(1)))
+        .await
+        .unwrap();
+    assert_eq!(pruned, 1);
+
+    // The events that were not published yet are kept no matter how old they are
+    assert_eq!(unpublished_events(&outbox_repo).await, recorded[1..]);
+}
+
+pub async fn events_that_cant_be_read_are_set_aside(
+    ingredient_repo: impl IngredientRepository,
+    recipe_repo: impl RecipeRepository,
+    outbox_repo: PostgresOutboxRepository,
+) {
+    let recipe = recipe_fixture();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+    let recorded = unpublished_events(&outbox_repo).await;
+
+    // Written by a newer version of the app, before everything else
+    let unreadable = Uuid::from_u128(1);
+    sqlx::query(
+        "INSERT INTO outbox (id, event_type, version, payload, created_at) VALUES ($1, 'recipe_eaten', 2, '{}', $2)",
+    )
+    .bind(unreadable)
+    .bind(Utc::now() - TimeDelta::days(1))
+    .execute(&outbox_repo.0)
+    .await
+    .unwrap();
+
+    let published = outbox_repo.publish(usize::MAX, &|_| true).await.unwrap();
+
+    assert_eq!(published, recorded.len());
+    assert!(unpublished_events(&outbox_repo).await.is_empty());
+
+    let row =
+        sqlx::query("SELECT failed_at IS NOT NULL AS failed, failure FROM outbox WHERE id = $1")
+            .bind(unreadable)
+            .fetch_one(&outbox_repo.0)
+            .await
+            .unwrap();
+
+    assert!(row.get::<bool, _>("failed"));
+    assert_eq!(
+        row.get::<String, _>("failure"),
+        "Version 2 of the outbox payload is unknown"
+    );
+}
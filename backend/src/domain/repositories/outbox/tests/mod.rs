@@ -0,0 +1,98 @@
+mod __tests__;
+mod in_memory {
+    use super::__tests__;
+
+    use crate::domain::repositories::{
+        ingredients::in_memory::InMemoryIngredientRepository,
+        outbox::in_memory::InMemoryOutboxRepository, recipe::in_memory::InMemoryRecipeRepository,
+    };
+
+    #[tokio::test]
+    async fn refused_events_stay_in_the_outbox() {
+        let outbox = InMemoryOutboxRepository::new();
+
+        __tests__::refused_events_stay_in_the_outbox(
+            InMemoryIngredientRepository::new().with_outbox(outbox.clone()),
+            InMemoryRecipeRepository::new().with_outbox(outbox.clone()),
+            outbox,
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn publishing_stops_at_the_first_refused_event() {
+        let outbox = InMemoryOutboxRepository::new();
+
+        __tests__::publishing_stops_at_the_first_refused_event(
+            InMemoryIngredientRepository::new().with_outbox(outbox.clone()),
+            InMemoryRecipeRepository::new().with_outbox(outbox.clone()),
+            outbox,
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn only_events_published_long_enough_ago_are_pruned() {
+        let outbox = InMemoryOutboxRepository::new();
+
+        __tests__::only_events_published_long_enough_ago_are_pruned(
+            InMemoryIngredientRepository::new().with_outbox(outbox.clone()),
+            InMemoryRecipeRepository::new().with_outbox(outbox.clone()),
+            outbox,
+        )
+        .await
+    }
+
+    // The in memory outbox keeps the events as they are, so there is nothing that could not be
+    // read back, which is only tested against Postgres
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        ingredients::postgres::PostgresIngredientRepository,
+        outbox::postgres::PostgresOutboxRepository, recipe::postgres::PostgresRecipeRepository,
+    };
+
+    #[sqlx::test]
+    async fn refused_events_stay_in_the_outbox(pool: PgPool) {
+        __tests__::refused_events_stay_in_the_outbox(
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresOutboxRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn publishing_stops_at_the_first_refused_event(pool: PgPool) {
+        __tests__::publishing_stops_at_the_first_refused_event(
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresOutboxRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn only_events_published_long_enough_ago_are_pruned(pool: PgPool) {
+        __tests__::only_events_published_long_enough_ago_are_pruned(
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresOutboxRepository::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn events_that_cant_be_read_are_set_aside(pool: PgPool) {
+        __tests__::events_that_cant_be_read_are_set_aside(
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresOutboxRepository::new(pool),
+        )
+        .await
+    }
+}
@@ -0,0 +1,112 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use async_trait::async_trait;
+use chrono::Utc;
+use eyre::eyre;
+use uuid::Uuid;
+
+use crate::domain::entities::pantry::PantryItem;
+
+use super::{
+    errors::{
+        DeletePantryItemError, GetPantryItemError, InsertPantryItemError, UpdatePantryItemError,
+    },
+    PantryRepository, PantryRepositoryService,
+};
+
+pub struct InMemoryPantryRepository(pub Mutex<HashMap<Uuid, PantryItem>>);
+
+#[async_trait]
+impl PantryRepository for InMemoryPantryRepository {
+    async fn insert(&self, item: PantryItem) -> Result<(), InsertPantryItemError> {
+        let mut lock = self.0.lock()?;
+
+        if lock.contains_key(&item.id) {
+            return Err(InsertPantryItemError::Conflict(item.id));
+        }
+
+        if lock.values().any(|existing| {
+            existing.user_id == item.user_id && existing.ingredient.id == item.ingredient.id
+        }) {
+            return Err(InsertPantryItemError::AlreadyInPantry(item.ingredient.id));
+        }
+
+        lock.insert(item.id, item);
+
+        Ok(())
+    }
+
+    async fn get_by_id(&self, user_id: &Uuid, id: &Uuid) -> Result<PantryItem, GetPantryItemError> {
+        let lock = self.0.lock()?;
+
+        lock.get(id)
+            .filter(|item| &item.user_id == user_id)
+            .cloned()
+            .ok_or(GetPantryItemError::NotFound(*id))
+    }
+
+    async fn get_all(&self, user_id: &Uuid) -> Result<Vec<PantryItem>, GetPantryItemError> {
+        let lock = self.0.lock()?;
+
+        let mut result: Vec<PantryItem> = lock
+            .values()
+            .filter(|item| &item.user_id == user_id)
+            .cloned()
+            .collect();
+        result.sort_by(|a, b| {
+            // Items without an expiry date go last
+            let expiry = |item: &PantryItem| (item.expires_at.is_none(), item.expires_at);
+
+            expiry(a)
+                .cmp(&expiry(b))
+                .then_with(|| a.ingredient.name.cmp(&b.ingredient.name))
+        });
+
+        Ok(result)
+    }
+
+    async fn update(&self, item: &PantryItem) -> Result<(), UpdatePantryItemError> {
+        let mut lock = self.0.lock()?;
+        let existing = lock
+            .get_mut(&item.id)
+            .ok_or(UpdatePantryItemError::UnknownError(eyre!(
+                "The pantry item could not be found somehow"
+            )))?;
+
+        existing.amount = item.amount.clone();
+        existing.expires_at = item.expires_at;
+        existing.updated_at = Utc::now();
+
+        Ok(())
+    }
+
+    async fn delete(&self, item: &PantryItem) -> Result<(), DeletePantryItemError> {
+        let mut lock = self.0.lock()?;
+
+        lock.remove(&item.id)
+            .ok_or(DeletePantryItemError::UnknownError(eyre!(
+                "The pantry item could not be found somehow"
+            )))?;
+
+        Ok(())
+    }
+}
+
+impl Default for InMemoryPantryRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InMemoryPantryRepository {
+    pub fn new() -> Self {
+        Self(Mutex::new(HashMap::new()))
+    }
+
+    pub fn service(self) -> PantryRepositoryService {
+        Arc::new(Box::new(self))
+    }
+}
@@ -0,0 +1,32 @@
+pub mod errors;
+pub mod in_memory;
+pub mod postgres;
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::pantry::PantryItem;
+
+use self::errors::{
+    DeletePantryItemError, GetPantryItemError, InsertPantryItemError, UpdatePantryItemError,
+};
+
+#[async_trait]
+pub trait PantryRepository: Send + Sync + 'static {
+    async fn insert(&self, item: PantryItem) -> Result<(), InsertPantryItemError>;
+
+    /// The item with the given ID, as long as it is in the pantry of the user
+    async fn get_by_id(&self, user_id: &Uuid, id: &Uuid) -> Result<PantryItem, GetPantryItemError>;
+
+    /// Every item in the pantry of the user, the ones that expire first coming first
+    async fn get_all(&self, user_id: &Uuid) -> Result<Vec<PantryItem>, GetPantryItemError>;
+
+    /// Saves the amount and the expiry date of the item
+    async fn update(&self, item: &PantryItem) -> Result<(), UpdatePantryItemError>;
+
+    async fn delete(&self, item: &PantryItem) -> Result<(), DeletePantryItemError>;
+}
+
+pub type PantryRepositoryService = Arc<Box<dyn PantryRepository>>;
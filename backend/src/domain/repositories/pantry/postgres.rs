@@ -0,0 +1,129 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sqlx::{error::Error as SQLXError, PgPool};
+use uuid::Uuid;
+
+use crate::domain::entities::{
+    ingredient::IngredientModel,
+    pantry::{PantryItem, PantryItemModel},
+};
+
+use super::{
+    errors::{
+        DeletePantryItemError, GetPantryItemError, InsertPantryItemError, UpdatePantryItemError,
+    },
+    PantryRepository, PantryRepositoryService,
+};
+
+pub struct PostgresPantryRepository(pub PgPool);
+
+#[async_trait]
+impl PantryRepository for PostgresPantryRepository {
+    #[tracing::instrument("[PANTRY REPOSITORY] [POSTGRES] Insert a new pantry item", skip(self))]
+    async fn insert(&self, item: PantryItem) -> Result<(), InsertPantryItemError> {
+        let amount = item
+            .amount
+            .as_ref()
+            .map(serde_json::to_value)
+            .transpose()
+            .map_err(|e| InsertPantryItemError::UnknownError(e.into()))?;
+
+        sqlx::query_file!(
+            "queries/pantry/insert_pantry_item.sql",
+            item.id,
+            item.user_id,
+            item.ingredient.id,
+            amount,
+            item.expires_at,
+            item.created_at,
+            item.updated_at
+        )
+        .execute(&self.0)
+        .await
+        .map_err(|e| match e {
+            SQLXError::Database(dberror) if dberror.is_unique_violation() => {
+                match dberror.constraint() {
+                    Some("pantry_items_user_id_ingredient_id_key") => {
+                        InsertPantryItemError::AlreadyInPantry(item.ingredient.id)
+                    }
+                    _ => InsertPantryItemError::Conflict(item.id),
+                }
+            }
+            e => e.into(),
+        })?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument("[PANTRY REPOSITORY] [POSTGRES] Get pantry item with ID", skip(self))]
+    async fn get_by_id(&self, user_id: &Uuid, id: &Uuid) -> Result<PantryItem, GetPantryItemError> {
+        let result = sqlx::query_file_as!(
+            PantryItemModel,
+            "queries/pantry/get_pantry_item.sql",
+            id,
+            user_id
+        )
+        .fetch_one(&self.0)
+        .await
+        .map_err(|e| GetPantryItemError::with_id(id, e))?;
+
+        Ok(result.try_into()?)
+    }
+
+    #[tracing::instrument("[PANTRY REPOSITORY] [POSTGRES] Get all pantry items", skip(self))]
+    async fn get_all(&self, user_id: &Uuid) -> Result<Vec<PantryItem>, GetPantryItemError> {
+        let result = sqlx::query_file_as!(
+            PantryItemModel,
+            "queries/pantry/get_pantry_items.sql",
+            user_id
+        )
+        .fetch_all(&self.0)
+        .await?
+        .into_iter()
+        .map(PantryItem::try_from)
+        .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(result)
+    }
+
+    #[tracing::instrument("[PANTRY REPOSITORY] [POSTGRES] Update pantry item", skip(self))]
+    async fn update(&self, item: &PantryItem) -> Result<(), UpdatePantryItemError> {
+        let amount = item
+            .amount
+            .as_ref()
+            .map(serde_json::to_value)
+            .transpose()
+            .map_err(|e| UpdatePantryItemError::UnknownError(e.into()))?;
+
+        sqlx::query_file!(
+            "queries/pantry/update_pantry_item.sql",
+            item.id,
+            amount,
+            item.expires_at
+        )
+        .execute(&self.0)
+        .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument("[PANTRY REPOSITORY] [POSTGRES] Delete pantry item", skip(self))]
+    async fn delete(&self, item: &PantryItem) -> Result<(), DeletePantryItemError> {
+        sqlx::query_file!("queries/pantry/delete_pantry_item.sql", item.id)
+            .execute(&self.0)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl PostgresPantryRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self(pool)
+    }
+
+    pub fn service(self) -> PantryRepositoryService {
+        Arc::new(Box::new(self))
+    }
+}
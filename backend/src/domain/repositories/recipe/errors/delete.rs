@@ -1,3 +1,4 @@
+use crate::domain::repositories::outbox::errors::InsertOutboxEventError;
 use eyre::eyre;
 use sqlx::Error as SQLXError;
 use std::sync::PoisonError;
@@ -20,3 +21,9 @@ impl From<SQLXError> for DeleteRecipeError {
         Self::UnknownError(e.into())
     }
 }
+
+impl From<InsertOutboxEventError> for DeleteRecipeError {
+    fn from(e: InsertOutboxEventError) -> Self {
+        Self::UnknownError(e.into())
+    }
+}
@@ -3,6 +3,7 @@ use sqlx::Error as SQLXError;
 use std::sync::PoisonError;
 use thiserror::Error;
 
+use crate::domain::repositories::outbox::errors::InsertOutboxEventError;
 use crate::domain::repositories::recipe::errors::constraint_to_field;
 
 #[derive(Error, Debug)]
@@ -30,3 +31,9 @@ impl From<SQLXError> for AddIngredientIntoRecipeError {
         }
     }
 }
+
+impl From<InsertOutboxEventError> for AddIngredientIntoRecipeError {
+    fn from(e: InsertOutboxEventError) -> Self {
+        Self::UnknownError(e.into())
+    }
+}
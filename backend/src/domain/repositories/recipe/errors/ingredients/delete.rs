@@ -4,6 +4,7 @@ use std::sync::PoisonError;
 use thiserror::Error;
 
 use crate::domain::entities::recipe::errors::ValidationError;
+use crate::domain::repositories::outbox::errors::InsertOutboxEventError;
 
 #[derive(Error, Debug)]
 pub enum DeleteIngredientFromRecipeError {
@@ -25,3 +26,9 @@ impl From<SQLXError> for DeleteIngredientFromRecipeError {
         Self::UnknownError(e.into())
     }
 }
+
+impl From<InsertOutboxEventError> for DeleteIngredientFromRecipeError {
+    fn from(e: InsertOutboxEventError) -> Self {
+        Self::UnknownError(e.into())
+    }
+}
@@ -4,6 +4,7 @@ use std::sync::PoisonError;
 use thiserror::Error;
 
 use crate::domain::entities::recipe::errors::ValidationError;
+use crate::domain::repositories::outbox::errors::InsertOutboxEventError;
 
 #[derive(Error, Debug)]
 pub enum UpdateIngredientInRecipeError {
@@ -31,3 +32,9 @@ impl From<serde_json::Error> for UpdateIngredientInRecipeError {
         Self::UnknownError(e.into())
     }
 }
+
+impl From<InsertOutboxEventError> for UpdateIngredientInRecipeError {
+    fn from(e: InsertOutboxEventError) -> Self {
+        Self::UnknownError(e.into())
+    }
+}
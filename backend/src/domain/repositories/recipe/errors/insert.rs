@@ -5,7 +5,8 @@ use thiserror::Error;
 
 use crate::domain::entities::recipe::errors::ValidationError;
 
-use super::{constraint_to_field, AddIngredientIntoRecipeError};
+use super::{constraint_to_field, AddIngredientIntoRecipeError, GetRecipeByIdError};
+use crate::domain::repositories::outbox::errors::InsertOutboxEventError;
 
 #[derive(Error, Debug)]
 pub enum InsertRecipeError {
@@ -38,3 +39,15 @@ impl<T> From<PoisonError<T>> for InsertRecipeError {
         eyre!("Recipe repository lock was poisoned during a previous access and can no longer be locked").into()
     }
 }
+
+impl From<InsertOutboxEventError> for InsertRecipeError {
+    fn from(e: InsertOutboxEventError) -> Self {
+        Self::UnknownError(e.into())
+    }
+}
+
+impl From<GetRecipeByIdError> for InsertRecipeError {
+    fn from(e: GetRecipeByIdError) -> Self {
+        Self::UnknownError(e.into())
+    }
+}
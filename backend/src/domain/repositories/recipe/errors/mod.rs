@@ -9,6 +9,8 @@ fn constraint_to_field(field: &str) -> &str {
             ("ingredients_name_key", "ingredient name"),
             ("ingredients_pkey", "ingredient id"),
             ("recipes_pkey", "recipe id"),
+            ("recipe_reviews_pkey", "review id"),
+            ("recipe_reviews_recipe_id_user_id_key", "review author"),
         ])
     });
     m.get(field).unwrap_or(&field)
@@ -18,10 +20,14 @@ mod delete;
 mod get;
 mod ingredients;
 mod insert;
+mod reviews;
+mod share_tokens;
 mod update;
 
 pub use delete::*;
 pub use get::*;
 pub use ingredients::*;
 pub use insert::*;
+pub use reviews::*;
+pub use share_tokens::*;
 pub use update::*;
@@ -3,6 +3,9 @@ use sqlx::Error as SQLXError;
 use std::sync::PoisonError;
 use thiserror::Error;
 
+use super::GetRecipeByIdError;
+use crate::domain::repositories::outbox::errors::InsertOutboxEventError;
+
 #[derive(Error, Debug)]
 pub enum UpdateRecipeError {
     #[error(transparent)]
@@ -20,3 +23,15 @@ impl From<SQLXError> for UpdateRecipeError {
         Self::UnknownError(e.into())
     }
 }
+
+impl From<InsertOutboxEventError> for UpdateRecipeError {
+    fn from(e: InsertOutboxEventError) -> Self {
+        Self::UnknownError(e.into())
+    }
+}
+
+impl From<GetRecipeByIdError> for UpdateRecipeError {
+    fn from(e: GetRecipeByIdError) -> Self {
+        Self::UnknownError(e.into())
+    }
+}
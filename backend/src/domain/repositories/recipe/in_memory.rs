@@ -11,21 +11,39 @@ use uuid::Uuid;
 
 use crate::domain::{
     entities::{
+        event::DomainEvent,
         ingredient::Ingredient,
-        recipe::{IngredientUnit, IngredientWithAmount, Recipe, RecipeChangeset},
+        recipe::{IngredientInRecipeChangeset, IngredientWithAmount, Recipe, RecipeChangeset},
+        review::{RecipeRating, Review, ReviewChangeset},
+        share_token::ShareToken,
+    },
+    repositories::{
+        outbox::in_memory::InMemoryOutboxRepository, recipe::errors::InsertRecipeError,
     },
-    repositories::recipe::errors::InsertRecipeError,
 };
 
 use super::{
     errors::{
         AddIngredientIntoRecipeError, DeleteIngredientFromRecipeError, DeleteRecipeError,
-        GetRecipeByIdError, UpdateIngredientInRecipeError, UpdateRecipeError,
+        DeleteShareTokenError, GetRecipeByIdError, GetReviewError, GetShareTokenError,
+        InsertReviewError, InsertShareTokenError, UpdateIngredientInRecipeError, UpdateRecipeError,
+        UpdateReviewError,
     },
     RecipeRepository, RecipeRepositoryService,
 };
 
-pub struct InMemoryRecipeRepository(pub Mutex<HashMap<uuid::Uuid, Recipe>>);
+/// Clones share the same recipes, so that a unit of work can see them
+#[derive(Clone)]
+pub struct InMemoryRecipeRepository(
+    pub Arc<Mutex<HashMap<uuid::Uuid, Recipe>>>,
+    pub Arc<Mutex<HashMap<uuid::Uuid, Review>>>,
+    pub Arc<Mutex<HashMap<String, ShareToken>>>,
+    pub InMemoryOutboxRepository,
+);
+
+fn rating_of(reviews: &HashMap<Uuid, Review>, recipe_id: &Uuid) -> RecipeRating {
+    RecipeRating::from_reviews(reviews.values().filter(|r| &r.recipe_id == recipe_id))
+}
 
 #[async_trait]
 impl RecipeRepository for InMemoryRecipeRepository {
@@ -38,6 +56,7 @@ impl RecipeRepository for InMemoryRecipeRepository {
         };
 
         lock.insert(input.id, input.clone());
+        self.3.record(DomainEvent::RecipeCreated(input))?;
 
         Ok(())
     }
@@ -53,15 +72,45 @@ impl RecipeRepository for InMemoryRecipeRepository {
         Ok(result)
     }
 
+    async fn get_all(&self) -> Result<Vec<Recipe>, GetRecipeByIdError> {
+        let lock = self.0.lock()?;
+
+        Ok(lock.values().cloned().collect())
+    }
+
+    async fn get_forks(&self, recipe: &Recipe) -> Result<Vec<Recipe>, GetRecipeByIdError> {
+        let lock = self.0.lock()?;
+
+        let mut result: Vec<Recipe> = lock
+            .values()
+            .filter(|r| r.forked_from == Some(recipe.id))
+            .cloned()
+            .collect();
+        result.sort_by_key(|r| (r.created_at, r.id));
+
+        Ok(result)
+    }
+
     async fn delete(&self, recipe: &Recipe) -> Result<(), DeleteRecipeError> {
         let id = &recipe.id;
         let mut lock = self.0.lock()?;
+        let mut reviews = self.1.lock()?;
+        let mut share_tokens = self.2.lock()?;
 
         lock.remove(id)
             .ok_or(DeleteRecipeError::UnknownError(eyre!(
                 "The recipe could not be found somehow"
             )))?;
 
+        reviews.retain(|_, review| &review.recipe_id != id);
+        share_tokens.retain(|_, share_token| &share_token.recipe_id != id);
+
+        lock.values_mut()
+            .filter(|r| r.forked_from.as_ref() == Some(id))
+            .for_each(|r| r.forked_from = None);
+
+        self.3.record(DomainEvent::RecipeDeleted(*id))?;
+
         Ok(())
     }
 
@@ -99,6 +148,11 @@ impl RecipeRepository for InMemoryRecipeRepository {
             updated = true;
         };
 
+        if let Some(v) = changeset.visibility {
+            recipe.visibility = v;
+            updated = true;
+        };
+
         if let Some(v) = changeset.description {
             recipe.description = v;
             updated = true;
@@ -106,6 +160,7 @@ impl RecipeRepository for InMemoryRecipeRepository {
 
         if updated {
             recipe.updated_at = Utc::now();
+            self.3.record(DomainEvent::RecipeUpdated(recipe.clone()))?;
         };
 
         Ok(())
@@ -123,9 +178,15 @@ impl RecipeRepository for InMemoryRecipeRepository {
                 "Recipe is not in the repo"
             )))?;
 
+        let ingredient_id = ingredient.ingredient.id;
         recipe.ingredients.push(ingredient);
         recipe.updated_at = Utc::now();
 
+        self.3.record(DomainEvent::IngredientAddedToRecipe {
+            recipe_id: recipe.id,
+            ingredient_id,
+        })?;
+
         Ok(())
     }
 
@@ -144,7 +205,7 @@ impl RecipeRepository for InMemoryRecipeRepository {
         let new_ingredients: Vec<_> = recipe
             .ingredients
             .iter()
-            .filter(|&ingam| ingam.ingredient.id != ingredient.ingredient.id)
+            .filter(|&ingam| ingam.id != ingredient.id)
             .cloned()
             .collect();
 
@@ -153,14 +214,19 @@ impl RecipeRepository for InMemoryRecipeRepository {
             .map_err(DeleteIngredientFromRecipeError::ValidationError)?;
         recipe.updated_at = Utc::now();
 
+        self.3.record(DomainEvent::IngredientRemovedFromRecipe {
+            recipe_id: recipe.id,
+            ingredient_id: ingredient.ingredient.id,
+        })?;
+
         Ok(())
     }
 
-    async fn update_ingredient_amount(
+    async fn update_ingredient(
         &self,
         recipe: &Recipe,
         ingredient: &IngredientWithAmount,
-        new_amount: &IngredientUnit,
+        changeset: IngredientInRecipeChangeset,
     ) -> Result<(), UpdateIngredientInRecipeError> {
         let mut lock = self.0.lock()?;
         let recipe =
@@ -169,20 +235,48 @@ impl RecipeRepository for InMemoryRecipeRepository {
                     "Recipe is not in the repo somehow"
                 )))?;
 
-        let ingredient = recipe
+        let index = recipe
             .ingredients
-            .iter_mut()
-            .find(|i| i.ingredient.id == ingredient.ingredient.id)
+            .iter()
+            .position(|i| i.id == ingredient.id)
             .ok_or(UpdateIngredientInRecipeError::UnknownError(eyre!(
                 "Ingredient somehow is not in the recipe, but the command made sure there was."
             )))?;
 
-        ingredient.amount = new_amount.clone();
+        let ingredient = &mut recipe.ingredients[index];
+        changeset.apply(ingredient);
+        let ingredient_id = ingredient.ingredient.id;
+
+        changeset.reorder(&mut recipe.ingredients, index);
         recipe.updated_at = Utc::now();
 
+        self.3.record(DomainEvent::IngredientInRecipeUpdated {
+            recipe_id: recipe.id,
+            ingredient_id,
+        })?;
+
         Ok(())
     }
 
+    async fn get_by_ingredients(
+        &self,
+        ingredient_ids: &[Uuid],
+    ) -> Result<Vec<Recipe>, GetRecipeByIdError> {
+        let lock = self.0.lock()?;
+
+        let result = lock
+            .values()
+            .filter(|r| {
+                r.ingredients
+                    .iter()
+                    .any(|i| ingredient_ids.contains(&i.ingredient.id))
+            })
+            .cloned()
+            .collect();
+
+        Ok(result)
+    }
+
     async fn recipes_containing_ingredient_exist(
         &self,
         ingredient: Ingredient,
@@ -196,6 +290,123 @@ impl RecipeRepository for InMemoryRecipeRepository {
 
         Ok(some_recipe_with_ingredient.is_some())
     }
+
+    async fn add_review(&self, recipe: &Recipe, review: Review) -> Result<(), InsertReviewError> {
+        let mut lock = self.0.lock()?;
+        let mut reviews = self.1.lock()?;
+        let recipe = lock
+            .get_mut(&recipe.id)
+            .ok_or(InsertReviewError::UnknownError(eyre!(
+                "The recipe could not be found somehow"
+            )))?;
+
+        if reviews.contains_key(&review.id) {
+            return Err(InsertReviewError::Conflict("review id".to_string()));
+        }
+
+        if reviews
+            .values()
+            .any(|r| r.recipe_id == recipe.id && r.user_id.is_some() && r.user_id == review.user_id)
+        {
+            return Err(InsertReviewError::Conflict("review author".to_string()));
+        }
+
+        reviews.insert(review.id, review);
+        recipe.rating = rating_of(&reviews, &recipe.id);
+
+        Ok(())
+    }
+
+    async fn get_review(&self, recipe: &Recipe, id: &Uuid) -> Result<Review, GetReviewError> {
+        let reviews = self.1.lock()?;
+
+        reviews
+            .get(id)
+            .filter(|review| review.recipe_id == recipe.id)
+            .cloned()
+            .ok_or(GetReviewError::NotFound(*id))
+    }
+
+    async fn get_reviews(&self, recipe: &Recipe) -> Result<Vec<Review>, GetReviewError> {
+        let reviews = self.1.lock()?;
+
+        let mut result: Vec<Review> = reviews
+            .values()
+            .filter(|review| review.recipe_id == recipe.id)
+            .cloned()
+            .collect();
+        result.sort_by_key(|review| (review.created_at, review.id));
+
+        Ok(result)
+    }
+
+    async fn update_review(
+        &self,
+        review: &Review,
+        changeset: ReviewChangeset,
+    ) -> Result<(), UpdateReviewError> {
+        let mut lock = self.0.lock()?;
+        let mut reviews = self.1.lock()?;
+        let stored = reviews
+            .get_mut(&review.id)
+            .ok_or(UpdateReviewError::UnknownError(eyre!(
+                "The review could not be found somehow"
+            )))?;
+
+        if stored.apply(&changeset) {
+            stored.updated_at = Utc::now();
+        }
+
+        if let Some(recipe) = lock.get_mut(&review.recipe_id) {
+            recipe.rating = rating_of(&reviews, &review.recipe_id);
+        }
+
+        Ok(())
+    }
+
+    async fn add_share_token(&self, share_token: ShareToken) -> Result<(), InsertShareTokenError> {
+        let mut share_tokens = self.2.lock()?;
+
+        share_tokens.insert(share_token.token.clone(), share_token);
+
+        Ok(())
+    }
+
+    async fn get_share_token(&self, token: &str) -> Result<ShareToken, GetShareTokenError> {
+        let share_tokens = self.2.lock()?;
+
+        share_tokens
+            .get(token)
+            .cloned()
+            .ok_or(GetShareTokenError::NotFound)
+    }
+
+    async fn get_share_tokens(
+        &self,
+        recipe: &Recipe,
+    ) -> Result<Vec<ShareToken>, GetShareTokenError> {
+        let share_tokens = self.2.lock()?;
+
+        let mut result: Vec<ShareToken> = share_tokens
+            .values()
+            .filter(|share_token| share_token.recipe_id == recipe.id)
+            .cloned()
+            .collect();
+        result.sort_by(|a, b| (a.created_at, &a.token).cmp(&(b.created_at, &b.token)));
+
+        Ok(result)
+    }
+
+    async fn delete_share_token(
+        &self,
+        share_token: &ShareToken,
+    ) -> Result<(), DeleteShareTokenError> {
+        let mut share_tokens = self.2.lock()?;
+
+        share_tokens.remove(&share_token.token);
+
+        Ok(())
+    }
 }
 
 impl Default for InMemoryRecipeRepository {
@@ -206,7 +417,18 @@ impl Default for InMemoryRecipeRepository {
 
 impl InMemoryRecipeRepository {
     pub fn new() -> Self {
-        Self(Mutex::new(HashMap::new()))
+        Self(
+            Arc::new(Mutex::new(HashMap::new())),
+            Arc::new(Mutex::new(HashMap::new())),
+            Arc::new(Mutex::new(HashMap::new())),
+            InMemoryOutboxRepository::new(),
+        )
+    }
+
+    /// Records the events into the given outbox instead of one of its own
+    pub fn with_outbox(mut self, outbox: InMemoryOutboxRepository) -> Self {
+        self.3 = outbox;
+        self
     }
 
     pub fn service(self) -> RecipeRepositoryService {
@@ -216,6 +438,11 @@ impl InMemoryRecipeRepository {
 
 impl From<HashMap<uuid::Uuid, Recipe>> for InMemoryRecipeRepository {
     fn from(value: HashMap<uuid::Uuid, Recipe>) -> Self {
-        Self(Mutex::new(value))
+        Self(
+            Arc::new(Mutex::new(value)),
+            Arc::new(Mutex::new(HashMap::new())),
+            Arc::new(Mutex::new(HashMap::new())),
+            InMemoryOutboxRepository::new(),
+        )
     }
 }
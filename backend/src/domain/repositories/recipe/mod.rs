@@ -4,7 +4,10 @@ pub mod postgres;
 
 use crate::domain::entities::{
     ingredient::Ingredient,
-    recipe::{IngredientUnit, IngredientWithAmount, Recipe, RecipeChangeset},
+    recipe::{IngredientInRecipeChangeset, IngredientWithAmount, Recipe, RecipeChangeset},
+    review::{Review, ReviewChangeset},
+    share_token::ShareToken,
+    visibility::Visibility,
 };
 use async_trait::async_trait;
 use errors::AddIngredientIntoRecipeError;
@@ -12,17 +15,50 @@ use std::sync::Arc;
 use uuid::Uuid;
 
 use self::errors::{
-    DeleteIngredientFromRecipeError, DeleteRecipeError, GetRecipeByIdError, InsertRecipeError,
-    UpdateIngredientInRecipeError, UpdateRecipeError,
+    DeleteIngredientFromRecipeError, DeleteRecipeError, DeleteShareTokenError, GetRecipeByIdError,
+    GetReviewError, GetShareTokenError, InsertRecipeError, InsertReviewError,
+    InsertShareTokenError, UpdateIngredientInRecipeError, UpdateRecipeError, UpdateReviewError,
 };
 
 #[async_trait]
 pub trait RecipeRepository: Send + Sync + 'static {
-    // TODO: Include user information
     async fn insert(&self, input: Recipe) -> Result<(), InsertRecipeError>;
 
     async fn get_by_id(&self, id: &Uuid) -> Result<Recipe, GetRecipeByIdError>;
 
+    /// Gets the recipe the way the user sees it: unlisted recipes need a share token and private ones are only found by their owner.
+    /// Hidden recipes are reported as missing, so that nobody can tell they exist.
+    async fn get_visible(
+        &self,
+        user_id: Option<&Uuid>,
+        id: &Uuid,
+        share_token: Option<&str>,
+    ) -> Result<Recipe, GetRecipeByIdError> {
+        let recipe = self.get_by_id(id).await?;
+
+        let share_token = match (recipe.visibility, share_token) {
+            (Visibility::Unlisted, Some(token)) => match self.get_share_token(token).await {
+                Ok(share_token) => Some(share_token),
+                Err(GetShareTokenError::NotFound) => None,
+                Err(e) => return Err(GetRecipeByIdError::UnknownError(e.into())),
+            },
+            _ => None,
+        };
+
+        if !recipe.is_visible_to(user_id, share_token.as_ref()) {
+            return Err(GetRecipeByIdError::NotFound(*id));
+        }
+
+        Ok(recipe)
+    }
+
+    /// Every recipe, in no particular order, whatever its visibility
+    async fn get_all(&self) -> Result<Vec<Recipe>, GetRecipeByIdError>;
+
+    /// Recipes forked directly from the recipe, oldest first
+    async fn get_forks(&self, recipe: &Recipe) -> Result<Vec<Recipe>, GetRecipeByIdError>;
+
+    /// Forks of a deleted recipe are kept, but they no longer point to it
     async fn delete(&self, recipe: &Recipe) -> Result<(), DeleteRecipeError>;
 
     async fn update(
@@ -43,17 +79,51 @@ pub trait RecipeRepository: Send + Sync + 'static {
         ingredient: &IngredientWithAmount,
     ) -> Result<(), DeleteIngredientFromRecipeError>;
 
-    async fn update_ingredient_amount(
+    async fn update_ingredient(
         &self,
         recipe: &Recipe,
         ingredient: &IngredientWithAmount,
-        new_amount: &IngredientUnit,
+        changeset: IngredientInRecipeChangeset,
     ) -> Result<(), UpdateIngredientInRecipeError>;
 
+    /// Every recipe that uses at least one of the ingredients
+    async fn get_by_ingredients(
+        &self,
+        ingredient_ids: &[Uuid],
+    ) -> Result<Vec<Recipe>, GetRecipeByIdError>;
+
     async fn recipes_containing_ingredient_exist(
         &self,
         ingredient: Ingredient,
     ) -> eyre::Result<bool>;
+
+    async fn add_review(&self, recipe: &Recipe, review: Review) -> Result<(), InsertReviewError>;
+
+    async fn get_review(&self, recipe: &Recipe, id: &Uuid) -> Result<Review, GetReviewError>;
+
+    /// Reviews of the recipe, oldest first
+    async fn get_reviews(&self, recipe: &Recipe) -> Result<Vec<Review>, GetReviewError>;
+
+    async fn update_review(
+        &self,
+        review: &Review,
+        changeset: ReviewChangeset,
+    ) -> Result<(), UpdateReviewError>;
+
+    async fn add_share_token(&self, share_token: ShareToken) -> Result<(), InsertShareTokenError>;
+
+    async fn get_share_token(&self, token: &str) -> Result<ShareToken, GetShareTokenError>;
+
+    /// Share tokens of the recipe, oldest first
+    async fn get_share_tokens(
+        &self,
+        recipe: &Recipe,
+    ) -> Result<Vec<ShareToken>, GetShareTokenError>;
+
+    async fn delete_share_token(
+        &self,
+        share_token: &ShareToken,
+    ) -> Result<(), DeleteShareTokenError>;
 }
 
 pub type RecipeRepositoryService = Arc<Box<dyn RecipeRepository>>;
@@ -1,29 +1,39 @@
+use std::str::FromStr;
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use futures::future::join_all;
-use sqlx::PgPool;
+use sqlx::{Connection, PgConnection, PgPool};
+use strum::VariantNames;
 use uuid::Uuid;
 
+use crate::domain::entities::event::DomainEvent;
 use crate::domain::entities::ingredient::{Ingredient, IngredientModel};
+use crate::domain::entities::recipe::errors::ValidationError;
 use crate::domain::entities::recipe::{
-    IngredientUnit, IngredientWithAmount, IngredientWithAmountModel, Recipe, RecipeChangeset,
+    IngredientInRecipeChangeset, IngredientWithAmount, IngredientWithAmountModel, Recipe,
+    RecipeChangeset,
 };
+use crate::domain::entities::review::{RecipeRating, Review, ReviewChangeset, ReviewModel};
+use crate::domain::entities::share_token::ShareToken;
+use crate::domain::entities::visibility::Visibility;
 
 use super::errors::{
     AddIngredientIntoRecipeError, DeleteIngredientFromRecipeError, DeleteRecipeError,
-    UpdateIngredientInRecipeError, UpdateRecipeError,
+    DeleteShareTokenError, GetReviewError, GetShareTokenError, InsertReviewError,
+    InsertShareTokenError, UpdateIngredientInRecipeError, UpdateRecipeError, UpdateReviewError,
 };
 use super::RecipeRepositoryService;
 use super::{
     errors::{GetRecipeByIdError, InsertRecipeError},
     RecipeRepository,
 };
+use crate::domain::repositories::outbox::postgres::record_event;
+use crate::domain::repositories::unit_of_work::postgres::PostgresExecutor;
 
-pub struct PostgresRecipeRepository(pub PgPool);
+pub struct PostgresRecipeRepository(pub PostgresExecutor);
 
 async fn insert_ingredient(
-    pool: &PgPool,
+    conn: &mut PgConnection,
     id: Uuid,
     ingredient: &IngredientWithAmount,
 ) -> Result<(), AddIngredientIntoRecipeError> {
@@ -33,22 +43,74 @@ async fn insert_ingredient(
     sqlx::query_file!(
         "queries/recipes/insert_ingredient.sql",
         id,
+        ingredient.id,
         ingredient.ingredient.id,
         amount,
         ingredient.notes,
-        ingredient.optional
+        ingredient.optional,
+        ingredient.group
     )
-    .execute(pool)
+    .execute(conn)
     .await
     .map_err(AddIngredientIntoRecipeError::from)?;
 
     Ok(())
 }
 
-async fn update_timestamps_in_recipe(pool: &PgPool, id: Uuid) {
-    let _ = sqlx::query_file!("queries/recipes/update_recipe_timestamps.sql", id)
-        .execute(pool)
-        .await;
+async fn update_timestamps_in_recipe(conn: &mut PgConnection, id: Uuid) -> sqlx::Result<()> {
+    sqlx::query_file!("queries/recipes/update_recipe_timestamps.sql", id)
+        .execute(conn)
+        .await?;
+
+    Ok(())
+}
+
+/// Reads the recipe on the given connection, so that a transaction can see its own changes
+async fn fetch_recipe(conn: &mut PgConnection, id: &Uuid) -> Result<Recipe, GetRecipeByIdError> {
+    let result = sqlx::query_file!("queries/recipes/get_recipe.sql", id)
+        .fetch_one(&mut *conn)
+        .await
+        .map_err(|e| GetRecipeByIdError::with_id(id, e))?;
+
+    let result_ingredients = sqlx::query_file_as!(
+        IngredientWithAmountModel,
+        "queries/recipes/get_ingredients_for_recipe.sql",
+        id
+    )
+    .fetch_all(&mut *conn)
+    .await?;
+
+    let ingredients = result_ingredients
+        .iter()
+        .map(IngredientWithAmount::try_from)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(GetRecipeByIdError::from)?;
+
+    let time = serde_json::from_value(result.time)?;
+
+    let servings = serde_json::from_value(result.servings)?;
+
+    let recipe = Recipe {
+        id: result.id,
+        name: result.name,
+        description: result.description,
+        steps: result.steps.try_into()?,
+        time,
+        servings,
+        ingredients: ingredients.try_into()?,
+        rating: RecipeRating {
+            average: result.rating_average,
+            count: result.rating_count.try_into().unwrap_or_default(),
+        },
+        forked_from: result.forked_from,
+        visibility: Visibility::from_str(&result.visibility)
+            .map_err(|_| ValidationError::DoesNotMatch("visibility", Visibility::VARIANTS))?,
+        owner_id: result.owner_id,
+        created_at: result.created_at,
+        updated_at: result.updated_at,
+    };
+
+    Ok(recipe)
 }
 
 #[async_trait]
@@ -60,7 +122,8 @@ impl RecipeRepository for PostgresRecipeRepository {
         let servings = serde_json::to_value(&input.servings)
             .map_err(|e| InsertRecipeError::UnknownError(e.into()))?;
 
-        let tx = self.0.begin().await.map_err(InsertRecipeError::from)?;
+        let mut conn = self.0.acquire().await.map_err(InsertRecipeError::from)?;
+        let mut tx = conn.begin().await.map_err(InsertRecipeError::from)?;
 
         let result = sqlx::query_file!(
             "queries/recipes/insert_recipe.sql",
@@ -70,21 +133,21 @@ impl RecipeRepository for PostgresRecipeRepository {
             &input.steps.as_ref(),
             time,
             servings,
-            serde_json::json!({})
+            serde_json::json!({}),
+            input.forked_from,
+            input.visibility.to_string(),
+            input.owner_id
         )
-        .fetch_one(&self.0)
+        .fetch_one(&mut *tx)
         .await
         .map_err(InsertRecipeError::from)?;
 
-        join_all(
-            input
-                .ingredients
-                .iter()
-                .map(|i| insert_ingredient(&self.0, result.id, i)),
-        )
-        .await
-        .into_iter()
-        .collect::<Result<Vec<()>, AddIngredientIntoRecipeError>>()?;
+        for ingredient in input.ingredients.iter() {
+            insert_ingredient(&mut tx, result.id, ingredient).await?;
+        }
+
+        let recipe = fetch_recipe(&mut tx, &result.id).await?;
+        record_event(&mut tx, DomainEvent::RecipeCreated(recipe)).await?;
 
         tx.commit().await.map_err(InsertRecipeError::from)?;
 
@@ -92,58 +155,62 @@ impl RecipeRepository for PostgresRecipeRepository {
     }
 
     async fn get_by_id(&self, id: &Uuid) -> Result<Recipe, GetRecipeByIdError> {
-        let result = sqlx::query_file!("queries/recipes/get_recipe.sql", id)
-            .fetch_one(&self.0)
-            .await
-            .map_err(|e| GetRecipeByIdError::with_id(id, e))?;
+        let mut conn = self.0.acquire().await?;
 
-        let result_ingredients = sqlx::query_file_as!(
-            IngredientWithAmountModel,
-            "queries/recipes/get_ingredients_for_recipe.sql",
-            id
-        )
-        .fetch_all(&self.0)
-        .await?;
+        fetch_recipe(&mut conn, id).await
+    }
 
-        let ingredients = result_ingredients
-            .iter()
-            .map(IngredientWithAmount::try_from)
-            .collect::<Result<Vec<_>, _>>()
-            .map_err(GetRecipeByIdError::from)?;
+    async fn get_all(&self) -> Result<Vec<Recipe>, GetRecipeByIdError> {
+        let mut conn = self.0.acquire().await?;
 
-        let time = serde_json::from_value(result.time)?;
+        let recipe_ids = sqlx::query_file!("queries/recipes/get_recipes.sql")
+            .fetch_all(&mut *conn)
+            .await?;
 
-        let servings = serde_json::from_value(result.servings)?;
+        let mut result = Vec::with_capacity(recipe_ids.len());
+        for row in recipe_ids {
+            result.push(fetch_recipe(&mut conn, &row.id).await?);
+        }
 
-        let recipe = Recipe {
-            id: result.id,
-            name: result.name,
-            description: result.description,
-            steps: result.steps.try_into()?,
-            time,
-            servings,
-            ingredients: ingredients.try_into()?,
-            created_at: result.created_at,
-            updated_at: result.updated_at,
-        };
+        Ok(result)
+    }
 
-        Ok(recipe)
+    async fn get_forks(&self, recipe: &Recipe) -> Result<Vec<Recipe>, GetRecipeByIdError> {
+        let mut conn = self.0.acquire().await?;
+
+        let recipe_ids = sqlx::query_file!("queries/recipes/get_forks_of_recipe.sql", recipe.id)
+            .fetch_all(&mut *conn)
+            .await?;
+
+        let mut result = Vec::with_capacity(recipe_ids.len());
+        for row in recipe_ids {
+            result.push(fetch_recipe(&mut conn, &row.id).await?);
+        }
+
+        Ok(result)
     }
 
     async fn delete(&self, recipe: &Recipe) -> Result<(), DeleteRecipeError> {
-        let tx = self.0.begin().await?;
+        let mut conn = self.0.acquire().await?;
+        let mut tx = conn.begin().await?;
+
+        sqlx::query_file!("queries/recipes/delete_reviews_for_recipe.sql", recipe.id)
+            .execute(&mut *tx)
+            .await?;
 
         sqlx::query_file!(
             "queries/recipes/delete_ingredients_for_recipe.sql",
             recipe.id
         )
-        .execute(&self.0)
+        .execute(&mut *tx)
         .await?;
 
         sqlx::query_file!("queries/recipes/delete_recipe.sql", recipe.id)
-            .execute(&self.0)
+            .execute(&mut *tx)
             .await?;
 
+        record_event(&mut tx, DomainEvent::RecipeDeleted(recipe.id)).await?;
+
         tx.commit().await?;
 
         Ok(())
@@ -155,7 +222,8 @@ impl RecipeRepository for PostgresRecipeRepository {
         changeset: RecipeChangeset,
     ) -> Result<(), UpdateRecipeError> {
         let id = &recipe.id;
-        let tx = self.0.begin().await?;
+        let mut conn = self.0.acquire().await?;
+        let mut tx = conn.begin().await?;
         let mut updated = false;
 
         if let Some(value) = changeset.name {
@@ -169,7 +237,7 @@ impl RecipeRepository for PostgresRecipeRepository {
                     id,
                     value
                 )
-                .execute(&self.0)
+                .execute(&mut *tx)
                 .await?;
                 updated = true;
             }
@@ -186,7 +254,7 @@ impl RecipeRepository for PostgresRecipeRepository {
                     id,
                     value
                 )
-                .execute(&self.0)
+                .execute(&mut *tx)
                 .await?;
                 updated = true;
             }
@@ -206,7 +274,7 @@ impl RecipeRepository for PostgresRecipeRepository {
                     id,
                     value
                 )
-                .execute(&self.0)
+                .execute(&mut *tx)
                 .await?;
                 updated = true;
             }
@@ -226,7 +294,24 @@ impl RecipeRepository for PostgresRecipeRepository {
                     id,
                     value
                 )
-                .execute(&self.0)
+                .execute(&mut *tx)
+                .await?;
+                updated = true;
+            }
+        }
+
+        if let Some(value) = changeset.visibility {
+            if value != recipe.visibility {
+                sqlx::query!(
+                    r#"
+                    UPDATE recipes
+                    SET visibility = $2
+                    WHERE id = $1
+                    "#,
+                    id,
+                    value.to_string()
+                )
+                .execute(&mut *tx)
                 .await?;
                 updated = true;
             }
@@ -245,14 +330,17 @@ impl RecipeRepository for PostgresRecipeRepository {
                     id,
                     value
                 )
-                .execute(&self.0)
+                .execute(&mut *tx)
                 .await?;
                 updated = true;
             }
         }
 
         if updated {
-            update_timestamps_in_recipe(&self.0, *id).await;
+            update_timestamps_in_recipe(&mut tx, *id).await?;
+
+            let recipe = fetch_recipe(&mut tx, id).await?;
+            record_event(&mut tx, DomainEvent::RecipeUpdated(recipe)).await?;
         }
 
         tx.commit()
@@ -266,8 +354,22 @@ impl RecipeRepository for PostgresRecipeRepository {
         recipe: &Recipe,
         ingredient: IngredientWithAmount,
     ) -> Result<(), AddIngredientIntoRecipeError> {
-        insert_ingredient(&self.0, recipe.id, &ingredient).await?;
-        update_timestamps_in_recipe(&self.0, recipe.id).await;
+        let mut conn = self.0.acquire().await?;
+        let mut tx = conn.begin().await?;
+
+        insert_ingredient(&mut tx, recipe.id, &ingredient).await?;
+        update_timestamps_in_recipe(&mut tx, recipe.id).await?;
+
+        record_event(
+            &mut tx,
+            DomainEvent::IngredientAddedToRecipe {
+                recipe_id: recipe.id,
+                ingredient_id: ingredient.ingredient.id,
+            },
+        )
+        .await?;
+
+        tx.commit().await?;
 
         Ok(())
     }
@@ -277,63 +379,282 @@ impl RecipeRepository for PostgresRecipeRepository {
         recipe: &Recipe,
         ingredient: &IngredientWithAmount,
     ) -> Result<(), DeleteIngredientFromRecipeError> {
+        let mut conn = self.0.acquire().await?;
+        let mut tx = conn.begin().await?;
+
         sqlx::query_file!(
             "queries/recipes/delete_ingredient_from_recipe_by_id.sql",
             recipe.id,
-            ingredient.ingredient.id
+            ingredient.id
         )
-        .execute(&self.0)
+        .execute(&mut *tx)
         .await?;
 
-        update_timestamps_in_recipe(&self.0, recipe.id).await;
+        update_timestamps_in_recipe(&mut tx, recipe.id).await?;
+
+        record_event(
+            &mut tx,
+            DomainEvent::IngredientRemovedFromRecipe {
+                recipe_id: recipe.id,
+                ingredient_id: ingredient.ingredient.id,
+            },
+        )
+        .await?;
+
+        tx.commit().await?;
 
         Ok(())
     }
 
-    async fn update_ingredient_amount(
+    async fn update_ingredient(
         &self,
         recipe: &Recipe,
         ingredient: &IngredientWithAmount,
-        new_amount: &IngredientUnit,
+        changeset: IngredientInRecipeChangeset,
     ) -> Result<(), UpdateIngredientInRecipeError> {
-        let tx = self.0.begin().await?;
+        let mut conn = self.0.acquire().await?;
+        let mut tx = conn.begin().await?;
 
-        let amount = serde_json::to_value(new_amount)?;
+        let amount = changeset
+            .amount
+            .as_ref()
+            .map(serde_json::to_value)
+            .transpose()?;
+        let notes = changeset.notes.clone().flatten();
+        let group = changeset.group.clone().flatten();
 
         sqlx::query_file!(
             "queries/recipes/update_ingredient_in_recipe.sql",
             recipe.id,
-            ingredient.ingredient.id,
-            amount
+            ingredient.id,
+            amount,
+            changeset.optional,
+            changeset.notes.is_some(),
+            notes,
+            changeset.group.is_some(),
+            group
         )
-        .execute(&self.0)
+        .execute(&mut *tx)
         .await?;
 
-        update_timestamps_in_recipe(&self.0, recipe.id).await;
+        if changeset.position.is_some() {
+            let mut order: Vec<Uuid> = sqlx::query_file_scalar!(
+                "queries/recipes/get_ingredient_order_for_recipe.sql",
+                recipe.id
+            )
+            .fetch_all(&mut *tx)
+            .await?;
+
+            if let Some(index) = order.iter().position(|id| *id == ingredient.id) {
+                changeset.reorder(&mut order, index);
+            }
+
+            sqlx::query_file!(
+                "queries/recipes/reorder_ingredients_in_recipe.sql",
+                recipe.id,
+                &order
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        update_timestamps_in_recipe(&mut tx, recipe.id).await?;
+
+        record_event(
+            &mut tx,
+            DomainEvent::IngredientInRecipeUpdated {
+                recipe_id: recipe.id,
+                ingredient_id: ingredient.ingredient.id,
+            },
+        )
+        .await?;
 
         tx.commit().await?;
 
         Ok(())
     }
 
+    async fn get_by_ingredients(
+        &self,
+        ingredient_ids: &[Uuid],
+    ) -> Result<Vec<Recipe>, GetRecipeByIdError> {
+        let mut conn = self.0.acquire().await?;
+
+        let recipe_ids = sqlx::query_file!(
+            "queries/recipes/get_recipes_using_any_ingredient.sql",
+            ingredient_ids
+        )
+        .fetch_all(&mut *conn)
+        .await?;
+
+        let mut result = Vec::with_capacity(recipe_ids.len());
+        for row in recipe_ids {
+            result.push(fetch_recipe(&mut conn, &row.recipe_id).await?);
+        }
+
+        Ok(result)
+    }
+
     async fn recipes_containing_ingredient_exist(
         &self,
         ingredient: Ingredient,
     ) -> eyre::Result<bool> {
+        let mut conn = self.0.acquire().await?;
+
         let recipes_using_ingredient = sqlx::query_file!(
             "queries/recipes/get_recipes_using_ingredient.sql",
             ingredient.id
         )
-        .fetch_optional(&self.0)
+        .fetch_optional(&mut *conn)
         .await?;
 
         Ok(recipes_using_ingredient.is_some())
     }
+
+    async fn add_review(&self, recipe: &Recipe, review: Review) -> Result<(), InsertReviewError> {
+        let mut conn = self.0.acquire().await?;
+
+        sqlx::query_file!(
+            "queries/recipes/insert_review.sql",
+            review.id,
+            recipe.id,
+            review.user_id,
+            review.author,
+            i16::from(review.rating.value()),
+            review.body,
+            review.created_at,
+            review.updated_at
+        )
+        .execute(&mut *conn)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn get_review(&self, recipe: &Recipe, id: &Uuid) -> Result<Review, GetReviewError> {
+        let mut conn = self.0.acquire().await?;
+
+        let result =
+            sqlx::query_file_as!(ReviewModel, "queries/recipes/get_review.sql", id, recipe.id)
+                .fetch_one(&mut *conn)
+                .await
+                .map_err(|e| GetReviewError::with_id(id, e))?;
+
+        Ok(result.try_into()?)
+    }
+
+    async fn get_reviews(&self, recipe: &Recipe) -> Result<Vec<Review>, GetReviewError> {
+        let mut conn = self.0.acquire().await?;
+
+        let result = sqlx::query_file_as!(
+            ReviewModel,
+            "queries/recipes/get_reviews_for_recipe.sql",
+            recipe.id
+        )
+        .fetch_all(&mut *conn)
+        .await?;
+
+        Ok(result
+            .into_iter()
+            .map(Review::try_from)
+            .collect::<Result<Vec<_>, _>>()?)
+    }
+
+    async fn update_review(
+        &self,
+        review: &Review,
+        changeset: ReviewChangeset,
+    ) -> Result<(), UpdateReviewError> {
+        let mut conn = self.0.acquire().await?;
+
+        let rating = changeset.rating.map(|rating| i16::from(rating.value()));
+        let body = changeset.body.clone().flatten();
+
+        sqlx::query_file!(
+            "queries/recipes/update_review.sql",
+            review.id,
+            rating,
+            changeset.body.is_some(),
+            body
+        )
+        .execute(&mut *conn)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn add_share_token(&self, share_token: ShareToken) -> Result<(), InsertShareTokenError> {
+        let mut conn = self.0.acquire().await?;
+
+        sqlx::query_file!(
+            "queries/recipes/insert_share_token.sql",
+            share_token.token,
+            share_token.recipe_id,
+            share_token.created_at
+        )
+        .execute(&mut *conn)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn get_share_token(&self, token: &str) -> Result<ShareToken, GetShareTokenError> {
+        let mut conn = self.0.acquire().await?;
+
+        let result = sqlx::query_file_as!(ShareToken, "queries/recipes/get_share_token.sql", token)
+            .fetch_one(&mut *conn)
+            .await?;
+
+        Ok(result)
+    }
+
+    async fn get_share_tokens(
+        &self,
+        recipe: &Recipe,
+    ) -> Result<Vec<ShareToken>, GetShareTokenError> {
+        let mut conn = self.0.acquire().await?;
+
+        let result = sqlx::query_file_as!(
+            ShareToken,
+            "queries/recipes/get_share_tokens_for_recipe.sql",
+            recipe.id
+        )
+        .fetch_all(&mut *conn)
+        .await?;
+
+        Ok(result)
+    }
+
+    async fn delete_share_token(
+        &self,
+        share_token: &ShareToken,
+    ) -> Result<(), DeleteShareTokenError> {
+        let mut conn = self.0.acquire().await?;
+
+        sqlx::query_file!("queries/recipes/delete_share_token.sql", share_token.token)
+            .execute(&mut *conn)
+            .await?;
+
+        Ok(())
+    }
 }
 
 impl PostgresRecipeRepository {
     pub fn new(pool: PgPool) -> Self {
-        Self(pool)
+        Self(pool.into())
+    }
+
+    /// Like `get_by_id`, but nobody else can change the recipe until the transaction the
+    /// repository runs in ends
+    pub(crate) async fn lock(&self, id: &Uuid) -> Result<Recipe, GetRecipeByIdError> {
+        let mut conn = self.0.acquire().await?;
+
+        sqlx::query_file!("queries/recipes/lock_recipe.sql", id)
+            .fetch_one(&mut *conn)
+            .await
+            .map_err(|e| GetRecipeByIdError::with_id(id, e))?;
+
+        fetch_recipe(&mut conn, id).await
     }
 
     pub fn service(self) -> RecipeRepositoryService {
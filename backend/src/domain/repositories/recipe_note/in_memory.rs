@@ -0,0 +1,73 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use async_trait::async_trait;
+use eyre::eyre;
+use uuid::Uuid;
+
+use crate::domain::entities::recipe_note::RecipeNote;
+
+use super::{
+    errors::{DeleteRecipeNoteError, GetRecipeNoteError, SaveRecipeNoteError},
+    RecipeNoteRepository, RecipeNoteRepositoryService,
+};
+
+/// Notes by user and recipe
+pub struct InMemoryRecipeNoteRepository(pub Mutex<HashMap<(Uuid, Uuid), RecipeNote>>);
+
+#[async_trait]
+impl RecipeNoteRepository for InMemoryRecipeNoteRepository {
+    async fn save(&self, note: &RecipeNote) -> Result<(), SaveRecipeNoteError> {
+        let mut lock = self.0.lock()?;
+
+        lock.entry((note.user_id, note.recipe_id))
+            .and_modify(|existing| {
+                existing.content.clone_from(&note.content);
+                existing.updated_at = note.updated_at;
+            })
+            .or_insert_with(|| note.clone());
+
+        Ok(())
+    }
+
+    async fn get(
+        &self,
+        user_id: &Uuid,
+        recipe_id: &Uuid,
+    ) -> Result<RecipeNote, GetRecipeNoteError> {
+        let lock = self.0.lock()?;
+
+        lock.get(&(*user_id, *recipe_id))
+            .cloned()
+            .ok_or(GetRecipeNoteError::NotFound(*recipe_id))
+    }
+
+    async fn delete(&self, note: &RecipeNote) -> Result<(), DeleteRecipeNoteError> {
+        let mut lock = self.0.lock()?;
+
+        lock.remove(&(note.user_id, note.recipe_id))
+            .ok_or(DeleteRecipeNoteError::UnknownError(eyre!(
+                "The note could not be found somehow"
+            )))?;
+
+        Ok(())
+    }
+}
+
+impl Default for InMemoryRecipeNoteRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InMemoryRecipeNoteRepository {
+    pub fn new() -> Self {
+        Self(Mutex::new(HashMap::new()))
+    }
+
+    pub fn service(self) -> RecipeNoteRepositoryService {
+        Arc::new(Box::new(self))
+    }
+}
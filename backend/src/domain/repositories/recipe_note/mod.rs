@@ -0,0 +1,25 @@
+pub mod errors;
+pub mod in_memory;
+pub mod postgres;
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::recipe_note::RecipeNote;
+
+use self::errors::{DeleteRecipeNoteError, GetRecipeNoteError, SaveRecipeNoteError};
+
+#[async_trait]
+pub trait RecipeNoteRepository: Send + Sync + 'static {
+    /// Creates the note of the recipe, or replaces its content if there is one already
+    async fn save(&self, note: &RecipeNote) -> Result<(), SaveRecipeNoteError>;
+
+    async fn get(&self, user_id: &Uuid, recipe_id: &Uuid)
+        -> Result<RecipeNote, GetRecipeNoteError>;
+
+    async fn delete(&self, note: &RecipeNote) -> Result<(), DeleteRecipeNoteError>;
+}
+
+pub type RecipeNoteRepositoryService = Arc<Box<dyn RecipeNoteRepository>>;
@@ -0,0 +1,75 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::entities::recipe_note::RecipeNote;
+
+use super::{
+    errors::{DeleteRecipeNoteError, GetRecipeNoteError, SaveRecipeNoteError},
+    RecipeNoteRepository, RecipeNoteRepositoryService,
+};
+
+pub struct PostgresRecipeNoteRepository(pub PgPool);
+
+#[async_trait]
+impl RecipeNoteRepository for PostgresRecipeNoteRepository {
+    #[tracing::instrument("[RECIPE NOTE REPOSITORY] [POSTGRES] Save a note", skip(self))]
+    async fn save(&self, note: &RecipeNote) -> Result<(), SaveRecipeNoteError> {
+        sqlx::query_file!(
+            "queries/recipe_notes/save_recipe_note.sql",
+            note.user_id,
+            note.recipe_id,
+            note.content,
+            note.created_at,
+            note.updated_at
+        )
+        .execute(&self.0)
+        .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument("[RECIPE NOTE REPOSITORY] [POSTGRES] Get note of recipe", skip(self))]
+    async fn get(
+        &self,
+        user_id: &Uuid,
+        recipe_id: &Uuid,
+    ) -> Result<RecipeNote, GetRecipeNoteError> {
+        let result = sqlx::query_file_as!(
+            RecipeNote,
+            "queries/recipe_notes/get_recipe_note.sql",
+            user_id,
+            recipe_id
+        )
+        .fetch_one(&self.0)
+        .await
+        .map_err(|e| GetRecipeNoteError::with_id(recipe_id, e))?;
+
+        Ok(result)
+    }
+
+    #[tracing::instrument("[RECIPE NOTE REPOSITORY] [POSTGRES] Delete a note", skip(self))]
+    async fn delete(&self, note: &RecipeNote) -> Result<(), DeleteRecipeNoteError> {
+        sqlx::query_file!(
+            "queries/recipe_notes/delete_recipe_note.sql",
+            note.user_id,
+            note.recipe_id
+        )
+        .execute(&self.0)
+        .await?;
+
+        Ok(())
+    }
+}
+
+impl PostgresRecipeNoteRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self(pool)
+    }
+
+    pub fn service(self) -> RecipeNoteRepositoryService {
+        Arc::new(Box::new(self))
+    }
+}
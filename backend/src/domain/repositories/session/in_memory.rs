@@ -0,0 +1,60 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use async_trait::async_trait;
+
+use crate::domain::entities::user::Session;
+
+use super::{
+    errors::{DeleteSessionError, GetSessionError, InsertSessionError},
+    SessionRepository, SessionRepositoryService,
+};
+
+pub struct InMemorySessionRepository(pub Mutex<HashMap<String, Session>>);
+
+#[async_trait]
+impl SessionRepository for InMemorySessionRepository {
+    async fn insert(&self, session: &Session) -> Result<(), InsertSessionError> {
+        let mut lock = self.0.lock()?;
+
+        lock.retain(|_, session| !session.is_expired());
+        lock.insert(session.token_hash.clone(), session.clone());
+
+        Ok(())
+    }
+
+    async fn get(&self, token_hash: &str) -> Result<Session, GetSessionError> {
+        let lock = self.0.lock()?;
+
+        lock.get(token_hash)
+            .filter(|session| !session.is_expired())
+            .cloned()
+            .ok_or(GetSessionError::NotFound)
+    }
+
+    async fn delete(&self, token_hash: &str) -> Result<(), DeleteSessionError> {
+        let mut lock = self.0.lock()?;
+
+        lock.remove(token_hash);
+
+        Ok(())
+    }
+}
+
+impl Default for InMemorySessionRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InMemorySessionRepository {
+    pub fn new() -> Self {
+        Self(Mutex::new(HashMap::new()))
+    }
+
+    pub fn service(self) -> SessionRepositoryService {
+        Arc::new(Box::new(self))
+    }
+}
@@ -0,0 +1,25 @@
+pub mod errors;
+pub mod in_memory;
+pub mod redis;
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::entities::user::Session;
+
+use self::errors::{DeleteSessionError, GetSessionError, InsertSessionError};
+
+#[async_trait]
+pub trait SessionRepository: Send + Sync + 'static {
+    /// Keeps the session until it expires
+    async fn insert(&self, session: &Session) -> Result<(), InsertSessionError>;
+
+    /// The session opened with the token hashed as `token_hash`, as long as it has not expired
+    async fn get(&self, token_hash: &str) -> Result<Session, GetSessionError>;
+
+    /// Ends the session, ending one that does not exist is not an error
+    async fn delete(&self, token_hash: &str) -> Result<(), DeleteSessionError>;
+}
+
+pub type SessionRepositoryService = Arc<Box<dyn SessionRepository>>;
@@ -0,0 +1,89 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use redis::{aio::ConnectionManager, AsyncCommands, Client, RedisError};
+use tokio::sync::OnceCell;
+
+use crate::domain::entities::user::Session;
+
+use super::{
+    errors::{DeleteSessionError, GetSessionError, InsertSessionError},
+    SessionRepository, SessionRepositoryService,
+};
+
+/// Keeps sessions in Redis, which throws them away by itself once they expire
+pub struct RedisSessionRepository {
+    client: Client,
+    /// Connected on first use, so that the app can start before Redis does
+    connection: OnceCell<ConnectionManager>,
+}
+
+#[async_trait]
+impl SessionRepository for RedisSessionRepository {
+    #[tracing::instrument("[SESSION REPOSITORY] [REDIS] Open a session", skip_all)]
+    async fn insert(&self, session: &Session) -> Result<(), InsertSessionError> {
+        let seconds = (session.expires_at - Utc::now()).num_seconds();
+
+        if seconds <= 0 {
+            return Ok(());
+        }
+
+        self.connection()
+            .await?
+            .set_ex::<_, _, ()>(
+                Self::key(&session.token_hash),
+                serde_json::to_string(session)?,
+                seconds.unsigned_abs(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument("[SESSION REPOSITORY] [REDIS] Get a session", skip_all)]
+    async fn get(&self, token_hash: &str) -> Result<Session, GetSessionError> {
+        let value: Option<String> = self.connection().await?.get(Self::key(token_hash)).await?;
+        let session: Session = serde_json::from_str(&value.ok_or(GetSessionError::NotFound)?)?;
+
+        if session.is_expired() {
+            return Err(GetSessionError::NotFound);
+        }
+
+        Ok(session)
+    }
+
+    #[tracing::instrument("[SESSION REPOSITORY] [REDIS] End a session", skip_all)]
+    async fn delete(&self, token_hash: &str) -> Result<(), DeleteSessionError> {
+        self.connection()
+            .await?
+            .del::<_, ()>(Self::key(token_hash))
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl RedisSessionRepository {
+    pub fn new(client: Client) -> Self {
+        Self {
+            client,
+            connection: OnceCell::new(),
+        }
+    }
+
+    pub fn service(self) -> SessionRepositoryService {
+        Arc::new(Box::new(self))
+    }
+
+    async fn connection(&self) -> Result<ConnectionManager, RedisError> {
+        self.connection
+            .get_or_try_init(|| ConnectionManager::new(self.client.clone()))
+            .await
+            .cloned()
+    }
+
+    fn key(token_hash: &str) -> String {
+        format!("session:{token_hash}")
+    }
+}
@@ -0,0 +1,96 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use async_trait::async_trait;
+use chrono::Utc;
+use eyre::eyre;
+use uuid::Uuid;
+
+use crate::domain::entities::shopping_list::ShoppingList;
+
+use super::{
+    errors::{
+        DeleteShoppingListError, GetShoppingListByIdError, InsertShoppingListError,
+        UpdateShoppingListItemError,
+    },
+    ShoppingListRepository, ShoppingListRepositoryService,
+};
+
+pub struct InMemoryShoppingListRepository(pub Mutex<HashMap<Uuid, ShoppingList>>);
+
+#[async_trait]
+impl ShoppingListRepository for InMemoryShoppingListRepository {
+    async fn insert(&self, shopping_list: ShoppingList) -> Result<(), InsertShoppingListError> {
+        let mut lock = self.0.lock()?;
+
+        if lock.contains_key(&shopping_list.id) {
+            return Err(InsertShoppingListError::Conflict(shopping_list.id));
+        }
+
+        lock.insert(shopping_list.id, shopping_list);
+
+        Ok(())
+    }
+
+    async fn get_by_id(&self, id: &Uuid) -> Result<ShoppingList, GetShoppingListByIdError> {
+        let lock = self.0.lock()?;
+
+        lock.get(id)
+            .cloned()
+            .ok_or(GetShoppingListByIdError::NotFound(*id))
+    }
+
+    async fn set_item_checked(
+        &self,
+        shopping_list: &ShoppingList,
+        item_id: &Uuid,
+        checked: bool,
+    ) -> Result<(), UpdateShoppingListItemError> {
+        let mut lock = self.0.lock()?;
+        let shopping_list =
+            lock.get_mut(&shopping_list.id)
+                .ok_or(UpdateShoppingListItemError::UnknownError(eyre!(
+                    "The shopping list could not be found somehow"
+                )))?;
+
+        let item = shopping_list
+            .items
+            .iter_mut()
+            .find(|item| &item.id == item_id)
+            .ok_or(UpdateShoppingListItemError::NotFound(*item_id))?;
+
+        item.checked = checked;
+        shopping_list.updated_at = Utc::now();
+
+        Ok(())
+    }
+
+    async fn delete(&self, shopping_list: &ShoppingList) -> Result<(), DeleteShoppingListError> {
+        let mut lock = self.0.lock()?;
+
+        lock.remove(&shopping_list.id)
+            .ok_or(DeleteShoppingListError::UnknownError(eyre!(
+                "The shopping list could not be found somehow"
+            )))?;
+
+        Ok(())
+    }
+}
+
+impl Default for InMemoryShoppingListRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InMemoryShoppingListRepository {
+    pub fn new() -> Self {
+        Self(Mutex::new(HashMap::new()))
+    }
+
+    pub fn service(self) -> ShoppingListRepositoryService {
+        Arc::new(Box::new(self))
+    }
+}
@@ -0,0 +1,33 @@
+pub mod errors;
+pub mod in_memory;
+pub mod postgres;
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::shopping_list::ShoppingList;
+
+use self::errors::{
+    DeleteShoppingListError, GetShoppingListByIdError, InsertShoppingListError,
+    UpdateShoppingListItemError,
+};
+
+#[async_trait]
+pub trait ShoppingListRepository: Send + Sync + 'static {
+    async fn insert(&self, shopping_list: ShoppingList) -> Result<(), InsertShoppingListError>;
+
+    async fn get_by_id(&self, id: &Uuid) -> Result<ShoppingList, GetShoppingListByIdError>;
+
+    async fn set_item_checked(
+        &self,
+        shopping_list: &ShoppingList,
+        item_id: &Uuid,
+        checked: bool,
+    ) -> Result<(), UpdateShoppingListItemError>;
+
+    async fn delete(&self, shopping_list: &ShoppingList) -> Result<(), DeleteShoppingListError>;
+}
+
+pub type ShoppingListRepositoryService = Arc<Box<dyn ShoppingListRepository>>;
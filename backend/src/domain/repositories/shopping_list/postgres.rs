@@ -0,0 +1,161 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sqlx::{error::Error as SQLXError, PgPool};
+use uuid::Uuid;
+
+use crate::domain::entities::{
+    ingredient::IngredientModel,
+    shopping_list::{ShoppingList, ShoppingListItem, ShoppingListItemModel},
+};
+
+use super::{
+    errors::{
+        DeleteShoppingListError, GetShoppingListByIdError, InsertShoppingListError,
+        UpdateShoppingListItemError,
+    },
+    ShoppingListRepository, ShoppingListRepositoryService,
+};
+
+pub struct PostgresShoppingListRepository(pub PgPool);
+
+#[async_trait]
+impl ShoppingListRepository for PostgresShoppingListRepository {
+    #[tracing::instrument(
+        "[SHOPPING LIST REPOSITORY] [POSTGRES] Insert a new shopping list",
+        skip(self)
+    )]
+    async fn insert(&self, shopping_list: ShoppingList) -> Result<(), InsertShoppingListError> {
+        let mut tx = self.0.begin().await?;
+
+        sqlx::query_file!(
+            "queries/shopping_lists/insert_shopping_list.sql",
+            shopping_list.id,
+            shopping_list.name,
+            &shopping_list.recipe_ids,
+            shopping_list.created_at,
+            shopping_list.updated_at
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| match e {
+            SQLXError::Database(dberror) if dberror.is_unique_violation() => {
+                InsertShoppingListError::Conflict(shopping_list.id)
+            }
+            e => e.into(),
+        })?;
+
+        for (position, item) in shopping_list.items.iter().enumerate() {
+            let amount = serde_json::to_value(&item.amount)?;
+
+            sqlx::query_file!(
+                "queries/shopping_lists/insert_shopping_list_item.sql",
+                item.id,
+                shopping_list.id,
+                item.ingredient.id,
+                amount,
+                item.checked,
+                position as i32
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(
+        "[SHOPPING LIST REPOSITORY] [POSTGRES] Get shopping list with ID",
+        skip(self)
+    )]
+    async fn get_by_id(&self, id: &Uuid) -> Result<ShoppingList, GetShoppingListByIdError> {
+        let result = sqlx::query_file!("queries/shopping_lists/get_shopping_list.sql", id)
+            .fetch_one(&self.0)
+            .await
+            .map_err(|e| GetShoppingListByIdError::with_id(id, e))?;
+
+        let items = sqlx::query_file_as!(
+            ShoppingListItemModel,
+            "queries/shopping_lists/get_items_for_shopping_list.sql",
+            id
+        )
+        .fetch_all(&self.0)
+        .await?
+        .into_iter()
+        .map(ShoppingListItem::try_from)
+        .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(ShoppingList {
+            id: result.id,
+            name: result.name,
+            recipe_ids: result.recipe_ids,
+            items,
+            created_at: result.created_at,
+            updated_at: result.updated_at,
+        })
+    }
+
+    #[tracing::instrument(
+        "[SHOPPING LIST REPOSITORY] [POSTGRES] Check off a shopping list item",
+        skip(self)
+    )]
+    async fn set_item_checked(
+        &self,
+        shopping_list: &ShoppingList,
+        item_id: &Uuid,
+        checked: bool,
+    ) -> Result<(), UpdateShoppingListItemError> {
+        let mut tx = self.0.begin().await?;
+
+        let result = sqlx::query_file!(
+            "queries/shopping_lists/update_shopping_list_item.sql",
+            shopping_list.id,
+            item_id,
+            checked
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(UpdateShoppingListItemError::NotFound(*item_id));
+        }
+
+        sqlx::query_file!(
+            "queries/shopping_lists/update_shopping_list_timestamps.sql",
+            shopping_list.id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(
+        "[SHOPPING LIST REPOSITORY] [POSTGRES] Delete shopping list",
+        skip(self)
+    )]
+    async fn delete(&self, shopping_list: &ShoppingList) -> Result<(), DeleteShoppingListError> {
+        sqlx::query_file!(
+            "queries/shopping_lists/delete_shopping_list.sql",
+            shopping_list.id
+        )
+        .execute(&self.0)
+        .await?;
+
+        Ok(())
+    }
+}
+
+impl PostgresShoppingListRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self(pool)
+    }
+
+    pub fn service(self) -> ShoppingListRepositoryService {
+        Arc::new(Box::new(self))
+    }
+}
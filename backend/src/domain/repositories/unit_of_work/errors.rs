@@ -0,0 +1,49 @@
+use eyre::eyre;
+use sqlx::Error as SQLXError;
+use std::sync::PoisonError;
+use thiserror::Error;
+
+const POISONED: &str =
+    "Unit of work lock was poisoned during a previous access and can no longer be locked";
+
+#[derive(Error, Debug)]
+pub enum BeginTransactionError {
+    #[error(transparent)]
+    UnknownError(#[from] eyre::Error),
+}
+
+impl<T> From<PoisonError<T>> for BeginTransactionError {
+    fn from(_value: PoisonError<T>) -> Self {
+        eyre!(POISONED).into()
+    }
+}
+
+impl From<SQLXError> for BeginTransactionError {
+    fn from(e: SQLXError) -> Self {
+        Self::UnknownError(e.into())
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum CommitTransactionError {
+    #[error("The transaction was already committed")]
+    AlreadyCommitted,
+
+    #[error("Somebody else changed the same rows while the transaction ran")]
+    Conflict,
+
+    #[error(transparent)]
+    UnknownError(#[from] eyre::Error),
+}
+
+impl<T> From<PoisonError<T>> for CommitTransactionError {
+    fn from(_value: PoisonError<T>) -> Self {
+        eyre!(POISONED).into()
+    }
+}
+
+impl From<SQLXError> for CommitTransactionError {
+    fn from(e: SQLXError) -> Self {
+        Self::UnknownError(e.into())
+    }
+}
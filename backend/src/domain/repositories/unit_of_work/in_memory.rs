@@ -0,0 +1,287 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    hash::Hash,
+    sync::{Arc, Mutex},
+};
+
+use async_trait::async_trait;
+use tokio::sync::{Mutex as AsyncMutex, OwnedMutexGuard};
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{ingredient::Ingredient, recipe::Recipe},
+    repositories::{
+        ingredients::{
+            errors::GetIngredientByIdError, in_memory::InMemoryIngredientRepository,
+            IngredientRepository, IngredientRepositoryService,
+        },
+        outbox::in_memory::InMemoryOutboxRepository,
+        recipe::{
+            errors::GetRecipeByIdError, in_memory::InMemoryRecipeRepository, RecipeRepository,
+            RecipeRepositoryService,
+        },
+    },
+};
+
+use super::{
+    errors::{BeginTransactionError, CommitTransactionError},
+    Transaction, UnitOfWork, UnitOfWorkService,
+};
+
+/// Transactions work on a copy of the repositories. Committing writes back only the rows the
+/// transaction added, changed or removed, so changes made outside of it in the meantime are kept.
+/// Only one transaction runs at a time. When a row the transaction changed was also changed
+/// outside of it, committing fails instead of overwriting that change.
+pub struct InMemoryUnitOfWork {
+    ingredients: InMemoryIngredientRepository,
+    recipes: InMemoryRecipeRepository,
+    lock: Arc<AsyncMutex<()>>,
+}
+
+struct InMemoryTransaction {
+    ingredients: InMemoryIngredientRepository,
+    recipes: InMemoryRecipeRepository,
+    /// What the repositories looked like when the transaction began, to tell what it changed
+    snapshot_ingredients: InMemoryIngredientRepository,
+    snapshot_recipes: InMemoryRecipeRepository,
+    working_ingredients: InMemoryIngredientRepository,
+    working_recipes: InMemoryRecipeRepository,
+    _guard: OwnedMutexGuard<()>,
+}
+
+fn copy_of<T: Clone>(value: &Mutex<T>) -> Result<Arc<Mutex<T>>, BeginTransactionError> {
+    Ok(Arc::new(Mutex::new(value.lock()?.clone())))
+}
+
+/// The maps the in memory repositories keep their rows in
+trait Rows: Clone {
+    type Key: Clone;
+    type Row: Clone + PartialEq;
+
+    fn keys(&self) -> Vec<Self::Key>;
+    fn row(&self, key: &Self::Key) -> Option<&Self::Row>;
+    fn put(&mut self, key: Self::Key, row: Self::Row);
+    fn delete(&mut self, key: &Self::Key);
+}
+
+impl<K: Clone + Eq + Hash, V: Clone + PartialEq> Rows for HashMap<K, V> {
+    type Key = K;
+    type Row = V;
+
+    fn keys(&self) -> Vec<K> {
+        self.keys().cloned().collect()
+    }
+
+    fn row(&self, key: &K) -> Option<&V> {
+        self.get(key)
+    }
+
+    fn put(&mut self, key: K, row: V) {
+        self.insert(key, row);
+    }
+
+    fn delete(&mut self, key: &K) {
+        self.remove(key);
+    }
+}
+
+impl<K: Clone + Ord, V: Clone + PartialEq> Rows for BTreeMap<K, V> {
+    type Key = K;
+    type Row = V;
+
+    fn keys(&self) -> Vec<K> {
+        self.keys().cloned().collect()
+    }
+
+    fn row(&self, key: &K) -> Option<&V> {
+        self.get(key)
+    }
+
+    fn put(&mut self, key: K, row: V) {
+        self.insert(key, row);
+    }
+
+    fn delete(&mut self, key: &K) {
+        self.remove(key);
+    }
+}
+
+type Changes<T> = Vec<(<T as Rows>::Key, Option<<T as Rows>::Row>)>;
+
+/// The rows the transaction added, changed or removed, with what it left in their place
+fn changes<T: Rows>(
+    snapshot: &Mutex<T>,
+    working: &Mutex<T>,
+) -> Result<Changes<T>, CommitTransactionError> {
+    let snapshot = snapshot.lock()?;
+    let working = working.lock()?;
+    let mut changes = vec![];
+
+    for key in working.keys() {
+        if let Some(row) = working
+            .row(&key)
+            .filter(|&row| snapshot.row(&key) != Some(row))
+        {
+            changes.push((key, Some(row.clone())));
+        }
+    }
+
+    for key in snapshot.keys() {
+        if working.row(&key).is_none() {
+            changes.push((key, None));
+        }
+    }
+
+    Ok(changes)
+}
+
+/// Whether somebody else changed one of the rows since the transaction began,
+/// unless they happened to change it the same way
+fn conflicts<T: Rows>(
+    target: &T,
+    snapshot: &Mutex<T>,
+    changes: &Changes<T>,
+) -> Result<bool, CommitTransactionError> {
+    let snapshot = snapshot.lock()?;
+
+    Ok(changes.iter().any(|(key, row)| {
+        let current = target.row(key);
+        current != snapshot.row(key) && current != row.as_ref()
+    }))
+}
+
+fn apply<T: Rows>(target: &mut T, changes: Changes<T>) {
+    for (key, row) in changes {
+        match row {
+            Some(row) => target.put(key, row),
+            None => target.delete(&key),
+        }
+    }
+}
+
+fn publish(
+    target: &InMemoryOutboxRepository,
+    source: &InMemoryOutboxRepository,
+) -> Result<(), CommitTransactionError> {
+    let events: Vec<_> = source.0.lock()?.drain(..).collect();
+    target.0.lock()?.extend(events);
+
+    Ok(())
+}
+
+#[async_trait]
+impl Transaction for InMemoryTransaction {
+    fn ingredients(&self) -> IngredientRepositoryService {
+        Arc::new(Box::new(self.working_ingredients.clone()))
+    }
+
+    fn recipes(&self) -> RecipeRepositoryService {
+        Arc::new(Box::new(self.working_recipes.clone()))
+    }
+
+    async fn lock_ingredient(&self, id: &Uuid) -> Result<Ingredient, GetIngredientByIdError> {
+        // Transactions run one after the other, so there is nothing else to lock
+        self.working_ingredients.get_by_id(id).await
+    }
+
+    async fn lock_recipe(&self, id: &Uuid) -> Result<Recipe, GetRecipeByIdError> {
+        self.working_recipes.get_by_id(id).await
+    }
+
+    async fn commit(self: Box<Self>) -> Result<(), CommitTransactionError> {
+        let ingredients = changes(&self.snapshot_ingredients.0, &self.working_ingredients.0)?;
+        let recipes = changes(&self.snapshot_recipes.0, &self.working_recipes.0)?;
+        let reviews = changes(&self.snapshot_recipes.1, &self.working_recipes.1)?;
+        let share_tokens = changes(&self.snapshot_recipes.2, &self.working_recipes.2)?;
+
+        {
+            // Everything is checked before anything is written, so a conflict leaves no trace
+            let mut target_ingredients = self.ingredients.0.lock()?;
+            let mut target_recipes = self.recipes.0.lock()?;
+            let mut target_reviews = self.recipes.1.lock()?;
+            let mut target_share_tokens = self.recipes.2.lock()?;
+
+            if conflicts(
+                &*target_ingredients,
+                &self.snapshot_ingredients.0,
+                &ingredients,
+            )? || conflicts(&*target_recipes, &self.snapshot_recipes.0, &recipes)?
+                || conflicts(&*target_reviews, &self.snapshot_recipes.1, &reviews)?
+                || conflicts(
+                    &*target_share_tokens,
+                    &self.snapshot_recipes.2,
+                    &share_tokens,
+                )?
+            {
+                return Err(CommitTransactionError::Conflict);
+            }
+
+            apply(&mut *target_ingredients, ingredients);
+            apply(&mut *target_recipes, recipes);
+            apply(&mut *target_reviews, reviews);
+            apply(&mut *target_share_tokens, share_tokens);
+        }
+
+        publish(&self.ingredients.1, &self.working_ingredients.1)?;
+        publish(&self.recipes.3, &self.working_recipes.3)?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl UnitOfWork for InMemoryUnitOfWork {
+    #[tracing::instrument("[UNIT OF WORK] [IN MEMORY] Begin a transaction", skip(self))]
+    async fn begin(&self) -> Result<Box<dyn Transaction>, BeginTransactionError> {
+        let guard = self.lock.clone().lock_owned().await;
+
+        let snapshot_ingredients = InMemoryIngredientRepository(
+            copy_of(&self.ingredients.0)?,
+            InMemoryOutboxRepository::new(),
+        );
+        let snapshot_recipes = InMemoryRecipeRepository(
+            copy_of(&self.recipes.0)?,
+            copy_of(&self.recipes.1)?,
+            copy_of(&self.recipes.2)?,
+            InMemoryOutboxRepository::new(),
+        );
+        let working_ingredients = InMemoryIngredientRepository(
+            copy_of(&snapshot_ingredients.0)?,
+            InMemoryOutboxRepository::new(),
+        );
+        let working_recipes = InMemoryRecipeRepository(
+            copy_of(&snapshot_recipes.0)?,
+            copy_of(&snapshot_recipes.1)?,
+            copy_of(&snapshot_recipes.2)?,
+            InMemoryOutboxRepository::new(),
+        );
+
+        Ok(Box::new(InMemoryTransaction {
+            ingredients: self.ingredients.clone(),
+            recipes: self.recipes.clone(),
+            snapshot_ingredients,
+            snapshot_recipes,
+            working_ingredients,
+            working_recipes,
+            _guard: guard,
+        }))
+    }
+}
+
+impl InMemoryUnitOfWork {
+    /// The repositories are shared with the unit of work, so they see what it commits
+    pub fn new(
+        ingredients: InMemoryIngredientRepository,
+        recipes: InMemoryRecipeRepository,
+    ) -> Self {
+        Self {
+            ingredients,
+            recipes,
+            lock: Default::default(),
+        }
+    }
+
+    pub fn service(self) -> UnitOfWorkService {
+        Arc::new(Box::new(self))
+    }
+}
@@ -0,0 +1,48 @@
+pub mod errors;
+pub mod in_memory;
+pub mod postgres;
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::{ingredient::Ingredient, recipe::Recipe};
+
+use super::{
+    ingredients::{errors::GetIngredientByIdError, IngredientRepositoryService},
+    recipe::{errors::GetRecipeByIdError, RecipeRepositoryService},
+};
+
+use self::errors::{BeginTransactionError, CommitTransactionError};
+
+/// Repositories that share a single transaction.
+///
+/// Reads see the writes made earlier in the transaction, while nobody else sees them until it
+/// gets committed. Dropping a transaction without committing it rolls everything back.
+#[async_trait]
+pub trait Transaction: Send + Sync {
+    fn ingredients(&self) -> IngredientRepositoryService;
+
+    fn recipes(&self) -> RecipeRepositoryService;
+
+    /// Gets the ingredient, and nobody else can change it until the transaction ends.
+    /// Meant for checking the ingredient before writing to it.
+    async fn lock_ingredient(&self, id: &Uuid) -> Result<Ingredient, GetIngredientByIdError>;
+
+    /// Gets the recipe, and nobody else can change it until the transaction ends.
+    /// Meant for checking the recipe before writing to it.
+    async fn lock_recipe(&self, id: &Uuid) -> Result<Recipe, GetRecipeByIdError>;
+
+    async fn commit(self: Box<Self>) -> Result<(), CommitTransactionError>;
+}
+
+#[async_trait]
+pub trait UnitOfWork: Send + Sync + 'static {
+    async fn begin(&self) -> Result<Box<dyn Transaction>, BeginTransactionError>;
+}
+
+pub type UnitOfWorkService = Arc<Box<dyn UnitOfWork>>;
+
+#[cfg(test)]
+mod tests;
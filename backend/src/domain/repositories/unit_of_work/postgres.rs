@@ -0,0 +1,144 @@
+use std::ops::{Deref, DerefMut};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sqlx::pool::PoolConnection;
+use sqlx::{PgConnection, PgPool, Postgres};
+use tokio::sync::{MappedMutexGuard, Mutex, MutexGuard};
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{ingredient::Ingredient, recipe::Recipe},
+    repositories::{
+        ingredients::{
+            errors::GetIngredientByIdError, postgres::PostgresIngredientRepository,
+            IngredientRepositoryService,
+        },
+        recipe::{
+            errors::GetRecipeByIdError, postgres::PostgresRecipeRepository, RecipeRepositoryService,
+        },
+    },
+};
+
+use super::{
+    errors::{BeginTransactionError, CommitTransactionError},
+    Transaction, UnitOfWork, UnitOfWorkService,
+};
+
+type PgTransaction = sqlx::Transaction<'static, Postgres>;
+
+/// Where the queries of a Postgres repository run: either on a connection of the pool, or on
+/// a transaction shared with other repositories. Beginning a transaction on a connection that
+/// is already in one creates a savepoint, so repositories can keep their own transactions.
+#[derive(Clone)]
+pub enum PostgresExecutor {
+    Pool(PgPool),
+    Transaction(Arc<Mutex<Option<PgTransaction>>>),
+}
+
+/// A connection acquired from a [`PostgresExecutor`]. When the executor is a transaction,
+/// nobody else can use it until the connection is dropped.
+pub enum PostgresConnection<'a> {
+    Pool(Box<PoolConnection<Postgres>>),
+    Transaction(MappedMutexGuard<'a, PgTransaction>),
+}
+
+impl PostgresExecutor {
+    pub async fn acquire(&self) -> Result<PostgresConnection<'_>, sqlx::Error> {
+        match self {
+            Self::Pool(pool) => Ok(PostgresConnection::Pool(Box::new(pool.acquire().await?))),
+            // A repository can outlive the transaction it was handed out from
+            Self::Transaction(tx) => MutexGuard::try_map(tx.lock().await, Option::as_mut)
+                .map(PostgresConnection::Transaction)
+                .map_err(|_| sqlx::Error::PoolClosed),
+        }
+    }
+}
+
+impl From<PgPool> for PostgresExecutor {
+    fn from(pool: PgPool) -> Self {
+        Self::Pool(pool)
+    }
+}
+
+impl Deref for PostgresConnection<'_> {
+    type Target = PgConnection;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Self::Pool(conn) => conn,
+            Self::Transaction(tx) => tx,
+        }
+    }
+}
+
+impl DerefMut for PostgresConnection<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        match self {
+            Self::Pool(conn) => conn,
+            Self::Transaction(tx) => tx,
+        }
+    }
+}
+
+pub struct PostgresUnitOfWork(pub PgPool);
+
+struct PostgresTransaction(Arc<Mutex<Option<PgTransaction>>>);
+
+#[async_trait]
+impl Transaction for PostgresTransaction {
+    fn ingredients(&self) -> IngredientRepositoryService {
+        let executor = PostgresExecutor::Transaction(self.0.clone());
+        Arc::new(Box::new(PostgresIngredientRepository(executor)))
+    }
+
+    fn recipes(&self) -> RecipeRepositoryService {
+        let executor = PostgresExecutor::Transaction(self.0.clone());
+        Arc::new(Box::new(PostgresRecipeRepository(executor)))
+    }
+
+    async fn lock_ingredient(&self, id: &Uuid) -> Result<Ingredient, GetIngredientByIdError> {
+        let executor = PostgresExecutor::Transaction(self.0.clone());
+        PostgresIngredientRepository(executor).lock(id).await
+    }
+
+    async fn lock_recipe(&self, id: &Uuid) -> Result<Recipe, GetRecipeByIdError> {
+        let executor = PostgresExecutor::Transaction(self.0.clone());
+        PostgresRecipeRepository(executor).lock(id).await
+    }
+
+    async fn commit(self: Box<Self>) -> Result<(), CommitTransactionError> {
+        let tx = self
+            .0
+            .lock()
+            .await
+            .take()
+            .ok_or(CommitTransactionError::AlreadyCommitted)?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl UnitOfWork for PostgresUnitOfWork {
+    #[tracing::instrument("[UNIT OF WORK] [POSTGRES] Begin a transaction", skip(self))]
+    async fn begin(&self) -> Result<Box<dyn Transaction>, BeginTransactionError> {
+        let tx = self.0.begin().await?;
+
+        Ok(Box::new(PostgresTransaction(Arc::new(Mutex::new(Some(
+            tx,
+        ))))))
+    }
+}
+
+impl PostgresUnitOfWork {
+    pub fn new(pool: PgPool) -> Self {
+        Self(pool)
+    }
+
+    pub fn service(self) -> UnitOfWorkService {
+        Arc::new(Box::new(self))
+    }
+}
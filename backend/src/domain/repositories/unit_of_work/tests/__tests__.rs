@@ -0,0 +1,156 @@
+use crate::{
+    domain::{
+        entities::{
+            event::DomainEvent,
+            ingredient::{types::IngredientName, IngredientChangeset},
+        },
+        repositories::{
+            ingredients::IngredientRepository,
+            outbox::OutboxRepository,
+            recipe::RecipeRepository,
+            unit_of_work::{errors::CommitTransactionError, UnitOfWork},
+        },
+    },
+    test_utils::{recipe_fixture, unpublished_events},
+};
+
+pub async fn committed_changes_are_visible_to_everyone(
+    ingredient_repo: impl IngredientRepository,
+    recipe_repo: impl RecipeRepository,
+    outbox_repo: impl OutboxRepository,
+    unit_of_work: impl UnitOfWork,
+) {
+    let recipe = recipe_fixture();
+    let tx = unit_of_work.begin().await.unwrap();
+
+    for ingredient in recipe.ingredients.iter() {
+        tx.ingredients()
+            .insert(ingredient.ingredient.clone())
+            .await
+            .unwrap();
+    }
+    tx.recipes().insert(recipe.clone()).await.unwrap();
+
+    // The transaction sees its own changes, but nobody else does yet
+    tx.recipes().get_by_id(&recipe.id).await.unwrap();
+    recipe_repo.get_by_id(&recipe.id).await.unwrap_err();
+    assert!(unpublished_events(&outbox_repo).await.is_empty());
+
+    tx.commit().await.unwrap();
+
+    let saved = recipe_repo.get_by_id(&recipe.id).await.unwrap();
+    assert_eq!(saved.ingredients.len(), recipe.ingredients.len());
+    assert_eq!(
+        ingredient_repo.get_all().await.unwrap().len(),
+        recipe.ingredients.len()
+    );
+    assert!(unpublished_events(&outbox_repo)
+        .await
+        .contains(&DomainEvent::RecipeCreated(saved)));
+}
+
+pub async fn uncommitted_changes_are_rolled_back(
+    ingredient_repo: impl IngredientRepository,
+    recipe_repo: impl RecipeRepository,
+    outbox_repo: impl OutboxRepository,
+    unit_of_work: impl UnitOfWork,
+) {
+    let recipe = recipe_fixture();
+    let tx = unit_of_work.begin().await.unwrap();
+
+    for ingredient in recipe.ingredients.iter() {
+        tx.ingredients()
+            .insert(ingredient.ingredient.clone())
+            .await
+            .unwrap();
+    }
+    tx.recipes().insert(recipe.clone()).await.unwrap();
+
+    drop(tx);
+
+    recipe_repo.get_by_id(&recipe.id).await.unwrap_err();
+    assert!(ingredient_repo.get_all().await.unwrap().is_empty());
+    assert!(unpublished_events(&outbox_repo).await.is_empty());
+}
+
+pub async fn a_failed_write_does_not_undo_the_rest_of_the_transaction(
+    ingredient_repo: impl IngredientRepository,
+    unit_of_work: impl UnitOfWork,
+) {
+    let recipe = recipe_fixture();
+    let ingredient = recipe.ingredients.first().unwrap().ingredient.clone();
+    let tx = unit_of_work.begin().await.unwrap();
+
+    tx.ingredients().insert(ingredient.clone()).await.unwrap();
+    tx.ingredients()
+        .insert(ingredient.clone())
+        .await
+        .unwrap_err();
+    tx.commit().await.unwrap();
+
+    assert_eq!(ingredient_repo.get_all().await.unwrap(), vec![ingredient]);
+}
+
+pub async fn changes_made_outside_of_a_transaction_are_kept(
+    ingredient_repo: impl IngredientRepository,
+    unit_of_work: impl UnitOfWork,
+) {
+    let recipe = recipe_fixture();
+    let mut ingredients = recipe.ingredients.iter().map(|i| i.ingredient.clone());
+    let inside = ingredients.next().unwrap();
+    let outside = ingredients.next().unwrap();
+    let tx = unit_of_work.begin().await.unwrap();
+
+    tx.ingredients().insert(inside.clone()).await.unwrap();
+    ingredient_repo.insert(outside.clone()).await.unwrap();
+    tx.commit().await.unwrap();
+
+    let mut saved: Vec<_> = ingredient_repo.get_all().await.unwrap();
+    saved.sort_by_key(|i| i.id);
+    let mut expected = vec![inside, outside];
+    expected.sort_by_key(|i| i.id);
+    assert_eq!(saved, expected);
+}
+
+/// Postgres makes the write outside of the transaction wait for it instead
+pub async fn conflicting_changes_made_outside_of_a_transaction_fail_the_commit(
+    ingredient_repo: impl IngredientRepository,
+    unit_of_work: impl UnitOfWork,
+) {
+    let recipe = recipe_fixture();
+    let mut ingredients = recipe.ingredients.iter().map(|i| i.ingredient.clone());
+    let changed = ingredients.next().unwrap();
+    let untouched = ingredients.next().unwrap();
+    ingredient_repo.insert(changed.clone()).await.unwrap();
+    let tx = unit_of_work.begin().await.unwrap();
+
+    tx.ingredients().insert(untouched.clone()).await.unwrap();
+    tx.ingredients()
+        .update(
+            &changed,
+            IngredientChangeset {
+                name: Some(IngredientName("Inside".to_owned())),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    ingredient_repo
+        .update(
+            &changed,
+            IngredientChangeset {
+                name: Some(IngredientName("Outside".to_owned())),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    let error = tx.commit().await.unwrap_err();
+
+    assert!(matches!(error, CommitTransactionError::Conflict));
+    assert_eq!(
+        ingredient_repo.get_by_id(&changed.id).await.unwrap().name,
+        IngredientName("Outside".to_owned())
+    );
+    ingredient_repo.get_by_id(&untouched.id).await.unwrap_err();
+}
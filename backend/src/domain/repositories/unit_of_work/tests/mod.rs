@@ -0,0 +1,129 @@
+mod __tests__;
+mod in_memory {
+    use super::__tests__;
+
+    use crate::domain::repositories::{
+        ingredients::in_memory::InMemoryIngredientRepository,
+        outbox::in_memory::InMemoryOutboxRepository, recipe::in_memory::InMemoryRecipeRepository,
+        unit_of_work::in_memory::InMemoryUnitOfWork,
+    };
+
+    #[tokio::test]
+    async fn committed_changes_are_visible_to_everyone() {
+        let outbox = InMemoryOutboxRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new().with_outbox(outbox.clone());
+        let recipe_repo = InMemoryRecipeRepository::new().with_outbox(outbox.clone());
+        let unit_of_work = InMemoryUnitOfWork::new(ingredient_repo.clone(), recipe_repo.clone());
+
+        __tests__::committed_changes_are_visible_to_everyone(
+            ingredient_repo,
+            recipe_repo,
+            outbox,
+            unit_of_work,
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn uncommitted_changes_are_rolled_back() {
+        let outbox = InMemoryOutboxRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new().with_outbox(outbox.clone());
+        let recipe_repo = InMemoryRecipeRepository::new().with_outbox(outbox.clone());
+        let unit_of_work = InMemoryUnitOfWork::new(ingredient_repo.clone(), recipe_repo.clone());
+
+        __tests__::uncommitted_changes_are_rolled_back(
+            ingredient_repo,
+            recipe_repo,
+            outbox,
+            unit_of_work,
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn a_failed_write_does_not_undo_the_rest_of_the_transaction() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let unit_of_work =
+            InMemoryUnitOfWork::new(ingredient_repo.clone(), InMemoryRecipeRepository::new());
+
+        __tests__::a_failed_write_does_not_undo_the_rest_of_the_transaction(
+            ingredient_repo,
+            unit_of_work,
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn changes_made_outside_of_a_transaction_are_kept() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let unit_of_work =
+            InMemoryUnitOfWork::new(ingredient_repo.clone(), InMemoryRecipeRepository::new());
+
+        __tests__::changes_made_outside_of_a_transaction_are_kept(ingredient_repo, unit_of_work)
+            .await
+    }
+
+    #[tokio::test]
+    async fn conflicting_changes_made_outside_of_a_transaction_fail_the_commit() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let unit_of_work =
+            InMemoryUnitOfWork::new(ingredient_repo.clone(), InMemoryRecipeRepository::new());
+
+        __tests__::conflicting_changes_made_outside_of_a_transaction_fail_the_commit(
+            ingredient_repo,
+            unit_of_work,
+        )
+        .await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        ingredients::postgres::PostgresIngredientRepository,
+        outbox::postgres::PostgresOutboxRepository, recipe::postgres::PostgresRecipeRepository,
+        unit_of_work::postgres::PostgresUnitOfWork,
+    };
+
+    #[sqlx::test]
+    async fn committed_changes_are_visible_to_everyone(pool: PgPool) {
+        __tests__::committed_changes_are_visible_to_everyone(
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresOutboxRepository::new(pool.clone()),
+            PostgresUnitOfWork::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn uncommitted_changes_are_rolled_back(pool: PgPool) {
+        __tests__::uncommitted_changes_are_rolled_back(
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresRecipeRepository::new(pool.clone()),
+            PostgresOutboxRepository::new(pool.clone()),
+            PostgresUnitOfWork::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn a_failed_write_does_not_undo_the_rest_of_the_transaction(pool: PgPool) {
+        __tests__::a_failed_write_does_not_undo_the_rest_of_the_transaction(
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresUnitOfWork::new(pool),
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn changes_made_outside_of_a_transaction_are_kept(pool: PgPool) {
+        __tests__::changes_made_outside_of_a_transaction_are_kept(
+            PostgresIngredientRepository::new(pool.clone()),
+            PostgresUnitOfWork::new(pool),
+        )
+        .await
+    }
+}
@@ -0,0 +1,60 @@
+use eyre::eyre;
+use sqlx::Error as SQLXError;
+use std::sync::PoisonError;
+use thiserror::Error;
+
+const POISONED: &str =
+    "User repository lock was poisoned during a previous access and can no longer be locked";
+
+#[derive(Error, Debug)]
+pub enum InsertUserError {
+    #[error("A user with the same {0} already exists")]
+    Conflict(&'static str),
+
+    #[error(transparent)]
+    UnknownError(#[from] eyre::Error),
+}
+
+impl<T> From<PoisonError<T>> for InsertUserError {
+    fn from(_value: PoisonError<T>) -> Self {
+        eyre!(POISONED).into()
+    }
+}
+
+impl From<SQLXError> for InsertUserError {
+    fn from(e: SQLXError) -> Self {
+        match e {
+            SQLXError::Database(dberror) if dberror.is_unique_violation() => {
+                match dberror.constraint() {
+                    Some("users_email_key") => Self::Conflict("email"),
+                    _ => Self::Conflict("username"),
+                }
+            }
+            e => Self::UnknownError(e.into()),
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum GetUserError {
+    #[error("The user was not found")]
+    NotFound,
+
+    #[error(transparent)]
+    UnknownError(#[from] eyre::Error),
+}
+
+impl<T> From<PoisonError<T>> for GetUserError {
+    fn from(_value: PoisonError<T>) -> Self {
+        eyre!(POISONED).into()
+    }
+}
+
+impl From<SQLXError> for GetUserError {
+    fn from(e: SQLXError) -> Self {
+        match e {
+            SQLXError::RowNotFound => Self::NotFound,
+            _ => Self::UnknownError(e.into()),
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::user::User;
+
+use super::{
+    errors::{GetUserError, InsertUserError},
+    UserRepository, UserRepositoryService,
+};
+
+pub struct InMemoryUserRepository(pub Mutex<HashMap<Uuid, User>>);
+
+#[async_trait]
+impl UserRepository for InMemoryUserRepository {
+    async fn insert(&self, user: &User) -> Result<(), InsertUserError> {
+        let mut lock = self.0.lock()?;
+
+        if let Some(existing) = lock
+            .values()
+            .find(|existing| existing.username == user.username || existing.email == user.email)
+        {
+            return Err(match existing.username == user.username {
+                true => InsertUserError::Conflict("username"),
+                false => InsertUserError::Conflict("email"),
+            });
+        }
+
+        lock.insert(user.id, user.clone());
+
+        Ok(())
+    }
+
+    async fn get_by_id(&self, id: &Uuid) -> Result<User, GetUserError> {
+        let lock = self.0.lock()?;
+
+        lock.get(id).cloned().ok_or(GetUserError::NotFound)
+    }
+
+    async fn get_by_username(&self, username: &str) -> Result<User, GetUserError> {
+        let lock = self.0.lock()?;
+
+        lock.values()
+            .find(|user| user.username == username)
+            .cloned()
+            .ok_or(GetUserError::NotFound)
+    }
+}
+
+impl Default for InMemoryUserRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InMemoryUserRepository {
+    pub fn new() -> Self {
+        Self(Mutex::new(HashMap::new()))
+    }
+
+    pub fn service(self) -> UserRepositoryService {
+        Arc::new(Box::new(self))
+    }
+}
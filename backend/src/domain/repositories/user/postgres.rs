@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use uuid::Uuid;
+
+use crate::domain::entities::user::User;
+
+use super::{
+    errors::{GetUserError, InsertUserError},
+    UserRepository, UserRepositoryService,
+};
+
+pub struct PostgresUserRepository(pub PgPool);
+
+#[async_trait]
+impl UserRepository for PostgresUserRepository {
+    #[tracing::instrument("[USER REPOSITORY] [POSTGRES] Insert a user", skip(self, user))]
+    async fn insert(&self, user: &User) -> Result<(), InsertUserError> {
+        sqlx::query_file!(
+            "queries/users/insert_user.sql",
+            user.id,
+            user.username,
+            user.email,
+            user.password_hash,
+            user.created_at
+        )
+        .execute(&self.0)
+        .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument("[USER REPOSITORY] [POSTGRES] Get user by ID", skip(self))]
+    async fn get_by_id(&self, id: &Uuid) -> Result<User, GetUserError> {
+        let result = sqlx::query_file_as!(User, "queries/users/get_user_by_id.sql", id)
+            .fetch_one(&self.0)
+            .await?;
+
+        Ok(result)
+    }
+
+    #[tracing::instrument("[USER REPOSITORY] [POSTGRES] Get user by username", skip(self))]
+    async fn get_by_username(&self, username: &str) -> Result<User, GetUserError> {
+        let result = sqlx::query_file_as!(User, "queries/users/get_user_by_username.sql", username)
+            .fetch_one(&self.0)
+            .await?;
+
+        Ok(result)
+    }
+}
+
+impl PostgresUserRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self(pool)
+    }
+
+    pub fn service(self) -> UserRepositoryService {
+        Arc::new(Box::new(self))
+    }
+}
@@ -0,0 +1,115 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use async_trait::async_trait;
+use eyre::eyre;
+use uuid::Uuid;
+
+use crate::domain::entities::webhook::{Webhook, WebhookDelivery};
+
+use super::{
+    errors::{
+        DeleteWebhookError, GetAllWebhooksError, GetWebhookDeliveriesError, GetWebhookError,
+        InsertWebhookDeliveryError, InsertWebhookError,
+    },
+    WebhookRepository, WebhookRepositoryService,
+};
+
+pub struct InMemoryWebhookRepository(
+    pub Mutex<HashMap<Uuid, Webhook>>,
+    pub Mutex<Vec<WebhookDelivery>>,
+);
+
+#[async_trait]
+impl WebhookRepository for InMemoryWebhookRepository {
+    async fn insert(&self, webhook: Webhook) -> Result<(), InsertWebhookError> {
+        let mut lock = self.0.lock()?;
+
+        lock.insert(webhook.id, webhook);
+
+        Ok(())
+    }
+
+    async fn get_by_id(&self, id: &Uuid) -> Result<Webhook, GetWebhookError> {
+        let lock = self.0.lock()?;
+
+        lock.get(id).cloned().ok_or(GetWebhookError::NotFound(*id))
+    }
+
+    async fn get_all(&self) -> Result<Vec<Webhook>, GetAllWebhooksError> {
+        let lock = self.0.lock()?;
+
+        let mut result: Vec<Webhook> = lock.values().cloned().collect();
+        result.sort_by_key(|webhook| webhook.created_at);
+
+        Ok(result)
+    }
+
+    async fn delete(&self, webhook: &Webhook) -> Result<(), DeleteWebhookError> {
+        let mut lock = self.0.lock()?;
+
+        lock.remove(&webhook.id)
+            .ok_or(DeleteWebhookError::UnknownError(eyre!(
+                "The webhook could not be found somehow"
+            )))?;
+
+        self.1
+            .lock()?
+            .retain(|delivery| delivery.webhook_id != webhook.id);
+
+        Ok(())
+    }
+
+    async fn add_delivery(
+        &self,
+        delivery: WebhookDelivery,
+    ) -> Result<(), InsertWebhookDeliveryError> {
+        let webhooks = self.0.lock()?;
+
+        if !webhooks.contains_key(&delivery.webhook_id) {
+            return Err(eyre!(
+                "The webhook with ID of {} was not found",
+                delivery.webhook_id
+            )
+            .into());
+        }
+
+        self.1.lock()?.push(delivery);
+
+        Ok(())
+    }
+
+    async fn get_deliveries(
+        &self,
+        webhook: &Webhook,
+    ) -> Result<Vec<WebhookDelivery>, GetWebhookDeliveriesError> {
+        let lock = self.1.lock()?;
+
+        let mut result: Vec<WebhookDelivery> = lock
+            .iter()
+            .filter(|delivery| delivery.webhook_id == webhook.id)
+            .cloned()
+            .collect();
+        result.sort_by_key(|delivery| (delivery.created_at, delivery.attempt));
+
+        Ok(result)
+    }
+}
+
+impl Default for InMemoryWebhookRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InMemoryWebhookRepository {
+    pub fn new() -> Self {
+        Self(Mutex::new(HashMap::new()), Mutex::new(Vec::new()))
+    }
+
+    pub fn service(self) -> WebhookRepositoryService {
+        Arc::new(Box::new(self))
+    }
+}
@@ -0,0 +1,40 @@
+pub mod errors;
+pub mod in_memory;
+pub mod postgres;
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::webhook::{Webhook, WebhookDelivery};
+
+use self::errors::{
+    DeleteWebhookError, GetAllWebhooksError, GetWebhookDeliveriesError, GetWebhookError,
+    InsertWebhookDeliveryError, InsertWebhookError,
+};
+
+#[async_trait]
+pub trait WebhookRepository: Send + Sync + 'static {
+    async fn insert(&self, webhook: Webhook) -> Result<(), InsertWebhookError>;
+
+    async fn get_by_id(&self, id: &Uuid) -> Result<Webhook, GetWebhookError>;
+
+    async fn get_all(&self) -> Result<Vec<Webhook>, GetAllWebhooksError>;
+
+    /// Deletes the webhook along with its delivery log
+    async fn delete(&self, webhook: &Webhook) -> Result<(), DeleteWebhookError>;
+
+    async fn add_delivery(
+        &self,
+        delivery: WebhookDelivery,
+    ) -> Result<(), InsertWebhookDeliveryError>;
+
+    /// Delivery log of the webhook, oldest first
+    async fn get_deliveries(
+        &self,
+        webhook: &Webhook,
+    ) -> Result<Vec<WebhookDelivery>, GetWebhookDeliveriesError>;
+}
+
+pub type WebhookRepositoryService = Arc<Box<dyn WebhookRepository>>;
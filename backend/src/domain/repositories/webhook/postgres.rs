@@ -0,0 +1,117 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::entities::webhook::{Webhook, WebhookDelivery, WebhookDeliveryModel};
+
+use super::{
+    errors::{
+        DeleteWebhookError, GetAllWebhooksError, GetWebhookDeliveriesError, GetWebhookError,
+        InsertWebhookDeliveryError, InsertWebhookError,
+    },
+    WebhookRepository, WebhookRepositoryService,
+};
+
+pub struct PostgresWebhookRepository(pub PgPool);
+
+#[async_trait]
+impl WebhookRepository for PostgresWebhookRepository {
+    #[tracing::instrument("[WEBHOOK REPOSITORY] [POSTGRES] Register a webhook", skip(self))]
+    async fn insert(&self, webhook: Webhook) -> Result<(), InsertWebhookError> {
+        sqlx::query_file!(
+            "queries/webhooks/insert_webhook.sql",
+            webhook.id,
+            webhook.url,
+            webhook.secret,
+            &webhook.event_types,
+            webhook.owner_id,
+            webhook.created_at
+        )
+        .execute(&self.0)
+        .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument("[WEBHOOK REPOSITORY] [POSTGRES] Get webhook by ID", skip(self))]
+    async fn get_by_id(&self, id: &Uuid) -> Result<Webhook, GetWebhookError> {
+        let result = sqlx::query_file_as!(Webhook, "queries/webhooks/get_webhook.sql", id)
+            .fetch_one(&self.0)
+            .await
+            .map_err(|e| GetWebhookError::with_id(id, e))?;
+
+        Ok(result)
+    }
+
+    #[tracing::instrument("[WEBHOOK REPOSITORY] [POSTGRES] Get all webhooks", skip(self))]
+    async fn get_all(&self) -> Result<Vec<Webhook>, GetAllWebhooksError> {
+        let result = sqlx::query_file_as!(Webhook, "queries/webhooks/get_all_webhooks.sql")
+            .fetch_all(&self.0)
+            .await?;
+
+        Ok(result)
+    }
+
+    #[tracing::instrument("[WEBHOOK REPOSITORY] [POSTGRES] Delete a webhook", skip(self))]
+    async fn delete(&self, webhook: &Webhook) -> Result<(), DeleteWebhookError> {
+        sqlx::query_file!("queries/webhooks/delete_webhook.sql", webhook.id)
+            .execute(&self.0)
+            .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument("[WEBHOOK REPOSITORY] [POSTGRES] Log a webhook delivery", skip(self))]
+    async fn add_delivery(
+        &self,
+        delivery: WebhookDelivery,
+    ) -> Result<(), InsertWebhookDeliveryError> {
+        sqlx::query_file!(
+            "queries/webhooks/insert_webhook_delivery.sql",
+            delivery.id,
+            delivery.webhook_id,
+            delivery.event_type,
+            delivery.payload,
+            delivery.attempt as i32,
+            delivery.status_code.map(i32::from),
+            delivery.error,
+            delivery.succeeded,
+            delivery.created_at
+        )
+        .execute(&self.0)
+        .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(
+        "[WEBHOOK REPOSITORY] [POSTGRES] Get deliveries of a webhook",
+        skip(self)
+    )]
+    async fn get_deliveries(
+        &self,
+        webhook: &Webhook,
+    ) -> Result<Vec<WebhookDelivery>, GetWebhookDeliveriesError> {
+        let result = sqlx::query_file_as!(
+            WebhookDeliveryModel,
+            "queries/webhooks/get_webhook_deliveries.sql",
+            webhook.id
+        )
+        .fetch_all(&self.0)
+        .await?;
+
+        Ok(result.into_iter().map(Into::into).collect())
+    }
+}
+
+impl PostgresWebhookRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self(pool)
+    }
+
+    pub fn service(self) -> WebhookRepositoryService {
+        Arc::new(Box::new(self))
+    }
+}
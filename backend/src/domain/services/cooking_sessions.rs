@@ -0,0 +1,148 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use tokio::sync::{
+    broadcast::{self, error::RecvError},
+    Mutex as AsyncMutex, OwnedMutexGuard,
+};
+use uuid::Uuid;
+
+use crate::domain::entities::cooking_session::CookingSession;
+
+/// How many state changes a slow device can fall behind before it starts missing them
+const CAPACITY: usize = 32;
+
+struct Channel {
+    sender: broadcast::Sender<CookingSession>,
+    lock: Arc<AsyncMutex<()>>,
+}
+
+impl Channel {
+    fn new() -> Self {
+        let (sender, _) = broadcast::channel(CAPACITY);
+
+        Self {
+            sender,
+            lock: Arc::new(AsyncMutex::new(())),
+        }
+    }
+
+    /// Nobody is listening, changing the session or waiting to change it
+    fn is_unused(&self) -> bool {
+        self.sender.receiver_count() == 0 && Arc::strong_count(&self.lock) == 1
+    }
+}
+
+/// Connects all the devices that take part in the same cooking session.
+/// Every change to a session goes through here, so that the devices get to see it,
+/// and so that two devices changing the session at once do not overwrite each other.
+#[derive(Clone, Default)]
+pub struct CookingSessionHub(Arc<Mutex<HashMap<Uuid, Channel>>>);
+
+impl CookingSessionHub {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn with_channel<T>(&self, id: &Uuid, f: impl FnOnce(&Channel) -> T) -> T {
+        let mut lock = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        let channel = lock.entry(*id).or_insert_with(Channel::new);
+
+        f(channel)
+    }
+
+    /// Forgets the channel once the last device left and the last change went through,
+    /// so that the hub does not keep every session that was ever cooked
+    fn release(&self, id: &Uuid) {
+        let mut lock = self.0.lock().unwrap_or_else(|e| e.into_inner());
+
+        if lock.get(id).is_some_and(Channel::is_unused) {
+            lock.remove(id);
+        }
+    }
+
+    /// Waits until nobody else is changing the session
+    pub async fn lock(&self, id: &Uuid) -> SessionGuard {
+        let guard = self
+            .with_channel(id, |channel| channel.lock.clone())
+            .lock_owned()
+            .await;
+
+        SessionGuard {
+            hub: self.clone(),
+            id: *id,
+            guard: Some(guard),
+        }
+    }
+
+    pub fn subscribe(&self, id: &Uuid) -> Subscription {
+        let receiver = self.with_channel(id, |channel| channel.sender.subscribe());
+
+        Subscription {
+            hub: self.clone(),
+            id: *id,
+            receiver: Some(receiver),
+        }
+    }
+
+    /// Sends the new state of the session to every connected device
+    pub fn publish(&self, session: &CookingSession) {
+        let lock = self.0.lock().unwrap_or_else(|e| e.into_inner());
+
+        // Nobody listening is not an error, the session can be used without any devices connected
+        if let Some(channel) = lock.get(&session.id) {
+            let _ = channel.sender.send(session.clone());
+        }
+    }
+
+    /// Disconnects all the devices from the session
+    pub fn close(&self, id: &Uuid) {
+        let mut lock = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        lock.remove(id);
+    }
+
+    #[cfg(test)]
+    pub(crate) fn is_open(&self, id: &Uuid) -> bool {
+        let lock = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        lock.contains_key(id)
+    }
+}
+
+/// Held while changing a session, nobody else can change it in the meantime
+pub struct SessionGuard {
+    hub: CookingSessionHub,
+    id: Uuid,
+    guard: Option<OwnedMutexGuard<()>>,
+}
+
+impl Drop for SessionGuard {
+    fn drop(&mut self) {
+        self.guard.take();
+        self.hub.release(&self.id);
+    }
+}
+
+/// The changes made to a session, for as long as a device is connected to it
+pub struct Subscription {
+    hub: CookingSessionHub,
+    id: Uuid,
+    receiver: Option<broadcast::Receiver<CookingSession>>,
+}
+
+impl Subscription {
+    pub async fn recv(&mut self) -> Result<CookingSession, RecvError> {
+        match &mut self.receiver {
+            Some(receiver) => receiver.recv().await,
+            None => Err(RecvError::Closed),
+        }
+    }
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        self.receiver.take();
+        self.hub.release(&self.id);
+    }
+}
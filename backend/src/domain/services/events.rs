@@ -0,0 +1,40 @@
+use tokio::sync::broadcast;
+
+use crate::domain::entities::event::OutboxEvent;
+
+/// How many events a slow subscriber can fall behind before it starts missing them
+const CAPACITY: usize = 256;
+
+/// Lets anybody interested know about what the commands did.
+/// The events keep the ID they were recorded in the outbox with, so that subscribers can tell
+/// when they see the same event again.
+#[derive(Clone)]
+pub struct EventBus(broadcast::Sender<OutboxEvent>);
+
+impl EventBus {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(CAPACITY);
+
+        Self(sender)
+    }
+
+    /// Returns whether anybody was listening to receive the event
+    pub fn publish(&self, event: OutboxEvent) -> bool {
+        self.0.send(event).is_ok()
+    }
+
+    /// How many events can be published before the slowest subscriber starts missing them
+    pub fn room(&self) -> usize {
+        CAPACITY.saturating_sub(self.0.len())
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<OutboxEvent> {
+        self.0.subscribe()
+    }
+}
+
+impl Default for EventBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,4 @@
+pub mod cooking_sessions;
+pub mod events;
+pub mod outbox;
+pub mod webhooks;
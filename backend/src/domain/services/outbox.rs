@@ -0,0 +1,99 @@
+use std::time::Duration;
+
+use chrono::{TimeDelta, Utc};
+use tokio::{task::JoinHandle, time::Instant};
+
+use crate::domain::repositories::outbox::OutboxRepositoryService;
+
+use super::events::EventBus;
+
+/// How long to wait before looking again for events to publish
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// How many events to take out of the outbox at once
+const BATCH_SIZE: usize = 100;
+
+/// How long a subscriber gets to catch up before it is left behind to miss events, so that one
+/// that stopped reading does not hold up everybody else
+const CATCH_UP_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// How often to get rid of the events that were published long enough ago
+const PRUNE_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// How long to keep the events around after they were published
+const RETENTION: TimeDelta = TimeDelta::days(7);
+
+/// Moves the events recorded by the repositories onto the event bus.
+/// An event is only marked as published after it was handed over, so when marking fails it is
+/// published again rather than lost. Subscribers have to be fine with seeing an event twice.
+/// While nobody is listening, or the subscribers have no room left, the events wait in the outbox.
+#[derive(Clone)]
+pub struct OutboxRelay(OutboxRepositoryService);
+
+impl OutboxRelay {
+    pub fn new(outbox_repo: OutboxRepositoryService) -> Self {
+        Self(outbox_repo)
+    }
+
+    /// Publishes a single batch of at most `limit` events, returning how many there were
+    pub async fn relay(&self, events: &EventBus, limit: usize) -> eyre::Result<usize> {
+        let published = self
+            .0
+            .publish(limit.min(BATCH_SIZE), &|event| events.publish(event))
+            .await?;
+
+        Ok(published)
+    }
+
+    /// Forgets the events published longer ago than they are kept for
+    pub async fn prune(&self) -> eyre::Result<u64> {
+        let pruned = self.0.prune_published(&(Utc::now() - RETENTION)).await?;
+
+        Ok(pruned)
+    }
+
+    /// Keeps relaying the events for as long as the application runs
+    pub fn start(self, events: &EventBus) -> JoinHandle<()> {
+        let events = events.clone();
+
+        tokio::spawn(async move {
+            let mut full_since: Option<Instant> = None;
+            let mut leaving_behind = false;
+            let mut last_pruned: Option<Instant> = None;
+
+            loop {
+                let room = events.room();
+                let limit = if room > 0 {
+                    full_since = None;
+                    leaving_behind = false;
+                    room
+                } else if full_since.get_or_insert_with(Instant::now).elapsed() < CATCH_UP_TIMEOUT {
+                    0
+                } else {
+                    if !leaving_behind {
+                        tracing::warn!(
+                            "A subscriber stopped reading the events, leaving it behind"
+                        );
+                        leaving_behind = true;
+                    }
+                    BATCH_SIZE
+                };
+
+                if limit > 0 {
+                    if let Err(e) = self.relay(&events, limit).await {
+                        tracing::error!("Could not relay the events from the outbox: {e}");
+                    }
+                }
+
+                if last_pruned.is_none_or(|at| at.elapsed() >= PRUNE_INTERVAL) {
+                    match self.prune().await {
+                        Ok(_) => last_pruned = Some(Instant::now()),
+                        Err(e) => tracing::error!("Could not prune the outbox: {e}"),
+                    }
+                }
+
+                tokio::time::sleep(POLL_INTERVAL).await;
+            }
+        })
+    }
+}
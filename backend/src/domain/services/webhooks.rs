@@ -0,0 +1,196 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+use eyre::eyre;
+use reqwest::Url;
+use tokio::{sync::broadcast::error::RecvError, task::JoinHandle};
+
+use crate::domain::{
+    commands::webhooks::deliver::{deliver_event, RetryPolicy},
+    entities::event::OutboxEvent,
+    queries::events::is_visible::is_event_visible,
+    repositories::{recipe::RecipeRepositoryService, webhook::WebhookRepositoryService},
+};
+
+use super::events::EventBus;
+
+/// Which addresses webhooks are allowed to point to. Webhooks are registered by users, so
+/// unless told otherwise they can't reach anything that is not on the public internet, like
+/// the database or the other services next to the app.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WebhookTargets {
+    #[default]
+    Public,
+    /// Loopback and private addresses too, for trying webhooks out locally
+    Any,
+}
+
+impl WebhookTargets {
+    /// Looks up the addresses of the host of the URL, failing when any of them is not allowed.
+    /// Requests have to go to the returned addresses, so that the host can't point elsewhere
+    /// once it was checked. When any address will do, nothing is looked up.
+    pub async fn resolve(&self, url: &Url) -> eyre::Result<Vec<SocketAddr>> {
+        if *self == Self::Any {
+            return Ok(vec![]);
+        }
+
+        if !["http", "https"].contains(&url.scheme()) {
+            return Err(eyre!("Webhooks can only be reached over HTTP or HTTPS"));
+        }
+
+        let host = url
+            .host_str()
+            .ok_or_else(|| eyre!("The URL has no host"))?
+            .trim_start_matches('[')
+            .trim_end_matches(']');
+        let port = url
+            .port_or_known_default()
+            .ok_or_else(|| eyre!("The URL has no port"))?;
+
+        let addrs: Vec<SocketAddr> = tokio::net::lookup_host((host, port)).await?.collect();
+        if addrs.is_empty() {
+            return Err(eyre!("{host} has no addresses"));
+        }
+
+        if let Some(addr) = addrs.iter().find(|addr| !is_public(&addr.ip())) {
+            return Err(eyre!("{host} points to {}, which is not public", addr.ip()));
+        }
+
+        Ok(addrs)
+    }
+}
+
+fn is_public(ip: &IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_public_v4(ip),
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(ip) => is_public_v4(&ip),
+            None => is_public_v6(ip),
+        },
+    }
+}
+
+fn is_public_v4(ip: &Ipv4Addr) -> bool {
+    let [a, b, c, _] = ip.octets();
+
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_broadcast()
+        || ip.is_documentation()
+        || ip.is_multicast()
+        // "This network", shared address space, protocol assignments and benchmarking
+        || a == 0
+        || (a == 100 && (64..128).contains(&b))
+        || (a == 192 && b == 0 && c == 0)
+        || (a == 198 && (18..20).contains(&b))
+        // Reserved for future use
+        || a >= 240)
+}
+
+fn is_public_v6(ip: &Ipv6Addr) -> bool {
+    let [first, second, ..] = ip.segments();
+
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_multicast()
+        // Unique local, link local and documentation
+        || (first & 0xfe00) == 0xfc00
+        || (first & 0xffc0) == 0xfe80
+        || (first == 0x2001 && second == 0x0db8))
+}
+
+/// Sends the events published by the commands to every webhook that asked for them
+#[derive(Clone)]
+pub struct WebhookDispatcher {
+    webhook_repo: WebhookRepositoryService,
+    recipe_repo: RecipeRepositoryService,
+    targets: WebhookTargets,
+    policy: RetryPolicy,
+}
+
+impl WebhookDispatcher {
+    pub fn new(
+        webhook_repo: WebhookRepositoryService,
+        recipe_repo: RecipeRepositoryService,
+    ) -> Self {
+        Self {
+            webhook_repo,
+            recipe_repo,
+            targets: WebhookTargets::default(),
+            policy: RetryPolicy::default(),
+        }
+    }
+
+    pub fn with_targets(mut self, targets: WebhookTargets) -> Self {
+        self.targets = targets;
+        self
+    }
+
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Listens to the events until the bus goes away
+    pub fn start(self, events: &EventBus) -> JoinHandle<()> {
+        let mut receiver = events.subscribe();
+
+        tokio::spawn(async move {
+            loop {
+                match receiver.recv().await {
+                    Ok(event) => self.dispatch(event).await,
+                    Err(RecvError::Lagged(skipped)) => {
+                        tracing::warn!("Webhooks missed {skipped} events");
+                    }
+                    Err(RecvError::Closed) => break,
+                }
+            }
+        })
+    }
+
+    /// Starts a delivery for every interested webhook whose owner can see what the event is
+    /// about, so that a slow partner does not hold up the others
+    async fn dispatch(&self, event: OutboxEvent) {
+        let webhooks = match self.webhook_repo.get_all().await {
+            Ok(webhooks) => webhooks,
+            Err(e) => {
+                tracing::error!("Could not get the webhooks: {e}");
+                return;
+            }
+        };
+
+        for webhook in webhooks.into_iter().filter(|w| w.wants(&event.event)) {
+            match is_event_visible(
+                self.recipe_repo.clone(),
+                webhook.owner_id.as_ref(),
+                &event.event,
+            )
+            .await
+            {
+                Ok(true) => {}
+                Ok(false) => continue,
+                Err(e) => {
+                    tracing::error!("Could not tell whether to deliver an event: {e}");
+                    continue;
+                }
+            }
+
+            let webhook_repo = self.webhook_repo.clone();
+            let targets = self.targets;
+            let policy = self.policy;
+            let event = event.clone();
+
+            tokio::spawn(async move {
+                if let Err(e) = deliver_event(webhook_repo, targets, policy, &webhook, &event).await
+                {
+                    tracing::error!(
+                        "Could not deliver {} to {}: {e}",
+                        event.event.as_ref(),
+                        webhook.url
+                    );
+                }
+            });
+        }
+    }
+}
@@ -14,7 +14,11 @@ async fn main() -> Result<()> {
 
     let config = Settings::get()?;
     let db = PgPool::connect_lazy_with(config.database.with_db());
-    let app = AppBuilder::new().with_postgres_database(db).build()?;
+    let redis = redis::Client::open(config.session.get_redis_connection_string())?;
+    let app = AppBuilder::new()
+        .with_postgres_database(db)
+        .with_redis(redis)
+        .build()?;
     let listener = config.application.get_listener().await?;
     app.serve(listener).await?;
 
@@ -1,15 +1,31 @@
-use std::{collections::BTreeMap, time::Duration};
+use std::{collections::BTreeMap, sync::OnceLock, time::Duration};
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use futures::future::join_all;
 
 use crate::domain::commands::recipes::update::UpdateRecipe;
 use crate::domain::entities::recipe::{IngredientUnit, IngredientWithAmount, ServingsType};
 use crate::domain::entities::{
+    collection::Collection,
+    cooking_session::CookingSession,
+    event::DomainEvent,
+    favorite::Favorite,
     ingredient::{types::DietViolations, Ingredient},
+    meal_plan::{MealPlanEntry, MealSlot},
+    pantry::PantryItem,
     recipe::Recipe,
+    recipe_note::RecipeNote,
+    review::{Rating, Review},
+    shopping_list::{items_from_recipes, ShoppingList},
+    user::User,
+    visibility::Visibility,
+    webhook::Webhook,
+};
+use crate::domain::repositories::{
+    collection::CollectionRepository, cooking_session::CookingSessionRepository,
+    ingredients::IngredientRepository, meal_plan::MealPlanRepository, outbox::OutboxRepository,
+    pantry::PantryRepository, recipe::RecipeRepository, user::UserRepository,
 };
-use crate::domain::repositories::ingredients::IngredientRepository;
 
 pub fn ingredient_fixture() -> Ingredient {
     Ingredient {
@@ -17,6 +33,7 @@ pub fn ingredient_fixture() -> Ingredient {
         name: "Beef".try_into().unwrap(),
         description: "You killed a cow for it".try_into().unwrap(),
         diet_violations: vec![DietViolations::GlutenFree].into(),
+        updated_at: DateTime::<Utc>::from_timestamp_nanos(1_662_921_288_000_000_000),
     }
 }
 
@@ -33,8 +50,13 @@ pub fn recipe_fixture() -> Recipe {
             ("Cook time".to_string(), Duration::from_secs(10 * 60))
         ]),
         servings: ServingsType::Exact(4),
+        rating: Default::default(),
+        forked_from: None,
+        visibility: Visibility::Public,
+        owner_id: None,
         ingredients: vec![
             IngredientWithAmount {
+                id: uuid::Uuid::from_u128(100),
                 ingredient: Ingredient {
                     id: uuid::Uuid::nil(),
                     name: "Firm tofu".try_into().unwrap(),
@@ -44,12 +66,15 @@ pub fn recipe_fixture() -> Recipe {
                         DietViolations::Vegetarian,
                         DietViolations::GlutenFree
                     ].into(),
+                    updated_at: DateTime::<Utc>::from_timestamp_nanos(1_662_921_288_000_000_000),
                 },
                 amount: IngredientUnit::Grams(400.0),
                 notes: None,
-                optional: false
+                optional: false,
+                group: None,
             },
             IngredientWithAmount {
+                id: uuid::Uuid::from_u128(101),
                 ingredient: Ingredient {
                     id: uuid::Uuid::from_u128(1),
                     name: "Broccoli".try_into().unwrap(),
@@ -59,12 +84,15 @@ pub fn recipe_fixture() -> Recipe {
                         DietViolations::Vegetarian,
                         DietViolations::GlutenFree
                     ].into(),
+                    updated_at: DateTime::<Utc>::from_timestamp_nanos(1_662_921_288_000_000_000),
                 },
                 amount: IngredientUnit::Other{unit: "head".to_string(), amount: 1.0},
                 notes: None,
-                optional: false
+                optional: false,
+                group: None,
             },
             IngredientWithAmount {
+                id: uuid::Uuid::from_u128(102),
                 ingredient: Ingredient {
                     id: uuid::Uuid::from_u128(2),
                     name: "Garlic".try_into().unwrap(),
@@ -74,10 +102,12 @@ pub fn recipe_fixture() -> Recipe {
                         DietViolations::Vegetarian,
                         DietViolations::GlutenFree
                     ].into(),
+                    updated_at: DateTime::<Utc>::from_timestamp_nanos(1_662_921_288_000_000_000),
                 },
                 amount: IngredientUnit::Other{ unit: "clove".to_string(), amount: 4.0 },
                 notes: None,
-                optional: false
+                optional: false,
+                group: None,
 
             },
         ].try_into().unwrap(),
@@ -105,9 +135,273 @@ pub fn recipe_changeset() -> UpdateRecipe {
         )])),
         steps: Some(vec!["WE UPDATED ANOTHER THING".to_string()]),
         servings: Some(ServingsType::Exact(4).into()),
+        visibility: Some(Visibility::Unlisted),
+    }
+}
+
+pub fn shopping_list_fixture() -> ShoppingList {
+    let recipe = recipe_fixture();
+
+    ShoppingList {
+        id: uuid::Uuid::from_u128(128),
+        name: "Groceries".to_string(),
+        recipe_ids: vec![recipe.id],
+        items: items_from_recipes(&[(recipe, None)], false),
+        created_at: DateTime::<Utc>::from_timestamp_nanos(1_662_921_288_000_000_000),
+        updated_at: DateTime::<Utc>::from_timestamp_nanos(1_662_921_288_000_000_000),
+    }
+}
+
+/// 500 g of the tofu from the recipe fixture, about to expire
+pub fn pantry_item_fixture() -> PantryItem {
+    PantryItem {
+        id: uuid::Uuid::from_u128(512),
+        user_id: user_fixture().id,
+        ingredient: recipe_fixture().ingredients[0].ingredient.clone(),
+        amount: Some(IngredientUnit::Grams(500.0)),
+        expires_at: NaiveDate::from_ymd_opt(2024, 10, 22),
+        created_at: DateTime::<Utc>::from_timestamp_nanos(1_662_921_288_000_000_000),
+        updated_at: DateTime::<Utc>::from_timestamp_nanos(1_662_921_288_000_000_000),
+    }
+}
+
+/// A user whose password is "hunter2".
+/// Hashing the password is slow on purpose, so it is only done once
+pub fn user_fixture() -> User {
+    static USER: OnceLock<User> = OnceLock::new();
+
+    USER.get_or_init(|| User {
+        id: uuid::Uuid::from_u128(4096),
+        created_at: DateTime::<Utc>::from_timestamp_nanos(1_662_921_288_000_000_000),
+        ..User::new("cook", "cook@example.com", "hunter2").unwrap()
+    })
+    .clone()
+}
+
+/// Somebody else than the user fixture, to check that they can't see each other's data
+pub fn another_user_fixture() -> User {
+    User {
+        id: uuid::Uuid::from_u128(4097),
+        username: "baker".to_string(),
+        email: "baker@example.com".to_string(),
+        ..user_fixture()
+    }
+}
+
+pub async fn insert_users(user_repo: &impl UserRepository) {
+    for user in [user_fixture(), another_user_fixture()] {
+        user_repo.insert(&user).await.unwrap();
+    }
+}
+
+pub fn meal_plan_entry_fixture() -> MealPlanEntry {
+    MealPlanEntry {
+        id: uuid::Uuid::from_u128(256),
+        user_id: user_fixture().id,
+        date: NaiveDate::from_ymd_opt(2024, 10, 21).unwrap(),
+        slot: MealSlot::Dinner,
+        recipe_id: recipe_fixture().id,
+        servings: 2,
+        created_at: DateTime::<Utc>::from_timestamp_nanos(1_662_921_288_000_000_000),
+        updated_at: DateTime::<Utc>::from_timestamp_nanos(1_662_921_288_000_000_000),
     }
 }
 
+/// Inserts the users and the recipe fixture along with its ingredients, then plans it with the given entries
+pub async fn insert_meal_plan(
+    meal_plan_repo: &impl MealPlanRepository,
+    recipe_repo: &impl RecipeRepository,
+    ingredient_repo: &impl IngredientRepository,
+    user_repo: &impl UserRepository,
+    entries: &[MealPlanEntry],
+) {
+    insert_users(user_repo).await;
+    let recipe = recipe_fixture();
+    insert_all_ingredients_of_recipe(ingredient_repo, &recipe).await;
+    recipe_repo.insert(recipe).await.unwrap();
+
+    for entry in entries {
+        meal_plan_repo.insert(entry.clone()).await.unwrap();
+    }
+}
+
+/// Inserts the users and the ingredients of the recipe fixture, then puts the given items in the pantry
+pub async fn insert_pantry(
+    pantry_repo: &impl PantryRepository,
+    ingredient_repo: &impl IngredientRepository,
+    user_repo: &impl UserRepository,
+    items: &[PantryItem],
+) {
+    insert_users(user_repo).await;
+    insert_all_ingredients_of_recipe(ingredient_repo, &recipe_fixture()).await;
+
+    for item in items {
+        pantry_repo.insert(item.clone()).await.unwrap();
+    }
+}
+
+/// The recipe fixture, owned by the user fixture and hidden from everybody else
+pub fn private_recipe_fixture() -> Recipe {
+    Recipe {
+        visibility: Visibility::Private,
+        owner_id: Some(user_fixture().id),
+        ..recipe_fixture()
+    }
+}
+
+/// The recipe fixture under another ID and name, for when a test needs more than one recipe
+pub fn another_recipe_fixture(n: u128) -> Recipe {
+    Recipe {
+        id: uuid::Uuid::from_u128(2048 + n),
+        name: format!("Hoisin Tofu and Broccoli #{n}"),
+        ..recipe_fixture()
+    }
+}
+
+/// A five star review of the recipe fixture by the user fixture
+pub fn review_fixture() -> Review {
+    Review {
+        id: uuid::Uuid::from_u128(4096),
+        recipe_id: recipe_fixture().id,
+        user_id: Some(user_fixture().id),
+        author: user_fixture().username,
+        rating: Rating::new(5).unwrap(),
+        body: Some("Quick, cheap and the sauce is great".to_string()),
+        created_at: DateTime::<Utc>::from_timestamp_nanos(1_662_921_288_000_000_000),
+        updated_at: DateTime::<Utc>::from_timestamp_nanos(1_662_921_288_000_000_000),
+    }
+}
+
+/// Inserts the users and the recipe fixture along with its ingredients, then adds the given reviews to it
+pub async fn insert_reviews(
+    recipe_repo: &impl RecipeRepository,
+    ingredient_repo: &impl IngredientRepository,
+    user_repo: &impl UserRepository,
+    reviews: &[Review],
+) {
+    insert_users(user_repo).await;
+    let recipe = recipe_fixture();
+    insert_all_ingredients_of_recipe(ingredient_repo, &recipe).await;
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+
+    for review in reviews {
+        recipe_repo
+            .add_review(&recipe, review.clone())
+            .await
+            .unwrap();
+    }
+}
+
+pub fn favorite_fixture() -> Favorite {
+    Favorite {
+        user_id: user_fixture().id,
+        recipe_id: recipe_fixture().id,
+        created_at: DateTime::<Utc>::from_timestamp_nanos(1_662_921_288_000_000_000),
+    }
+}
+
+pub fn recipe_note_fixture() -> RecipeNote {
+    RecipeNote {
+        user_id: user_fixture().id,
+        recipe_id: recipe_fixture().id,
+        content: "Used half the chili, was perfect".to_string(),
+        created_at: DateTime::<Utc>::from_timestamp_nanos(1_662_921_288_000_000_000),
+        updated_at: DateTime::<Utc>::from_timestamp_nanos(1_662_921_288_000_000_000),
+    }
+}
+
+/// A collection with the recipe fixture as its only recipe and cover
+pub fn collection_fixture() -> Collection {
+    Collection {
+        id: uuid::Uuid::from_u128(1024),
+        name: "Weeknight dinners".to_string(),
+        description: "Quick enough to make after work".to_string(),
+        visibility: Visibility::Public,
+        owner_id: None,
+        recipes: vec![recipe_fixture().id],
+        cover_recipe_id: Some(recipe_fixture().id),
+        created_at: DateTime::<Utc>::from_timestamp_nanos(1_662_921_288_000_000_000),
+        updated_at: DateTime::<Utc>::from_timestamp_nanos(1_662_921_288_000_000_000),
+    }
+}
+
+/// The collection fixture, owned by the user fixture and hidden from everybody else
+pub fn private_collection_fixture() -> Collection {
+    Collection {
+        visibility: Visibility::Private,
+        owner_id: Some(user_fixture().id),
+        ..collection_fixture()
+    }
+}
+
+/// Inserts the recipe fixture along with its ingredients, then creates the given collections
+pub async fn insert_collections(
+    collection_repo: &impl CollectionRepository,
+    recipe_repo: &impl RecipeRepository,
+    ingredient_repo: &impl IngredientRepository,
+    collections: &[Collection],
+) {
+    let recipe = recipe_fixture();
+    insert_all_ingredients_of_recipe(ingredient_repo, &recipe).await;
+    recipe_repo.insert(recipe).await.unwrap();
+
+    for collection in collections {
+        collection_repo.insert(collection.clone()).await.unwrap();
+    }
+}
+
+/// A cooking session of the recipe fixture that has not gotten past the first step yet
+pub fn cooking_session_fixture() -> CookingSession {
+    CookingSession {
+        id: uuid::Uuid::from_u128(8192),
+        recipe_id: recipe_fixture().id,
+        current_step: 0,
+        checked_ingredients: Default::default(),
+        timers: vec![],
+        created_at: DateTime::<Utc>::from_timestamp_nanos(1_662_921_288_000_000_000),
+        updated_at: DateTime::<Utc>::from_timestamp_nanos(1_662_921_288_000_000_000),
+    }
+}
+
+/// Inserts the recipe fixture along with its ingredients, then starts cooking it
+pub async fn insert_cooking_session(
+    session_repo: &impl CookingSessionRepository,
+    recipe_repo: &impl RecipeRepository,
+    ingredient_repo: &impl IngredientRepository,
+) -> CookingSession {
+    let recipe = recipe_fixture();
+    insert_all_ingredients_of_recipe(ingredient_repo, &recipe).await;
+    recipe_repo.insert(recipe).await.unwrap();
+
+    let session = cooking_session_fixture();
+    session_repo.insert(session.clone()).await.unwrap();
+
+    session
+}
+
+/// A webhook that only cares about new recipes
+pub fn webhook_fixture() -> Webhook {
+    Webhook {
+        id: uuid::Uuid::from_u128(16384),
+        url: "http://localhost:9999/hooks/recipes".to_string(),
+        secret: "keep it secret".to_string(),
+        event_types: vec!["recipe_created".to_string()],
+        owner_id: None,
+        created_at: DateTime::<Utc>::from_timestamp_nanos(1_662_921_288_000_000_000),
+    }
+}
+
+/// Events the repositories recorded so far, oldest first
+pub async fn unpublished_events(outbox_repo: &impl OutboxRepository) -> Vec<DomainEvent> {
+    outbox_repo
+        .get_unpublished(usize::MAX)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|e| e.event)
+        .collect()
+}
+
 pub async fn insert_all_ingredients_of_recipe(
     ingredient_repo: &impl IngredientRepository,
     recipe: &Recipe,
@@ -127,3 +421,57 @@ pub async fn insert_all_ingredients(
     )
     .await;
 }
+
+/// A request received by [`spawn_webhook_receiver`]
+#[derive(Debug, Clone)]
+pub struct ReceivedWebhook {
+    pub headers: axum::http::HeaderMap,
+    pub body: axum::body::Bytes,
+}
+
+/// A stand-in for a partner receiving webhooks on a random port. It answers with the given
+/// status codes in order, and with 200 once they run out.
+pub async fn spawn_webhook_receiver(
+    statuses: Vec<axum::http::StatusCode>,
+) -> (
+    String,
+    std::sync::Arc<std::sync::Mutex<Vec<ReceivedWebhook>>>,
+) {
+    use std::sync::{Arc, Mutex};
+
+    use axum::{
+        body::Bytes,
+        extract::State,
+        http::{HeaderMap, StatusCode},
+        routing::post,
+        Router,
+    };
+
+    type Received = Arc<Mutex<Vec<ReceivedWebhook>>>;
+
+    async fn receive(
+        State((statuses, received)): State<(Arc<Vec<StatusCode>>, Received)>,
+        headers: HeaderMap,
+        body: Bytes,
+    ) -> StatusCode {
+        let mut received = received.lock().unwrap();
+        let status = statuses
+            .get(received.len())
+            .copied()
+            .unwrap_or(StatusCode::OK);
+        received.push(ReceivedWebhook { headers, body });
+
+        status
+    }
+
+    let received: Received = Arc::default();
+    let router = Router::new()
+        .route("/hooks", post(receive))
+        .with_state((Arc::new(statuses), received.clone()));
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+
+    (format!("http://{addr}/hooks"), received)
+}
@@ -0,0 +1,320 @@
+use common::{
+    error::ErrorMessage, AddRecipeToCollectionDTO, CollectionDTO, CreateCollectionDTO,
+    IngredientDTO, RecipeDTO, UpdateCollectionDTO, VisibilityDTO,
+};
+use pretty_assertions::assert_eq;
+use reqwest::{Client, StatusCode};
+use uuid::Uuid;
+
+use crate::{
+    fixtures::{ingredient::ingredient_fixture, recipe::recipe_fixture},
+    setup::TestApp,
+};
+
+/// Creates the given number of recipes, returning their IDs
+async fn create_recipes(app: &TestApp, client: &Client, count: usize) -> Vec<Uuid> {
+    let ingredient: IngredientDTO = client
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let mut recipes = vec![];
+    for _ in 0..count {
+        let recipe: RecipeDTO = client
+            .post(app.get_base("recipe/create"))
+            .json(&recipe_fixture(std::slice::from_ref(&ingredient)))
+            .send()
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap();
+        recipes.push(recipe.id.parse().unwrap());
+    }
+
+    recipes
+}
+
+#[tokio::test]
+async fn managing_a_collection_works() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+    let token = app.login("cook").await;
+    let recipes = create_recipes(&app, &client, 2).await;
+    let (first, second) = (recipes[0], recipes[1]);
+
+    let response = client
+        .post(app.get_base("collection/create"))
+        .bearer_auth(&token)
+        .json(&CreateCollectionDTO {
+            name: "Weeknight dinners".to_string(),
+            description: "Quick enough to make after work".to_string(),
+            visibility: VisibilityDTO::Public,
+            recipes: vec![second],
+            cover_recipe_id: Some(second),
+        })
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let collection: CollectionDTO = response.json().await.unwrap();
+
+    let collection: CollectionDTO = client
+        .post(app.get_base(&format!("collection/{}/recipe", collection.id)))
+        .bearer_auth(&token)
+        .json(&AddRecipeToCollectionDTO {
+            recipe_id: first,
+            position: Some(0),
+        })
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert_eq!(collection.recipes, vec![first, second]);
+
+    let collection: CollectionDTO = client
+        .put(app.get_base(&format!("collection/{}", collection.id)))
+        .bearer_auth(&token)
+        .json(&UpdateCollectionDTO {
+            name: None,
+            description: None,
+            visibility: Some(VisibilityDTO::Unlisted),
+            cover_recipe_id: Some(first),
+        })
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert_eq!(collection.visibility, VisibilityDTO::Unlisted);
+    assert_eq!(collection.cover_recipe_id, Some(first));
+
+    let collections: Vec<CollectionDTO> = client
+        .get(app.get_base(&format!("recipe/{}/collections", second)))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert_eq!(collections, vec![collection.clone()]);
+
+    let collection: CollectionDTO = client
+        .delete(app.get_base(&format!("collection/{}/recipe/{}", collection.id, second)))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert_eq!(collection.recipes, vec![first]);
+
+    let response = client
+        .delete(app.get_base(&format!("collection/{}", collection.id)))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = client
+        .get(app.get_base(&format!("collection/{}", collection.id)))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let collections: Vec<CollectionDTO> = client
+        .get(app.get_base("collection"))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert_eq!(collections, vec![]);
+}
+
+#[tokio::test]
+async fn deleting_a_recipe_removes_it_from_collections() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+    let token = app.login("cook").await;
+    let recipes = create_recipes(&app, &client, 2).await;
+    let (first, second) = (recipes[0], recipes[1]);
+
+    let collection: CollectionDTO = client
+        .post(app.get_base("collection/create"))
+        .bearer_auth(&token)
+        .json(&CreateCollectionDTO {
+            name: "Christmas 2026".to_string(),
+            description: "".to_string(),
+            visibility: VisibilityDTO::Private,
+            recipes: vec![first, second],
+            cover_recipe_id: Some(first),
+        })
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let response = client
+        .delete(app.get_base(&format!("recipe/{}", first)))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let collection: CollectionDTO = client
+        .get(app.get_base(&format!("collection/{}", collection.id)))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert_eq!(collection.recipes, vec![second]);
+    assert_eq!(collection.cover_recipe_id, None);
+}
+
+#[tokio::test]
+async fn adding_a_recipe_to_a_collection_twice_fails() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+    let recipes = create_recipes(&app, &client, 1).await;
+
+    let collection: CollectionDTO = client
+        .post(app.get_base("collection/create"))
+        .json(&CreateCollectionDTO {
+            name: "Christmas 2026".to_string(),
+            description: "".to_string(),
+            visibility: VisibilityDTO::Public,
+            recipes: vec![recipes[0]],
+            cover_recipe_id: None,
+        })
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let response = client
+        .post(app.get_base(&format!("collection/{}/recipe", collection.id)))
+        .json(&AddRecipeToCollectionDTO {
+            recipe_id: recipes[0],
+            position: None,
+        })
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    let result: ErrorMessage<String> = response.json().await.unwrap();
+
+    assert_eq!(result.kind, "AlreadyInCollection");
+}
+
+#[tokio::test]
+async fn collections_of_someone_else_are_hidden_or_read_only() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+    let token = app.login("cook").await;
+    let other_token = app.login("baker").await;
+    let recipes = create_recipes(&app, &client, 1).await;
+
+    let mut ids = vec![];
+    for visibility in [VisibilityDTO::Private, VisibilityDTO::Public] {
+        let collection: CollectionDTO = client
+            .post(app.get_base("collection/create"))
+            .bearer_auth(&token)
+            .json(&CreateCollectionDTO {
+                name: "Christmas 2026".to_string(),
+                description: "".to_string(),
+                visibility,
+                recipes: vec![recipes[0]],
+                cover_recipe_id: None,
+            })
+            .send()
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap();
+        ids.push(collection.id);
+    }
+    let (private, public) = (ids[0], ids[1]);
+
+    let response = client
+        .get(app.get_base(&format!("collection/{}", private)))
+        .bearer_auth(&other_token)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let collections: Vec<CollectionDTO> = client
+        .get(app.get_base("collection"))
+        .bearer_auth(&other_token)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert_eq!(
+        collections.iter().map(|c| c.id).collect::<Vec<_>>(),
+        vec![public]
+    );
+
+    let response = client
+        .delete(app.get_base(&format!("collection/{}", public)))
+        .bearer_auth(&other_token)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let collections: Vec<CollectionDTO> = client
+        .get(app.get_base("collection"))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert_eq!(collections.len(), 2);
+}
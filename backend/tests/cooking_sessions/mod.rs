@@ -0,0 +1,176 @@
+use common::{
+    CookingActionDTO, CookingSessionDTO, CreateCookingSessionDTO, IngredientDTO, RecipeDTO,
+};
+use futures::{SinkExt, StreamExt};
+use pretty_assertions::assert_eq;
+use reqwest::{Client, StatusCode};
+use tokio_tungstenite::{connect_async, tungstenite::Message, MaybeTlsStream, WebSocketStream};
+use uuid::Uuid;
+
+use crate::{
+    fixtures::{ingredient::ingredient_fixture, recipe::recipe_fixture},
+    setup::TestApp,
+};
+
+type Socket = WebSocketStream<MaybeTlsStream<tokio::net::TcpStream>>;
+
+async fn create_recipe(app: &TestApp, client: &Client) -> RecipeDTO {
+    let ingredient: IngredientDTO = client
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    client
+        .post(app.get_base("recipe/create"))
+        .json(&recipe_fixture(&[ingredient]))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap()
+}
+
+async fn connect(app: &TestApp, session_id: &Uuid) -> Socket {
+    let (socket, _) = connect_async(format!(
+        "ws://{}/cooking-session/{}/ws",
+        app.addr, session_id
+    ))
+    .await
+    .unwrap();
+
+    socket
+}
+
+async fn receive_session(socket: &mut Socket) -> CookingSessionDTO {
+    match socket.next().await.unwrap().unwrap() {
+        Message::Text(text) => serde_json::from_str(&text).unwrap(),
+        message => panic!("Unexpected message: {message:?}"),
+    }
+}
+
+#[tokio::test]
+async fn cooking_together_on_two_devices_works() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+    let recipe = create_recipe(&app, &client).await;
+
+    let response = client
+        .post(app.get_base("cooking-session"))
+        .json(&CreateCookingSessionDTO {
+            recipe_id: recipe.id.parse().unwrap(),
+        })
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let session: CookingSessionDTO = response.json().await.unwrap();
+
+    assert_eq!(session.current_step, 0);
+
+    let mut phone = connect(&app, &session.id).await;
+    let mut tablet = connect(&app, &session.id).await;
+
+    assert_eq!(receive_session(&mut phone).await, session);
+    assert_eq!(receive_session(&mut tablet).await, session);
+
+    phone
+        .send(Message::Text(
+            serde_json::to_string(&CookingActionDTO::NextStep).unwrap(),
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(receive_session(&mut phone).await.current_step, 1);
+    assert_eq!(receive_session(&mut tablet).await.current_step, 1);
+
+    let response = client
+        .post(app.get_base(&format!("cooking-session/{}/action", session.id)))
+        .json(&CookingActionDTO::GoToStep { step: 0 })
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(receive_session(&mut tablet).await.current_step, 0);
+
+    let response = client
+        .delete(app.get_base(&format!("cooking-session/{}", session.id)))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(receive_session(&mut phone).await.current_step, 0);
+    assert!(matches!(
+        phone.next().await,
+        Some(Ok(Message::Close(_))) | None
+    ));
+}
+
+#[tokio::test]
+async fn invalid_actions_are_only_reported_to_the_device_that_sent_them() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+    let recipe = create_recipe(&app, &client).await;
+
+    let session: CookingSessionDTO = client
+        .post(app.get_base("cooking-session"))
+        .json(&CreateCookingSessionDTO {
+            recipe_id: recipe.id.parse().unwrap(),
+        })
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let mut phone = connect(&app, &session.id).await;
+    receive_session(&mut phone).await;
+
+    phone
+        .send(Message::Text(
+            serde_json::to_string(&CookingActionDTO::GoToStep { step: 100 }).unwrap(),
+        ))
+        .await
+        .unwrap();
+
+    let error: serde_json::Value = match phone.next().await.unwrap().unwrap() {
+        Message::Text(text) => serde_json::from_str(&text).unwrap(),
+        message => panic!("Unexpected message: {message:?}"),
+    };
+
+    assert_eq!(error["kind"], "InvalidAction");
+
+    let response = client
+        .get(app.get_base(&format!("cooking-session/{}", session.id)))
+        .send()
+        .await
+        .unwrap();
+
+    let current: CookingSessionDTO = response.json().await.unwrap();
+
+    assert_eq!(current, session);
+}
+
+#[tokio::test]
+async fn connecting_to_a_nonexistent_cooking_session_fails() {
+    let app = TestApp::new().await;
+
+    let result = connect_async(format!(
+        "ws://{}/cooking-session/{}/ws",
+        app.addr,
+        Uuid::from_u128(404)
+    ))
+    .await;
+
+    assert!(result.is_err());
+}
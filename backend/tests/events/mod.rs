@@ -0,0 +1,250 @@
+use std::net::SocketAddr;
+
+use backend::api::AppBuilder;
+use common::{DomainEventDTO, IngredientAmountDTO, IngredientDTO, IngredientUnitDTO, RecipeDTO};
+use pretty_assertions::assert_eq;
+use reqwest::{Client, Response, StatusCode};
+use tokio::net::TcpListener;
+use uuid::Uuid;
+
+use crate::{
+    fixtures::{
+        ingredient::{ingredient_fixture, ingredient_fixture_meat},
+        recipe::recipe_fixture,
+    },
+    setup::login,
+};
+
+/// The events have to work without a database too, so these tests run against the in-memory repositories
+async fn spawn_in_memory_app() -> SocketAddr {
+    let listener = TcpListener::bind("0.0.0.0:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let app = AppBuilder::new().build().unwrap();
+
+    tokio::spawn(async move {
+        app.serve(listener).await.unwrap();
+    });
+
+    addr
+}
+
+/// Reads the next event out of the stream, skipping the keep-alive comments.
+/// Returns the ID of the event along with its name and what it carries.
+async fn next_event(
+    response: &mut Response,
+    buffer: &mut String,
+) -> (Uuid, String, DomainEventDTO) {
+    loop {
+        if let Some(end) = buffer.find("\n\n") {
+            let message: String = buffer.drain(..end + 2).collect();
+            let mut id = None;
+            let mut name = None;
+            let mut data = None;
+
+            for line in message.lines() {
+                if let Some(value) = line.strip_prefix("id:") {
+                    id = Some(value.trim().parse().unwrap());
+                }
+                if let Some(value) = line.strip_prefix("event:") {
+                    name = Some(value.trim().to_string());
+                }
+                if let Some(value) = line.strip_prefix("data:") {
+                    data = Some(serde_json::from_str(value.trim()).unwrap());
+                }
+            }
+
+            if let (Some(id), Some(name), Some(data)) = (id, name, data) {
+                return (id, name, data);
+            }
+
+            continue;
+        }
+
+        let chunk = response.chunk().await.unwrap().unwrap();
+        buffer.push_str(std::str::from_utf8(&chunk).unwrap());
+    }
+}
+
+async fn create_ingredient(
+    client: &Client,
+    addr: &SocketAddr,
+    body: serde_json::Value,
+) -> IngredientDTO {
+    client
+        .post(format!("http://{addr}/ingredient/create"))
+        .json(&body)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn changes_are_streamed_as_they_happen() {
+    let addr = spawn_in_memory_app().await;
+    let client = Client::new();
+
+    let mut events = client
+        .get(format!("http://{addr}/events"))
+        .send()
+        .await
+        .unwrap();
+    let mut buffer = String::new();
+
+    assert_eq!(events.status(), StatusCode::OK);
+
+    let ingredient = create_ingredient(&client, &addr, ingredient_fixture()).await;
+
+    let (first_id, name, event) = next_event(&mut events, &mut buffer).await;
+
+    assert_eq!(name, "ingredient_created");
+    match event {
+        DomainEventDTO::IngredientCreated {
+            ingredient: created,
+        } => {
+            assert_eq!(created, ingredient)
+        }
+        e => panic!("Wrong event received: {e:?}"),
+    }
+
+    let recipe: RecipeDTO = client
+        .post(format!("http://{addr}/recipe/create"))
+        .json(&recipe_fixture(&[ingredient]))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let (id, name, event) = next_event(&mut events, &mut buffer).await;
+
+    assert_ne!(id, first_id);
+    assert_eq!(name, "recipe_created");
+    match event {
+        DomainEventDTO::RecipeCreated { recipe: created } => assert_eq!(created.id, recipe.id),
+        e => panic!("Wrong event received: {e:?}"),
+    }
+}
+
+#[tokio::test]
+async fn events_can_be_filtered_by_recipe() {
+    let addr = spawn_in_memory_app().await;
+    let client = Client::new();
+
+    let ingredient = create_ingredient(&client, &addr, ingredient_fixture()).await;
+    let recipe: RecipeDTO = client
+        .post(format!("http://{addr}/recipe/create"))
+        .json(&recipe_fixture(&[ingredient]))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let mut events = client
+        .get(format!("http://{addr}/events?recipe_id={}", recipe.id))
+        .send()
+        .await
+        .unwrap();
+    let mut buffer = String::new();
+
+    let meat = create_ingredient(&client, &addr, ingredient_fixture_meat()).await;
+
+    let response = client
+        .post(format!("http://{addr}/recipe/{}/ingredient", recipe.id))
+        .json(&IngredientAmountDTO {
+            ingredient_id: meat.id,
+            optional: true,
+            amount: IngredientUnitDTO::Grams(10.0),
+            notes: None,
+            group: None,
+        })
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // Events are relayed from the outbox, so the creation of the recipe may
+    // still arrive after subscribing
+    let (name, event) = loop {
+        let (_, name, event) = next_event(&mut events, &mut buffer).await;
+        if name != "recipe_created" {
+            break (name, event);
+        }
+    };
+
+    assert_eq!(name, "ingredient_added_to_recipe");
+    match event {
+        DomainEventDTO::IngredientAddedToRecipe {
+            recipe_id,
+            ingredient_id,
+        } => {
+            assert_eq!(recipe_id.to_string(), recipe.id);
+            assert_eq!(ingredient_id, meat.id);
+        }
+        e => panic!("Wrong event received: {e:?}"),
+    }
+}
+
+#[tokio::test]
+async fn events_about_private_recipes_of_someone_else_are_left_out() {
+    let addr = spawn_in_memory_app().await;
+    let client = Client::new();
+    let token = login(&addr, "cook").await;
+    let other_token = login(&addr, "baker").await;
+
+    let ingredient = create_ingredient(&client, &addr, ingredient_fixture()).await;
+    let recipe: RecipeDTO = client
+        .post(format!("http://{addr}/recipe/create"))
+        .bearer_auth(&token)
+        .json(&recipe_fixture(&[ingredient]))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let mut events = client
+        .get(format!("http://{addr}/events"))
+        .bearer_auth(&other_token)
+        .send()
+        .await
+        .unwrap();
+    let mut buffer = String::new();
+
+    let response = client
+        .put(format!("http://{addr}/recipe/{}", recipe.id))
+        .bearer_auth(&token)
+        .json(&serde_json::json!({ "visibility": "private", "name": "Secret" }))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let meat = create_ingredient(&client, &addr, ingredient_fixture_meat()).await;
+
+    // What happened before subscribing may still arrive, as events are relayed from the outbox.
+    // They arrive in order though, so the update of the now private recipe was skipped when
+    // the ingredient created after it comes next.
+    let event = loop {
+        match next_event(&mut events, &mut buffer).await.2 {
+            DomainEventDTO::RecipeCreated { .. } => continue,
+            DomainEventDTO::IngredientCreated { ingredient } if ingredient.id != meat.id => {
+                continue
+            }
+            event => break event,
+        }
+    };
+
+    match event {
+        DomainEventDTO::IngredientCreated { ingredient } => assert_eq!(ingredient, meat),
+        e => panic!("Wrong event received: {e:?}"),
+    }
+}
@@ -0,0 +1,157 @@
+use common::{
+    FavoriteDTO, FavoriteRecipeDTO, IngredientDTO, RecipeDTO, RecipeNoteDTO, SaveRecipeNoteDTO,
+};
+use pretty_assertions::assert_eq;
+use reqwest::{Client, StatusCode};
+
+use crate::{
+    fixtures::{ingredient::ingredient_fixture, recipe::recipe_fixture},
+    setup::TestApp,
+};
+
+async fn create_recipe(app: &TestApp, client: &Client) -> RecipeDTO {
+    let ingredient: IngredientDTO = client
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    client
+        .post(app.get_base("recipe/create"))
+        .json(&recipe_fixture(&[ingredient]))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn favoriting_and_taking_notes_works() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+    let token = app.login("cook").await;
+    let recipe = create_recipe(&app, &client).await;
+
+    let response = client
+        .put(app.get_base(&format!("recipe/{}/favorite", recipe.id)))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let favorite: FavoriteDTO = response.json().await.unwrap();
+
+    assert_eq!(favorite.recipe_id.to_string(), recipe.id);
+
+    let response = client
+        .put(app.get_base(&format!("recipe/{}/notes", recipe.id)))
+        .bearer_auth(&token)
+        .json(&SaveRecipeNoteDTO {
+            content: "Used half the cucumber, was perfect".to_string(),
+        })
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let note: RecipeNoteDTO = response.json().await.unwrap();
+
+    let favorites: Vec<FavoriteRecipeDTO> = client
+        .get(app.get_base("favorite"))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert_eq!(favorites.len(), 1);
+    assert_eq!(favorites[0].recipe.id, recipe.id);
+    assert_eq!(favorites[0].notes, Some(note.content));
+
+    let someone_else = app.login("baker").await;
+    let favorites: Vec<FavoriteRecipeDTO> = client
+        .get(app.get_base("favorite"))
+        .bearer_auth(&someone_else)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert!(favorites.is_empty());
+
+    let response = client
+        .get(app.get_base(&format!("recipe/{}/notes", recipe.id)))
+        .bearer_auth(&someone_else)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let recipe_after: RecipeDTO = client
+        .get(app.get_base(&format!("recipe/{}", recipe.id)))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert_eq!(recipe_after.updated_at, recipe.updated_at);
+
+    let response = client
+        .delete(app.get_base(&format!("recipe/{}/favorite", recipe.id)))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = client
+        .delete(app.get_base(&format!("recipe/{}/favorite", recipe.id)))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let response = client
+        .get(app.get_base(&format!("recipe/{}/notes", recipe.id)))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn favoriting_a_nonexistent_recipe_fails() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+    let token = app.login("cook").await;
+
+    let response = client
+        .put(app.get_base(&format!("recipe/{}/favorite", uuid::Uuid::nil())))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
@@ -30,6 +30,7 @@ async fn getting_all_with_full_database_returns_in_array() {
             DietViolations::Vegan.to_string(),
             DietViolations::Vegetarian.to_string(),
         ],
+        updated_at: Default::default(),
     }];
 
     let tx = app.db.begin().await.unwrap();
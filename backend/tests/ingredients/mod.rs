@@ -1,4 +1,6 @@
 mod delete;
 mod get_all;
 mod insert;
+mod parse;
+mod update;
 // TODO: add more e2e tests for ingredients
@@ -0,0 +1,76 @@
+use common::{
+    error::ErrorMessage, IngredientDTO, IngredientUnitDTO, ParseIngredientLinesDTO,
+    ParsedIngredientLineDTO,
+};
+use pretty_assertions::assert_eq;
+use reqwest::{Client, StatusCode};
+
+use crate::{fixtures::ingredient::ingredient_fixture_meat, setup::TestApp};
+
+#[tokio::test]
+async fn parsing_ingredient_lines_works() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+
+    let meat: IngredientDTO = client
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture_meat())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let body = ParseIngredientLinesDTO {
+        lines: vec![
+            "500g minced beef, browned".to_string(),
+            "2-3 cloves garlic (optional)".to_string(),
+        ],
+    };
+
+    let response = client
+        .post(app.get_base("ingredient/parse"))
+        .json(&body)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let result: Vec<ParsedIngredientLineDTO> = response.json().await.unwrap();
+
+    assert_eq!(result.len(), 2);
+
+    assert_eq!(result[0].amount, IngredientUnitDTO::Grams(500.0));
+    assert_eq!(result[0].name, "minced beef");
+    assert_eq!(result[0].notes.as_deref(), Some("browned"));
+    assert_eq!(result[0].ingredient, Some(meat));
+
+    assert_eq!(result[1].name, "garlic");
+    assert!(result[1].optional);
+    assert_eq!(result[1].ingredient, None);
+}
+
+#[tokio::test]
+async fn parsing_a_line_without_an_ingredient_fails() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+
+    let body = ParseIngredientLinesDTO {
+        lines: vec!["2 cups".to_string()],
+    };
+
+    let response = client
+        .post(app.get_base("ingredient/parse"))
+        .json(&body)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    let result: ErrorMessage<String> = response.json().await.unwrap();
+
+    assert_eq!(result.kind, "InvalidLine");
+}
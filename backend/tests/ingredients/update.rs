@@ -0,0 +1,58 @@
+use common::{error::ErrorMessage, IngredientDTO};
+use reqwest::{header, Client, StatusCode};
+
+use crate::{fixtures::ingredient::ingredient_fixture, setup::TestApp};
+
+#[tokio::test]
+async fn updating_ingredient_honors_etags() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+    let ingredient_create_path = app.get_base("ingredient/create");
+
+    let ingredient: IngredientDTO = client
+        .post(&ingredient_create_path)
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let ingredient_path = app.get_base(&format!("ingredient/{}", ingredient.id));
+
+    let response = client.get(&ingredient_path).send().await.unwrap();
+    let etag = response.headers()[header::ETAG].clone();
+
+    let response = client
+        .get(&ingredient_path)
+        .header(header::IF_NONE_MATCH, etag.clone())
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+
+    let response = client
+        .put(&ingredient_path)
+        .header(header::IF_MATCH, etag.clone())
+        .json(&serde_json::json!({ "name": "Cucumber, peeled" }))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = client
+        .put(&ingredient_path)
+        .header(header::IF_MATCH, etag)
+        .json(&serde_json::json!({ "name": "Cucumber, sliced" }))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::PRECONDITION_FAILED);
+
+    let error: ErrorMessage<String> = response.json().await.unwrap();
+    assert_eq!(error.kind, "PreconditionFailed");
+}
@@ -0,0 +1,371 @@
+use chrono::{NaiveDate, Utc};
+use common::{
+    error::ErrorMessage, CalendarFeedDTO, CreateCalendarFeedDTO, CreateMealPlanEntryDTO,
+    IngredientDTO, IngredientUnitDTO, MealPlanEntryDTO, MealPlanSummaryDTO, MealSlotDTO, RecipeDTO,
+    UpdateMealPlanEntryDTO,
+};
+use pretty_assertions::assert_eq;
+use reqwest::{Client, StatusCode};
+use uuid::Uuid;
+
+use crate::{
+    fixtures::{ingredient::ingredient_fixture, recipe::recipe_fixture},
+    setup::TestApp,
+};
+
+#[tokio::test]
+async fn planning_meals_works() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+    let token = app.login("cook").await;
+
+    let cucumber: IngredientDTO = client
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let recipe: RecipeDTO = client
+        .post(app.get_base("recipe/create"))
+        .json(&recipe_fixture(std::slice::from_ref(&cucumber)))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let recipe_id: Uuid = recipe.id.parse().unwrap();
+
+    let monday = NaiveDate::from_ymd_opt(2024, 10, 21).unwrap();
+    let tuesday = NaiveDate::from_ymd_opt(2024, 10, 22).unwrap();
+
+    let mut entries = vec![];
+    for (date, slot, servings) in [
+        (tuesday, MealSlotDTO::Lunch, 3),
+        (monday, MealSlotDTO::Dinner, 2),
+    ] {
+        let response = client
+            .post(app.get_base("meal-plan/create"))
+            .bearer_auth(&token)
+            .json(&CreateMealPlanEntryDTO {
+                date,
+                slot,
+                recipe_id,
+                servings,
+            })
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let entry: MealPlanEntryDTO = response.json().await.unwrap();
+        entries.push(entry);
+    }
+
+    let plan: Vec<MealPlanEntryDTO> = client
+        .get(app.get_base("meal-plan?from=2024-10-21&to=2024-10-27"))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert_eq!(
+        plan.iter().map(|e| e.id).collect::<Vec<_>>(),
+        vec![entries[1].id, entries[0].id]
+    );
+
+    let summary: MealPlanSummaryDTO = client
+        .get(app.get_base("meal-plan/summary?from=2024-10-21&to=2024-10-27"))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert_eq!(summary.entries.len(), 2);
+    assert_eq!(summary.ingredients.len(), 1);
+    assert_eq!(summary.ingredients[0].ingredient, cucumber);
+    assert_eq!(
+        summary.ingredients[0].amount,
+        IngredientUnitDTO::Grams(500.0)
+    );
+
+    let entry_path = app.get_base(&format!("meal-plan/{}", entries[0].id));
+
+    let updated: MealPlanEntryDTO = client
+        .put(&entry_path)
+        .bearer_auth(&token)
+        .json(&UpdateMealPlanEntryDTO {
+            date: None,
+            slot: Some(MealSlotDTO::Breakfast),
+            recipe_id: None,
+            servings: None,
+        })
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert_eq!(updated.slot, MealSlotDTO::Breakfast);
+
+    let response = client
+        .delete(&entry_path)
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = client
+        .get(&entry_path)
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn meal_plans_are_only_visible_to_their_owner() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+    let token = app.login("cook").await;
+    let someone_else = app.login("baker").await;
+
+    let cucumber: IngredientDTO = client
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let recipe: RecipeDTO = client
+        .post(app.get_base("recipe/create"))
+        .json(&recipe_fixture(std::slice::from_ref(&cucumber)))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let entry: MealPlanEntryDTO = client
+        .post(app.get_base("meal-plan/create"))
+        .bearer_auth(&token)
+        .json(&CreateMealPlanEntryDTO {
+            date: Utc::now().date_naive(),
+            slot: MealSlotDTO::Dinner,
+            recipe_id: recipe.id.parse().unwrap(),
+            servings: 2,
+        })
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let entry_path = app.get_base(&format!("meal-plan/{}", entry.id));
+
+    let response = client.get(&entry_path).send().await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let response = client
+        .get(&entry_path)
+        .bearer_auth("not a token")
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let response = client
+        .delete(&entry_path)
+        .bearer_auth(&someone_else)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let plan: Vec<MealPlanEntryDTO> = client
+        .get(app.get_base(&format!(
+            "meal-plan?from={0}&to={0}",
+            Utc::now().date_naive()
+        )))
+        .bearer_auth(&someone_else)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert!(plan.is_empty());
+
+    let feed: CalendarFeedDTO = client
+        .post(app.get_base("meal-plan/feed/create"))
+        .bearer_auth(&someone_else)
+        .json(&CreateCalendarFeedDTO {
+            name: "Phone".to_string(),
+        })
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let calendar = client
+        .get(app.get_base(feed.url.trim_start_matches('/')))
+        .send()
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+
+    assert!(!calendar.contains("BEGIN:VEVENT"));
+
+    let response = client
+        .delete(app.get_base(&format!("meal-plan/feed/{}", feed.token)))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let response = client
+        .get(&entry_path)
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn getting_a_meal_plan_for_an_invalid_range_fails() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+    let token = app.login("cook").await;
+
+    let response = client
+        .get(app.get_base("meal-plan?from=2024-10-27&to=2024-10-21"))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let result: ErrorMessage<String> = response.json().await.unwrap();
+
+    assert_eq!(result.kind, "InvalidRange");
+}
+
+#[tokio::test]
+async fn subscribing_to_the_meal_plan_works() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+    let token = app.login("cook").await;
+
+    let cucumber: IngredientDTO = client
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let recipe: RecipeDTO = client
+        .post(app.get_base("recipe/create"))
+        .json(&recipe_fixture(std::slice::from_ref(&cucumber)))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let response = client
+        .post(app.get_base("meal-plan/create"))
+        .bearer_auth(&token)
+        .json(&CreateMealPlanEntryDTO {
+            date: Utc::now().date_naive(),
+            slot: MealSlotDTO::Dinner,
+            recipe_id: recipe.id.parse().unwrap(),
+            servings: 2,
+        })
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let response = client
+        .post(app.get_base("meal-plan/feed/create"))
+        .bearer_auth(&token)
+        .json(&CreateCalendarFeedDTO {
+            name: "Kitchen tablet".to_string(),
+        })
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let feed: CalendarFeedDTO = response.json().await.unwrap();
+    let feed_url = app.get_base(feed.url.trim_start_matches('/'));
+
+    let response = client.get(&feed_url).send().await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()["content-type"],
+        "text/calendar; charset=utf-8"
+    );
+
+    let calendar = response.text().await.unwrap().replace("\r\n ", "");
+
+    assert!(calendar.contains("BEGIN:VEVENT"));
+    assert!(calendar.contains(&format!("SUMMARY:{}", recipe.name)));
+    assert!(calendar.contains(&format!(
+        "URL:{}",
+        app.get_base(&format!("recipe/{}", recipe.id))
+    )));
+
+    let response = client
+        .delete(app.get_base(&format!("meal-plan/feed/{}", feed.token)))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = client.get(&feed_url).send().await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
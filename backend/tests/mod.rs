@@ -1,4 +1,16 @@
+// Fixtures are built from cloned DTOs, i.e. `recipe_fixture(&[ingredient.clone()])`
+#![allow(clippy::cloned_ref_to_slice_refs)]
+
+mod collections;
+mod cooking_sessions;
+mod events;
+mod favorites;
 pub mod fixtures;
 mod ingredients;
+mod meal_plans;
+mod pantry;
 mod recipes;
 mod setup;
+mod shopping_lists;
+mod users;
+mod webhooks;
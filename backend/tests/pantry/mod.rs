@@ -0,0 +1,262 @@
+use chrono::{Days, Utc};
+use common::{
+    error::ErrorMessage, CookableRecipeDTO, CreatePantryItemDTO, IngredientDTO, IngredientUnitDTO,
+    PantryItemDTO, RecipeDTO, UpdatePantryItemDTO,
+};
+use pretty_assertions::assert_eq;
+use reqwest::{Client, StatusCode};
+
+use crate::{
+    fixtures::{
+        ingredient::{ingredient_fixture, ingredient_fixture_meat},
+        recipe::recipe_fixture,
+    },
+    setup::TestApp,
+};
+
+#[tokio::test]
+async fn finding_what_to_cook_works() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+    let token = app.login("cook").await;
+
+    let mut ingredients: Vec<IngredientDTO> = vec![];
+    for ingredient in [ingredient_fixture(), ingredient_fixture_meat()] {
+        let ingredient: IngredientDTO = client
+            .post(app.get_base("ingredient/create"))
+            .json(&ingredient)
+            .send()
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap();
+        ingredients.push(ingredient);
+    }
+    let (cucumber, beef) = (ingredients[0].clone(), ingredients[1].clone());
+
+    let recipe: RecipeDTO = client
+        .post(app.get_base("recipe/create"))
+        .json(&recipe_fixture(&ingredients))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let response = client
+        .post(app.get_base("pantry/create"))
+        .bearer_auth(&token)
+        .json(&CreatePantryItemDTO {
+            ingredient_id: cucumber.id,
+            amount: Some(IngredientUnitDTO::Grams(50.0)),
+            expires_at: Utc::now().date_naive().checked_add_days(Days::new(1)),
+        })
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let item: PantryItemDTO = response.json().await.unwrap();
+
+    assert_eq!(item.ingredient, cucumber);
+
+    let cookable: Vec<CookableRecipeDTO> = client
+        .get(app.get_base("pantry/cookable"))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    // There is only half the cucumber needed, and no beef
+    assert_eq!(cookable.len(), 1);
+    assert_eq!(cookable[0].recipe.id, recipe.id);
+    assert_eq!((cookable[0].covered, cookable[0].required), (0, 2));
+    assert_eq!(cookable[0].expiring, vec![cucumber.clone()]);
+
+    let response = client
+        .put(app.get_base(&format!("pantry/{}", item.id)))
+        .bearer_auth(&token)
+        .json(&UpdatePantryItemDTO {
+            amount: None,
+            expires_at: None,
+        })
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let cookable: Vec<CookableRecipeDTO> = client
+        .get(app.get_base("pantry/cookable"))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert_eq!((cookable[0].covered, cookable[0].required), (1, 2));
+    assert_eq!(cookable[0].missing.len(), 1);
+    assert_eq!(cookable[0].missing[0].ingredient, beef);
+
+    let response = client
+        .delete(app.get_base(&format!("pantry/{}", item.id)))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let pantry: Vec<PantryItemDTO> = client
+        .get(app.get_base("pantry"))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert_eq!(pantry, vec![]);
+}
+
+#[tokio::test]
+async fn adding_an_ingredient_to_the_pantry_twice_fails() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+    let token = app.login("cook").await;
+
+    let cucumber: IngredientDTO = client
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let body = CreatePantryItemDTO {
+        ingredient_id: cucumber.id,
+        amount: None,
+        expires_at: None,
+    };
+
+    let response = client
+        .post(app.get_base("pantry/create"))
+        .bearer_auth(&token)
+        .json(&body)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let response = client
+        .post(app.get_base("pantry/create"))
+        .bearer_auth(&token)
+        .json(&body)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    let result: ErrorMessage<String> = response.json().await.unwrap();
+
+    assert_eq!(result.kind, "AlreadyInPantry");
+}
+
+#[tokio::test]
+async fn pantries_are_kept_per_user() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+    let token = app.login("cook").await;
+    let someone_else = app.login("baker").await;
+
+    let cucumber: IngredientDTO = client
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let body = CreatePantryItemDTO {
+        ingredient_id: cucumber.id,
+        amount: None,
+        expires_at: None,
+    };
+
+    let response = client.get(app.get_base("pantry")).send().await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let item: PantryItemDTO = client
+        .post(app.get_base("pantry/create"))
+        .bearer_auth(&token)
+        .json(&body)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let pantry: Vec<PantryItemDTO> = client
+        .get(app.get_base("pantry"))
+        .bearer_auth(&someone_else)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert_eq!(pantry, vec![]);
+
+    let response = client
+        .delete(app.get_base(&format!("pantry/{}", item.id)))
+        .bearer_auth(&someone_else)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    // The same ingredient can be in both pantries
+    let response = client
+        .post(app.get_base("pantry/create"))
+        .bearer_auth(&someone_else)
+        .json(&body)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let pantry: Vec<PantryItemDTO> = client
+        .get(app.get_base("pantry"))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert_eq!(
+        pantry.into_iter().map(|i| i.id).collect::<Vec<_>>(),
+        vec![item.id]
+    );
+}
@@ -0,0 +1,132 @@
+use common::{error::ErrorMessage, FailedIngredientOperationDTO, IngredientDTO, RecipeDTO};
+use pretty_assertions::assert_eq;
+use reqwest::{Client, StatusCode};
+use uuid::Uuid;
+
+use crate::{
+    fixtures::{
+        ingredient::{ingredient_fixture, ingredient_fixture_meat},
+        recipe::recipe_fixture,
+    },
+    setup::TestApp,
+};
+
+async fn create_ingredient(
+    app: &TestApp,
+    client: &Client,
+    ingredient: serde_json::Value,
+) -> IngredientDTO {
+    client
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn batch_editing_recipe_applies_all_operations() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+
+    let cucumber = create_ingredient(&app, &client, ingredient_fixture()).await;
+    let beef = create_ingredient(&app, &client, ingredient_fixture_meat()).await;
+
+    let recipe: RecipeDTO = client
+        .post(app.get_base("recipe/create"))
+        .json(&recipe_fixture(std::slice::from_ref(&cucumber)))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let response = client
+        .post(app.get_base(&format!("recipe/{}/batch", recipe.id)))
+        .json(&serde_json::json!({
+            "recipe": { "name": "Just beef" },
+            "operations": [
+                {
+                    "op": "add",
+                    "ingredient_id": beef.id,
+                    "amount": { "_type": "grams", "amount": 5.0 },
+                    "optional": false,
+                    "notes": null
+                },
+                { "op": "remove", "entry_id": recipe.ingredients[0].id },
+            ]
+        }))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let result: RecipeDTO = response.json().await.unwrap();
+
+    assert_eq!(result.name, "Just beef");
+    assert_eq!(result.ingredients.len(), 1);
+    assert_eq!(result.ingredients[0].ingredient.id, beef.id);
+}
+
+#[tokio::test]
+async fn batch_editing_recipe_reports_every_invalid_operation() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+
+    let cucumber = create_ingredient(&app, &client, ingredient_fixture()).await;
+
+    let recipe: RecipeDTO = client
+        .post(app.get_base("recipe/create"))
+        .json(&recipe_fixture(std::slice::from_ref(&cucumber)))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let response = client
+        .post(app.get_base(&format!("recipe/{}/batch", recipe.id)))
+        .json(&serde_json::json!({
+            "recipe": { "name": "This never gets saved" },
+            "operations": [
+                { "op": "remove", "entry_id": Uuid::nil() },
+                {
+                    "op": "update",
+                    "entry_id": recipe.ingredients[0].id,
+                    "amount": { "_type": "grams", "amount": 100.0 }
+                },
+                { "op": "remove", "entry_id": recipe.ingredients[0].id },
+            ]
+        }))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    let error: ErrorMessage<Vec<FailedIngredientOperationDTO>> = response.json().await.unwrap();
+    let failed: Vec<_> = error
+        .error
+        .iter()
+        .map(|e| (e.index, e.kind.as_str()))
+        .collect();
+
+    assert_eq!(failed, vec![(0, "NotInRecipe"), (2, "LastIngredient")]);
+
+    let unchanged: RecipeDTO = client
+        .get(app.get_base(&format!("recipe/{}", recipe.id)))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert_eq!(unchanged.name, recipe.name);
+}
@@ -244,3 +244,88 @@ async fn inserting_recipe_with_partially_incorrect_ingredients() {
 
     assert_eq!(result.kind, "IngredientsNotFound");
 }
+
+#[tokio::test]
+async fn creating_a_recipe_with_a_repeated_ingredient_works() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+
+    let ingredient: IngredientDTO = client
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let mut data = recipe_fixture(&[ingredient.clone(), ingredient.clone()]);
+    data["ingredients"][1]["group"] = serde_json::json!("For the dressing");
+
+    let result: RecipeDTO = client
+        .post(app.get_base("recipe/create"))
+        .json(&data)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert_eq!(result.ingredients.len(), 2);
+    assert_ne!(result.ingredients[0].id, result.ingredients[1].id);
+    assert_eq!(result.ingredients[0].group, None);
+    assert_eq!(
+        result.ingredients[1].group.as_deref(),
+        Some("For the dressing")
+    );
+}
+
+#[tokio::test]
+async fn creating_a_recipe_with_ranges_and_unquantified_amounts_works() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+
+    let ingredient: IngredientDTO = client
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let mut data = recipe_fixture(&[ingredient.clone(), ingredient.clone()]);
+    data["ingredients"][0]["amount"] = serde_json::json!({
+        "_type": "range",
+        "amount": {
+            "from": { "_type": "pieces", "amount": 2.0 },
+            "to": 3.0
+        }
+    });
+    data["ingredients"][1]["amount"] = serde_json::json!({
+        "_type": "unquantified",
+        "amount": "to taste"
+    });
+
+    let result: serde_json::Value = client
+        .post(app.get_base("recipe/create"))
+        .json(&data)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert_eq!(
+        result["ingredients"][0]["amount"],
+        data["ingredients"][0]["amount"]
+    );
+    assert_eq!(
+        result["ingredients"][1]["amount"],
+        data["ingredients"][1]["amount"]
+    );
+}
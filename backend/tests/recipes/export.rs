@@ -0,0 +1,150 @@
+use common::{error::ErrorMessage, ingredients::IngredientDTO, ExportCookbookDTO, RecipeDTO};
+use reqwest::{
+    header::{CONTENT_DISPOSITION, CONTENT_TYPE},
+    Client, StatusCode,
+};
+use uuid::Uuid;
+
+use crate::{
+    fixtures::{ingredient::ingredient_fixture, recipe::recipe_fixture},
+    setup::TestApp,
+};
+
+async fn create_recipe(app: &TestApp, client: &Client) -> RecipeDTO {
+    let ingredient: IngredientDTO = client
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    client
+        .post(app.get_base("recipe/create"))
+        .json(&recipe_fixture(&[ingredient]))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn exporting_recipe_as_jsonld_works() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+    let recipe = create_recipe(&app, &client).await;
+
+    let response = client
+        .get(app.get_base(&format!("recipe/{}/export", recipe.id)))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()[CONTENT_TYPE], "application/ld+json");
+
+    let body: serde_json::Value = response.json().await.unwrap();
+
+    assert_eq!(body["@type"], "Recipe");
+    assert_eq!(body["name"], "A diced cucumber");
+    assert_eq!(body["prepTime"], "PT1H40M");
+}
+
+#[tokio::test]
+async fn exporting_recipe_as_markdown_works() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+    let recipe = create_recipe(&app, &client).await;
+
+    let response = client
+        .get(app.get_base(&format!("recipe/{}/export?format=markdown", recipe.id)))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()[CONTENT_TYPE],
+        "text/markdown; charset=utf-8"
+    );
+
+    let body = response.text().await.unwrap();
+
+    assert!(body.starts_with("# A diced cucumber\n"));
+}
+
+#[tokio::test]
+async fn exporting_nonexistent_recipe_fails() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+
+    let response = client
+        .get(app.get_base(&format!("recipe/{}/export", Uuid::nil())))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn exporting_a_cookbook_as_epub_works() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+    let recipe = create_recipe(&app, &client).await;
+
+    let response = client
+        .post(app.get_base("recipe/export/epub"))
+        .json(&ExportCookbookDTO {
+            title: Some("Cucumber classics".to_string()),
+            recipe_ids: vec![recipe.id.parse().unwrap()],
+            collection_id: None,
+            diets: vec![],
+        })
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()[CONTENT_TYPE], "application/epub+zip");
+    assert_eq!(
+        response.headers()[CONTENT_DISPOSITION],
+        "attachment; filename=\"cucumber-classics.epub\""
+    );
+
+    let body = response.bytes().await.unwrap();
+
+    // A ZIP archive, starting with the uncompressed mimetype
+    assert!(body.starts_with(b"PK\x03\x04"));
+    assert_eq!(&body[30..38], b"mimetype");
+    assert_eq!(&body[38..58], b"application/epub+zip");
+}
+
+#[tokio::test]
+async fn exporting_a_cookbook_with_an_unknown_diet_fails() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+    let recipe = create_recipe(&app, &client).await;
+
+    let response = client
+        .post(app.get_base("recipe/export/epub"))
+        .json(&ExportCookbookDTO {
+            title: None,
+            recipe_ids: vec![recipe.id.parse().unwrap()],
+            collection_id: None,
+            diets: vec!["carnivore".to_string()],
+        })
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let result: ErrorMessage<String> = response.json().await.unwrap();
+
+    assert_eq!(result.kind, "ValidationError");
+}
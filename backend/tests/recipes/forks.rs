@@ -0,0 +1,90 @@
+use common::{ingredients::IngredientDTO, RecipeDTO};
+use reqwest::{Client, StatusCode};
+
+use crate::{
+    fixtures::{ingredient::ingredient_fixture, recipe::recipe_fixture},
+    setup::TestApp,
+};
+
+async fn create_recipe(app: &TestApp, client: &Client) -> RecipeDTO {
+    let ingredient: IngredientDTO = client
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    client
+        .post(app.get_base("recipe/create"))
+        .json(&recipe_fixture(&[ingredient]))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap()
+}
+
+async fn fork(app: &TestApp, client: &Client, recipe: &RecipeDTO) -> RecipeDTO {
+    let response = client
+        .post(app.get_base(&format!("recipe/{}/fork", recipe.id)))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    response.json().await.unwrap()
+}
+
+async fn get_recipes(app: &TestApp, client: &Client, path: &str) -> Vec<RecipeDTO> {
+    let response = client.get(app.get_base(path)).send().await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    response.json().await.unwrap()
+}
+
+#[tokio::test]
+async fn forking_a_recipe_works() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+    let recipe = create_recipe(&app, &client).await;
+
+    let child = fork(&app, &client, &recipe).await;
+    let grandchild = fork(&app, &client, &child).await;
+
+    assert_ne!(child.id, recipe.id);
+    assert_eq!(child.forked_from, Some(recipe.id.clone()));
+    assert_eq!(child.name, recipe.name);
+    assert_eq!(child.ingredients.len(), recipe.ingredients.len());
+
+    let forks = get_recipes(&app, &client, &format!("recipe/{}/forks", recipe.id)).await;
+    assert_eq!(
+        forks.into_iter().map(|r| r.id).collect::<Vec<_>>(),
+        vec![child.id.clone()]
+    );
+
+    let ancestry = get_recipes(&app, &client, &format!("recipe/{}/ancestry", grandchild.id)).await;
+    assert_eq!(
+        ancestry.into_iter().map(|r| r.id).collect::<Vec<_>>(),
+        vec![child.id, recipe.id]
+    );
+}
+
+#[tokio::test]
+async fn forking_a_nonexistent_recipe_fails() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+
+    let response = client
+        .post(app.get_base(&format!("recipe/{}/fork", uuid::Uuid::nil())))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
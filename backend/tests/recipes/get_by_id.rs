@@ -96,3 +96,63 @@ async fn getting_nonexistent_recipe_fails() {
 
     assert_eq!(body.kind, "NotFound");
 }
+
+#[tokio::test]
+async fn getting_unchanged_recipe_returns_not_modified() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+    let ingredient_create_path = app.get_base("ingredient/create");
+    let recipe_create_path = app.get_base("recipe/create");
+
+    let ingredient: IngredientDTO = client
+        .post(&ingredient_create_path)
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let recipe: RecipeDTO = client
+        .post(&recipe_create_path)
+        .json(&recipe_fixture(&[ingredient]))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let recipe_path = app.get_base(&format!("recipe/{}", recipe.id));
+
+    let response = client.get(&recipe_path).send().await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let etag = response.headers()[reqwest::header::ETAG].clone();
+
+    let response = client
+        .get(&recipe_path)
+        .header(reqwest::header::IF_NONE_MATCH, etag.clone())
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+    assert_eq!(response.headers()[reqwest::header::ETAG], etag);
+
+    client
+        .put(&recipe_path)
+        .json(&serde_json::json!({ "name": "Changed in the meantime" }))
+        .send()
+        .await
+        .unwrap();
+
+    let response = client
+        .get(&recipe_path)
+        .header(reqwest::header::IF_NONE_MATCH, etag)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
@@ -0,0 +1,228 @@
+use common::{
+    error::ErrorMessage, BulkImportedRecipesDTO, ImportedRecipeDTO, IngredientDTO,
+    RecipePreviewsDTO,
+};
+use reqwest::{
+    multipart::{Form, Part},
+    Client, StatusCode,
+};
+
+use crate::{fixtures::ingredient::ingredient_fixture, setup::TestApp};
+
+fn jsonld_upload(document: &str) -> Form {
+    Form::new().part(
+        "file",
+        Part::text(document.to_string()).file_name("recipe.html"),
+    )
+}
+
+async fn create_ingredient(app: &TestApp, client: &Client) -> IngredientDTO {
+    client
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap()
+}
+
+const COOKLANG_RECIPE: &str = r#"
+>> servings: 1
+>> prep time: 5 minutes
+
+Get a @cucumber{2}(diced) and a #knife.
+
+Dice it, then season with @salt{1%tsp}.
+"#;
+
+#[tokio::test]
+async fn importing_a_jsonld_recipe_works() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+
+    let ingredient: IngredientDTO = client
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let document = r#"
+        <html><head><script type="application/ld+json">
+        {
+            "@context": "https://schema.org",
+            "@type": "Recipe",
+            "name": "A diced cucumber",
+            "description": "Cucumber that's been diced",
+            "recipeYield": "1 serving",
+            "prepTime": "PT5M",
+            "recipeIngredient": ["2 cucumbers, diced", "1 tsp salt"],
+            "recipeInstructions": [
+                { "@type": "HowToStep", "text": "Get a cucumber" },
+                { "@type": "HowToStep", "text": "Dice it" }
+            ]
+        }
+        </script></head></html>
+    "#;
+
+    let response = client
+        .post(app.get_base("recipe/import/jsonld"))
+        .multipart(jsonld_upload(document))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let result: ImportedRecipeDTO = response.json().await.unwrap();
+
+    assert_eq!(result.unmatched_ingredients, vec!["1 tsp salt"]);
+    assert_eq!(result.recipe.name, "A diced cucumber");
+    assert_eq!(result.recipe.steps, vec!["Get a cucumber", "Dice it"]);
+    assert_eq!(result.recipe.time.get("Prep time"), Some(&300));
+    assert_eq!(result.recipe.ingredients.len(), 1);
+    assert_eq!(result.recipe.ingredients[0].ingredient, ingredient);
+    assert_eq!(result.recipe.ingredients[0].notes.as_deref(), Some("diced"));
+}
+
+#[tokio::test]
+async fn importing_a_document_without_a_recipe_fails() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+
+    let response = client
+        .post(app.get_base("recipe/import/jsonld"))
+        .multipart(jsonld_upload(
+            "<html><body>Nothing to see here</body></html>",
+        ))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    let result: ErrorMessage<String> = response.json().await.unwrap();
+
+    assert_eq!(result.kind, "RecipeNotFound");
+}
+
+#[tokio::test]
+async fn importing_a_cooklang_recipe_works() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+    let ingredient = create_ingredient(&app, &client).await;
+
+    let response = client
+        .post(app.get_base("recipe/import/cooklang"))
+        .multipart(Form::new().part(
+            "file",
+            Part::text(COOKLANG_RECIPE).file_name("A diced cucumber.cook"),
+        ))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let result: ImportedRecipeDTO = response.json().await.unwrap();
+
+    assert_eq!(result.unmatched_ingredients, vec!["salt"]);
+    assert_eq!(result.recipe.name, "A diced cucumber");
+    assert_eq!(
+        result.recipe.steps,
+        vec![
+            "Get a cucumber and a knife.",
+            "Dice it, then season with salt."
+        ]
+    );
+    assert_eq!(result.recipe.time.get("Prep time"), Some(&300));
+    assert_eq!(result.recipe.ingredients.len(), 1);
+    assert_eq!(result.recipe.ingredients[0].ingredient, ingredient);
+    assert_eq!(result.recipe.ingredients[0].notes.as_deref(), Some("diced"));
+}
+
+#[tokio::test]
+async fn importing_a_directory_of_cooklang_recipes_works() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+    create_ingredient(&app, &client).await;
+
+    let form = Form::new()
+        .part(
+            "files",
+            Part::text(COOKLANG_RECIPE).file_name("recipes/Cucumber.cook"),
+        )
+        .part(
+            "files",
+            Part::text("Boil some @water.").file_name("recipes/Water.cook"),
+        )
+        .part(
+            "files",
+            Part::text("not a recipe").file_name("recipes/README.md"),
+        );
+
+    let response = client
+        .post(app.get_base("recipe/import/cooklang/bulk"))
+        .multipart(form)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let result: BulkImportedRecipesDTO = response.json().await.unwrap();
+
+    assert_eq!(result.imported.len(), 1);
+    assert_eq!(result.imported[0].recipe.name, "Cucumber");
+    assert_eq!(result.failed.len(), 1);
+    assert_eq!(result.failed[0].file_name, "recipes/Water.cook");
+    assert_eq!(result.failed[0].kind, "NoIngredientsMatched");
+}
+
+#[tokio::test]
+async fn previewing_a_text_recipe_does_not_create_it() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+    let ingredient = create_ingredient(&app, &client).await;
+
+    let document = "A diced cucumber\n\nIngredients:\n2 cucumbers, diced\n\nDirections:\n1. Get a cucumber\n2. Dice it\n";
+
+    let response = client
+        .post(app.get_base("recipe/import/text"))
+        .multipart(Form::new().part("file", Part::text(document).file_name("cucumber.txt")))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let result: RecipePreviewsDTO = response.json().await.unwrap();
+
+    assert_eq!(result.previews.len(), 1);
+    assert!(result.failed.is_empty());
+    assert_eq!(result.previews[0].recipe.name, "A diced cucumber");
+    assert_eq!(
+        result.previews[0].recipe.steps,
+        vec!["Get a cucumber", "Dice it"]
+    );
+    assert_eq!(result.previews[0].recipe.ingredients.len(), 1);
+    assert_eq!(
+        result.previews[0].recipe.ingredients[0].ingredient_id,
+        ingredient.id
+    );
+
+    // The preview can be sent as-is to create the recipe
+    let response = client
+        .post(app.get_base("recipe/create"))
+        .json(&result.previews[0].recipe)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+}
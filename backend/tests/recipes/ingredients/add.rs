@@ -1,5 +1,6 @@
 use common::{
-    error::ErrorMessage, IngredientAmountDTO, IngredientDTO, IngredientUnitDTO, RecipeDTO,
+    error::ErrorMessage, AddIngredientLineToRecipeDTO, IngredientAmountDTO, IngredientDTO,
+    IngredientUnitDTO, RecipeDTO,
 };
 use futures::future::join_all;
 use pretty_assertions::assert_eq;
@@ -63,6 +64,7 @@ async fn adding_an_ingredient_to_a_recipe_works() {
         optional: true,
         amount: IngredientUnitDTO::Grams(10.0),
         notes: None,
+        group: None,
     };
 
     let result = client
@@ -134,6 +136,7 @@ async fn adding_a_nonexistent_ingredient_to_a_recipe_fails() {
         optional: true,
         amount: IngredientUnitDTO::Grams(10.0),
         notes: None,
+        group: None,
     };
 
     let result = client
@@ -179,6 +182,7 @@ async fn adding_an_ingredient_to_a_nonexistent_recipe_fails() {
         optional: true,
         amount: IngredientUnitDTO::Grams(10.0),
         notes: None,
+        group: None,
     };
 
     let result = client
@@ -194,3 +198,111 @@ async fn adding_an_ingredient_to_a_nonexistent_recipe_fails() {
 
     assert_eq!(result.kind, "GetRecipe")
 }
+
+#[tokio::test]
+async fn adding_an_ingredient_line_to_a_recipe_works() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+    let ingredient_create_path = app.get_base("ingredient/create");
+    let recipe_create_path = app.get_base("recipe/create");
+
+    let ingredients_to_create = [ingredient_fixture(), ingredient_fixture_meat()];
+
+    let ingredients: Vec<IngredientDTO> =
+        join_all(ingredients_to_create.iter().map(|ingredient| async {
+            client
+                .post(&ingredient_create_path)
+                .json(&ingredient.clone())
+                .send()
+                .await
+                .unwrap()
+                .json()
+                .await
+                .unwrap()
+        }))
+        .await;
+
+    let [ref veg, ref meat, ..] = ingredients[..] else {
+        panic!("Something went wrong with the ingredient adding step")
+    };
+
+    let data = recipe_fixture(std::slice::from_ref(veg));
+
+    let result: RecipeDTO = client
+        .post(&recipe_create_path)
+        .json(&data)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let ingredient_add_path = app.get_base(&format!("recipe/{}/ingredient/line", result.id));
+
+    let ingredient_to_add = AddIngredientLineToRecipeDTO {
+        line: "200 g beef, sliced thinly".to_string(),
+    };
+
+    let result = client
+        .post(&ingredient_add_path)
+        .json(&ingredient_to_add)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(result.status(), StatusCode::OK);
+
+    let result: RecipeDTO = result.json().await.unwrap();
+    let added = result
+        .ingredients
+        .iter()
+        .find(|i| i.ingredient.id == meat.id)
+        .unwrap();
+
+    assert_eq!(added.amount, IngredientUnitDTO::Grams(200.0));
+    assert_eq!(added.notes.as_deref(), Some("sliced thinly"));
+
+    let ingredient_to_add = AddIngredientLineToRecipeDTO {
+        line: "1 tbsp hoisin sauce".to_string(),
+    };
+
+    let result = client
+        .post(&ingredient_add_path)
+        .json(&ingredient_to_add)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(result.status(), StatusCode::BAD_REQUEST);
+
+    let result: ErrorMessage<String> = result.json().await.unwrap();
+
+    assert_eq!(result.kind, "IngredientNotMatched")
+}
+
+#[tokio::test]
+async fn adding_an_ingredient_with_a_malformed_amount_reports_the_field() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+
+    let ingredient_add_path = app.get_base(&format!("recipe/{}/ingredient", Uuid::nil()));
+
+    let result = client
+        .post(&ingredient_add_path)
+        .json(&serde_json::json!({
+            "ingredient_id": Uuid::nil(),
+            "optional": false,
+            "amount": { "_type": "grams", "amount": "a lot" },
+        }))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(result.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    let result: ErrorMessage<String> = result.json().await.unwrap();
+
+    assert_eq!(result.kind, "JSON_PARSE");
+    assert!(result.error.contains("amount"), "{}", result.error);
+}
@@ -53,8 +53,14 @@ pub async fn deleting_an_existing_ingredient_works() {
 
     assert_eq!(result.ingredients.len(), 2);
 
+    let entry = result
+        .ingredients
+        .iter()
+        .find(|entry| entry.ingredient.id == meat.id)
+        .unwrap();
+
     let ingredient_delete_path =
-        app.get_base(&format!("recipe/{}/ingredient/{}", result.id, meat.id));
+        app.get_base(&format!("recipe/{}/ingredient/{}", result.id, entry.id));
     let get_recipe_path = app.get_base(&format!("recipe/{}", result.id));
 
     let result = client.delete(&ingredient_delete_path).send().await.unwrap();
@@ -213,8 +219,10 @@ pub async fn deleting_the_last_ingredient_in_recipe_errors() {
 
     assert_eq!(result.ingredients.len(), 1);
 
-    let ingredient_delete_path =
-        app.get_base(&format!("recipe/{}/ingredient/{}", result.id, veg.id));
+    let ingredient_delete_path = app.get_base(&format!(
+        "recipe/{}/ingredient/{}",
+        result.id, result.ingredients[0].id
+    ));
 
     let result = client.delete(&ingredient_delete_path).send().await.unwrap();
 
@@ -1,5 +1,7 @@
 use assert_json_diff::assert_json_include;
-use common::{error::ErrorMessage, IngredientDTO, IngredientUnitDTO, RecipeDTO};
+use common::{
+    error::ErrorMessage, IngredientDTO, IngredientUnitDTO, RecipeDTO, UpdateIngredientInRecipeDTO,
+};
 use futures::future::join_all;
 use reqwest::{Client, StatusCode};
 use uuid::Uuid;
@@ -45,15 +47,18 @@ async fn updating_an_ingredient_in_a_recipe_works() {
 
     let ingredient_update_path = app.get_base(&format!(
         "recipe/{}/ingredient/{}",
-        result.id, ingredient.id
+        result.id, result.ingredients[0].id
     ));
     let get_recipe_path = app.get_base(&format!("recipe/{}", result.id));
 
-    let ingredient_to_add = IngredientUnitDTO::Grams(999.0);
+    let changeset = UpdateIngredientInRecipeDTO {
+        amount: Some(IngredientUnitDTO::Grams(999.0)),
+        ..Default::default()
+    };
 
     let result = client
         .put(&ingredient_update_path)
-        .json(&ingredient_to_add)
+        .json(&changeset)
         .send()
         .await
         .unwrap();
@@ -125,11 +130,14 @@ async fn updating_a_nonexistent_ingredient_in_a_recipe_errors() {
     let ingredient_update_path =
         app.get_base(&format!("recipe/{}/ingredient/{}", result.id, meat.id));
 
-    let ingredient_to_add = IngredientUnitDTO::Grams(999.0);
+    let changeset = UpdateIngredientInRecipeDTO {
+        amount: Some(IngredientUnitDTO::Grams(999.0)),
+        ..Default::default()
+    };
 
     let result = client
         .put(&ingredient_update_path)
-        .json(&ingredient_to_add)
+        .json(&changeset)
         .send()
         .await
         .unwrap();
@@ -163,11 +171,14 @@ async fn updating_an_ingredient_in_a_nonexistent_recipe_errors() {
         ingredient.id
     ));
 
-    let ingredient_to_add = IngredientUnitDTO::Grams(999.0);
+    let changeset = UpdateIngredientInRecipeDTO {
+        amount: Some(IngredientUnitDTO::Grams(999.0)),
+        ..Default::default()
+    };
 
     let result = client
         .put(&ingredient_update_path)
-        .json(&ingredient_to_add)
+        .json(&changeset)
         .send()
         .await
         .unwrap();
@@ -178,3 +189,58 @@ async fn updating_an_ingredient_in_a_nonexistent_recipe_errors() {
 
     assert_eq!(result.kind, "GetRecipe")
 }
+
+#[tokio::test]
+async fn updating_the_notes_of_an_ingredient_keeps_the_amount() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+
+    let ingredient: IngredientDTO = client
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let recipe: RecipeDTO = client
+        .post(app.get_base("recipe/create"))
+        .json(&recipe_fixture(std::slice::from_ref(&ingredient)))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let result: RecipeDTO = client
+        .put(app.get_base(&format!(
+            "recipe/{}/ingredient/{}",
+            recipe.id, recipe.ingredients[0].id
+        )))
+        .json(&serde_json::json!({
+            "notes": "peeled",
+            "optional": true,
+            "group": "For the salad"
+        }))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert_json_include!(actual: result, expected: serde_json::json!({
+        "ingredients": [{
+            "amount": {
+                "_type": "grams",
+                "amount": 100.0
+            },
+            "notes": "peeled",
+            "optional": true,
+            "group": "For the salad"
+        }]
+    }));
+}
@@ -1,5 +1,11 @@
+mod batch;
 mod create;
 mod delete;
+mod export;
+mod forks;
 mod get_by_id;
+mod import;
 mod ingredients;
+mod reviews;
 mod update;
+mod visibility;
@@ -0,0 +1,153 @@
+use common::{
+    error::ErrorMessage, ingredients::IngredientDTO, CreateReviewDTO, RecipeDTO, ReviewDTO,
+    UpdateReviewDTO,
+};
+use reqwest::{Client, StatusCode};
+
+use crate::{
+    fixtures::{ingredient::ingredient_fixture, recipe::recipe_fixture},
+    setup::TestApp,
+};
+
+async fn create_recipe(app: &TestApp, client: &Client) -> RecipeDTO {
+    let ingredient: IngredientDTO = client
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    client
+        .post(app.get_base("recipe/create"))
+        .json(&recipe_fixture(&[ingredient]))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap()
+}
+
+fn review(rating: u8) -> CreateReviewDTO {
+    CreateReviewDTO {
+        rating,
+        body: Some("Crunchy".to_string()),
+    }
+}
+
+#[tokio::test]
+async fn reviewing_a_recipe_works() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+    let token = app.login("cook").await;
+    let recipe = create_recipe(&app, &client).await;
+
+    assert_eq!(recipe.rating_average, None);
+    assert_eq!(recipe.rating_count, 0);
+
+    let response = client
+        .post(app.get_base(&format!("recipe/{}/review", recipe.id)))
+        .bearer_auth(&token)
+        .json(&review(4))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let created: ReviewDTO = response.json().await.unwrap();
+
+    assert_eq!(created.author, "cook");
+
+    let response = client
+        .post(app.get_base(&format!("recipe/{}/review", recipe.id)))
+        .bearer_auth(&token)
+        .json(&review(1))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    let edit = UpdateReviewDTO {
+        rating: Some(2),
+        body: Some("".to_string()),
+    };
+    let review_path = app.get_base(&format!("recipe/{}/review/{}", recipe.id, created.id));
+
+    let response = client
+        .put(&review_path)
+        .bearer_auth(app.login("baker").await)
+        .json(&edit)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = client
+        .put(&review_path)
+        .bearer_auth(&token)
+        .json(&edit)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let edited: ReviewDTO = response.json().await.unwrap();
+
+    assert_eq!(edited.rating, 2);
+    assert_eq!(edited.body, None);
+    assert_eq!(edited.created_at, created.created_at);
+
+    let reviews: Vec<ReviewDTO> = client
+        .get(app.get_base(&format!("recipe/{}/reviews", recipe.id)))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert_eq!(reviews, vec![edited]);
+
+    let recipes: Vec<RecipeDTO> = client
+        .get(app.get_base("recipe"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert_eq!(recipes.len(), 1);
+    assert_eq!(recipes[0].id, recipe.id);
+    assert_eq!(recipes[0].rating_average, Some(2.0));
+    assert_eq!(recipes[0].rating_count, 1);
+}
+
+#[tokio::test]
+async fn reviewing_with_a_rating_out_of_range_fails() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+    let token = app.login("cook").await;
+    let recipe = create_recipe(&app, &client).await;
+
+    let response = client
+        .post(app.get_base(&format!("recipe/{}/review", recipe.id)))
+        .bearer_auth(&token)
+        .json(&review(6))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let error: ErrorMessage<String> = response.json().await.unwrap();
+
+    assert_eq!(error.kind, "ValidationError");
+}
@@ -1,5 +1,5 @@
 use assert_json_diff::assert_json_include;
-use common::{ingredients::IngredientDTO, RecipeDTO};
+use common::{error::ErrorMessage, ingredients::IngredientDTO, RecipeDTO};
 use futures::future::join_all;
 use pretty_assertions::assert_eq;
 use reqwest::{Client, StatusCode};
@@ -162,3 +162,71 @@ async fn updating_empty_changeset_errors() {
 
     assert_eq!(result.status(), StatusCode::BAD_REQUEST);
 }
+
+#[tokio::test]
+async fn updating_recipe_with_outdated_etag_fails() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+    let ingredient_create_path = app.get_base("ingredient/create");
+    let recipe_create_path = app.get_base("recipe/create");
+
+    let ingredient: IngredientDTO = client
+        .post(&ingredient_create_path)
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let recipe: RecipeDTO = client
+        .post(&recipe_create_path)
+        .json(&recipe_fixture(&[ingredient]))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let recipe_path = app.get_base(&format!("recipe/{}", &recipe.id));
+
+    let response = client.get(&recipe_path).send().await.unwrap();
+    let etag = response.headers()[reqwest::header::ETAG].clone();
+
+    let response = client
+        .put(&recipe_path)
+        .header(reqwest::header::IF_MATCH, etag.clone())
+        .json(&serde_json::json!({ "name": "First update" }))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_ne!(response.headers()[reqwest::header::ETAG], etag);
+
+    let response = client
+        .put(&recipe_path)
+        .header(reqwest::header::IF_MATCH, etag)
+        .json(&serde_json::json!({ "name": "Second update" }))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::PRECONDITION_FAILED);
+
+    let error: ErrorMessage<String> = response.json().await.unwrap();
+    assert_eq!(error.kind, "PreconditionFailed");
+
+    let recipe: RecipeDTO = client
+        .get(&recipe_path)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert_eq!(recipe.name, "First update");
+}
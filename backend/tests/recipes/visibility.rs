@@ -0,0 +1,292 @@
+use common::{ingredients::IngredientDTO, RecipeDTO, ShareTokenDTO, VisibilityDTO};
+use reqwest::{Client, StatusCode};
+
+use crate::{
+    fixtures::{ingredient::ingredient_fixture, recipe::recipe_fixture},
+    setup::TestApp,
+};
+
+async fn create_recipe(app: &TestApp, client: &Client, token: &str) -> RecipeDTO {
+    let ingredient: IngredientDTO = client
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    client
+        .post(app.get_base("recipe/create"))
+        .bearer_auth(token)
+        .json(&recipe_fixture(&[ingredient]))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap()
+}
+
+async fn set_visibility(
+    app: &TestApp,
+    client: &Client,
+    token: &str,
+    recipe: &RecipeDTO,
+    visibility: &str,
+) -> StatusCode {
+    client
+        .put(app.get_base(&format!("recipe/{}", recipe.id)))
+        .bearer_auth(token)
+        .json(&serde_json::json!({ "visibility": visibility }))
+        .send()
+        .await
+        .unwrap()
+        .status()
+}
+
+async fn get_status(app: &TestApp, client: &Client, path: &str) -> StatusCode {
+    client
+        .get(app.get_base(path))
+        .send()
+        .await
+        .unwrap()
+        .status()
+}
+
+async fn get_status_as(app: &TestApp, client: &Client, token: &str, path: &str) -> StatusCode {
+    client
+        .get(app.get_base(path))
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+        .status()
+}
+
+#[tokio::test]
+async fn unlisted_recipes_are_reachable_through_share_tokens() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+    let token = app.login("cook").await;
+    let other_token = app.login("baker").await;
+    let recipe = create_recipe(&app, &client, &token).await;
+
+    assert_eq!(recipe.visibility, VisibilityDTO::Public);
+
+    assert_eq!(
+        set_visibility(&app, &client, &other_token, &recipe, "unlisted").await,
+        StatusCode::FORBIDDEN
+    );
+    assert_eq!(
+        set_visibility(&app, &client, &token, &recipe, "unlisted").await,
+        StatusCode::OK
+    );
+
+    let recipe_path = format!("recipe/{}", recipe.id);
+    assert_eq!(
+        get_status(&app, &client, &recipe_path).await,
+        StatusCode::NOT_FOUND
+    );
+    assert_eq!(
+        get_status_as(&app, &client, &token, &recipe_path).await,
+        StatusCode::OK
+    );
+
+    let listing: Vec<RecipeDTO> = client
+        .get(app.get_base("recipe"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert!(listing.is_empty());
+
+    let share_path = format!("recipe/{}/share", recipe.id);
+    let response = client.post(app.get_base(&share_path)).send().await.unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let response = client
+        .post(app.get_base(&share_path))
+        .bearer_auth(&other_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let response = client
+        .post(app.get_base(&share_path))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let share_token: ShareTokenDTO = response.json().await.unwrap();
+
+    let shared: RecipeDTO = client
+        .get(app.get_base(share_token.url.trim_start_matches('/')))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(shared.id, recipe.id);
+    assert_eq!(shared.visibility, VisibilityDTO::Unlisted);
+
+    let shares_path = format!("recipe/{}/shares", recipe.id);
+    assert_eq!(
+        get_status(&app, &client, &shares_path).await,
+        StatusCode::UNAUTHORIZED
+    );
+    assert_eq!(
+        get_status_as(&app, &client, &other_token, &shares_path).await,
+        StatusCode::NOT_FOUND
+    );
+
+    let share_tokens: Vec<ShareTokenDTO> = client
+        .get(app.get_base(&shares_path))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(share_tokens, vec![share_token.clone()]);
+
+    let revoke_path = format!("recipe/{}/share/{}", recipe.id, share_token.token);
+    let response = client
+        .delete(app.get_base(&revoke_path))
+        .bearer_auth(&other_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let response = client
+        .delete(app.get_base(&revoke_path))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    assert_eq!(
+        get_status(&app, &client, share_token.url.trim_start_matches('/')).await,
+        StatusCode::NOT_FOUND
+    );
+}
+
+#[tokio::test]
+async fn private_recipes_are_only_seen_by_their_owner() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+    let token = app.login("cook").await;
+    let other_token = app.login("baker").await;
+    let recipe = create_recipe(&app, &client, &token).await;
+
+    let share_token: ShareTokenDTO = client
+        .post(app.get_base(&format!("recipe/{}/share", recipe.id)))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert_eq!(
+        set_visibility(&app, &client, &token, &recipe, "private").await,
+        StatusCode::OK
+    );
+
+    assert_eq!(
+        get_status(&app, &client, share_token.url.trim_start_matches('/')).await,
+        StatusCode::NOT_FOUND
+    );
+
+    for path in [
+        format!("recipe/{}", recipe.id),
+        format!("recipe/{}/export", recipe.id),
+        format!("recipe/{}/reviews", recipe.id),
+        format!("recipe/{}/forks", recipe.id),
+        format!("recipe/{}/ancestry", recipe.id),
+    ] {
+        assert_eq!(
+            get_status_as(&app, &client, &other_token, &path).await,
+            StatusCode::NOT_FOUND
+        );
+        assert_eq!(
+            get_status_as(&app, &client, &token, &path).await,
+            StatusCode::OK
+        );
+    }
+
+    let fork_path = format!("recipe/{}/fork", recipe.id);
+    let response = client
+        .post(app.get_base(&fork_path))
+        .bearer_auth(&other_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let fork: RecipeDTO = client
+        .post(app.get_base(&fork_path))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(fork.visibility, VisibilityDTO::Private);
+
+    let listing: Vec<RecipeDTO> = client
+        .get(app.get_base("recipe"))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(listing.len(), 2);
+}
+
+#[tokio::test]
+async fn recipes_without_an_owner_stay_public() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+    let token = app.login("cook").await;
+    let ingredient: IngredientDTO = client
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let recipe: RecipeDTO = client
+        .post(app.get_base("recipe/create"))
+        .json(&recipe_fixture(&[ingredient]))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert_eq!(
+        set_visibility(&app, &client, &token, &recipe, "private").await,
+        StatusCode::FORBIDDEN
+    );
+    assert_eq!(
+        get_status(&app, &client, &format!("recipe/{}", recipe.id)).await,
+        StatusCode::OK
+    );
+}
@@ -1,6 +1,8 @@
 use std::{net::SocketAddr, time::Duration};
 
 use backend::api::AppBuilder;
+use common::user::{CreateNewUserDTO, LoginUserDTO, SessionDTO};
+use reqwest::Client;
 use sqlx::{pool::PoolOptions, postgres::PgConnectOptions, PgPool, Postgres};
 use testcontainers::{runners::AsyncRunner, ContainerAsync};
 use testcontainers_modules::postgres::Postgres as PostgresContainer;
@@ -63,4 +65,44 @@ impl TestApp {
     pub fn get_base(&self, rest: &str) -> String {
         format!("http://{}/{}", self.addr, rest)
     }
+
+    /// Signs up a user with the given name and logs them in, returning the token of their session
+    pub async fn login(&self, username: &str) -> String {
+        login(&self.addr, username).await
+    }
+}
+
+/// Signs up a user with the given name on the app at `addr` and logs them in, returning the token
+/// of their session
+pub async fn login(addr: &SocketAddr, username: &str) -> String {
+    let client = Client::new();
+    let password = "correct horse battery staple".to_string();
+
+    client
+        .post(format!("http://{addr}/user/signup"))
+        .json(&CreateNewUserDTO {
+            username: username.to_string(),
+            email: format!("{username}@example.com"),
+            password: password.clone(),
+        })
+        .send()
+        .await
+        .unwrap()
+        .error_for_status()
+        .unwrap();
+
+    let session: SessionDTO = client
+        .post(format!("http://{addr}/user/login"))
+        .json(&LoginUserDTO {
+            username: username.to_string(),
+            password,
+        })
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    session.token
 }
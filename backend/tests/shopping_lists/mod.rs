@@ -0,0 +1,142 @@
+use common::{
+    error::ErrorMessage, CreateShoppingListDTO, IngredientDTO, IngredientUnitDTO, RecipeDTO,
+    ShoppingListDTO, ShoppingListRecipeDTO, UpdateShoppingListItemDTO,
+};
+use pretty_assertions::assert_eq;
+use reqwest::{Client, StatusCode};
+use uuid::Uuid;
+
+use crate::{
+    fixtures::{ingredient::ingredient_fixture, recipe::recipe_fixture},
+    setup::TestApp,
+};
+
+#[tokio::test]
+async fn shopping_for_recipes_works() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+
+    let cucumber: IngredientDTO = client
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let recipe: RecipeDTO = client
+        .post(app.get_base("recipe/create"))
+        .json(&recipe_fixture(std::slice::from_ref(&cucumber)))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let recipe_id: Uuid = recipe.id.parse().unwrap();
+
+    let body = CreateShoppingListDTO {
+        name: "Cucumber party".to_string(),
+        recipes: vec![
+            ShoppingListRecipeDTO {
+                recipe_id,
+                servings: Some(3),
+            },
+            ShoppingListRecipeDTO {
+                recipe_id,
+                servings: None,
+            },
+        ],
+        exclude_optional: false,
+    };
+
+    let response = client
+        .post(app.get_base("shopping-list/create"))
+        .json(&body)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let shopping_list: ShoppingListDTO = response.json().await.unwrap();
+
+    assert_eq!(shopping_list.items.len(), 1);
+    assert_eq!(shopping_list.items[0].ingredient, cucumber);
+    assert_eq!(
+        shopping_list.items[0].amount,
+        IngredientUnitDTO::Grams(400.0)
+    );
+
+    let item_path = app.get_base(&format!(
+        "shopping-list/{}/item/{}",
+        shopping_list.id, shopping_list.items[0].id
+    ));
+
+    let response = client
+        .put(&item_path)
+        .json(&UpdateShoppingListItemDTO { checked: true })
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = client
+        .get(app.get_base(&format!("shopping-list/{}/export", shopping_list.id)))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.text().await.unwrap(),
+        "Cucumber party\n\n[x] 400 g Cucumber\n"
+    );
+
+    let response = client
+        .delete(app.get_base(&format!("shopping-list/{}", shopping_list.id)))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = client
+        .get(app.get_base(&format!("shopping-list/{}", shopping_list.id)))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn shopping_for_a_nonexistent_recipe_fails() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+
+    let body = CreateShoppingListDTO {
+        name: "Nothing".to_string(),
+        recipes: vec![ShoppingListRecipeDTO {
+            recipe_id: Uuid::nil(),
+            servings: None,
+        }],
+        exclude_optional: false,
+    };
+
+    let response = client
+        .post(app.get_base("shopping-list/create"))
+        .json(&body)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let result: ErrorMessage<String> = response.json().await.unwrap();
+
+    assert_eq!(result.kind, "RecipeNotFound");
+}
@@ -0,0 +1,133 @@
+use common::{
+    error::ErrorMessage,
+    user::{CreateNewUserDTO, LoginUserDTO, SessionDTO, UserDataDTO},
+};
+use pretty_assertions::assert_eq;
+use reqwest::{Client, StatusCode};
+
+use crate::setup::TestApp;
+
+fn new_user() -> CreateNewUserDTO {
+    CreateNewUserDTO {
+        username: "cook".to_string(),
+        email: "cook@example.com".to_string(),
+        password: "hunter2".to_string(),
+    }
+}
+
+#[tokio::test]
+async fn signing_up_and_logging_in_works() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+
+    let response = client
+        .post(app.get_base("user/signup"))
+        .json(&new_user())
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let user: UserDataDTO = response.json().await.unwrap();
+
+    assert_eq!(user.username, "cook");
+    assert_eq!(user.email, "cook@example.com");
+
+    let response = client
+        .post(app.get_base("user/signup"))
+        .json(&new_user())
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    let response = client
+        .post(app.get_base("user/login"))
+        .json(&LoginUserDTO {
+            username: "cook".to_string(),
+            password: "hunter2".to_string(),
+        })
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let session: SessionDTO = response.json().await.unwrap();
+
+    assert_eq!(session.user, user);
+    assert!(!session.token.is_empty());
+}
+
+#[tokio::test]
+async fn logging_in_with_wrong_credentials_fails() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+
+    let response = client
+        .post(app.get_base("user/login"))
+        .json(&LoginUserDTO {
+            username: "cook".to_string(),
+            password: "hunter2".to_string(),
+        })
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let unknown_user: ErrorMessage<String> = response.json().await.unwrap();
+
+    client
+        .post(app.get_base("user/signup"))
+        .json(&new_user())
+        .send()
+        .await
+        .unwrap();
+
+    let response = client
+        .post(app.get_base("user/login"))
+        .json(&LoginUserDTO {
+            username: "cook".to_string(),
+            password: "hunter3".to_string(),
+        })
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let wrong_password: ErrorMessage<String> = response.json().await.unwrap();
+
+    // Both look the same, so that nobody can find out who has an account
+    assert_eq!(unknown_user.error, "The username or the password is wrong");
+    assert_eq!(unknown_user.error, wrong_password.error);
+    assert_eq!(unknown_user.kind, wrong_password.kind);
+}
+
+#[tokio::test]
+async fn logging_out_ends_the_session() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+    let token = app.login("cook").await;
+
+    let response = client
+        .post(app.get_base("user/logout"))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = client
+        .get(app.get_base("meal-plan?from=2024-01-01&to=2024-01-07"))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
@@ -0,0 +1,286 @@
+use std::{
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use axum::{
+    body::Bytes,
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    routing::post,
+    Router,
+};
+use backend::api::AppBuilder;
+use common::{CreateWebhookDTO, DomainEventDTO, IngredientDTO, WebhookDTO, WebhookDeliveryDTO};
+use hmac::{Hmac, Mac};
+use pretty_assertions::assert_eq;
+use reqwest::Client;
+use sha2::Sha256;
+use tokio::net::TcpListener;
+
+use crate::{fixtures::ingredient::ingredient_fixture, setup::login};
+
+type Received = Arc<Mutex<Vec<(HeaderMap, Bytes)>>>;
+
+/// The deliveries have to work without a database too, so these tests run against the in-memory repositories.
+/// The partner runs next to the tests, which only works when webhooks can point to private addresses.
+async fn spawn_in_memory_app(builder: AppBuilder) -> SocketAddr {
+    let listener = TcpListener::bind("0.0.0.0:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let app = builder.build().unwrap();
+
+    tokio::spawn(async move {
+        app.serve(listener).await.unwrap();
+    });
+
+    addr
+}
+
+/// Stands in for the partner: fails the first delivery, and accepts the rest
+async fn spawn_receiver() -> (String, Received) {
+    async fn receive(
+        State(received): State<Received>,
+        headers: HeaderMap,
+        body: Bytes,
+    ) -> StatusCode {
+        let mut received = received.lock().unwrap();
+        received.push((headers, body));
+
+        if received.len() == 1 {
+            StatusCode::INTERNAL_SERVER_ERROR
+        } else {
+            StatusCode::OK
+        }
+    }
+
+    let received: Received = Arc::default();
+    let router = Router::new()
+        .route("/hooks", post(receive))
+        .with_state(received.clone());
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+
+    (format!("http://{addr}/hooks"), received)
+}
+
+async fn get_deliveries(
+    client: &Client,
+    addr: &SocketAddr,
+    token: &str,
+    webhook: &WebhookDTO,
+) -> Vec<WebhookDeliveryDTO> {
+    client
+        .get(format!("http://{addr}/webhook/{}/deliveries", webhook.id))
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn events_are_delivered_to_webhooks_until_they_succeed() {
+    let addr = spawn_in_memory_app(AppBuilder::new().allow_private_webhook_targets()).await;
+    let (url, received) = spawn_receiver().await;
+    let client = Client::new();
+    let token = login(&addr, "cook").await;
+
+    let response = client
+        .post(format!("http://{addr}/webhook/create"))
+        .bearer_auth(&token)
+        .json(&CreateWebhookDTO {
+            url,
+            secret: "keep it secret".to_string(),
+            event_types: vec!["ingredient_created".to_string()],
+        })
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let webhook: WebhookDTO = response.json().await.unwrap();
+
+    let ingredient: IngredientDTO = client
+        .post(format!("http://{addr}/ingredient/create"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let mut deliveries = vec![];
+    for _ in 0..50 {
+        deliveries = get_deliveries(&client, &addr, &token, &webhook).await;
+        if deliveries.iter().any(|d| d.succeeded) {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+
+    let attempts: Vec<_> = deliveries
+        .iter()
+        .map(|d| (d.attempt, d.status_code, d.succeeded))
+        .collect();
+    assert_eq!(attempts, vec![(1, Some(500), false), (2, Some(200), true)]);
+    assert_eq!(deliveries[0].id, deliveries[1].id);
+
+    let received = received.lock().unwrap().clone();
+    assert_eq!(received.len(), 2);
+
+    let (headers, body) = &received[1];
+    let mut mac = Hmac::<Sha256>::new_from_slice(b"keep it secret").unwrap();
+    mac.update(body);
+    let signature = format!("sha256={}", hex::encode(mac.finalize().into_bytes()));
+
+    assert_eq!(headers["X-Webhook-Signature"].to_str().unwrap(), signature);
+    assert_eq!(headers["X-Webhook-Event"], "ingredient_created");
+    assert_eq!(
+        received[0].0["X-Webhook-Delivery"],
+        headers["X-Webhook-Delivery"]
+    );
+    assert_eq!(
+        headers["X-Webhook-Delivery"].to_str().unwrap(),
+        deliveries[0].id.to_string()
+    );
+
+    match serde_json::from_slice(body).unwrap() {
+        DomainEventDTO::IngredientCreated {
+            ingredient: created,
+        } => assert_eq!(created, ingredient),
+        e => panic!("Wrong event delivered: {e:?}"),
+    }
+}
+
+#[tokio::test]
+async fn webhooks_for_unknown_events_are_rejected() {
+    let addr = spawn_in_memory_app(AppBuilder::new()).await;
+    let client = Client::new();
+    let token = login(&addr, "cook").await;
+
+    let response = client
+        .post(format!("http://{addr}/webhook/create"))
+        .bearer_auth(&token)
+        .json(&CreateWebhookDTO {
+            url: "https://example.com/hooks".to_string(),
+            secret: "keep it secret".to_string(),
+            event_types: vec!["recipe_eaten".to_string()],
+        })
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let webhooks: Vec<WebhookDTO> = client
+        .get(format!("http://{addr}/webhook"))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert!(webhooks.is_empty());
+}
+
+#[tokio::test]
+async fn webhooks_to_private_addresses_are_rejected() {
+    let addr = spawn_in_memory_app(AppBuilder::new()).await;
+    let (url, _) = spawn_receiver().await;
+    let client = Client::new();
+    let token = login(&addr, "cook").await;
+
+    let response = client
+        .post(format!("http://{addr}/webhook/create"))
+        .bearer_auth(&token)
+        .json(&CreateWebhookDTO {
+            url,
+            secret: "keep it secret".to_string(),
+            event_types: vec!["ingredient_created".to_string()],
+        })
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn webhooks_can_only_be_managed_by_their_owner() {
+    let addr = spawn_in_memory_app(AppBuilder::new().allow_private_webhook_targets()).await;
+    let (url, _) = spawn_receiver().await;
+    let client = Client::new();
+    let input = CreateWebhookDTO {
+        url,
+        secret: "keep it secret".to_string(),
+        event_types: vec!["ingredient_created".to_string()],
+    };
+
+    let response = client
+        .post(format!("http://{addr}/webhook/create"))
+        .json(&input)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let token = login(&addr, "cook").await;
+    let other_token = login(&addr, "baker").await;
+
+    let webhook: WebhookDTO = client
+        .post(format!("http://{addr}/webhook/create"))
+        .bearer_auth(&token)
+        .json(&input)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let webhooks: Vec<WebhookDTO> = client
+        .get(format!("http://{addr}/webhook"))
+        .bearer_auth(&other_token)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert!(webhooks.is_empty());
+
+    let response = client
+        .get(format!("http://{addr}/webhook/{}/deliveries", webhook.id))
+        .bearer_auth(&other_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let response = client
+        .delete(format!("http://{addr}/webhook/{}", webhook.id))
+        .bearer_auth(&other_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let webhooks: Vec<WebhookDTO> = client
+        .get(format!("http://{addr}/webhook"))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(webhooks, vec![webhook]);
+}
@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IngredientOperationDTO } from "./IngredientOperationDTO";
+import type { UpdateRecipeDTO } from "./UpdateRecipeDTO";
+
+export interface BatchEditRecipeDTO { recipe: UpdateRecipeDTO | null, operations: Array<IngredientOperationDTO>, }
\ No newline at end of file
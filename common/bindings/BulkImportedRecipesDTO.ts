@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { FailedRecipeImportDTO } from "./FailedRecipeImportDTO";
+import type { ImportedRecipeDTO } from "./ImportedRecipeDTO";
+
+export interface BulkImportedRecipesDTO { imported: Array<ImportedRecipeDTO>, failed: Array<FailedRecipeImportDTO>, }
\ No newline at end of file
@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { VisibilityDTO } from "./VisibilityDTO";
+
+export interface CollectionDTO { id: string, name: string, description: string, visibility: VisibilityDTO, recipes: Array<string>, cover_recipe_id: string | null, created_at: string, updated_at: string, }
\ No newline at end of file
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IngredientDTO } from "./IngredientDTO";
+import type { IngredientWithAmountDTO } from "./IngredientWithAmountDTO";
+import type { RecipeDTO } from "./RecipeDTO";
+
+export interface CookableRecipeDTO { recipe: RecipeDTO, covered: number, required: number, missing: Array<IngredientWithAmountDTO>, expiring: Array<IngredientDTO>, }
\ No newline at end of file
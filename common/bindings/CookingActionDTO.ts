@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type CookingActionDTO = { "type": "go_to_step", step: number, } | { "type": "next_step" } | { "type": "previous_step" } | { "type": "check_ingredient", ingredient_id: string, } | { "type": "uncheck_ingredient", ingredient_id: string, } | { "type": "start_timer", label: string, } | { "type": "cancel_timer", timer_id: string, };
\ No newline at end of file
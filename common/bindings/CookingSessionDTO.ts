@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CookingTimerDTO } from "./CookingTimerDTO";
+
+export interface CookingSessionDTO { id: string, recipe_id: string, current_step: number, checked_ingredients: Array<string>, timers: Array<CookingTimerDTO>, created_at: string, updated_at: string, }
\ No newline at end of file
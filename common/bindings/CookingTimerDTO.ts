@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export interface CookingTimerDTO { id: string, label: string, duration: bigint, started_at: string, ends_at: string, finished: boolean, }
\ No newline at end of file
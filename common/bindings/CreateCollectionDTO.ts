@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { VisibilityDTO } from "./VisibilityDTO";
+
+export interface CreateCollectionDTO { name: string, description: string, visibility: VisibilityDTO, recipes: Array<string>, cover_recipe_id: string | null, }
\ No newline at end of file
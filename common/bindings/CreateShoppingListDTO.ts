@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ShoppingListRecipeDTO } from "./ShoppingListRecipeDTO";
+
+export interface CreateShoppingListDTO { name: string, recipes: Array<ShoppingListRecipeDTO>, exclude_optional: boolean, }
\ No newline at end of file
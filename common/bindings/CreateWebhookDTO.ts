@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export interface CreateWebhookDTO { url: string, secret: string, event_types: Array<string>, }
\ No newline at end of file
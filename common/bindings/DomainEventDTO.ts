@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IngredientDTO } from "./IngredientDTO";
+import type { RecipeDTO } from "./RecipeDTO";
+
+export type DomainEventDTO = { "type": "recipe_created", recipe: RecipeDTO, } | { "type": "recipe_updated", recipe: RecipeDTO, } | { "type": "recipe_deleted", recipe_id: string, } | { "type": "ingredient_added_to_recipe", recipe_id: string, ingredient_id: string, } | { "type": "ingredient_in_recipe_updated", recipe_id: string, ingredient_id: string, } | { "type": "ingredient_removed_from_recipe", recipe_id: string, ingredient_id: string, } | { "type": "ingredient_created", ingredient: IngredientDTO, } | { "type": "ingredient_updated", ingredient: IngredientDTO, } | { "type": "ingredient_deleted", ingredient_id: string, };
\ No newline at end of file
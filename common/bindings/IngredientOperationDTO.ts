@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IngredientAmountDTO } from "./IngredientAmountDTO";
+import type { IngredientUnitDTO } from "./IngredientUnitDTO";
+
+export type IngredientOperationDTO = { "op": "add" } & IngredientAmountDTO | { "op": "update", entry_id: string, amount: IngredientUnitDTO | null, notes: string | null, optional: boolean | null, group: string | null, position: number | null, } | { "op": "remove", entry_id: string, };
\ No newline at end of file
@@ -1,3 +1,3 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export type IngredientUnitDTO = { "_type": "mililiters", "amount": number } | { "_type": "grams", "amount": number } | { "_type": "teaspoons", "amount": number } | { "_type": "cups", "amount": number } | { "_type": "other", "amount": { amount: number, unit: string, } };
\ No newline at end of file
+export type IngredientUnitDTO = { "_type": "mililiters", "amount": number } | { "_type": "grams", "amount": number } | { "_type": "teaspoons", "amount": number } | { "_type": "tablespoons", "amount": number } | { "_type": "cups", "amount": number } | { "_type": "pieces", "amount": number } | { "_type": "other", "amount": { amount: number, unit: string, } } | { "_type": "range", "amount": { from: IngredientUnitDTO, to: number, } } | { "_type": "unquantified", "amount": string };
\ No newline at end of file
@@ -2,4 +2,4 @@
 import type { IngredientDTO } from "./IngredientDTO";
 import type { IngredientUnitDTO } from "./IngredientUnitDTO";
 
-export interface IngredientWithAmountDTO { ingredient: IngredientDTO, amount: IngredientUnitDTO, optional: boolean, notes: string | null, }
\ No newline at end of file
+export interface IngredientWithAmountDTO { id: string, ingredient: IngredientDTO, amount: IngredientUnitDTO, optional: boolean, notes: string | null, group: string | null, }
\ No newline at end of file
@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { MealSlotDTO } from "./MealSlotDTO";
+
+export interface MealPlanEntryDTO { id: string, date: string, slot: MealSlotDTO, recipe_id: string, servings: number, created_at: string, updated_at: string, }
\ No newline at end of file
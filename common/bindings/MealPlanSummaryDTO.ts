@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AggregatedIngredientDTO } from "./AggregatedIngredientDTO";
+import type { MealPlanEntryDTO } from "./MealPlanEntryDTO";
+
+export interface MealPlanSummaryDTO { from: string, to: string, entries: Array<MealPlanEntryDTO>, ingredients: Array<AggregatedIngredientDTO>, diet_violations: Array<string>, }
\ No newline at end of file
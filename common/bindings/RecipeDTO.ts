@@ -1,5 +1,6 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { IngredientWithAmountDTO } from "./IngredientWithAmountDTO";
 import type { ServingsTypeDTO } from "./ServingsTypeDTO";
+import type { VisibilityDTO } from "./VisibilityDTO";
 
-export interface RecipeDTO { id: string, name: string, description: string, steps: Array<string>, time: Record<string, number>, ingredients: Array<IngredientWithAmountDTO>, servings: ServingsTypeDTO, created_at: string, updated_at: string, diet_violations: Array<string>, }
\ No newline at end of file
+export interface RecipeDTO { id: string, name: string, description: string, steps: Array<string>, time: Record<string, number>, ingredients: Array<IngredientWithAmountDTO>, servings: ServingsTypeDTO, created_at: string, updated_at: string, diet_violations: Array<string>, rating_average: number | null, rating_count: number, forked_from: string | null, visibility: VisibilityDTO, }
\ No newline at end of file
@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { FailedRecipePreviewDTO } from "./FailedRecipePreviewDTO";
+import type { RecipePreviewDTO } from "./RecipePreviewDTO";
+
+export interface RecipePreviewsDTO { previews: Array<RecipePreviewDTO>, failed: Array<FailedRecipePreviewDTO>, }
\ No newline at end of file
@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export interface ReviewDTO { id: string, recipe_id: string, author: string, rating: number, body: string | null, created_at: string, updated_at: string, }
\ No newline at end of file
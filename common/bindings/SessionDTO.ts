@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { UserDataDTO } from "./UserDataDTO";
+
+export interface SessionDTO { token: string, user: UserDataDTO, expires_at: string, }
\ No newline at end of file
@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ShoppingListItemDTO } from "./ShoppingListItemDTO";
+
+export interface ShoppingListDTO { id: string, name: string, recipe_ids: Array<string>, items: Array<ShoppingListItemDTO>, created_at: string, updated_at: string, }
\ No newline at end of file
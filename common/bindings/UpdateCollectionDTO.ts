@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { VisibilityDTO } from "./VisibilityDTO";
+
+export interface UpdateCollectionDTO { name: string | null, description: string | null, visibility: VisibilityDTO | null, cover_recipe_id: string | null, }
\ No newline at end of file
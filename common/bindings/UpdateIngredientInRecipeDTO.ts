@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IngredientUnitDTO } from "./IngredientUnitDTO";
+
+export interface UpdateIngredientInRecipeDTO { amount: IngredientUnitDTO | null, notes: string | null, optional: boolean | null, group: string | null, position: number | null, }
\ No newline at end of file
@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { MealSlotDTO } from "./MealSlotDTO";
+
+export interface UpdateMealPlanEntryDTO { date: string | null, slot: MealSlotDTO | null, recipe_id: string | null, servings: number | null, }
\ No newline at end of file
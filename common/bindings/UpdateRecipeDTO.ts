@@ -1,4 +1,5 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { ServingsTypeDTO } from "./ServingsTypeDTO";
+import type { VisibilityDTO } from "./VisibilityDTO";
 
-export interface UpdateRecipeDTO { name: string | null, description: string | null, steps: Array<string> | null, time: Record<string, number> | null, servings: ServingsTypeDTO | null, }
\ No newline at end of file
+export interface UpdateRecipeDTO { name: string | null, description: string | null, steps: Array<string> | null, time: Record<string, number> | null, servings: ServingsTypeDTO | null, visibility: VisibilityDTO | null, }
\ No newline at end of file
@@ -1,3 +1,3 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export interface UserDataDTO { username: string, email: string, }
\ No newline at end of file
+export interface UserDataDTO { id: string, username: string, email: string, }
\ No newline at end of file
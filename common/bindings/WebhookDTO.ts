@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export interface WebhookDTO { id: string, url: string, event_types: Array<string>, created_at: string, }
\ No newline at end of file
@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export interface WebhookDeliveryDTO { id: string, webhook_id: string, event_type: string, payload: unknown, attempt: number, status_code: number | null, error: string | null, succeeded: boolean, created_at: string, }
\ No newline at end of file
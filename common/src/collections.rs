@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use uuid::Uuid;
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Serialize, Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum VisibilityDTO {
+    Private,
+    Unlisted,
+    Public,
+}
+
+#[derive(Deserialize, Serialize, TS, Debug)]
+#[ts(export)]
+pub struct CreateCollectionDTO {
+    pub name: String,
+    pub description: String,
+    pub visibility: VisibilityDTO,
+    /// Recipes in the order they should appear in
+    pub recipes: Vec<Uuid>,
+    /// Has to be one of the recipes of the collection
+    pub cover_recipe_id: Option<Uuid>,
+}
+
+#[derive(Deserialize, Serialize, TS, Debug)]
+#[ts(export)]
+pub struct UpdateCollectionDTO {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub visibility: Option<VisibilityDTO>,
+    pub cover_recipe_id: Option<Uuid>,
+}
+
+#[derive(Deserialize, Serialize, TS, Debug)]
+#[ts(export)]
+pub struct AddRecipeToCollectionDTO {
+    pub recipe_id: Uuid,
+    /// Where to put the recipe, counting from zero. Recipes are appended at the end when empty.
+    pub position: Option<usize>,
+}
+
+#[derive(Deserialize, Serialize, TS, Debug, Clone, PartialEq)]
+#[ts(export)]
+pub struct CollectionDTO {
+    pub id: Uuid,
+    pub name: String,
+    pub description: String,
+    pub visibility: VisibilityDTO,
+    pub recipes: Vec<Uuid>,
+    pub cover_recipe_id: Option<Uuid>,
+    pub created_at: String,
+    pub updated_at: String,
+}
@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use uuid::Uuid;
+
+#[derive(Deserialize, Serialize, TS, Debug)]
+#[ts(export)]
+pub struct CreateCookingSessionDTO {
+    pub recipe_id: Uuid,
+}
+
+#[derive(Deserialize, Serialize, TS, Debug, Clone, PartialEq)]
+#[ts(export)]
+pub struct CookingTimerDTO {
+    pub id: Uuid,
+    /// Either a key of the recipe's time, or `Step N` for a duration found in the N-th step
+    pub label: String,
+    /// In seconds
+    pub duration: u64,
+    pub started_at: String,
+    pub ends_at: String,
+    pub finished: bool,
+}
+
+#[derive(Deserialize, Serialize, TS, Debug, Clone, PartialEq)]
+#[ts(export)]
+pub struct CookingSessionDTO {
+    pub id: Uuid,
+    pub recipe_id: Uuid,
+    /// Index of the step being cooked, starting at 0
+    pub current_step: u32,
+    pub checked_ingredients: Vec<Uuid>,
+    pub timers: Vec<CookingTimerDTO>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// Something a cook did in the session, sent over the WebSocket or posted to the session
+#[derive(Deserialize, Serialize, TS, Debug, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[ts(export)]
+pub enum CookingActionDTO {
+    GoToStep { step: u32 },
+    NextStep,
+    PreviousStep,
+    CheckIngredient { ingredient_id: Uuid },
+    UncheckIngredient { ingredient_id: Uuid },
+    StartTimer { label: String },
+    CancelTimer { timer_id: Uuid },
+}
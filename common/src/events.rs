@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use uuid::Uuid;
+
+use crate::{IngredientDTO, RecipeDTO};
+
+/// Something that happened to a recipe or an ingredient.
+/// Sent as the data of a server-sent event, with the event name being the same as `type`.
+#[derive(Deserialize, Serialize, TS, Debug)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[ts(export)]
+pub enum DomainEventDTO {
+    RecipeCreated {
+        recipe: RecipeDTO,
+    },
+    RecipeUpdated {
+        recipe: RecipeDTO,
+    },
+    RecipeDeleted {
+        recipe_id: Uuid,
+    },
+    IngredientAddedToRecipe {
+        recipe_id: Uuid,
+        ingredient_id: Uuid,
+    },
+    IngredientInRecipeUpdated {
+        recipe_id: Uuid,
+        ingredient_id: Uuid,
+    },
+    IngredientRemovedFromRecipe {
+        recipe_id: Uuid,
+        ingredient_id: Uuid,
+    },
+    IngredientCreated {
+        ingredient: IngredientDTO,
+    },
+    IngredientUpdated {
+        ingredient: IngredientDTO,
+    },
+    IngredientDeleted {
+        ingredient_id: Uuid,
+    },
+}
@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use uuid::Uuid;
+
+use crate::RecipeDTO;
+
+#[derive(Deserialize, Serialize, TS, Debug, Clone, PartialEq)]
+#[ts(export)]
+pub struct FavoriteDTO {
+    pub recipe_id: Uuid,
+    pub created_at: String,
+}
+
+#[derive(Deserialize, Serialize, TS, Debug)]
+#[ts(export)]
+pub struct FavoriteRecipeDTO {
+    pub recipe: RecipeDTO,
+    pub favorited_at: String,
+    pub notes: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, TS, Debug)]
+#[ts(export)]
+pub struct SaveRecipeNoteDTO {
+    pub content: String,
+}
+
+#[derive(Deserialize, Serialize, TS, Debug, Clone, PartialEq)]
+#[ts(export)]
+pub struct RecipeNoteDTO {
+    pub recipe_id: Uuid,
+    pub content: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
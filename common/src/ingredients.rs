@@ -3,6 +3,8 @@ use ts_rs::TS;
 use utoipa::ToSchema;
 use uuid::Uuid;
 
+use crate::IngredientUnitDTO;
+
 #[derive(Serialize, Deserialize, Debug, ToSchema, TS)]
 #[ts(export)]
 pub struct CreateIngredientDTO {
@@ -40,3 +42,21 @@ pub struct UpdateIngredientDTO {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub diet_violations: Option<Vec<String>>,
 }
+
+#[derive(Serialize, Deserialize, Debug, ToSchema, TS)]
+#[ts(export)]
+pub struct ParseIngredientLinesDTO {
+    pub lines: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, TS)]
+#[ts(export)]
+pub struct ParsedIngredientLineDTO {
+    pub line: String,
+    pub amount: IngredientUnitDTO,
+    pub name: String,
+    pub notes: Option<String>,
+    pub optional: bool,
+    /// The existing ingredient the line most likely refers to
+    pub ingredient: Option<IngredientDTO>,
+}
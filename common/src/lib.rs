@@ -1,7 +1,25 @@
+pub mod collections;
+pub mod cooking_sessions;
 pub mod error;
+pub mod events;
+pub mod favorites;
 pub mod ingredients;
+pub mod meal_plans;
+pub mod pantry;
 pub mod recipes;
+pub mod reviews;
+pub mod shopping_lists;
 pub mod user;
+pub mod webhooks;
 
+pub use collections::*;
+pub use cooking_sessions::*;
+pub use events::*;
+pub use favorites::*;
 pub use ingredients::*;
+pub use meal_plans::*;
+pub use pantry::*;
 pub use recipes::*;
+pub use reviews::*;
+pub use shopping_lists::*;
+pub use webhooks::*;
@@ -0,0 +1,80 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use uuid::Uuid;
+
+use crate::{IngredientDTO, IngredientUnitDTO};
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Serialize, Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum MealSlotDTO {
+    Breakfast,
+    Lunch,
+    Dinner,
+    Snack,
+}
+
+#[derive(Deserialize, Serialize, TS, Debug)]
+#[ts(export)]
+pub struct CreateMealPlanEntryDTO {
+    pub date: NaiveDate,
+    pub slot: MealSlotDTO,
+    pub recipe_id: Uuid,
+    pub servings: u16,
+}
+
+#[derive(Deserialize, Serialize, TS, Debug)]
+#[ts(export)]
+pub struct UpdateMealPlanEntryDTO {
+    pub date: Option<NaiveDate>,
+    pub slot: Option<MealSlotDTO>,
+    pub recipe_id: Option<Uuid>,
+    pub servings: Option<u16>,
+}
+
+#[derive(Deserialize, Serialize, TS, Debug, Clone, PartialEq)]
+#[ts(export)]
+pub struct MealPlanEntryDTO {
+    pub id: Uuid,
+    pub date: NaiveDate,
+    pub slot: MealSlotDTO,
+    pub recipe_id: Uuid,
+    pub servings: u16,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Deserialize, Serialize, TS, Debug, Clone, PartialEq)]
+#[ts(export)]
+pub struct AggregatedIngredientDTO {
+    pub ingredient: IngredientDTO,
+    pub amount: IngredientUnitDTO,
+}
+
+#[derive(Deserialize, Serialize, TS, Debug)]
+#[ts(export)]
+pub struct MealPlanSummaryDTO {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub entries: Vec<MealPlanEntryDTO>,
+    pub ingredients: Vec<AggregatedIngredientDTO>,
+    pub diet_violations: Vec<String>,
+}
+
+#[derive(Deserialize, Serialize, TS, Debug)]
+#[ts(export)]
+pub struct CreateCalendarFeedDTO {
+    pub name: String,
+}
+
+#[derive(Deserialize, Serialize, TS, Debug, Clone, PartialEq)]
+#[ts(export)]
+pub struct CalendarFeedDTO {
+    pub id: Uuid,
+    pub name: String,
+    pub token: String,
+    /// Path of the iCalendar feed, relative to the API
+    pub url: String,
+    pub created_at: String,
+}
@@ -0,0 +1,43 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use uuid::Uuid;
+
+use crate::{IngredientDTO, IngredientUnitDTO, IngredientWithAmountDTO, RecipeDTO};
+
+#[derive(Deserialize, Serialize, TS, Debug)]
+#[ts(export)]
+pub struct CreatePantryItemDTO {
+    pub ingredient_id: Uuid,
+    /// Leave empty when the amount does not matter, i.e. for spices
+    pub amount: Option<IngredientUnitDTO>,
+    pub expires_at: Option<NaiveDate>,
+}
+
+#[derive(Deserialize, Serialize, TS, Debug)]
+#[ts(export)]
+pub struct UpdatePantryItemDTO {
+    pub amount: Option<IngredientUnitDTO>,
+    pub expires_at: Option<NaiveDate>,
+}
+
+#[derive(Deserialize, Serialize, TS, Debug, Clone, PartialEq)]
+#[ts(export)]
+pub struct PantryItemDTO {
+    pub id: Uuid,
+    pub ingredient: IngredientDTO,
+    pub amount: Option<IngredientUnitDTO>,
+    pub expires_at: Option<NaiveDate>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Deserialize, Serialize, TS, Debug)]
+#[ts(export)]
+pub struct CookableRecipeDTO {
+    pub recipe: RecipeDTO,
+    pub covered: usize,
+    pub required: usize,
+    pub missing: Vec<IngredientWithAmountDTO>,
+    pub expiring: Vec<IngredientDTO>,
+}
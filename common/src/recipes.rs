@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 use uuid::Uuid;
 
-use crate::IngredientDTO;
+use crate::{IngredientDTO, VisibilityDTO};
 
 #[derive(Deserialize, Serialize, Debug, TS)]
 #[ts(export)]
@@ -32,6 +32,12 @@ pub struct RecipeDTO {
     pub created_at: String,
     pub updated_at: String,
     pub diet_violations: Vec<String>,
+    /// Average of all the ratings, missing if nobody reviewed the recipe yet
+    pub rating_average: Option<f64>,
+    pub rating_count: u32,
+    /// ID of the recipe this one was forked from
+    pub forked_from: Option<String>,
+    pub visibility: VisibilityDTO,
 }
 
 #[derive(PartialEq, Debug, Clone, Serialize, Deserialize, TS)]
@@ -49,15 +55,27 @@ pub struct IngredientAmountDTO {
     pub amount: IngredientUnitDTO,
     pub optional: bool,
     pub notes: Option<String>,
+    /// Label of the part of the recipe the ingredient is used in, i.e. `For the sauce`
+    pub group: Option<String>,
+}
+
+/// An ingredient written as a single line, like `2 cloves garlic, minced`
+#[derive(Deserialize, Serialize, TS, Debug)]
+#[ts(export)]
+pub struct AddIngredientLineToRecipeDTO {
+    pub line: String,
 }
 
 #[derive(Deserialize, Serialize, TS, Debug)]
 #[ts(export)]
 pub struct IngredientWithAmountDTO {
+    /// ID of the entry in the ingredient list, the same ingredient can be listed more than once
+    pub id: Uuid,
     pub ingredient: IngredientDTO,
     pub amount: IngredientUnitDTO,
     pub optional: bool,
     pub notes: Option<String>,
+    pub group: Option<String>,
 }
 
 #[derive(PartialEq, Debug, Clone, Serialize, Deserialize, TS)]
@@ -67,8 +85,35 @@ pub enum IngredientUnitDTO {
     Mililiters(f64),
     Grams(f64),
     Teaspoons(f64),
+    Tablespoons(f64),
     Cups(f64),
-    Other { amount: f64, unit: String },
+    /// A number of whole things, i.e. `3 eggs`
+    Pieces(f64),
+    Other {
+        amount: f64,
+        unit: String,
+    },
+    /// `2–3 cloves` is `from` 2 cloves `to` 3
+    Range {
+        from: Box<IngredientUnitDTO>,
+        to: f64,
+    },
+    /// An amount nobody measures, i.e. `a pinch` or `to taste`
+    Unquantified(String),
+}
+
+/// Changes to a single ingredient of a recipe, anything left out stays the same
+#[derive(Deserialize, Serialize, TS, Debug, Default)]
+#[ts(export)]
+pub struct UpdateIngredientInRecipeDTO {
+    pub amount: Option<IngredientUnitDTO>,
+    /// An empty string removes the notes
+    pub notes: Option<String>,
+    pub optional: Option<bool>,
+    /// An empty string takes the ingredient out of its group
+    pub group: Option<String>,
+    /// Where to move the ingredient in the list, starting from zero
+    pub position: Option<usize>,
 }
 
 #[derive(Deserialize, Serialize, TS, Debug)]
@@ -80,4 +125,109 @@ pub struct UpdateRecipeDTO {
     #[ts(type = "Record<string, number> | null")]
     pub time: Option<BTreeMap<String, u64>>,
     pub servings: Option<ServingsTypeDTO>,
+    pub visibility: Option<VisibilityDTO>,
+}
+
+/// A single change to the ingredient list of a recipe, as part of [`BatchEditRecipeDTO`]
+#[derive(Deserialize, Serialize, TS, Debug)]
+#[serde(tag = "op", rename_all = "snake_case")]
+#[ts(export)]
+pub enum IngredientOperationDTO {
+    Add(IngredientAmountDTO),
+    Update {
+        entry_id: Uuid,
+        #[serde(flatten)]
+        changes: UpdateIngredientInRecipeDTO,
+    },
+    Remove {
+        entry_id: Uuid,
+    },
+}
+
+/// Changes to a recipe and its ingredients that are applied all at once
+#[derive(Deserialize, Serialize, TS, Debug)]
+#[ts(export)]
+pub struct BatchEditRecipeDTO {
+    pub recipe: Option<UpdateRecipeDTO>,
+    /// Applied in order, entries are referred to by the IDs the recipe was fetched with
+    #[serde(default)]
+    pub operations: Vec<IngredientOperationDTO>,
+}
+
+#[derive(Deserialize, Serialize, TS, Debug)]
+#[ts(export)]
+pub struct FailedIngredientOperationDTO {
+    /// Position of the operation in the batch
+    pub index: usize,
+    pub kind: String,
+    pub message: String,
+}
+
+#[derive(Deserialize, Serialize, TS, Debug)]
+#[ts(export)]
+pub struct ImportedRecipeDTO {
+    pub recipe: RecipeDTO,
+    pub unmatched_ingredients: Vec<String>,
+}
+
+#[derive(Deserialize, Serialize, TS, Debug)]
+#[ts(export)]
+pub struct FailedRecipeImportDTO {
+    pub file_name: String,
+    pub kind: String,
+    pub message: String,
+}
+
+#[derive(Deserialize, Serialize, TS, Debug)]
+#[ts(export)]
+pub struct BulkImportedRecipesDTO {
+    pub imported: Vec<ImportedRecipeDTO>,
+    pub failed: Vec<FailedRecipeImportDTO>,
+}
+
+#[derive(Deserialize, Serialize, TS, Debug)]
+#[ts(export)]
+pub struct RecipePreviewDTO {
+    pub recipe: CreateRecipeDTO,
+    pub unmatched_ingredients: Vec<String>,
+}
+
+#[derive(Deserialize, Serialize, TS, Debug)]
+#[ts(export)]
+pub struct FailedRecipePreviewDTO {
+    /// Position of the recipe in the document, i.e. `recipe 2`
+    pub recipe: String,
+    pub kind: String,
+    pub message: String,
+}
+
+#[derive(Deserialize, Serialize, TS, Debug)]
+#[ts(export)]
+pub struct RecipePreviewsDTO {
+    pub previews: Vec<RecipePreviewDTO>,
+    pub failed: Vec<FailedRecipePreviewDTO>,
+}
+
+#[derive(Deserialize, Serialize, TS, Debug)]
+#[ts(export)]
+pub struct ExportCookbookDTO {
+    /// Defaults to the name of the collection, if there is one
+    pub title: Option<String>,
+    /// Recipes to put in the cookbook, in order. Either this or the collection has to be given.
+    #[serde(default)]
+    pub recipe_ids: Vec<Uuid>,
+    pub collection_id: Option<Uuid>,
+    /// Only keep the recipes suitable for all of these diets, e.g. `vegan`
+    #[serde(default)]
+    pub diets: Vec<String>,
+}
+
+#[derive(Deserialize, Serialize, TS, Debug, Clone, PartialEq)]
+#[ts(export)]
+pub struct ShareTokenDTO {
+    pub token: String,
+    pub recipe_id: Uuid,
+    /// Path of the shared recipe, relative to the API
+    pub url: String,
+    pub created_at: String,
 }
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use uuid::Uuid;
+
+/// Written in the name of the user who is logged in, every user can review a recipe once
+#[derive(Deserialize, Serialize, TS, Debug)]
+#[ts(export)]
+pub struct CreateReviewDTO {
+    /// From 1 to 5
+    pub rating: u8,
+    pub body: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, TS, Debug)]
+#[ts(export)]
+pub struct UpdateReviewDTO {
+    pub rating: Option<u8>,
+    /// An empty body removes the text of the review
+    pub body: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, TS, Debug, Clone, PartialEq)]
+#[ts(export)]
+pub struct ReviewDTO {
+    pub id: Uuid,
+    pub recipe_id: Uuid,
+    /// Username of the author
+    pub author: String,
+    pub rating: u8,
+    pub body: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use uuid::Uuid;
+
+use crate::{IngredientDTO, IngredientUnitDTO};
+
+#[derive(Deserialize, Serialize, TS, Debug)]
+#[ts(export)]
+pub struct ShoppingListRecipeDTO {
+    pub recipe_id: Uuid,
+    /// Defaults to the servings of the recipe
+    pub servings: Option<u16>,
+}
+
+#[derive(Deserialize, Serialize, TS, Debug)]
+#[ts(export)]
+pub struct CreateShoppingListDTO {
+    pub name: String,
+    pub recipes: Vec<ShoppingListRecipeDTO>,
+    #[serde(default)]
+    pub exclude_optional: bool,
+}
+
+#[derive(Deserialize, Serialize, TS, Debug, Clone, PartialEq)]
+#[ts(export)]
+pub struct ShoppingListItemDTO {
+    pub id: Uuid,
+    pub ingredient: IngredientDTO,
+    pub amount: IngredientUnitDTO,
+    pub checked: bool,
+}
+
+#[derive(Deserialize, Serialize, TS, Debug)]
+#[ts(export)]
+pub struct ShoppingListDTO {
+    pub id: Uuid,
+    pub name: String,
+    pub recipe_ids: Vec<Uuid>,
+    pub items: Vec<ShoppingListItemDTO>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Deserialize, Serialize, TS, Debug)]
+#[ts(export)]
+pub struct UpdateShoppingListItemDTO {
+    pub checked: bool,
+}
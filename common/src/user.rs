@@ -1,6 +1,8 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 use utoipa::ToSchema;
+use uuid::Uuid;
 
 #[derive(Serialize, Deserialize, ToSchema, TS)]
 #[ts(export)]
@@ -17,9 +19,20 @@ pub struct LoginUserDTO {
     pub password: String,
 }
 
-#[derive(Serialize, Deserialize, ToSchema, TS)]
+#[derive(Serialize, Deserialize, ToSchema, TS, Debug, Clone, PartialEq)]
 #[ts(export)]
 pub struct UserDataDTO {
+    pub id: Uuid,
     pub username: String,
     pub email: String,
 }
+
+#[derive(Serialize, Deserialize, ToSchema, TS, Debug, Clone, PartialEq)]
+#[ts(export)]
+pub struct SessionDTO {
+    /// Sent back as `Authorization: Bearer <token>` on the requests of the user
+    pub token: String,
+    pub user: UserDataDTO,
+    /// After this the user has to log in again
+    pub expires_at: DateTime<Utc>,
+}
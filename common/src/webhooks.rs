@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use uuid::Uuid;
+
+#[derive(Deserialize, Serialize, TS, Debug)]
+#[ts(export)]
+pub struct CreateWebhookDTO {
+    pub url: String,
+    /// Used to sign the deliveries, never sent back
+    pub secret: String,
+    /// Names of the events to deliver, i.e. `recipe_created`
+    pub event_types: Vec<String>,
+}
+
+#[derive(Deserialize, Serialize, TS, Debug, Clone, PartialEq)]
+#[ts(export)]
+pub struct WebhookDTO {
+    pub id: Uuid,
+    pub url: String,
+    pub event_types: Vec<String>,
+    pub created_at: String,
+}
+
+/// A single attempt at delivering an event to a webhook
+#[derive(Deserialize, Serialize, TS, Debug, Clone, PartialEq)]
+#[ts(export)]
+pub struct WebhookDeliveryDTO {
+    /// Same for every attempt at delivering the same event
+    pub id: Uuid,
+    pub webhook_id: Uuid,
+    pub event_type: String,
+    #[ts(type = "unknown")]
+    pub payload: serde_json::Value,
+    pub attempt: u32,
+    pub status_code: Option<u16>,
+    pub error: Option<String>,
+    pub succeeded: bool,
+    pub created_at: String,
+}
@@ -6,27 +6,45 @@ import convert from 'convert-units';
 import { formatQuantity } from 'format-quantity';
 import type { DetailedHTMLProps, FC, HTMLAttributes, PropsWithChildren } from 'react';
 import * as Tooltip from '@radix-ui/react-tooltip';
-import { CircleHelpIcon, GlassWaterIcon, WeightIcon } from 'lucide-react';
+import { CircleHelpIcon, GlassWaterIcon, HashIcon, WeightIcon } from 'lucide-react';
 
 import Spoon from '~/icons/spoon.svg?react';
 
-type UnitIconProps = { unit: IngredientUnitDTO['_type'] };
+type UnitIconProps = { unit: IngredientUnitDTO };
 
 export const UnitIcon: FC<UnitIconProps> = ({ unit }) => {
-  switch (unit) {
+  switch (unit._type) {
     case 'mililiters':
     case 'cups':
       return <GlassWaterIcon />;
     case 'teaspoons':
+    case 'tablespoons':
       return <Spoon />;
     case 'grams':
       return <WeightIcon />;
+    case 'pieces':
+      return <HashIcon />;
+    case 'range':
+      return <UnitIcon unit={unit.amount.from} />;
     default:
       return '';
   }
 };
 
-const mapIngredientUnit = (ingredientUnit: IngredientUnitDTO): { unit: string; val: number } => {
+type QuantifiedUnitDTO = Exclude<IngredientUnitDTO, { _type: 'range' | 'unquantified' }>;
+
+// Cubic and imperial units are never what a recipe means by a spoonful
+const convertSpoons = (amount: number, from: 'tsp' | 'Tbs') =>
+  convert(amount)
+    .from(from)
+    .toBest({
+      exclude: [
+        'mm3', 'cm3', 'm3', 'km3', 'in3', 'fl-oz', 'pnt', 'qt', 'gal', 'ft3', 'yd3',
+      ],
+      cutOffNumber: 1,
+    });
+
+const mapIngredientUnit = (ingredientUnit: QuantifiedUnitDTO): { unit: string; val: number } => {
   let unit: { unit: string; val: number };
 
   switch (ingredientUnit._type) {
@@ -37,18 +55,17 @@ const mapIngredientUnit = (ingredientUnit: IngredientUnitDTO): { unit: string; v
       unit = convert(ingredientUnit.amount).from('g').toBest();
       break;
     case 'teaspoons':
-      unit = convert(ingredientUnit.amount)
-        .from('tsp')
-        .toBest({
-          exclude: [
-            'mm3', 'cm3', 'm3', 'km3', 'in3', 'fl-oz', 'pnt', 'qt', 'gal', 'ft3', 'yd3',
-          ],
-          cutOffNumber: 1,
-        });
+      unit = convertSpoons(ingredientUnit.amount, 'tsp');
+      break;
+    case 'tablespoons':
+      unit = convertSpoons(ingredientUnit.amount, 'Tbs');
       break;
     case 'mililiters':
       unit = convert(ingredientUnit.amount).from('ml').toBest();
       break;
+    case 'pieces':
+      unit = { unit: '', val: ingredientUnit.amount };
+      break;
     default:
       unit = {
         unit: ingredientUnit.amount.unit,
@@ -59,6 +76,34 @@ const mapIngredientUnit = (ingredientUnit: IngredientUnitDTO): { unit: string; v
   return unit;
 };
 
+const quantityOf = (ingredientUnit: QuantifiedUnitDTO): number =>
+  ingredientUnit._type === 'other' ? ingredientUnit.amount.amount : ingredientUnit.amount;
+
+const formatIngredientAmount = (ingredientUnit: IngredientUnitDTO): string => {
+  switch (ingredientUnit._type) {
+    case 'unquantified':
+      return ingredientUnit.amount;
+    case 'range': {
+      const { from, to } = ingredientUnit.amount;
+      if (from._type === 'range' || from._type === 'unquantified') {
+        return formatIngredientAmount(from);
+      }
+
+      // Both ends are shown in the unit picked for the lower one
+      const lower = mapIngredientUnit(from);
+      const quantity = quantityOf(from);
+      const upper = quantity === 0 ? to : to * lower.val / quantity;
+
+      return `${formatQuantity(lower.val, true)}-${formatQuantity(upper, true)} ${lower.unit}`.trim();
+    }
+    default: {
+      const unit = mapIngredientUnit(ingredientUnit);
+
+      return `${formatQuantity(unit.val, true)} ${unit.unit}`.trim();
+    }
+  }
+};
+
 type IngredientListElementProps =
     PropsWithChildren<
       DetailedHTMLProps<
@@ -95,7 +140,7 @@ export function IngredientList({ ingredients, className }: IngredientListProps)
       {ingredients.map(ingredientWithAmount => (
         <IngredientListItem
           ingredient={ingredientWithAmount}
-          key={ingredientWithAmount.ingredient.id}
+          key={ingredientWithAmount.id}
         />
       ))}
     </IngredientListElement>
@@ -103,8 +148,7 @@ export function IngredientList({ ingredients, className }: IngredientListProps)
 }
 
 export function IngredientListItem({ ingredient }: { ingredient: IngredientWithAmountDTO }) {
-  const unit = mapIngredientUnit(ingredient.amount);
-  const amount = `${formatQuantity(unit.val, true)} ${unit.unit}`;
+  const amount = formatIngredientAmount(ingredient.amount);
 
   return (
     <Tooltip.Root>
@@ -129,7 +173,7 @@ export function IngredientListItem({ ingredient }: { ingredient: IngredientWithA
           <div className="flex flex-row items-baseline">
             {ingredient.optional && <span className="font-semibold text-text-400 text-sm">(optional)</span>}
             <div className="flex flex-row items-center">
-              <UnitIcon unit={ingredient.amount._type} />
+              <UnitIcon unit={ingredient.amount} />
               <span className="ml-2">{amount}</span>
             </div>
           </div>